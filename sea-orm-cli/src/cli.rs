@@ -167,6 +167,7 @@ pub enum GenerateSubcommands {
     #[command(about = "Generate entity")]
     #[command(group(ArgGroup::new("formats").args(&["compact_format", "expanded_format"])))]
     #[command(group(ArgGroup::new("group-tables").args(&["tables", "include_hidden_tables"])))]
+    #[command(group(ArgGroup::new("import").args(&["from_diesel_schema", "from_sql_migrations"])))]
     Entity {
         #[arg(long, help = "Generate entity file of compact format")]
         compact_format: bool,
@@ -228,8 +229,35 @@ pub enum GenerateSubcommands {
         )]
         database_schema: Option<String>,
 
-        #[arg(short = 'u', long, env = "DATABASE_URL", help = "Database URL")]
-        database_url: String,
+        #[arg(
+            short = 'u',
+            long,
+            env = "DATABASE_URL",
+            required_unless_present_any = ["from_diesel_schema", "from_sql_migrations"],
+            help = "Database URL"
+        )]
+        database_url: Option<String>,
+
+        #[arg(
+            long,
+            help = "Generate entity files from a Diesel `schema.rs` instead of a database"
+        )]
+        from_diesel_schema: Option<String>,
+
+        #[arg(
+            long,
+            long_help = "Generate entity files from a directory of SQL migrations instead of a database.\n\
+                         Each migration is either a `.sql` file or a directory with an `up.sql` file, \
+                         replayed in the order of their names."
+        )]
+        from_sql_migrations: Option<String>,
+
+        #[arg(
+            long,
+            requires = "import",
+            help = "Also write a baseline migration of the imported schema to this migration directory"
+        )]
+        baseline_migration_dir: Option<String>,
 
         #[arg(
             long,
@@ -166,7 +166,7 @@ pub fn run_migrate_generate(
 /// This way, `src` doesn't need to be appended in the standard case where
 /// migrations are in their own crate. If the migrations are in a submodule
 /// of another crate, `migration_dir` can point directly to that module.
-fn get_full_migration_dir(migration_dir: &str) -> PathBuf {
+pub(crate) fn get_full_migration_dir(migration_dir: &str) -> PathBuf {
     let without_src = Path::new(migration_dir).to_owned();
     let with_src = without_src.join("src");
     match () {
@@ -208,7 +208,10 @@ fn get_migrator_filepath(migration_dir: &str) -> PathBuf {
     }
 }
 
-fn update_migrator(migration_name: &str, migration_dir: &str) -> Result<(), Box<dyn Error>> {
+pub(crate) fn update_migrator(
+    migration_name: &str,
+    migration_dir: &str,
+) -> Result<(), Box<dyn Error>> {
     let migrator_filepath = get_migrator_filepath(migration_dir);
     println!(
         "Adding migration `{}` to `{}`",
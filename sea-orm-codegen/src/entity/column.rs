@@ -119,76 +119,6 @@ impl Column {
     }
 
     pub fn get_def(&self) -> TokenStream {
-        fn write_col_def(col_type: &ColumnType) -> TokenStream {
-            match col_type {
-                ColumnType::Char(s) => match s {
-                    Some(s) => quote! { ColumnType::Char(Some(#s)) },
-                    None => quote! { ColumnType::Char(None) },
-                },
-                ColumnType::String(s) => match s {
-                    StringLen::N(s) => quote! { ColumnType::String(StringLen::N(#s)) },
-                    StringLen::None => quote! { ColumnType::String(StringLen::None) },
-                    StringLen::Max => quote! { ColumnType::String(StringLen::Max) },
-                },
-                ColumnType::Text => quote! { ColumnType::Text },
-                ColumnType::TinyInteger => quote! { ColumnType::TinyInteger },
-                ColumnType::SmallInteger => quote! { ColumnType::SmallInteger },
-                ColumnType::Integer => quote! { ColumnType::Integer },
-                ColumnType::BigInteger => quote! { ColumnType::BigInteger },
-                ColumnType::TinyUnsigned => quote! { ColumnType::TinyUnsigned },
-                ColumnType::SmallUnsigned => quote! { ColumnType::SmallUnsigned },
-                ColumnType::Unsigned => quote! { ColumnType::Unsigned },
-                ColumnType::BigUnsigned => quote! { ColumnType::BigUnsigned },
-                ColumnType::Float => quote! { ColumnType::Float },
-                ColumnType::Double => quote! { ColumnType::Double },
-                ColumnType::Decimal(s) => match s {
-                    Some((s1, s2)) => quote! { ColumnType::Decimal(Some((#s1, #s2))) },
-                    None => quote! { ColumnType::Decimal(None) },
-                },
-                ColumnType::DateTime => quote! { ColumnType::DateTime },
-                ColumnType::Timestamp => quote! { ColumnType::Timestamp },
-                ColumnType::TimestampWithTimeZone => {
-                    quote! { ColumnType::TimestampWithTimeZone }
-                }
-                ColumnType::Time => quote! { ColumnType::Time },
-                ColumnType::Date => quote! { ColumnType::Date },
-                ColumnType::Binary(s) => {
-                    quote! { ColumnType::Binary(#s) }
-                }
-                ColumnType::VarBinary(s) => match s {
-                    StringLen::N(s) => quote! { ColumnType::VarBinary(StringLen::N(#s)) },
-                    StringLen::None => quote! { ColumnType::VarBinary(StringLen::None) },
-                    StringLen::Max => quote! { ColumnType::VarBinary(StringLen::Max) },
-                },
-                ColumnType::Blob => quote! { ColumnType::Blob },
-                ColumnType::Boolean => quote! { ColumnType::Boolean },
-                ColumnType::Money(s) => match s {
-                    Some((s1, s2)) => quote! { ColumnType::Money(Some((#s1, #s2))) },
-                    None => quote! { ColumnType::Money(None) },
-                },
-                ColumnType::Json => quote! { ColumnType::Json },
-                ColumnType::JsonBinary => quote! { ColumnType::JsonBinary },
-                ColumnType::Uuid => quote! { ColumnType::Uuid },
-                ColumnType::Custom(s) => {
-                    let s = s.to_string();
-                    quote! { ColumnType::custom(#s) }
-                }
-                ColumnType::Enum { name, .. } => {
-                    let enum_ident = format_ident!("{}", name.to_string().to_upper_camel_case());
-                    quote! { #enum_ident::db_type() }
-                }
-                ColumnType::Array(column_type) => {
-                    let column_type = write_col_def(column_type);
-                    quote! { ColumnType::Array(RcOrArc::new(#column_type)) }
-                }
-                ColumnType::Vector(size) => match size {
-                    Some(size) => quote! { ColumnType::Vector(Some(#size)) },
-                    None => quote! { ColumnType::Vector(None) },
-                },
-                #[allow(unreachable_patterns)]
-                _ => unimplemented!(),
-            }
-        }
         let mut col_def = write_col_def(&self.col_type);
         col_def.extend(quote! {
             .def()
@@ -263,6 +193,77 @@ impl Column {
     }
 }
 
+pub(crate) fn write_col_def(col_type: &ColumnType) -> TokenStream {
+    match col_type {
+        ColumnType::Char(s) => match s {
+            Some(s) => quote! { ColumnType::Char(Some(#s)) },
+            None => quote! { ColumnType::Char(None) },
+        },
+        ColumnType::String(s) => match s {
+            StringLen::N(s) => quote! { ColumnType::String(StringLen::N(#s)) },
+            StringLen::None => quote! { ColumnType::String(StringLen::None) },
+            StringLen::Max => quote! { ColumnType::String(StringLen::Max) },
+        },
+        ColumnType::Text => quote! { ColumnType::Text },
+        ColumnType::TinyInteger => quote! { ColumnType::TinyInteger },
+        ColumnType::SmallInteger => quote! { ColumnType::SmallInteger },
+        ColumnType::Integer => quote! { ColumnType::Integer },
+        ColumnType::BigInteger => quote! { ColumnType::BigInteger },
+        ColumnType::TinyUnsigned => quote! { ColumnType::TinyUnsigned },
+        ColumnType::SmallUnsigned => quote! { ColumnType::SmallUnsigned },
+        ColumnType::Unsigned => quote! { ColumnType::Unsigned },
+        ColumnType::BigUnsigned => quote! { ColumnType::BigUnsigned },
+        ColumnType::Float => quote! { ColumnType::Float },
+        ColumnType::Double => quote! { ColumnType::Double },
+        ColumnType::Decimal(s) => match s {
+            Some((s1, s2)) => quote! { ColumnType::Decimal(Some((#s1, #s2))) },
+            None => quote! { ColumnType::Decimal(None) },
+        },
+        ColumnType::DateTime => quote! { ColumnType::DateTime },
+        ColumnType::Timestamp => quote! { ColumnType::Timestamp },
+        ColumnType::TimestampWithTimeZone => {
+            quote! { ColumnType::TimestampWithTimeZone }
+        }
+        ColumnType::Time => quote! { ColumnType::Time },
+        ColumnType::Date => quote! { ColumnType::Date },
+        ColumnType::Binary(s) => {
+            quote! { ColumnType::Binary(#s) }
+        }
+        ColumnType::VarBinary(s) => match s {
+            StringLen::N(s) => quote! { ColumnType::VarBinary(StringLen::N(#s)) },
+            StringLen::None => quote! { ColumnType::VarBinary(StringLen::None) },
+            StringLen::Max => quote! { ColumnType::VarBinary(StringLen::Max) },
+        },
+        ColumnType::Blob => quote! { ColumnType::Blob },
+        ColumnType::Boolean => quote! { ColumnType::Boolean },
+        ColumnType::Money(s) => match s {
+            Some((s1, s2)) => quote! { ColumnType::Money(Some((#s1, #s2))) },
+            None => quote! { ColumnType::Money(None) },
+        },
+        ColumnType::Json => quote! { ColumnType::Json },
+        ColumnType::JsonBinary => quote! { ColumnType::JsonBinary },
+        ColumnType::Uuid => quote! { ColumnType::Uuid },
+        ColumnType::Custom(s) => {
+            let s = s.to_string();
+            quote! { ColumnType::custom(#s) }
+        }
+        ColumnType::Enum { name, .. } => {
+            let enum_ident = format_ident!("{}", name.to_string().to_upper_camel_case());
+            quote! { #enum_ident::db_type() }
+        }
+        ColumnType::Array(column_type) => {
+            let column_type = write_col_def(column_type);
+            quote! { ColumnType::Array(RcOrArc::new(#column_type)) }
+        }
+        ColumnType::Vector(size) => match size {
+            Some(size) => quote! { ColumnType::Vector(Some(#size)) },
+            None => quote! { ColumnType::Vector(None) },
+        },
+        #[allow(unreachable_patterns)]
+        _ => unimplemented!(),
+    }
+}
+
 impl From<ColumnDef> for Column {
     fn from(col_def: ColumnDef) -> Self {
         (&col_def).into()
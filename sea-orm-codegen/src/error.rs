@@ -4,6 +4,7 @@ use std::{error, fmt, io};
 pub enum Error {
     StdIoError(io::Error),
     TransformError(String),
+    ImportError(String),
 }
 
 impl fmt::Display for Error {
@@ -11,6 +12,7 @@ impl fmt::Display for Error {
         match self {
             Self::StdIoError(e) => write!(f, "{e:?}"),
             Self::TransformError(e) => write!(f, "{e:?}"),
+            Self::ImportError(e) => write!(f, "{e:?}"),
         }
     }
 }
@@ -19,7 +21,7 @@ impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
             Self::StdIoError(e) => Some(e),
-            Self::TransformError(_) => None,
+            Self::TransformError(_) | Self::ImportError(_) => None,
         }
     }
 }
@@ -0,0 +1,470 @@
+use super::{column_type, ImportedSchema, SchemaColumn, SchemaForeignKey, SchemaTable};
+use crate::Error;
+use heck::ToSnakeCase;
+use quote::ToTokens;
+use sea_query::ColumnType;
+use std::collections::BTreeMap;
+use syn::{
+    braced, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, GenericArgument, Ident, Item, ItemUse, LitInt, LitStr, PathArguments, Token, Type,
+};
+
+impl ImportedSchema {
+    /// Import the tables declared with `diesel::table!` in a Diesel `schema.rs`, and the foreign
+    /// keys declared with `diesel::joinable!`. The schema does not tell the column defaults, nor
+    /// the auto-incremented columns: a single integer primary key is taken as auto-incremented.
+    pub fn from_diesel_schema(source: &str) -> Result<Self, Error> {
+        let file = syn::parse_file(source).map_err(import_error)?;
+        // The custom SQL types declared in `sql_types`, by their Rust name
+        let mut sql_types = BTreeMap::new();
+        let mut tables = Vec::new();
+        let mut joins = Vec::new();
+        for item in file.items {
+            match item {
+                Item::Mod(item) if item.ident == "sql_types" => {
+                    for item in item.content.map(|(_, items)| items).unwrap_or_default() {
+                        if let Item::Struct(item) = item {
+                            let name = sql_type_name(&item.attrs)?
+                                .unwrap_or_else(|| item.ident.to_string().to_snake_case());
+                            sql_types.insert(item.ident.to_string(), name);
+                        }
+                    }
+                }
+                Item::Macro(item) if is_macro(&item.mac.path, "table") => {
+                    let DieselTables(parsed) = item.mac.parse_body().map_err(import_error)?;
+                    tables.extend(parsed);
+                }
+                Item::Macro(item) if is_macro(&item.mac.path, "joinable") => {
+                    joins.push(item.mac.parse_body::<DieselJoin>().map_err(import_error)?);
+                }
+                _ => {}
+            }
+        }
+
+        let mut schema = Self::default();
+        for table in tables.iter() {
+            schema.tables.push(table.to_schema_table(&sql_types));
+        }
+        for join in joins {
+            let (Some(child), Some(parent)) = (
+                tables.iter().position(|table| table.ident == join.child),
+                tables.iter().find(|table| table.ident == join.parent),
+            ) else {
+                return Err(Error::ImportError(format!(
+                    "Unknown table in `joinable!({} -> {})`",
+                    join.child, join.parent
+                )));
+            };
+            let column = tables[child].column_name(&join.column);
+            schema.tables[child].foreign_keys.push(SchemaForeignKey {
+                name: None,
+                columns: vec![column],
+                ref_schema: parent.schema.clone(),
+                ref_table: parent.name(),
+                ref_columns: parent
+                    .primary_key
+                    .iter()
+                    .map(|column| parent.column_name(column))
+                    .collect(),
+                on_delete: None,
+                on_update: None,
+            });
+        }
+        Ok(schema)
+    }
+}
+
+fn import_error(err: syn::Error) -> Error {
+    Error::ImportError(format!("Failed to parse the Diesel schema: {err}"))
+}
+
+fn is_macro(path: &syn::Path, name: &str) -> bool {
+    path.segments
+        .last()
+        .map_or(false, |segment| segment.ident == name)
+}
+
+/// The value of an attribute like `#[sql_name = "..."]`
+fn attr_value<T: Parse>(attrs: &[Attribute], name: &str) -> syn::Result<Option<T>> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(name))
+        .map(|attr| syn::parse2(attr.meta.require_name_value()?.value.to_token_stream()))
+        .next()
+        .transpose()
+}
+
+/// The name in `#[diesel(postgres_type(name = "..."))]`
+fn sql_type_name(attrs: &[Attribute]) -> Result<Option<String>, Error> {
+    let mut name = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("diesel")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("postgres_type") {
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("name") {
+                        name = Some(meta.value()?.parse::<LitStr>()?.value());
+                    } else {
+                        // Skip `oid` and `array_oid`
+                        let _: syn::Expr = meta.value()?.parse()?;
+                    }
+                    Ok(())
+                })
+            } else {
+                // Skip `mysql_type(name = "Enum")` and `sqlite_type(..)`, their names are not
+                // specific to the type
+                meta.input.parse::<proc_macro2::TokenStream>().map(|_| ())
+            }
+        })
+        .map_err(import_error)?;
+    }
+    Ok(name)
+}
+
+struct DieselTables(Vec<DieselTable>);
+
+struct DieselTable {
+    schema: Option<String>,
+    ident: String,
+    sql_name: Option<String>,
+    primary_key: Vec<String>,
+    columns: Vec<DieselColumn>,
+}
+
+struct DieselColumn {
+    ident: String,
+    sql_name: Option<String>,
+    max_length: Option<u32>,
+    ty: Type,
+}
+
+struct DieselJoin {
+    child: String,
+    parent: String,
+    column: String,
+}
+
+fn unraw(ident: &Ident) -> String {
+    syn::ext::IdentExt::unraw(ident).to_string()
+}
+
+impl Parse for DieselTables {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut tables = Vec::new();
+        while !input.is_empty() {
+            if input.peek(Token![use]) {
+                input.parse::<ItemUse>()?;
+            } else {
+                tables.push(input.parse()?);
+            }
+        }
+        Ok(Self(tables))
+    }
+}
+
+impl Parse for DieselTable {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let mut schema = None;
+        let mut ident: Ident = input.parse()?;
+        if input.peek(Token![.]) {
+            input.parse::<Token![.]>()?;
+            schema = Some(unraw(&ident));
+            ident = input.parse()?;
+        }
+        let primary_key = if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            Punctuated::<Ident, Token![,]>::parse_terminated(&content)?
+                .iter()
+                .map(unraw)
+                .collect()
+        } else {
+            vec!["id".to_owned()]
+        };
+        let content;
+        braced!(content in input);
+        let columns = Punctuated::<DieselColumn, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+        Ok(Self {
+            schema,
+            ident: unraw(&ident),
+            sql_name: attr_value::<LitStr>(&attrs, "sql_name")?.map(|name| name.value()),
+            primary_key,
+            columns,
+        })
+    }
+}
+
+impl Parse for DieselColumn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let ident: Ident = input.parse()?;
+        input.parse::<Token![->]>()?;
+        Ok(Self {
+            ident: unraw(&ident),
+            sql_name: attr_value::<LitStr>(&attrs, "sql_name")?.map(|name| name.value()),
+            max_length: attr_value::<LitInt>(&attrs, "max_length")?
+                .map(|length| length.base10_parse())
+                .transpose()?,
+            ty: input.parse()?,
+        })
+    }
+}
+
+impl Parse for DieselJoin {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let child: Ident = input.parse()?;
+        input.parse::<Token![->]>()?;
+        let parent: Ident = input.parse()?;
+        let content;
+        parenthesized!(content in input);
+        let column: Ident = content.parse()?;
+        Ok(Self {
+            child: unraw(&child),
+            parent: unraw(&parent),
+            column: unraw(&column),
+        })
+    }
+}
+
+impl DieselTable {
+    fn name(&self) -> String {
+        self.sql_name.clone().unwrap_or_else(|| self.ident.clone())
+    }
+
+    fn column_name(&self, ident: &str) -> String {
+        self.columns
+            .iter()
+            .find(|column| column.ident == ident)
+            .and_then(|column| column.sql_name.clone())
+            .unwrap_or_else(|| ident.to_owned())
+    }
+
+    fn to_schema_table(&self, sql_types: &BTreeMap<String, String>) -> SchemaTable {
+        let mut table = SchemaTable::new(self.schema.clone(), self.name());
+        for column in self.columns.iter() {
+            let (col_type, not_null) = diesel_type(&column.ty, column.max_length, sql_types);
+            table.columns.push(SchemaColumn {
+                name: self.column_name(&column.ident),
+                col_type,
+                not_null,
+                auto_increment: false,
+                unique: false,
+                default: None,
+            });
+        }
+        table.set_primary_key(
+            self.primary_key
+                .iter()
+                .map(|column| self.column_name(column))
+                .collect(),
+        );
+        if let [column] = table.primary_key.as_slice() {
+            let column = table.columns.iter_mut().find(|col| &col.name == column);
+            if let Some(column) = column {
+                column.auto_increment = matches!(
+                    column.col_type,
+                    ColumnType::SmallInteger | ColumnType::Integer | ColumnType::BigInteger
+                );
+            }
+        }
+        table
+    }
+}
+
+/// The column type of a Diesel SQL type, and whether it is not null
+fn diesel_type(
+    ty: &Type,
+    max_length: Option<u32>,
+    sql_types: &BTreeMap<String, String>,
+) -> (ColumnType, bool) {
+    let Type::Path(path) = ty else {
+        return (ColumnType::custom(ty.to_token_stream().to_string()), true);
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return (ColumnType::custom(ty.to_token_stream().to_string()), true);
+    };
+    let ident = segment.ident.to_string();
+    let inner = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    };
+    match (ident.as_str(), inner) {
+        ("Nullable", Some(inner)) => (diesel_type(inner, max_length, sql_types).0, false),
+        ("Array", Some(inner)) => {
+            let (col_type, _) = diesel_type(inner, max_length, sql_types);
+            (ColumnType::Array(sea_query::RcOrArc::new(col_type)), true)
+        }
+        ("Unsigned", Some(inner)) => {
+            let name = match diesel_type(inner, None, sql_types).0 {
+                ColumnType::TinyInteger => "tinyint unsigned",
+                ColumnType::SmallInteger => "smallint unsigned",
+                ColumnType::BigInteger => "bigint unsigned",
+                _ => "integer unsigned",
+            };
+            (column_type(name, &[], &BTreeMap::new()).0, true)
+        }
+        _ => {
+            let name = sql_types
+                .get(&ident)
+                .cloned()
+                .unwrap_or_else(|| ident.to_lowercase());
+            let args: Vec<u32> = max_length.into_iter().collect();
+            (column_type(&name, &args, &BTreeMap::new()).0, true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use sea_query::{ForeignKeyAction, StringLen};
+
+    const SCHEMA: &str = r#"
+// @generated automatically by Diesel CLI.
+
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "mood"))]
+    pub struct Mood;
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Mood;
+
+    /// Representation of the `users` table.
+    users (id) {
+        id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        mood -> Nullable<Mood>,
+        tags -> Array<Nullable<Text>>,
+    }
+}
+
+diesel::table! {
+    #[sql_name = "blog_posts"]
+    posts {
+        id -> Int8,
+        user_id -> Int4,
+        #[sql_name = "type"]
+        r#type -> Text,
+        published_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    post_tags (post_id, tag) {
+        post_id -> Int8,
+        tag -> Text,
+    }
+}
+
+diesel::joinable!(posts -> users (user_id));
+diesel::joinable!(post_tags -> posts (post_id));
+
+diesel::allow_tables_to_appear_in_same_query!(post_tags, posts, users,);
+"#;
+
+    fn column(name: &str, col_type: ColumnType, not_null: bool) -> SchemaColumn {
+        SchemaColumn {
+            name: name.to_owned(),
+            col_type,
+            not_null,
+            auto_increment: false,
+            unique: false,
+            default: None,
+        }
+    }
+
+    fn foreign_key(column: &str, ref_table: &str) -> SchemaForeignKey {
+        SchemaForeignKey {
+            name: None,
+            columns: vec![column.to_owned()],
+            ref_schema: None,
+            ref_table: ref_table.to_owned(),
+            ref_columns: vec!["id".to_owned()],
+            on_delete: None::<ForeignKeyAction>,
+            on_update: None,
+        }
+    }
+
+    #[test]
+    fn import_diesel_schema() -> Result<(), Error> {
+        let schema = ImportedSchema::from_diesel_schema(SCHEMA)?;
+
+        assert_eq!(
+            schema.tables,
+            [
+                SchemaTable {
+                    schema: None,
+                    name: "users".to_owned(),
+                    columns: vec![
+                        SchemaColumn {
+                            auto_increment: true,
+                            ..column("id", ColumnType::Integer, true)
+                        },
+                        column("name", ColumnType::String(StringLen::N(255)), true),
+                        column("mood", ColumnType::custom("mood"), false),
+                        column(
+                            "tags",
+                            ColumnType::Array(sea_query::RcOrArc::new(ColumnType::Text)),
+                            true
+                        ),
+                    ],
+                    primary_key: vec!["id".to_owned()],
+                    indexes: vec![],
+                    foreign_keys: vec![],
+                },
+                SchemaTable {
+                    schema: None,
+                    name: "blog_posts".to_owned(),
+                    columns: vec![
+                        SchemaColumn {
+                            auto_increment: true,
+                            ..column("id", ColumnType::BigInteger, true)
+                        },
+                        column("user_id", ColumnType::Integer, true),
+                        column("type", ColumnType::Text, true),
+                        column("published_at", ColumnType::TimestampWithTimeZone, false),
+                    ],
+                    primary_key: vec!["id".to_owned()],
+                    indexes: vec![],
+                    foreign_keys: vec![foreign_key("user_id", "users")],
+                },
+                SchemaTable {
+                    schema: None,
+                    name: "post_tags".to_owned(),
+                    columns: vec![
+                        column("post_id", ColumnType::BigInteger, true),
+                        column("tag", ColumnType::Text, true),
+                    ],
+                    primary_key: vec!["post_id".to_owned(), "tag".to_owned()],
+                    indexes: vec![],
+                    foreign_keys: vec![foreign_key("post_id", "blog_posts")],
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_joinable_table() {
+        let err =
+            ImportedSchema::from_diesel_schema("diesel::joinable!(posts -> users (user_id));")
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#""Unknown table in `joinable!(posts -> users)`""#
+        );
+    }
+}
@@ -0,0 +1,518 @@
+use super::{ImportedSchema, SchemaTable};
+use crate::{util::escape_rust_keyword, write_col_def, EntityWriter, OutputFile};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use sea_query::{ColumnType, ForeignKeyAction, StringLen};
+
+impl ImportedSchema {
+    /// Write a baseline migration of `sea-orm-migration` creating the imported schema, in a file
+    /// named after the migration, e.g. `m20220101_000001_baseline.rs`
+    pub fn write_baseline_migration(&self, migration_name: &str) -> OutputFile {
+        let tables = self.sorted_tables();
+        let idens: Vec<TableIdens> = tables.iter().map(|table| TableIdens::new(table)).collect();
+
+        let create_types = self.enums.iter().map(|(name, variants)| {
+            quote! {
+                manager
+                    .create_type(
+                        extension::postgres::Type::create()
+                            .as_enum(Alias::new(#name))
+                            .values([#(Alias::new(#variants)),*])
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        });
+        let create_tables = tables
+            .iter()
+            .map(|table| self.gen_create_table(table, &idens));
+        let create_indexes = tables.iter().flat_map(|table| {
+            let idens = TableIdens::find(&idens, &table.name);
+            let table_ref = gen_table_ref(table.schema.as_deref(), &idens.table());
+            table.indexes.iter().map(move |index| {
+                let name = index
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("idx-{}-{}", table.name, index.columns.join("-")));
+                let columns = index.columns.iter().map(|column| idens.column(column));
+                let unique = index.unique.then(|| quote! { .unique() });
+                quote! {
+                    manager
+                        .create_index(
+                            Index::create()
+                                .name(#name)
+                                .table(#table_ref)
+                                #(.col(#columns))*
+                                #unique
+                                .to_owned(),
+                        )
+                        .await?;
+                }
+            })
+        });
+        let drop_tables = tables.iter().rev().map(|table| {
+            let idens = TableIdens::find(&idens, &table.name);
+            let table_ref = gen_table_ref(table.schema.as_deref(), &idens.table());
+            quote! {
+                manager
+                    .drop_table(Table::drop().table(#table_ref).to_owned())
+                    .await?;
+            }
+        });
+        let drop_types = self.enums.keys().map(|name| {
+            quote! {
+                manager
+                    .drop_type(extension::postgres::Type::drop().name(Alias::new(#name)).to_owned())
+                    .await?;
+            }
+        });
+        let iden_enums = idens.iter().map(TableIdens::gen_enum);
+
+        let code_blocks = vec![
+            quote! {
+                use sea_orm_migration::prelude::*;
+            },
+            quote! {
+                #[derive(DeriveMigrationName)]
+                pub struct Migration;
+            },
+            quote! {
+                #[async_trait::async_trait]
+                impl MigrationTrait for Migration {
+                    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+                        #(#create_types)*
+                        #(#create_tables)*
+                        #(#create_indexes)*
+                        Ok(())
+                    }
+
+                    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+                        #(#drop_tables)*
+                        #(#drop_types)*
+                        Ok(())
+                    }
+                }
+            },
+        ]
+        .into_iter()
+        .chain(iden_enums)
+        .collect();
+
+        let mut lines = Vec::new();
+        EntityWriter::write(&mut lines, code_blocks);
+        OutputFile {
+            name: format!("{migration_name}.rs"),
+            content: lines.join("\n\n"),
+        }
+    }
+
+    fn gen_create_table(&self, table: &SchemaTable, idens: &[TableIdens]) -> TokenStream {
+        let table_idens = TableIdens::find(idens, &table.name);
+        let table_ref = gen_table_ref(table.schema.as_deref(), &table_idens.table());
+        let columns = table.columns.iter().map(|column| {
+            let iden = table_idens.column(&column.name);
+            let col_type = gen_col_type(&column.col_type);
+            let not_null = column.not_null.then(|| quote! { .not_null() });
+            let auto_increment = column.auto_increment.then(|| quote! { .auto_increment() });
+            let primary_key =
+                (table.primary_key == [column.name.as_str()]).then(|| quote! { .primary_key() });
+            let unique = column.unique.then(|| quote! { .unique_key() });
+            let default = column
+                .default
+                .as_ref()
+                .map(|default| quote! { .default(Expr::cust(#default)) });
+            quote! {
+                .col(
+                    ColumnDef::new(#iden)
+                        .#col_type
+                        #not_null
+                        #auto_increment
+                        #primary_key
+                        #unique
+                        #default
+                )
+            }
+        });
+        let primary_key = (table.primary_key.len() > 1).then(|| {
+            let columns = table
+                .primary_key
+                .iter()
+                .map(|column| table_idens.column(column));
+            quote! {
+                .primary_key(Index::create()#(.col(#columns))*)
+            }
+        });
+        let foreign_keys = table.foreign_keys.iter().map(|fk| {
+            let name = fk.name.clone().unwrap_or_else(|| {
+                format!(
+                    "fk-{}-{}-{}",
+                    table.name,
+                    fk.columns.join("-"),
+                    fk.ref_table
+                )
+            });
+            let columns = fk.columns.iter().map(|column| table_idens.column(column));
+            let (ref_table, ref_columns): (TokenStream, Vec<TokenStream>) =
+                match idens.iter().find(|idens| idens.name == fk.ref_table) {
+                    Some(ref_idens) => (
+                        gen_table_ref(fk.ref_schema.as_deref(), &ref_idens.table()),
+                        fk.ref_columns
+                            .iter()
+                            .map(|column| ref_idens.column(column))
+                            .collect(),
+                    ),
+                    None => (
+                        gen_table_ref(fk.ref_schema.as_deref(), &gen_alias(&fk.ref_table)),
+                        fk.ref_columns
+                            .iter()
+                            .map(|column| gen_alias(column))
+                            .collect(),
+                    ),
+                };
+            let on_delete = fk.on_delete.as_ref().map(|action| {
+                let action = gen_foreign_key_action(action);
+                quote! { .on_delete(ForeignKeyAction::#action) }
+            });
+            let on_update = fk.on_update.as_ref().map(|action| {
+                let action = gen_foreign_key_action(action);
+                quote! { .on_update(ForeignKeyAction::#action) }
+            });
+            quote! {
+                .foreign_key(
+                    ForeignKey::create()
+                        .name(#name)
+                        #(.from_col(#columns))*
+                        .to_tbl(#ref_table)
+                        #(.to_col(#ref_columns))*
+                        #on_delete
+                        #on_update
+                )
+            }
+        });
+        quote! {
+            manager
+                .create_table(
+                    Table::create()
+                        .table(#table_ref)
+                        .if_not_exists()
+                        #(#columns)*
+                        #primary_key
+                        #(#foreign_keys)*
+                        .to_owned(),
+                )
+                .await?;
+        }
+    }
+}
+
+/// The `DeriveIden` enum of a table, with a variant for the table and for each column
+struct TableIdens {
+    name: String,
+    ident: Ident,
+    variants: Vec<(String, Ident)>,
+}
+
+impl TableIdens {
+    fn new(table: &SchemaTable) -> Self {
+        let variants = table
+            .columns
+            .iter()
+            .map(|column| {
+                let mut variant = column.name.to_upper_camel_case();
+                if variant == "Table" {
+                    variant = "TableColumn".to_owned();
+                }
+                (
+                    column.name.clone(),
+                    format_ident!("{}", escape_rust_keyword(variant)),
+                )
+            })
+            .collect();
+        Self {
+            name: table.name.clone(),
+            ident: format_ident!("{}", escape_rust_keyword(table.name.to_upper_camel_case())),
+            variants,
+        }
+    }
+
+    fn find<'a>(idens: &'a [Self], name: &str) -> &'a Self {
+        idens
+            .iter()
+            .find(|idens| idens.name == name)
+            .expect("Every table has its idens")
+    }
+
+    fn table(&self) -> TokenStream {
+        let ident = &self.ident;
+        quote! { #ident::Table }
+    }
+
+    /// The variant of the column, or an alias if the column does not exist
+    fn column(&self, name: &str) -> TokenStream {
+        let ident = &self.ident;
+        match self.variants.iter().find(|(column, _)| column == name) {
+            Some((_, variant)) => quote! { #ident::#variant },
+            None => gen_alias(name),
+        }
+    }
+
+    fn gen_enum(&self) -> TokenStream {
+        let ident = &self.ident;
+        let table_attr = gen_iden_attr(&ident.to_string(), &self.name);
+        let variants = self.variants.iter().map(|(name, variant)| {
+            let attr = gen_iden_attr(&variant.to_string(), name);
+            quote! { #attr #variant }
+        });
+        quote! {
+            #[derive(DeriveIden)]
+            enum #ident {
+                #table_attr
+                Table,
+                #(#variants,)*
+            }
+        }
+    }
+}
+
+/// `#[sea_orm(iden = "..")]` if the name is not the one `DeriveIden` derives from the ident
+fn gen_iden_attr(ident: &str, name: &str) -> Option<TokenStream> {
+    (ident.trim_start_matches("r#").to_snake_case() != name)
+        .then(|| quote! { #[sea_orm(iden = #name)] })
+}
+
+fn gen_alias(name: &str) -> TokenStream {
+    quote! { Alias::new(#name) }
+}
+
+fn gen_table_ref(schema: Option<&str>, table: &TokenStream) -> TokenStream {
+    match schema {
+        Some(schema) => quote! { (Alias::new(#schema), #table) },
+        None => table.clone(),
+    }
+}
+
+fn gen_foreign_key_action(action: &ForeignKeyAction) -> Ident {
+    format_ident!(
+        "{}",
+        match action {
+            ForeignKeyAction::Restrict => "Restrict",
+            ForeignKeyAction::Cascade => "Cascade",
+            ForeignKeyAction::SetNull => "SetNull",
+            ForeignKeyAction::NoAction => "NoAction",
+            ForeignKeyAction::SetDefault => "SetDefault",
+        }
+    )
+}
+
+/// The `ColumnDef` method setting the column type
+fn gen_col_type(col_type: &ColumnType) -> TokenStream {
+    fn option(value: &Option<u32>) -> TokenStream {
+        match value {
+            Some(value) => quote! { Some(#value) },
+            None => quote! { None },
+        }
+    }
+    match col_type {
+        ColumnType::Char(Some(length)) => quote! { char_len(#length) },
+        ColumnType::Char(None) => quote! { char() },
+        ColumnType::String(StringLen::N(length)) => quote! { string_len(#length) },
+        ColumnType::String(_) => quote! { string() },
+        ColumnType::Text => quote! { text() },
+        ColumnType::Blob => quote! { blob() },
+        ColumnType::TinyInteger => quote! { tiny_integer() },
+        ColumnType::SmallInteger => quote! { small_integer() },
+        ColumnType::Integer => quote! { integer() },
+        ColumnType::BigInteger => quote! { big_integer() },
+        ColumnType::TinyUnsigned => quote! { tiny_unsigned() },
+        ColumnType::SmallUnsigned => quote! { small_unsigned() },
+        ColumnType::Unsigned => quote! { unsigned() },
+        ColumnType::BigUnsigned => quote! { big_unsigned() },
+        ColumnType::Float => quote! { float() },
+        ColumnType::Double => quote! { double() },
+        ColumnType::Decimal(Some((precision, scale))) => {
+            quote! { decimal_len(#precision, #scale) }
+        }
+        ColumnType::Decimal(None) => quote! { decimal() },
+        ColumnType::DateTime => quote! { date_time() },
+        ColumnType::Timestamp => quote! { timestamp() },
+        ColumnType::TimestampWithTimeZone => quote! { timestamp_with_time_zone() },
+        ColumnType::Time => quote! { time() },
+        ColumnType::Date => quote! { date() },
+        ColumnType::Year => quote! { year() },
+        ColumnType::Interval(_, _) => quote! { interval(None, None) },
+        ColumnType::Binary(length) => quote! { binary_len(#length) },
+        ColumnType::VarBinary(StringLen::N(length)) => quote! { var_binary(#length) },
+        ColumnType::VarBinary(_) => quote! { binary() },
+        ColumnType::Bit(length) => {
+            let length = option(length);
+            quote! { bit(#length) }
+        }
+        ColumnType::VarBit(length) => quote! { varbit(#length) },
+        ColumnType::Boolean => quote! { boolean() },
+        ColumnType::Money(Some((precision, scale))) => quote! { money_len(#precision, #scale) },
+        ColumnType::Money(None) => quote! { money() },
+        ColumnType::Json => quote! { json() },
+        ColumnType::JsonBinary => quote! { json_binary() },
+        ColumnType::Uuid => quote! { uuid() },
+        ColumnType::Custom(name) => {
+            let name = name.to_string();
+            quote! { custom(Alias::new(#name)) }
+        }
+        ColumnType::Enum { name, variants } => {
+            let name = name.to_string();
+            let variants = variants.iter().map(|variant| variant.to_string());
+            quote! { enumeration(Alias::new(#name), [#(Alias::new(#variants)),*]) }
+        }
+        ColumnType::Array(col_type) => {
+            let col_type = write_col_def(col_type);
+            quote! { array(#col_type) }
+        }
+        ColumnType::Vector(size) => {
+            let size = option(size);
+            quote! { vector(#size) }
+        }
+        ColumnType::Cidr => quote! { cidr() },
+        ColumnType::Inet => quote! { inet() },
+        ColumnType::MacAddr => quote! { mac_address() },
+        ColumnType::LTree => quote! { ltree() },
+        #[allow(unreachable_patterns)]
+        _ => unimplemented!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn write_baseline_migration() -> Result<(), crate::Error> {
+        let schema = ImportedSchema::from_sql_migrations([r#"
+CREATE TYPE mood AS ENUM ('sad', 'happy');
+
+CREATE TABLE post_tags (
+    post_id INTEGER NOT NULL REFERENCES "Posts" (id) ON DELETE CASCADE,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (post_id, tag)
+);
+
+CREATE TABLE "Posts" (
+    id SERIAL PRIMARY KEY,
+    type VARCHAR(20) NOT NULL DEFAULT 'text',
+    mood mood,
+    tags TEXT[]
+);
+
+CREATE INDEX post_tags_tag ON post_tags (tag);
+"#])?;
+
+        let output = schema.write_baseline_migration("m20220101_000001_baseline");
+
+        assert_eq!(output.name, "m20220101_000001_baseline.rs");
+        assert_eq!(
+            output.content,
+            [
+                quote! {
+                    use sea_orm_migration::prelude::*;
+                },
+                quote! {
+                    #[derive(DeriveMigrationName)]
+                    pub struct Migration;
+                },
+                quote! {
+                    #[async_trait::async_trait]
+                    impl MigrationTrait for Migration {
+                        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+                            manager
+                                .create_type(
+                                    extension::postgres::Type::create()
+                                        .as_enum(Alias::new("mood"))
+                                        .values([Alias::new("sad"), Alias::new("happy")])
+                                        .to_owned(),
+                                )
+                                .await?;
+                            manager
+                                .create_table(
+                                    Table::create()
+                                        .table(Posts::Table)
+                                        .if_not_exists()
+                                        .col(ColumnDef::new(Posts::Id).integer().not_null().auto_increment().primary_key())
+                                        .col(ColumnDef::new(Posts::Type).string_len(20u32).not_null().default(Expr::cust("'text'")))
+                                        .col(ColumnDef::new(Posts::Mood).enumeration(Alias::new("mood"), [Alias::new("sad"), Alias::new("happy")]))
+                                        .col(ColumnDef::new(Posts::Tags).array(ColumnType::Text))
+                                        .to_owned(),
+                                )
+                                .await?;
+                            manager
+                                .create_table(
+                                    Table::create()
+                                        .table(PostTags::Table)
+                                        .if_not_exists()
+                                        .col(ColumnDef::new(PostTags::PostId).integer().not_null())
+                                        .col(ColumnDef::new(PostTags::Tag).text().not_null())
+                                        .primary_key(Index::create().col(PostTags::PostId).col(PostTags::Tag))
+                                        .foreign_key(
+                                            ForeignKey::create()
+                                                .name("fk-post_tags-post_id-Posts")
+                                                .from_col(PostTags::PostId)
+                                                .to_tbl(Posts::Table)
+                                                .to_col(Posts::Id)
+                                                .on_delete(ForeignKeyAction::Cascade)
+                                        )
+                                        .to_owned(),
+                                )
+                                .await?;
+                            manager
+                                .create_index(
+                                    Index::create()
+                                        .name("post_tags_tag")
+                                        .table(PostTags::Table)
+                                        .col(PostTags::Tag)
+                                        .to_owned(),
+                                )
+                                .await?;
+                            Ok(())
+                        }
+
+                        async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+                            manager
+                                .drop_table(Table::drop().table(PostTags::Table).to_owned())
+                                .await?;
+                            manager
+                                .drop_table(Table::drop().table(Posts::Table).to_owned())
+                                .await?;
+                            manager
+                                .drop_type(extension::postgres::Type::drop().name(Alias::new("mood")).to_owned())
+                                .await?;
+                            Ok(())
+                        }
+                    }
+                },
+                quote! {
+                    #[derive(DeriveIden)]
+                    enum Posts {
+                        #[sea_orm(iden = "Posts")]
+                        Table,
+                        Id,
+                        Type,
+                        Mood,
+                        Tags,
+                    }
+                },
+                quote! {
+                    #[derive(DeriveIden)]
+                    enum PostTags {
+                        Table,
+                        PostId,
+                        Tag,
+                    }
+                },
+            ]
+            .map(|code_block| code_block.to_string())
+            .join("\n\n")
+        );
+
+        Ok(())
+    }
+}
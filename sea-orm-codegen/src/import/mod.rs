@@ -0,0 +1,378 @@
+mod diesel;
+mod migration;
+mod sql;
+
+use crate::Error;
+use sea_query::{
+    Alias, ColumnDef, ColumnType, Expr, ForeignKey, ForeignKeyAction, Index, IntoIden,
+    IntoTableRef, StringLen, Table, TableCreateStatement, TableRef,
+};
+use std::collections::BTreeMap;
+
+/// The schema of an existing project, imported from a Diesel `schema.rs` or from its plain SQL
+/// migrations, to generate the entities (with [EntityTransformer](crate::EntityTransformer))
+/// and a baseline migration from.
+#[derive(Clone, Debug, Default)]
+pub struct ImportedSchema {
+    pub(crate) tables: Vec<SchemaTable>,
+    pub(crate) enums: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SchemaTable {
+    pub(crate) schema: Option<String>,
+    pub(crate) name: String,
+    pub(crate) columns: Vec<SchemaColumn>,
+    pub(crate) primary_key: Vec<String>,
+    pub(crate) indexes: Vec<SchemaIndex>,
+    pub(crate) foreign_keys: Vec<SchemaForeignKey>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SchemaColumn {
+    pub(crate) name: String,
+    pub(crate) col_type: ColumnType,
+    pub(crate) not_null: bool,
+    pub(crate) auto_increment: bool,
+    pub(crate) unique: bool,
+    pub(crate) default: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SchemaIndex {
+    pub(crate) name: Option<String>,
+    pub(crate) columns: Vec<String>,
+    pub(crate) unique: bool,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct SchemaForeignKey {
+    pub(crate) name: Option<String>,
+    pub(crate) columns: Vec<String>,
+    pub(crate) ref_schema: Option<String>,
+    pub(crate) ref_table: String,
+    pub(crate) ref_columns: Vec<String>,
+    pub(crate) on_delete: Option<ForeignKeyAction>,
+    pub(crate) on_update: Option<ForeignKeyAction>,
+}
+
+impl PartialEq for SchemaForeignKey {
+    fn eq(&self, other: &Self) -> bool {
+        let action_eq = |a: &Option<ForeignKeyAction>, b: &Option<ForeignKeyAction>| {
+            a.as_ref().map(std::mem::discriminant) == b.as_ref().map(std::mem::discriminant)
+        };
+        self.name == other.name
+            && self.columns == other.columns
+            && self.ref_schema == other.ref_schema
+            && self.ref_table == other.ref_table
+            && self.ref_columns == other.ref_columns
+            && action_eq(&self.on_delete, &other.on_delete)
+            && action_eq(&self.on_update, &other.on_update)
+    }
+}
+
+impl ImportedSchema {
+    /// The `CREATE TABLE` statements of the imported tables, ordered so that every table comes
+    /// after the tables it references (unless they reference each other)
+    pub fn table_create_stmts(&self) -> Vec<TableCreateStatement> {
+        self.sorted_tables()
+            .into_iter()
+            .map(SchemaTable::create_stmt)
+            .collect()
+    }
+
+    /// Keep only the tables whose name satisfies the predicate
+    pub fn retain_tables<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str) -> bool,
+    {
+        self.tables.retain(|table| f(&table.name));
+    }
+
+    pub(crate) fn sorted_tables(&self) -> Vec<&SchemaTable> {
+        let mut sorted: Vec<&SchemaTable> = Vec::with_capacity(self.tables.len());
+        let mut pending: Vec<&SchemaTable> = self.tables.iter().collect();
+        while !pending.is_empty() {
+            let ready = pending.iter().position(|table| {
+                table.foreign_keys.iter().all(|fk| {
+                    fk.ref_table == table.name
+                        || !pending.iter().any(|other| other.name == fk.ref_table)
+                })
+            });
+            // The remaining tables reference each other, keep them in their original order
+            sorted.push(pending.remove(ready.unwrap_or(0)));
+        }
+        sorted
+    }
+
+    pub(crate) fn table_mut(&mut self, name: &str) -> Result<&mut SchemaTable, Error> {
+        self.tables
+            .iter_mut()
+            .find(|table| table.name == name)
+            .ok_or_else(|| Error::ImportError(format!("Table `{name}` does not exist")))
+    }
+}
+
+impl SchemaTable {
+    pub(crate) fn new(schema: Option<String>, name: String) -> Self {
+        Self {
+            schema,
+            name,
+            columns: Vec::new(),
+            primary_key: Vec::new(),
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+        }
+    }
+
+    pub(crate) fn column_mut(&mut self, name: &str) -> Result<&mut SchemaColumn, Error> {
+        let table = &self.name;
+        self.columns
+            .iter_mut()
+            .find(|column| column.name == name)
+            .ok_or_else(|| {
+                Error::ImportError(format!("Column `{name}` does not exist in table `{table}`"))
+            })
+    }
+
+    /// Mark the columns as the primary key, which are never null
+    pub(crate) fn set_primary_key(&mut self, columns: Vec<String>) {
+        for column in self.columns.iter_mut() {
+            if columns.contains(&column.name) {
+                column.not_null = true;
+            }
+        }
+        self.primary_key = columns;
+    }
+
+    /// Add a unique constraint, on the column itself if it is a single column one
+    pub(crate) fn add_unique(&mut self, name: Option<String>, columns: Vec<String>) {
+        match self
+            .columns
+            .iter_mut()
+            .find(|column| columns.len() == 1 && column.name == columns[0])
+        {
+            Some(column) => column.unique = true,
+            None => self.indexes.push(SchemaIndex {
+                name,
+                columns,
+                unique: true,
+            }),
+        }
+    }
+
+    pub(crate) fn table_ref(&self) -> TableRef {
+        table_ref(&self.schema, &self.name)
+    }
+
+    fn create_stmt(&self) -> TableCreateStatement {
+        let mut stmt = Table::create();
+        stmt.table(self.table_ref());
+        for column in self.columns.iter() {
+            let mut def =
+                ColumnDef::new_with_type(Alias::new(&column.name), column.col_type.clone());
+            if column.not_null {
+                def.not_null();
+            }
+            if column.auto_increment {
+                def.auto_increment();
+            }
+            if self.primary_key == [column.name.as_str()] {
+                def.primary_key();
+            }
+            if column.unique {
+                def.unique_key();
+            }
+            if let Some(default) = &column.default {
+                def.default(Expr::cust(default));
+            }
+            stmt.col(&mut def);
+        }
+        if self.primary_key.len() > 1 {
+            let mut index = Index::create();
+            for column in self.primary_key.iter() {
+                index.col(Alias::new(column));
+            }
+            stmt.primary_key(&mut index);
+        }
+        for schema_index in self.indexes.iter().filter(|index| index.unique) {
+            let mut index = Index::create();
+            index.unique();
+            if let Some(name) = &schema_index.name {
+                index.name(name);
+            }
+            for column in schema_index.columns.iter() {
+                index.col(Alias::new(column));
+            }
+            stmt.index(&mut index);
+        }
+        for schema_fk in self.foreign_keys.iter() {
+            let mut fk = ForeignKey::create();
+            if let Some(name) = &schema_fk.name {
+                fk.name(name);
+            }
+            fk.to_tbl(table_ref(&schema_fk.ref_schema, &schema_fk.ref_table));
+            for column in schema_fk.columns.iter() {
+                fk.from_col(Alias::new(column));
+            }
+            for column in schema_fk.ref_columns.iter() {
+                fk.to_col(Alias::new(column));
+            }
+            if let Some(action) = schema_fk.on_delete {
+                fk.on_delete(action);
+            }
+            if let Some(action) = schema_fk.on_update {
+                fk.on_update(action);
+            }
+            stmt.foreign_key(&mut fk);
+        }
+        stmt
+    }
+}
+
+fn table_ref(schema: &Option<String>, name: &str) -> TableRef {
+    match schema {
+        Some(schema) => (Alias::new(schema), Alias::new(name)).into_table_ref(),
+        None => Alias::new(name).into_table_ref(),
+    }
+}
+
+/// Map a SQL type name, lower case and without its arguments, e.g. `character varying` or
+/// `int unsigned`, to a column type and whether it is auto-incremented (the `serial` types).
+/// Unknown types are taken as the enums of the schema, or as custom types.
+pub(crate) fn column_type(
+    name: &str,
+    args: &[u32],
+    enums: &BTreeMap<String, Vec<String>>,
+) -> (ColumnType, bool) {
+    let arg = args.first().copied();
+    let precision = match args {
+        [precision, scale, ..] => Some((*precision, *scale)),
+        [precision] => Some((*precision, 0)),
+        [] => None,
+    };
+    let col_type = match name {
+        "smallserial" | "serial2" => return (ColumnType::SmallInteger, true),
+        "serial" | "serial4" => return (ColumnType::Integer, true),
+        "bigserial" | "serial8" => return (ColumnType::BigInteger, true),
+        "tinyint" => ColumnType::TinyInteger,
+        "smallint" | "int2" => ColumnType::SmallInteger,
+        "integer" | "int" | "int4" | "mediumint" => ColumnType::Integer,
+        "bigint" | "int8" => ColumnType::BigInteger,
+        "tinyint unsigned" => ColumnType::TinyUnsigned,
+        "smallint unsigned" => ColumnType::SmallUnsigned,
+        "integer unsigned" | "int unsigned" | "mediumint unsigned" | "oid" => ColumnType::Unsigned,
+        "bigint unsigned" => ColumnType::BigUnsigned,
+        "real" | "float" | "float4" => ColumnType::Float,
+        "double" | "double precision" | "float8" => ColumnType::Double,
+        "numeric" | "decimal" | "dec" => ColumnType::Decimal(precision),
+        "money" => ColumnType::Money(None),
+        "char" | "character" | "bpchar" | "nchar" => ColumnType::Char(arg),
+        "varchar" | "character varying" | "char varying" | "nvarchar" => {
+            ColumnType::String(arg.map(StringLen::N).unwrap_or(StringLen::None))
+        }
+        "text" | "tinytext" | "mediumtext" | "longtext" | "clob" | "citext" => ColumnType::Text,
+        "bool" | "boolean" => ColumnType::Boolean,
+        "bit" => ColumnType::Bit(arg),
+        "varbit" | "bit varying" => ColumnType::VarBit(arg.unwrap_or(1)),
+        "date" => ColumnType::Date,
+        "time" | "timetz" | "time without time zone" | "time with time zone" => ColumnType::Time,
+        "timestamp" | "timestamp without time zone" | "datetime" => ColumnType::DateTime,
+        "timestamptz" | "timestamp with time zone" | "timestamptzsqlite" => {
+            ColumnType::TimestampWithTimeZone
+        }
+        "year" => ColumnType::Year,
+        "interval" => ColumnType::Interval(None, None),
+        "uuid" => ColumnType::Uuid,
+        "json" => ColumnType::Json,
+        "jsonb" => ColumnType::JsonBinary,
+        "bytea" => ColumnType::VarBinary(StringLen::None),
+        "binary" => match arg {
+            Some(length) => ColumnType::Binary(length),
+            None => ColumnType::Blob,
+        },
+        "varbinary" => ColumnType::VarBinary(arg.map(StringLen::N).unwrap_or(StringLen::None)),
+        "blob" | "tinyblob" | "mediumblob" | "longblob" => ColumnType::Blob,
+        "inet" => ColumnType::Inet,
+        "cidr" => ColumnType::Cidr,
+        "macaddr" => ColumnType::MacAddr,
+        "ltree" => ColumnType::LTree,
+        _ => match enums.get(name) {
+            Some(variants) => ColumnType::Enum {
+                name: Alias::new(name).into_iden(),
+                variants: variants
+                    .iter()
+                    .map(|variant| Alias::new(variant).into_iden())
+                    .collect(),
+            },
+            None => ColumnType::custom(name),
+        },
+    };
+    (col_type, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn table(name: &str, references: &[&str]) -> SchemaTable {
+        let mut table = SchemaTable::new(None, name.to_owned());
+        for ref_table in references {
+            table.foreign_keys.push(SchemaForeignKey {
+                name: None,
+                columns: vec![format!("{ref_table}_id")],
+                ref_schema: None,
+                ref_table: ref_table.to_string(),
+                ref_columns: vec!["id".to_owned()],
+                on_delete: None,
+                on_update: None,
+            });
+        }
+        table
+    }
+
+    #[test]
+    fn sort_tables_by_references() {
+        let schema = ImportedSchema {
+            tables: vec![
+                table("comment", &["post", "user"]),
+                table("post", &["user"]),
+                table("tree", &["tree"]),
+                table("user", &[]),
+            ],
+            enums: BTreeMap::new(),
+        };
+        let names: Vec<_> = schema
+            .sorted_tables()
+            .into_iter()
+            .map(|table| table.name.as_str())
+            .collect();
+        assert_eq!(names, ["tree", "user", "post", "comment"]);
+    }
+
+    #[test]
+    fn map_column_types() {
+        let enums = BTreeMap::from([("mood".to_owned(), vec!["sad".to_owned()])]);
+        assert_eq!(
+            column_type("serial", &[], &enums),
+            (ColumnType::Integer, true)
+        );
+        assert_eq!(
+            column_type("character varying", &[64], &enums),
+            (ColumnType::String(StringLen::N(64)), false)
+        );
+        assert_eq!(
+            column_type("numeric", &[10, 2], &enums),
+            (ColumnType::Decimal(Some((10, 2))), false)
+        );
+        assert!(matches!(
+            column_type("mood", &[], &enums),
+            (ColumnType::Enum { .. }, false)
+        ));
+        assert_eq!(
+            column_type("tsvector", &[], &enums),
+            (ColumnType::custom("tsvector"), false)
+        );
+    }
+}
@@ -0,0 +1,655 @@
+use super::{
+    column_type, ImportedSchema, SchemaColumn, SchemaForeignKey, SchemaIndex, SchemaTable,
+};
+use crate::Error;
+use sea_query::{ColumnType, ForeignKeyAction, RcOrArc};
+use sqlparser::{
+    ast::{
+        AlterColumnOperation, AlterTable, AlterTableOperation, ArrayElemTypeDef, ColumnDef,
+        ColumnOption, CreateIndex, CreateTable, DataType, Expr, ForeignKeyConstraint, IndexColumn,
+        ObjectName, ObjectNamePart, ObjectType, ReferentialAction, RenameTableNameKind, Statement,
+        TableConstraint, UserDefinedTypeRepresentation,
+    },
+    dialect::GenericDialect,
+    parser::Parser,
+    tokenizer::{Token, Tokenizer},
+};
+use std::{fs, path::Path};
+
+impl ImportedSchema {
+    /// Import the tables of a project from its SQL migrations, replayed in the given order.
+    /// `CREATE TABLE`, `ALTER TABLE`, `CREATE INDEX`, `CREATE TYPE .. AS ENUM` and the `DROP`
+    /// statements are taken into account, any other statement is skipped.
+    pub fn from_sql_migrations<I, S>(migrations: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut schema = Self::default();
+        for sql in migrations {
+            schema.apply_sql(sql.as_ref())?;
+        }
+        Ok(schema)
+    }
+
+    /// Import the tables of a project from a directory of SQL migrations, sorted by name. Each
+    /// migration is either a `.sql` file, or a directory with an `up.sql` file as Diesel lays
+    /// them out; the `down.sql` and `.down.sql` files are skipped.
+    pub fn from_sql_migrations_dir<P>(dir: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                let up = path.join("up.sql");
+                if up.is_file() {
+                    paths.push(up);
+                }
+            } else {
+                let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+                if file_name.ends_with(".sql")
+                    && file_name != "down.sql"
+                    && !file_name.ends_with(".down.sql")
+                {
+                    paths.push(path);
+                }
+            }
+        }
+        paths.sort();
+        let migrations = paths
+            .iter()
+            .map(fs::read_to_string)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_sql_migrations(migrations)
+    }
+
+    fn apply_sql(&mut self, sql: &str) -> Result<(), Error> {
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql)
+            .tokenize()
+            .map_err(|err| Error::ImportError(format!("Failed to parse the SQL: {err}")))?;
+        // Parse the statements one by one, to skip the ones not understood, e.g. the bodies of
+        // triggers. Only the statements altering tables have to be parsed.
+        for tokens in tokens.split(|token| token == &Token::SemiColon) {
+            let words: Vec<String> = tokens
+                .iter()
+                .filter(|token| !matches!(token, Token::Whitespace(_)))
+                .take(3)
+                .map(|token| token.to_string().to_uppercase())
+                .collect();
+            if words.is_empty() {
+                continue;
+            }
+            match Parser::new(&dialect)
+                .with_tokens(tokens.to_vec())
+                .parse_statement()
+            {
+                Ok(statement) => self.apply_statement(statement)?,
+                Err(err)
+                    if matches!(words[0].as_str(), "CREATE" | "ALTER")
+                        && words.iter().any(|word| word == "TABLE") =>
+                {
+                    return Err(Error::ImportError(format!(
+                        "Failed to parse the SQL: {err}"
+                    )));
+                }
+                Err(err) => tracing::debug!("Skipping a SQL statement: {err}"),
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_statement(&mut self, statement: Statement) -> Result<(), Error> {
+        match statement {
+            Statement::CreateTable(create) => self.create_table(create),
+            Statement::AlterTable(alter) => self.alter_table(alter),
+            Statement::CreateIndex(create) => self.create_index(create),
+            Statement::CreateType {
+                name,
+                representation: Some(UserDefinedTypeRepresentation::Enum { labels }),
+            } => {
+                let (_, name) = object_name(&name);
+                let labels = labels.into_iter().map(|label| label.value).collect();
+                self.enums.insert(name, labels);
+                Ok(())
+            }
+            Statement::Drop {
+                object_type, names, ..
+            } => {
+                for (_, name) in names.iter().map(object_name) {
+                    match object_type {
+                        ObjectType::Table => self.tables.retain(|table| table.name != name),
+                        ObjectType::Index => {
+                            for table in self.tables.iter_mut() {
+                                table
+                                    .indexes
+                                    .retain(|index| index.name.as_ref() != Some(&name));
+                            }
+                        }
+                        ObjectType::Type => {
+                            self.enums.remove(&name);
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn create_table(&mut self, create: CreateTable) -> Result<(), Error> {
+        let (schema, name) = object_name(&create.name);
+        if self.tables.iter().any(|table| table.name == name) {
+            if create.if_not_exists {
+                return Ok(());
+            }
+            self.tables.retain(|table| table.name != name);
+        }
+        let mut table = SchemaTable::new(schema, name);
+        self.tables.push(table.clone());
+        for column in create.columns {
+            self.add_column(&mut table, column)?;
+        }
+        for constraint in create.constraints {
+            self.add_constraint(&mut table, constraint)?;
+        }
+        let name = table.name.clone();
+        *self.table_mut(&name)? = table;
+        Ok(())
+    }
+
+    fn alter_table(&mut self, alter: AlterTable) -> Result<(), Error> {
+        let (_, name) = object_name(&alter.name);
+        let mut table = self.table_mut(&name)?.clone();
+        for operation in alter.operations {
+            match operation {
+                AlterTableOperation::AddColumn { column_def, .. } => {
+                    self.add_column(&mut table, column_def)?
+                }
+                AlterTableOperation::AddConstraint { constraint, .. } => {
+                    self.add_constraint(&mut table, constraint)?
+                }
+                AlterTableOperation::DropColumn { column_names, .. } => {
+                    for column in column_names.iter().map(|name| &name.value) {
+                        table.columns.retain(|col| &col.name != column);
+                        table.primary_key.retain(|col| col != column);
+                        table
+                            .indexes
+                            .retain(|index| !index.columns.contains(column));
+                        table.foreign_keys.retain(|fk| !fk.columns.contains(column));
+                    }
+                }
+                AlterTableOperation::DropConstraint { name, .. } => {
+                    let name = Some(name.value);
+                    table.indexes.retain(|index| index.name != name);
+                    table.foreign_keys.retain(|fk| fk.name != name);
+                }
+                AlterTableOperation::RenameColumn {
+                    old_column_name,
+                    new_column_name,
+                } => {
+                    let (old, new) = (old_column_name.value, new_column_name.value);
+                    table.column_mut(&old)?.name = new.clone();
+                    let rename = |columns: &mut Vec<String>| {
+                        for column in columns.iter_mut().filter(|column| **column == old) {
+                            column.clone_from(&new);
+                        }
+                    };
+                    rename(&mut table.primary_key);
+                    table
+                        .indexes
+                        .iter_mut()
+                        .for_each(|index| rename(&mut index.columns));
+                    table.foreign_keys.iter_mut().for_each(|fk| {
+                        rename(&mut fk.columns);
+                        if fk.ref_table == table.name {
+                            rename(&mut fk.ref_columns);
+                        }
+                    });
+                    for other in self.tables.iter_mut().filter(|other| other.name != name) {
+                        for fk in other.foreign_keys.iter_mut() {
+                            if fk.ref_table == name {
+                                rename(&mut fk.ref_columns);
+                            }
+                        }
+                    }
+                }
+                AlterTableOperation::RenameTable {
+                    table_name: RenameTableNameKind::As(new) | RenameTableNameKind::To(new),
+                } => {
+                    let (_, new) = object_name(&new);
+                    for fk in self
+                        .tables
+                        .iter_mut()
+                        .flat_map(|table| table.foreign_keys.iter_mut())
+                        .chain(table.foreign_keys.iter_mut())
+                        .filter(|fk| fk.ref_table == table.name)
+                    {
+                        fk.ref_table.clone_from(&new);
+                    }
+                    self.table_mut(&table.name.clone())?.name.clone_from(&new);
+                    table.name = new;
+                }
+                AlterTableOperation::AlterColumn { column_name, op } => {
+                    let column = table.column_mut(&column_name.value)?;
+                    match op {
+                        AlterColumnOperation::SetNotNull => column.not_null = true,
+                        AlterColumnOperation::DropNotNull => column.not_null = false,
+                        AlterColumnOperation::SetDefault { value } => {
+                            column.default = Some(value.to_string())
+                        }
+                        AlterColumnOperation::DropDefault => column.default = None,
+                        AlterColumnOperation::SetDataType { data_type, .. } => {
+                            column.col_type = self.column_type(&data_type).0
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+        let name = table.name.clone();
+        *self.table_mut(&name)? = table;
+        Ok(())
+    }
+
+    fn create_index(&mut self, create: CreateIndex) -> Result<(), Error> {
+        let (_, table_name) = object_name(&create.table_name);
+        let name = create.name.as_ref().map(|name| object_name(name).1);
+        let columns = create.columns.iter().map(index_column).collect();
+        let table = self.table_mut(&table_name)?;
+        if create.unique {
+            table.add_unique(name, columns);
+        } else {
+            table.indexes.push(SchemaIndex {
+                name,
+                columns,
+                unique: false,
+            });
+        }
+        Ok(())
+    }
+
+    fn add_column(&self, table: &mut SchemaTable, column: ColumnDef) -> Result<(), Error> {
+        let (col_type, mut auto_increment) = self.column_type(&column.data_type);
+        let mut schema_column = SchemaColumn {
+            name: column.name.value,
+            col_type,
+            not_null: false,
+            auto_increment: false,
+            unique: false,
+            default: None,
+        };
+        let mut primary_key = false;
+        for option in column.options.into_iter().map(|option| option.option) {
+            match option {
+                ColumnOption::Null => schema_column.not_null = false,
+                ColumnOption::NotNull => schema_column.not_null = true,
+                ColumnOption::Default(expr) => schema_column.default = Some(expr.to_string()),
+                ColumnOption::PrimaryKey(_) => primary_key = true,
+                ColumnOption::Unique(_) => schema_column.unique = true,
+                ColumnOption::ForeignKey(fk) => {
+                    let fk = self.foreign_key(table, fk, vec![schema_column.name.clone()])?;
+                    table.foreign_keys.push(fk);
+                }
+                ColumnOption::DialectSpecific(tokens) => {
+                    auto_increment |= tokens.iter().any(|token| {
+                        matches!(
+                            token.to_string().to_uppercase().as_str(),
+                            "AUTOINCREMENT" | "AUTO_INCREMENT"
+                        )
+                    })
+                }
+                ColumnOption::Generated {
+                    generation_expr: None,
+                    ..
+                }
+                | ColumnOption::Identity(_) => auto_increment = true,
+                _ => {}
+            }
+        }
+        schema_column.auto_increment = auto_increment;
+        let name = schema_column.name.clone();
+        table.columns.push(schema_column);
+        if primary_key {
+            table.set_primary_key(vec![name]);
+        }
+        Ok(())
+    }
+
+    fn add_constraint(
+        &self,
+        table: &mut SchemaTable,
+        constraint: TableConstraint,
+    ) -> Result<(), Error> {
+        match constraint {
+            TableConstraint::PrimaryKey(primary_key) => {
+                table.set_primary_key(primary_key.columns.iter().map(index_column).collect())
+            }
+            TableConstraint::Unique(unique) => table.add_unique(
+                unique.name.map(|name| name.value),
+                unique.columns.iter().map(index_column).collect(),
+            ),
+            TableConstraint::ForeignKey(fk) => {
+                let columns = fk
+                    .columns
+                    .iter()
+                    .map(|column| column.value.clone())
+                    .collect();
+                let fk = self.foreign_key(table, fk, columns)?;
+                table.foreign_keys.push(fk);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn foreign_key(
+        &self,
+        table: &SchemaTable,
+        fk: ForeignKeyConstraint,
+        columns: Vec<String>,
+    ) -> Result<SchemaForeignKey, Error> {
+        let (ref_schema, ref_table) = object_name(&fk.foreign_table);
+        let ref_columns = if fk.referred_columns.is_empty() {
+            // Reference the primary key of the table, which may be the table being created
+            match self.tables.iter().find(|table| table.name == ref_table) {
+                Some(_) if ref_table == table.name => table.primary_key.clone(),
+                Some(ref_table) => ref_table.primary_key.clone(),
+                None => {
+                    return Err(Error::ImportError(format!(
+                        "Table `{ref_table}` does not exist"
+                    )))
+                }
+            }
+        } else {
+            fk.referred_columns
+                .into_iter()
+                .map(|column| column.value)
+                .collect()
+        };
+        Ok(SchemaForeignKey {
+            name: fk.name.map(|name| name.value),
+            columns,
+            ref_schema,
+            ref_table,
+            ref_columns,
+            on_delete: fk.on_delete.map(foreign_key_action),
+            on_update: fk.on_update.map(foreign_key_action),
+        })
+    }
+
+    fn column_type(&self, data_type: &DataType) -> (ColumnType, bool) {
+        match data_type {
+            DataType::Array(
+                ArrayElemTypeDef::AngleBracket(elem)
+                | ArrayElemTypeDef::SquareBracket(elem, _)
+                | ArrayElemTypeDef::Parenthesis(elem)
+                | ArrayElemTypeDef::Qualified(elem, _),
+            ) => (
+                ColumnType::Array(RcOrArc::new(self.column_type(elem).0)),
+                false,
+            ),
+            DataType::Custom(name, args) => {
+                let (_, name) = object_name(name);
+                let args: Vec<u32> = args.iter().filter_map(|arg| arg.parse().ok()).collect();
+                column_type(&name, &args, &self.enums)
+            }
+            _ => {
+                let (name, args) = type_name(&data_type.to_string());
+                column_type(&name, &args, &self.enums)
+            }
+        }
+    }
+}
+
+/// The schema and the name of an object, unquoted identifiers being case-insensitive
+fn object_name(name: &ObjectName) -> (Option<String>, String) {
+    let mut idents: Vec<String> = name
+        .0
+        .iter()
+        .map(|part| match part {
+            ObjectNamePart::Identifier(ident) if ident.quote_style.is_none() => {
+                ident.value.to_lowercase()
+            }
+            ObjectNamePart::Identifier(ident) => ident.value.clone(),
+            ObjectNamePart::Function(function) => function.to_string(),
+        })
+        .collect();
+    let name = idents.pop().unwrap_or_default();
+    (idents.pop(), name)
+}
+
+fn index_column(column: &IndexColumn) -> String {
+    match &column.column.expr {
+        Expr::Identifier(ident) => ident.value.clone(),
+        expr => expr.to_string(),
+    }
+}
+
+/// Split a type like `TIMESTAMP(3) WITH TIME ZONE` into its lower case name and its arguments
+fn type_name(data_type: &str) -> (String, Vec<u32>) {
+    let mut name = String::new();
+    let mut args = Vec::new();
+    let mut rest = data_type;
+    while let Some(start) = rest.find('(') {
+        name.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(')')
+            .map_or(rest.len(), |end| start + end);
+        args.extend(
+            rest[start + 1..end]
+                .split(',')
+                .filter_map(|arg| arg.trim().parse::<u32>().ok()),
+        );
+        rest = rest.get(end + 1..).unwrap_or_default();
+    }
+    name.push_str(rest);
+    let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    (name.to_lowercase(), args)
+}
+
+fn foreign_key_action(action: ReferentialAction) -> ForeignKeyAction {
+    match action {
+        ReferentialAction::Restrict => ForeignKeyAction::Restrict,
+        ReferentialAction::Cascade => ForeignKeyAction::Cascade,
+        ReferentialAction::SetNull => ForeignKeyAction::SetNull,
+        ReferentialAction::NoAction => ForeignKeyAction::NoAction,
+        ReferentialAction::SetDefault => ForeignKeyAction::SetDefault,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use sea_query::StringLen;
+
+    const INITIAL_SETUP: &str = r#"
+CREATE OR REPLACE FUNCTION diesel_manage_updated_at(_tbl regclass) RETURNS VOID AS $$
+BEGIN
+    EXECUTE format('CREATE TRIGGER set_updated_at BEFORE UPDATE ON %s
+                    FOR EACH ROW EXECUTE PROCEDURE diesel_set_updated_at()', _tbl);
+END;
+$$ LANGUAGE plpgsql;
+"#;
+
+    const CREATE_USERS: &str = r#"
+CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy');
+
+CREATE TABLE users (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    mood mood,
+    nickname TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+SELECT diesel_manage_updated_at('users');
+"#;
+
+    const CREATE_POSTS: &str = r#"
+CREATE TABLE posts (
+    id BIGSERIAL,
+    user_id INTEGER NOT NULL REFERENCES users ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    price NUMERIC(10, 2),
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    PRIMARY KEY (id),
+    CONSTRAINT posts_user_title UNIQUE (user_id, title)
+);
+
+CREATE INDEX posts_price ON posts (price);
+ALTER TABLE users DROP COLUMN nickname;
+ALTER TABLE posts RENAME COLUMN title TO headline;
+ALTER TABLE posts ALTER COLUMN price SET NOT NULL;
+"#;
+
+    fn column(name: &str, col_type: ColumnType, not_null: bool) -> SchemaColumn {
+        SchemaColumn {
+            name: name.to_owned(),
+            col_type,
+            not_null,
+            auto_increment: false,
+            unique: false,
+            default: None,
+        }
+    }
+
+    #[test]
+    fn import_sql_migrations() -> Result<(), Error> {
+        let schema =
+            ImportedSchema::from_sql_migrations([INITIAL_SETUP, CREATE_USERS, CREATE_POSTS])?;
+
+        let mood = column_type("mood", &[], &schema.enums).0;
+        assert!(matches!(mood, ColumnType::Enum { .. }));
+        assert_eq!(
+            schema.tables,
+            [
+                SchemaTable {
+                    schema: None,
+                    name: "users".to_owned(),
+                    columns: vec![
+                        SchemaColumn {
+                            auto_increment: true,
+                            ..column("id", ColumnType::Integer, true)
+                        },
+                        SchemaColumn {
+                            unique: true,
+                            ..column("name", ColumnType::String(StringLen::N(255)), true)
+                        },
+                        column("mood", mood, false),
+                        SchemaColumn {
+                            default: Some("NOW()".to_owned()),
+                            ..column("created_at", ColumnType::DateTime, true)
+                        },
+                    ],
+                    primary_key: vec!["id".to_owned()],
+                    indexes: vec![],
+                    foreign_keys: vec![],
+                },
+                SchemaTable {
+                    schema: None,
+                    name: "posts".to_owned(),
+                    columns: vec![
+                        SchemaColumn {
+                            auto_increment: true,
+                            ..column("id", ColumnType::BigInteger, true)
+                        },
+                        column("user_id", ColumnType::Integer, true),
+                        column("headline", ColumnType::Text, true),
+                        column("price", ColumnType::Decimal(Some((10, 2))), true),
+                        SchemaColumn {
+                            default: Some("'{}'".to_owned()),
+                            ..column(
+                                "tags",
+                                ColumnType::Array(RcOrArc::new(ColumnType::Text)),
+                                true
+                            )
+                        },
+                    ],
+                    primary_key: vec!["id".to_owned()],
+                    indexes: vec![
+                        SchemaIndex {
+                            name: Some("posts_user_title".to_owned()),
+                            columns: vec!["user_id".to_owned(), "headline".to_owned()],
+                            unique: true,
+                        },
+                        SchemaIndex {
+                            name: Some("posts_price".to_owned()),
+                            columns: vec!["price".to_owned()],
+                            unique: false,
+                        },
+                    ],
+                    foreign_keys: vec![SchemaForeignKey {
+                        name: None,
+                        columns: vec!["user_id".to_owned()],
+                        ref_schema: None,
+                        ref_table: "users".to_owned(),
+                        ref_columns: vec!["id".to_owned()],
+                        on_delete: Some(ForeignKeyAction::Cascade),
+                        on_update: None,
+                    }],
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn import_sql_migrations_dir() -> Result<(), Error> {
+        let dir = std::env::temp_dir().join("sea_orm_codegen_import_sql_migrations_dir");
+        let _ = fs::remove_dir_all(&dir);
+        for (name, up) in [
+            ("00000000000000_diesel_initial_setup", INITIAL_SETUP),
+            ("2024-01-01-000000_create_users", CREATE_USERS),
+            ("2024-01-02-000000_create_posts", CREATE_POSTS),
+        ] {
+            fs::create_dir_all(dir.join(name))?;
+            fs::write(dir.join(name).join("up.sql"), up)?;
+            fs::write(dir.join(name).join("down.sql"), "DROP TABLE users;")?;
+        }
+
+        let schema = ImportedSchema::from_sql_migrations_dir(&dir)?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(
+            schema
+                .tables
+                .iter()
+                .map(|table| table.name.as_str())
+                .collect::<Vec<_>>(),
+            ["users", "posts"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mysql_and_sqlite_migrations() -> Result<(), Error> {
+        let schema = ImportedSchema::from_sql_migrations([
+            "CREATE TABLE `cake` (`id` INT(11) UNSIGNED NOT NULL AUTO_INCREMENT PRIMARY KEY, `name` TEXT);",
+            "CREATE TABLE fruit (id INTEGER PRIMARY KEY AUTOINCREMENT, cake_id INT, FOREIGN KEY (cake_id) REFERENCES cake (id));",
+            "ALTER TABLE fruit RENAME TO fruits;",
+        ])?;
+
+        assert_eq!(schema.tables[0].columns[0].col_type, ColumnType::Unsigned);
+        assert!(schema.tables[0].columns[0].auto_increment);
+        assert_eq!(schema.tables[1].name, "fruits");
+        assert!(schema.tables[1].columns[0].auto_increment);
+        assert_eq!(schema.tables[1].foreign_keys[0].ref_table, "cake");
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_create_table() {
+        let err =
+            ImportedSchema::from_sql_migrations(["CREATE TABLE cake (id INTEGER,,);"]).unwrap_err();
+        assert!(err.to_string().contains("Failed to parse the SQL"));
+    }
+}
@@ -1,9 +1,11 @@
 mod entity;
 mod error;
+mod import;
 mod util;
 
 pub use entity::*;
 pub use error::*;
+pub use import::*;
 
 #[cfg(test)]
 mod tests_cfg;
@@ -73,6 +73,8 @@ impl ActiveEnum {
                         enum_name = litstr.value();
                     } else if meta.path.is_ident("rename_all") {
                         rename_all = Some((&meta).try_into()?);
+                    } else if meta.path.is_ident("schema") {
+                        // `utoipa::ToSchema`, implemented by `expand_to_schema`
                     } else {
                         return Err(meta.error(format!(
                             "Unknown attribute parameter found: {:?}",
@@ -377,6 +379,14 @@ impl ActiveEnum {
 
             #impl_try_getable_array
 
+            #[automatically_derived]
+            impl sea_orm::Fake for #ident {
+                fn fake(rng: &mut sea_orm::FakeRng) -> Self {
+                    rng.choose(<Self as sea_orm::Iterable>::iter())
+                        .expect("Active enum without variants")
+                }
+            }
+
             #[automatically_derived]
             #[allow(clippy::from_over_into)]
             impl Into<sea_orm::sea_query::Value> for #ident {
@@ -1,11 +1,60 @@
 use super::util::{
-    escape_rust_keyword, field_not_ignored, format_field_ident, trim_starting_raw_identifier,
+    escape_rust_keyword, field_not_ignored, format_field_ident, skip_meta_value,
+    trim_starting_raw_identifier,
 };
 use heck::ToUpperCamelCase;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 use syn::{punctuated::IntoIter, Data, DataStruct, Expr, Field, Fields, LitStr, Type};
 
+/// The methods of the traits implemented for an ActiveModel, which a chainable setter of a
+/// field with the same name would shadow, so the field has none
+const SETTER_RESERVED: &[&str] = &[
+    "after_commit",
+    "after_delete",
+    "after_rollback",
+    "after_save",
+    "before_delete",
+    "before_save",
+    "clone",
+    "default",
+    "delete",
+    "delete_atomic",
+    "diff",
+    "eq",
+    "factory",
+    "fake",
+    "fmt",
+    "from",
+    "from_json",
+    "get",
+    "get_primary_key_value",
+    "insert",
+    "into",
+    "into_active_model",
+    "is_changed",
+    "is_not_set",
+    "ne",
+    "new",
+    "not_set",
+    "reset",
+    "reset_all",
+    "save",
+    "save_atomic",
+    "save_many",
+    "set",
+    "set_expr",
+    "set_from_json",
+    "set_from_json_with",
+    "take",
+    "try_from",
+    "try_into",
+    "try_into_model",
+    "update",
+    "validate",
+    "validate_model",
+];
+
 /// Method to derive an [ActiveModel](sea_orm::ActiveModel)
 pub fn expand_derive_active_model(ident: Ident, data: Data) -> syn::Result<TokenStream> {
     // including ignored fields
@@ -53,10 +102,7 @@ fn derive_active_model(ident: &Ident, all_fields: IntoIter<Field>) -> syn::Resul
                             let litstr: LitStr = meta.value()?.parse()?;
                             ident = syn::parse_str(&litstr.value()).unwrap();
                         } else {
-                            // Reads the value expression to advance the parse stream.
-                            // Some parameters, such as `primary_key`, do not have any value,
-                            // so ignoring an error occurred here.
-                            let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
+                            skip_meta_value(&meta)?;
                         }
 
                         Ok(())
@@ -66,7 +112,19 @@ fn derive_active_model(ident: &Ident, all_fields: IntoIter<Field>) -> syn::Resul
         })
         .collect::<Result<_, _>>()?;
 
-    let ty: Vec<Type> = fields.into_iter().map(|Field { ty, .. }| ty).collect();
+    let validate: Vec<TokenStream> = fields
+        .clone()
+        .map(derive_field_validation)
+        .collect::<Result<_, _>>()?;
+
+    let ty: Vec<Type> = fields.clone().map(|Field { ty, .. }| ty).collect();
+
+    let (setter, setter_ty): (Vec<Ident>, Vec<Type>) = fields
+        .map(|field| (format_field_ident(field.clone()), field.ty))
+        .filter(|(field, _)| {
+            !SETTER_RESERVED.contains(&trim_starting_raw_identifier(field).as_str())
+        })
+        .unzip();
 
     Ok(quote!(
         #[doc = " Generated by sea-orm-macros"]
@@ -86,6 +144,18 @@ fn derive_active_model(ident: &Ident, all_fields: IntoIter<Field>) -> syn::Resul
             }
         }
 
+        #[automatically_derived]
+        impl ActiveModel {
+            #(
+                #[doc = " Generated by sea-orm-macros"]
+                #[allow(clippy::wrong_self_convention)]
+                pub fn #setter(mut self, v: impl Into<#setter_ty>) -> Self {
+                    self.#setter = sea_orm::ActiveValue::set(v.into());
+                    self
+                }
+            )*
+        }
+
         #[automatically_derived]
         impl std::convert::From<#ident> for ActiveModel {
             fn from(m: #ident) -> Self {
@@ -131,6 +201,13 @@ fn derive_active_model(ident: &Ident, all_fields: IntoIter<Field>) -> syn::Resul
                 }
             }
 
+            fn set_expr(&mut self, c: <Self::Entity as sea_orm::EntityTrait>::Column, expr: sea_orm::sea_query::SimpleExpr) {
+                match c {
+                    #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => self.#field = sea_orm::ActiveValue::SetExpr(expr),)*
+                    _ => panic!("This ActiveModel does not have this field"),
+                }
+            }
+
             fn not_set(&mut self, c: <Self::Entity as sea_orm::EntityTrait>::Column) {
                 match c {
                     #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => self.#field = sea_orm::ActiveValue::not_set(),)*
@@ -157,6 +234,70 @@ fn derive_active_model(ident: &Ident, all_fields: IntoIter<Field>) -> syn::Resul
                     _ => panic!("This ActiveModel does not have this field"),
                 }
             }
+
+            fn validate_values(&self) -> Result<(), sea_orm::ValidationErrors> {
+                let mut errors = sea_orm::ValidationErrors::new();
+                #(#validate)*
+                <Self as sea_orm::ActiveModelBehavior>::validate_model(self, &mut errors);
+                errors.into_result()
+            }
+        }
+    ))
+}
+
+/// The checks of the rules in `#[sea_orm(validate(length(min = 1, max = 255), range(min = 0)))]`,
+/// run on the value of the field if it is `Set`
+fn derive_field_validation(field: Field) -> syn::Result<TokenStream> {
+    let ident = format_field_ident(field.clone());
+    let field_name = trim_starting_raw_identifier(&ident);
+    let mut checks = Vec::new();
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("sea_orm"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("validate") {
+                return skip_meta_value(&meta);
+            }
+            meta.parse_nested_meta(|rule| {
+                let (mut min, mut max) = (quote!(None), quote!(None));
+                let is_length = rule.path.is_ident("length");
+                if !is_length && !rule.path.is_ident("range") {
+                    return Err(rule.error("unknown validation rule, expected `length` or `range`"));
+                }
+                rule.parse_nested_meta(|bound| {
+                    let expr: Expr = bound.value()?.parse()?;
+                    let expr = if is_length {
+                        quote!(Some(#expr))
+                    } else {
+                        quote!(Some((#expr) as f64))
+                    };
+                    if bound.path.is_ident("min") {
+                        min = expr;
+                    } else if bound.path.is_ident("max") {
+                        max = expr;
+                    } else {
+                        return Err(bound.error("expected `min` or `max`"));
+                    }
+                    Ok(())
+                })?;
+                let check = if is_length {
+                    quote!(check_length)
+                } else {
+                    quote!(check_range)
+                };
+                checks.push(quote!(errors.#check(#field_name, value, #min, #max);));
+                Ok(())
+            })
+        })?;
+    }
+    if checks.is_empty() {
+        return Ok(TokenStream::new());
+    }
+    Ok(quote!(
+        if let sea_orm::ActiveValue::Set(value) = &self.#ident {
+            #(#checks)*
         }
     ))
 }
@@ -195,7 +336,7 @@ fn derive_into_model(ident: &Ident, model_fields: IntoIter<Field>) -> syn::Resul
         impl std::convert::TryFrom<ActiveModel> for #ident {
             type Error = sea_orm::DbErr;
             fn try_from(a: ActiveModel) -> Result<Self, sea_orm::DbErr> {
-                #(if matches!(a.#active_model_field, sea_orm::ActiveValue::NotSet) {
+                #(if matches!(a.#active_model_field, sea_orm::ActiveValue::NotSet | sea_orm::ActiveValue::SetExpr(_)) {
                     return Err(sea_orm::DbErr::AttrNotSet(stringify!(#active_model_field).to_owned()));
                 })*
                 Ok(
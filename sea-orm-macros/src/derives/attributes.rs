@@ -14,8 +14,13 @@ pub mod derive_attr {
         pub schema_name: Option<syn::Lit>,
         pub table_name: Option<syn::Lit>,
         pub comment: Option<syn::Lit>,
+        pub tenant_key: Option<syn::Lit>,
+        pub default_scope: Option<syn::LitStr>,
+        pub versioned: Option<()>,
         pub table_iden: Option<()>,
         pub rename_all: Option<syn::Lit>,
+        pub schema: Option<()>,
+        pub graphql: Option<()>,
     }
 }
 
@@ -35,6 +40,8 @@ pub mod field_attr {
         pub to: Option<syn::Lit>,
         pub fk_name: Option<syn::Lit>,
         pub condition_type: Option<syn::Lit>,
+        pub morph_type: Option<syn::Lit>,
+        pub morph_value: Option<syn::Lit>,
     }
 }
 
@@ -14,6 +14,9 @@ struct DeriveEntity {
     relation_ident: syn::Ident,
     schema_name: Option<syn::Lit>,
     table_name: Option<syn::Lit>,
+    tenant_key: Option<syn::Lit>,
+    default_scope: Option<syn::Path>,
+    versioned: bool,
 }
 
 impl DeriveEntity {
@@ -35,6 +38,12 @@ impl DeriveEntity {
 
         let table_name = sea_attr.table_name;
         let schema_name = sea_attr.schema_name;
+        let tenant_key = sea_attr.tenant_key;
+        let default_scope = sea_attr
+            .default_scope
+            .map(|scope| scope.parse::<syn::Path>())
+            .transpose()?;
+        let versioned = sea_attr.versioned.is_some();
 
         Ok(DeriveEntity {
             column_ident,
@@ -45,6 +54,9 @@ impl DeriveEntity {
             relation_ident,
             schema_name,
             table_name,
+            tenant_key,
+            default_scope,
+            versioned,
         })
     }
 
@@ -73,6 +85,17 @@ impl DeriveEntity {
             .as_ref()
             .map(|schema| quote!(Some(#schema)))
             .unwrap_or_else(|| quote!(None));
+        let expanded_tenant_key = self
+            .tenant_key
+            .as_ref()
+            .map(|key| quote!(Some(#key)))
+            .unwrap_or_else(|| quote!(None));
+        let expanded_default_scope = self
+            .default_scope
+            .as_ref()
+            .map(|scope| quote!(Some(sea_orm::sea_query::IntoCondition::into_condition(#scope()))))
+            .unwrap_or_else(|| quote!(None));
+        let versioned = self.versioned;
 
         quote!(
             #[automatically_derived]
@@ -84,6 +107,18 @@ impl DeriveEntity {
                 fn table_name(&self) -> &str {
                     #table_name
                 }
+
+                fn tenant_key(&self) -> Option<&str> {
+                    #expanded_tenant_key
+                }
+
+                fn default_scope(&self) -> Option<sea_orm::sea_query::Condition> {
+                    #expanded_default_scope
+                }
+
+                fn versioned(&self) -> bool {
+                    #versioned
+                }
             }
         )
     }
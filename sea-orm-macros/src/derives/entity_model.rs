@@ -1,10 +1,11 @@
 use super::case_style::{CaseStyle, CaseStyleHelpers};
-use super::util::{escape_rust_keyword, trim_starting_raw_identifier};
-use heck::{ToSnakeCase, ToUpperCamelCase};
+use super::util::{escape_rust_keyword, skip_meta_value, trim_starting_raw_identifier};
+use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{
-    punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data, Expr, Fields, Lit,
+    ext::IdentExt, punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data, Fields,
+    Lit,
 };
 
 /// Method to derive an Model
@@ -13,8 +14,12 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut table_name = None;
     let mut comment = quote! {None};
     let mut schema_name = quote! { None };
+    let mut tenant_key = None;
+    let mut default_scope = quote! { None };
+    let mut versioned = false;
     let mut table_iden = false;
     let mut rename_all: Option<CaseStyle> = None;
+    let mut graphql = false;
 
     attrs
         .iter()
@@ -29,21 +34,33 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                 } else if meta.path.is_ident("schema_name") {
                     let name: Lit = meta.value()?.parse()?;
                     schema_name = quote! { Some(#name) };
+                } else if meta.path.is_ident("tenant_key") {
+                    tenant_key = Some(meta.value()?.parse::<syn::LitStr>()?);
+                } else if meta.path.is_ident("default_scope") {
+                    let scope = meta.value()?.parse::<syn::LitStr>()?.parse::<syn::Path>()?;
+                    default_scope = quote! {
+                        Some(sea_orm::sea_query::IntoCondition::into_condition(#scope()))
+                    };
+                } else if meta.path.is_ident("versioned") {
+                    versioned = true;
                 } else if meta.path.is_ident("table_iden") {
                     table_iden = true;
                 } else if meta.path.is_ident("rename_all") {
                     rename_all = Some((&meta).try_into()?);
+                } else if meta.path.is_ident("graphql") {
+                    graphql = true;
                 } else {
-                    // Reads the value expression to advance the parse stream.
-                    // Some parameters, such as `primary_key`, do not have any value,
-                    // so ignoring an error occurred here.
-                    let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
+                    skip_meta_value(&meta)?;
                 }
 
                 Ok(())
             })
         })?;
 
+    let entity_tenant_key = match &tenant_key {
+        Some(name) => quote! { Some(#name) },
+        None => quote! { None },
+    };
     let entity_def = table_name
         .as_ref()
         .map(|table_name| {
@@ -65,6 +82,18 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     fn comment(&self) -> Option<&str> {
                         #comment
                     }
+
+                    fn tenant_key(&self) -> Option<&str> {
+                        #entity_tenant_key
+                    }
+
+                    fn default_scope(&self) -> Option<sea_orm::sea_query::Condition> {
+                        #default_scope
+                    }
+
+                    fn versioned(&self) -> bool {
+                        #versioned
+                    }
                 }
             }
         })
@@ -74,11 +103,21 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut columns_enum: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_trait: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_enum_type_name: Punctuated<_, Comma> = Punctuated::new();
+    let mut columns_value_type: Vec<TokenStream> = Vec::new();
     let mut columns_select_as: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_save_as: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_keys: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_key_types: Punctuated<_, Comma> = Punctuated::new();
+    let mut column_names = Vec::new();
+    let mut graphql_fields: Punctuated<_, Comma> = Punctuated::new();
+    let mut debug_fields: Vec<TokenStream> = Vec::new();
+    let mut masked_values: Vec<TokenStream> = Vec::new();
     let mut auto_increment = true;
+    // the GraphQL object type of the entity is named after its table
+    let graphql_name = match &table_name {
+        Some(Lit::Str(name)) => Some(name.value().to_upper_camel_case()),
+        _ => None,
+    };
     if table_iden {
         if let Some(table_name) = table_name {
             let table_field_name = Ident::new("Table", Span::call_site());
@@ -112,6 +151,13 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     let mut indexed = false;
                     let mut ignore = false;
                     let mut unique = false;
+                    let mut expires_at = false;
+                    let mut soft_delete = false;
+                    let mut created_at = None;
+                    let mut updated_at = None;
+                    let mut version = false;
+                    let mut masked = None;
+                    let mut graphql_field = Some(original_field_name.to_lower_camel_case());
                     let mut sql_type = None;
                     let mut column_name = if let Some(case_style) = rename_all {
                         Some(field_name.convert_case(Some(case_style)))
@@ -126,7 +172,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
 
                     let mut enum_name = None;
                     let mut is_primary_key = false;
-                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(StringLen::N(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
+                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(StringLen::N(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique, expires_at, soft_delete, created_at, updated_at = "client", version, masked = "hr, admin")]
                     for attr in field.attrs.iter() {
                         if !attr.path().is_ident("sea_orm") {
                             continue;
@@ -209,11 +255,42 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 indexed = true;
                             } else if meta.path.is_ident("unique") {
                                 unique = true;
+                            } else if meta.path.is_ident("expires_at") {
+                                expires_at = true;
+                            } else if meta.path.is_ident("soft_delete") {
+                                soft_delete = true;
+                            } else if meta.path.is_ident("version") {
+                                version = true;
+                            } else if meta.path.is_ident("created_at") {
+                                created_at = Some(timestamp_clock(&meta)?);
+                            } else if meta.path.is_ident("updated_at") {
+                                updated_at = Some(timestamp_clock(&meta)?);
+                            } else if meta.path.is_ident("masked") {
+                                let mut roles = Vec::new();
+                                if meta.input.peek(syn::Token![=]) {
+                                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                                    roles = lit
+                                        .value()
+                                        .split(',')
+                                        .map(|role| role.trim().to_owned())
+                                        .filter(|role| !role.is_empty())
+                                        .collect();
+                                }
+                                masked = Some(roles);
+                            } else if meta.path.is_ident("graphql") {
+                                meta.parse_nested_meta(|meta| {
+                                    if meta.path.is_ident("skip") {
+                                        graphql_field = None;
+                                    } else if meta.path.is_ident("name") {
+                                        let name = meta.value()?.parse::<syn::LitStr>()?;
+                                        graphql_field = Some(name.value());
+                                    } else {
+                                        return Err(meta.error("expected `skip` or `name`"));
+                                    }
+                                    Ok(())
+                                })?;
                             } else {
-                                // Reads the value expression to advance the parse stream.
-                                // Some parameters, such as `primary_key`, do not have any value,
-                                // so ignoring an error occurred here.
-                                let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
+                                skip_meta_value(&meta)?;
                             }
 
                             Ok(())
@@ -236,13 +313,44 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                         },
                     };
 
+                    // the fields of masked columns are redacted in the `Debug` of the Model
+                    let debug_name = ident.unraw().to_string();
+                    if masked.is_some() && !ignore {
+                        let masked_value =
+                            format_ident!("__sea_orm_masked_{}", original_field_name);
+                        masked_values.push(quote! {
+                            #[doc(hidden)]
+                            pub fn #masked_value<'a, T>(value: &'a T) -> sea_orm::MaskedValue<'a, Self, T>
+                            where
+                                T: ?Sized,
+                            {
+                                sea_orm::MaskedValue::new(Self::#field_name, value)
+                            }
+                        });
+                        debug_fields.push(quote! {
+                            .field(#debug_name, &Column::#masked_value(&self.#ident))
+                        });
+                    } else {
+                        debug_fields.push(quote! { .field(#debug_name, &self.#ident) });
+                    }
+
                     if ignore {
                         continue;
                     } else {
+                        column_names.push(
+                            column_name
+                                .clone()
+                                .unwrap_or_else(|| field_name.unraw().to_string().to_snake_case()),
+                        );
                         columns_enum.push(quote! {
                             #variant_attrs
                             #field_name
                         });
+                        let graphql_field = match graphql_field {
+                            Some(name) => quote! { Some(#name) },
+                            None => quote! { None },
+                        };
+                        graphql_fields.push(quote! { Self::Column::#field_name => #graphql_field });
                     }
 
                     if is_primary_key {
@@ -264,6 +372,13 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     }
 
                     let field_type = &field.ty;
+                    let value_type = format_ident!("__sea_orm_value_type_{}", field_name.unraw());
+                    columns_value_type.push(quote! {
+                        #[doc(hidden)]
+                        pub fn #value_type(&self) -> std::marker::PhantomData<#field_type> {
+                            std::marker::PhantomData
+                        }
+                    });
                     let field_type = quote! { #field_type }
                         .to_string() //E.g.: "Option < String >"
                         .replace(' ', ""); // Remove spaces
@@ -292,6 +407,27 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     if unique {
                         match_row = quote! { #match_row.unique() };
                     }
+                    if expires_at {
+                        match_row = quote! { #match_row.expires_at() };
+                    }
+                    if soft_delete {
+                        match_row = quote! { #match_row.soft_delete() };
+                    }
+                    if version {
+                        match_row = quote! { #match_row.version() };
+                    }
+                    if let Some(clock) = created_at {
+                        match_row = quote! { #match_row.created_at(#clock) };
+                    }
+                    if let Some(clock) = updated_at {
+                        match_row = quote! { #match_row.updated_at(#clock) };
+                    }
+                    if let Some(roles) = masked {
+                        match_row = quote! { #match_row.masked() };
+                        for role in roles {
+                            match_row = quote! { #match_row.masked_unless(#role) };
+                        }
+                    }
                     if let Some(default_value) = default_value {
                         match_row = quote! { #match_row.default_value(#default_value) };
                     }
@@ -318,6 +454,15 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
         }
     }
 
+    if let Some(tenant_key) = &tenant_key {
+        if !column_names.contains(&tenant_key.value()) {
+            let msg = format!("tenant_key `{}` is not a column", tenant_key.value());
+            return Ok(quote::quote_spanned! {
+                tenant_key.span() => compile_error!(#msg);
+            });
+        }
+    }
+
     // Add tailing comma
     if !columns_select_as.is_empty() {
         columns_select_as.push_punct(Comma::default());
@@ -326,6 +471,45 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
         columns_save_as.push_punct(Comma::default());
     }
 
+    if table_iden {
+        graphql_fields.push(quote! { Self::Column::Table => None });
+    }
+    let graphql_entity = match graphql_name {
+        Some(name) if graphql && cfg!(feature = "graphql") => {
+            quote! {
+                #[automatically_derived]
+                impl sea_orm::graphql::GraphQLEntity for Entity {
+                    fn graphql_name() -> &'static str {
+                        #name
+                    }
+
+                    fn graphql_field(column: Self::Column) -> Option<&'static str> {
+                        match column {
+                            #graphql_fields
+                        }
+                    }
+                }
+            }
+        }
+        _ => quote! {},
+    };
+
+    // a Model with masked columns does not derive `Debug`, see `UnmaskContext`
+    let model_debug = if masked_values.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            #[automatically_derived]
+            impl std::fmt::Debug for Model {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.debug_struct("Model")
+                        #(#debug_fields)*
+                        .finish()
+                }
+            }
+        }
+    };
+
     let primary_key = {
         let auto_increment = auto_increment && primary_keys.len() == 1;
         let primary_key_types = if primary_key_types.len() == 1 {
@@ -390,8 +574,35 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
             }
         }
 
+        // the Rust types of the columns, checked against the fields of partial models
+        #[automatically_derived]
+        #[allow(non_snake_case, private_interfaces)]
+        impl Column {
+            #(#columns_value_type)*
+            #(#masked_values)*
+        }
+
+        #model_debug
+
         #entity_def
 
+        #graphql_entity
+
         #primary_key
     })
 }
+
+/// The clock of `created_at` or `updated_at`: the database's by default, or `= "client"`
+fn timestamp_clock(meta: &syn::meta::ParseNestedMeta) -> syn::Result<TokenStream> {
+    if !meta.input.peek(syn::Token![=]) {
+        return Ok(quote! { sea_orm::TimestampClock::Database });
+    }
+    let lit = meta.value()?.parse::<syn::LitStr>()?;
+    match lit.value().as_str() {
+        "database" => Ok(quote! { sea_orm::TimestampClock::Database }),
+        "client" => Ok(quote! { sea_orm::TimestampClock::Client }),
+        clock => Err(meta.error(format!(
+            "Invalid clock {clock:?}, expected \"database\" or \"client\""
+        ))),
+    }
+}
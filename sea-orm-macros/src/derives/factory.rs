@@ -0,0 +1,35 @@
+use super::util::{field_not_ignored, format_field_ident};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Data, DataStruct, Field, Fields, Type};
+
+/// Method to derive an implementation of [FakeActiveModel](sea_orm::FakeActiveModel)
+pub fn expand_derive_factory(ident: Ident, data: Data) -> syn::Result<TokenStream> {
+    let fields = match data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(named),
+            ..
+        }) => named.named,
+        _ => {
+            return Ok(quote_spanned! {
+                ident.span() => compile_error!("you can only derive DeriveFactory on structs");
+            })
+        }
+    }
+    .into_iter()
+    .filter(field_not_ignored);
+
+    let field: Vec<Ident> = fields.clone().map(format_field_ident).collect();
+    let ty: Vec<Type> = fields.map(|Field { ty, .. }| ty).collect();
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl sea_orm::FakeActiveModel for ActiveModel {
+            fn fake(rng: &mut sea_orm::FakeRng) -> Self {
+                Self {
+                    #(#field: sea_orm::ActiveValue::set(<#ty as sea_orm::Fake>::fake(rng))),*
+                }
+            }
+        }
+    ))
+}
@@ -1,32 +1,55 @@
 use super::util::GetMeta;
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
     ext::IdentExt, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Fields,
-    Generics, Meta,
+    Generics, Meta, Type,
 };
 
 #[derive(Debug)]
 enum Error {
     InputNotStruct,
+    TagNotSpecified,
+    VariantNotNewtype(Span),
+    Syn(syn::Error),
 }
 
 pub(super) enum ItemType {
     Flat,
     Skip,
     Nested,
+    /// nested `Vec`, merged across rows
+    NestedVec,
+}
+
+/// Whether a nested field is a `Vec`, hydrated from the rows of a one-to-many join
+pub(super) fn is_vec(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "Vec"),
+        _ => false,
+    }
 }
 
 pub(super) struct DeriveFromQueryResult {
     pub ident: syn::Ident,
     pub generics: Generics,
     pub fields: Vec<FromQueryResultItem>,
+    /// the fields are the elements of a tuple struct
+    pub tuple: bool,
 }
 
 pub(super) struct FromQueryResultItem {
     pub typ: ItemType,
     pub ident: Ident,
     pub alias: Option<String>,
+    /// the value of the field when its column is absent from the result set
+    pub default: Option<TokenStream>,
+    /// the prefix of the columns of a nested model
+    pub nested_prefix: Option<String>,
 }
 
 /// Initially, we try to obtain the value for each field and check if it is an ordinary DB error
@@ -38,24 +61,54 @@ pub(super) struct FromQueryResultItem {
 /// since structs embedding the current one might have wrapped the current one in an `Option`.
 /// In this case, we do not want to swallow other errors, which are very likely to actually be
 /// programming errors that should be noticed (and fixed).
-struct TryFromQueryResultCheck<'a>(bool, &'a FromQueryResultItem);
+struct TryFromQueryResultCheck<'a>(bool, Option<usize>, &'a FromQueryResultItem);
 
 impl ToTokens for TryFromQueryResultCheck<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let FromQueryResultItem { ident, typ, alias } = self.1;
+        let FromQueryResultItem {
+            ident,
+            typ,
+            alias,
+            default,
+            nested_prefix,
+        } = self.2;
 
         match typ {
             ItemType::Flat => {
-                let name = alias
-                    .to_owned()
-                    .unwrap_or_else(|| ident.unraw().to_string());
-                tokens.extend(quote! {
-                    let #ident = match row.try_get_nullable(pre, #name) {
+                let (get, has_column) = match (alias, self.1) {
+                    (None, Some(index)) => (
+                        quote!(row.try_get_by_index_nullable(#index)),
+                        quote!(row.column_names().len() > #index),
+                    ),
+                    _ => {
+                        let name = alias
+                            .to_owned()
+                            .unwrap_or_else(|| ident.unraw().to_string());
+                        (
+                            quote!(row.try_get_nullable(pre, #name)),
+                            quote!(row.has_column(pre, #name)),
+                        )
+                    }
+                };
+                let get = quote! {
+                    match #get {
                         Err(v @ sea_orm::TryGetError::DbErr(_)) => {
                             return Err(v);
                         }
                         v => v,
-                    };
+                    }
+                };
+                tokens.extend(match default {
+                    Some(default) => quote! {
+                        let #ident = if #has_column {
+                            #get
+                        } else {
+                            Ok(#default)
+                        };
+                    },
+                    None => quote! {
+                        let #ident = #get;
+                    },
                 });
             }
             ItemType::Skip => {
@@ -63,8 +116,10 @@ impl ToTokens for TryFromQueryResultCheck<'_> {
                     let #ident = std::default::Default::default();
                 });
             }
-            ItemType::Nested => {
-                let prefix = if self.0 {
+            ItemType::Nested | ItemType::NestedVec => {
+                let prefix = if let Some(nested_prefix) = nested_prefix {
+                    quote! { &format!("{pre}{}", #nested_prefix) }
+                } else if self.0 {
                     let name = ident.unraw().to_string();
                     quote! { &format!("{pre}{}_", #name) }
                 } else {
@@ -83,21 +138,22 @@ impl ToTokens for TryFromQueryResultCheck<'_> {
     }
 }
 
-struct TryFromQueryResultAssignment<'a>(&'a FromQueryResultItem);
+struct TryFromQueryResultAssignment<'a>(bool, &'a FromQueryResultItem);
 
 impl ToTokens for TryFromQueryResultAssignment<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let FromQueryResultItem { ident, typ, .. } = self.0;
+        let FromQueryResultItem { ident, typ, .. } = self.1;
+        let member = if self.0 { quote!() } else { quote!(#ident:) };
 
         match typ {
-            ItemType::Flat | ItemType::Nested => {
+            ItemType::Flat | ItemType::Nested | ItemType::NestedVec => {
                 tokens.extend(quote! {
-                    #ident: #ident?,
+                    #member #ident?,
                 });
             }
             ItemType::Skip => {
                 tokens.extend(quote! {
-                    #ident,
+                    #member #ident,
                 });
             }
         }
@@ -113,18 +169,24 @@ impl DeriveFromQueryResult {
             ..
         }: DeriveInput,
     ) -> Result<Self, Error> {
-        let parsed_fields = match data {
+        let (parsed_fields, tuple) = match data {
             Data::Struct(DataStruct {
                 fields: Fields::Named(named),
                 ..
-            }) => named.named,
+            }) => (named.named, false),
+            Data::Struct(DataStruct {
+                fields: Fields::Unnamed(unnamed),
+                ..
+            }) => (unnamed.unnamed, true),
             _ => return Err(Error::InputNotStruct),
         };
 
         let mut fields = Vec::with_capacity(parsed_fields.len());
-        for parsed_field in parsed_fields {
+        for (index, parsed_field) in parsed_fields.into_iter().enumerate() {
             let mut typ = ItemType::Flat;
             let mut alias = None;
+            let mut default = None;
+            let mut nested_prefix = None;
             for attr in parsed_field.attrs.iter() {
                 if !attr.path().is_ident("sea_orm") {
                     continue;
@@ -135,21 +197,47 @@ impl DeriveFromQueryResult {
                         if meta.exists("skip") {
                             typ = ItemType::Skip;
                         } else if meta.exists("nested") {
-                            typ = ItemType::Nested;
-                        } else {
-                            alias = meta.get_as_kv("from_alias");
+                            typ = if is_vec(&parsed_field.ty) {
+                                ItemType::NestedVec
+                            } else {
+                                ItemType::Nested
+                            };
+                        } else if let Some(s) = meta.get_as_kv("nested_prefix") {
+                            typ = if is_vec(&parsed_field.ty) {
+                                ItemType::NestedVec
+                            } else {
+                                ItemType::Nested
+                            };
+                            nested_prefix = Some(s);
+                        } else if meta.exists("default") {
+                            default = Some(quote!(std::default::Default::default()));
+                        } else if let Some(s) = meta.get_as_kv("default_expr") {
+                            let expr: syn::Expr = syn::parse_str(&s).map_err(Error::Syn)?;
+                            default = Some(expr.into_token_stream());
+                        } else if let Some(s) = meta.get_as_kv("from_alias") {
+                            alias = Some(s);
                         }
                     }
                 }
             }
-            let ident = format_ident!("{}", parsed_field.ident.unwrap().to_string());
-            fields.push(FromQueryResultItem { typ, ident, alias });
+            let ident = match parsed_field.ident {
+                Some(ident) => format_ident!("{}", ident.to_string()),
+                None => tuple_field_ident(index),
+            };
+            fields.push(FromQueryResultItem {
+                typ,
+                ident,
+                alias,
+                default,
+                nested_prefix,
+            });
         }
 
         Ok(Self {
             ident,
             generics,
             fields,
+            tuple,
         })
     }
 
@@ -162,15 +250,30 @@ impl DeriveFromQueryResult {
             ident,
             generics,
             fields,
+            tuple,
         } = self;
 
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+        // the elements of a tuple struct are read by their position, unless selected by a partial
+        // model under their names
         let ident_try_init: Vec<_> = fields
             .iter()
-            .map(|s| TryFromQueryResultCheck(prefix, s))
+            .enumerate()
+            .map(|(index, s)| {
+                TryFromQueryResultCheck(prefix, (*tuple && !prefix).then_some(index), s)
+            })
+            .collect();
+        let ident_try_assign: Vec<_> = fields
+            .iter()
+            .map(|s| TryFromQueryResultAssignment(*tuple, s))
             .collect();
-        let ident_try_assign: Vec<_> = fields.iter().map(TryFromQueryResultAssignment).collect();
+        let construct = if *tuple {
+            quote!(Self(#(#ident_try_assign)*))
+        } else {
+            quote!(Self { #(#ident_try_assign)* })
+        };
+        let merge_row = self.impl_merge_row();
 
         quote!(
             #[automatically_derived]
@@ -182,22 +285,210 @@ impl DeriveFromQueryResult {
                 fn from_query_result_nullable(row: &sea_orm::QueryResult, pre: &str) -> std::result::Result<Self, sea_orm::TryGetError> {
                     #(#ident_try_init)*
 
-                    Ok(Self {
-                        #(#ident_try_assign)*
-                    })
+                    Ok(#construct)
                 }
+
+                #merge_row
             }
         )
     }
+
+    /// Merge rows with the same values in the fields other than the nested `Vec`s, appending the
+    /// items of the `Vec`s
+    fn impl_merge_row(&self) -> TokenStream {
+        let members = |filter: fn(&ItemType) -> bool| -> Vec<TokenStream> {
+            self.fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| filter(&field.typ))
+                .map(|(index, field)| {
+                    if self.tuple {
+                        syn::Index::from(index).into_token_stream()
+                    } else {
+                        field.ident.to_token_stream()
+                    }
+                })
+                .collect()
+        };
+        let vecs = members(|typ| matches!(typ, ItemType::NestedVec));
+        if vecs.is_empty() {
+            return quote!();
+        }
+        let same = members(|typ| matches!(typ, ItemType::Flat | ItemType::Nested));
+        let same = if same.is_empty() {
+            quote!(true)
+        } else {
+            quote!(#(self.#same == next.#same)&&*)
+        };
+
+        quote! {
+            fn merge_row(&mut self, next: Self) -> Option<Self> {
+                if #same {
+                    #(sea_orm::FromQueryResult::merge_row(&mut self.#vecs, next.#vecs);)*
+                    None
+                } else {
+                    Some(next)
+                }
+            }
+        }
+    }
+}
+
+/// An enum decoded from the rows of a single table inheritance, by the value of its tag column
+struct DeriveFromQueryResultEnum {
+    ident: syn::Ident,
+    generics: Generics,
+    tag: String,
+    variants: Vec<FromQueryResultVariant>,
+}
+
+struct FromQueryResultVariant {
+    ident: Ident,
+    tag_value: String,
+    /// the variant holds a nested model, or is a unit variant
+    nested: bool,
+}
+
+impl DeriveFromQueryResultEnum {
+    fn new(
+        DeriveInput {
+            ident,
+            data,
+            generics,
+            attrs,
+            ..
+        }: DeriveInput,
+    ) -> Result<Self, Error> {
+        let Data::Enum(data) = data else {
+            return Err(Error::InputNotStruct);
+        };
+
+        let mut tag = None;
+        for attr in attrs.iter() {
+            if !attr.path().is_ident("sea_orm") {
+                continue;
+            }
+            if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated) {
+                for meta in list.iter() {
+                    if let Some(s) = meta.get_as_kv("tag") {
+                        tag = Some(s);
+                    }
+                }
+            }
+        }
+        let tag = tag.ok_or(Error::TagNotSpecified)?;
+
+        let mut variants = Vec::with_capacity(data.variants.len());
+        for variant in data.variants {
+            let nested = match &variant.fields {
+                Fields::Unit => false,
+                Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => true,
+                _ => return Err(Error::VariantNotNewtype(variant.ident.span())),
+            };
+            let mut tag_value = variant.ident.unraw().to_string();
+            for attr in variant.attrs.iter() {
+                if !attr.path().is_ident("sea_orm") {
+                    continue;
+                }
+                if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+                {
+                    for meta in list.iter() {
+                        if let Some(s) = meta.get_as_kv("tag_value") {
+                            tag_value = s;
+                        }
+                    }
+                }
+            }
+            variants.push(FromQueryResultVariant {
+                ident: variant.ident,
+                tag_value,
+                nested,
+            });
+        }
+
+        Ok(Self {
+            ident,
+            generics,
+            tag,
+            variants,
+        })
+    }
+
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let Self {
+            ident,
+            generics,
+            tag,
+            variants,
+        } = self;
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let arms = variants.iter().map(
+            |FromQueryResultVariant {
+                 ident,
+                 tag_value,
+                 nested,
+             }| {
+                if *nested {
+                    quote! {
+                        #tag_value => Ok(Self::#ident(
+                            sea_orm::FromQueryResult::from_query_result_nullable(row, pre)?,
+                        )),
+                    }
+                } else {
+                    quote! {
+                        #tag_value => Ok(Self::#ident),
+                    }
+                }
+            },
+        );
+        let unknown = format!("unknown `{tag}` of `{ident}`: {{}}");
+
+        Ok(quote!(
+            #[automatically_derived]
+            impl #impl_generics sea_orm::FromQueryResult for #ident #ty_generics #where_clause {
+                fn from_query_result(row: &sea_orm::QueryResult, pre: &str) -> std::result::Result<Self, sea_orm::DbErr> {
+                    Ok(Self::from_query_result_nullable(row, pre)?)
+                }
+
+                fn from_query_result_nullable(row: &sea_orm::QueryResult, pre: &str) -> std::result::Result<Self, sea_orm::TryGetError> {
+                    let tag: String = row.try_get_nullable(pre, #tag)?;
+                    match tag.as_str() {
+                        #(#arms)*
+                        _ => Err(sea_orm::TryGetError::DbErr(sea_orm::DbErr::Type(format!(#unknown, tag)))),
+                    }
+                }
+            }
+        ))
+    }
+}
+
+/// The local variable holding an element of a tuple struct
+pub(super) fn tuple_field_ident(index: usize) -> Ident {
+    format_ident!("_{}", index)
 }
 
 pub fn expand_derive_from_query_result(input: DeriveInput) -> syn::Result<TokenStream> {
     let ident_span = input.ident.span();
 
-    match DeriveFromQueryResult::new(input) {
-        Ok(partial_model) => partial_model.expand(),
+    let derived = if matches!(input.data, Data::Enum(_)) {
+        DeriveFromQueryResultEnum::new(input).map(|derive| derive.expand())
+    } else {
+        DeriveFromQueryResult::new(input).map(|derive| derive.expand())
+    };
+
+    match derived {
+        Ok(expanded) => expanded,
         Err(Error::InputNotStruct) => Ok(quote_spanned! {
-            ident_span => compile_error!("you can only derive `FromQueryResult` on named struct");
+            ident_span => compile_error!("you can only derive `FromQueryResult` on struct with fields or enum");
+        }),
+        Err(Error::TagNotSpecified) => Ok(quote_spanned! {
+            ident_span => compile_error!("you need to specify the `tag` column of the enum");
+        }),
+        Err(Error::VariantNotNewtype(span)) => Ok(quote_spanned! {
+            span => compile_error!("the variants of the enum can only hold one nested model, or be unit variants");
         }),
+        Err(Error::Syn(err)) => Err(err),
     }
 }
@@ -0,0 +1,69 @@
+use super::util::{escape_rust_keyword, skip_meta_value, trim_starting_raw_identifier};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Data, DataStruct, Fields, LitStr};
+
+/// Method to derive an implementation of [HierarchyModel](sea_orm::HierarchyModel)
+pub fn expand_derive_hierarchy(ident: Ident, data: Data) -> syn::Result<TokenStream> {
+    let fields = match data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(named),
+            ..
+        }) => named.named,
+        _ => {
+            return Ok(quote_spanned! {
+                ident.span() => compile_error!("you can only derive DeriveHierarchy on structs");
+            })
+        }
+    };
+
+    let mut parent = None;
+    for field in fields {
+        let field_ident = field.ident.clone().unwrap();
+        let mut column = Ident::new(
+            &escape_rust_keyword(trim_starting_raw_identifier(&field_ident).to_upper_camel_case()),
+            Span::call_site(),
+        );
+        let mut is_parent = false;
+        for attr in field.attrs.iter() {
+            if !attr.path().is_ident("sea_orm") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("parent_key") {
+                    is_parent = true;
+                } else if meta.path.is_ident("enum_name") {
+                    column = syn::parse_str(&meta.value()?.parse::<LitStr>()?.value())?;
+                } else {
+                    skip_meta_value(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+        if is_parent {
+            if parent.is_some() {
+                return Err(syn::Error::new_spanned(
+                    field_ident,
+                    "only one field can be the `parent_key`",
+                ));
+            }
+            parent = Some(column);
+        }
+    }
+
+    let Some(parent_column) = parent else {
+        return Ok(quote_spanned! {
+            ident.span() => compile_error!("DeriveHierarchy needs a field marked `#[sea_orm(parent_key)]`");
+        });
+    };
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl sea_orm::HierarchyModel for #ident {
+            fn parent_column() -> <<Self as sea_orm::ModelTrait>::Entity as sea_orm::EntityTrait>::Column {
+                Column::#parent_column
+            }
+        }
+    ))
+}
@@ -12,7 +12,10 @@ enum Error {
 pub(super) struct DeriveIntoActiveModel {
     pub ident: syn::Ident,
     pub active_model: Option<syn::Type>,
-    pub fields: Vec<syn::Ident>,
+    /// Each field, with the field of the active model it's written to
+    pub fields: Vec<(syn::Ident, syn::Ident)>,
+    /// Leave the other fields `NotSet`, instead of the defaults of `ActiveModelBehavior::new`
+    pub not_set_others: bool,
 }
 
 impl DeriveIntoActiveModel {
@@ -43,13 +46,17 @@ impl DeriveIntoActiveModel {
 
         let field_idents = fields
             .iter()
-            .map(|field| field.ident.as_ref().unwrap().clone())
+            .map(|field| {
+                let ident = field.ident.as_ref().unwrap().clone();
+                (ident.clone(), ident)
+            })
             .collect();
 
         Ok(Self {
             ident: input.ident,
             active_model,
             fields: field_idents,
+            not_set_others: false,
         })
     }
 
@@ -64,6 +71,7 @@ impl DeriveIntoActiveModel {
             ident,
             active_model,
             fields,
+            not_set_others,
         } = self;
 
         let mut active_model_ident = active_model
@@ -90,12 +98,18 @@ impl DeriveIntoActiveModel {
             quote!()
         };
 
-        let expanded_fields = fields.iter().map(|field_ident| {
+        let expanded_fields = fields.iter().map(|(field_ident, active_field)| {
             quote!(
-                sea_orm::IntoActiveValue::<_>::into_active_value(self.#field_ident).into()
+                #active_field: sea_orm::IntoActiveValue::<_>::into_active_value(self.#field_ident).into()
             )
         });
 
+        let others = if *not_set_others {
+            quote!(<#active_model_ident as sea_orm::ActiveModelTrait>::default())
+        } else {
+            quote!(::std::default::Default::default())
+        };
+
         quote!(
             #type_alias_definition
 
@@ -103,8 +117,8 @@ impl DeriveIntoActiveModel {
             impl sea_orm::IntoActiveModel<#active_model_ident> for #ident {
                 fn into_active_model(self) -> #active_model_ident {
                     #active_model_ident {
-                        #( #fields: #expanded_fields, )*
-                        ..::std::default::Default::default()
+                        #( #expanded_fields, )*
+                        ..#others
                     }
                 }
             }
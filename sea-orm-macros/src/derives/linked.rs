@@ -0,0 +1,67 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Attribute, Data, Expr, LitStr, Type};
+
+/// Method to derive an implementation of [Linked](sea_orm::Linked)
+pub fn expand_derive_linked(
+    ident: Ident,
+    data: Data,
+    attrs: Vec<Attribute>,
+) -> syn::Result<TokenStream> {
+    if !matches!(data, Data::Struct(_)) {
+        return Ok(quote_spanned! {
+            ident.span() => compile_error!("you can only derive DeriveLinked on structs");
+        });
+    }
+
+    let mut from_entity = quote!(Entity);
+    let mut to_entity = None;
+    let mut through = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("sea_orm")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("from") {
+                from_entity = meta
+                    .value()?
+                    .parse::<LitStr>()?
+                    .parse::<Type>()
+                    .map(|ty| quote!(#ty))?;
+            } else if meta.path.is_ident("to") {
+                to_entity = Some(meta.value()?.parse::<LitStr>()?.parse::<Type>()?);
+            } else if meta.path.is_ident("through") {
+                through.push(meta.value()?.parse::<LitStr>()?.parse::<Expr>()?);
+            } else {
+                return Err(meta.error(format!(
+                    "Unknown attribute parameter found: {:?}",
+                    meta.path.get_ident()
+                )));
+            }
+            Ok(())
+        })?;
+    }
+
+    let Some(to_entity) = to_entity else {
+        return Ok(quote_spanned! {
+            ident.span() => compile_error!("DeriveLinked needs the target entity `#[sea_orm(to = \"...\")]`");
+        });
+    };
+    if through.is_empty() {
+        return Ok(quote_spanned! {
+            ident.span() => compile_error!("DeriveLinked needs at least one relation `#[sea_orm(through = \"...\")]`");
+        });
+    }
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl sea_orm::Linked for #ident {
+            type FromEntity = #from_entity;
+
+            type ToEntity = #to_entity;
+
+            fn link(&self) -> Vec<sea_orm::LinkDef> {
+                sea_orm::link_through::<Self::FromEntity>([
+                    #(sea_orm::RelationTrait::def(&#through)),*
+                ])
+            }
+        }
+    ))
+}
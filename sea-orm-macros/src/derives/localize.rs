@@ -0,0 +1,44 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Data, DataStruct, Fields};
+
+/// Method to derive an implementation of [Localize](sea_orm::Localize)
+pub fn expand_derive_localize(ident: Ident, data: Data) -> syn::Result<TokenStream> {
+    let fields = match data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(named),
+            ..
+        }) => named.named,
+        _ => {
+            return Ok(quote_spanned! {
+                ident.span() => compile_error!("you can only derive DeriveLocalize on structs");
+            })
+        }
+    };
+
+    let mut translatable = Vec::new();
+    for field in fields {
+        for attr in field.attrs.iter() {
+            if !attr.path().is_ident("sea_orm") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("translatable") {
+                    translatable.push(field.ident.clone().unwrap());
+                } else {
+                    super::util::skip_meta_value(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl sea_orm::Localize for #ident {
+            fn localize(&mut self, locale: &sea_orm::Locale) {
+                #(sea_orm::Localize::localize(&mut self.#translatable, locale);)*
+            }
+        }
+    ))
+}
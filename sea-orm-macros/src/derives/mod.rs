@@ -8,15 +8,25 @@ mod column;
 mod derive_iden;
 mod entity;
 mod entity_model;
+mod factory;
 mod from_query_result;
+mod hierarchy;
 mod into_active_model;
+mod linked;
+mod localize;
 mod migration;
 mod model;
+mod model_serde;
+mod morph_to;
 mod partial_model;
 mod primary_key;
 mod related_entity;
 mod relation;
 mod sql_type_match;
+mod state_machine;
+#[cfg(feature = "utoipa")]
+mod to_schema;
+mod tree;
 mod try_getable_from_json;
 mod util;
 mod value_type;
@@ -29,13 +39,23 @@ pub use column::*;
 pub use derive_iden::*;
 pub use entity::*;
 pub use entity_model::*;
+pub use factory::*;
 pub use from_query_result::*;
+pub use hierarchy::*;
 pub use into_active_model::*;
+pub use linked::*;
+pub use localize::*;
 pub use migration::*;
 pub use model::*;
+pub use model_serde::*;
+pub use morph_to::*;
 pub use partial_model::*;
 pub use primary_key::*;
 pub use related_entity::*;
 pub use relation::*;
+pub use state_machine::*;
+#[cfg(feature = "utoipa")]
+pub use to_schema::*;
+pub use tree::*;
 pub use try_getable_from_json::*;
 pub use value_type::*;
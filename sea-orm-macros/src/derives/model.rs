@@ -1,12 +1,12 @@
 use super::{
     attributes::derive_attr,
-    util::{escape_rust_keyword, field_not_ignored, trim_starting_raw_identifier},
+    util::{escape_rust_keyword, field_not_ignored, skip_meta_value, trim_starting_raw_identifier},
 };
 use heck::ToUpperCamelCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
 use std::iter::FromIterator;
-use syn::{Expr, Ident, LitStr};
+use syn::{Ident, LitStr};
 
 enum Error {
     InputNotStruct,
@@ -60,10 +60,7 @@ impl DeriveModel {
                                 ident = syn::parse_str(&meta.value()?.parse::<LitStr>()?.value())
                                     .unwrap();
                             } else {
-                                // Reads the value expression to advance the parse stream.
-                                // Some parameters, such as `primary_key`, do not have any value,
-                                // so ignoring an error occurred here.
-                                let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
+                                skip_meta_value(&meta)?;
                             }
 
                             Ok(())
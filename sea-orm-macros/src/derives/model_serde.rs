@@ -0,0 +1,217 @@
+use super::util::{skip_meta_value, trim_starting_raw_identifier};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parenthesized, parse_quote, punctuated::Punctuated, token::Comma, Attribute, Data, DeriveInput,
+    Expr, ExprLit, Field, Lit, Meta,
+};
+
+/// The serde attributes given with `#[sea_orm(serde(..))]` on a Model and its fields
+pub(crate) struct SerdeAttrs {
+    container: Vec<Attribute>,
+    fields: Vec<Vec<Attribute>>,
+}
+
+/// Container arguments naming the fields they apply to, e.g. `skip = "password_hash"`
+const FIELD_POLICIES: [&str; 3] = ["skip", "skip_serializing", "skip_deserializing"];
+
+impl SerdeAttrs {
+    /// Parse the attributes, `None` if there are none
+    pub(crate) fn parse(input: &DeriveInput) -> syn::Result<Option<Self>> {
+        let Data::Struct(data) = &input.data else {
+            return Ok(None);
+        };
+        let names: Vec<String> = data
+            .fields
+            .iter()
+            .map(|field| {
+                field
+                    .ident
+                    .as_ref()
+                    .map(trim_starting_raw_identifier)
+                    .unwrap_or_default()
+            })
+            .collect();
+        let mut found = false;
+        let mut container = Vec::new();
+        let mut fields = vec![Vec::new(); names.len()];
+
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("sea_orm"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if !meta.path.is_ident("serde") {
+                    return skip_meta_value(&meta);
+                }
+                found = true;
+                let content;
+                parenthesized!(content in meta.input);
+                for item in Punctuated::<Meta, Comma>::parse_terminated(&content)? {
+                    let Meta::NameValue(name_value) = &item else {
+                        container.push(parse_quote!(#[serde(#item)]));
+                        continue;
+                    };
+                    let Some(policy) = FIELD_POLICIES
+                        .iter()
+                        .find(|policy| name_value.path.is_ident(policy))
+                    else {
+                        container.push(parse_quote!(#[serde(#item)]));
+                        continue;
+                    };
+                    let Expr::Lit(ExprLit {
+                        lit: Lit::Str(list),
+                        ..
+                    }) = &name_value.value
+                    else {
+                        return Err(syn::Error::new_spanned(
+                            &name_value.value,
+                            format!("expected the fields to {policy}, e.g. \"password_hash\""),
+                        ));
+                    };
+                    let policy = format_ident!("{}", policy);
+                    for name in list.value().split(',').map(str::trim) {
+                        let bare = trim_starting_raw_identifier(name);
+                        let i = names.iter().position(|n| *n == bare).ok_or_else(|| {
+                            syn::Error::new_spanned(list, format!("no field named `{name}`"))
+                        })?;
+                        fields[i].push(parse_quote!(#[serde(#policy)]));
+                    }
+                }
+                Ok(())
+            })?;
+        }
+
+        for (field, attrs) in data.fields.iter().zip(fields.iter_mut()) {
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("sea_orm"))
+            {
+                attr.parse_nested_meta(|meta| {
+                    if !meta.path.is_ident("serde") {
+                        return skip_meta_value(&meta);
+                    }
+                    found = true;
+                    let content;
+                    parenthesized!(content in meta.input);
+                    let content: TokenStream = content.parse()?;
+                    attrs.push(parse_quote!(#[serde(#content)]));
+                    Ok(())
+                })?;
+            }
+        }
+
+        Ok(found.then_some(Self { container, fields }))
+    }
+
+    /// Add the attributes to a copy of the Model
+    pub(crate) fn apply(&self, input: &mut DeriveInput) {
+        input.attrs.extend(self.container.iter().cloned());
+        if let Data::Struct(data) = &mut input.data {
+            for (field, attrs) in data.fields.iter_mut().zip(self.fields.iter()) {
+                field.attrs.extend(attrs.iter().cloned());
+            }
+        }
+    }
+}
+
+/// Method to implement `serde::Serialize` and `serde::Deserialize` for a Model with the
+/// attributes given with `#[sea_orm(serde(..))]`, if any
+pub fn expand_model_serde(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Some(serde_attrs) = SerdeAttrs::parse(input)? else {
+        return Ok(TokenStream::new());
+    };
+    let ident = &input.ident;
+    let remote = ident.to_string();
+
+    let mut shadow = input.clone();
+    shadow.ident = format_ident!("__SeaOrmSerde");
+    shadow.attrs.clear();
+    if let Data::Struct(data) = &mut shadow.data {
+        for field in data.fields.iter_mut() {
+            field.attrs.clear();
+        }
+    }
+    serde_attrs.apply(&mut shadow);
+
+    // the fields of masked columns are serialized through their `MaskedValue`
+    let mut masked_serializers = Vec::new();
+    if let (Data::Struct(data), Data::Struct(shadow_data)) = (&input.data, &mut shadow.data) {
+        for (field, shadow_field) in data.fields.iter().zip(shadow_data.fields.iter_mut()) {
+            let Some(ident) = &field.ident else {
+                continue;
+            };
+            if !is_masked(field)? {
+                continue;
+            }
+            let name = trim_starting_raw_identifier(ident);
+            let masked_value = format_ident!("__sea_orm_masked_{}", name);
+            let serializer = format_ident!("__sea_orm_serialize_{}", name);
+            let serializer_name = serializer.to_string();
+            shadow_field
+                .attrs
+                .push(parse_quote!(#[serde(serialize_with = #serializer_name)]));
+            masked_serializers.push(quote!(
+                fn #serializer<T, S>(value: &T, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    T: serde::Serialize,
+                    S: serde::Serializer,
+                {
+                    serde::Serialize::serialize(&Column::#masked_value(value), serializer)
+                }
+            ));
+        }
+    }
+
+    Ok(quote!(
+        #[automatically_derived]
+        const _: () = {
+            #(#masked_serializers)*
+
+            #[allow(dead_code)]
+            #[derive(serde::Serialize, serde::Deserialize)]
+            #[serde(remote = #remote)]
+            #shadow
+
+            impl serde::Serialize for #ident {
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    __SeaOrmSerde::serialize(self, serializer)
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    __SeaOrmSerde::deserialize(deserializer)
+                }
+            }
+        };
+    ))
+}
+
+/// Whether a field is marked `#[sea_orm(masked)]` and not ignored
+fn is_masked(field: &Field) -> syn::Result<bool> {
+    let (mut masked, mut ignore) = (false, false);
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("sea_orm"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("masked") {
+                masked = true;
+            } else if meta.path.is_ident("ignore") {
+                ignore = true;
+            }
+            skip_meta_value(&meta)
+        })?;
+    }
+    Ok(masked && !ignore)
+}
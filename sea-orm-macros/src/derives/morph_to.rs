@@ -0,0 +1,84 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Attribute, Data, DataEnum, Fields, LitStr, Type};
+
+/// Method to derive an implementation of [MorphTo](sea_orm::MorphTo)
+pub fn expand_derive_morph_to(
+    ident: Ident,
+    data: Data,
+    attrs: Vec<Attribute>,
+) -> syn::Result<TokenStream> {
+    let variants = match data {
+        Data::Enum(DataEnum { variants, .. }) => variants,
+        _ => {
+            return Ok(quote_spanned! {
+                ident.span() => compile_error!("you can only derive DeriveMorphTo on enums");
+            })
+        }
+    };
+
+    let mut model = quote!(Model);
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("sea_orm")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("model") {
+                model = meta.value()?.parse::<LitStr>()?.parse::<Type>().map(|ty| quote!(#ty))?;
+            } else {
+                return Err(meta.error(format!(
+                    "Unknown attribute parameter found: {:?}",
+                    meta.path.get_ident()
+                )));
+            }
+            Ok(())
+        })?;
+    }
+
+    let mut variant_idents = Vec::new();
+    let mut variant_models = Vec::new();
+    for variant in variants {
+        match variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                variant_idents.push(variant.ident);
+                variant_models.push(fields.unnamed.into_iter().map(|field| field.ty));
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "each variant must hold the Model of a related entity",
+                ))
+            }
+        }
+    }
+    let variant_models = variant_models.into_iter().flatten();
+
+    Ok(quote!(
+        #[automatically_derived]
+        #[sea_orm::prelude::async_trait::async_trait]
+        impl sea_orm::MorphTo<#model> for #ident {
+            async fn load<C>(
+                models: &[#model],
+                db: &C,
+            ) -> std::result::Result<Vec<Option<Self>>, sea_orm::DbErr>
+            where
+                C: sea_orm::ReadConnectionTrait,
+            {
+                use sea_orm::LoaderTrait;
+
+                let mut result: Vec<Option<Self>> = models.iter().map(|_| None).collect();
+                #(
+                    let related = models
+                        .load_one(
+                            <<#variant_models as sea_orm::ModelTrait>::Entity as std::default::Default>::default(),
+                            db,
+                        )
+                        .await?;
+                    for (slot, related) in result.iter_mut().zip(related) {
+                        if let Some(related) = related {
+                            *slot = Some(Self::#variant_idents(related));
+                        }
+                    }
+                )*
+                Ok(result)
+            }
+        }
+    ))
+}
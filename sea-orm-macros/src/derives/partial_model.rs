@@ -1,12 +1,13 @@
-use heck::ToUpperCamelCase;
+use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::collections::BTreeMap;
 use syn::{
     ext::IdentExt, punctuated::Punctuated, spanned::Spanned, token::Comma, Expr, Meta, Type,
 };
 
 use super::from_query_result::{
-    DeriveFromQueryResult, FromQueryResultItem, ItemType as FqrItemType,
+    is_vec, tuple_field_ident, DeriveFromQueryResult, FromQueryResultItem, ItemType as FqrItemType,
 };
 use super::into_active_model::DeriveIntoActiveModel;
 use super::util::GetMeta;
@@ -15,52 +16,80 @@ use super::util::GetMeta;
 enum Error {
     InputNotStruct,
     EntityNotSpecified,
-    NotSupportGeneric(Span),
+    GenericIntoActiveModel(Span),
     OverlappingAttributes(Span),
+    TupleIntoActiveModel,
+    ContextNotSpecified(Span),
     Syn(syn::Error),
 }
 
 #[derive(Debug, PartialEq, Eq)]
 enum ColumnAs {
-    /// alias from a column in model
+    /// alias from a column in model, by the field name, or by the position of a tuple element
     Col {
         col: Option<syn::Ident>,
         field: syn::Ident,
+        position: Option<usize>,
+        typ: Type,
+    },
+    /// alias from a column of another entity, joined in the query
+    JoinedCol {
+        col: syn::Path,
+        field: syn::Ident,
+        typ: Type,
     },
     /// from an expr
     Expr {
         expr: syn::Expr,
         field: syn::Ident,
     },
-    /// nesting another struct
+    /// from an expr built by a function from the context of the query
+    ExprFn {
+        func: syn::Path,
+        field: syn::Ident,
+    },
+    /// nesting another struct, whose columns are aliased with the prefix
     Nested {
         typ: Type,
         field: syn::Ident,
+        prefix: String,
     },
     Skip(syn::Ident),
 }
 
+/// The column of `from_col`
+enum FromCol {
+    /// a column of the entity, by name
+    Col(syn::Ident),
+    /// a column of a joined entity, by path
+    Joined(syn::Path),
+}
+
 struct DerivePartialModel {
     entity: Option<syn::Type>,
     active_model: Option<syn::Type>,
     alias: Option<String>,
+    context: Option<syn::Type>,
     ident: syn::Ident,
+    generics: syn::Generics,
     fields: Vec<ColumnAs>,
+    tuple: bool,
     from_query_result: bool,
     into_active_model: bool,
+    skip_type_check: bool,
 }
 
 impl DerivePartialModel {
     fn new(input: syn::DeriveInput) -> Result<Self, Error> {
-        if !input.generics.params.is_empty() {
-            return Err(Error::NotSupportGeneric(input.generics.params.span()));
-        }
-
-        let fields = match input.data {
+        let (fields, tuple) = match input.data {
             syn::Data::Struct(syn::DataStruct {
                 fields: syn::Fields::Named(syn::FieldsNamed { named, .. }),
                 ..
-            }) => named,
+            }) => (named, false),
+            syn::Data::Struct(syn::DataStruct {
+                fields: syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }),
+                ..
+            }) => (unnamed, true),
             _ => return Err(Error::InputNotStruct),
         };
 
@@ -68,8 +97,11 @@ impl DerivePartialModel {
         let mut entity_string = String::new();
         let mut active_model = None;
         let mut alias = None;
+        let mut context = None;
+        let mut separator = "_".to_owned();
         let mut from_query_result = false;
         let mut into_active_model = false;
+        let mut skip_type_check = false;
 
         for attr in input.attrs.iter() {
             if !attr.path().is_ident("sea_orm") {
@@ -83,15 +115,29 @@ impl DerivePartialModel {
                         entity_string = s;
                     } else if let Some(s) = meta.get_as_kv("alias") {
                         alias = Some(s);
+                    } else if let Some(s) = meta.get_as_kv("context") {
+                        context = Some(syn::parse_str::<syn::Type>(&s).map_err(Error::Syn)?);
+                    } else if let Some(s) = meta.get_as_kv("separator") {
+                        separator = s;
                     } else if meta.exists("from_query_result") {
                         from_query_result = true;
                     } else if meta.exists("into_active_model") {
                         into_active_model = true;
+                    } else if meta.exists("skip_type_check") {
+                        skip_type_check = true;
                     }
                 }
             }
         }
 
+        if into_active_model && tuple {
+            return Err(Error::TupleIntoActiveModel);
+        }
+
+        if into_active_model && !input.generics.params.is_empty() {
+            return Err(Error::GenericIntoActiveModel(input.generics.params.span()));
+        }
+
         if into_active_model {
             active_model = Some(
                 syn::parse_str::<syn::Type>(&format!(
@@ -103,12 +149,14 @@ impl DerivePartialModel {
 
         let mut column_as_list = Vec::with_capacity(fields.len());
 
-        for field in fields {
+        for (index, field) in fields.into_iter().enumerate() {
             let field_span = field.span();
 
             let mut from_col = None;
             let mut from_expr = None;
+            let mut from_expr_fn = None;
             let mut nested = false;
+            let mut nested_prefix = None;
             let mut skip = false;
 
             for attr in field.attrs.iter() {
@@ -123,19 +171,34 @@ impl DerivePartialModel {
                             skip = true;
                         } else if meta.exists("nested") {
                             nested = true;
+                        } else if let Some(s) = meta.get_as_kv("nested_prefix") {
+                            nested = true;
+                            nested_prefix = Some(s);
                         } else if let Some(s) = meta.get_as_kv("from_col") {
-                            from_col = Some(format_ident!("{}", s.to_upper_camel_case()));
+                            from_col = Some(if s.contains("::") {
+                                FromCol::Joined(syn::parse_str(&s).map_err(Error::Syn)?)
+                            } else {
+                                FromCol::Col(format_ident!("{}", s.to_upper_camel_case()))
+                            });
                         } else if let Some(s) = meta.get_as_kv("from_expr") {
                             from_expr = Some(syn::parse_str::<Expr>(&s).map_err(Error::Syn)?);
+                        } else if let Some(s) = meta.get_as_kv("from_expr_fn") {
+                            from_expr_fn =
+                                Some(syn::parse_str::<syn::Path>(&s).map_err(Error::Syn)?);
                         }
                     }
                 }
             }
 
-            let field_name = field.ident.unwrap();
+            let field_name = field.ident.unwrap_or_else(|| tuple_field_ident(index));
 
-            let col_as = match (from_col, from_expr, nested) {
-                (Some(col), None, false) => {
+            let col_as = match (from_col, from_expr, from_expr_fn, nested) {
+                (Some(FromCol::Joined(col)), None, None, false) => ColumnAs::JoinedCol {
+                    col,
+                    field: field_name,
+                    typ: field.ty,
+                },
+                (Some(FromCol::Col(col)), None, None, false) => {
                     if entity.is_none() {
                         return Err(Error::EntityNotSpecified);
                     }
@@ -143,17 +206,31 @@ impl DerivePartialModel {
                     ColumnAs::Col {
                         col: Some(col),
                         field: field_name,
+                        position: None,
+                        typ: field.ty,
                     }
                 }
-                (None, Some(expr), false) => ColumnAs::Expr {
+                (None, Some(expr), None, false) => ColumnAs::Expr {
                     expr,
                     field: field_name,
                 },
-                (None, None, true) => ColumnAs::Nested {
+                (None, None, Some(func), false) => {
+                    if context.is_none() {
+                        return Err(Error::ContextNotSpecified(field_span));
+                    }
+
+                    ColumnAs::ExprFn {
+                        func,
+                        field: field_name,
+                    }
+                }
+                (None, None, None, true) => ColumnAs::Nested {
                     typ: field.ty,
+                    prefix: nested_prefix
+                        .unwrap_or_else(|| format!("{}{separator}", field_name.unraw())),
                     field: field_name,
                 },
-                (None, None, false) => {
+                (None, None, None, false) => {
                     if entity.is_none() {
                         return Err(Error::EntityNotSpecified);
                     }
@@ -163,10 +240,12 @@ impl DerivePartialModel {
                         ColumnAs::Col {
                             col: None,
                             field: field_name,
+                            position: tuple.then_some(index),
+                            typ: field.ty,
                         }
                     }
                 }
-                (_, _, _) => return Err(Error::OverlappingAttributes(field_span)),
+                (_, _, _, _) => return Err(Error::OverlappingAttributes(field_span)),
             };
             column_as_list.push(col_as);
         }
@@ -175,10 +254,14 @@ impl DerivePartialModel {
             entity,
             active_model,
             alias,
+            context,
             ident: input.ident,
+            generics: input.generics,
             fields: column_as_list,
+            tuple,
             from_query_result,
             into_active_model,
+            skip_type_check,
         })
     }
 
@@ -188,26 +271,35 @@ impl DerivePartialModel {
         let impl_from_query_result = if self.from_query_result {
             DeriveFromQueryResult {
                 ident: self.ident.clone(),
-                generics: Default::default(),
+                generics: self.generics.clone(),
                 fields: self
                     .fields
                     .iter()
                     .map(|col_as| FromQueryResultItem {
                         typ: match col_as {
+                            ColumnAs::Nested { typ, .. } if is_vec(typ) => FqrItemType::NestedVec,
                             ColumnAs::Nested { .. } => FqrItemType::Nested,
                             ColumnAs::Skip(_) => FqrItemType::Skip,
                             _ => FqrItemType::Flat,
                         },
                         ident: match col_as {
                             ColumnAs::Col { field, .. } => field,
+                            ColumnAs::JoinedCol { field, .. } => field,
                             ColumnAs::Expr { field, .. } => field,
+                            ColumnAs::ExprFn { field, .. } => field,
                             ColumnAs::Nested { field, .. } => field,
                             ColumnAs::Skip(field) => field,
                         }
                         .to_owned(),
                         alias: None,
+                        default: None,
+                        nested_prefix: match col_as {
+                            ColumnAs::Nested { prefix, .. } => Some(prefix.to_owned()),
+                            _ => None,
+                        },
                     })
                     .collect(),
+                tuple: self.tuple,
             }
             .impl_from_query_result(true)
         } else {
@@ -221,51 +313,161 @@ impl DerivePartialModel {
                 fields: self
                     .fields
                     .iter()
-                    .filter_map(|col_as| {
-                        match col_as {
-                            ColumnAs::Col { field, .. } => Some(field),
-                            ColumnAs::Expr { field, .. } => Some(field),
-                            ColumnAs::Nested { .. } => None,
-                            ColumnAs::Skip(_) => None,
-                        }
-                        .cloned()
+                    .filter_map(|col_as| match col_as {
+                        ColumnAs::Col {
+                            col: Some(col),
+                            field,
+                            ..
+                        } => Some((field.clone(), active_model_field(col))),
+                        ColumnAs::Col {
+                            col: None, field, ..
+                        } => Some((field.clone(), field.clone())),
+                        ColumnAs::JoinedCol { .. }
+                        | ColumnAs::Expr { .. }
+                        | ColumnAs::ExprFn { .. }
+                        | ColumnAs::Nested { .. }
+                        | ColumnAs::Skip(_) => None,
                     })
                     .collect(),
+                not_set_others: true,
             }
             .impl_into_active_model()
         } else {
             quote!()
         };
 
+        let check_field_types = self.check_field_types();
+
         Ok(quote! {
             #impl_partial_model
             #impl_from_query_result
             #impl_into_active_model
+            #check_field_types
         })
     }
 
+    /// Check at compile time that the fields selecting a column can hold its values. Columns
+    /// without a Rust type, of entities not derived with `DeriveEntityModel`, fall back to the
+    /// unchecked method of the local trait, as inherent methods take precedence.
+    fn check_field_types(&self) -> TokenStream {
+        if self.skip_type_check || !self.generics.params.is_empty() {
+            return quote!();
+        }
+        let (methods, checks): (BTreeMap<_, _>, Vec<_>) = self
+            .fields
+            .iter()
+            .filter_map(|col_as| {
+                let (variant, col, typ) = match col_as {
+                    ColumnAs::Col {
+                        col,
+                        field,
+                        position: None,
+                        typ,
+                    } => {
+                        let variant = match col {
+                            Some(col) => col.unraw(),
+                            None => {
+                                format_ident!("{}", field.unraw().to_string().to_upper_camel_case())
+                            }
+                        };
+                        let entity = self.entity.as_ref().unwrap();
+                        let col = quote!(<#entity as sea_orm::EntityTrait>::Column::#variant);
+                        (variant, col, typ)
+                    }
+                    ColumnAs::JoinedCol { col, typ, .. } => {
+                        let variant = col.segments.last().unwrap().ident.unraw();
+                        (variant, col.to_token_stream(), typ)
+                    }
+                    _ => return None,
+                };
+                let method = format_ident!("__sea_orm_value_type_{}", variant);
+                Some((
+                    (
+                        method.to_string(),
+                        quote!(fn #method(&self) -> sea_orm::UncheckedColumn {
+                            sea_orm::UncheckedColumn
+                        }),
+                    ),
+                    quote_spanned!(typ.span() =>
+                        sea_orm::check_partial_model_field::<#typ, _>((#col).#method());
+                    ),
+                ))
+            })
+            .unzip();
+        if checks.is_empty() {
+            return quote!();
+        }
+        let methods = methods.values();
+
+        quote! {
+            const _: () = {
+                #[allow(non_snake_case, dead_code)]
+                trait Unchecked {
+                    #(#methods)*
+                }
+
+                impl<T> Unchecked for T {}
+
+                #[allow(dead_code)]
+                fn check() {
+                    #(#checks)*
+                }
+            };
+        }
+    }
+
     fn impl_partial_model(&self) -> TokenStream {
         let select_ident = format_ident!("select");
         let DerivePartialModel {
             entity,
             alias,
+            context,
             ident,
+            generics,
             fields,
             ..
         } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        // the columns of a generic entity are looked up by name, as its variants are unknown
+        let column = |col: &syn::Ident| {
+            let entity = entity.as_ref().unwrap();
+            if generics.params.is_empty() {
+                quote!(<#entity as sea_orm::EntityTrait>::Column:: #col)
+            } else {
+                let name = col.unraw().to_string().to_snake_case();
+                let message =
+                    format!("`{ident}` selects the column `{name}`, which the entity doesn't have");
+                quote!(
+                    <<#entity as sea_orm::EntityTrait>::Column as std::str::FromStr>::from_str(#name)
+                        .unwrap_or_else(|_| panic!(#message))
+                )
+            }
+        };
         let select_col_code_gen = fields.iter().map(|col_as| match col_as {
-            ColumnAs::Col { col, field } => {
+            ColumnAs::Col {
+                col,
+                field,
+                position,
+                ..
+            } => {
                 let field = field.unraw().to_string();
                 let entity = entity.as_ref().unwrap();
-                let col_name = if let Some(col) = col {
-                    col
-                } else {
-                    &format_ident!("{}", field.to_upper_camel_case())
+                let col = match (col, position) {
+                    (Some(col), _) => column(col),
+                    (None, Some(position)) => {
+                        let message = format!("`{ident}` has more elements than the columns of the entity");
+                        quote!(
+                            <<#entity as sea_orm::EntityTrait>::Column as sea_orm::Iterable>::iter()
+                                .nth(#position)
+                                .expect(#message)
+                        )
+                    }
+                    (None, None) => column(&format_ident!("{}", field.to_upper_camel_case())),
                 };
                 let col_value = if let Some(alias) = alias {
-                    quote!(sea_orm::sea_query::Expr::col((sea_orm::sea_query::Alias::new(#alias), <#entity as sea_orm::EntityTrait>::Column:: #col_name)))
+                    quote!(sea_orm::sea_query::Expr::col((sea_orm::sea_query::Alias::new(#alias), #col)))
                 } else {
-                    quote!( <#entity as sea_orm::EntityTrait>::Column:: #col_name)
+                    col
                 };
                 quote!(let #select_ident =
                     if let Some(prefix) = pre {
@@ -276,25 +478,46 @@ impl DerivePartialModel {
                     };
                 )
             }
+            ColumnAs::JoinedCol { col, field, .. } => {
+                let field = field.unraw().to_string();
+                quote!(let #select_ident =
+                    if let Some(prefix) = pre {
+                        let ident = format!("{prefix}{}", #field);
+                        sea_orm::SelectColumns::select_column_as(#select_ident, #col, ident)
+                    } else {
+                        sea_orm::SelectColumns::select_column_as(#select_ident, #col, #field)
+                    };
+                )
+            }
             ColumnAs::Expr { expr, field } => {
                 let field = field.unraw().to_string();
                 quote!(let #select_ident =
                     if let Some(prefix) = pre {
                         let ident = format!("{prefix}{}", #field);
-                        sea_orm::SelectColumns::select_column_as(#select_ident, #expr, ident)
+                        sea_orm::IntoSelectExpr::select_as(#expr, #select_ident, ident)
                     } else {
-                        sea_orm::SelectColumns::select_column_as(#select_ident, #expr, #field)
+                        sea_orm::IntoSelectExpr::select_as(#expr, #select_ident, #field)
                     };
                 )
             }
-            ColumnAs::Nested { typ, field } => {
+            ColumnAs::ExprFn { func, field } => {
                 let field = field.unraw().to_string();
+                quote!(let #select_ident =
+                    if let Some(prefix) = pre {
+                        let ident = format!("{prefix}{}", #field);
+                        sea_orm::IntoSelectExpr::select_as(#func(context), #select_ident, ident)
+                    } else {
+                        sea_orm::IntoSelectExpr::select_as(#func(context), #select_ident, #field)
+                    };
+                )
+            }
+            ColumnAs::Nested { typ, prefix, .. } => {
                 quote!(let #select_ident =
                     <#typ as sea_orm::PartialModelTrait>::select_cols_nested(#select_ident,
                         Some(&if let Some(prefix) = pre {
-                                format!("{prefix}{}_", #field)
+                                format!("{prefix}{}", #prefix)
                             } else {
-                                format!("{}_", #field)
+                                #prefix.to_owned()
                             }
                         ));
                 )
@@ -302,9 +525,31 @@ impl DerivePartialModel {
             ColumnAs::Skip(_) => quote!(),
         });
 
+        if let Some(context) = context {
+            return quote! {
+                #[automatically_derived]
+                impl #impl_generics sea_orm::PartialModelWithContext for #ident #ty_generics #where_clause {
+                    type Context = #context;
+
+                    fn select_cols_with<S: sea_orm::SelectColumns>(#select_ident: S, context: &Self::Context) -> S {
+                        Self::select_cols_nested_with(#select_ident, None, context)
+                    }
+
+                    fn select_cols_nested_with<S: sea_orm::SelectColumns>(
+                        #select_ident: S,
+                        pre: Option<&str>,
+                        context: &Self::Context,
+                    ) -> S {
+                        #(#select_col_code_gen)*
+                        #select_ident
+                    }
+                }
+            };
+        }
+
         quote! {
             #[automatically_derived]
-            impl sea_orm::PartialModelTrait for #ident {
+            impl #impl_generics sea_orm::PartialModelTrait for #ident #ty_generics #where_clause {
                 fn select_cols<S: sea_orm::SelectColumns>(#select_ident: S) -> S {
                     Self::select_cols_nested(#select_ident, None)
                 }
@@ -318,22 +563,34 @@ impl DerivePartialModel {
     }
 }
 
+/// The field of the active model for a column variant, a keyword being a raw identifier
+fn active_model_field(col: &syn::Ident) -> syn::Ident {
+    let name = col.unraw().to_string().to_snake_case();
+    syn::parse_str(&name).unwrap_or_else(|_| format_ident!("r#{}", name))
+}
+
 pub fn expand_derive_partial_model(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     let ident_span = input.ident.span();
 
     match DerivePartialModel::new(input) {
         Ok(partial_model) => partial_model.expand(),
-        Err(Error::NotSupportGeneric(span)) => Ok(quote_spanned! {
-            span => compile_error!("you can only derive `DerivePartialModel` on concrete struct");
+        Err(Error::GenericIntoActiveModel(span)) => Ok(quote_spanned! {
+            span => compile_error!("you can only derive `into_active_model` on concrete struct");
         }),
         Err(Error::OverlappingAttributes(span)) => Ok(quote_spanned! {
-            span => compile_error!("you can only use one of `from_col`, `from_expr`, `nested`");
+            span => compile_error!("you can only use one of `from_col`, `from_expr`, `from_expr_fn`, `nested`, `nested_prefix`");
         }),
         Err(Error::EntityNotSpecified) => Ok(quote_spanned! {
             ident_span => compile_error!("you need specific which entity you are using")
         }),
         Err(Error::InputNotStruct) => Ok(quote_spanned! {
-            ident_span => compile_error!("you can only derive `DerivePartialModel` on named struct");
+            ident_span => compile_error!("you can only derive `DerivePartialModel` on struct with fields");
+        }),
+        Err(Error::TupleIntoActiveModel) => Ok(quote_spanned! {
+            ident_span => compile_error!("you can only derive `into_active_model` on named struct");
+        }),
+        Err(Error::ContextNotSpecified(span)) => Ok(quote_spanned! {
+            span => compile_error!("you need to specify the `context` of the struct to use `from_expr_fn`");
         }),
         Err(Error::Syn(err)) => Err(err),
     }
@@ -373,7 +630,9 @@ mod test {
             middle.fields[0],
             ColumnAs::Col {
                 col: None,
-                field: format_ident!("default_field")
+                field: format_ident!("default_field"),
+                position: None,
+                typ: parse_str::<Type>("i32").unwrap(),
             }
         );
         assert_eq!(
@@ -381,6 +640,8 @@ mod test {
             ColumnAs::Col {
                 col: Some(format_ident!("Bar")),
                 field: format_ident!("alias_field"),
+                position: None,
+                typ: parse_str::<Type>("i32").unwrap(),
             },
         );
         assert_eq!(
@@ -390,7 +651,7 @@ mod test {
                 field: format_ident!("expr_field"),
             }
         );
-        assert_eq!(middle.from_query_result, false);
+        assert!(!middle.from_query_result);
 
         Ok(())
     }
@@ -414,10 +675,115 @@ mod test {
             middle.fields[0],
             ColumnAs::Col {
                 col: None,
-                field: format_ident!("default_field")
+                field: format_ident!("default_field"),
+                position: None,
+                typ: parse_str::<Type>("i32").unwrap(),
             }
         );
-        assert_eq!(middle.from_query_result, true);
+        assert!(middle.from_query_result);
+
+        Ok(())
+    }
+
+    const CODE_SNIPPET_3: &str = r#"
+        #[sea_orm(entity = "Entity", from_query_result)]
+        struct PartialModel(i32, #[sea_orm(from_col = "bar")] String);
+        "#;
+
+    #[test]
+    fn test_load_macro_input_3() -> StdResult<()> {
+        let input = parse_str::<DeriveInput>(CODE_SNIPPET_3)?;
+
+        let middle = DerivePartialModel::new(input).unwrap();
+        assert!(middle.tuple);
+        assert_eq!(
+            middle.fields,
+            [
+                ColumnAs::Col {
+                    col: None,
+                    field: format_ident!("_0"),
+                    position: Some(0),
+                    typ: parse_str::<Type>("i32").unwrap(),
+                },
+                ColumnAs::Col {
+                    col: Some(format_ident!("Bar")),
+                    field: format_ident!("_1"),
+                    position: None,
+                    typ: parse_str::<Type>("String").unwrap(),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    const CODE_SNIPPET_4: &str = r#"
+        #[sea_orm(entity = "Entity", context = "Params")]
+        struct PartialModel {
+            #[sea_orm(from_expr_fn = "price_in_currency")]
+            price: f64,
+        }
+        "#;
+
+    #[test]
+    fn test_load_macro_input_4() -> StdResult<()> {
+        let input = parse_str::<DeriveInput>(CODE_SNIPPET_4)?;
+
+        let middle = DerivePartialModel::new(input).unwrap();
+        assert_eq!(middle.context, Some(parse_str::<Type>("Params").unwrap()));
+        assert_eq!(
+            middle.fields,
+            [ColumnAs::ExprFn {
+                func: parse_str("price_in_currency").unwrap(),
+                field: format_ident!("price"),
+            }]
+        );
+
+        Ok(())
+    }
+
+    const CODE_SNIPPET_5: &str = r#"
+        #[sea_orm(entity = "E")]
+        struct IdOnly<E: EntityTrait> {
+            id: i32,
+            #[sea_orm(skip)]
+            entity: PhantomData<E>,
+        }
+        "#;
+
+    #[test]
+    fn test_load_macro_input_5() -> StdResult<()> {
+        let input = parse_str::<DeriveInput>(CODE_SNIPPET_5)?;
+
+        let middle = DerivePartialModel::new(input).unwrap();
+        assert_eq!(middle.entity, Some(parse_str::<Type>("E").unwrap()));
+        assert_eq!(middle.generics.params.len(), 1);
+        assert_eq!(middle.fields.len(), 2);
+
+        Ok(())
+    }
+
+    const CODE_SNIPPET_6: &str = r#"
+        #[sea_orm(entity = "cake::Entity")]
+        struct CakeWithBakery {
+            #[sea_orm(from_col = "bakery::Column::Name")]
+            bakery_name: String,
+        }
+        "#;
+
+    #[test]
+    fn test_load_macro_input_6() -> StdResult<()> {
+        let input = parse_str::<DeriveInput>(CODE_SNIPPET_6)?;
+
+        let middle = DerivePartialModel::new(input).unwrap();
+        assert_eq!(
+            middle.fields,
+            [ColumnAs::JoinedCol {
+                col: parse_str("bakery::Column::Name").unwrap(),
+                field: format_ident!("bakery_name"),
+                typ: parse_str::<Type>("String").unwrap(),
+            }]
+        );
 
         Ok(())
     }
@@ -118,7 +118,7 @@ impl DeriveRelation {
                     let on_update = attr
                         .on_update
                         .as_ref()
-                        .map(Self::parse_lit_string)
+                        .map(Self::parse_foreign_key_action)
                         .ok_or_else(|| {
                             syn::Error::new_spanned(variant, "Missing value for 'on_update'")
                         })??;
@@ -129,7 +129,7 @@ impl DeriveRelation {
                     let on_delete = attr
                         .on_delete
                         .as_ref()
-                        .map(Self::parse_lit_string)
+                        .map(Self::parse_foreign_key_action)
                         .ok_or_else(|| {
                             syn::Error::new_spanned(variant, "Missing value for 'on_delete'")
                         })??;
@@ -185,6 +185,26 @@ impl DeriveRelation {
                     result = quote! { #result.condition_type(#condition_type) };
                 }
 
+                match (&attr.morph_type, &attr.morph_value) {
+                    (Some(morph_type), Some(morph_value)) => {
+                        if attr.belongs_to.is_none() {
+                            return Err(syn::Error::new_spanned(
+                                variant,
+                                "'morph_type' is only allowed on a 'belongs_to' relation",
+                            ));
+                        }
+                        let morph_type = Self::parse_lit_string(morph_type)?;
+                        result = quote! { #result.morph(#morph_type, #morph_value) };
+                    }
+                    (Some(_), None) => {
+                        return Err(syn::Error::new_spanned(variant, "Missing attribute 'morph_value'"));
+                    }
+                    (None, Some(_)) => {
+                        return Err(syn::Error::new_spanned(variant, "Missing attribute 'morph_type'"));
+                    }
+                    (None, None) => {}
+                }
+
                 result = quote! { #result.into() };
 
                 Result::<_, syn::Error>::Ok(result)
@@ -204,6 +224,34 @@ impl DeriveRelation {
         ))
     }
 
+    /// Parse a foreign key action, by its variant name like `SetNull` or as written in SQL like
+    /// `SET NULL`, in any case
+    fn parse_foreign_key_action(lit: &syn::Lit) -> syn::Result<syn::Ident> {
+        let syn::Lit::Str(lit_str) = lit else {
+            return Err(syn::Error::new_spanned(lit, "attribute must be a string"));
+        };
+        let action: String = lit_str
+            .value()
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect();
+        let action = match action.as_str() {
+            "cascade" => "Cascade",
+            "setnull" => "SetNull",
+            "setdefault" => "SetDefault",
+            "restrict" => "Restrict",
+            "noaction" => "NoAction",
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "foreign key action must be one of `Cascade`, `SetNull`, `SetDefault`, `Restrict` or `NoAction`",
+                ))
+            }
+        };
+        Ok(format_ident!("{}", action, span = lit_str.span()))
+    }
+
     fn parse_lit_string(lit: &syn::Lit) -> syn::Result<TokenStream> {
         match lit {
             syn::Lit::Str(lit_str) => lit_str
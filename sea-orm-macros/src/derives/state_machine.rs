@@ -0,0 +1,80 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Attribute, Data, DataEnum, LitStr};
+
+/// Method to derive an implementation of [StateMachine](sea_orm::StateMachine)
+pub fn expand_derive_state_machine(
+    ident: Ident,
+    data: Data,
+    attrs: Vec<Attribute>,
+) -> syn::Result<TokenStream> {
+    let variants: Vec<Ident> = match data {
+        Data::Enum(DataEnum { variants, .. }) => {
+            variants.into_iter().map(|variant| variant.ident).collect()
+        }
+        _ => {
+            return Ok(quote_spanned! {
+                ident.span() => compile_error!("you can only derive DeriveStateMachine on enums");
+            })
+        }
+    };
+
+    let mut from_states = Vec::new();
+    let mut to_states = Vec::new();
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("transition"))
+    {
+        let mut from = None;
+        let mut to = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("from") {
+                from = Some(meta.value()?.parse::<LitStr>()?);
+            } else if meta.path.is_ident("to") {
+                to = Some(meta.value()?.parse::<LitStr>()?);
+            } else {
+                return Err(meta.error(format!(
+                    "Unknown attribute parameter found: {:?}",
+                    meta.path.get_ident()
+                )));
+            }
+            Ok(())
+        })?;
+        let (Some(from), Some(to)) = (from, to) else {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`transition` requires both `from` and `to`",
+            ));
+        };
+        from_states.push(variant_ident(&ident, &variants, &from)?);
+        to_states.push(variant_ident(&ident, &variants, &to)?);
+    }
+
+    let can_transition = if from_states.is_empty() {
+        quote!(false)
+    } else {
+        quote!(matches!((from, to), #((Self::#from_states, Self::#to_states))|*))
+    };
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl sea_orm::StateMachine for #ident {
+            fn can_transition(from: &Self, to: &Self) -> bool {
+                #can_transition
+            }
+        }
+    ))
+}
+
+fn variant_ident(ident: &Ident, variants: &[Ident], state: &LitStr) -> syn::Result<Ident> {
+    variants
+        .iter()
+        .find(|variant| *variant == &state.value())
+        .cloned()
+        .ok_or_else(|| {
+            syn::Error::new(
+                state.span(),
+                format!("`{}` is not a variant of `{ident}`", state.value()),
+            )
+        })
+}
@@ -0,0 +1,178 @@
+use super::{model_serde::SerdeAttrs, util::skip_meta_value};
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Fields, GenericArgument, Lit, PathArguments, Type};
+
+/// Method to implement `utoipa::ToSchema` for a Model marked `#[sea_orm(schema)]`, named after
+/// its table
+pub fn expand_model_to_schema(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    if !has_schema(&input)? {
+        return Ok(TokenStream::new());
+    }
+    let mut table_name = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("sea_orm"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table_name") {
+                if let Lit::Str(name) = meta.value()?.parse::<Lit>()? {
+                    table_name = Some(name.value());
+                }
+            } else {
+                skip_meta_value(&meta)?;
+            }
+            Ok(())
+        })?;
+    }
+    let name = match table_name {
+        Some(table_name) => table_name.to_upper_camel_case(),
+        None => input.ident.to_string(),
+    };
+    if let Some(serde_attrs) = SerdeAttrs::parse(&input)? {
+        serde_attrs.apply(&mut input);
+    }
+    expand_to_schema(input, name)
+}
+
+/// Method to implement `utoipa::ToSchema` for a type marked `#[sea_orm(schema)]`, by deriving
+/// it for a copy of the type that only keeps the `serde`, `schema` and doc attributes
+pub fn expand_to_schema(input: DeriveInput, name: String) -> syn::Result<TokenStream> {
+    if !has_schema(&input)? {
+        return Ok(TokenStream::new());
+    }
+    let ident = input.ident.clone();
+    let mut shadow = input;
+    shadow.ident = format_ident!("__SeaOrmToSchema");
+    shadow.attrs.retain(is_schema_attr);
+    match &mut shadow.data {
+        Data::Struct(data) => retain_field_attrs(&mut data.fields),
+        Data::Enum(data) => {
+            for variant in data.variants.iter_mut() {
+                variant.attrs.retain(is_schema_attr);
+                retain_field_attrs(&mut variant.fields);
+            }
+        }
+        Data::Union(_) => {}
+    }
+    let (impl_generics, ty_generics, where_clause) = shadow.generics.split_for_impl();
+
+    Ok(quote!(
+        #[automatically_derived]
+        const _: () = {
+            // the derive names `utoipa` paths, resolved through the re-export
+            use sea_orm::utoipa;
+
+            #[allow(dead_code)]
+            #[derive(sea_orm::utoipa::ToSchema)]
+            #shadow
+
+            impl #impl_generics sea_orm::utoipa::PartialSchema for #ident #ty_generics #where_clause {
+                fn schema() -> sea_orm::utoipa::openapi::RefOr<sea_orm::utoipa::openapi::schema::Schema> {
+                    <__SeaOrmToSchema #ty_generics as sea_orm::utoipa::PartialSchema>::schema()
+                }
+            }
+
+            impl #impl_generics sea_orm::utoipa::ToSchema for #ident #ty_generics #where_clause {
+                fn name() -> std::borrow::Cow<'static, str> {
+                    std::borrow::Cow::Borrowed(#name)
+                }
+
+                fn schemas(
+                    schemas: &mut Vec<(
+                        String,
+                        sea_orm::utoipa::openapi::RefOr<sea_orm::utoipa::openapi::schema::Schema>,
+                    )>,
+                ) {
+                    <__SeaOrmToSchema #ty_generics as sea_orm::utoipa::ToSchema>::schemas(schemas)
+                }
+            }
+        };
+    ))
+}
+
+/// Whether the type is marked `#[sea_orm(schema)]`
+fn has_schema(input: &DeriveInput) -> syn::Result<bool> {
+    let mut schema = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("sea_orm"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("schema") {
+                schema = true;
+            } else {
+                skip_meta_value(&meta)?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(schema)
+}
+
+fn is_schema_attr(attr: &Attribute) -> bool {
+    ["serde", "schema", "doc"]
+        .iter()
+        .any(|name| attr.path().is_ident(name))
+}
+
+fn retain_field_attrs(fields: &mut Fields) {
+    for field in fields.iter_mut() {
+        let has_schema = field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("schema"));
+        field.attrs.retain(is_schema_attr);
+        if has_schema {
+            continue;
+        }
+        if let Some(value_type) = value_type(&field.ty) {
+            field.attrs.push(syn::parse_quote!(#[schema(#value_type)]));
+        }
+    }
+}
+
+/// The schema of the type aliases of SeaORM that utoipa does not know about
+fn value_type(ty: &Type) -> Option<TokenStream> {
+    let (value_type, format) = alias_value_type(ty)?;
+    Some(match format {
+        Some(format) => quote!(value_type = #value_type, format = #format),
+        None => quote!(value_type = #value_type),
+    })
+}
+
+fn alias_value_type(ty: &Type) -> Option<(TokenStream, Option<TokenStream>)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident == "Option" {
+        let PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        let Some(GenericArgument::Type(ty)) = args.args.first() else {
+            return None;
+        };
+        let (value_type, format) = alias_value_type(ty)?;
+        return Some((quote!(Option<#value_type>), format));
+    }
+    Some(match segment.ident.to_string().as_str() {
+        "DateTimeWithTimeZone"
+        | "DateTimeUtc"
+        | "DateTimeLocal"
+        | "ChronoDateTime"
+        | "ChronoDateTimeWithTimeZone"
+        | "ChronoDateTimeUtc"
+        | "ChronoDateTimeLocal"
+        | "TimeDateTime"
+        | "TimeDateTimeWithTimeZone" => (quote!(String), Some(quote!(DateTime))),
+        "ChronoDate" | "TimeDate" => (quote!(String), Some(quote!(Date))),
+        "Time" | "ChronoTime" | "TimeTime" | "BigDecimal" => (quote!(String), None),
+        "Json" => (quote!(Value), None),
+        "PgVector" => (quote!(Vec<f32>), None),
+        _ => return None,
+    })
+}
@@ -0,0 +1,89 @@
+use super::util::{escape_rust_keyword, skip_meta_value, trim_starting_raw_identifier};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Data, DataStruct, Fields, LitStr};
+
+/// Method to derive an implementation of [TreeModel](sea_orm::TreeModel)
+pub fn expand_derive_tree(ident: Ident, data: Data) -> syn::Result<TokenStream> {
+    let fields = match data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(named),
+            ..
+        }) => named.named,
+        _ => {
+            return Ok(quote_spanned! {
+                ident.span() => compile_error!("you can only derive DeriveTree on structs");
+            })
+        }
+    };
+
+    let mut path = None;
+    let mut primary_keys = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.clone().unwrap();
+        let mut column = Ident::new(
+            &escape_rust_keyword(trim_starting_raw_identifier(&field_ident).to_upper_camel_case()),
+            Span::call_site(),
+        );
+        let mut is_path = false;
+        let mut is_primary_key = false;
+        for attr in field.attrs.iter() {
+            if !attr.path().is_ident("sea_orm") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tree_path") {
+                    is_path = true;
+                } else if meta.path.is_ident("primary_key") {
+                    is_primary_key = true;
+                } else if meta.path.is_ident("enum_name") {
+                    column = syn::parse_str(&meta.value()?.parse::<LitStr>()?.value())?;
+                } else {
+                    skip_meta_value(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+        if is_path {
+            if path.is_some() {
+                return Err(syn::Error::new_spanned(
+                    field_ident,
+                    "only one field can be the `tree_path`",
+                ));
+            }
+            path = Some((field_ident.clone(), column));
+        }
+        if is_primary_key {
+            primary_keys.push(field_ident);
+        }
+    }
+
+    let Some((path, path_column)) = path else {
+        return Ok(quote_spanned! {
+            ident.span() => compile_error!("DeriveTree needs a field marked `#[sea_orm(tree_path)]`");
+        });
+    };
+    if primary_keys.is_empty() {
+        return Ok(quote_spanned! {
+            ident.span() => compile_error!("DeriveTree needs a field marked `#[sea_orm(primary_key)]`");
+        });
+    }
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl sea_orm::TreeModel for #ident {
+            fn tree_path_column() -> <<Self as sea_orm::ModelTrait>::Entity as sea_orm::EntityTrait>::Column {
+                Column::#path_column
+            }
+
+            fn tree_path(&self) -> &str {
+                &self.#path
+            }
+
+            fn tree_segment(&self) -> String {
+                [#(self.#primary_keys.to_string()),*].join("-")
+            }
+        }
+    ))
+}
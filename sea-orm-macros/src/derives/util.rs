@@ -1,6 +1,9 @@
 use heck::ToUpperCamelCase;
 use quote::format_ident;
-use syn::{punctuated::Punctuated, token::Comma, Field, Ident, Meta, MetaNameValue};
+use syn::{
+    meta::ParseNestedMeta, punctuated::Punctuated, token::Comma, Expr, Field, Ident, Meta,
+    MetaNameValue,
+};
 
 pub(crate) fn field_not_ignored(field: &Field) -> bool {
     for attr in field.attrs.iter() {
@@ -27,6 +30,17 @@ pub(crate) fn field_not_ignored(field: &Field) -> bool {
     true
 }
 
+/// Reads the value of an argument that is not handled to advance the parse stream.
+/// The argument can be `name = value`, `name(..)` or have no value at all.
+pub(crate) fn skip_meta_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::token::Paren) {
+        let _: proc_macro2::TokenTree = meta.input.parse()?;
+    } else {
+        let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
+    }
+    Ok(())
+}
+
 pub(crate) fn format_field_ident(field: Field) -> Ident {
     format_ident!("{}", field.ident.unwrap().to_string())
 }
@@ -133,13 +133,52 @@ pub fn derive_entity(input: TokenStream) -> TokenStream {
 /// #
 /// # impl ActiveModelBehavior for ActiveModel {}
 /// ```
+///
+/// `serde::Serialize` and `serde::Deserialize` are implemented for the Model when it is
+/// given `#[sea_orm(serde(..))]`, instead of deriving them. Container arguments are
+/// forwarded as `#[serde(..)]`, except `skip`, `skip_serializing` and `skip_deserializing`,
+/// which list the fields they apply to. Field arguments are forwarded to the field.
+/// This requires `serde` with its `derive` feature as a dependency.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "user")]
+/// #[sea_orm(serde(rename_all = "camelCase", skip = "password_hash"))]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(serde(rename = "name"))]
+///     pub user_name: String,
+///     pub password_hash: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// ```
+///
+/// With the `utoipa` feature, `#[sea_orm(schema)]` implements `utoipa::ToSchema` for the Model
+/// as well, named after the table in upper camel case. The schema honors the `serde` and
+/// `schema` attributes, as if `#[derive(utoipa::ToSchema)]` was on the Model. The same goes for
+/// `DerivePartialModel` and `DeriveActiveEnum`, named after the type.
+///
+/// With the `graphql` feature, `#[sea_orm(graphql)]` implements `sea_orm::graphql::GraphQLEntity`
+/// for the Entity, to expose it as a GraphQL object type. `#[sea_orm(graphql(name = "..."))]`
+/// renames the field of a column, and `#[sea_orm(graphql(skip))]` leaves it out.
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveEntityModel, attributes(sea_orm))]
 pub fn derive_entity_model(input: TokenStream) -> TokenStream {
     let input_ts = input.clone();
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    #[cfg(feature = "utoipa")]
+    let to_schema = derives::expand_model_to_schema(derive_input.clone());
+    let model_serde = derives::expand_model_serde(&derive_input);
     let DeriveInput {
         ident, data, attrs, ..
-    } = parse_macro_input!(input as DeriveInput);
+    } = derive_input;
 
     if ident != "Model" {
         panic!("Struct name must be Model");
@@ -148,6 +187,13 @@ pub fn derive_entity_model(input: TokenStream) -> TokenStream {
     let mut ts: TokenStream = derives::expand_derive_entity_model(data, attrs)
         .unwrap_or_else(Error::into_compile_error)
         .into();
+    ts.extend(TokenStream::from(
+        model_serde.unwrap_or_else(Error::into_compile_error),
+    ));
+    #[cfg(feature = "utoipa")]
+    ts.extend(TokenStream::from(
+        to_schema.unwrap_or_else(Error::into_compile_error),
+    ));
     ts.extend([
         derive_model(input_ts.clone()),
         derive_active_model(input_ts),
@@ -369,6 +415,10 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
 /// The DeriveActiveModel derive macro will implement ActiveModelTrait for ActiveModel
 /// which provides setters and getters for all active values in the active model.
 ///
+/// It also generates a chainable setter for each field, named after the field, which sets
+/// its value and leaves the others as they are. A field named like a method of the traits
+/// of an ActiveModel, like `insert` or `new`, gets no setter.
+///
 /// ### Usage
 ///
 /// ```
@@ -429,6 +479,10 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
 /// # }
 /// #
 /// # impl ActiveModelBehavior for ActiveModel {}
+///
+/// let cake = ActiveModel::new().name("Cheese Cake");
+/// assert!(cake.id.is_not_set());
+/// assert_eq!(cake.name, sea_orm::ActiveValue::Set("Cheese Cake".to_owned()));
 /// ```
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveActiveModel, attributes(sea_orm))]
@@ -441,6 +495,329 @@ pub fn derive_active_model(input: TokenStream) -> TokenStream {
     }
 }
 
+/// The DeriveFactory derive macro will implement FakeActiveModel for ActiveModel,
+/// filling every field with a random value of its type for test data.
+/// Active enums derived with `DeriveActiveEnum` pick a random variant and nullable fields
+/// are left `None`, other field types have to implement `Fake`.
+///
+/// ### Usage
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, ActiveValue};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveFactory)]
+/// #[sea_orm(table_name = "cake")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub name: String,
+///     pub price: Option<f64>,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// let cake = ActiveModel::factory().set(Column::Name, "Cheese").build();
+/// assert!(cake.id.is_not_set());
+/// assert_eq!(cake.name, ActiveValue::Set("Cheese".to_owned()));
+/// assert_eq!(cake.price, ActiveValue::Set(None));
+/// ```
+#[cfg(feature = "derive")]
+#[proc_macro_derive(DeriveFactory, attributes(sea_orm))]
+pub fn derive_factory(input: TokenStream) -> TokenStream {
+    let DeriveInput { ident, data, .. } = parse_macro_input!(input);
+
+    match derives::expand_derive_factory(ident, data) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// A derive macro to implement `sea_orm::StateMachine` for an ActiveEnum, from the
+/// transitions allowed between its variants.
+///
+/// Each `#[transition(from = "Variant", to = "Variant")]` attribute allows one transition.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, DeriveStateMachine, StateMachine};
+///
+/// #[derive(Clone, Debug, PartialEq, EnumIter, DeriveActiveEnum, DeriveStateMachine)]
+/// #[sea_orm(rs_type = "i32", db_type = "Integer")]
+/// #[transition(from = "Draft", to = "Published")]
+/// #[transition(from = "Published", to = "Draft")]
+/// pub enum State {
+///     Draft = 0,
+///     Published = 1,
+/// }
+///
+/// assert!(State::can_transition(&State::Draft, &State::Published));
+/// assert!(!State::can_transition(&State::Draft, &State::Draft));
+/// ```
+#[cfg(feature = "derive")]
+#[proc_macro_derive(DeriveStateMachine, attributes(transition))]
+pub fn derive_state_machine(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident, data, attrs, ..
+    } = parse_macro_input!(input);
+
+    match derives::expand_derive_state_machine(ident, data, attrs) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// A derive macro to implement `sea_orm::MorphTo` for an enum of the entities a polymorphic
+/// relation relates to, to load them with `LoaderTrait::load_morph`.
+///
+/// Each variant holds the Model of a related entity. The relations to them are the
+/// `belongs_to` relations of the Model marked with `morph_type` and `morph_value`; the
+/// Model is `Model` unless given with `#[sea_orm(model = "...")]`.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, tests_cfg::{cake, fruit}, DeriveMorphTo};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "comment")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub commentable_type: String,
+///     pub commentable_id: i32,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {
+///     #[sea_orm(
+///         belongs_to = "cake::Entity",
+///         from = "Column::CommentableId",
+///         to = "cake::Column::Id",
+///         morph_type = "Column::CommentableType",
+///         morph_value = "cake"
+///     )]
+///     Cake,
+///     #[sea_orm(
+///         belongs_to = "fruit::Entity",
+///         from = "Column::CommentableId",
+///         to = "fruit::Column::Id",
+///         morph_type = "Column::CommentableType",
+///         morph_value = "fruit"
+///     )]
+///     Fruit,
+/// }
+///
+/// impl Related<cake::Entity> for Entity {
+///     fn to() -> RelationDef {
+///         Relation::Cake.def()
+///     }
+/// }
+///
+/// impl Related<fruit::Entity> for Entity {
+///     fn to() -> RelationDef {
+///         Relation::Fruit.def()
+///     }
+/// }
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveMorphTo)]
+/// pub enum Commentable {
+///     Cake(cake::Model),
+///     Fruit(fruit::Model),
+/// }
+/// ```
+#[cfg(feature = "derive")]
+#[proc_macro_derive(DeriveMorphTo, attributes(sea_orm))]
+pub fn derive_morph_to(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident, data, attrs, ..
+    } = parse_macro_input!(input);
+
+    match derives::expand_derive_morph_to(ident, data, attrs) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// A derive macro to implement `sea_orm::HierarchyModel` for a Model linking each row to its
+/// parent in the same table.
+///
+/// The column holding the primary key of the parent is marked with `#[sea_orm(parent_key)]`.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, DbBackend, DeriveHierarchy, HierarchyModel, QueryTrait};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveHierarchy)]
+/// #[sea_orm(table_name = "category")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub name: String,
+///     #[sea_orm(parent_key)]
+///     pub parent_id: Option<i32>,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// let shoes = Model {
+///     id: 4,
+///     name: "Shoes".to_owned(),
+///     parent_id: Some(1),
+/// };
+/// assert_eq!(
+///     shoes.parent().build(DbBackend::Postgres).to_string(),
+///     r#"SELECT "category"."id", "category"."name", "category"."parent_id" FROM "category" WHERE "category"."id" = 1"#
+/// );
+/// ```
+#[cfg(feature = "derive")]
+#[proc_macro_derive(DeriveHierarchy, attributes(sea_orm))]
+pub fn derive_hierarchy(input: TokenStream) -> TokenStream {
+    let DeriveInput { ident, data, .. } = parse_macro_input!(input);
+
+    match derives::expand_derive_hierarchy(ident, data) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// A derive macro to implement `sea_orm::Linked` for a relation reaching an entity through
+/// any number of intermediate entities.
+///
+/// The relations are listed in order with `#[sea_orm(through = "...")]`, starting from the
+/// `from` entity (`Entity` by default) and ending at the `to` entity. A relation declared on the
+/// other side of a hop is walked in reverse, so `belongs_to` relations of a junction entity
+/// can be listed as is.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, tests_cfg::*, DbBackend, DeriveLinked, QueryTrait};
+///
+/// #[derive(Debug, DeriveLinked)]
+/// #[sea_orm(
+///     from = "cake::Entity",
+///     to = "vendor::Entity",
+///     through = "cake_filling::Relation::Cake",
+///     through = "cake_filling::Relation::Filling",
+///     through = "filling::Relation::Vendor"
+/// )]
+/// pub struct CakeToVendor;
+///
+/// assert_eq!(
+///     cake::Entity::find()
+///         .find_also_linked(CakeToVendor)
+///         .build(DbBackend::MySql)
+///         .to_string(),
+///     [
+///         "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+///         "`r2`.`id` AS `B_id`, `r2`.`name` AS `B_name`",
+///         "FROM `cake`",
+///         "LEFT JOIN `cake_filling` AS `r0` ON `cake`.`id` = `r0`.`cake_id`",
+///         "LEFT JOIN `filling` AS `r1` ON `r0`.`filling_id` = `r1`.`id`",
+///         "LEFT JOIN `vendor` AS `r2` ON `r1`.`vendor_id` = `r2`.`id`",
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[cfg(feature = "derive")]
+#[proc_macro_derive(DeriveLinked, attributes(sea_orm))]
+pub fn derive_linked(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident, data, attrs, ..
+    } = parse_macro_input!(input);
+
+    match derives::expand_derive_linked(ident, data, attrs) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// A derive macro to implement `sea_orm::TreeModel` for a Model stored as a materialized path.
+///
+/// The path column is marked with `#[sea_orm(tree_path)]`, and the primary key of a node is its
+/// segment in the paths of its descendants.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, DeriveTree, TreeModel};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveTree)]
+/// #[sea_orm(table_name = "category")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub name: String,
+///     #[sea_orm(tree_path)]
+///     pub path: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// let shoes = Model {
+///     id: 4,
+///     name: "Shoes".to_owned(),
+///     path: "1/4/".to_owned(),
+/// };
+/// assert_eq!(shoes.tree_segment(), "4");
+/// assert_eq!(shoes.ancestor_paths(), ["1/"]);
+/// ```
+#[cfg(feature = "derive")]
+#[proc_macro_derive(DeriveTree, attributes(sea_orm))]
+pub fn derive_tree(input: TokenStream) -> TokenStream {
+    let DeriveInput { ident, data, .. } = parse_macro_input!(input);
+
+    match derives::expand_derive_tree(ident, data) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// A derive macro to implement `sea_orm::Localize` for a Model, from the columns marked
+/// `#[sea_orm(translatable)]`. A translatable column is a `sea_orm::Translations`, or an
+/// `Option` of it.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, DeriveLocalize, Locale, Localize, Translations};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveLocalize)]
+/// #[sea_orm(table_name = "post")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(translatable)]
+///     pub title: Translations,
+///     #[sea_orm(translatable)]
+///     pub summary: Option<Translations>,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// let mut post = Model {
+///     id: 1,
+///     title: Translations::new().with("en", "Hello").with("de", "Hallo"),
+///     summary: None,
+/// };
+/// post.localize(&Locale::new("en-GB"));
+/// assert_eq!(post.title, Translations::new().with("en", "Hello"));
+/// ```
+#[cfg(feature = "derive")]
+#[proc_macro_derive(DeriveLocalize, attributes(sea_orm))]
+pub fn derive_localize(input: TokenStream) -> TokenStream {
+    let DeriveInput { ident, data, .. } = parse_macro_input!(input);
+
+    match derives::expand_derive_localize(ident, data) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 /// Derive into an active model
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveIntoActiveModel, attributes(sea_orm))]
@@ -571,9 +948,16 @@ pub fn derive_active_model_behavior(input: TokenStream) -> TokenStream {
 #[proc_macro_derive(DeriveActiveEnum, attributes(sea_orm))]
 pub fn derive_active_enum(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    match derives::expand_derive_active_enum(input) {
-        Ok(ts) => ts.into(),
-        Err(e) => e.to_compile_error().into(),
+    #[cfg(feature = "utoipa")]
+    let to_schema = derives::expand_to_schema(input.clone(), input.ident.to_string());
+    #[cfg(not(feature = "utoipa"))]
+    let to_schema = Ok(proc_macro2::TokenStream::new());
+    match (derives::expand_derive_active_enum(input), to_schema) {
+        (Ok(mut ts), Ok(to_schema)) => {
+            ts.extend(to_schema);
+            ts.into()
+        }
+        (Err(e), _) | (_, Err(e)) => e.to_compile_error().into(),
     }
 }
 
@@ -582,8 +966,13 @@ pub fn derive_active_enum(input: TokenStream) -> TokenStream {
 /// ### Attributes
 ///
 /// - `skip`: will not try to pull this field from the query result. And set it to the default value of the type.
-/// - `nested`: allows nesting models. can be any type that implements `FromQueryResult`
+/// - `nested`: allows nesting models. can be any type that implements `FromQueryResult`.
+///   A `Vec` collects the nested models of consecutive rows with the same other fields, see below
 /// - `from_alias`: get the value from this column alias
+/// - `nested_prefix`: a `nested` field read from the columns aliased with this prefix
+/// - `default`: set it to the default value of the type when the column is absent from the query
+///   result, so one struct can be shared by queries selecting different columns
+/// - `default_expr`: set it to this expression when the column is absent from the query result
 ///
 /// ### Usage
 ///
@@ -611,6 +1000,51 @@ pub fn derive_active_enum(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// A column absent from the query result fails the conversion, unless the field has a default:
+///
+/// ```
+/// use sea_orm::FromQueryResult;
+///
+/// #[derive(FromQueryResult)]
+/// struct Bakery {
+///     id: i32,
+///     #[sea_orm(default)]
+///     name: String,
+///     #[sea_orm(default_expr = "0.1")]
+///     profit_margin: f64,
+/// }
+/// ```
+///
+/// The elements of a tuple struct are read by their position in the row, or by `from_alias`:
+///
+/// ```
+/// use sea_orm::FromQueryResult;
+///
+/// #[derive(FromQueryResult)]
+/// struct IdName(i32, String, #[sea_orm(from_alias = "profit")] f64);
+/// ```
+///
+/// An enum is decoded by the value of its `tag` column, as the rows of a single table
+/// inheritance: each variant holds a nested model read from the same row, or is a unit variant.
+/// The tag value of a variant is its name, unless set by `tag_value`.
+///
+/// ```
+/// use sea_orm::FromQueryResult;
+///
+/// #[derive(FromQueryResult)]
+/// struct Circle {
+///     radius: f64,
+/// }
+///
+/// #[derive(FromQueryResult)]
+/// #[sea_orm(tag = "kind")]
+/// enum Shape {
+///     #[sea_orm(tag_value = "circle")]
+///     Circle(Circle),
+///     Point,
+/// }
+/// ```
+///
 /// You can compose this with regular Models, if there's no column collision:
 ///
 /// ```ignore
@@ -658,6 +1092,30 @@ pub fn derive_from_query_result(input: TokenStream) -> TokenStream {
 ///     CakeExpanded,
 /// }
 /// ```
+///
+/// `on_delete` and `on_update` on a `belongs_to` relation set the referential actions of the
+/// foreign key created for it by `Schema::create_table_from_entity`. An action is named by its
+/// `ForeignKeyAction` variant, like `SetNull`, or as written in SQL, like `SET NULL`.
+///
+/// ```
+/// # use sea_orm::tests_cfg::fruit::Entity;
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {
+///     #[sea_orm(
+///         belongs_to = "sea_orm::tests_cfg::cake::Entity",
+///         from = "sea_orm::tests_cfg::fruit::Column::CakeId",
+///         to = "sea_orm::tests_cfg::cake::Column::Id",
+///         on_delete = "SET NULL",
+///         on_update = "Restrict"
+///     )]
+///     Cake,
+/// }
+///
+/// assert!(matches!(Relation::Cake.def().on_delete, Some(ForeignKeyAction::SetNull)));
+/// assert!(matches!(Relation::Cake.def().on_update, Some(ForeignKeyAction::Restrict)));
+/// ```
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveRelation, attributes(sea_orm))]
 pub fn derive_relation(input: TokenStream) -> TokenStream {
@@ -775,6 +1233,9 @@ pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// A `from_expr` can also be a window function over its window, like
+/// `WindowFunc::row_number().over(Window::new().order_by(Column::Price, Order::Desc))`.
+///
 /// If all fields in the partial model is `from_expr`, the specifying the `entity` can be skipped.
 /// ```
 /// use sea_orm::{entity::prelude::*, sea_query::Expr, DerivePartialModel, FromQueryResult};
@@ -786,6 +1247,51 @@ pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// A `from_col` path selects the column of another entity joined in the query, without nesting a
+/// struct per join:
+/// ```ignore
+/// #[derive(DerivePartialModel)]
+/// #[sea_orm(entity = "cake::Entity", from_query_result)]
+/// struct CakeWithBakery {
+///     name: String,
+///     #[sea_orm(from_col = "bakery::Column::Name")]
+///     bakery_name: Option<String>,
+/// }
+///
+/// let cakes: Vec<CakeWithBakery> = cake::Entity::find()
+///     .left_join(bakery::Entity)
+///     .into_partial_model()
+///     .all(&db)
+///     .await?;
+/// ```
+///
+/// Expressions with runtime parameters are built by a function from a context, given to
+/// `into_partial_model_with`: a field `#[sea_orm(from_expr_fn = "path::to::fn")]` calls the
+/// function with a reference to the `context` of the struct. Such a partial model implements
+/// `PartialModelWithContext` instead of `PartialModelTrait`, so it can't be nested.
+/// ```
+/// use sea_orm::{
+///     entity::prelude::*,
+///     sea_query::{Expr, SimpleExpr},
+///     DerivePartialModel, FromQueryResult,
+/// };
+///
+/// struct Params {
+///     offset: i32,
+/// }
+///
+/// fn shifted(params: &Params) -> SimpleExpr {
+///     Expr::val(1).add(params.offset)
+/// }
+///
+/// #[derive(Debug, FromQueryResult, DerivePartialModel)]
+/// #[sea_orm(context = "Params")]
+/// struct SelectResult {
+///     #[sea_orm(from_expr_fn = "shifted")]
+///     sum: i32,
+/// }
+/// ```
+///
 /// Since SeaORM 1.1.7, `DerivePartialModel` can also assumes the function of `FromQueryResult`.
 /// This is necessary to support nested partial models.
 ///
@@ -881,7 +1387,150 @@ pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
 /// LIMIT 1
 /// ```
 ///
-/// A field cannot have attributes `from_col`, `from_expr` or `nested` at the same time.
+/// The columns of a nested field are aliased with the prefix `{field}_`, which may collide with
+/// the columns of the struct, like `bakery_id` of `cake` with the `id` of a nested `bakery`. The
+/// separator of the struct is set by `separator`, and the whole prefix of a nested field by
+/// `nested_prefix`:
+///
+/// ```ignore
+/// #[derive(DerivePartialModel)]
+/// #[sea_orm(entity = "cake::Entity", from_query_result, separator = "__")]
+/// struct CakeBakery {
+///     bakery_id: Option<i32>,
+///     // aliased as "bakery__id", "bakery__plant"
+///     #[sea_orm(nested)]
+///     bakery: Option<Factory>,
+///     // aliased as "f.id", "f.plant"
+///     #[sea_orm(nested_prefix = "f.")]
+///     factory: Option<Factory>,
+/// }
+/// ```
+///
+/// A generic struct can select the same columns of any entity having them, for reusable
+/// projections. The columns of a generic struct are looked up by name when the query is built,
+/// panicking if the entity doesn't have one, and their types aren't checked at compile time.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, DerivePartialModel};
+/// use std::marker::PhantomData;
+///
+/// #[derive(DerivePartialModel)]
+/// #[sea_orm(entity = "E", from_query_result)]
+/// struct IdOnly<E: EntityTrait> {
+///     id: i32,
+///     #[sea_orm(skip)]
+///     entity: PhantomData<E>,
+/// }
+/// ```
+///
+/// Tuple structs select the columns of the entity in the order of its columns, or by `from_col`,
+/// and read them by position, or by name when they're also derived with `from_query_result`:
+///
+/// ```ignore
+/// #[derive(DerivePartialModel)]
+/// #[sea_orm(entity = "cake::Entity", from_query_result)]
+/// struct IdName(i32, String, #[sea_orm(from_col = "bakery_id")] Option<i32>);
+///
+/// let cakes: Vec<IdName> = cake::Entity::find().into_partial_model().all(&db).await?;
+/// ```
+///
+/// A `nested` field of type `Vec` hydrates a one-to-many relation: each row of the join holds
+/// one item, or none when its columns are null, and `all` merges consecutive rows whose other
+/// fields are equal, which then must implement `PartialEq`. Order the rows by the parent, and
+/// nest a single `Vec` per struct, so the rows of a parent are consecutive and not a cross
+/// product. `one` and paginators return an item per row.
+///
+/// ```ignore
+/// #[derive(DerivePartialModel)]
+/// #[sea_orm(entity = "bakery::Entity", from_query_result)]
+/// struct BakeryCakes {
+///     id: i32,
+///     name: String,
+///     #[sea_orm(nested)]
+///     cakes: Vec<Cake>,
+/// }
+///
+/// let bakeries: Vec<BakeryCakes> = bakery::Entity::find()
+///     .left_join(cake::Entity)
+///     .order_by_asc(bakery::Column::Id)
+///     .into_partial_model()
+///     .all(&db)
+///     .await?;
+/// ```
+///
+/// With `into_active_model`, the partial model also implements `IntoActiveModel` of the entity,
+/// for PATCH-style updates: the fields selecting a column, by name or `from_col`, are `Set` to
+/// the column, and the other columns of the active model are `NotSet`. Fields of `from_expr`,
+/// `from_expr_fn`, `nested` and `skip` are not written back.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, DerivePartialModel, IntoActiveModel, NotSet, Set};
+/// # mod cake {
+/// # use sea_orm::entity::prelude::*;
+/// # #[derive(Clone, Debug, DeriveEntityModel)]
+/// # #[sea_orm(table_name = "cake")]
+/// # pub struct Model {
+/// #     #[sea_orm(primary_key)]
+/// #     pub id: i32,
+/// #     pub name: String,
+/// #     pub price: i32,
+/// # }
+/// # #[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+/// # pub enum Relation {}
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// # }
+///
+/// #[derive(DerivePartialModel)]
+/// #[sea_orm(entity = "cake::Entity", from_query_result, into_active_model)]
+/// struct CakeTitle {
+///     id: i32,
+///     #[sea_orm(from_col = "name")]
+///     title: String,
+/// }
+///
+/// let cake = CakeTitle {
+///     id: 1,
+///     title: "Cheesecake".to_owned(),
+/// }
+/// .into_active_model();
+///
+/// assert_eq!(cake.id, Set(1));
+/// assert_eq!(cake.name, Set("Cheesecake".to_owned()));
+/// assert_eq!(cake.price, NotSet);
+/// ```
+///
+/// The type of a field selecting a column must be the type of the column in the entity, or either
+/// one wrapped in an `Option`, for entities derived with `DeriveEntityModel`. Or, it will result
+/// in a compile error.
+///
+/// ```compile_fail
+/// use sea_orm::{DerivePartialModel, FromQueryResult};
+/// # mod cake {
+/// # use sea_orm::entity::prelude::*;
+/// # #[derive(Clone, Debug, DeriveEntityModel)]
+/// # #[sea_orm(table_name = "cake")]
+/// # pub struct Model {
+/// #     #[sea_orm(primary_key)]
+/// #     pub id: i32,
+/// #     pub name: String,
+/// # }
+/// # #[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+/// # pub enum Relation {}
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// # }
+///
+/// #[derive(DerivePartialModel)]
+/// #[sea_orm(entity = "cake::Entity", from_query_result)]
+/// struct Cake {
+///     id: String,
+///     name: String,
+/// }
+/// ```
+///
+/// `#[sea_orm(skip_type_check)]` leaves the types unchecked, to be converted at runtime.
+///
+/// A field cannot have attributes `from_col`, `from_expr`, `from_expr_fn` or `nested` (or
+/// `nested_prefix`) at the same time.
 /// Or, it will result in a compile error.
 ///
 /// ```compile_fail
@@ -897,11 +1546,21 @@ pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DerivePartialModel, attributes(sea_orm))]
 pub fn derive_partial_model(input: TokenStream) -> TokenStream {
-    let derive_input = parse_macro_input!(input);
+    let derive_input: DeriveInput = parse_macro_input!(input);
+    #[cfg(feature = "utoipa")]
+    let to_schema = derives::expand_to_schema(derive_input.clone(), derive_input.ident.to_string());
+    #[cfg(not(feature = "utoipa"))]
+    let to_schema = Ok(proc_macro2::TokenStream::new());
 
-    match derives::expand_derive_partial_model(derive_input) {
-        Ok(token_stream) => token_stream.into(),
-        Err(e) => e.to_compile_error().into(),
+    match (
+        derives::expand_derive_partial_model(derive_input),
+        to_schema,
+    ) {
+        (Ok(mut token_stream), Ok(to_schema)) => {
+            token_stream.extend(to_schema);
+            token_stream.into()
+        }
+        (Err(e), _) | (_, Err(e)) => e.to_compile_error().into(),
     }
 }
 
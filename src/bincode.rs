@@ -0,0 +1,299 @@
+use crate::{DbErr, Value};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt::Display, str::FromStr};
+
+pub use ::bincode;
+
+/// Encode a value, e.g. a Model or a `Vec` of them, with the standard bincode configuration
+pub fn encode<T>(value: &T) -> Result<Vec<u8>, DbErr>
+where
+    T: Serialize,
+{
+    ::bincode::serde::encode_to_vec(value, ::bincode::config::standard())
+        .map_err(|err| DbErr::Custom(format!("Failed to encode: {err}")))
+}
+
+/// Decode a value encoded by [encode]
+pub fn decode<T>(bytes: &[u8]) -> Result<T, DbErr>
+where
+    T: DeserializeOwned,
+{
+    let (value, _) = ::bincode::serde::decode_from_slice(bytes, ::bincode::config::standard())
+        .map_err(|err| DbErr::Custom(format!("Failed to decode: {err}")))?;
+    Ok(value)
+}
+
+/// Serialize a field by its string form, for the types whose `Deserialize` relies on
+/// `deserialize_any`, which binary formats do not support: `Json`, `Decimal` and
+/// `BigDecimal`. Use it as `#[serde(with = "sea_orm::bincode::as_string")]`.
+pub mod as_string {
+    use super::*;
+
+    #[allow(missing_docs)]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    #[allow(missing_docs)]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// [as_string] for optional fields, `#[serde(with = "sea_orm::bincode::option_as_string")]`
+pub mod option_as_string {
+    use super::*;
+
+    #[allow(missing_docs)]
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        value
+            .as_ref()
+            .map(ToString::to_string)
+            .serialize(serializer)
+    }
+
+    #[allow(missing_docs)]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| value.parse().map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+/// A [Value] that can be serialized with serde, in binary formats as well. Arrays, vectors,
+/// IP networks and MAC addresses are not supported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerdeValue(pub Value);
+
+impl From<Value> for SerdeValue {
+    fn from(value: Value) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SerdeValue> for Value {
+    fn from(value: SerdeValue) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for SerdeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ValueRepr::try_from(self.0.clone())
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SerdeValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ValueRepr::deserialize(deserializer)?
+            .try_into()
+            .map(Self)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+macro_rules! value_repr {
+    (
+        plain { $( $plain:ident($plain_ty:ty), )* }
+        boxed { $( $(#[$boxed_meta:meta])* $boxed:ident($boxed_ty:ty), )* }
+        string { $( $(#[$string_meta:meta])* $string:ident($string_ty:ty), )* }
+    ) => {
+        /// The serde representation of a [Value]
+        #[derive(Serialize, Deserialize)]
+        enum ValueRepr {
+            $( $plain(Option<$plain_ty>), )*
+            $( $(#[$boxed_meta])* $boxed(Option<$boxed_ty>), )*
+            $( $(#[$string_meta])* $string(Option<String>), )*
+        }
+
+        impl TryFrom<Value> for ValueRepr {
+            type Error = String;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                Ok(match value {
+                    $( Value::$plain(v) => Self::$plain(v), )*
+                    $( $(#[$boxed_meta])* Value::$boxed(v) => Self::$boxed(v.map(|v| *v)), )*
+                    $( $(#[$string_meta])* Value::$string(v) => Self::$string(v.map(|v| v.to_string())), )*
+                    #[allow(unreachable_patterns)]
+                    value => return Err(format!("{value:?} cannot be serialized")),
+                })
+            }
+        }
+
+        impl TryFrom<ValueRepr> for Value {
+            type Error = String;
+
+            fn try_from(value: ValueRepr) -> Result<Self, Self::Error> {
+                Ok(match value {
+                    $( ValueRepr::$plain(v) => Value::$plain(v), )*
+                    $( $(#[$boxed_meta])* ValueRepr::$boxed(v) => Value::$boxed(v.map(Box::new)), )*
+                    $( $(#[$string_meta])* ValueRepr::$string(v) => Value::$string(
+                        v.map(|v| v.parse::<$string_ty>().map(Box::new).map_err(|err| err.to_string()))
+                            .transpose()?,
+                    ), )*
+                })
+            }
+        }
+    };
+}
+
+value_repr! {
+    plain {
+        Bool(bool),
+        TinyInt(i8),
+        SmallInt(i16),
+        Int(i32),
+        BigInt(i64),
+        TinyUnsigned(u8),
+        SmallUnsigned(u16),
+        Unsigned(u32),
+        BigUnsigned(u64),
+        Float(f32),
+        Double(f64),
+        Char(char),
+    }
+    boxed {
+        String(String),
+        Bytes(Vec<u8>),
+        #[cfg(feature = "with-chrono")]
+        ChronoDate(chrono::NaiveDate),
+        #[cfg(feature = "with-chrono")]
+        ChronoTime(chrono::NaiveTime),
+        #[cfg(feature = "with-chrono")]
+        ChronoDateTime(chrono::NaiveDateTime),
+        #[cfg(feature = "with-chrono")]
+        ChronoDateTimeUtc(chrono::DateTime<chrono::Utc>),
+        #[cfg(feature = "with-chrono")]
+        ChronoDateTimeLocal(chrono::DateTime<chrono::Local>),
+        #[cfg(feature = "with-chrono")]
+        ChronoDateTimeWithTimeZone(chrono::DateTime<chrono::FixedOffset>),
+        #[cfg(feature = "with-time")]
+        TimeDate(time::Date),
+        #[cfg(feature = "with-time")]
+        TimeTime(time::Time),
+        #[cfg(feature = "with-time")]
+        TimeDateTime(time::PrimitiveDateTime),
+        #[cfg(feature = "with-time")]
+        TimeDateTimeWithTimeZone(time::OffsetDateTime),
+        #[cfg(feature = "with-uuid")]
+        Uuid(uuid::Uuid),
+    }
+    string {
+        #[cfg(feature = "with-json")]
+        Json(serde_json::Value),
+        #[cfg(feature = "with-rust_decimal")]
+        Decimal(rust_decimal::Decimal),
+        #[cfg(feature = "with-bigdecimal")]
+        BigDecimal(bigdecimal::BigDecimal),
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(
+    feature = "with-json",
+    feature = "with-chrono",
+    feature = "with-rust_decimal",
+    feature = "with-uuid"
+))]
+mod tests {
+    use super::*;
+    use crate::entity::prelude::*;
+
+    mod order {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+        #[sea_orm(table_name = "order")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub token: Uuid,
+            pub placed_at: DateTimeWithTimeZone,
+            #[serde(with = "crate::bincode::as_string")]
+            pub total: Decimal,
+            #[serde(with = "crate::bincode::option_as_string")]
+            pub details: Option<Json>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn model_round_trip() -> Result<(), DbErr> {
+        let models = vec![
+            order::Model {
+                id: 1,
+                token: Uuid::nil(),
+                placed_at: "2024-01-02T03:04:05+08:00".parse().unwrap(),
+                total: "12.50".parse().unwrap(),
+                details: Some(serde_json::json!({ "gift": true })),
+            },
+            order::Model {
+                id: 2,
+                token: Uuid::max(),
+                placed_at: "2024-01-02T03:04:05Z".parse().unwrap(),
+                total: Decimal::ZERO,
+                details: None,
+            },
+        ];
+
+        assert_eq!(decode::<Vec<order::Model>>(&encode(&models)?)?, models);
+
+        Ok(())
+    }
+
+    #[test]
+    fn value_round_trip() -> Result<(), DbErr> {
+        let values: Vec<SerdeValue> = [
+            Value::Int(Some(1)),
+            Value::BigUnsigned(None),
+            Value::String(Some(Box::new("Cheese".to_owned()))),
+            Value::Bytes(Some(Box::new(vec![1, 2]))),
+            Value::Json(Some(Box::new(serde_json::json!([1, "a"])))),
+            Value::ChronoDate(Some(Box::new("2024-01-02".parse().unwrap()))),
+            Value::Uuid(Some(Box::new(Uuid::nil()))),
+            Value::Decimal(Some(Box::new("-0.5".parse().unwrap()))),
+            Value::Decimal(None),
+        ]
+        .into_iter()
+        .map(SerdeValue)
+        .collect();
+
+        assert_eq!(decode::<Vec<SerdeValue>>(&encode(&values)?)?, values);
+
+        Ok(())
+    }
+}
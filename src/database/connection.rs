@@ -1,7 +1,9 @@
 use crate::{
-    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Statement, TransactionError,
+    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, ReadOnlyConnection, Statement,
+    TransactionCallback, TransactionError, TransactionOutcome, WithLogLevel,
 };
 use futures_util::Stream;
+use log::LevelFilter;
 use std::{future::Future, pin::Pin};
 
 /// The generic API for a database connection that can perform query or execute statements.
@@ -34,6 +36,83 @@ pub trait ConnectionTrait: Sync {
     fn is_mock_connection(&self) -> bool {
         false
     }
+
+    /// Run a callback once the transaction of this connection ends, with whether it was
+    /// committed. A transaction nested in another ends with the outer one. A connection
+    /// outside a transaction runs the callback right away as committed, as each statement it
+    /// executes commits on its own.
+    ///
+    /// The callbacks of a transaction dropped without a commit or rollback run as rolled back
+    /// with the transaction it is nested in, if any. Those of an outer transaction are dropped
+    /// without running, as they can't be awaited in `Drop`.
+    async fn on_transaction_end(&self, callback: TransactionCallback) {
+        callback(TransactionOutcome::Committed).await
+    }
+
+    /// Execute statements through the returned connection with the given logging level,
+    /// overriding the statement logging settings of this connection.
+    /// Use [LevelFilter::Off] to keep the statements out of the logs. SQLx connections only
+    /// honor it with [ConnectOptions::statement_log_level_overrides](crate::ConnectOptions::statement_log_level_overrides) set.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// # async fn function(db: &DbConn) -> Result<(), DbErr> {
+    /// use log::LevelFilter;
+    ///
+    /// let cakes = cake::Entity::find()
+    ///     .all(&db.with_log_level(LevelFilter::Off))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_log_level(&self, level: LevelFilter) -> WithLogLevel<'_, Self>
+    where
+        Self: Sized,
+    {
+        WithLogLevel::new(self, level)
+    }
+
+    /// A connection through which only selects can be executed, see [ReadOnlyConnection]
+    fn as_read_only(&self) -> ReadOnlyConnection<'_, Self>
+    where
+        Self: Sized,
+    {
+        ReadOnlyConnection::new(self)
+    }
+}
+
+/// The API for a connection that can query, taken by selects, paginators, cursors and loaders.
+///
+/// It is implemented for every [ConnectionTrait], and for [ReadOnlyConnection], which can't
+/// execute other statements.
+#[async_trait::async_trait]
+pub trait ReadConnectionTrait: Sync {
+    /// Fetch the database backend as specified in [DbBackend]
+    fn read_backend(&self) -> DbBackend;
+
+    /// Execute a [Statement] and return a query
+    async fn read_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr>;
+
+    /// Execute a [Statement] and return a collection Vec<[QueryResult]> on success
+    async fn read_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr>;
+}
+
+#[async_trait::async_trait]
+impl<C> ReadConnectionTrait for C
+where
+    C: ConnectionTrait,
+{
+    fn read_backend(&self) -> DbBackend {
+        self.get_database_backend()
+    }
+
+    async fn read_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.query_one(stmt).await
+    }
+
+    async fn read_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.query_all(stmt).await
+    }
 }
 
 /// Stream query results
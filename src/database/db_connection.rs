@@ -17,6 +17,7 @@ use std::sync::Arc;
 /// flags. This creates a database pool. This will be `Clone` unless the feature
 /// flag `mock` is enabled.
 #[cfg_attr(not(feature = "mock"), derive(Clone))]
+#[derive(Default)]
 pub enum DatabaseConnection {
     /// Create a MYSQL database connection and pool
     #[cfg(feature = "sqlx-mysql")]
@@ -30,6 +31,16 @@ pub enum DatabaseConnection {
     #[cfg(feature = "sqlx-sqlite")]
     SqlxSqlitePoolConnection(crate::SqlxSqlitePoolConnection),
 
+    /// Create a libSQL database connection
+    #[cfg(feature = "libsql")]
+    LibsqlConnection(crate::LibsqlConnection),
+    /// Create a DuckDB database connection
+    #[cfg(feature = "duckdb")]
+    DuckdbConnection(crate::DuckdbConnection),
+    /// Create a tokio-postgres database connection
+    #[cfg(feature = "tokio-postgres")]
+    TokioPostgresConnection(crate::TokioPostgresConnection),
+
     /// Create a Mock database connection useful for testing
     #[cfg(feature = "mock")]
     MockDatabaseConnection(Arc<crate::MockDatabaseConnection>),
@@ -39,18 +50,13 @@ pub enum DatabaseConnection {
     ProxyDatabaseConnection(Arc<crate::ProxyDatabaseConnection>),
 
     /// The connection to the database has been severed
+    #[default]
     Disconnected,
 }
 
 /// The same as a [DatabaseConnection]
 pub type DbConn = DatabaseConnection;
 
-impl Default for DatabaseConnection {
-    fn default() -> Self {
-        Self::Disconnected
-    }
-}
-
 /// The type of database backend for real world databases.
 /// This is enabled by feature flags as specified in the crate documentation
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -74,6 +80,12 @@ pub(crate) enum InnerConnection {
     Postgres(PoolConnection<sqlx::Postgres>),
     #[cfg(feature = "sqlx-sqlite")]
     Sqlite(PoolConnection<sqlx::Sqlite>),
+    #[cfg(feature = "libsql")]
+    Libsql(crate::driver::libsql_db::LibsqlInnerConnection),
+    #[cfg(feature = "duckdb")]
+    Duckdb(crate::driver::duckdb_db::DuckdbInnerConnection),
+    #[cfg(feature = "tokio-postgres")]
+    TokioPostgres(crate::driver::tokio_postgres_db::TokioPostgresInnerConnection),
     #[cfg(feature = "mock")]
     Mock(Arc<crate::MockDatabaseConnection>),
     #[cfg(feature = "proxy")]
@@ -92,6 +104,12 @@ impl std::fmt::Debug for DatabaseConnection {
                 Self::SqlxPostgresPoolConnection(_) => "SqlxPostgresPoolConnection",
                 #[cfg(feature = "sqlx-sqlite")]
                 Self::SqlxSqlitePoolConnection(_) => "SqlxSqlitePoolConnection",
+                #[cfg(feature = "libsql")]
+                Self::LibsqlConnection(_) => "LibsqlConnection",
+                #[cfg(feature = "duckdb")]
+                Self::DuckdbConnection(_) => "DuckdbConnection",
+                #[cfg(feature = "tokio-postgres")]
+                Self::TokioPostgresConnection(_) => "TokioPostgresConnection",
                 #[cfg(feature = "mock")]
                 Self::MockDatabaseConnection(_) => "MockDatabaseConnection",
                 #[cfg(feature = "proxy")]
@@ -112,6 +130,12 @@ impl ConnectionTrait for DatabaseConnection {
             DatabaseConnection::SqlxPostgresPoolConnection(_) => DbBackend::Postgres,
             #[cfg(feature = "sqlx-sqlite")]
             DatabaseConnection::SqlxSqlitePoolConnection(_) => DbBackend::Sqlite,
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(_) => DbBackend::Sqlite,
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(_) => DbBackend::Postgres,
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(_) => DbBackend::Postgres,
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => conn.get_database_backend(),
             #[cfg(feature = "proxy")]
@@ -130,8 +154,17 @@ impl ConnectionTrait for DatabaseConnection {
             DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.execute(stmt).await,
             #[cfg(feature = "sqlx-sqlite")]
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.execute(stmt).await,
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => conn.execute(stmt).await,
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => conn.execute(stmt).await,
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => conn.execute(stmt).await,
             #[cfg(feature = "mock")]
-            DatabaseConnection::MockDatabaseConnection(conn) => conn.execute(stmt),
+            DatabaseConnection::MockDatabaseConnection(conn) => {
+                conn.inject_fault(&stmt).await?;
+                conn.execute(stmt)
+            }
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => conn.execute(stmt).await,
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
@@ -152,10 +185,17 @@ impl ConnectionTrait for DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
                 conn.execute_unprepared(sql).await
             }
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => conn.execute_unprepared(sql).await,
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => conn.execute_unprepared(sql).await,
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => conn.execute_unprepared(sql).await,
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
                 let db_backend = conn.get_database_backend();
                 let stmt = Statement::from_string(db_backend, sql);
+                conn.inject_fault(&stmt).await?;
                 conn.execute(stmt)
             }
             #[cfg(feature = "proxy")]
@@ -178,8 +218,17 @@ impl ConnectionTrait for DatabaseConnection {
             DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.query_one(stmt).await,
             #[cfg(feature = "sqlx-sqlite")]
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.query_one(stmt).await,
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => conn.query_one(stmt).await,
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => conn.query_one(stmt).await,
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => conn.query_one(stmt).await,
             #[cfg(feature = "mock")]
-            DatabaseConnection::MockDatabaseConnection(conn) => conn.query_one(stmt),
+            DatabaseConnection::MockDatabaseConnection(conn) => {
+                conn.inject_fault(&stmt).await?;
+                conn.query_one(stmt)
+            }
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => conn.query_one(stmt).await,
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
@@ -196,8 +245,17 @@ impl ConnectionTrait for DatabaseConnection {
             DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.query_all(stmt).await,
             #[cfg(feature = "sqlx-sqlite")]
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.query_all(stmt).await,
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => conn.query_all(stmt).await,
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => conn.query_all(stmt).await,
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => conn.query_all(stmt).await,
             #[cfg(feature = "mock")]
-            DatabaseConnection::MockDatabaseConnection(conn) => conn.query_all(stmt),
+            DatabaseConnection::MockDatabaseConnection(conn) => {
+                conn.inject_fault(&stmt).await?;
+                conn.query_all(stmt)
+            }
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => conn.query_all(stmt).await,
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
@@ -228,8 +286,15 @@ impl StreamTrait for DatabaseConnection {
                 DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.stream(stmt).await,
                 #[cfg(feature = "sqlx-sqlite")]
                 DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.stream(stmt).await,
+                #[cfg(feature = "libsql")]
+                DatabaseConnection::LibsqlConnection(conn) => conn.stream(stmt).await,
+                #[cfg(feature = "duckdb")]
+                DatabaseConnection::DuckdbConnection(conn) => conn.stream(stmt).await,
+                #[cfg(feature = "tokio-postgres")]
+                DatabaseConnection::TokioPostgresConnection(conn) => conn.stream(stmt).await,
                 #[cfg(feature = "mock")]
                 DatabaseConnection::MockDatabaseConnection(conn) => {
+                    conn.inject_fault(&stmt).await?;
                     Ok(crate::QueryStream::from((Arc::clone(conn), stmt, None)))
                 }
                 #[cfg(feature = "proxy")]
@@ -253,6 +318,12 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.begin(None, None).await,
             #[cfg(feature = "sqlx-sqlite")]
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.begin(None, None).await,
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => conn.begin(None, None).await,
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => conn.begin(None, None).await,
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => conn.begin(None, None).await,
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
                 DatabaseTransaction::new_mock(Arc::clone(conn), None).await
@@ -284,6 +355,18 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
                 conn.begin(_isolation_level, _access_mode).await
             }
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => {
+                conn.begin(_isolation_level, _access_mode).await
+            }
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => {
+                conn.begin(_isolation_level, _access_mode).await
+            }
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => {
+                conn.begin(_isolation_level, _access_mode).await
+            }
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
                 DatabaseTransaction::new_mock(Arc::clone(conn), None).await
@@ -321,6 +404,18 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
                 conn.transaction(_callback, None, None).await
             }
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => {
+                conn.transaction(_callback, None, None).await
+            }
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => {
+                conn.transaction(_callback, None, None).await
+            }
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => {
+                conn.transaction(_callback, None, None).await
+            }
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
                 let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None)
@@ -372,6 +467,21 @@ impl TransactionTrait for DatabaseConnection {
                 conn.transaction(_callback, _isolation_level, _access_mode)
                     .await
             }
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => {
+                conn.transaction(_callback, _isolation_level, _access_mode)
+                    .await
+            }
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => {
+                conn.transaction(_callback, _isolation_level, _access_mode)
+                    .await
+            }
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => {
+                conn.transaction(_callback, _isolation_level, _access_mode)
+                    .await
+            }
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
                 let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None)
@@ -418,6 +528,24 @@ impl DatabaseConnection {
             .expect("Fail to acquire mocker");
         mocker.drain_transaction_log()
     }
+
+    /// Check that every [MockExpectation](crate::MockExpectation) with a call count
+    /// has been matched exactly that many times
+    ///
+    /// # Panics
+    ///
+    /// Panics if an expectation is unmet, if [DbConn] is not a mock connection, or if the mocker
+    /// mutex is poisoned.
+    pub fn verify_expectations(&self) {
+        let mocker = self
+            .as_mock_connection()
+            .get_mocker_mutex()
+            .lock()
+            .expect("Fail to acquire mocker");
+        if let Err(err) = mocker.verify_expectations() {
+            panic!("{err}");
+        }
+    }
 }
 
 #[cfg(feature = "proxy")]
@@ -454,10 +582,98 @@ impl DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
                 conn.set_metric_callback(_callback)
             }
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => conn.set_metric_callback(_callback),
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => conn.set_metric_callback(_callback),
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => {
+                conn.set_metric_callback(_callback)
+            }
             _ => {}
         }
     }
 
+    /// Sets a callback to observe deadlock and lock wait timeout errors of this connection.
+    ///
+    /// The callback decides whether the failed statement should be executed again. Retries
+    /// back off exponentially and stop after [MAX_LOCK_ERR_RETRIES](crate::MAX_LOCK_ERR_RETRIES).
+    /// Only standalone statements are retried; inside a transaction the lock error
+    /// is returned as is, because the database may have aborted the whole transaction.
+    pub fn set_lock_err_callback<F>(&mut self, _callback: F)
+    where
+        F: Fn(&crate::LockErrInfo<'_>) -> crate::LockErrAction + Send + Sync + 'static,
+    {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => {
+                conn.set_lock_err_callback(_callback)
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.set_lock_err_callback(_callback)
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
+                conn.set_lock_err_callback(_callback)
+            }
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => conn.set_lock_err_callback(_callback),
+            _ => {}
+        }
+    }
+
+    /// Whether the connection was set up for CockroachDB with [ConnectOptions::cockroach](crate::ConnectOptions::cockroach)
+    pub fn is_cockroach(&self) -> bool {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.cockroach,
+            _ => false,
+        }
+    }
+
+    /// Whether the connection is to a DuckDB database, which reports [DbBackend::Postgres]
+    /// as its statements are built in the PostgreSQL dialect
+    pub fn is_duckdb(&self) -> bool {
+        match self {
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(_) => true,
+            _ => false,
+        }
+    }
+
+    fn serialization_retries(&self) -> u32 {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.serialization_retries,
+            _ => 0,
+        }
+    }
+
+    /// Execute the function inside a transaction, like [TransactionTrait::transaction], and run
+    /// the whole transaction again whenever it was aborted by a serialization failure.
+    ///
+    /// The transaction is retried up to [ConnectOptions::serialization_retries](crate::ConnectOptions::serialization_retries)
+    /// times, when committing fails or when the function returns an error that is, or was caused
+    /// by, a [serialization failure](DbErr::is_serialization_failure). The function may thus be
+    /// called more than once.
+    #[instrument(level = "trace", skip(callback))]
+    pub async fn transaction_with_retry<F, T, E>(
+        &self,
+        callback: F,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> Fn(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send
+            + Sync,
+        T: Send,
+        E: std::error::Error + Send + 'static,
+    {
+        crate::retry_transaction(self.serialization_retries(), || self.transaction(&callback)).await
+    }
+
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
         match self {
@@ -467,6 +683,12 @@ impl DatabaseConnection {
             DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.ping().await,
             #[cfg(feature = "sqlx-sqlite")]
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.ping().await,
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => conn.ping().await,
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => conn.ping().await,
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => conn.ping().await,
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => conn.ping(),
             #[cfg(feature = "proxy")]
@@ -490,6 +712,12 @@ impl DatabaseConnection {
             DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.close_by_ref().await,
             #[cfg(feature = "sqlx-sqlite")]
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.close_by_ref().await,
+            #[cfg(feature = "libsql")]
+            DatabaseConnection::LibsqlConnection(conn) => conn.close_by_ref().await,
+            #[cfg(feature = "duckdb")]
+            DatabaseConnection::DuckdbConnection(conn) => conn.close_by_ref().await,
+            #[cfg(feature = "tokio-postgres")]
+            DatabaseConnection::TokioPostgresConnection(conn) => conn.close_by_ref().await,
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(_) => {
                 // Nothing to cleanup, we just consume the `DatabaseConnection`
@@ -532,6 +760,20 @@ impl DatabaseConnection {
         }
     }
 
+    /// Get the [deadpool_postgres::Pool] of a tokio-postgres connection, e.g. to run a binary
+    /// `COPY` or to pipeline statements on one client
+    ///
+    /// # Panics
+    ///
+    /// Panics if [DbConn] is not a tokio-postgres connection.
+    #[cfg(feature = "tokio-postgres")]
+    pub fn get_tokio_postgres_pool(&self) -> &deadpool_postgres::Pool {
+        match self {
+            DatabaseConnection::TokioPostgresConnection(conn) => conn.pool(),
+            _ => panic!("Not tokio-postgres Connection"),
+        }
+    }
+
     /// Get [sqlx::SqlitePool]
     ///
     /// # Panics
@@ -544,6 +786,43 @@ impl DatabaseConnection {
             _ => panic!("Not SQLite Connection"),
         }
     }
+
+    /// Get [libsql::Database]
+    ///
+    /// # Panics
+    ///
+    /// Panics if [DbConn] is not a libSQL connection.
+    #[cfg(feature = "libsql")]
+    pub fn get_libsql_database(&self) -> &libsql::Database {
+        match self {
+            DatabaseConnection::LibsqlConnection(conn) => &conn.db,
+            _ => panic!("Not libSQL Connection"),
+        }
+    }
+
+    /// Pull the changes made on the remote database into the embedded replica of a libSQL
+    /// connection, returning the replication index (frame number) the replica is at.
+    ///
+    /// Returns an error if the connection is not a libSQL embedded replica.
+    #[cfg(feature = "libsql")]
+    pub async fn sync_replica(&self) -> Result<Option<u64>, DbErr> {
+        match self {
+            DatabaseConnection::LibsqlConnection(conn) => conn.sync().await,
+            _ => Err(conn_err("Not libSQL Connection")),
+        }
+    }
+
+    /// Create or replace a view named `view` over Parquet files, so an entity with
+    /// `table_name = "<view>"` can query them. `path` may be a glob, e.g. `data/trips-*.parquet`.
+    ///
+    /// Returns an error if the connection is not a DuckDB connection.
+    #[cfg(feature = "duckdb")]
+    pub async fn register_parquet(&self, view: &str, path: &str) -> Result<(), DbErr> {
+        match self {
+            DatabaseConnection::DuckdbConnection(conn) => conn.register_parquet(view, path).await,
+            _ => Err(conn_err("Not DuckDB Connection")),
+        }
+    }
 }
 
 impl DbBackend {
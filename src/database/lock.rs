@@ -0,0 +1,394 @@
+use crate::{DbErr, SqlErr, Statement, TransactionError};
+use std::{
+    error::Error,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+pub(crate) type LockErrCallback = Arc<dyn Fn(&LockErrInfo<'_>) -> LockErrAction + Send + Sync>;
+
+/// A statement is executed again at most this many times after lock errors, whatever the
+/// lock error callback asks for
+pub const MAX_LOCK_ERR_RETRIES: u32 = 5;
+
+/// The wait before the first retry, doubled on every following retry
+const LOCK_ERR_BACKOFF: Duration = Duration::from_millis(10);
+
+/// A deadlock or lock wait timeout encountered while executing a statement
+#[derive(Debug)]
+pub struct LockErrInfo<'a> {
+    /// The statement that failed
+    pub statement: &'a Statement,
+    /// An error classified as [SqlErr::Deadlock] or [SqlErr::LockTimeout] by [DbErr::sql_err]
+    pub error: &'a DbErr,
+    /// How many times the statement has failed so far, starting from 1
+    pub attempt: u32,
+}
+
+/// What to do with a statement after a lock error has been observed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockErrAction {
+    /// Return the error to the caller
+    Fail,
+    /// Execute the statement again after a backoff, unless it has already been retried
+    /// [MAX_LOCK_ERR_RETRIES] times
+    Retry,
+}
+
+impl DbErr {
+    /// Whether the database reported a deadlock or a lock wait timeout, i.e. [DbErr::sql_err]
+    /// is [SqlErr::Deadlock] or [SqlErr::LockTimeout]
+    pub fn is_lock_err(&self) -> bool {
+        matches!(
+            self.sql_err(),
+            Some(SqlErr::Deadlock(_) | SqlErr::LockTimeout(_))
+        )
+    }
+
+    /// Whether the database aborted the transaction with a serialization failure, i.e.
+    /// [DbErr::sql_err] is [SqlErr::SerializationFailure]
+    pub fn is_serialization_failure(&self) -> bool {
+        matches!(self.sql_err(), Some(SqlErr::SerializationFailure(_)))
+    }
+}
+
+/// Run a single statement, reporting lock errors to the callback and re-running the
+/// statement, with an exponential backoff, for as long as the callback asks to retry
+#[allow(dead_code)]
+pub(crate) async fn retry_on_lock_err<T, F, Fut>(
+    callback: &Option<LockErrCallback>,
+    stmt: &Statement,
+    mut f: F,
+) -> Result<T, DbErr>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, DbErr>>,
+{
+    let mut attempt = 0;
+    loop {
+        let res = f().await;
+        if let (Err(error), Some(callback)) = (&res, callback.as_deref()) {
+            if error.is_lock_err() {
+                attempt += 1;
+                let info = LockErrInfo {
+                    statement: stmt,
+                    error,
+                    attempt,
+                };
+                if callback(&info) == LockErrAction::Retry && attempt <= MAX_LOCK_ERR_RETRIES {
+                    sleep(LOCK_ERR_BACKOFF * 2u32.pow(attempt - 1)).await;
+                    continue;
+                }
+            }
+        }
+        return res;
+    }
+}
+
+/// Run a standalone statement again whenever it was aborted by a serialization failure,
+/// at most `max_retries` times
+#[allow(dead_code)]
+pub(crate) async fn retry_on_serialization_failure<T, F, Fut>(
+    max_retries: u32,
+    mut f: F,
+) -> Result<T, DbErr>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, DbErr>>,
+{
+    let mut retries = 0;
+    loop {
+        match f().await {
+            Err(err) if err.is_serialization_failure() && retries < max_retries => retries += 1,
+            res => return res,
+        }
+    }
+}
+
+/// Run a whole transaction again whenever it was aborted by a serialization failure,
+/// at most `max_retries` times. An error returned by the callback counts if it is,
+/// or was caused by, a [serialization failure](DbErr::is_serialization_failure).
+pub(crate) async fn retry_transaction<T, E, F, Fut>(
+    max_retries: u32,
+    mut f: F,
+) -> Result<T, TransactionError<E>>
+where
+    E: Error + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, TransactionError<E>>>,
+{
+    let mut retries = 0;
+    loop {
+        let res = f().await;
+        let retry = match &res {
+            Err(TransactionError::Connection(err)) => err.is_serialization_failure(),
+            Err(TransactionError::Transaction(err)) => caused_by_serialization_failure(err),
+            Ok(_) => false,
+        };
+        if !retry || retries == max_retries {
+            return res;
+        }
+        retries += 1;
+    }
+}
+
+fn caused_by_serialization_failure(err: &(dyn Error + 'static)) -> bool {
+    let mut err = Some(err);
+    while let Some(e) = err {
+        if e.downcast_ref()
+            .is_some_and(DbErr::is_serialization_failure)
+        {
+            return true;
+        }
+        err = e.source();
+    }
+    false
+}
+
+/// Wait on the timer of the async runtime enabled by the `runtime-*` features, or, outside of
+/// one, on a [Sleep]
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-actix"))]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return tokio::time::sleep(duration).await;
+    }
+    #[cfg(feature = "runtime-async-std")]
+    return async_std::task::sleep(duration).await;
+    #[allow(unreachable_code)]
+    Sleep::new(duration).await;
+}
+
+/// A timer which does not depend on an async runtime, waking the task from a thread
+struct Sleep {
+    deadline: Instant,
+    waker: Option<Arc<Mutex<Waker>>>,
+}
+
+impl Sleep {
+    fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + duration,
+            waker: None,
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let now = Instant::now();
+        if now >= self.deadline {
+            return Poll::Ready(());
+        }
+        match &self.waker {
+            Some(waker) => {
+                if let Ok(mut waker) = waker.lock() {
+                    waker.clone_from(cx.waker());
+                }
+            }
+            None => {
+                let waker = Arc::new(Mutex::new(cx.waker().clone()));
+                let remaining = self.deadline - now;
+                let thread_waker = waker.clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(remaining);
+                    if let Ok(waker) = thread_waker.lock() {
+                        waker.wake_by_ref();
+                    }
+                });
+                self.waker = Some(waker);
+            }
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DbBackend, RuntimeErr};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn deadlock() -> DbErr {
+        DbErr::Exec(RuntimeErr::Mock(SqlErr::Deadlock(
+            "Deadlock found".to_owned(),
+        )))
+    }
+
+    #[smol_potat::test]
+    async fn retry_until_success() {
+        let stmt = Statement::from_string(DbBackend::MySql, "UPDATE `cake` SET `name` = 'A'");
+        let callback: Option<LockErrCallback> = Some(Arc::new(|info: &LockErrInfo<'_>| {
+            assert!(matches!(info.error.sql_err(), Some(SqlErr::Deadlock(_))));
+            if info.attempt < 3 {
+                LockErrAction::Retry
+            } else {
+                LockErrAction::Fail
+            }
+        }));
+
+        let calls = AtomicU32::new(0);
+        let res = retry_on_lock_err(&callback, &stmt, || async {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err(deadlock()),
+                n => Ok(n),
+            }
+        })
+        .await;
+        assert_eq!(res, Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        calls.store(0, Ordering::SeqCst);
+        let res = retry_on_lock_err(&callback, &stmt, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(deadlock())
+        })
+        .await;
+        assert_eq!(res, Err(deadlock()));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[smol_potat::test]
+    async fn retries_are_capped() {
+        let stmt = Statement::from_string(DbBackend::MySql, "UPDATE `cake` SET `name` = 'A'");
+        let callback: Option<LockErrCallback> =
+            Some(Arc::new(|_: &LockErrInfo<'_>| LockErrAction::Retry));
+
+        let calls = AtomicU32::new(0);
+        let start = Instant::now();
+        let res = retry_on_lock_err(&callback, &stmt, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(deadlock())
+        })
+        .await;
+        assert_eq!(res, Err(deadlock()));
+        assert_eq!(calls.load(Ordering::SeqCst), MAX_LOCK_ERR_RETRIES + 1);
+        // 10 + 20 + 40 + 80 + 160 ms
+        assert!(start.elapsed() >= Duration::from_millis(310));
+    }
+
+    #[smol_potat::test]
+    async fn other_errors_are_not_retried() {
+        let stmt = Statement::from_string(DbBackend::Postgres, "SELECT 1");
+        let callback: Option<LockErrCallback> =
+            Some(Arc::new(|_: &LockErrInfo<'_>| LockErrAction::Retry));
+
+        let calls = AtomicU32::new(0);
+        let res = retry_on_lock_err(&callback, &stmt, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(DbErr::Custom("boom".to_owned()))
+        })
+        .await;
+        assert_eq!(res, Err(DbErr::Custom("boom".to_owned())));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        calls.store(0, Ordering::SeqCst);
+        let res = retry_on_lock_err(&None, &stmt, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(deadlock())
+        })
+        .await;
+        assert_eq!(res, Err(deadlock()));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    fn serialization_failure() -> DbErr {
+        DbErr::Exec(RuntimeErr::Mock(SqlErr::SerializationFailure(
+            "restart transaction".to_owned(),
+        )))
+    }
+
+    #[smol_potat::test]
+    async fn retry_statement_on_serialization_failure() {
+        let calls = AtomicU32::new(0);
+        let res = retry_on_serialization_failure(3, || async {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err(serialization_failure()),
+                n => Ok(n),
+            }
+        })
+        .await;
+        assert_eq!(res, Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        calls.store(0, Ordering::SeqCst);
+        let res = retry_on_serialization_failure(2, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(serialization_failure())
+        })
+        .await;
+        assert_eq!(res, Err(serialization_failure()));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        calls.store(0, Ordering::SeqCst);
+        let res = retry_on_serialization_failure(2, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(deadlock())
+        })
+        .await;
+        assert_eq!(res, Err(deadlock()));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    enum AppErr {
+        #[error("Database: {0}")]
+        Db(#[from] DbErr),
+        #[error("Out of stock")]
+        OutOfStock,
+    }
+
+    #[smol_potat::test]
+    async fn retry_transaction_on_serialization_failure() {
+        let calls = AtomicU32::new(0);
+        let res = retry_transaction(5, || async {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                // failed to commit
+                0 => Err(TransactionError::Connection(serialization_failure())),
+                // a statement in the callback failed
+                1 => Err(TransactionError::Transaction(AppErr::Db(
+                    serialization_failure(),
+                ))),
+                n => Ok(n),
+            }
+        })
+        .await;
+        assert!(matches!(res, Ok(2)));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        calls.store(0, Ordering::SeqCst);
+        let res = retry_transaction(5, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(TransactionError::Transaction(AppErr::OutOfStock))
+        })
+        .await;
+        assert!(matches!(
+            res,
+            Err(TransactionError::Transaction(AppErr::OutOfStock))
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        calls.store(0, Ordering::SeqCst);
+        let res = retry_transaction(1, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(TransactionError::Transaction(serialization_failure()))
+        })
+        .await;
+        assert!(
+            matches!(res, Err(TransactionError::Transaction(err)) if err.is_serialization_failure())
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[cfg(feature = "runtime-tokio")]
+    #[tokio::test]
+    async fn sleep_on_tokio_timer() {
+        let start = Instant::now();
+        sleep(Duration::from_millis(10)).await;
+        assert!(start.elapsed() >= Duration::from_millis(10));
+    }
+}
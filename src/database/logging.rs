@@ -0,0 +1,309 @@
+use crate::{
+    ConnectionTrait, DbBackend, DbErr, ExecResult, QueryResult, Statement, StreamTrait,
+    TransactionCallback,
+};
+use log::LevelFilter;
+#[cfg(any(
+    feature = "sqlx-dep",
+    feature = "tokio-postgres",
+    feature = "libsql",
+    feature = "duckdb"
+))]
+use std::time::Instant;
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+thread_local! {
+    static LEVEL: Cell<Option<LevelFilter>> = const { Cell::new(None) };
+}
+
+/// Statement logging settings of a connection whose statements SeaORM logs itself.
+///
+/// Drivers without statement logging of their own always log through these settings. SQLx can
+/// only configure logging per connection, so SQLx connections only do when
+/// [crate::ConnectOptions::statement_log_level_overrides] is set, turning off the logging of SQLx.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LogSettings {
+    enabled: bool,
+    statements_level: LevelFilter,
+    slow_statements_level: LevelFilter,
+    slow_statements_threshold: Duration,
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            statements_level: LevelFilter::Off,
+            slow_statements_level: LevelFilter::Off,
+            slow_statements_threshold: Duration::from_secs(1),
+        }
+    }
+}
+
+impl LogSettings {
+    #[cfg(any(
+        feature = "sqlx-dep",
+        feature = "tokio-postgres",
+        feature = "libsql",
+        feature = "duckdb"
+    ))]
+    pub(crate) fn new(options: &crate::ConnectOptions) -> Self {
+        let mut settings = Self {
+            enabled: true,
+            ..Default::default()
+        };
+        if options.sqlx_logging {
+            settings.statements_level = options.sqlx_logging_level;
+            settings.slow_statements_level = options.sqlx_slow_statements_logging_level;
+            settings.slow_statements_threshold = options.sqlx_slow_statements_logging_threshold;
+        }
+        settings
+    }
+
+    /// The settings of a SQLx connection, which leaves the logging to SQLx unless overrides
+    /// are enabled
+    #[cfg(feature = "sqlx-dep")]
+    pub(crate) fn for_sqlx(options: &crate::ConnectOptions) -> Self {
+        if options.statement_log_level_overrides {
+            Self::new(options)
+        } else {
+            Self::default()
+        }
+    }
+
+    /// The level a statement should be logged at, given how long it took if known
+    pub(crate) fn level(&self, elapsed: Option<Duration>) -> LevelFilter {
+        if !self.enabled {
+            return LevelFilter::Off;
+        }
+        if let Some(level) = LEVEL.with(Cell::get) {
+            return level;
+        }
+        match elapsed {
+            Some(elapsed)
+                if self.slow_statements_level != LevelFilter::Off
+                    && elapsed >= self.slow_statements_threshold =>
+            {
+                self.slow_statements_level
+            }
+            _ => self.statements_level,
+        }
+    }
+
+    /// Log a statement; the values are never logged
+    pub(crate) fn log(&self, stmt: &Statement, elapsed: Option<Duration>) {
+        // keep the target of SQLx so existing log filters continue to work
+        macro_rules! log_stmt {
+            ($level:expr) => {
+                match elapsed {
+                    Some(elapsed) => tracing::event!(
+                        target: "sqlx::query",
+                        $level,
+                        ?elapsed,
+                        elapsed_secs = elapsed.as_secs_f64(),
+                        "{}",
+                        stmt.sql
+                    ),
+                    None => tracing::event!(target: "sqlx::query", $level, "{}", stmt.sql),
+                }
+            };
+        }
+        match self.level(elapsed).to_level() {
+            Some(log::Level::Error) => log_stmt!(tracing::Level::ERROR),
+            Some(log::Level::Warn) => log_stmt!(tracing::Level::WARN),
+            Some(log::Level::Info) => log_stmt!(tracing::Level::INFO),
+            Some(log::Level::Debug) => log_stmt!(tracing::Level::DEBUG),
+            Some(log::Level::Trace) => log_stmt!(tracing::Level::TRACE),
+            None => {}
+        }
+    }
+
+    /// Execute a statement and log it afterwards along with the time taken
+    #[cfg(any(
+        feature = "sqlx-dep",
+        feature = "tokio-postgres",
+        feature = "libsql",
+        feature = "duckdb"
+    ))]
+    pub(crate) async fn run<F, T>(&self, stmt: &Statement, fut: F) -> T
+    where
+        F: Future<Output = T>,
+    {
+        let start = Instant::now();
+        let res = fut.await;
+        self.log(stmt, Some(start.elapsed()));
+        res
+    }
+}
+
+/// A future running with the logging level of a [WithLogLevel] connection
+struct LevelScope<F> {
+    level: LevelFilter,
+    future: Pin<Box<F>>,
+}
+
+impl<F> LevelScope<F> {
+    fn new(level: LevelFilter, future: F) -> Self {
+        Self {
+            level,
+            future: Box::pin(future),
+        }
+    }
+}
+
+impl<F> Future for LevelScope<F>
+where
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let _guard = LevelGuard::enter(this.level);
+        this.future.as_mut().poll(cx)
+    }
+}
+
+/// Restores the previous level when dropped, so scopes nest and survive panics
+struct LevelGuard {
+    previous: Option<LevelFilter>,
+}
+
+impl LevelGuard {
+    fn enter(level: LevelFilter) -> Self {
+        Self {
+            previous: LEVEL.with(|current| current.replace(Some(level))),
+        }
+    }
+}
+
+impl Drop for LevelGuard {
+    fn drop(&mut self) {
+        LEVEL.with(|current| current.set(self.previous));
+    }
+}
+
+/// A connection overriding the logging level of the statements executed through it,
+/// created by [ConnectionTrait::with_log_level]. On SQLx connections, the level is only
+/// honored with [crate::ConnectOptions::statement_log_level_overrides] set.
+#[derive(Debug)]
+pub struct WithLogLevel<'c, C> {
+    conn: &'c C,
+    level: LevelFilter,
+}
+
+impl<'c, C> WithLogLevel<'c, C> {
+    pub(crate) fn new(conn: &'c C, level: LevelFilter) -> Self {
+        Self { conn, level }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> ConnectionTrait for WithLogLevel<'_, C>
+where
+    C: ConnectionTrait,
+{
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        LevelScope::new(self.level, self.conn.execute(stmt)).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        LevelScope::new(self.level, self.conn.execute_unprepared(sql)).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        LevelScope::new(self.level, self.conn.query_one(stmt)).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        LevelScope::new(self.level, self.conn.query_all(stmt)).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.conn.is_mock_connection()
+    }
+
+    async fn on_transaction_end(&self, callback: TransactionCallback) {
+        self.conn.on_transaction_end(callback).await
+    }
+}
+
+impl<C> StreamTrait for WithLogLevel<'_, C>
+where
+    C: StreamTrait,
+{
+    type Stream<'a>
+        = C::Stream<'a>
+    where
+        Self: 'a;
+
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        Box::pin(LevelScope::new(self.level, self.conn.stream(stmt)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(
+        feature = "sqlx-dep",
+        feature = "tokio-postgres",
+        feature = "libsql",
+        feature = "duckdb"
+    ))]
+    #[smol_potat::test]
+    async fn scope_overrides_connection_level() {
+        let mut options = crate::ConnectOptions::new("sqlite::memory:");
+        options
+            .sqlx_logging_level(LevelFilter::Info)
+            .sqlx_slow_statements_logging_settings(LevelFilter::Warn, Duration::from_secs(1));
+        let settings = LogSettings::new(&options);
+
+        assert_eq!(settings.level(None), LevelFilter::Info);
+        assert_eq!(
+            settings.level(Some(Duration::from_secs(2))),
+            LevelFilter::Warn
+        );
+
+        let levels = LevelScope::new(LevelFilter::Off, async {
+            (
+                settings.level(None),
+                settings.level(Some(Duration::from_secs(2))),
+            )
+        })
+        .await;
+        assert_eq!(levels, (LevelFilter::Off, LevelFilter::Off));
+        assert_eq!(settings.level(None), LevelFilter::Info);
+
+        options.sqlx_logging(false);
+        let settings = LogSettings::new(&options);
+        assert_eq!(settings.level(None), LevelFilter::Off);
+        let level = LevelScope::new(LevelFilter::Debug, async { settings.level(None) }).await;
+        assert_eq!(level, LevelFilter::Debug);
+    }
+
+    #[smol_potat::test]
+    async fn scope_ignored_when_not_logging() {
+        let settings = LogSettings::default();
+        let level = LevelScope::new(LevelFilter::Debug, async { settings.level(None) }).await;
+        assert_eq!(level, LevelFilter::Off);
+    }
+}
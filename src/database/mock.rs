@@ -1,10 +1,10 @@
 use crate::{
     error::*, DatabaseConnection, DbBackend, EntityTrait, ExecResult, ExecResultHolder, Iden,
-    IdenStatic, Iterable, MockDatabaseConnection, MockDatabaseTrait, ModelTrait, QueryResult,
-    QueryResultRow, SelectA, SelectB, Statement,
+    IdenStatic, Iterable, MockDatabaseConnection, MockDatabaseTrait, MockQueryStream, ModelTrait,
+    QueryResult, QueryResultRow, SelectA, SelectB, Statement,
 };
 use sea_query::{Value, ValueType, Values};
-use std::{collections::BTreeMap, sync::Arc};
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
 use tracing::instrument;
 
 /// Defines a Mock database suitable for testing
@@ -14,7 +14,12 @@ pub struct MockDatabase {
     transaction: Option<OpenTransaction>,
     transaction_log: Vec<Transaction>,
     exec_results: Vec<Result<MockExecResult, DbErr>>,
-    query_results: Vec<Result<Vec<MockRow>, DbErr>>,
+    query_results: Vec<Result<MockStream, DbErr>>,
+    expectations: Vec<MockExpectation>,
+    exec_matched: usize,
+    query_matched: usize,
+    faults: Vec<MockFault>,
+    operations: usize,
 }
 
 /// Defines the results obtained from a [MockDatabase]
@@ -34,12 +39,63 @@ pub struct MockRow {
     pub(crate) values: BTreeMap<String, Value>,
 }
 
+/// Rows returned by the [MockDatabase] for a query. When the query is streamed, the rows
+/// are delivered one at a time, with the delays and error in between.
+#[derive(Debug, Default)]
+pub struct MockStream {
+    items: Vec<MockStreamItem>,
+}
+
+#[derive(Debug)]
+enum MockStreamItem {
+    Row(MockRow),
+    Delay(Duration),
+    Error(DbErr),
+}
+
 /// A trait to get a [MockRow] from a type useful for testing in the [MockDatabase]
 pub trait IntoMockRow {
     /// The method to perform this operation
     fn into_mock_row(self) -> MockRow;
 }
 
+/// Decides whether a [Statement] is handled by a [MockExpectation]
+pub struct StatementMatcher {
+    description: String,
+    matches: Box<dyn Fn(&Statement) -> bool + Send>,
+}
+
+/// A result returned by the [MockDatabase] whenever a statement satisfying the
+/// [StatementMatcher] is executed, regardless of the order the statements are executed in
+pub struct MockExpectation {
+    matcher: StatementMatcher,
+    response: MockResponse,
+    times: Option<usize>,
+    calls: usize,
+}
+
+type MockResponder<T> = Box<dyn FnMut(&Statement) -> Result<T, DbErr> + Send>;
+
+enum MockResponse {
+    Exec(MockResponder<MockExecResult>),
+    Query(MockResponder<Vec<MockRow>>),
+}
+
+/// An error or latency injected by the [MockDatabase] before a statement, `BEGIN` or `COMMIT`
+/// is carried out. A failed statement is neither logged nor does it consume a result.
+pub struct MockFault {
+    kind: MockFaultKind,
+    at: usize,
+    matcher: Option<StatementMatcher>,
+    times: usize,
+    fired: usize,
+}
+
+enum MockFaultKind {
+    Error(Box<dyn Fn() -> DbErr + Send>),
+    Latency(Duration),
+}
+
 /// Defines a transaction that is has not been committed
 #[derive(Debug)]
 pub struct OpenTransaction {
@@ -53,6 +109,24 @@ pub struct Transaction {
     stmts: Vec<Statement>,
 }
 
+/// A transaction control statement recorded by the [MockDatabase], nested transactions
+/// are recorded as savepoints numbered by their depth
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionEvent {
+    /// `BEGIN`
+    Begin,
+    /// `SAVEPOINT savepoint_{n}`
+    Savepoint(usize),
+    /// `RELEASE SAVEPOINT savepoint_{n}`
+    ReleaseSavepoint(usize),
+    /// `ROLLBACK TO SAVEPOINT savepoint_{n}`
+    RollbackToSavepoint(usize),
+    /// `COMMIT`
+    Commit,
+    /// `ROLLBACK`
+    Rollback,
+}
+
 impl MockDatabase {
     /// Instantiate a mock database with a [DbBackend] to simulate real
     /// world SQL databases
@@ -63,6 +137,11 @@ impl MockDatabase {
             transaction_log: Vec::new(),
             exec_results: Vec::new(),
             query_results: Vec::new(),
+            expectations: Vec::new(),
+            exec_matched: 0,
+            query_matched: 0,
+            faults: Vec::new(),
+            operations: 0,
         }
     }
 
@@ -87,13 +166,18 @@ impl MockDatabase {
         I: IntoIterator<Item = T>,
         II: IntoIterator<Item = I>,
     {
-        for row in vec.into_iter() {
-            let row = row.into_iter().map(|vec| Ok(vec.into_mock_row())).collect();
-            self.query_results.push(row);
+        for rows in vec.into_iter() {
+            self.query_results.push(Ok(MockStream::new().rows(rows)));
         }
         self
     }
 
+    /// Add a [MockStream] to `query_results`, it is delivered row by row when streamed
+    pub fn append_query_stream(mut self, stream: MockStream) -> Self {
+        self.query_results.push(Ok(stream));
+        self
+    }
+
     /// Add some [DbErr]s to `exec_results`
     pub fn append_exec_errors<I>(mut self, vec: I) -> Self
     where
@@ -111,16 +195,90 @@ impl MockDatabase {
         self.query_results.extend(vec.into_iter().map(Result::Err));
         self
     }
+
+    /// Add a [MockFault], faults are checked in the order they were added
+    pub fn append_fault(mut self, fault: MockFault) -> Self {
+        self.faults.push(fault);
+        self
+    }
+
+    /// Add a [MockExpectation]. Statements are checked against the expectations in the
+    /// order they were added, and only statements matching none of them consume
+    /// `exec_results` and `query_results`.
+    pub fn append_expectation(mut self, expectation: MockExpectation) -> Self {
+        self.expectations.push(expectation);
+        self
+    }
+
+    fn respond_exec(&mut self, statement: &Statement) -> Option<Result<MockExecResult, DbErr>> {
+        self.expectations
+            .iter_mut()
+            .filter(|e| !e.saturated() && e.matcher.matches(statement))
+            .find_map(|e| match &mut e.response {
+                MockResponse::Exec(respond) => {
+                    e.calls += 1;
+                    Some(respond(statement))
+                }
+                _ => None,
+            })
+    }
+
+    fn next_query_result(
+        &mut self,
+        counter: usize,
+        statement: Statement,
+    ) -> Result<MockStream, DbErr> {
+        let response = self.respond_query(&statement);
+        if let Some(transaction) = &mut self.transaction {
+            transaction.push(statement);
+        } else {
+            self.transaction_log.push(Transaction::one(statement));
+        }
+        if let Some(result) = response {
+            self.query_matched += 1;
+            return result.map(|rows| MockStream::new().rows(rows));
+        }
+        let counter = counter - self.query_matched;
+        if counter < self.query_results.len() {
+            std::mem::replace(
+                &mut self.query_results[counter],
+                Err(query_err("this value has been consumed already")),
+            )
+        } else {
+            Err(query_err("`query_results` buffer is empty."))
+        }
+    }
+
+    fn respond_query(&mut self, statement: &Statement) -> Option<Result<Vec<MockRow>, DbErr>> {
+        self.expectations
+            .iter_mut()
+            .filter(|e| !e.saturated() && e.matcher.matches(statement))
+            .find_map(|e| match &mut e.response {
+                MockResponse::Query(respond) => {
+                    e.calls += 1;
+                    Some(respond(statement))
+                }
+                _ => None,
+            })
+    }
 }
 
 impl MockDatabaseTrait for MockDatabase {
     #[instrument(level = "trace")]
     fn execute(&mut self, counter: usize, statement: Statement) -> Result<ExecResult, DbErr> {
+        let response = self.respond_exec(&statement);
         if let Some(transaction) = &mut self.transaction {
             transaction.push(statement);
         } else {
             self.transaction_log.push(Transaction::one(statement));
         }
+        if let Some(result) = response {
+            self.exec_matched += 1;
+            return result.map(|result| ExecResult {
+                result: ExecResultHolder::Mock(result),
+            });
+        }
+        let counter = counter - self.exec_matched;
         if counter < self.exec_results.len() {
             match std::mem::replace(
                 &mut self.exec_results[counter],
@@ -138,26 +296,16 @@ impl MockDatabaseTrait for MockDatabase {
 
     #[instrument(level = "trace")]
     fn query(&mut self, counter: usize, statement: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        if let Some(transaction) = &mut self.transaction {
-            transaction.push(statement);
-        } else {
-            self.transaction_log.push(Transaction::one(statement));
-        }
-        if counter < self.query_results.len() {
-            match std::mem::replace(
-                &mut self.query_results[counter],
-                Err(query_err("this value has been consumed already")),
-            ) {
-                Ok(result) => Ok(result
-                    .into_iter()
-                    .map(|row| QueryResult {
-                        row: QueryResultRow::Mock(row),
-                    })
-                    .collect()),
-                Err(err) => Err(err),
-            }
-        } else {
-            Err(query_err("`query_results` buffer is empty."))
+        self.next_query_result(counter, statement)?
+            .into_rows()
+            .map(into_query_results)
+    }
+
+    #[instrument(level = "trace")]
+    fn query_stream(&mut self, counter: usize, statement: Statement) -> MockQueryStream {
+        match self.next_query_result(counter, statement) {
+            Ok(stream) => stream.into_stream(),
+            Err(err) => Box::pin(futures_util::stream::once(async { Err(err) })),
         }
     }
 
@@ -208,6 +356,178 @@ impl MockDatabaseTrait for MockDatabase {
     fn ping(&self) -> Result<(), DbErr> {
         Ok(())
     }
+
+    fn fault(&mut self, stmt: &Statement) -> Result<Option<Duration>, DbErr> {
+        let operation = self.operations;
+        self.operations += 1;
+        match self.faults.iter_mut().find(|f| f.fires(operation, stmt)) {
+            Some(fault) => {
+                fault.fired += 1;
+                match &fault.kind {
+                    MockFaultKind::Error(err) => Err(err()),
+                    MockFaultKind::Latency(latency) => Ok(Some(*latency)),
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn verify_expectations(&self) -> Result<(), DbErr> {
+        let unmet: Vec<_> = self
+            .expectations
+            .iter()
+            .filter_map(|e| match e.times {
+                Some(times) if times != e.calls => Some(format!(
+                    "{} expected to be called {} times, was called {} times",
+                    e.matcher.description, times, e.calls
+                )),
+                _ => None,
+            })
+            .collect();
+        if unmet.is_empty() {
+            Ok(())
+        } else {
+            Err(DbErr::Custom(unmet.join("; ")))
+        }
+    }
+}
+
+fn into_query_results(rows: Vec<MockRow>) -> Vec<QueryResult> {
+    rows.into_iter()
+        .map(|row| QueryResult {
+            row: QueryResultRow::Mock(row),
+        })
+        .collect()
+}
+
+impl StatementMatcher {
+    /// Match statements by a predicate, the description is used in error messages
+    pub fn new<D, F>(description: D, f: F) -> Self
+    where
+        D: Into<String>,
+        F: Fn(&Statement) -> bool + Send + 'static,
+    {
+        Self {
+            description: description.into(),
+            matches: Box::new(f),
+        }
+    }
+
+    /// Match statements with exactly this SQL
+    pub fn sql<T>(sql: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let sql = sql.into();
+        Self::new(format!("statement `{sql}`"), move |stmt| stmt.sql == sql)
+    }
+
+    /// Match statements whose SQL contains this fragment
+    pub fn sql_contains<T>(fragment: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let fragment = fragment.into();
+        Self::new(format!("statement containing `{fragment}`"), move |stmt| {
+            stmt.sql.contains(&fragment)
+        })
+    }
+
+    /// Match statements whose SQL matches this regular expression, which fails if it's invalid
+    pub fn sql_regex<T>(pattern: T) -> Result<Self, regex::Error>
+    where
+        T: AsRef<str>,
+    {
+        let regex = regex::Regex::new(pattern.as_ref())?;
+        Ok(Self::new(
+            format!("statement matching `{regex}`"),
+            move |stmt| regex.is_match(&stmt.sql),
+        ))
+    }
+
+    /// Whether the statement satisfies this matcher
+    pub fn matches(&self, stmt: &Statement) -> bool {
+        (self.matches)(stmt)
+    }
+}
+
+impl std::fmt::Debug for StatementMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StatementMatcher")
+            .field(&self.description)
+            .finish()
+    }
+}
+
+impl MockExpectation {
+    /// Return these rows every time a matching query is executed
+    pub fn query<T, I>(matcher: StatementMatcher, rows: I) -> Self
+    where
+        T: IntoMockRow,
+        I: IntoIterator<Item = T>,
+    {
+        let rows: Vec<MockRow> = rows.into_iter().map(IntoMockRow::into_mock_row).collect();
+        Self::query_with(matcher, move |_| Ok(rows.clone()))
+    }
+
+    /// Compute the result of a matching query from the statement
+    pub fn query_with<F>(matcher: StatementMatcher, f: F) -> Self
+    where
+        F: FnMut(&Statement) -> Result<Vec<MockRow>, DbErr> + Send + 'static,
+    {
+        Self::new(matcher, MockResponse::Query(Box::new(f)))
+    }
+
+    /// Return this result every time a matching statement is executed
+    pub fn exec(matcher: StatementMatcher, result: MockExecResult) -> Self {
+        Self::exec_with(matcher, move |_| Ok(result.clone()))
+    }
+
+    /// Compute the result of a matching statement from the statement
+    pub fn exec_with<F>(matcher: StatementMatcher, f: F) -> Self
+    where
+        F: FnMut(&Statement) -> Result<MockExecResult, DbErr> + Send + 'static,
+    {
+        Self::new(matcher, MockResponse::Exec(Box::new(f)))
+    }
+
+    fn new(matcher: StatementMatcher, response: MockResponse) -> Self {
+        Self {
+            matcher,
+            response,
+            times: None,
+            calls: 0,
+        }
+    }
+
+    /// Expect exactly this many matching statements. Further matching statements are
+    /// left to the next expectations, and
+    /// [DatabaseConnection::verify_expectations] fails if fewer were executed.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    fn saturated(&self) -> bool {
+        self.times.is_some_and(|times| self.calls >= times)
+    }
+}
+
+impl std::fmt::Debug for MockExpectation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockExpectation")
+            .field("matcher", &self.matcher)
+            .field(
+                "kind",
+                &match self.response {
+                    MockResponse::Exec(_) => "exec",
+                    MockResponse::Query(_) => "query",
+                },
+            )
+            .field("times", &self.times)
+            .field("calls", &self.calls)
+            .finish()
+    }
 }
 
 impl MockRow {
@@ -324,6 +644,166 @@ where
     }
 }
 
+impl MockFault {
+    /// Fail with the error returned by the closure
+    pub fn error<F>(f: F) -> Self
+    where
+        F: Fn() -> DbErr + Send + 'static,
+    {
+        Self::new(MockFaultKind::Error(Box::new(f)))
+    }
+
+    /// Fail as if the connection was dropped
+    pub fn connection_closed() -> Self {
+        Self::error(|| DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed))
+    }
+
+    /// Fail as if no connection could be acquired in time
+    pub fn timeout() -> Self {
+        Self::error(|| DbErr::ConnectionAcquire(ConnAcquireErr::Timeout))
+    }
+
+    /// Fail as if the transaction could not be serialized
+    pub fn serialization_failure() -> Self {
+        Self::error(|| {
+            DbErr::Exec(RuntimeErr::Mock(SqlErr::SerializationFailure(
+                "could not serialize access due to concurrent update".to_owned(),
+            )))
+        })
+    }
+
+    /// Fail as if the statement was chosen as the deadlock victim
+    pub fn deadlock() -> Self {
+        Self::error(|| {
+            DbErr::Exec(RuntimeErr::Mock(SqlErr::Deadlock(
+                "deadlock detected".to_owned(),
+            )))
+        })
+    }
+
+    /// Wait before carrying out the statement
+    pub fn latency(duration: Duration) -> Self {
+        Self::new(MockFaultKind::Latency(duration))
+    }
+
+    fn new(kind: MockFaultKind) -> Self {
+        Self {
+            kind,
+            at: 0,
+            matcher: None,
+            times: 1,
+            fired: 0,
+        }
+    }
+
+    /// Only inject the fault from the n-th operation on, counting every statement, `BEGIN`
+    /// and `COMMIT` from zero
+    pub fn at(mut self, operation: usize) -> Self {
+        self.at = operation;
+        self
+    }
+
+    /// Only inject the fault into statements satisfying the matcher. `BEGIN` and `COMMIT`
+    /// are matched as statements with exactly this SQL.
+    pub fn when(mut self, matcher: StatementMatcher) -> Self {
+        self.matcher = Some(matcher);
+        self
+    }
+
+    /// Inject the fault this many times, once by default
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = times;
+        self
+    }
+
+    fn fires(&self, operation: usize, stmt: &Statement) -> bool {
+        self.fired < self.times
+            && operation >= self.at
+            && self.matcher.as_ref().map_or(true, |m| m.matches(stmt))
+    }
+}
+
+impl std::fmt::Debug for MockFault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = f.debug_struct("MockFault");
+        match &self.kind {
+            MockFaultKind::Error(err) => s.field("error", &err()),
+            MockFaultKind::Latency(latency) => s.field("latency", latency),
+        };
+        s.field("at", &self.at)
+            .field("matcher", &self.matcher)
+            .field("times", &self.times)
+            .field("fired", &self.fired)
+            .finish()
+    }
+}
+
+impl MockStream {
+    /// Create an empty stream
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deliver these rows
+    pub fn rows<T, I>(mut self, rows: I) -> Self
+    where
+        T: IntoMockRow,
+        I: IntoIterator<Item = T>,
+    {
+        self.items.extend(
+            rows.into_iter()
+                .map(|row| MockStreamItem::Row(row.into_mock_row())),
+        );
+        self
+    }
+
+    /// Wait before delivering the next row, ignored when the query is not streamed
+    pub fn delay(mut self, duration: Duration) -> Self {
+        self.items.push(MockStreamItem::Delay(duration));
+        self
+    }
+
+    /// Fail with this error, ending the stream
+    pub fn error(mut self, err: DbErr) -> Self {
+        self.items.push(MockStreamItem::Error(err));
+        self
+    }
+
+    fn into_rows(self) -> Result<Vec<MockRow>, DbErr> {
+        let mut rows = Vec::new();
+        for item in self.items {
+            match item {
+                MockStreamItem::Row(row) => rows.push(row),
+                MockStreamItem::Delay(_) => {}
+                MockStreamItem::Error(err) => return Err(err),
+            }
+        }
+        Ok(rows)
+    }
+
+    fn into_stream(self) -> MockQueryStream {
+        Box::pin(futures_util::stream::unfold(
+            self.items.into_iter(),
+            |mut items| async move {
+                loop {
+                    match items.next()? {
+                        MockStreamItem::Row(row) => {
+                            let row = QueryResult {
+                                row: QueryResultRow::Mock(row),
+                            };
+                            return Some((Ok(row), items));
+                        }
+                        MockStreamItem::Delay(duration) => crate::sleep(duration).await,
+                        MockStreamItem::Error(err) => {
+                            return Some((Err(err), Vec::new().into_iter()));
+                        }
+                    }
+                }
+            },
+        ))
+    }
+}
+
 impl Transaction {
     /// Get the [Value]s from s raw SQL statement depending on the [DatabaseBackend](crate::DatabaseBackend)
     pub fn from_sql_and_values<I, T>(db_backend: DbBackend, sql: T, values: I) -> Self
@@ -364,6 +844,44 @@ impl Transaction {
     pub fn statements(&self) -> &[Statement] {
         &self.stmts
     }
+
+    /// Get the begin, savepoint, commit and rollback statements in the order they were issued
+    pub fn events(&self) -> Vec<TransactionEvent> {
+        self.stmts
+            .iter()
+            .filter_map(TransactionEvent::from_statement)
+            .collect()
+    }
+
+    /// Whether the transaction was committed, as opposed to rolled back
+    pub fn is_committed(&self) -> bool {
+        self.events().last() == Some(&TransactionEvent::Commit)
+    }
+
+    /// Whether the transaction was rolled back, as opposed to committed
+    pub fn is_rolled_back(&self) -> bool {
+        self.events().last() == Some(&TransactionEvent::Rollback)
+    }
+}
+
+impl TransactionEvent {
+    fn from_statement(stmt: &Statement) -> Option<Self> {
+        let savepoint = |prefix: &str| {
+            stmt.sql
+                .strip_prefix(prefix)
+                .and_then(|n| n.strip_prefix("savepoint_"))
+                .and_then(|n| n.parse().ok())
+        };
+        match stmt.sql.as_str() {
+            "BEGIN" => Some(Self::Begin),
+            "COMMIT" => Some(Self::Commit),
+            "ROLLBACK" => Some(Self::Rollback),
+            _ => savepoint("SAVEPOINT ")
+                .map(Self::Savepoint)
+                .or_else(|| savepoint("RELEASE SAVEPOINT ").map(Self::ReleaseSavepoint))
+                .or_else(|| savepoint("ROLLBACK TO SAVEPOINT ").map(Self::RollbackToSavepoint)),
+        }
+    }
 }
 
 impl OpenTransaction {
@@ -426,8 +944,9 @@ impl OpenTransaction {
 #[cfg(feature = "mock")]
 mod tests {
     use crate::{
-        entity::*, error::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase, Statement,
-        Transaction, TransactionError, TransactionTrait,
+        entity::*, error::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase,
+        MockExecResult, MockExpectation, MockFault, MockStream, Statement, StatementMatcher,
+        Transaction, TransactionError, TransactionEvent, TransactionTrait,
     };
     use pretty_assertions::assert_eq;
 
@@ -626,6 +1145,195 @@ mod tests {
         );
     }
 
+    #[smol_potat::test]
+    async fn test_nested_transaction_rollback() -> Result<(), DbErr> {
+        use TransactionEvent::*;
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let txn = db.begin().await?;
+        {
+            let nested = txn.begin().await?;
+            let _ = cake::Entity::find().all(&nested).await;
+            nested.rollback().await?;
+        }
+        {
+            let nested = txn.begin().await?;
+            let _ = fruit::Entity::find().all(&nested).await;
+            {
+                let _dropped = nested.begin().await?;
+            }
+            nested.commit().await?;
+        }
+        txn.commit().await?;
+
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log[0].events(),
+            [
+                Begin,
+                Savepoint(1),
+                RollbackToSavepoint(1),
+                Savepoint(1),
+                Savepoint(2),
+                RollbackToSavepoint(2),
+                ReleaseSavepoint(1),
+                Commit,
+            ]
+        );
+        assert!(log[0].is_committed());
+        assert!(!log[0].is_rolled_back());
+        assert_eq!(log[0].statements().len(), 10);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_expectations() -> Result<(), DbErr> {
+        let apple = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: None,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_expectation(
+                MockExpectation::query(
+                    StatementMatcher::sql_contains(r#"FROM "fruit""#),
+                    [apple.clone()],
+                )
+                .times(2),
+            )
+            .append_expectation(MockExpectation::exec(
+                StatementMatcher::new("delete cake", |stmt| {
+                    stmt.sql.starts_with(r#"DELETE FROM "cake""#)
+                }),
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 3,
+                },
+            ))
+            .append_query_results([Vec::<cake::Model>::new()])
+            .into_connection();
+
+        assert_eq!(cake::Entity::find().all(&db).await?, []);
+        assert_eq!(
+            cake::Entity::delete_many().exec(&db).await?.rows_affected,
+            3
+        );
+        assert_eq!(fruit::Entity::find().one(&db).await?, Some(apple.clone()));
+        assert_eq!(fruit::Entity::find().all(&db).await?, [apple]);
+        assert_eq!(
+            fruit::Entity::find().all(&db).await,
+            Err(query_err("`query_results` buffer is empty."))
+        );
+
+        db.verify_expectations();
+        assert_eq!(db.into_transaction_log().len(), 5);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[should_panic(expected = "statement containing `UPDATE` expected to be called 1 times")]
+    async fn test_unmet_expectation() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_expectation(
+                MockExpectation::exec(
+                    StatementMatcher::sql_contains("UPDATE"),
+                    MockExecResult::default(),
+                )
+                .times(1),
+            )
+            .into_connection();
+
+        db.verify_expectations();
+    }
+
+    #[smol_potat::test]
+    async fn test_regex_expectation() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_expectation(
+                MockExpectation::exec(
+                    StatementMatcher::sql_regex(r#"^DELETE FROM "(cake|fruit)""#).unwrap(),
+                    MockExecResult {
+                        last_insert_id: 0,
+                        rows_affected: 2,
+                    },
+                )
+                .times(2),
+            )
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::delete_many().exec(&db).await?.rows_affected,
+            2
+        );
+        assert_eq!(
+            fruit::Entity::delete_many().exec(&db).await?.rows_affected,
+            2
+        );
+        db.verify_expectations();
+
+        assert!(StatementMatcher::sql_regex("(").is_err());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_faults() -> Result<(), DbErr> {
+        use std::time::{Duration, Instant};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_fault(MockFault::connection_closed().at(1))
+            .append_fault(
+                MockFault::serialization_failure()
+                    .when(StatementMatcher::sql("COMMIT"))
+                    .times(2),
+            )
+            .append_fault(MockFault::latency(Duration::from_millis(50)).at(3))
+            .append_query_results([
+                Vec::<cake::Model>::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+            ])
+            .into_connection();
+
+        cake::Entity::find().all(&db).await?;
+        assert_eq!(
+            cake::Entity::find().all(&db).await,
+            Err(DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed))
+        );
+
+        let mut attempts = 0;
+        let start = Instant::now();
+        let res = loop {
+            attempts += 1;
+            let res = db
+                .transaction::<_, _, DbErr>(|txn| {
+                    Box::pin(async move { cake::Entity::find().all(txn).await })
+                })
+                .await;
+            match res {
+                Err(TransactionError::Connection(err)) if err.is_serialization_failure() => {
+                    continue
+                }
+                res => break res,
+            }
+        };
+        assert!(matches!(res, Ok(cakes) if cakes.is_empty()));
+        assert_eq!(attempts, 3);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 4);
+        assert!(log[1..3].iter().all(Transaction::is_rolled_back));
+        assert!(log[3].is_committed());
+
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn test_stream_1() -> Result<(), DbErr> {
         use futures_util::TryStreamExt;
@@ -675,6 +1383,45 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn test_stream_delay_and_error() -> Result<(), DbErr> {
+        use futures_util::TryStreamExt;
+        use std::time::{Duration, Instant};
+
+        let apple = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: Some(1),
+        };
+
+        let stream = || {
+            MockStream::new()
+                .rows([apple.clone()])
+                .delay(Duration::from_millis(50))
+                .error(query_err("connection reset"))
+                .rows([apple.clone()])
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_stream(stream())
+            .append_query_stream(stream())
+            .into_connection();
+
+        let mut stream = fruit::Entity::find().stream(&db).await?;
+        assert_eq!(stream.try_next().await?, Some(apple.clone()));
+        let start = Instant::now();
+        assert_eq!(stream.try_next().await, Err(query_err("connection reset")));
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert_eq!(stream.try_next().await?, None);
+        drop(stream);
+
+        assert_eq!(
+            fruit::Entity::find().all(&db).await,
+            Err(query_err("connection reset"))
+        );
+
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn test_stream_in_transaction() -> Result<(), DbErr> {
         use futures_util::TryStreamExt;
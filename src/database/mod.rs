@@ -2,24 +2,38 @@ use std::time::Duration;
 
 mod connection;
 mod db_connection;
+mod lock;
+mod logging;
 #[cfg(feature = "mock")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
 mod mock;
 #[cfg(feature = "proxy")]
 #[cfg_attr(docsrs, doc(cfg(feature = "proxy")))]
 mod proxy;
+mod read_only;
+#[cfg(feature = "proxy-replay")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proxy-replay")))]
+mod replay;
+mod rls;
 mod statement;
 mod stream;
 mod transaction;
 
 pub use connection::*;
 pub use db_connection::*;
+pub use lock::*;
+pub use logging::*;
 #[cfg(feature = "mock")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
 pub use mock::*;
 #[cfg(feature = "proxy")]
 #[cfg_attr(docsrs, doc(cfg(feature = "proxy")))]
 pub use proxy::*;
+pub use read_only::*;
+#[cfg(feature = "proxy-replay")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proxy-replay")))]
+pub use replay::*;
+pub use rls::*;
 pub use statement::*;
 use std::borrow::Cow;
 pub use stream::*;
@@ -58,10 +72,28 @@ pub struct ConnectOptions {
     pub(crate) sqlx_slow_statements_logging_level: log::LevelFilter,
     /// SQLx slow statements duration threshold (ignored if `sqlx_logging` is false)
     pub(crate) sqlx_slow_statements_logging_threshold: Duration,
+    /// Log the statements of SQLx connections in SeaORM, so that their level can be overridden
+    pub(crate) statement_log_level_overrides: bool,
     /// set sqlcipher key
     pub(crate) sqlcipher_key: Option<Cow<'static, str>>,
+    /// PRAGMAs to set on every SQLite connection (SQLite only)
+    pub(crate) sqlite_pragmas: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    /// Extensions to load on every SQLite connection, with optional entry points (SQLite only)
+    pub(crate) sqlite_extensions: Vec<(Cow<'static, str>, Option<Cow<'static, str>>)>,
+    /// Busy timeout of every SQLite connection (SQLite only)
+    pub(crate) sqlite_busy_timeout: Option<Duration>,
+    /// Auth token of a remote libSQL database (libSQL only)
+    pub(crate) libsql_auth_token: Option<String>,
+    /// Local file of an embedded replica of a remote libSQL database (libSQL only)
+    pub(crate) libsql_replica_path: Option<String>,
+    /// How often an embedded replica syncs with the remote database (libSQL only)
+    pub(crate) libsql_sync_interval: Option<Duration>,
     /// Schema search path (PostgreSQL only)
     pub(crate) schema_search_path: Option<String>,
+    /// The PostgreSQL database is CockroachDB
+    pub(crate) cockroach: bool,
+    /// How many times a statement or transaction aborted by a serialization failure is retried
+    pub(crate) serialization_retries: Option<u32>,
     pub(crate) test_before_acquire: bool,
     /// Only establish connections to the DB as needed. If set to `true`, the db connection will
     /// be created using SQLx's [connect_lazy](https://docs.rs/sqlx/latest/sqlx/struct.Pool.html#method.connect_lazy)
@@ -83,6 +115,11 @@ impl Database {
         if DbBackend::MySql.is_prefix_of(&opt.url) {
             return crate::SqlxMySqlConnector::connect(opt).await;
         }
+        // tokio-postgres takes over PostgreSQL when enabled, even alongside sqlx-postgres
+        #[cfg(feature = "tokio-postgres")]
+        if crate::TokioPostgresConnector::accepts(&opt.url) {
+            return crate::TokioPostgresConnector::connect(opt).await;
+        }
         #[cfg(feature = "sqlx-postgres")]
         if DbBackend::Postgres.is_prefix_of(&opt.url) {
             return crate::SqlxPostgresConnector::connect(opt).await;
@@ -91,11 +128,26 @@ impl Database {
         if DbBackend::Sqlite.is_prefix_of(&opt.url) {
             return crate::SqlxSqliteConnector::connect(opt).await;
         }
+        #[cfg(feature = "libsql")]
+        if crate::LibsqlConnector::accepts(&opt.url) {
+            return crate::LibsqlConnector::connect(opt).await;
+        }
+        #[cfg(feature = "duckdb")]
+        if crate::DuckdbConnector::accepts(&opt.url) {
+            return crate::DuckdbConnector::connect(opt).await;
+        }
         #[cfg(feature = "mock")]
         if crate::MockDatabaseConnector::accepts(&opt.url) {
             return crate::MockDatabaseConnector::connect(&opt.url).await;
         }
 
+        // see "SQL Server" in DESIGN.md
+        if opt.url.starts_with("mssql:") || opt.url.starts_with("sqlserver:") {
+            return Err(conn_err(
+                "SQL Server is not supported, as sea-query has no T-SQL backend",
+            ));
+        }
+
         Err(conn_err(format!(
             "The connection string '{}' has no supporting driver.",
             opt.url
@@ -130,6 +182,23 @@ impl Database {
             }
         }
     }
+
+    /// Method to create a [DatabaseConnection] on a database driver running in the browser,
+    /// e.g. sql.js or wa-sqlite backed by OPFS
+    #[cfg(all(
+        feature = "wasm",
+        target_arch = "wasm32",
+        not(target_feature = "atomics")
+    ))]
+    #[instrument(level = "trace", skip(driver))]
+    pub async fn connect_wasm<D>(db_type: DbBackend, driver: D) -> Result<DatabaseConnection, DbErr>
+    where
+        D: crate::WasmDatabaseTrait + 'static,
+    {
+        let proxy: Box<dyn ProxyDatabaseTrait> =
+            Box::new(crate::driver::wasm::WasmProxy(Box::new(driver)));
+        crate::ProxyDatabaseConnector::connect(db_type, std::sync::Arc::new(proxy))
+    }
 }
 
 impl<T> From<T> for ConnectOptions
@@ -159,8 +228,17 @@ impl ConnectOptions {
             sqlx_logging_level: log::LevelFilter::Info,
             sqlx_slow_statements_logging_level: log::LevelFilter::Off,
             sqlx_slow_statements_logging_threshold: Duration::from_secs(1),
+            statement_log_level_overrides: false,
             sqlcipher_key: None,
+            sqlite_pragmas: Vec::new(),
+            sqlite_extensions: Vec::new(),
+            sqlite_busy_timeout: None,
+            libsql_auth_token: None,
+            libsql_replica_path: None,
+            libsql_sync_interval: None,
             schema_search_path: None,
+            cockroach: false,
+            serialization_retries: None,
             test_before_acquire: true,
             connect_lazy: false,
         }
@@ -280,6 +358,20 @@ impl ConnectOptions {
         )
     }
 
+    /// Let [ConnectionTrait::with_log_level] override the logging level of statements on SQLx
+    /// connections (default false). SQLx can only configure logging per connection, so SeaORM
+    /// then logs the statements in place of SQLx, under the same target and with the same
+    /// settings. Other drivers always honor the override.
+    pub fn statement_log_level_overrides(&mut self, value: bool) -> &mut Self {
+        self.statement_log_level_overrides = value;
+        self
+    }
+
+    /// Get whether the logging level of statements on SQLx connections can be overridden
+    pub fn get_statement_log_level_overrides(&self) -> bool {
+        self.statement_log_level_overrides
+    }
+
     /// set key for sqlcipher
     pub fn sqlcipher_key<T>(&mut self, value: T) -> &mut Self
     where
@@ -289,6 +381,106 @@ impl ConnectOptions {
         self
     }
 
+    /// Set a PRAGMA on every connection of the pool, e.g. `journal_mode` to `WAL` or
+    /// `foreign_keys` to `OFF` (SQLite only). Setting the same PRAGMA again overrides it.
+    pub fn sqlite_pragma<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    {
+        let key = key.into();
+        let value = value.into();
+        match self.sqlite_pragmas.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.sqlite_pragmas.push((key, value)),
+        }
+        self
+    }
+
+    /// Get the PRAGMAs set on every connection of the pool (SQLite only)
+    pub fn get_sqlite_pragmas(&self) -> &[(Cow<'static, str>, Cow<'static, str>)] {
+        &self.sqlite_pragmas
+    }
+
+    /// Load an extension, e.g. `mod_spatialite`, on every connection of the pool (SQLite only)
+    pub fn sqlite_extension<T>(&mut self, name: T) -> &mut Self
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        self.sqlite_extensions.push((name.into(), None));
+        self
+    }
+
+    /// Load an extension with a custom entry point on every connection of the pool (SQLite only)
+    pub fn sqlite_extension_with_entrypoint<T, E>(&mut self, name: T, entry_point: E) -> &mut Self
+    where
+        T: Into<Cow<'static, str>>,
+        E: Into<Cow<'static, str>>,
+    {
+        self.sqlite_extensions
+            .push((name.into(), Some(entry_point.into())));
+        self
+    }
+
+    /// Get the extensions loaded on every connection of the pool (SQLite only)
+    pub fn get_sqlite_extensions(&self) -> &[(Cow<'static, str>, Option<Cow<'static, str>>)] {
+        &self.sqlite_extensions
+    }
+
+    /// Set how long a connection waits for a locked database before returning
+    /// `SQLITE_BUSY` (SQLite only, default 5 seconds)
+    pub fn sqlite_busy_timeout(&mut self, value: Duration) -> &mut Self {
+        self.sqlite_busy_timeout = Some(value);
+        self
+    }
+
+    /// Get the busy timeout of SQLite connections
+    pub fn get_sqlite_busy_timeout(&self) -> Option<Duration> {
+        self.sqlite_busy_timeout
+    }
+
+    /// Set the auth token of a remote libSQL database, e.g. a Turso database (libSQL only)
+    pub fn libsql_auth_token<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.libsql_auth_token = Some(value.into());
+        self
+    }
+
+    /// Get the auth token of a remote libSQL database
+    pub fn get_libsql_auth_token(&self) -> Option<&str> {
+        self.libsql_auth_token.as_deref()
+    }
+
+    /// Keep an embedded replica of a remote libSQL database in a local file, serving reads
+    /// locally and forwarding writes to the remote database (libSQL only)
+    pub fn libsql_replica_path<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.libsql_replica_path = Some(value.into());
+        self
+    }
+
+    /// Get the local file of an embedded replica of a remote libSQL database
+    pub fn get_libsql_replica_path(&self) -> Option<&str> {
+        self.libsql_replica_path.as_deref()
+    }
+
+    /// Set how often an embedded replica syncs with the remote database in the background.
+    /// Without it the replica is only synced on connect and by
+    /// `DatabaseConnection::sync_replica` (libSQL only)
+    pub fn libsql_sync_interval(&mut self, value: Duration) -> &mut Self {
+        self.libsql_sync_interval = Some(value);
+        self
+    }
+
+    /// Get how often an embedded replica syncs with the remote database
+    pub fn get_libsql_sync_interval(&self) -> Option<Duration> {
+        self.libsql_sync_interval
+    }
+
     /// Set schema search path (PostgreSQL only)
     pub fn set_schema_search_path<T>(&mut self, schema_search_path: T) -> &mut Self
     where
@@ -298,6 +490,62 @@ impl ConnectOptions {
         self
     }
 
+    /// Talk to CockroachDB over the PostgreSQL wire protocol (PostgreSQL only).
+    ///
+    /// CockroachDB runs every transaction as `SERIALIZABLE` and asks clients to retry the ones
+    /// it aborts with a serialization failure, so standalone statements are then retried
+    /// up to [`serialization_retries`](Self::serialization_retries) times (default 5).
+    /// Use [`DatabaseConnection::transaction_with_retry`] to retry whole transactions.
+    pub fn cockroach(&mut self, value: bool) -> &mut Self {
+        self.cockroach = value;
+        self
+    }
+
+    /// Get whether the database is CockroachDB
+    pub fn get_cockroach(&self) -> bool {
+        self.cockroach
+    }
+
+    /// Set how many times a standalone statement, or a transaction run by
+    /// [`DatabaseConnection::transaction_with_retry`], is retried after it was aborted by a
+    /// serialization failure (PostgreSQL only, default 0, or 5 for CockroachDB)
+    pub fn serialization_retries(&mut self, value: u32) -> &mut Self {
+        self.serialization_retries = Some(value);
+        self
+    }
+
+    /// Get how many times an operation aborted by a serialization failure is retried
+    pub fn get_serialization_retries(&self) -> u32 {
+        match self.serialization_retries {
+            Some(retries) => retries,
+            None if self.cockroach => 5,
+            None => 0,
+        }
+    }
+
+    /// The statement setting the schema search path of every new connection
+    #[cfg(any(feature = "sqlx-postgres", feature = "tokio-postgres"))]
+    pub(crate) fn set_search_path_sql(&self) -> Option<String> {
+        self.schema_search_path.as_ref().map(|schema| {
+            let mut string = "SET search_path = ".to_owned();
+            if schema.starts_with('"') {
+                string.push_str(schema);
+            } else {
+                for (i, schema) in schema.split(',').enumerate() {
+                    if i > 0 {
+                        string.push(',');
+                    }
+                    if schema.starts_with('"') {
+                        string.push_str(schema);
+                    } else {
+                        string.push_str(&format!("\"{schema}\""));
+                    }
+                }
+            }
+            string
+        })
+    }
+
     /// If true, the connection will be pinged upon acquiring from the pool (default true).
     pub fn test_before_acquire(&mut self, value: bool) -> &mut Self {
         self.test_before_acquire = value;
@@ -316,3 +564,16 @@ impl ConnectOptions {
         self.connect_lazy
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[smol_potat::test]
+    async fn connect_sql_server() {
+        let err = Database::connect("mssql://sa@localhost/master")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("SQL Server is not supported"));
+    }
+}
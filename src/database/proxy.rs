@@ -21,12 +21,51 @@ pub trait ProxyDatabaseTrait: Send + Sync + std::fmt::Debug {
     /// Rollback a transaction in the [ProxyDatabase]
     async fn rollback(&self) {}
 
+    /// Rollback a transaction in the [ProxyDatabase] that was dropped without a commit or
+    /// rollback. Called from `Drop`, so it can't await; the rollback can be queued and
+    /// performed on the next call.
+    fn start_rollback(&self) {}
+
     /// Ping the [ProxyDatabase], it should return an error if the database is not available
     async fn ping(&self) -> Result<(), DbErr> {
         Ok(())
     }
 }
 
+#[async_trait::async_trait]
+impl<T> ProxyDatabaseTrait for std::sync::Arc<T>
+where
+    T: ProxyDatabaseTrait,
+{
+    async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+        self.as_ref().query(statement).await
+    }
+
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+        self.as_ref().execute(statement).await
+    }
+
+    async fn begin(&self) {
+        self.as_ref().begin().await
+    }
+
+    async fn commit(&self) {
+        self.as_ref().commit().await
+    }
+
+    async fn rollback(&self) {
+        self.as_ref().rollback().await
+    }
+
+    fn start_rollback(&self) {
+        self.as_ref().start_rollback()
+    }
+
+    async fn ping(&self) -> Result<(), DbErr> {
+        self.as_ref().ping().await
+    }
+}
+
 /// Defines the results obtained from a [ProxyDatabase]
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct ProxyExecResult {
@@ -78,6 +117,21 @@ impl From<ExecResult> for ProxyExecResult {
                 last_insert_id: result.last_insert_rowid() as u64,
                 rows_affected: result.rows_affected(),
             },
+            #[cfg(feature = "libsql")]
+            ExecResultHolder::Libsql(result) => Self {
+                last_insert_id: result.last_insert_rowid as u64,
+                rows_affected: result.rows_affected,
+            },
+            #[cfg(feature = "duckdb")]
+            ExecResultHolder::Duckdb(result) => Self {
+                last_insert_id: 0,
+                rows_affected: result.rows_affected,
+            },
+            #[cfg(feature = "tokio-postgres")]
+            ExecResultHolder::TokioPostgres(result) => Self {
+                last_insert_id: 0,
+                rows_affected: result.rows_affected,
+            },
             #[cfg(feature = "mock")]
             ExecResultHolder::Mock(result) => Self {
                 last_insert_id: result.last_insert_id,
@@ -90,7 +144,7 @@ impl From<ExecResult> for ProxyExecResult {
 
 /// Defines the structure of a Row for the [ProxyDatabase]
 /// which is just a [BTreeMap]<[String], [Value]>
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct ProxyRow {
     /// The values of the single row
     pub values: BTreeMap<String, Value>,
@@ -103,14 +157,6 @@ impl ProxyRow {
     }
 }
 
-impl Default for ProxyRow {
-    fn default() -> Self {
-        Self {
-            values: BTreeMap::new(),
-        }
-    }
-}
-
 impl From<BTreeMap<String, Value>> for ProxyRow {
     fn from(values: BTreeMap<String, Value>) -> Self {
         Self { values }
@@ -138,9 +184,9 @@ impl From<ProxyRow> for QueryResult {
 }
 
 #[cfg(feature = "with-json")]
-impl Into<serde_json::Value> for ProxyRow {
-    fn into(self) -> serde_json::Value {
-        self.values
+impl From<ProxyRow> for serde_json::Value {
+    fn from(row: ProxyRow) -> Self {
+        row.values
             .into_iter()
             .map(|(k, v)| (k, sea_query::sea_value_to_json_value(&v)))
             .collect()
@@ -156,6 +202,18 @@ pub fn from_query_result_to_proxy_row(result: &QueryResult) -> ProxyRow {
         QueryResultRow::SqlxPostgres(row) => crate::from_sqlx_postgres_row_to_proxy_row(&row),
         #[cfg(feature = "sqlx-sqlite")]
         QueryResultRow::SqlxSqlite(row) => crate::from_sqlx_sqlite_row_to_proxy_row(&row),
+        #[cfg(feature = "libsql")]
+        QueryResultRow::Libsql(row) => ProxyRow {
+            values: row.clone().into_column_value_tuples().collect(),
+        },
+        #[cfg(feature = "duckdb")]
+        QueryResultRow::Duckdb(row) => ProxyRow {
+            values: row.clone().into_column_value_tuples().collect(),
+        },
+        #[cfg(feature = "tokio-postgres")]
+        QueryResultRow::TokioPostgres(row) => ProxyRow {
+            values: row.clone().into_column_value_tuples().collect(),
+        },
         #[cfg(feature = "mock")]
         QueryResultRow::Mock(row) => ProxyRow {
             values: row.values.clone(),
@@ -202,11 +260,12 @@ mod tests {
         entity::*, tests_cfg::*, Database, DbBackend, DbErr, ProxyDatabaseTrait, ProxyExecResult,
         ProxyRow, Statement,
     };
-    use std::sync::{Arc, Mutex};
+    use std::sync::Arc;
 
     #[derive(Debug)]
     struct ProxyDb {}
 
+    #[async_trait::async_trait]
     impl ProxyDatabaseTrait for ProxyDb {
         async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
             println!("SQL query: {}", statement.sql);
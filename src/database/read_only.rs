@@ -0,0 +1,145 @@
+use crate::{
+    ConnectionTrait, DbBackend, DbErr, QueryResult, ReadConnectionTrait, Statement, StreamTrait,
+};
+use std::{future::Future, pin::Pin};
+
+/// A connection that can only read, created by [ConnectionTrait::as_read_only].
+///
+/// It implements [ReadConnectionTrait] but not [ConnectionTrait] nor
+/// [TransactionTrait](crate::TransactionTrait), so selects, counts, paginators, cursors and
+/// loaders run on it, while inserts, updates, deletes and transactions don't compile. Take it
+/// in code that must never write, and create it from a read replica or from the primary
+/// connection.
+///
+/// Statements passed to [ReadConnectionTrait::read_all] and the like are not checked, the type
+/// only rules out the write APIs.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model { id: 1, name: "Cheese Cake".to_owned() }]])
+/// #     .into_connection();
+/// #
+/// use sea_orm::ReadOnlyConnection;
+///
+/// async fn list_cakes(db: &ReadOnlyConnection<'_, DbConn>) -> Result<Vec<cake::Model>, DbErr> {
+///     cake::Entity::find().all(db).await
+/// }
+///
+/// let cakes = list_cakes(&db.as_read_only()).await?;
+/// assert_eq!(cakes[0].name, "Cheese Cake");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// async fn rename(db: &ReadOnlyConnection<'_, DbConn>) -> Result<(), DbErr> {
+///     let cake = cake::ActiveModel {
+///         id: ActiveValue::Unchanged(1),
+///         name: ActiveValue::Set("Lemon Cake".to_owned()),
+///     };
+///     cake.update(db).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ReadOnlyConnection<'c, C> {
+    conn: &'c C,
+}
+
+impl<'c, C> ReadOnlyConnection<'c, C>
+where
+    C: ConnectionTrait,
+{
+    pub(crate) fn new(conn: &'c C) -> Self {
+        Self { conn }
+    }
+}
+
+impl<C> Clone for ReadOnlyConnection<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for ReadOnlyConnection<'_, C> {}
+
+#[async_trait::async_trait]
+impl<C> ReadConnectionTrait for ReadOnlyConnection<'_, C>
+where
+    C: ConnectionTrait,
+{
+    fn read_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    async fn read_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.conn.query_one(stmt).await
+    }
+
+    async fn read_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn.query_all(stmt).await
+    }
+}
+
+impl<C> StreamTrait for ReadOnlyConnection<'_, C>
+where
+    C: ConnectionTrait + StreamTrait,
+{
+    type Stream<'a>
+        = C::Stream<'a>
+    where
+        Self: 'a;
+
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.conn.stream(stmt)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, tests_cfg::*, ConnectionTrait, DbBackend, DbErr, LoaderTrait, MockDatabase,
+        PaginatorTrait,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn read_through_read_only() -> Result<(), DbErr> {
+        let cheese = cake::Model {
+            id: 1,
+            name: "Cheese Cake".to_owned(),
+        };
+        let lemon = fruit::Model {
+            id: 7,
+            name: "Lemon".to_owned(),
+            cake_id: Some(1),
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cheese.clone()]])
+            .append_query_results([[lemon.clone()]])
+            .append_query_results([[maplit::btreemap! {
+                "num_items" => Into::<crate::Value>::into(1i64),
+            }]])
+            .into_connection();
+        let db = db.as_read_only();
+
+        let cakes = cake::Entity::find().all(&db).await?;
+        assert_eq!(cakes, [cheese]);
+        assert_eq!(cakes.load_many(fruit::Entity, &db).await?, [[lemon]]);
+        assert_eq!(cake::Entity::find().count(&db).await?, 1);
+
+        Ok(())
+    }
+}
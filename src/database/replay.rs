@@ -0,0 +1,608 @@
+//! Record and replay database traffic through the proxy connection.
+//!
+//! Run the test suite once against a real database with a [ProxyRecorder] in
+//! front of it, save the recorded [Cassette], then connect a [ProxyReplayer]
+//! over that cassette in CI. The replayer answers every statement with the
+//! recorded response, in order, without a database.
+//!
+//! ```ignore
+//! // record run
+//! let recorder = Arc::new(ProxyRecorder::new(Database::connect(url).await?));
+//! let db = Database::connect_proxy(DbBackend::Postgres, Arc::new(Box::new(recorder.clone()))).await?;
+//! run_scenario(&db).await?;
+//! recorder.save("tests/cassettes/scenario.json")?;
+//!
+//! // replay run
+//! let replayer = ProxyReplayer::load("tests/cassettes/scenario.json")?;
+//! let db = Database::connect_proxy(DbBackend::Postgres, Arc::new(Box::new(replayer))).await?;
+//! run_scenario(&db).await?;
+//! ```
+
+use crate::{
+    bincode::SerdeValue, error::*, from_query_result_to_proxy_row, ConnectionTrait,
+    DatabaseConnection, DatabaseTransaction, ProxyDatabaseTrait, ProxyExecResult, ProxyRow,
+    Statement, TransactionTrait,
+};
+use futures_util::lock::{Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+/// The recorded interactions with a database, in the order they happened
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    /// The recorded interactions
+    pub interactions: Vec<Interaction>,
+}
+
+/// A statement, or transaction boundary, and the response the database gave
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum Interaction {
+    /// A query and its rows, or the error it failed with
+    Query {
+        /// The statement sent
+        statement: RecordedStatement,
+        /// The rows returned
+        response: Result<Vec<RecordedRow>, String>,
+    },
+    /// A command and its result, or the error it failed with
+    Execute {
+        /// The statement sent
+        statement: RecordedStatement,
+        /// The result returned
+        response: Result<ProxyExecResult, String>,
+    },
+    /// A transaction was started
+    Begin,
+    /// A transaction was committed
+    Commit,
+    /// A transaction was rolled back
+    Rollback,
+}
+
+/// The SQL and values of a recorded [Statement]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedStatement {
+    /// The SQL query
+    pub sql: String,
+    /// The values for the SQL statement's parameters
+    pub values: Vec<SerdeValue>,
+}
+
+/// A recorded [ProxyRow]
+pub type RecordedRow = BTreeMap<String, SerdeValue>;
+
+impl From<&Statement> for RecordedStatement {
+    fn from(statement: &Statement) -> Self {
+        Self {
+            sql: statement.sql.clone(),
+            values: statement
+                .values
+                .iter()
+                .flat_map(|values| values.0.iter().cloned().map(SerdeValue))
+                .collect(),
+        }
+    }
+}
+
+impl RecordedStatement {
+    fn describe(&self, kind: &str) -> String {
+        let values: Vec<_> = self.values.iter().map(|v| &v.0).collect();
+        format!("{kind} `{}` with {values:?}", self.sql)
+    }
+}
+
+impl Interaction {
+    fn describe(&self) -> String {
+        match self {
+            Self::Query { statement, .. } => statement.describe("query"),
+            Self::Execute { statement, .. } => statement.describe("execute"),
+            Self::Begin => "begin".to_owned(),
+            Self::Commit => "commit".to_owned(),
+            Self::Rollback => "rollback".to_owned(),
+        }
+    }
+}
+
+impl Cassette {
+    /// Read a cassette saved as JSON
+    pub fn load<P>(path: P) -> Result<Self, DbErr>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)
+            .map_err(|err| DbErr::Custom(format!("Failed to open {}: {err}", path.display())))?;
+        serde_json::from_reader(std::io::BufReader::new(file)).map_err(json_err)
+    }
+
+    /// Save the cassette as JSON, replacing the file if it exists
+    pub fn save<P>(&self, path: P) -> Result<(), DbErr>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(json_err)?;
+        std::fs::write(path, json)
+            .map_err(|err| DbErr::Custom(format!("Failed to write {}: {err}", path.display())))
+    }
+}
+
+/// Forwards statements to a real connection and records every request and response.
+///
+/// Transactions are forwarded too: the statements sent inside one run in a transaction of
+/// the real connection, nested ones in a savepoint, so a rolled back transaction leaves the
+/// database as it was. One dropped without a commit or rollback is rolled back on the next
+/// call.
+#[derive(Debug)]
+pub struct ProxyRecorder {
+    conn: DatabaseConnection,
+    cassette: Mutex<Cassette>,
+    transactions: AsyncMutex<Transactions>,
+    /// The number of transactions dropped without a commit or rollback, rolled back on the
+    /// next call
+    abandoned: AtomicUsize,
+}
+
+/// The open transactions of a [ProxyRecorder], innermost last
+#[derive(Debug, Default)]
+struct Transactions {
+    open: Vec<DatabaseTransaction>,
+    /// The error of a boundary that failed, reported by the next statement, as the boundaries
+    /// of a proxy can't fail
+    failed: Option<DbErr>,
+}
+
+impl ProxyRecorder {
+    /// Record the traffic sent to `conn`
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self {
+            conn,
+            cassette: Default::default(),
+            transactions: Default::default(),
+            abandoned: Default::default(),
+        }
+    }
+
+    /// The interactions recorded so far
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cassette mutex is poisoned, by a thread that panicked while recording.
+    pub fn cassette(&self) -> Cassette {
+        self.cassette
+            .lock()
+            .expect("Fail to acquire cassette")
+            .clone()
+    }
+
+    /// Save the interactions recorded so far as JSON
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cassette mutex is poisoned, by a thread that panicked while recording.
+    pub fn save<P>(&self, path: P) -> Result<(), DbErr>
+    where
+        P: AsRef<Path>,
+    {
+        self.cassette().save(path)
+    }
+
+    /// Lock the open transactions, rolling back the innermost ones that were abandoned
+    async fn transactions(&self) -> AsyncMutexGuard<'_, Transactions> {
+        let mut transactions = self.transactions.lock().await;
+        for _ in 0..self.abandoned.swap(0, Ordering::SeqCst) {
+            if let Some(txn) = transactions.open.pop() {
+                if let Err(err) = txn.rollback().await {
+                    transactions.failed = Some(err);
+                }
+            }
+        }
+        transactions
+    }
+
+    fn record(&self, interaction: Interaction) {
+        self.cassette
+            .lock()
+            .expect("Fail to acquire cassette")
+            .interactions
+            .push(interaction);
+    }
+}
+
+#[async_trait::async_trait]
+impl ProxyDatabaseTrait for ProxyRecorder {
+    async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+        let recorded = RecordedStatement::from(&statement);
+        let mut transactions = self.transactions().await;
+        if let Some(err) = transactions.failed.take() {
+            return Err(err);
+        }
+        let rows = match transactions.open.last() {
+            Some(txn) => txn.query_all(statement).await,
+            None => self.conn.query_all(statement).await,
+        };
+        drop(transactions);
+        let result = rows.map(|rows| {
+            rows.iter()
+                .map(from_query_result_to_proxy_row)
+                .collect::<Vec<_>>()
+        });
+        let response = match &result {
+            Ok(rows) => Ok(rows
+                .iter()
+                .map(|row| {
+                    row.values
+                        .iter()
+                        .map(|(k, v)| (k.clone(), SerdeValue(v.clone())))
+                        .collect()
+                })
+                .collect()),
+            Err(err) => Err(err.to_string()),
+        };
+        self.record(Interaction::Query {
+            statement: recorded,
+            response,
+        });
+        result
+    }
+
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+        let recorded = RecordedStatement::from(&statement);
+        let mut transactions = self.transactions().await;
+        if let Some(err) = transactions.failed.take() {
+            return Err(err);
+        }
+        let result = match transactions.open.last() {
+            Some(txn) => txn.execute(statement).await,
+            None => self.conn.execute(statement).await,
+        }
+        .map(ProxyExecResult::from);
+        drop(transactions);
+        self.record(Interaction::Execute {
+            statement: recorded,
+            response: result
+                .as_ref()
+                .map(Clone::clone)
+                .map_err(ToString::to_string),
+        });
+        result
+    }
+
+    async fn begin(&self) {
+        self.record(Interaction::Begin);
+        let mut transactions = self.transactions().await;
+        let txn = match transactions.open.last() {
+            Some(txn) => txn.begin().await,
+            None => self.conn.begin().await,
+        };
+        match txn {
+            Ok(txn) => transactions.open.push(txn),
+            Err(err) => transactions.failed = Some(err),
+        }
+    }
+
+    async fn commit(&self) {
+        self.record(Interaction::Commit);
+        let mut transactions = self.transactions().await;
+        if let Some(txn) = transactions.open.pop() {
+            if let Err(err) = txn.commit().await {
+                transactions.failed = Some(err);
+            }
+        }
+    }
+
+    async fn rollback(&self) {
+        self.record(Interaction::Rollback);
+        let mut transactions = self.transactions().await;
+        if let Some(txn) = transactions.open.pop() {
+            if let Err(err) = txn.rollback().await {
+                transactions.failed = Some(err);
+            }
+        }
+    }
+
+    fn start_rollback(&self) {
+        self.record(Interaction::Rollback);
+        self.abandoned.fetch_add(1, Ordering::SeqCst);
+    }
+
+    async fn ping(&self) -> Result<(), DbErr> {
+        self.conn.ping().await
+    }
+}
+
+/// Answers statements from a [Cassette], in the order they were recorded.
+///
+/// A statement whose SQL or values differ from the next recorded one fails with an error
+/// naming both, so a change in the generated SQL shows up as a test failure.
+#[derive(Debug)]
+pub struct ProxyReplayer {
+    interactions: Vec<Interaction>,
+    position: Mutex<usize>,
+}
+
+impl ProxyReplayer {
+    /// Replay the interactions of a cassette
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            interactions: cassette.interactions,
+            position: Mutex::new(0),
+        }
+    }
+
+    /// Replay a cassette saved as JSON
+    pub fn load<P>(path: P) -> Result<Self, DbErr>
+    where
+        P: AsRef<Path>,
+    {
+        Cassette::load(path).map(Self::new)
+    }
+
+    /// Whether every recorded interaction has been replayed
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position mutex is poisoned, by a thread that panicked while replaying.
+    pub fn is_finished(&self) -> bool {
+        *self.position.lock().expect("Fail to acquire position") == self.interactions.len()
+    }
+
+    fn next(&self, request: &str) -> Result<&Interaction, DbErr> {
+        let mut position = self.position.lock().expect("Fail to acquire position");
+        let interaction = self.interactions.get(*position).ok_or_else(|| {
+            DbErr::Custom(format!(
+                "Replay exhausted after {position} interactions, got {request}"
+            ))
+        })?;
+        *position += 1;
+        Ok(interaction)
+    }
+
+    fn mismatch(interaction: &Interaction, request: &str) -> DbErr {
+        DbErr::Custom(format!(
+            "Replay mismatch: expected {}, got {request}",
+            interaction.describe()
+        ))
+    }
+
+    /// Transaction boundaries cannot fail, so an unexpected one is skipped
+    /// and the divergence surfaces at the next statement
+    fn boundary(&self, boundary: Interaction) {
+        let mut position = self.position.lock().expect("Fail to acquire position");
+        if let Some(interaction) = self.interactions.get(*position) {
+            if matches!(
+                (interaction, boundary),
+                (Interaction::Begin, Interaction::Begin)
+                    | (Interaction::Commit, Interaction::Commit)
+                    | (Interaction::Rollback, Interaction::Rollback)
+            ) {
+                *position += 1;
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProxyDatabaseTrait for ProxyReplayer {
+    async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+        let recorded = RecordedStatement::from(&statement);
+        let request = recorded.describe("query");
+        let interaction = self.next(&request)?;
+        match interaction {
+            Interaction::Query {
+                statement: expected,
+                response,
+            } if *expected == recorded => match response {
+                Ok(rows) => Ok(rows
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|(k, v)| (k.clone(), v.0.clone()))
+                            .collect::<BTreeMap<_, _>>()
+                            .into()
+                    })
+                    .collect()),
+                Err(err) => Err(query_err(err)),
+            },
+            _ => Err(Self::mismatch(interaction, &request)),
+        }
+    }
+
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+        let recorded = RecordedStatement::from(&statement);
+        let request = recorded.describe("execute");
+        let interaction = self.next(&request)?;
+        match interaction {
+            Interaction::Execute {
+                statement: expected,
+                response,
+            } if *expected == recorded => response.clone().map_err(exec_err),
+            _ => Err(Self::mismatch(interaction, &request)),
+        }
+    }
+
+    async fn begin(&self) {
+        self.boundary(Interaction::Begin);
+    }
+
+    async fn commit(&self) {
+        self.boundary(Interaction::Commit);
+    }
+
+    async fn rollback(&self) {
+        self.boundary(Interaction::Rollback);
+    }
+
+    fn start_rollback(&self) {
+        self.boundary(Interaction::Rollback);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::*, tests_cfg::*, Database, DbBackend, MockDatabase, MockExecResult, Transaction,
+        TransactionTrait,
+    };
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    #[smol_potat::test]
+    async fn record_then_replay() -> Result<(), DbErr> {
+        let conn = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let recorder = Arc::new(ProxyRecorder::new(conn));
+        let db = Database::connect_proxy(DbBackend::Postgres, Arc::new(Box::new(recorder.clone())))
+            .await?;
+        let recorded = cake::Entity::find_by_id(1).one(&db).await?;
+        cake::Entity::delete_by_id(1).exec(&db).await?;
+
+        let json = serde_json::to_string(&recorder.cassette()).unwrap();
+        let cassette: Cassette = serde_json::from_str(&json).unwrap();
+        assert_eq!(cassette.interactions.len(), 2);
+
+        let replayer = ProxyReplayer::new(cassette);
+        let db = Database::connect_proxy(DbBackend::Postgres, Arc::new(Box::new(replayer))).await?;
+        assert_eq!(cake::Entity::find_by_id(1).one(&db).await?, recorded);
+        assert_eq!(
+            cake::Entity::delete_by_id(1).exec(&db).await?.rows_affected,
+            1
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn record_transaction() -> Result<(), DbErr> {
+        let conn = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let recorder = Arc::new(ProxyRecorder::new(conn));
+        let db = Database::connect_proxy(DbBackend::Postgres, Arc::new(Box::new(recorder.clone())))
+            .await?;
+        let txn = db.begin().await?;
+        cake::Entity::delete_by_id(1).exec(&txn).await?;
+        txn.rollback().await?;
+        drop(db);
+
+        let recorder = Arc::try_unwrap(recorder).unwrap();
+        assert_eq!(recorder.cassette().interactions.len(), 3);
+        assert_eq!(
+            recorder.conn.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                    [1i32.into()]
+                ),
+                Statement::from_string(DbBackend::Postgres, "ROLLBACK"),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn record_dropped_transaction() -> Result<(), DbErr> {
+        let conn = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        let recorder = Arc::new(ProxyRecorder::new(conn));
+        let db = Database::connect_proxy(DbBackend::Postgres, Arc::new(Box::new(recorder.clone())))
+            .await?;
+        let txn = db.begin().await?;
+        cake::Entity::delete_by_id(1).exec(&txn).await?;
+        drop(txn);
+        cake::Entity::delete_by_id(2).exec(&db).await?;
+        drop(db);
+
+        let recorder = Arc::try_unwrap(recorder).unwrap();
+        let cassette = recorder.cassette();
+        assert_eq!(
+            recorder.conn.into_transaction_log(),
+            [
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                        [1i32.into()]
+                    ),
+                    Statement::from_string(DbBackend::Postgres, "ROLLBACK"),
+                ]),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                    [2i32.into()]
+                ),
+            ]
+        );
+
+        let replayer = Arc::new(ProxyReplayer::new(cassette));
+        let db = Database::connect_proxy(DbBackend::Postgres, Arc::new(Box::new(replayer.clone())))
+            .await?;
+        let txn = db.begin().await?;
+        cake::Entity::delete_by_id(1).exec(&txn).await?;
+        drop(txn);
+        cake::Entity::delete_by_id(2).exec(&db).await?;
+        assert!(replayer.is_finished());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn replay_mismatch() -> Result<(), DbErr> {
+        let replayer = ProxyReplayer::new(Cassette {
+            interactions: vec![Interaction::Execute {
+                statement: RecordedStatement {
+                    sql: r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#.to_owned(),
+                    values: vec![SerdeValue(1i32.into())],
+                },
+                response: Ok(ProxyExecResult::new(0, 1)),
+            }],
+        });
+        let db = Database::connect_proxy(DbBackend::Postgres, Arc::new(Box::new(replayer))).await?;
+
+        assert_eq!(
+            cake::Entity::delete_by_id(2).exec(&db).await.unwrap_err(),
+            DbErr::Custom(
+                r#"Replay mismatch: expected execute `DELETE FROM "cake" WHERE "cake"."id" = $1` with [Int(Some(1))], got execute `DELETE FROM "cake" WHERE "cake"."id" = $1` with [Int(Some(2))]"#
+                    .to_owned()
+            )
+        );
+
+        Ok(())
+    }
+}
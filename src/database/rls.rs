@@ -0,0 +1,292 @@
+use crate::{
+    error::*, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, EntityName,
+    Statement, TransactionError, TransactionTrait, Value,
+};
+use std::{future::Future, pin::Pin};
+use tracing::instrument;
+
+/// The setting [RlsContext::new] puts the user id in, read it in a policy with
+/// `current_setting('app.user_id')`
+pub const RLS_USER_ID_SETTING: &str = "app.user_id";
+
+/// The role and settings a transaction runs with for PostgreSQL row-level security policies.
+///
+/// The settings are set with `set_config(.., .., true)` and the role with `SET LOCAL ROLE`,
+/// so both are cleared when the transaction ends, before the connection returns to the pool.
+///
+/// ```
+/// # use sea_orm::{DbBackend, RlsContext, Statement};
+/// let context = RlsContext::new(42, "app_user").setting("app.tenant_id", "7");
+///
+/// assert_eq!(
+///     context.statements(),
+///     [
+///         Statement::from_sql_and_values(
+///             DbBackend::Postgres,
+///             "SELECT set_config($1, $2, true)",
+///             ["app.user_id".into(), "42".into()]
+///         ),
+///         Statement::from_sql_and_values(
+///             DbBackend::Postgres,
+///             "SELECT set_config($1, $2, true)",
+///             ["app.tenant_id".into(), "7".into()]
+///         ),
+///         Statement::from_string(DbBackend::Postgres, r#"SET LOCAL ROLE "app_user""#),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RlsContext {
+    role: Option<String>,
+    settings: Vec<(String, String)>,
+}
+
+/// Whether row-level security applies to a table, see [rls_status]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RlsStatus {
+    /// The table
+    pub table: String,
+    /// Row-level security is enabled on the table
+    pub enabled: bool,
+    /// Row-level security also applies to the owner of the table
+    pub forced: bool,
+    /// The names of the policies on the table
+    pub policies: Vec<String>,
+}
+
+impl RlsContext {
+    /// Run as `role` with the user id in [RLS_USER_ID_SETTING]
+    pub fn new<U, R>(user_id: U, role: R) -> Self
+    where
+        U: ToString,
+        R: Into<String>,
+    {
+        Self {
+            role: Some(role.into()),
+            settings: Vec::new(),
+        }
+        .setting(RLS_USER_ID_SETTING, user_id)
+    }
+
+    /// Run as the role of the connection, with only the settings added by [Self::setting]
+    pub fn settings_only() -> Self {
+        Self {
+            role: None,
+            settings: Vec::new(),
+        }
+    }
+
+    /// Set a custom setting, such as `app.tenant_id`, for the transaction
+    pub fn setting<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: ToString,
+    {
+        self.settings.push((name.into(), value.to_string()));
+        self
+    }
+
+    /// The statements applying the context at the start of a transaction
+    pub fn statements(&self) -> Vec<Statement> {
+        let mut stmts: Vec<Statement> = self
+            .settings
+            .iter()
+            .map(|(name, value)| {
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    "SELECT set_config($1, $2, true)",
+                    [Value::from(name.as_str()), Value::from(value.as_str())],
+                )
+            })
+            .collect();
+        if let Some(role) = &self.role {
+            stmts.push(Statement::from_string(
+                DbBackend::Postgres,
+                format!(r#"SET LOCAL ROLE "{}""#, role.replace('"', r#""""#)),
+            ));
+        }
+        stmts
+    }
+
+    async fn apply(&self, txn: &DatabaseTransaction) -> Result<(), DbErr> {
+        for stmt in self.statements() {
+            txn.execute(stmt).await?;
+        }
+        Ok(())
+    }
+}
+
+impl DatabaseConnection {
+    /// Execute the function inside a transaction, like [TransactionTrait::transaction], running
+    /// with the role and settings of a [RlsContext].
+    ///
+    /// Returns a [DbErr::Custom] error if the database is not PostgreSQL, DuckDB included.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([Vec::<cake::Model>::new()])
+    /// #     .append_exec_results([MockExecResult::default(), MockExecResult::default()])
+    /// #     .into_connection();
+    /// #
+    /// let cakes = db
+    ///     .with_rls_context(RlsContext::new(42, "app_user"), |txn| {
+    ///         Box::pin(async move { cake::Entity::find().all(txn).await })
+    ///     })
+    ///     .await
+    ///     .map_err(|e| DbErr::Custom(e.to_string()))?;
+    ///
+    /// assert_eq!(cakes, []);
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::many([
+    ///         Statement::from_string(DbBackend::Postgres, "BEGIN"),
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             "SELECT set_config($1, $2, true)",
+    ///             ["app.user_id".into(), "42".into()]
+    ///         ),
+    ///         Statement::from_string(DbBackend::Postgres, r#"SET LOCAL ROLE "app_user""#),
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///             []
+    ///         ),
+    ///         Statement::from_string(DbBackend::Postgres, "COMMIT"),
+    ///     ])]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(level = "trace", skip(callback))]
+    pub async fn with_rls_context<F, T, E>(
+        &self,
+        context: RlsContext,
+        callback: F,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        if self.get_database_backend() != DbBackend::Postgres || self.is_duckdb() {
+            return Err(TransactionError::Connection(DbErr::Custom(
+                "Row-level security is only supported on PostgreSQL".to_owned(),
+            )));
+        }
+        let txn = self.begin().await.map_err(TransactionError::Connection)?;
+        if let Err(err) = context.apply(&txn).await {
+            txn.rollback().await.map_err(TransactionError::Connection)?;
+            return Err(TransactionError::Connection(err));
+        }
+        txn.run(callback).await
+    }
+}
+
+impl RlsStatus {
+    /// Whether the policies of the table apply to every role but superusers and roles with
+    /// `BYPASSRLS`
+    pub fn is_enforced(&self) -> bool {
+        self.enabled && self.forced && !self.policies.is_empty()
+    }
+
+    /// Assert the policies of the table are enforced, for use in tests
+    ///
+    /// # Panics
+    ///
+    /// Panics if row-level security is not enabled or not forced, or if there is no policy
+    #[track_caller]
+    pub fn assert_enforced(&self) {
+        assert!(
+            self.enabled,
+            "row-level security is not enabled on `{}`",
+            self.table
+        );
+        assert!(
+            self.forced,
+            "row-level security is not forced on `{}`, the owner bypasses its policies",
+            self.table
+        );
+        assert!(
+            !self.policies.is_empty(),
+            "`{}` has no row-level security policy, no row is visible",
+            self.table
+        );
+    }
+}
+
+/// Look up whether row-level security applies to the table of an entity on a PostgreSQL database
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[maplit::btreemap! {
+/// #         "relrowsecurity" => Into::<Value>::into(true),
+/// #         "relforcerowsecurity" => Into::<Value>::into(true),
+/// #         "oid" => Into::<Value>::into(16384i64),
+/// #     }]])
+/// #     .append_query_results([[maplit::btreemap! {
+/// #         "polname" => Into::<Value>::into("cake_owner"),
+/// #     }]])
+/// #     .into_connection();
+/// #
+/// let status = rls_status(&db, cake::Entity).await?;
+///
+/// status.assert_enforced();
+/// assert_eq!(status.policies, ["cake_owner"]);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub async fn rls_status<C, E>(db: &C, entity: E) -> Result<RlsStatus, DbErr>
+where
+    C: ConnectionTrait,
+    E: EntityName,
+{
+    let schema = entity.schema_name().map(Value::from);
+    let table = entity.table_name().to_owned();
+    let row = db
+        .query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "c"."relrowsecurity", "c"."relforcerowsecurity", "c"."oid"::bigint AS "oid"
+FROM "pg_class" "c" JOIN "pg_namespace" "n" ON "n"."oid" = "c"."relnamespace"
+WHERE "c"."relname" = $1 AND "n"."nspname" = COALESCE($2, current_schema())"#,
+            [Value::from(table.as_str()), schema.unwrap_or(Value::String(None))],
+        ))
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound(format!("Table `{table}`")))?;
+    let enabled: bool = row.try_get("", "relrowsecurity")?;
+    let forced: bool = row.try_get("", "relforcerowsecurity")?;
+    let oid: i64 = row.try_get("", "oid")?;
+    let policies = db
+        .query_all(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "polname"::text AS "polname" FROM "pg_policy" WHERE "polrelid" = $1::bigint::oid ORDER BY "polname""#,
+            [oid.into()],
+        ))
+        .await?
+        .into_iter()
+        .map(|row| row.try_get("", "polname"))
+        .collect::<Result<_, _>>()?;
+
+    Ok(RlsStatus {
+        table,
+        enabled,
+        forced,
+        policies,
+    })
+}
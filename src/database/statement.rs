@@ -54,15 +54,77 @@ impl Statement {
             db_backend,
         }
     }
+
+    /// Render the statement for snapshot testing: the SQL with the placeholders numbered
+    /// as `$1`, `$2`, ... on every backend, followed by one line per value.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, Statement};
+    ///
+    /// let stmt = Statement::from_sql_and_values(
+    ///     DbBackend::MySql,
+    ///     "SELECT `id` FROM `cake` WHERE `name` = ? LIMIT ?",
+    ///     ["Cheese".into(), 1u64.into()],
+    /// );
+    /// assert_eq!(
+    ///     stmt.to_sql_string(),
+    ///     [
+    ///         "SELECT `id` FROM `cake` WHERE `name` = $1 LIMIT $2",
+    ///         "-- $1: 'Cheese'",
+    ///         "-- $2: 1",
+    ///     ]
+    ///     .join("\n")
+    /// );
+    /// ```
+    pub fn to_sql_string(&self) -> String {
+        let mut string = match self.db_backend {
+            DbBackend::Postgres => self.sql.clone(),
+            DbBackend::MySql | DbBackend::Sqlite => number_placeholders(&self.sql),
+        };
+        if let Some(values) = &self.values {
+            let query_builder = self.db_backend.get_query_builder();
+            for (i, value) in values.iter().enumerate() {
+                string.push_str(&format!(
+                    "\n-- ${}: {}",
+                    i + 1,
+                    query_builder.value_to_string(value)
+                ));
+            }
+        }
+        string
+    }
+}
+
+/// Replace the `?` placeholders outside of quoted strings and identifiers with `$N`
+fn number_placeholders(sql: &str) -> String {
+    let mut string = String::with_capacity(sql.len());
+    let mut quote = None;
+    let mut counter = 0;
+    for c in sql.chars() {
+        match (quote, c) {
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '?') => {
+                counter += 1;
+                string.push_str(&format!("${counter}"));
+                continue;
+            }
+            _ => {}
+        }
+        string.push(c);
+    }
+    string
 }
 
 impl fmt::Display for Statement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.values {
             Some(values) => {
+                let mut values = values.0.clone();
+                crate::redact_logged_values(&mut values);
                 let string = inject_parameters(
                     &self.sql,
-                    values.0.clone(),
+                    values,
                     self.db_backend.get_query_builder().as_ref(),
                 );
                 write!(f, "{}", &string)
@@ -145,3 +207,26 @@ macro_rules! build_type_stmt {
 build_type_stmt!(sea_query::extension::postgres::TypeAlterStatement);
 build_type_stmt!(sea_query::extension::postgres::TypeCreateStatement);
 build_type_stmt!(sea_query::extension::postgres::TypeDropStatement);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_placeholders_outside_quotes() {
+        let stmt = Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            r#"SELECT "a?" FROM "t" WHERE "b" = ? AND 'c?' <> ?"#,
+            [1i32.into(), "d'e".into()],
+        );
+        assert_eq!(
+            stmt.to_sql_string(),
+            [
+                r#"SELECT "a?" FROM "t" WHERE "b" = $1 AND 'c?' <> $2"#,
+                "-- $1: 1",
+                "-- $2: 'd''e'",
+            ]
+            .join("\n")
+        );
+    }
+}
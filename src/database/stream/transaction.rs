@@ -79,6 +79,27 @@ impl TransactionStream<'_> {
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
+                #[cfg(feature = "libsql")]
+                InnerConnection::Libsql(c) => {
+                    let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
+                    let stream = c.fetch(stmt);
+                    let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
+                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                }
+                #[cfg(feature = "duckdb")]
+                InnerConnection::Duckdb(c) => {
+                    let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
+                    let stream = c.fetch(stmt);
+                    let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
+                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                }
+                #[cfg(feature = "tokio-postgres")]
+                InnerConnection::TokioPostgres(c) => {
+                    let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
+                    let stream = c.fetch(stmt);
+                    let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
+                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                }
                 #[cfg(feature = "mock")]
                 InnerConnection::Mock(c) => {
                     let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
@@ -1,7 +1,7 @@
 use crate::{
     debug_print, error::*, AccessMode, ConnectionTrait, DbBackend, DbErr, ExecResult,
-    InnerConnection, IsolationLevel, QueryResult, Statement, StreamTrait, TransactionStream,
-    TransactionTrait,
+    InnerConnection, IsolationLevel, LogSettings, QueryResult, Statement, StreamTrait,
+    TransactionStream, TransactionTrait,
 };
 #[cfg(feature = "sqlx-dep")]
 use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
@@ -11,6 +11,21 @@ use sqlx::TransactionManager;
 use std::{future::Future, pin::Pin, sync::Arc};
 use tracing::instrument;
 
+/// How a transaction ended, given to the callbacks of [ConnectionTrait::on_transaction_end]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionOutcome {
+    /// The transaction was committed
+    Committed,
+    /// The transaction was rolled back, or failed to commit
+    RolledBack,
+}
+
+/// A callback run once a transaction ends, see [ConnectionTrait::on_transaction_end]
+pub type TransactionCallback =
+    Box<dyn FnOnce(TransactionOutcome) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>;
+
+type TransactionCallbacks = Arc<std::sync::Mutex<Vec<TransactionCallback>>>;
+
 // a Transaction is just a sugar for a connection where START TRANSACTION has been executed
 /// Defines a database transaction, whether it is an open transaction and the type of
 /// backend to use
@@ -19,6 +34,9 @@ pub struct DatabaseTransaction {
     backend: DbBackend,
     open: bool,
     metric_callback: Option<crate::metric::Callback>,
+    log_settings: LogSettings,
+    callbacks: TransactionCallbacks,
+    parent_callbacks: Option<TransactionCallbacks>,
 }
 
 impl std::fmt::Debug for DatabaseTransaction {
@@ -33,6 +51,7 @@ impl DatabaseTransaction {
         conn: Arc<Mutex<InnerConnection>>,
         backend: DbBackend,
         metric_callback: Option<crate::metric::Callback>,
+        log_settings: LogSettings,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
@@ -41,6 +60,9 @@ impl DatabaseTransaction {
             backend,
             open: true,
             metric_callback,
+            log_settings,
+            callbacks: Default::default(),
+            parent_callbacks: None,
         };
         match *res.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
@@ -74,11 +96,40 @@ impl DatabaseTransaction {
                     .await
                     .map_err(sqlx_error_to_query_err)
             }
+            #[cfg(feature = "libsql")]
+            InnerConnection::Libsql(ref mut c) => {
+                // in SQLite isolation level and access mode are global settings
+                crate::driver::libsql_db::set_transaction_config(isolation_level, access_mode);
+                c.begin().await
+            }
+            #[cfg(feature = "duckdb")]
+            InnerConnection::Duckdb(ref mut c) => {
+                crate::driver::duckdb_db::set_transaction_config(isolation_level, access_mode);
+                c.begin().await
+            }
+            #[cfg(feature = "tokio-postgres")]
+            InnerConnection::TokioPostgres(ref mut c) => {
+                c.begin().await?;
+                // in PostgreSQL SET TRANSACTION operations must be executed inside transaction
+                crate::driver::tokio_postgres_db::set_transaction_config(
+                    c,
+                    isolation_level,
+                    access_mode,
+                )
+                .await
+            }
             #[cfg(feature = "mock")]
             InnerConnection::Mock(ref mut c) => {
+                c.inject_fault(&Statement::from_string(backend, "BEGIN"))
+                    .await?;
                 c.begin();
                 Ok(())
             }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(ref mut c) => {
+                c.begin().await;
+                Ok(())
+            }
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
         }?;
@@ -110,9 +161,9 @@ impl DatabaseTransaction {
 
     /// Commit a transaction atomically
     #[instrument(level = "trace")]
-    #[allow(unreachable_code, unused_mut)]
+    #[allow(unreachable_code, unused_mut, unused_assignments)]
     pub async fn commit(mut self) -> Result<(), DbErr> {
-        match *self.conn.lock().await {
+        let res = match *self.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
             InnerConnection::MySql(ref mut c) => {
                 <sqlx::MySql as sqlx::Database>::TransactionManager::commit(c)
@@ -131,21 +182,42 @@ impl DatabaseTransaction {
                     .await
                     .map_err(sqlx_error_to_query_err)
             }
+            #[cfg(feature = "libsql")]
+            InnerConnection::Libsql(ref mut c) => c.commit().await,
+            #[cfg(feature = "duckdb")]
+            InnerConnection::Duckdb(ref mut c) => c.commit().await,
+            #[cfg(feature = "tokio-postgres")]
+            InnerConnection::TokioPostgres(ref mut c) => c.commit().await,
             #[cfg(feature = "mock")]
-            InnerConnection::Mock(ref mut c) => {
-                c.commit();
+            InnerConnection::Mock(ref mut c) => c
+                .inject_fault(&Statement::from_string(self.backend, "COMMIT"))
+                .await
+                .map(|_| c.commit()),
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(ref mut c) => {
+                c.commit().await;
                 Ok(())
             }
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
-        }?;
-        self.open = false;
-        Ok(())
+        };
+        match res {
+            Ok(()) => {
+                self.open = false;
+                match self.parent_callbacks.take() {
+                    // a nested transaction only ends with the one it is nested in
+                    Some(parent) => lock_callbacks(&parent).extend(self.take_callbacks()),
+                    None => self.run_callbacks(TransactionOutcome::Committed).await,
+                }
+            }
+            Err(_) => self.run_callbacks(TransactionOutcome::RolledBack).await,
+        }
+        res
     }
 
     /// rolls back a transaction in case error are encountered during the operation
     #[instrument(level = "trace")]
-    #[allow(unreachable_code, unused_mut)]
+    #[allow(unreachable_code, unused_mut, unused_assignments)]
     pub async fn rollback(mut self) -> Result<(), DbErr> {
         match *self.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
@@ -166,18 +238,40 @@ impl DatabaseTransaction {
                     .await
                     .map_err(sqlx_error_to_query_err)
             }
+            #[cfg(feature = "libsql")]
+            InnerConnection::Libsql(ref mut c) => c.rollback().await,
+            #[cfg(feature = "duckdb")]
+            InnerConnection::Duckdb(ref mut c) => c.rollback().await,
+            #[cfg(feature = "tokio-postgres")]
+            InnerConnection::TokioPostgres(ref mut c) => c.rollback().await,
             #[cfg(feature = "mock")]
             InnerConnection::Mock(ref mut c) => {
                 c.rollback();
                 Ok(())
             }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(ref mut c) => {
+                c.rollback().await;
+                Ok(())
+            }
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
         }?;
         self.open = false;
+        self.run_callbacks(TransactionOutcome::RolledBack).await;
         Ok(())
     }
 
+    fn take_callbacks(&self) -> Vec<TransactionCallback> {
+        std::mem::take(&mut *lock_callbacks(&self.callbacks))
+    }
+
+    async fn run_callbacks(&self, outcome: TransactionOutcome) {
+        for callback in self.take_callbacks() {
+            callback(outcome).await;
+        }
+    }
+
     // the rollback is queued and will be performed on next async operation, like returning the connection to the pool
     #[instrument(level = "trace")]
     fn start_rollback(&mut self) -> Result<(), DbErr> {
@@ -196,10 +290,26 @@ impl DatabaseTransaction {
                     InnerConnection::Sqlite(c) => {
                         <sqlx::Sqlite as sqlx::Database>::TransactionManager::start_rollback(c);
                     }
+                    #[cfg(feature = "libsql")]
+                    InnerConnection::Libsql(c) => {
+                        c.start_rollback();
+                    }
+                    #[cfg(feature = "duckdb")]
+                    InnerConnection::Duckdb(c) => {
+                        c.start_rollback();
+                    }
+                    #[cfg(feature = "tokio-postgres")]
+                    InnerConnection::TokioPostgres(c) => {
+                        c.start_rollback();
+                    }
                     #[cfg(feature = "mock")]
                     InnerConnection::Mock(c) => {
                         c.rollback();
                     }
+                    #[cfg(feature = "proxy")]
+                    InnerConnection::Proxy(c) => {
+                        c.start_rollback();
+                    }
                     #[allow(unreachable_patterns)]
                     _ => return Err(conn_err("Disconnected")),
                 }
@@ -215,9 +325,29 @@ impl DatabaseTransaction {
 impl Drop for DatabaseTransaction {
     fn drop(&mut self) {
         self.start_rollback().expect("Fail to rollback transaction");
+        if self.open {
+            let callbacks = self.take_callbacks();
+            // the callbacks of a nested transaction learn of the rollback when the one it is
+            // nested in ends; those of an outer transaction can't be awaited here, so they are
+            // dropped, see `ConnectionTrait::on_transaction_end`
+            if let Some(parent) = &self.parent_callbacks {
+                lock_callbacks(parent).extend(callbacks.into_iter().map(|callback| {
+                    Box::new(move |_| callback(TransactionOutcome::RolledBack))
+                        as TransactionCallback
+                }))
+            }
+        }
     }
 }
 
+fn lock_callbacks(
+    callbacks: &TransactionCallbacks,
+) -> std::sync::MutexGuard<'_, Vec<TransactionCallback>> {
+    callbacks
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
 #[async_trait::async_trait]
 impl ConnectionTrait for DatabaseTransaction {
     fn get_database_backend(&self) -> DbBackend {
@@ -225,6 +355,10 @@ impl ConnectionTrait for DatabaseTransaction {
         self.backend
     }
 
+    async fn on_transaction_end(&self, callback: TransactionCallback) {
+        lock_callbacks(&self.callbacks).push(callback);
+    }
+
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
@@ -236,7 +370,10 @@ impl ConnectionTrait for DatabaseTransaction {
                 let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                 let conn: &mut sqlx::MySqlConnection = &mut *conn;
                 crate::metric::metric!(self.metric_callback, &stmt, {
-                    query.execute(conn).await.map(Into::into)
+                    self.log_settings
+                        .run(&stmt, query.execute(conn))
+                        .await
+                        .map(Into::into)
                 })
                 .map_err(sqlx_error_to_exec_err)
             }
@@ -245,7 +382,10 @@ impl ConnectionTrait for DatabaseTransaction {
                 let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
                 let conn: &mut sqlx::PgConnection = &mut *conn;
                 crate::metric::metric!(self.metric_callback, &stmt, {
-                    query.execute(conn).await.map(Into::into)
+                    self.log_settings
+                        .run(&stmt, query.execute(conn))
+                        .await
+                        .map(Into::into)
                 })
                 .map_err(sqlx_error_to_exec_err)
             }
@@ -254,12 +394,38 @@ impl ConnectionTrait for DatabaseTransaction {
                 let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
                 let conn: &mut sqlx::SqliteConnection = &mut *conn;
                 crate::metric::metric!(self.metric_callback, &stmt, {
-                    query.execute(conn).await.map(Into::into)
+                    self.log_settings
+                        .run(&stmt, query.execute(conn))
+                        .await
+                        .map(Into::into)
                 })
                 .map_err(sqlx_error_to_exec_err)
             }
+            #[cfg(feature = "libsql")]
+            InnerConnection::Libsql(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    self.log_settings.run(&stmt, conn.execute(&stmt)).await
+                })
+            }
+            #[cfg(feature = "duckdb")]
+            InnerConnection::Duckdb(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    self.log_settings.run(&stmt, conn.execute(&stmt)).await
+                })
+            }
+            #[cfg(feature = "tokio-postgres")]
+            InnerConnection::TokioPostgres(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    self.log_settings.run(&stmt, conn.execute(&stmt)).await
+                })
+            }
             #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => return conn.execute(stmt),
+            InnerConnection::Mock(conn) => {
+                conn.inject_fault(&stmt).await?;
+                return conn.execute(stmt);
+            }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(conn) => conn.execute(stmt).await,
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
         }
@@ -269,12 +435,15 @@ impl ConnectionTrait for DatabaseTransaction {
     #[allow(unused_variables)]
     async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
+        #[cfg(feature = "sqlx-dep")]
+        let stmt = Statement::from_string(self.backend, sql);
 
         match &mut *self.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
             InnerConnection::MySql(conn) => {
                 let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                sqlx::Executor::execute(conn, sql)
+                self.log_settings
+                    .run(&stmt, sqlx::Executor::execute(conn, sql))
                     .await
                     .map(Into::into)
                     .map_err(sqlx_error_to_exec_err)
@@ -282,7 +451,8 @@ impl ConnectionTrait for DatabaseTransaction {
             #[cfg(feature = "sqlx-postgres")]
             InnerConnection::Postgres(conn) => {
                 let conn: &mut sqlx::PgConnection = &mut *conn;
-                sqlx::Executor::execute(conn, sql)
+                self.log_settings
+                    .run(&stmt, sqlx::Executor::execute(conn, sql))
                     .await
                     .map(Into::into)
                     .map_err(sqlx_error_to_exec_err)
@@ -290,17 +460,45 @@ impl ConnectionTrait for DatabaseTransaction {
             #[cfg(feature = "sqlx-sqlite")]
             InnerConnection::Sqlite(conn) => {
                 let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                sqlx::Executor::execute(conn, sql)
+                self.log_settings
+                    .run(&stmt, sqlx::Executor::execute(conn, sql))
                     .await
                     .map(Into::into)
                     .map_err(sqlx_error_to_exec_err)
             }
+            #[cfg(feature = "libsql")]
+            InnerConnection::Libsql(conn) => {
+                let stmt = Statement::from_string(self.backend, sql);
+                self.log_settings
+                    .run(&stmt, conn.execute_unprepared(sql))
+                    .await
+            }
+            #[cfg(feature = "duckdb")]
+            InnerConnection::Duckdb(conn) => {
+                let stmt = Statement::from_string(self.backend, sql);
+                self.log_settings
+                    .run(&stmt, conn.execute_unprepared(sql))
+                    .await
+            }
+            #[cfg(feature = "tokio-postgres")]
+            InnerConnection::TokioPostgres(conn) => {
+                let stmt = Statement::from_string(self.backend, sql);
+                self.log_settings
+                    .run(&stmt, conn.execute_unprepared(sql))
+                    .await
+            }
             #[cfg(feature = "mock")]
             InnerConnection::Mock(conn) => {
                 let db_backend = conn.get_database_backend();
                 let stmt = Statement::from_string(db_backend, sql);
+                conn.inject_fault(&stmt).await?;
                 conn.execute(stmt)
             }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(conn) => {
+                let db_backend = conn.get_database_backend();
+                conn.execute(Statement::from_string(db_backend, sql)).await
+            }
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
         }
@@ -318,7 +516,10 @@ impl ConnectionTrait for DatabaseTransaction {
                 let conn: &mut sqlx::MySqlConnection = &mut *conn;
                 crate::metric::metric!(self.metric_callback, &stmt, {
                     crate::sqlx_map_err_ignore_not_found(
-                        query.fetch_one(conn).await.map(|row| Some(row.into())),
+                        self.log_settings
+                            .run(&stmt, query.fetch_one(conn))
+                            .await
+                            .map(|row| Some(row.into())),
                     )
                 })
             }
@@ -328,7 +529,10 @@ impl ConnectionTrait for DatabaseTransaction {
                 let conn: &mut sqlx::PgConnection = &mut *conn;
                 crate::metric::metric!(self.metric_callback, &stmt, {
                     crate::sqlx_map_err_ignore_not_found(
-                        query.fetch_one(conn).await.map(|row| Some(row.into())),
+                        self.log_settings
+                            .run(&stmt, query.fetch_one(conn))
+                            .await
+                            .map(|row| Some(row.into())),
                     )
                 })
             }
@@ -338,12 +542,38 @@ impl ConnectionTrait for DatabaseTransaction {
                 let conn: &mut sqlx::SqliteConnection = &mut *conn;
                 crate::metric::metric!(self.metric_callback, &stmt, {
                     crate::sqlx_map_err_ignore_not_found(
-                        query.fetch_one(conn).await.map(|row| Some(row.into())),
+                        self.log_settings
+                            .run(&stmt, query.fetch_one(conn))
+                            .await
+                            .map(|row| Some(row.into())),
                     )
                 })
             }
+            #[cfg(feature = "libsql")]
+            InnerConnection::Libsql(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    self.log_settings.run(&stmt, conn.query_one(&stmt)).await
+                })
+            }
+            #[cfg(feature = "duckdb")]
+            InnerConnection::Duckdb(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    self.log_settings.run(&stmt, conn.query_one(&stmt)).await
+                })
+            }
+            #[cfg(feature = "tokio-postgres")]
+            InnerConnection::TokioPostgres(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    self.log_settings.run(&stmt, conn.query_one(&stmt)).await
+                })
+            }
             #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => return conn.query_one(stmt),
+            InnerConnection::Mock(conn) => {
+                conn.inject_fault(&stmt).await?;
+                return conn.query_one(stmt);
+            }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(conn) => conn.query_one(stmt).await,
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
         }
@@ -360,8 +590,8 @@ impl ConnectionTrait for DatabaseTransaction {
                 let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                 let conn: &mut sqlx::MySqlConnection = &mut *conn;
                 crate::metric::metric!(self.metric_callback, &stmt, {
-                    query
-                        .fetch_all(conn)
+                    self.log_settings
+                        .run(&stmt, query.fetch_all(conn))
                         .await
                         .map(|rows| rows.into_iter().map(|r| r.into()).collect())
                         .map_err(sqlx_error_to_query_err)
@@ -372,8 +602,8 @@ impl ConnectionTrait for DatabaseTransaction {
                 let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
                 let conn: &mut sqlx::PgConnection = &mut *conn;
                 crate::metric::metric!(self.metric_callback, &stmt, {
-                    query
-                        .fetch_all(conn)
+                    self.log_settings
+                        .run(&stmt, query.fetch_all(conn))
                         .await
                         .map(|rows| rows.into_iter().map(|r| r.into()).collect())
                         .map_err(sqlx_error_to_query_err)
@@ -384,15 +614,38 @@ impl ConnectionTrait for DatabaseTransaction {
                 let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
                 let conn: &mut sqlx::SqliteConnection = &mut *conn;
                 crate::metric::metric!(self.metric_callback, &stmt, {
-                    query
-                        .fetch_all(conn)
+                    self.log_settings
+                        .run(&stmt, query.fetch_all(conn))
                         .await
                         .map(|rows| rows.into_iter().map(|r| r.into()).collect())
                         .map_err(sqlx_error_to_query_err)
                 })
             }
+            #[cfg(feature = "libsql")]
+            InnerConnection::Libsql(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    self.log_settings.run(&stmt, conn.query_all(&stmt)).await
+                })
+            }
+            #[cfg(feature = "duckdb")]
+            InnerConnection::Duckdb(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    self.log_settings.run(&stmt, conn.query_all(&stmt)).await
+                })
+            }
+            #[cfg(feature = "tokio-postgres")]
+            InnerConnection::TokioPostgres(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    self.log_settings.run(&stmt, conn.query_all(&stmt)).await
+                })
+            }
             #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => return conn.query_all(stmt),
+            InnerConnection::Mock(conn) => {
+                conn.inject_fault(&stmt).await?;
+                return conn.query_all(stmt);
+            }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(conn) => conn.query_all(stmt).await,
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
         }
@@ -409,6 +662,12 @@ impl StreamTrait for DatabaseTransaction {
     ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
         Box::pin(async move {
             let conn = self.conn.lock().await;
+            #[cfg(feature = "mock")]
+            #[allow(irrefutable_let_patterns)]
+            if let InnerConnection::Mock(c) = &*conn {
+                c.inject_fault(&stmt).await?;
+            }
+            self.log_settings.log(&stmt, None);
             Ok(crate::TransactionStream::build(
                 conn,
                 stmt,
@@ -422,14 +681,17 @@ impl StreamTrait for DatabaseTransaction {
 impl TransactionTrait for DatabaseTransaction {
     #[instrument(level = "trace")]
     async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
-        DatabaseTransaction::begin(
+        let mut transaction = DatabaseTransaction::begin(
             Arc::clone(&self.conn),
             self.backend,
             self.metric_callback.clone(),
+            self.log_settings,
             None,
             None,
         )
-        .await
+        .await?;
+        transaction.parent_callbacks = Some(Arc::clone(&self.callbacks));
+        Ok(transaction)
     }
 
     #[instrument(level = "trace")]
@@ -438,14 +700,17 @@ impl TransactionTrait for DatabaseTransaction {
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
-        DatabaseTransaction::begin(
+        let mut transaction = DatabaseTransaction::begin(
             Arc::clone(&self.conn),
             self.backend,
             self.metric_callback.clone(),
+            self.log_settings,
             isolation_level,
             access_mode,
         )
-        .await
+        .await?;
+        transaction.parent_callbacks = Some(Arc::clone(&self.callbacks));
+        Ok(transaction)
     }
 
     /// Execute the function inside a transaction.
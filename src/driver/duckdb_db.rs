@@ -0,0 +1,974 @@
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+use duckdb::types::TimeUnit;
+use duckdb::types::Value as DuckValue;
+use futures_channel::oneshot;
+use futures_util::{
+    lock::{Mutex, OwnedMutexGuard},
+    TryStreamExt,
+};
+use sea_query::{ArrayType, Value};
+use std::{future::Future, pin::Pin, sync::mpsc, sync::Arc};
+use tracing::{instrument, warn};
+
+use crate::{
+    debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
+    DatabaseTransaction, DbBackend, IsolationLevel, LogSettings, QueryStream, Statement,
+    TransactionError,
+};
+
+/// Defines the [duckdb] connector
+#[derive(Debug)]
+pub struct DuckdbConnector;
+
+/// Defines a connection to an embedded DuckDB database, for analytical queries over local
+/// tables or files such as Parquet.
+///
+/// DuckDB follows the PostgreSQL dialect, so statements are built for [DbBackend::Postgres].
+/// The features of a PostgreSQL server, like row-level security, are refused; tell DuckDB
+/// apart with [DatabaseConnection::is_duckdb].
+/// Statements run one at a time on a thread owning the connection, so long running queries
+/// don't block the async runtime, and a transaction holds the connection until it is
+/// committed or rolled back.
+#[derive(Clone)]
+pub struct DuckdbConnection {
+    worker: Arc<Mutex<DuckdbWorker>>,
+    metric_callback: Option<crate::metric::Callback>,
+    log_settings: LogSettings,
+}
+
+impl std::fmt::Debug for DuckdbConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DuckdbConnection")
+    }
+}
+
+/// A row returned by a DuckDB database
+#[derive(Debug, Clone)]
+pub struct DuckdbRow {
+    columns: Arc<[String]>,
+    values: Vec<DuckValue>,
+}
+
+/// The result of executing a statement on a DuckDB database
+#[derive(Debug)]
+pub(crate) struct DuckdbExecResult {
+    pub(crate) rows_affected: u64,
+}
+
+type Job = Box<dyn FnOnce(&mut duckdb::Connection) + Send>;
+
+/// The sending end of a thread owning a DuckDB connection, running the jobs sent to it in order
+pub(crate) struct DuckdbWorker {
+    jobs: mpsc::Sender<Job>,
+}
+
+impl DuckdbConnector {
+    /// Check if the URI provided corresponds to `duckdb:` for a DuckDB database
+    pub fn accepts(string: &str) -> bool {
+        string.starts_with("duckdb:")
+    }
+
+    /// Open a DuckDB database, either a file, e.g. `duckdb:path/to/analytics.duckdb`, or an
+    /// in-memory database with `duckdb::memory:`
+    #[instrument(level = "trace")]
+    pub async fn connect(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
+        let path = match options.url.trim_start_matches("duckdb:") {
+            ":memory:" | "" => None,
+            path => Some(path.to_owned()),
+        };
+        let worker = DuckdbWorker::open(path).await?;
+
+        Ok(DatabaseConnection::DuckdbConnection(DuckdbConnection {
+            worker: Arc::new(Mutex::new(worker)),
+            metric_callback: None,
+            log_settings: LogSettings::new(&options),
+        }))
+    }
+}
+
+impl DuckdbWorker {
+    async fn open(path: Option<String>) -> Result<Self, DbErr> {
+        let (jobs, receiver) = mpsc::channel::<Job>();
+        let (opened, on_open) = oneshot::channel();
+        std::thread::Builder::new()
+            .name("sea-orm-duckdb".to_owned())
+            .spawn(move || {
+                let conn = match &path {
+                    Some(path) => duckdb::Connection::open(path),
+                    None => duckdb::Connection::open_in_memory(),
+                };
+                let mut conn = match conn {
+                    Ok(conn) => {
+                        let _ = opened.send(Ok(()));
+                        conn
+                    }
+                    Err(err) => {
+                        let _ = opened.send(Err(err));
+                        return;
+                    }
+                };
+                // the thread exits once every clone of the connection is dropped
+                for job in receiver {
+                    job(&mut conn);
+                }
+            })
+            .map_err(|e| conn_err(format!("Failed to spawn the DuckDB thread: {e}")))?;
+        on_open
+            .await
+            .map_err(|_| worker_stopped())?
+            .map_err(duckdb_error_to_conn_err)?;
+        Ok(Self { jobs })
+    }
+
+    /// Run a job on the thread owning the connection
+    async fn run<F, R>(&self, job: F) -> Result<R, DbErr>
+    where
+        F: FnOnce(&mut duckdb::Connection) -> Result<R, DbErr> + Send + 'static,
+        R: Send + 'static,
+    {
+        let (done, on_done) = oneshot::channel();
+        self.jobs
+            .send(Box::new(move |conn| {
+                let _ = done.send(job(conn));
+            }))
+            .map_err(|_| worker_stopped())?;
+        on_done.await.map_err(|_| worker_stopped())?
+    }
+}
+
+fn worker_stopped() -> DbErr {
+    conn_err("The DuckDB connection thread has stopped")
+}
+
+impl DuckdbConnection {
+    /// Wait for the connection, rolling back a transaction left open by a dropped [DatabaseTransaction]
+    async fn acquire(&self) -> Result<OwnedMutexGuard<DuckdbWorker>, DbErr> {
+        let worker = self.worker.clone().lock_owned().await;
+        worker
+            .run(|conn| {
+                if !conn.is_autocommit() {
+                    conn.execute_batch("ROLLBACK")
+                        .map_err(duckdb_error_to_conn_err)?;
+                }
+                Ok(())
+            })
+            .await?;
+        Ok(worker)
+    }
+
+    /// Execute a [Statement] on a DuckDB database
+    #[instrument(level = "trace")]
+    pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+
+        let worker = self.acquire().await?;
+        crate::metric::metric!(self.metric_callback, &stmt, {
+            self.log_settings.run(&stmt, execute(&worker, &stmt)).await
+        })
+    }
+
+    /// Execute an unprepared SQL statement on a DuckDB database
+    #[instrument(level = "trace")]
+    pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+
+        let stmt = Statement::from_string(DbBackend::Postgres, sql);
+        let worker = self.acquire().await?;
+        self.log_settings
+            .run(&stmt, execute_unprepared(&worker, sql))
+            .await
+    }
+
+    /// Get one result from a SQL query. Returns [Option::None] if no match was found
+    #[instrument(level = "trace")]
+    pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let worker = self.acquire().await?;
+        crate::metric::metric!(self.metric_callback, &stmt, {
+            self.log_settings
+                .run(&stmt, query_one(&worker, &stmt))
+                .await
+        })
+    }
+
+    /// Get the results of a query returning them as a Vec<[QueryResult]>
+    #[instrument(level = "trace")]
+    pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let worker = self.acquire().await?;
+        crate::metric::metric!(self.metric_callback, &stmt, {
+            self.log_settings
+                .run(&stmt, query_all(&worker, &stmt))
+                .await
+        })
+    }
+
+    /// Stream the results of executing a SQL query
+    #[instrument(level = "trace")]
+    pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
+        debug_print!("{}", stmt);
+
+        let worker = self.acquire().await?;
+        self.log_settings.log(&stmt, None);
+        Ok(QueryStream::build(
+            stmt,
+            crate::InnerConnection::Duckdb(DuckdbInnerConnection::new(worker)),
+            self.metric_callback.clone(),
+        ))
+    }
+
+    /// Bundle a set of SQL statements that execute together.
+    #[instrument(level = "trace")]
+    pub async fn begin(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        let worker = self.acquire().await?;
+        DatabaseTransaction::new_duckdb(
+            worker,
+            self.metric_callback.clone(),
+            self.log_settings,
+            isolation_level,
+            access_mode,
+        )
+        .await
+    }
+
+    /// Create a DuckDB transaction
+    #[instrument(level = "trace", skip(callback))]
+    pub async fn transaction<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'b> FnOnce(
+                &'b DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'b>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        let transaction = self
+            .begin(isolation_level, access_mode)
+            .await
+            .map_err(TransactionError::Connection)?;
+        transaction.run(callback).await
+    }
+
+    /// Create or replace a view named `view` over Parquet files, so an entity with
+    /// `table_name = "<view>"` can query them. `path` may be a glob, e.g. `data/trips-*.parquet`.
+    #[instrument(level = "trace")]
+    pub async fn register_parquet(&self, view: &str, path: &str) -> Result<(), DbErr> {
+        let sql = format!(
+            r#"CREATE OR REPLACE VIEW "{}" AS SELECT * FROM read_parquet('{}')"#,
+            view.replace('"', r#""""#),
+            path.replace('\'', "''"),
+        );
+        self.execute_unprepared(&sql).await?;
+        Ok(())
+    }
+
+    pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
+    {
+        self.metric_callback = Some(Arc::new(callback));
+    }
+
+    /// Checks if a connection to the database is still valid.
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        let worker = self.acquire().await?;
+        worker
+            .run(|conn| {
+                conn.execute_batch("SELECT 1")
+                    .map_err(duckdb_error_to_conn_err)
+            })
+            .await
+    }
+
+    /// Explicitly close the DuckDB connection.
+    /// See [`Self::close_by_ref`] for usage with references.
+    pub async fn close(self) -> Result<(), DbErr> {
+        self.close_by_ref().await
+    }
+
+    /// Explicitly close the DuckDB connection
+    pub async fn close_by_ref(&self) -> Result<(), DbErr> {
+        // the connection is closed once the last clone of it is dropped
+        Ok(())
+    }
+}
+
+/// The connection held by a transaction or a stream
+pub(crate) struct DuckdbInnerConnection {
+    worker: OwnedMutexGuard<DuckdbWorker>,
+    depth: usize,
+    // rollbacks of dropped transactions, run before the next statement
+    pending_rollbacks: Vec<String>,
+}
+
+impl std::fmt::Debug for DuckdbInnerConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DuckdbInnerConnection {{ depth: {} }}", self.depth)
+    }
+}
+
+impl DuckdbInnerConnection {
+    fn new(worker: OwnedMutexGuard<DuckdbWorker>) -> Self {
+        Self {
+            worker,
+            depth: 0,
+            pending_rollbacks: Vec::new(),
+        }
+    }
+
+    async fn ready(&mut self) -> Result<&DuckdbWorker, DbErr> {
+        for sql in std::mem::take(&mut self.pending_rollbacks) {
+            run_batch(&self.worker, sql, duckdb_error_to_exec_err).await?;
+        }
+        Ok(&self.worker)
+    }
+
+    pub(crate) async fn execute(&mut self, stmt: &Statement) -> Result<ExecResult, DbErr> {
+        execute(self.ready().await?, stmt).await
+    }
+
+    pub(crate) async fn execute_unprepared(&mut self, sql: &str) -> Result<ExecResult, DbErr> {
+        execute_unprepared(self.ready().await?, sql).await
+    }
+
+    pub(crate) async fn query_one(
+        &mut self,
+        stmt: &Statement,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        query_one(self.ready().await?, stmt).await
+    }
+
+    pub(crate) async fn query_all(&mut self, stmt: &Statement) -> Result<Vec<QueryResult>, DbErr> {
+        query_all(self.ready().await?, stmt).await
+    }
+
+    /// Stream the rows of a query; they are all fetched before the first one is returned
+    pub(crate) fn fetch<'a>(
+        &'a mut self,
+        stmt: &'a Statement,
+    ) -> impl futures_util::Stream<Item = Result<QueryResult, DbErr>> + Send + 'a {
+        futures_util::stream::once(self.query_all(stmt))
+            .map_ok(|rows| futures_util::stream::iter(rows.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    pub(crate) async fn begin(&mut self) -> Result<(), DbErr> {
+        let sql = match self.depth {
+            0 => "BEGIN".to_owned(),
+            depth => format!("SAVEPOINT sea_orm_savepoint_{depth}"),
+        };
+        run_batch(self.ready().await?, sql, duckdb_error_to_query_err).await?;
+        self.depth += 1;
+        Ok(())
+    }
+
+    pub(crate) async fn commit(&mut self) -> Result<(), DbErr> {
+        let sql = match self.depth {
+            1 => "COMMIT".to_owned(),
+            depth => format!("RELEASE SAVEPOINT sea_orm_savepoint_{}", depth - 1),
+        };
+        run_batch(self.ready().await?, sql, duckdb_error_to_query_err).await?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    pub(crate) async fn rollback(&mut self) -> Result<(), DbErr> {
+        let sql = self.rollback_sql();
+        run_batch(self.ready().await?, sql, duckdb_error_to_query_err).await?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    pub(crate) fn start_rollback(&mut self) {
+        if self.depth > 0 {
+            let sql = self.rollback_sql();
+            self.pending_rollbacks.push(sql);
+            self.depth -= 1;
+        }
+    }
+
+    fn rollback_sql(&self) -> String {
+        match self.depth {
+            1 => "ROLLBACK".to_owned(),
+            depth => format!("ROLLBACK TO SAVEPOINT sea_orm_savepoint_{}", depth - 1),
+        }
+    }
+}
+
+async fn run_batch(
+    worker: &DuckdbWorker,
+    sql: String,
+    map_err: fn(duckdb::Error) -> DbErr,
+) -> Result<(), DbErr> {
+    worker
+        .run(move |conn| conn.execute_batch(&sql).map_err(map_err))
+        .await
+}
+
+async fn execute(worker: &DuckdbWorker, stmt: &Statement) -> Result<ExecResult, DbErr> {
+    let sql = stmt.sql.clone();
+    let values = duckdb_values(stmt)?;
+    worker
+        .run(move |conn| {
+            let rows_affected = conn
+                .prepare(&sql)
+                .and_then(|mut stmt| stmt.execute(duckdb::params_from_iter(values)))
+                .map_err(duckdb_error_to_exec_err)?;
+            Ok(DuckdbExecResult {
+                rows_affected: rows_affected as u64,
+            }
+            .into())
+        })
+        .await
+}
+
+async fn execute_unprepared(worker: &DuckdbWorker, sql: &str) -> Result<ExecResult, DbErr> {
+    let sql = sql.to_owned();
+    worker
+        .run(move |conn| {
+            conn.execute_batch(&sql).map_err(duckdb_error_to_exec_err)?;
+            // DuckDB doesn't report the rows affected by a batch of statements
+            Ok(DuckdbExecResult { rows_affected: 0 }.into())
+        })
+        .await
+}
+
+async fn query_one(worker: &DuckdbWorker, stmt: &Statement) -> Result<Option<QueryResult>, DbErr> {
+    let sql = stmt.sql.clone();
+    let values = duckdb_values(stmt)?;
+    worker
+        .run(move |conn| {
+            let mut rows = query(conn, &sql, values, Some(1))?;
+            Ok(rows.pop().map(Into::into))
+        })
+        .await
+}
+
+async fn query_all(worker: &DuckdbWorker, stmt: &Statement) -> Result<Vec<QueryResult>, DbErr> {
+    let sql = stmt.sql.clone();
+    let values = duckdb_values(stmt)?;
+    worker
+        .run(move |conn| {
+            let rows = query(conn, &sql, values, None)?;
+            Ok(rows.into_iter().map(Into::into).collect())
+        })
+        .await
+}
+
+fn query(
+    conn: &mut duckdb::Connection,
+    sql: &str,
+    values: Vec<DuckValue>,
+    limit: Option<usize>,
+) -> Result<Vec<DuckdbRow>, DbErr> {
+    let mut stmt = conn.prepare(sql).map_err(duckdb_error_to_query_err)?;
+    let mut rows = stmt
+        .query(duckdb::params_from_iter(values))
+        .map_err(duckdb_error_to_query_err)?;
+    let columns: Arc<[String]> = rows
+        .as_ref()
+        .map(|stmt| stmt.column_names())
+        .unwrap_or_default()
+        .into();
+    let mut results = Vec::new();
+    while limit.map_or(true, |limit| results.len() < limit) {
+        let Some(row) = rows.next().map_err(duckdb_error_to_query_err)? else {
+            break;
+        };
+        let values = (0..columns.len())
+            .map(|i| row.get::<_, DuckValue>(i))
+            .collect::<Result<_, _>>()
+            .map_err(duckdb_error_to_query_err)?;
+        results.push(DuckdbRow {
+            columns: columns.clone(),
+            values,
+        });
+    }
+    Ok(results)
+}
+
+impl DuckdbRow {
+    /// The names of the columns of the row
+    pub fn column_names(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Get the value of a column as returned by DuckDB
+    pub fn get_value<I: crate::ColIdx>(&self, index: I) -> Result<&DuckValue, DbErr> {
+        let pos = if let Some(name) = index.as_str() {
+            self.columns.iter().position(|c| c == name)
+        } else if let Some(pos) = index.as_usize() {
+            (*pos < self.values.len()).then_some(*pos)
+        } else {
+            unreachable!("Missing ColIdx implementation for DuckdbRow");
+        };
+        pos.map(|pos| &self.values[pos])
+            .ok_or_else(|| query_err(format!("No column for ColIdx {index:?}")))
+    }
+
+    /// Get a value of the row, or [Option::None] if it is `NULL`
+    pub fn try_get<T, I: crate::ColIdx>(&self, index: I) -> Result<Option<T>, DbErr>
+    where
+        T: sea_query::ValueType,
+    {
+        match self.get_value(index)? {
+            DuckValue::Null => Ok(None),
+            value => {
+                <T as sea_query::ValueType>::try_from(from_duckdb_value(value, T::array_type())?)
+                    .map(Some)
+                    .map_err(type_err)
+            }
+        }
+    }
+
+    /// Get a `LIST` or `ARRAY` value of the row, or [Option::None] if it is `NULL`
+    #[cfg(feature = "postgres-array")]
+    pub fn try_get_array<T, I: crate::ColIdx>(&self, index: I) -> Result<Option<Vec<T>>, DbErr>
+    where
+        T: sea_query::ValueType,
+    {
+        let items = match self.get_value(index)? {
+            DuckValue::Null => return Ok(None),
+            DuckValue::List(items) | DuckValue::Array(items) => items,
+            value => return Err(mismatch(value, "LIST")),
+        };
+        items
+            .iter()
+            .map(|item| match item {
+                DuckValue::Null => Err(type_err("Unexpected NULL in LIST")),
+                item => {
+                    <T as sea_query::ValueType>::try_from(from_duckdb_value(item, T::array_type())?)
+                        .map_err(type_err)
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+
+    /// An iterator over the column names and values of the row. Integers, floats, booleans,
+    /// text and blobs keep their type; other values are converted to their text representation.
+    pub fn into_column_value_tuples(self) -> impl Iterator<Item = (String, Value)> {
+        let columns = self.columns;
+        self.values
+            .into_iter()
+            .enumerate()
+            .map(move |(i, value)| (columns[i].clone(), into_sea_query_value(value)))
+    }
+}
+
+fn into_sea_query_value(value: DuckValue) -> Value {
+    match value {
+        DuckValue::Null => Value::String(None),
+        DuckValue::Boolean(v) => Value::Bool(Some(v)),
+        DuckValue::TinyInt(v) => Value::TinyInt(Some(v)),
+        DuckValue::SmallInt(v) => Value::SmallInt(Some(v)),
+        DuckValue::Int(v) => Value::Int(Some(v)),
+        DuckValue::BigInt(v) => Value::BigInt(Some(v)),
+        DuckValue::UTinyInt(v) => Value::TinyUnsigned(Some(v)),
+        DuckValue::USmallInt(v) => Value::SmallUnsigned(Some(v)),
+        DuckValue::UInt(v) => Value::Unsigned(Some(v)),
+        DuckValue::UBigInt(v) => Value::BigUnsigned(Some(v)),
+        DuckValue::Float(v) => Value::Float(Some(v)),
+        DuckValue::Double(v) => Value::Double(Some(v)),
+        DuckValue::Text(v) | DuckValue::Enum(v) => Value::String(Some(Box::new(v))),
+        DuckValue::Blob(v) => Value::Bytes(Some(Box::new(v))),
+        DuckValue::HugeInt(v) => Value::String(Some(Box::new(v.to_string()))),
+        DuckValue::UHugeInt(v) => Value::String(Some(Box::new(v.to_string()))),
+        DuckValue::Decimal(v) => Value::String(Some(Box::new(v.to_string()))),
+        value => Value::String(Some(Box::new(format!("{value:?}")))),
+    }
+}
+
+impl From<DuckdbRow> for QueryResult {
+    fn from(row: DuckdbRow) -> QueryResult {
+        QueryResult {
+            row: QueryResultRow::Duckdb(row),
+        }
+    }
+}
+
+impl From<DuckdbExecResult> for ExecResult {
+    fn from(result: DuckdbExecResult) -> ExecResult {
+        ExecResult {
+            result: ExecResultHolder::Duckdb(result),
+        }
+    }
+}
+
+fn duckdb_values(stmt: &Statement) -> Result<Vec<DuckValue>, DbErr> {
+    stmt.values
+        .iter()
+        .flat_map(|values| values.0.iter())
+        .map(to_duckdb_value)
+        .collect()
+}
+
+fn to_duckdb_value(value: &Value) -> Result<DuckValue, DbErr> {
+    use DuckValue as D;
+
+    macro_rules! bind {
+        ($v: expr, $f: expr) => {
+            match $v {
+                Some(v) => $f(v),
+                None => D::Null,
+            }
+        };
+    }
+
+    Ok(match value {
+        Value::Bool(v) => bind!(v, |v: &bool| D::Boolean(*v)),
+        Value::TinyInt(v) => bind!(v, |v: &i8| D::TinyInt(*v)),
+        Value::SmallInt(v) => bind!(v, |v: &i16| D::SmallInt(*v)),
+        Value::Int(v) => bind!(v, |v: &i32| D::Int(*v)),
+        Value::BigInt(v) => bind!(v, |v: &i64| D::BigInt(*v)),
+        Value::TinyUnsigned(v) => bind!(v, |v: &u8| D::UTinyInt(*v)),
+        Value::SmallUnsigned(v) => bind!(v, |v: &u16| D::USmallInt(*v)),
+        Value::Unsigned(v) => bind!(v, |v: &u32| D::UInt(*v)),
+        Value::BigUnsigned(v) => bind!(v, |v: &u64| D::UBigInt(*v)),
+        Value::Float(v) => bind!(v, |v: &f32| D::Float(*v)),
+        Value::Double(v) => bind!(v, |v: &f64| D::Double(*v)),
+        Value::String(v) => bind!(v, |v: &String| D::Text(v.to_string())),
+        Value::Char(v) => bind!(v, |v: &char| D::Text(v.to_string())),
+        Value::Bytes(v) => bind!(v, |v: &Vec<u8>| D::Blob(v.to_vec())),
+        #[cfg(feature = "with-json")]
+        Value::Json(v) => bind!(v, |v: &serde_json::Value| D::Text(v.to_string())),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(v) => bind!(v, |v: &chrono::NaiveDate| D::Date32(
+            chrono::Datelike::num_days_from_ce(v) - UNIX_EPOCH_DAYS_FROM_CE
+        )),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(v) => bind!(v, |v: &chrono::NaiveTime| D::Time64(
+            TimeUnit::Microsecond,
+            (*v - chrono::NaiveTime::MIN)
+                .num_microseconds()
+                .expect("less than a day")
+        )),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(v) => bind!(v, |v: &chrono::NaiveDateTime| D::Timestamp(
+            TimeUnit::Microsecond,
+            v.and_utc().timestamp_micros()
+        )),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(v) => bind!(v, |v: &chrono::DateTime<chrono::Utc>| {
+            D::Text(v.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false))
+        }),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(v) => bind!(v, |v: &chrono::DateTime<chrono::Local>| {
+            D::Text(v.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false))
+        }),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(v) => {
+            bind!(v, |v: &chrono::DateTime<chrono::FixedOffset>| {
+                D::Text(v.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false))
+            })
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(v) => bind!(v, |v: &time::Date| D::Date32(
+            v.to_julian_day() - UNIX_EPOCH_JULIAN_DAY
+        )),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(v) => bind!(v, |v: &time::Time| D::Time64(
+            TimeUnit::Microsecond,
+            (*v - time::Time::MIDNIGHT).whole_microseconds() as i64
+        )),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(v) => bind!(v, |v: &time::PrimitiveDateTime| D::Timestamp(
+            TimeUnit::Microsecond,
+            (v.assume_utc().unix_timestamp_nanos() / 1_000) as i64
+        )),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(Some(v)) => D::Text(
+            v.format(&time::format_description::well_known::Rfc3339)
+                .map_err(|e| type_err(format!("Failed to format time value: {e}")))?,
+        ),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(None) => D::Null,
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(v) => bind!(v, |v: &uuid::Uuid| D::Text(v.to_string())),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(v) => bind!(v, |v: &rust_decimal::Decimal| D::Text(v.to_string())),
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(v) => bind!(v, |v: &bigdecimal::BigDecimal| D::Text(v.to_string())),
+        #[cfg(feature = "postgres-array")]
+        Value::Array(_, Some(v)) => {
+            D::List(v.iter().map(to_duckdb_value).collect::<Result<_, _>>()?)
+        }
+        #[cfg(feature = "postgres-array")]
+        Value::Array(_, None) => D::Null,
+        #[allow(unreachable_patterns)]
+        _ => return Err(type_err(format!("{value:?} unsupported by DuckDB"))),
+    })
+}
+
+#[cfg(feature = "with-chrono")]
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+#[cfg(feature = "with-time")]
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+/// Convert a non-`NULL` DuckDB value to the [Value] variant of `ty`
+fn from_duckdb_value(value: &DuckValue, ty: ArrayType) -> Result<Value, DbErr> {
+    macro_rules! integer {
+        ($variant: ident, $type: ty) => {{
+            let v = as_integer(value)?;
+            Value::$variant(Some(<$type>::try_from(v).map_err(|e| {
+                DbErr::TryIntoErr {
+                    from: "i128",
+                    into: stringify!($type),
+                    source: Box::new(e),
+                }
+            })?))
+        }};
+    }
+
+    Ok(match ty {
+        ArrayType::Bool => Value::Bool(Some(match value {
+            DuckValue::Boolean(v) => *v,
+            value => as_integer(value)? != 0,
+        })),
+        ArrayType::TinyInt => integer!(TinyInt, i8),
+        ArrayType::SmallInt => integer!(SmallInt, i16),
+        ArrayType::Int => integer!(Int, i32),
+        ArrayType::BigInt => integer!(BigInt, i64),
+        ArrayType::TinyUnsigned => integer!(TinyUnsigned, u8),
+        ArrayType::SmallUnsigned => integer!(SmallUnsigned, u16),
+        ArrayType::Unsigned => integer!(Unsigned, u32),
+        ArrayType::BigUnsigned => integer!(BigUnsigned, u64),
+        ArrayType::Float => Value::Float(Some(as_double(value)? as f32)),
+        ArrayType::Double => Value::Double(Some(as_double(value)?)),
+        ArrayType::String => Value::String(Some(Box::new(as_text(value)?.to_owned()))),
+        ArrayType::Char => Value::Char(as_text(value)?.chars().next()),
+        ArrayType::Bytes => Value::Bytes(Some(Box::new(match value {
+            DuckValue::Blob(v) => v.clone(),
+            DuckValue::Text(v) => v.clone().into_bytes(),
+            _ => return Err(mismatch(value, "BLOB")),
+        }))),
+        #[cfg(feature = "with-json")]
+        ArrayType::Json => Value::Json(Some(Box::new(
+            serde_json::from_str(as_text(value)?).map_err(json_err)?,
+        ))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDate => Value::ChronoDate(Some(Box::new(match value {
+            DuckValue::Date32(days) => UNIX_EPOCH_DAYS_FROM_CE
+                .checked_add(*days)
+                .and_then(chrono::NaiveDate::from_num_days_from_ce_opt)
+                .ok_or_else(|| type_err(format!("Date {days} out of range")))?,
+            value => chrono::NaiveDate::parse_from_str(as_text(value)?, "%F").map_err(type_err)?,
+        }))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoTime => Value::ChronoTime(Some(Box::new(match value {
+            DuckValue::Time64(unit, v) => {
+                chrono::NaiveTime::MIN + chrono::Duration::microseconds(unit.to_micros(*v))
+            }
+            value => {
+                chrono::NaiveTime::parse_from_str(as_text(value)?, "%T%.f").map_err(type_err)?
+            }
+        }))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTime => {
+            Value::ChronoDateTime(Some(Box::new(chrono_date_time(value)?.naive_utc())))
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeUtc => {
+            Value::ChronoDateTimeUtc(Some(Box::new(chrono_date_time(value)?)))
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeLocal => Value::ChronoDateTimeLocal(Some(Box::new(
+            chrono_date_time(value)?.with_timezone(&chrono::Local),
+        ))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeWithTimeZone => Value::ChronoDateTimeWithTimeZone(Some(Box::new(
+            chrono_date_time(value)?.fixed_offset(),
+        ))),
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDate => Value::TimeDate(Some(Box::new(match value {
+            DuckValue::Date32(days) => UNIX_EPOCH_JULIAN_DAY
+                .checked_add(*days)
+                .and_then(|day| time::Date::from_julian_day(day).ok())
+                .ok_or_else(|| type_err(format!("Date {days} out of range")))?,
+            value => time::Date::parse(
+                as_text(value)?,
+                time::macros::format_description!("[year]-[month]-[day]"),
+            )
+            .map_err(type_err)?,
+        }))),
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeTime => Value::TimeTime(Some(Box::new(match value {
+            DuckValue::Time64(unit, v) => {
+                time::Time::MIDNIGHT + time::Duration::microseconds(unit.to_micros(*v))
+            }
+            _ => return Err(mismatch(value, "TIME")),
+        }))),
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTime => {
+            let v = time_date_time(value)?;
+            Value::TimeDateTime(Some(Box::new(time::PrimitiveDateTime::new(
+                v.date(),
+                v.time(),
+            ))))
+        }
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTimeWithTimeZone => {
+            Value::TimeDateTimeWithTimeZone(Some(Box::new(time_date_time(value)?)))
+        }
+        #[cfg(feature = "with-uuid")]
+        ArrayType::Uuid => Value::Uuid(Some(Box::new(match value {
+            DuckValue::Blob(v) => uuid::Uuid::from_slice(v).map_err(type_err)?,
+            DuckValue::Text(v) => uuid::Uuid::parse_str(v).map_err(type_err)?,
+            DuckValue::UHugeInt(v) => uuid::Uuid::from_u128(*v),
+            _ => return Err(mismatch(value, "UUID")),
+        }))),
+        #[cfg(feature = "with-rust_decimal")]
+        ArrayType::Decimal => Value::Decimal(Some(Box::new(match value {
+            DuckValue::Float(v) => rust_decimal::Decimal::try_from(*v as f64).map_err(type_err)?,
+            DuckValue::Double(v) => rust_decimal::Decimal::try_from(*v).map_err(type_err)?,
+            value => decimal_text(value)?.parse().map_err(type_err)?,
+        }))),
+        #[cfg(feature = "with-bigdecimal")]
+        ArrayType::BigDecimal => Value::BigDecimal(Some(Box::new(match value {
+            DuckValue::Float(v) => bigdecimal::BigDecimal::try_from(*v as f64).map_err(type_err)?,
+            DuckValue::Double(v) => bigdecimal::BigDecimal::try_from(*v).map_err(type_err)?,
+            value => decimal_text(value)?.parse().map_err(type_err)?,
+        }))),
+        #[allow(unreachable_patterns)]
+        ty => return Err(type_err(format!("{ty:?} unsupported by DuckDB"))),
+    })
+}
+
+fn mismatch(value: &DuckValue, expected: &str) -> DbErr {
+    type_err(format!("Expected {expected}, found {value:?}"))
+}
+
+fn as_integer(value: &DuckValue) -> Result<i128, DbErr> {
+    Ok(match value {
+        DuckValue::TinyInt(v) => *v as i128,
+        DuckValue::SmallInt(v) => *v as i128,
+        DuckValue::Int(v) => *v as i128,
+        DuckValue::BigInt(v) => *v as i128,
+        DuckValue::HugeInt(v) => *v,
+        DuckValue::UTinyInt(v) => *v as i128,
+        DuckValue::USmallInt(v) => *v as i128,
+        DuckValue::UInt(v) => *v as i128,
+        DuckValue::UBigInt(v) => *v as i128,
+        DuckValue::Boolean(v) => *v as i128,
+        _ => return Err(mismatch(value, "INTEGER")),
+    })
+}
+
+fn as_double(value: &DuckValue) -> Result<f64, DbErr> {
+    match value {
+        DuckValue::Float(v) => Ok(*v as f64),
+        DuckValue::Double(v) => Ok(*v),
+        DuckValue::Decimal(v) => v.to_string().parse().map_err(type_err),
+        value => as_integer(value).map(|v| v as f64),
+    }
+}
+
+fn as_text(value: &DuckValue) -> Result<&str, DbErr> {
+    match value {
+        DuckValue::Text(v) | DuckValue::Enum(v) => Ok(v),
+        _ => Err(mismatch(value, "VARCHAR")),
+    }
+}
+
+#[cfg(any(feature = "with-rust_decimal", feature = "with-bigdecimal"))]
+fn decimal_text(value: &DuckValue) -> Result<String, DbErr> {
+    match value {
+        DuckValue::Decimal(v) => Ok(v.to_string()),
+        DuckValue::Text(v) => Ok(v.clone()),
+        value => as_integer(value).map(|v| v.to_string()),
+    }
+}
+
+/// `TIMESTAMP` and `TIMESTAMPTZ` values are both counted from the unix epoch in UTC
+#[cfg(feature = "with-chrono")]
+fn chrono_date_time(value: &DuckValue) -> Result<chrono::DateTime<chrono::Utc>, DbErr> {
+    match value {
+        DuckValue::Timestamp(unit, v) => {
+            let micros = unit.to_micros(*v);
+            chrono::DateTime::from_timestamp(
+                micros.div_euclid(1_000_000),
+                micros.rem_euclid(1_000_000) as u32 * 1_000,
+            )
+            .ok_or_else(|| type_err(format!("Timestamp {v} out of range")))
+        }
+        value => chrono::DateTime::parse_from_rfc3339(as_text(value)?)
+            .map(|v| v.with_timezone(&chrono::Utc))
+            .map_err(type_err),
+    }
+}
+
+/// See [chrono_date_time]
+#[cfg(feature = "with-time")]
+fn time_date_time(value: &DuckValue) -> Result<time::OffsetDateTime, DbErr> {
+    match value {
+        DuckValue::Timestamp(unit, v) => {
+            time::OffsetDateTime::from_unix_timestamp_nanos(unit.to_micros(*v) as i128 * 1_000)
+                .map_err(type_err)
+        }
+        value => time::OffsetDateTime::parse(
+            as_text(value)?,
+            &time::format_description::well_known::Rfc3339,
+        )
+        .map_err(type_err),
+    }
+}
+
+/// Converts a [duckdb::Error] execution error to a [DbErr]
+pub fn duckdb_error_to_exec_err(err: duckdb::Error) -> DbErr {
+    DbErr::Exec(RuntimeErr::DuckdbError(err))
+}
+
+/// Converts a [duckdb::Error] query error to a [DbErr]
+pub fn duckdb_error_to_query_err(err: duckdb::Error) -> DbErr {
+    DbErr::Query(RuntimeErr::DuckdbError(err))
+}
+
+/// Converts a [duckdb::Error] connection error to a [DbErr]
+pub fn duckdb_error_to_conn_err(err: duckdb::Error) -> DbErr {
+    DbErr::Conn(RuntimeErr::DuckdbError(err))
+}
+
+pub(crate) fn set_transaction_config(
+    isolation_level: Option<IsolationLevel>,
+    access_mode: Option<AccessMode>,
+) {
+    if isolation_level.is_some() {
+        warn!("Setting isolation level in a DuckDB transaction isn't supported");
+    }
+    if access_mode.is_some() {
+        warn!("Setting access mode in a DuckDB transaction isn't supported");
+    }
+}
+
+impl crate::DatabaseTransaction {
+    pub(crate) async fn new_duckdb(
+        inner: OwnedMutexGuard<DuckdbWorker>,
+        metric_callback: Option<crate::metric::Callback>,
+        log_settings: LogSettings,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<crate::DatabaseTransaction, DbErr> {
+        Self::begin(
+            Arc::new(Mutex::new(crate::InnerConnection::Duckdb(
+                DuckdbInnerConnection::new(inner),
+            ))),
+            crate::DbBackend::Postgres,
+            metric_callback,
+            log_settings,
+            isolation_level,
+            access_mode,
+        )
+        .await
+    }
+}
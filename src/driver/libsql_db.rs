@@ -0,0 +1,1031 @@
+use futures_util::{
+    lock::{Mutex, OwnedMutexGuard},
+    TryStreamExt,
+};
+use sea_query::{ArrayType, Value};
+use std::{future::Future, pin::Pin, sync::Arc, time::Duration};
+use tracing::{instrument, warn};
+
+use crate::{
+    debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
+    DatabaseTransaction, DbBackend, IsolationLevel, LogSettings, QueryStream, Statement,
+    TransactionError,
+};
+
+/// Defines the [libsql] connector
+#[derive(Debug)]
+pub struct LibsqlConnector;
+
+/// Defines a connection to a libSQL database, which is either a local file, a remote
+/// database (e.g. Turso) reached over HTTP, or an embedded replica of a remote database.
+///
+/// Like a SQLite pool with a single connection, statements are run one at a time and a
+/// transaction holds the connection until it is committed or rolled back.
+#[derive(Clone)]
+pub struct LibsqlConnection {
+    pub(crate) db: Arc<libsql::Database>,
+    conn: Arc<Mutex<libsql::Connection>>,
+    replica: bool,
+    metric_callback: Option<crate::metric::Callback>,
+    lock_err_callback: Option<crate::LockErrCallback>,
+    log_settings: LogSettings,
+}
+
+impl std::fmt::Debug for LibsqlConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LibsqlConnection {{ replica: {:?} }}", self.replica)
+    }
+}
+
+/// A row returned by a libSQL database
+#[derive(Debug, Clone)]
+pub struct LibsqlRow {
+    columns: Arc<[String]>,
+    values: Vec<libsql::Value>,
+}
+
+/// The result of executing a statement on a libSQL database
+#[derive(Debug)]
+pub(crate) struct LibsqlExecResult {
+    pub(crate) last_insert_rowid: i64,
+    pub(crate) rows_affected: u64,
+}
+
+impl LibsqlConnector {
+    /// Check if the URI provided corresponds to `libsql:` for a libSQL database
+    pub fn accepts(string: &str) -> bool {
+        string.starts_with("libsql:")
+    }
+
+    /// Connect to a libSQL database.
+    ///
+    /// `libsql://host` connects to a remote database, authenticated by
+    /// [ConnectOptions::libsql_auth_token]. With [ConnectOptions::libsql_replica_path] it keeps
+    /// an embedded replica of the remote database instead: reads are served from the local file
+    /// and writes are forwarded to the remote database. Any other `libsql:` URI, e.g.
+    /// `libsql:path/to/db.sqlite` or `libsql::memory:`, opens a local database.
+    #[instrument(level = "trace")]
+    pub async fn connect(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
+        let url = &options.url;
+        let auth_token = options.libsql_auth_token.clone().unwrap_or_default();
+        let remote = url.starts_with("libsql://");
+        let replica = remote && options.libsql_replica_path.is_some();
+
+        let db = match (&options.libsql_replica_path, remote) {
+            (Some(path), true) => {
+                let mut builder =
+                    libsql::Builder::new_remote_replica(path.as_str(), url.clone(), auth_token);
+                if let Some(sync_interval) = options.libsql_sync_interval {
+                    builder = builder.sync_interval(sync_interval);
+                }
+                builder.build().await
+            }
+            (None, true) => {
+                libsql::Builder::new_remote(url.clone(), auth_token)
+                    .build()
+                    .await
+            }
+            (_, false) => {
+                let path = url.trim_start_matches("libsql:");
+                libsql::Builder::new_local(path).build().await
+            }
+        }
+        .map_err(libsql_error_to_conn_err)?;
+        let conn = db.connect().map_err(libsql_error_to_conn_err)?;
+
+        if !remote || replica {
+            conn.busy_timeout(
+                options
+                    .sqlite_busy_timeout
+                    .unwrap_or(Duration::from_secs(5)),
+            )
+            .map_err(libsql_error_to_conn_err)?;
+            for (key, value) in &options.sqlite_pragmas {
+                // PRAGMAs may report their new value, which has to be read
+                let mut rows = conn
+                    .query(&format!("PRAGMA {key} = {value}"), ())
+                    .await
+                    .map_err(libsql_error_to_conn_err)?;
+                while rows
+                    .next()
+                    .await
+                    .map_err(libsql_error_to_conn_err)?
+                    .is_some()
+                {}
+            }
+        }
+
+        let conn = LibsqlConnection {
+            db: Arc::new(db),
+            conn: Arc::new(Mutex::new(conn)),
+            replica,
+            metric_callback: None,
+            lock_err_callback: None,
+            log_settings: LogSettings::new(&options),
+        };
+        if replica {
+            conn.sync().await?;
+        }
+
+        Ok(DatabaseConnection::LibsqlConnection(conn))
+    }
+}
+
+impl LibsqlConnection {
+    /// Wait for the connection, rolling back a transaction left open by a dropped [DatabaseTransaction]
+    async fn acquire(&self) -> Result<OwnedMutexGuard<libsql::Connection>, DbErr> {
+        let conn = self.conn.clone().lock_owned().await;
+        if !conn.is_autocommit() {
+            conn.execute("ROLLBACK", ())
+                .await
+                .map_err(libsql_error_to_conn_err)?;
+        }
+        Ok(conn)
+    }
+
+    /// Execute a [Statement] on a libSQL database
+    #[instrument(level = "trace")]
+    pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+
+        let stmt = &stmt;
+        crate::retry_on_lock_err(&self.lock_err_callback, stmt, move || async move {
+            let conn = self.acquire().await?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                self.log_settings.run(stmt, execute(&conn, stmt)).await
+            })
+        })
+        .await
+    }
+
+    /// Execute an unprepared SQL statement on a libSQL database
+    #[instrument(level = "trace")]
+    pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+
+        let stmt = Statement::from_string(DbBackend::Sqlite, sql);
+        let conn = self.acquire().await?;
+        self.log_settings
+            .run(&stmt, execute_unprepared(&conn, sql))
+            .await
+    }
+
+    /// Get one result from a SQL query. Returns [Option::None] if no match was found
+    #[instrument(level = "trace")]
+    pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let stmt = &stmt;
+        crate::retry_on_lock_err(&self.lock_err_callback, stmt, move || async move {
+            let conn = self.acquire().await?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                self.log_settings.run(stmt, query_one(&conn, stmt)).await
+            })
+        })
+        .await
+    }
+
+    /// Get the results of a query returning them as a Vec<[QueryResult]>
+    #[instrument(level = "trace")]
+    pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let stmt = &stmt;
+        crate::retry_on_lock_err(&self.lock_err_callback, stmt, move || async move {
+            let conn = self.acquire().await?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                self.log_settings.run(stmt, query_all(&conn, stmt)).await
+            })
+        })
+        .await
+    }
+
+    /// Stream the results of executing a SQL query
+    #[instrument(level = "trace")]
+    pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
+        debug_print!("{}", stmt);
+
+        let conn = self.acquire().await?;
+        self.log_settings.log(&stmt, None);
+        Ok(QueryStream::build(
+            stmt,
+            crate::InnerConnection::Libsql(LibsqlInnerConnection::new(conn)),
+            self.metric_callback.clone(),
+        ))
+    }
+
+    /// Bundle a set of SQL statements that execute together.
+    #[instrument(level = "trace")]
+    pub async fn begin(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        let conn = self.acquire().await?;
+        DatabaseTransaction::new_libsql(
+            conn,
+            self.metric_callback.clone(),
+            self.log_settings,
+            isolation_level,
+            access_mode,
+        )
+        .await
+    }
+
+    /// Create a libSQL transaction
+    #[instrument(level = "trace", skip(callback))]
+    pub async fn transaction<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'b> FnOnce(
+                &'b DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'b>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        let transaction = self
+            .begin(isolation_level, access_mode)
+            .await
+            .map_err(TransactionError::Connection)?;
+        transaction.run(callback).await
+    }
+
+    /// Whether this is an embedded replica of a remote database
+    pub fn is_replica(&self) -> bool {
+        self.replica
+    }
+
+    /// Pull the changes made on the remote database into the embedded replica, returning
+    /// the replication index (frame number) the replica is at.
+    ///
+    /// Returns an error if the connection is not an embedded replica.
+    #[instrument(level = "trace")]
+    pub async fn sync(&self) -> Result<Option<u64>, DbErr> {
+        if !self.replica {
+            return Err(conn_err("Not a libSQL embedded replica"));
+        }
+        let replicated = self.db.sync().await.map_err(libsql_error_to_conn_err)?;
+        Ok(replicated.frame_no())
+    }
+
+    pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
+    {
+        self.metric_callback = Some(Arc::new(callback));
+    }
+
+    pub(crate) fn set_lock_err_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&crate::LockErrInfo<'_>) -> crate::LockErrAction + Send + Sync + 'static,
+    {
+        self.lock_err_callback = Some(Arc::new(callback));
+    }
+
+    /// Checks if a connection to the database is still valid.
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        let conn = self.acquire().await?;
+        let mut rows = conn
+            .query("SELECT 1", ())
+            .await
+            .map_err(libsql_error_to_conn_err)?;
+        rows.next().await.map_err(libsql_error_to_conn_err)?;
+        Ok(())
+    }
+
+    /// Explicitly close the libSQL connection.
+    /// See [`Self::close_by_ref`] for usage with references.
+    pub async fn close(self) -> Result<(), DbErr> {
+        self.close_by_ref().await
+    }
+
+    /// Explicitly close the libSQL connection
+    pub async fn close_by_ref(&self) -> Result<(), DbErr> {
+        // the connection is closed once the last clone of it is dropped
+        Ok(())
+    }
+}
+
+/// The connection held by a transaction or a stream
+pub(crate) struct LibsqlInnerConnection {
+    conn: OwnedMutexGuard<libsql::Connection>,
+    depth: usize,
+    // rollbacks of dropped transactions, run before the next statement
+    pending_rollbacks: Vec<String>,
+}
+
+impl std::fmt::Debug for LibsqlInnerConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LibsqlInnerConnection {{ depth: {} }}", self.depth)
+    }
+}
+
+impl LibsqlInnerConnection {
+    fn new(conn: OwnedMutexGuard<libsql::Connection>) -> Self {
+        Self {
+            conn,
+            depth: 0,
+            pending_rollbacks: Vec::new(),
+        }
+    }
+
+    async fn ready(&mut self) -> Result<&libsql::Connection, DbErr> {
+        for sql in std::mem::take(&mut self.pending_rollbacks) {
+            self.conn
+                .execute(&sql, ())
+                .await
+                .map_err(libsql_error_to_exec_err)?;
+        }
+        Ok(&self.conn)
+    }
+
+    pub(crate) async fn execute(&mut self, stmt: &Statement) -> Result<ExecResult, DbErr> {
+        execute(self.ready().await?, stmt).await
+    }
+
+    pub(crate) async fn execute_unprepared(&mut self, sql: &str) -> Result<ExecResult, DbErr> {
+        execute_unprepared(self.ready().await?, sql).await
+    }
+
+    pub(crate) async fn query_one(
+        &mut self,
+        stmt: &Statement,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        query_one(self.ready().await?, stmt).await
+    }
+
+    pub(crate) async fn query_all(&mut self, stmt: &Statement) -> Result<Vec<QueryResult>, DbErr> {
+        query_all(self.ready().await?, stmt).await
+    }
+
+    /// Stream the rows of a query; they are all fetched before the first one is returned
+    pub(crate) fn fetch<'a>(
+        &'a mut self,
+        stmt: &'a Statement,
+    ) -> impl futures_util::Stream<Item = Result<QueryResult, DbErr>> + Send + 'a {
+        futures_util::stream::once(self.query_all(stmt))
+            .map_ok(|rows| futures_util::stream::iter(rows.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    pub(crate) async fn begin(&mut self) -> Result<(), DbErr> {
+        let sql = match self.depth {
+            0 => "BEGIN".to_owned(),
+            depth => format!("SAVEPOINT sea_orm_savepoint_{depth}"),
+        };
+        self.ready()
+            .await?
+            .execute(&sql, ())
+            .await
+            .map_err(libsql_error_to_query_err)?;
+        self.depth += 1;
+        Ok(())
+    }
+
+    pub(crate) async fn commit(&mut self) -> Result<(), DbErr> {
+        let sql = match self.depth {
+            1 => "COMMIT".to_owned(),
+            depth => format!("RELEASE SAVEPOINT sea_orm_savepoint_{}", depth - 1),
+        };
+        self.ready()
+            .await?
+            .execute(&sql, ())
+            .await
+            .map_err(libsql_error_to_query_err)?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    pub(crate) async fn rollback(&mut self) -> Result<(), DbErr> {
+        let sql = self.rollback_sql();
+        self.ready()
+            .await?
+            .execute(&sql, ())
+            .await
+            .map_err(libsql_error_to_query_err)?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    pub(crate) fn start_rollback(&mut self) {
+        if self.depth > 0 {
+            let sql = self.rollback_sql();
+            self.pending_rollbacks.push(sql);
+            self.depth -= 1;
+        }
+    }
+
+    fn rollback_sql(&self) -> String {
+        match self.depth {
+            1 => "ROLLBACK".to_owned(),
+            depth => format!("ROLLBACK TO SAVEPOINT sea_orm_savepoint_{}", depth - 1),
+        }
+    }
+}
+
+async fn execute(conn: &libsql::Connection, stmt: &Statement) -> Result<ExecResult, DbErr> {
+    let rows_affected = conn
+        .execute(&stmt.sql, libsql_values(stmt)?)
+        .await
+        .map_err(libsql_error_to_exec_err)?;
+    Ok(LibsqlExecResult {
+        last_insert_rowid: conn.last_insert_rowid(),
+        rows_affected,
+    }
+    .into())
+}
+
+async fn execute_unprepared(conn: &libsql::Connection, sql: &str) -> Result<ExecResult, DbErr> {
+    conn.execute_batch(sql)
+        .await
+        .map_err(libsql_error_to_exec_err)?;
+    Ok(LibsqlExecResult {
+        last_insert_rowid: conn.last_insert_rowid(),
+        rows_affected: conn.changes(),
+    }
+    .into())
+}
+
+async fn query_one(
+    conn: &libsql::Connection,
+    stmt: &Statement,
+) -> Result<Option<QueryResult>, DbErr> {
+    let mut rows = conn
+        .query(&stmt.sql, libsql_values(stmt)?)
+        .await
+        .map_err(libsql_error_to_query_err)?;
+    let columns = column_names(&rows);
+    match rows.next().await.map_err(libsql_error_to_query_err)? {
+        Some(row) => Ok(Some(LibsqlRow::new(columns, &row)?.into())),
+        None => Ok(None),
+    }
+}
+
+async fn query_all(conn: &libsql::Connection, stmt: &Statement) -> Result<Vec<QueryResult>, DbErr> {
+    let mut rows = conn
+        .query(&stmt.sql, libsql_values(stmt)?)
+        .await
+        .map_err(libsql_error_to_query_err)?;
+    let columns = column_names(&rows);
+    let mut results = Vec::new();
+    while let Some(row) = rows.next().await.map_err(libsql_error_to_query_err)? {
+        results.push(LibsqlRow::new(columns.clone(), &row)?.into());
+    }
+    Ok(results)
+}
+
+fn column_names(rows: &libsql::Rows) -> Arc<[String]> {
+    (0..rows.column_count())
+        .map(|i| rows.column_name(i).unwrap_or_default().to_owned())
+        .collect()
+}
+
+impl LibsqlRow {
+    fn new(columns: Arc<[String]>, row: &libsql::Row) -> Result<Self, DbErr> {
+        let values = (0..columns.len() as i32)
+            .map(|i| row.get_value(i))
+            .collect::<Result<_, _>>()
+            .map_err(libsql_error_to_query_err)?;
+        Ok(Self { columns, values })
+    }
+
+    /// The names of the columns of the row
+    pub fn column_names(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Get the value of a column as returned by libSQL
+    pub fn get_value<I: crate::ColIdx>(&self, index: I) -> Result<&libsql::Value, DbErr> {
+        let pos = if let Some(name) = index.as_str() {
+            self.columns.iter().position(|c| c == name)
+        } else if let Some(pos) = index.as_usize() {
+            (*pos < self.values.len()).then_some(*pos)
+        } else {
+            unreachable!("Missing ColIdx implementation for LibsqlRow");
+        };
+        pos.map(|pos| &self.values[pos])
+            .ok_or_else(|| query_err(format!("No column for ColIdx {index:?}")))
+    }
+
+    /// Get a value of the row, or [Option::None] if it is `NULL`
+    pub fn try_get<T, I: crate::ColIdx>(&self, index: I) -> Result<Option<T>, DbErr>
+    where
+        T: sea_query::ValueType,
+    {
+        match self.get_value(index)? {
+            libsql::Value::Null => Ok(None),
+            value => {
+                <T as sea_query::ValueType>::try_from(from_libsql_value(value, T::array_type())?)
+                    .map(Some)
+                    .map_err(type_err)
+            }
+        }
+    }
+
+    /// An iterator over the column names and values of the row, with `INTEGER`, `REAL`, `TEXT`
+    /// and `BLOB` values as [Value::BigInt], [Value::Double], [Value::String] and [Value::Bytes]
+    pub fn into_column_value_tuples(self) -> impl Iterator<Item = (String, Value)> {
+        let columns = self.columns;
+        self.values.into_iter().enumerate().map(move |(i, value)| {
+            let value = match value {
+                libsql::Value::Null => Value::String(None),
+                libsql::Value::Integer(v) => Value::BigInt(Some(v)),
+                libsql::Value::Real(v) => Value::Double(Some(v)),
+                libsql::Value::Text(v) => Value::String(Some(Box::new(v))),
+                libsql::Value::Blob(v) => Value::Bytes(Some(Box::new(v))),
+            };
+            (columns[i].clone(), value)
+        })
+    }
+}
+
+impl From<LibsqlRow> for QueryResult {
+    fn from(row: LibsqlRow) -> QueryResult {
+        QueryResult {
+            row: QueryResultRow::Libsql(row),
+        }
+    }
+}
+
+impl From<LibsqlExecResult> for ExecResult {
+    fn from(result: LibsqlExecResult) -> ExecResult {
+        ExecResult {
+            result: ExecResultHolder::Libsql(result),
+        }
+    }
+}
+
+/// Convert the values of a [Statement] the same way SQLx binds them to SQLite statements
+fn libsql_values(stmt: &Statement) -> Result<Vec<libsql::Value>, DbErr> {
+    stmt.values
+        .iter()
+        .flat_map(|values| values.0.iter())
+        .map(to_libsql_value)
+        .collect()
+}
+
+fn to_libsql_value(value: &Value) -> Result<libsql::Value, DbErr> {
+    use libsql::Value as L;
+
+    macro_rules! bind {
+        ($v: expr, $f: expr) => {
+            match $v {
+                Some(v) => $f(v),
+                None => L::Null,
+            }
+        };
+    }
+
+    Ok(match value {
+        Value::Bool(v) => bind!(v, |v: &bool| L::Integer(*v as i64)),
+        Value::TinyInt(v) => bind!(v, |v: &i8| L::Integer(*v as i64)),
+        Value::SmallInt(v) => bind!(v, |v: &i16| L::Integer(*v as i64)),
+        Value::Int(v) => bind!(v, |v: &i32| L::Integer(*v as i64)),
+        Value::BigInt(v) => bind!(v, |v: &i64| L::Integer(*v)),
+        Value::TinyUnsigned(v) => bind!(v, |v: &u8| L::Integer(*v as i64)),
+        Value::SmallUnsigned(v) => bind!(v, |v: &u16| L::Integer(*v as i64)),
+        Value::Unsigned(v) => bind!(v, |v: &u32| L::Integer(*v as i64)),
+        Value::BigUnsigned(Some(v)) => {
+            L::Integer(i64::try_from(*v).map_err(|e| DbErr::TryIntoErr {
+                from: "u64",
+                into: "i64",
+                source: Box::new(e),
+            })?)
+        }
+        Value::BigUnsigned(None) => L::Null,
+        Value::Float(v) => bind!(v, |v: &f32| L::Real(*v as f64)),
+        Value::Double(v) => bind!(v, |v: &f64| L::Real(*v)),
+        Value::String(v) => bind!(v, |v: &String| L::Text(v.to_string())),
+        Value::Char(v) => bind!(v, |v: &char| L::Text(v.to_string())),
+        Value::Bytes(v) => bind!(v, |v: &Vec<u8>| L::Blob(v.to_vec())),
+        #[cfg(feature = "with-json")]
+        Value::Json(v) => bind!(v, |v: &serde_json::Value| L::Text(v.to_string())),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(v) => bind!(v, |v: &chrono::NaiveDate| L::Text(
+            v.format("%F").to_string()
+        )),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(v) => bind!(v, |v: &chrono::NaiveTime| L::Text(
+            v.format("%T%.f").to_string()
+        )),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(v) => bind!(v, |v: &chrono::NaiveDateTime| L::Text(
+            v.format("%F %T%.f").to_string()
+        )),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(v) => bind!(v, |v: &chrono::DateTime<chrono::Utc>| {
+            L::Text(v.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false))
+        }),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(v) => bind!(v, |v: &chrono::DateTime<chrono::Local>| {
+            L::Text(v.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false))
+        }),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(v) => {
+            bind!(v, |v: &chrono::DateTime<chrono::FixedOffset>| {
+                L::Text(v.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false))
+            })
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(Some(v)) => L::Text(format_time(v.format(time_format::DATE))?),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(Some(v)) => L::Text(format_time(v.format(time_format::TIME))?),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(Some(v)) => L::Text(format_time(v.format(time_format::DATE_TIME))?),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(Some(v)) => L::Text(format_time(
+            v.format(&time::format_description::well_known::Rfc3339),
+        )?),
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(None)
+        | Value::TimeTime(None)
+        | Value::TimeDateTime(None)
+        | Value::TimeDateTimeWithTimeZone(None) => L::Null,
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(v) => bind!(v, |v: &uuid::Uuid| L::Blob(v.as_bytes().to_vec())),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(v) => bind!(v, |v: &rust_decimal::Decimal| L::Text(v.to_string())),
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(v) => bind!(v, |v: &bigdecimal::BigDecimal| L::Text(v.to_string())),
+        #[allow(unreachable_patterns)]
+        _ => return Err(type_err(format!("{value:?} unsupported by libsql"))),
+    })
+}
+
+#[cfg(feature = "with-time")]
+mod time_format {
+    use time::{format_description::FormatItem, macros::format_description};
+
+    pub(super) const DATE: &[FormatItem<'_>] = format_description!("[year]-[month]-[day]");
+    pub(super) const TIME: &[FormatItem<'_>] =
+        format_description!("[hour]:[minute]:[second].[subsecond]");
+    pub(super) const TIME_NO_SUBSECOND: &[FormatItem<'_>] =
+        format_description!("[hour]:[minute]:[second]");
+    pub(super) const DATE_TIME: &[FormatItem<'_>] =
+        format_description!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]");
+    pub(super) const DATE_TIME_NO_SUBSECOND: &[FormatItem<'_>] =
+        format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+}
+
+#[cfg(feature = "with-time")]
+fn format_time(res: Result<String, time::error::Format>) -> Result<String, DbErr> {
+    res.map_err(|e| type_err(format!("Failed to format time value: {e}")))
+}
+
+/// Convert a non-`NULL` libSQL value to the [Value] variant of `ty`, accepting the formats
+/// SQLx decodes SQLite values from
+fn from_libsql_value(value: &libsql::Value, ty: ArrayType) -> Result<Value, DbErr> {
+    macro_rules! integer {
+        ($variant: ident, $type: ty) => {{
+            let v = as_integer(value)?;
+            Value::$variant(Some(<$type>::try_from(v).map_err(|e| {
+                DbErr::TryIntoErr {
+                    from: "i64",
+                    into: stringify!($type),
+                    source: Box::new(e),
+                }
+            })?))
+        }};
+    }
+
+    Ok(match ty {
+        ArrayType::Bool => Value::Bool(Some(as_integer(value)? != 0)),
+        ArrayType::TinyInt => integer!(TinyInt, i8),
+        ArrayType::SmallInt => integer!(SmallInt, i16),
+        ArrayType::Int => integer!(Int, i32),
+        ArrayType::BigInt => Value::BigInt(Some(as_integer(value)?)),
+        ArrayType::TinyUnsigned => integer!(TinyUnsigned, u8),
+        ArrayType::SmallUnsigned => integer!(SmallUnsigned, u16),
+        ArrayType::Unsigned => integer!(Unsigned, u32),
+        ArrayType::BigUnsigned => integer!(BigUnsigned, u64),
+        ArrayType::Float => Value::Float(Some(as_real(value)? as f32)),
+        ArrayType::Double => Value::Double(Some(as_real(value)?)),
+        ArrayType::String => Value::String(Some(Box::new(as_text(value)?.to_owned()))),
+        ArrayType::Char => Value::Char(as_text(value)?.chars().next()),
+        ArrayType::Bytes => Value::Bytes(Some(Box::new(match value {
+            libsql::Value::Blob(v) => v.clone(),
+            libsql::Value::Text(v) => v.clone().into_bytes(),
+            _ => return Err(mismatch(value, "BLOB")),
+        }))),
+        #[cfg(feature = "with-json")]
+        ArrayType::Json => Value::Json(Some(Box::new(
+            serde_json::from_str(as_text(value)?).map_err(json_err)?,
+        ))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDate => Value::ChronoDate(Some(Box::new(
+            chrono::NaiveDate::parse_from_str(as_text(value)?, "%F").map_err(type_err)?,
+        ))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoTime => Value::ChronoTime(Some(Box::new(
+            chrono::NaiveTime::parse_from_str(as_text(value)?, "%T%.f").map_err(type_err)?,
+        ))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTime => {
+            Value::ChronoDateTime(Some(Box::new(chrono_date_time(value)?.naive_utc())))
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeUtc => Value::ChronoDateTimeUtc(Some(Box::new(
+            chrono_date_time(value)?.with_timezone(&chrono::Utc),
+        ))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeLocal => Value::ChronoDateTimeLocal(Some(Box::new(
+            chrono_date_time(value)?.with_timezone(&chrono::Local),
+        ))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeWithTimeZone => {
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(chrono_date_time(value)?)))
+        }
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDate => Value::TimeDate(Some(Box::new(
+            time::Date::parse(as_text(value)?, time_format::DATE).map_err(type_err)?,
+        ))),
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeTime => {
+            let text = as_text(value)?;
+            Value::TimeTime(Some(Box::new(
+                time::Time::parse(text, time_format::TIME)
+                    .or_else(|_| time::Time::parse(text, time_format::TIME_NO_SUBSECOND))
+                    .map_err(type_err)?,
+            )))
+        }
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTime => {
+            let v = time_date_time(value)?;
+            Value::TimeDateTime(Some(Box::new(time::PrimitiveDateTime::new(
+                v.date(),
+                v.time(),
+            ))))
+        }
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTimeWithTimeZone => {
+            Value::TimeDateTimeWithTimeZone(Some(Box::new(time_date_time(value)?)))
+        }
+        #[cfg(feature = "with-uuid")]
+        ArrayType::Uuid => Value::Uuid(Some(Box::new(match value {
+            libsql::Value::Blob(v) => uuid::Uuid::from_slice(v).map_err(type_err)?,
+            libsql::Value::Text(v) => uuid::Uuid::parse_str(v).map_err(type_err)?,
+            _ => return Err(mismatch(value, "BLOB")),
+        }))),
+        #[cfg(feature = "with-rust_decimal")]
+        ArrayType::Decimal => Value::Decimal(Some(Box::new(match value {
+            libsql::Value::Integer(v) => rust_decimal::Decimal::from(*v),
+            libsql::Value::Real(v) => rust_decimal::Decimal::try_from(*v).map_err(type_err)?,
+            libsql::Value::Text(v) => v.parse().map_err(type_err)?,
+            _ => return Err(mismatch(value, "REAL")),
+        }))),
+        #[cfg(feature = "with-bigdecimal")]
+        ArrayType::BigDecimal => Value::BigDecimal(Some(Box::new(match value {
+            libsql::Value::Integer(v) => bigdecimal::BigDecimal::from(*v),
+            libsql::Value::Real(v) => bigdecimal::BigDecimal::try_from(*v).map_err(type_err)?,
+            libsql::Value::Text(v) => v.parse().map_err(type_err)?,
+            _ => return Err(mismatch(value, "REAL")),
+        }))),
+        #[allow(unreachable_patterns)]
+        ty => return Err(type_err(format!("{ty:?} unsupported by libsql"))),
+    })
+}
+
+fn mismatch(value: &libsql::Value, expected: &str) -> DbErr {
+    type_err(format!("Expected {expected}, found {value:?}"))
+}
+
+fn as_integer(value: &libsql::Value) -> Result<i64, DbErr> {
+    match value {
+        libsql::Value::Integer(v) => Ok(*v),
+        _ => Err(mismatch(value, "INTEGER")),
+    }
+}
+
+fn as_real(value: &libsql::Value) -> Result<f64, DbErr> {
+    match value {
+        libsql::Value::Real(v) => Ok(*v),
+        libsql::Value::Integer(v) => Ok(*v as f64),
+        _ => Err(mismatch(value, "REAL")),
+    }
+}
+
+fn as_text(value: &libsql::Value) -> Result<&str, DbErr> {
+    match value {
+        libsql::Value::Text(v) => Ok(v),
+        _ => Err(mismatch(value, "TEXT")),
+    }
+}
+
+/// Date times are stored as RFC 3339 or `YYYY-MM-DD HH:MM:SS.SSS` text, assumed to be UTC
+/// without an offset, or as unix timestamps
+#[cfg(feature = "with-chrono")]
+fn chrono_date_time(value: &libsql::Value) -> Result<chrono::DateTime<chrono::FixedOffset>, DbErr> {
+    use chrono::{DateTime, FixedOffset, NaiveDateTime};
+
+    let utc = FixedOffset::east_opt(0).expect("zero offset");
+    if let libsql::Value::Integer(v) = value {
+        return DateTime::from_timestamp(*v, 0)
+            .map(|v| v.with_timezone(&utc))
+            .ok_or_else(|| type_err(format!("Timestamp {v} out of range")));
+    }
+    let text = as_text(value)?;
+    DateTime::parse_from_rfc3339(text)
+        .or_else(|_| DateTime::parse_from_str(text, "%F %T%.f%:z"))
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(text, "%F %T%.f")
+                .or_else(|_| NaiveDateTime::parse_from_str(text, "%FT%T%.f"))
+                .map(|v| v.and_utc().with_timezone(&utc))
+        })
+        .map_err(type_err)
+}
+
+/// See [chrono_date_time]
+#[cfg(feature = "with-time")]
+fn time_date_time(value: &libsql::Value) -> Result<time::OffsetDateTime, DbErr> {
+    use time::{format_description::well_known::Rfc3339, OffsetDateTime, PrimitiveDateTime};
+
+    if let libsql::Value::Integer(v) = value {
+        return OffsetDateTime::from_unix_timestamp(*v).map_err(type_err);
+    }
+    let text = as_text(value)?;
+    OffsetDateTime::parse(text, &Rfc3339)
+        .or_else(|_| {
+            let text = text.replacen('T', " ", 1);
+            PrimitiveDateTime::parse(&text, time_format::DATE_TIME)
+                .or_else(|_| PrimitiveDateTime::parse(&text, time_format::DATE_TIME_NO_SUBSECOND))
+                .map(PrimitiveDateTime::assume_utc)
+        })
+        .map_err(type_err)
+}
+
+/// The SQLite extended result code of an error, reported by name by remote databases
+pub(crate) fn libsql_error_code(err: &libsql::Error) -> Option<i32> {
+    const REMOTE_CODES: [(&str, i32); 9] = [
+        ("SQLITE_CONSTRAINT_PRIMARYKEY", 1555),
+        ("SQLITE_CONSTRAINT_UNIQUE", 2067),
+        ("SQLITE_CONSTRAINT_FOREIGNKEY", 787),
+        ("SQLITE_BUSY_RECOVERY", 261),
+        ("SQLITE_BUSY_SNAPSHOT", 517),
+        ("SQLITE_BUSY_TIMEOUT", 773),
+        ("SQLITE_BUSY", 5),
+        ("SQLITE_LOCKED_SHAREDCACHE", 262),
+        ("SQLITE_LOCKED", 6),
+    ];
+    match err {
+        libsql::Error::SqliteFailure(code, _) => Some(*code),
+        libsql::Error::RemoteSqliteFailure(_, code, _) => Some(*code),
+        libsql::Error::Hrana(e) => {
+            let message = e.to_string();
+            REMOTE_CODES
+                .iter()
+                .find(|(name, _)| message.contains(name))
+                .map(|(_, code)| *code)
+        }
+        _ => None,
+    }
+}
+
+/// Converts a [libsql::Error] execution error to a [DbErr]
+pub fn libsql_error_to_exec_err(err: libsql::Error) -> DbErr {
+    DbErr::Exec(RuntimeErr::LibsqlError(err))
+}
+
+/// Converts a [libsql::Error] query error to a [DbErr]
+pub fn libsql_error_to_query_err(err: libsql::Error) -> DbErr {
+    DbErr::Query(RuntimeErr::LibsqlError(err))
+}
+
+/// Converts a [libsql::Error] connection error to a [DbErr]
+pub fn libsql_error_to_conn_err(err: libsql::Error) -> DbErr {
+    DbErr::Conn(RuntimeErr::LibsqlError(err))
+}
+
+pub(crate) fn set_transaction_config(
+    isolation_level: Option<IsolationLevel>,
+    access_mode: Option<AccessMode>,
+) {
+    if isolation_level.is_some() {
+        warn!("Setting isolation level in a libSQL transaction isn't supported");
+    }
+    if access_mode.is_some() {
+        warn!("Setting access mode in a libSQL transaction isn't supported");
+    }
+}
+
+impl crate::DatabaseTransaction {
+    pub(crate) async fn new_libsql(
+        inner: OwnedMutexGuard<libsql::Connection>,
+        metric_callback: Option<crate::metric::Callback>,
+        log_settings: LogSettings,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<crate::DatabaseTransaction, DbErr> {
+        Self::begin(
+            Arc::new(Mutex::new(crate::InnerConnection::Libsql(
+                LibsqlInnerConnection::new(inner),
+            ))),
+            crate::DbBackend::Sqlite,
+            metric_callback,
+            log_settings,
+            isolation_level,
+            access_mode,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_libsql_value_round_trip() {
+        let values = [
+            Value::Bool(Some(true)),
+            Value::Int(Some(-7)),
+            Value::BigUnsigned(Some(7)),
+            Value::Double(Some(1.5)),
+            Value::String(Some(Box::new("libsql".to_owned()))),
+            Value::Bytes(Some(Box::new(vec![1, 2, 3]))),
+        ];
+        for value in values {
+            let converted = to_libsql_value(&value).unwrap();
+            let ty = match &value {
+                Value::Bool(_) => ArrayType::Bool,
+                Value::Int(_) => ArrayType::Int,
+                Value::BigUnsigned(_) => ArrayType::BigUnsigned,
+                Value::Double(_) => ArrayType::Double,
+                Value::String(_) => ArrayType::String,
+                _ => ArrayType::Bytes,
+            };
+            assert_eq!(from_libsql_value(&converted, ty).unwrap(), value);
+        }
+        assert_eq!(
+            to_libsql_value(&Value::Int(None)).unwrap(),
+            libsql::Value::Null
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn test_libsql_chrono_date_time() {
+        use chrono::{TimeZone, Utc};
+
+        let expected = Utc.with_ymd_and_hms(2024, 5, 6, 7, 8, 9).unwrap();
+        for text in [
+            "2024-05-06T07:08:09Z",
+            "2024-05-06 07:08:09",
+            "2024-05-06 07:08:09.000",
+            "2024-05-06T07:08:09",
+            "2024-05-06 07:08:09+00:00",
+        ] {
+            let value = libsql::Value::Text(text.to_owned());
+            assert_eq!(chrono_date_time(&value).unwrap(), expected, "{text}");
+        }
+        let value = libsql::Value::Integer(expected.timestamp());
+        assert_eq!(chrono_date_time(&value).unwrap(), expected);
+
+        let bound = to_libsql_value(&Value::ChronoDateTimeUtc(Some(Box::new(expected)))).unwrap();
+        assert_eq!(
+            from_libsql_value(&bound, ArrayType::ChronoDateTimeUtc).unwrap(),
+            Value::ChronoDateTimeUtc(Some(Box::new(expected)))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-time")]
+    fn test_libsql_time_date_time() {
+        use time::macros::datetime;
+
+        let expected = datetime!(2024-05-06 07:08:09 UTC);
+        for text in [
+            "2024-05-06T07:08:09Z",
+            "2024-05-06 07:08:09",
+            "2024-05-06 07:08:09.0",
+        ] {
+            let value = libsql::Value::Text(text.to_owned());
+            assert_eq!(time_date_time(&value).unwrap(), expected, "{text}");
+        }
+
+        let bound = to_libsql_value(&Value::TimeDateTime(Some(Box::new(datetime!(
+            2024-05-06 07:08:09.5
+        )))))
+        .unwrap();
+        assert_eq!(
+            bound,
+            libsql::Value::Text("2024-05-06 07:08:09.5".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_libsql_error_code() {
+        let err = libsql::Error::SqliteFailure(2067, "UNIQUE constraint failed".to_owned());
+        assert_eq!(libsql_error_code(&err), Some(2067));
+        assert!(matches!(libsql_error_to_exec_err(err), DbErr::Exec(_)));
+
+        let err = libsql::Error::RemoteSqliteFailure(5, 5, "database is locked".to_owned());
+        let err = libsql_error_to_query_err(err);
+        assert!(matches!(err.sql_err(), Some(crate::SqlErr::LockTimeout(_))));
+        assert!(err.is_lock_err());
+    }
+}
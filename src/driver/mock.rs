@@ -10,6 +10,7 @@ use std::{
         atomic::{AtomicUsize, Ordering},
         Arc, Mutex,
     },
+    time::Duration,
 };
 use tracing::instrument;
 
@@ -25,6 +26,9 @@ pub struct MockDatabaseConnection {
     mocker: Mutex<Box<dyn MockDatabaseTrait>>,
 }
 
+/// The rows of a query streamed from a [MockDatabaseTrait]
+pub type MockQueryStream = Pin<Box<dyn Stream<Item = Result<QueryResult, DbErr>> + Send>>;
+
 /// A Trait for any type wanting to perform operations on the [MockDatabase]
 pub trait MockDatabaseTrait: Send + Debug {
     /// Execute a statement in the [MockDatabase]
@@ -33,6 +37,14 @@ pub trait MockDatabaseTrait: Send + Debug {
     /// Execute a SQL query in the [MockDatabase]
     fn query(&mut self, counter: usize, stmt: Statement) -> Result<Vec<QueryResult>, DbErr>;
 
+    /// Execute a SQL query in the [MockDatabase], delivering the rows one by one
+    fn query_stream(&mut self, counter: usize, stmt: Statement) -> MockQueryStream {
+        match self.query(counter, stmt) {
+            Ok(v) => Box::pin(futures_util::stream::iter(v.into_iter().map(Ok))),
+            Err(e) => Box::pin(futures_util::stream::iter(Some(Err(e)))),
+        }
+    }
+
     /// Create a transaction that can be committed atomically
     fn begin(&mut self);
 
@@ -50,6 +62,17 @@ pub trait MockDatabaseTrait: Send + Debug {
 
     /// Ping the [MockDatabase]
     fn ping(&self) -> Result<(), DbErr>;
+
+    /// Inject a fault before a statement, `BEGIN` or `COMMIT` reaches the [MockDatabase],
+    /// either failing it or delaying it by the returned duration
+    fn fault(&mut self, _stmt: &Statement) -> Result<Option<Duration>, DbErr> {
+        Ok(None)
+    }
+
+    /// Check that the call counts of all expectations have been met
+    fn verify_expectations(&self) -> Result<(), DbErr> {
+        Ok(())
+    }
 }
 
 impl MockDatabaseConnector {
@@ -101,9 +124,9 @@ impl MockDatabaseConnector {
 
 impl MockDatabaseConnection {
     /// Create a connection to the [MockDatabase]
-    pub fn new<M: 'static>(m: M) -> Self
+    pub fn new<M>(m: M) -> Self
     where
-        M: MockDatabaseTrait,
+        M: MockDatabaseTrait + 'static,
     {
         Self {
             execute_counter: AtomicUsize::new(0),
@@ -163,19 +186,31 @@ impl MockDatabaseConnection {
             .query(counter, statement)
     }
 
+    /// Apply the fault injected before the statement, if any
+    pub(crate) async fn inject_fault(&self, statement: &Statement) -> Result<(), DbErr> {
+        let latency = self.mocker.lock().map_err(query_err)?.fault(statement)?;
+        if let Some(latency) = latency {
+            crate::sleep(latency).await;
+        }
+        Ok(())
+    }
+
     /// Return [QueryResult]s  from a multi-query operation
     #[instrument(level = "trace")]
-    pub fn fetch(
-        &self,
-        statement: &Statement,
-    ) -> Pin<Box<dyn Stream<Item = Result<QueryResult, DbErr>> + Send>> {
-        match self.query_all(statement.clone()) {
-            Ok(v) => Box::pin(futures_util::stream::iter(v.into_iter().map(Ok))),
-            Err(e) => Box::pin(futures_util::stream::iter(Some(Err(e)).into_iter())),
+    pub fn fetch(&self, statement: &Statement) -> MockQueryStream {
+        debug_print!("{}", statement);
+        let counter = self.query_counter.fetch_add(1, Ordering::SeqCst);
+        match self.mocker.lock() {
+            Ok(mut mocker) => mocker.query_stream(counter, statement.clone()),
+            Err(e) => Box::pin(futures_util::stream::iter(Some(Err(query_err(e))))),
         }
     }
 
     /// Create a statement block  of SQL statements that execute together.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the lock cannot be acquired.
     #[instrument(level = "trace")]
     pub fn begin(&self) {
         self.mocker
@@ -185,6 +220,10 @@ impl MockDatabaseConnection {
     }
 
     /// Commit a transaction atomically to the database
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the lock cannot be acquired.
     #[instrument(level = "trace")]
     pub fn commit(&self) {
         self.mocker
@@ -194,6 +233,10 @@ impl MockDatabaseConnection {
     }
 
     /// Roll back a faulty transaction
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the lock cannot be acquired.
     #[instrument(level = "trace")]
     pub fn rollback(&self) {
         self.mocker
@@ -237,6 +280,7 @@ impl crate::DatabaseTransaction {
             Arc::new(Mutex::new(crate::InnerConnection::Mock(inner))),
             backend,
             metric_callback,
+            Default::default(),
             None,
             None,
         )
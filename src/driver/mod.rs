@@ -1,3 +1,7 @@
+#[cfg(feature = "duckdb")]
+pub(crate) mod duckdb_db;
+#[cfg(feature = "libsql")]
+pub(crate) mod libsql_db;
 #[cfg(feature = "mock")]
 mod mock;
 #[cfg(feature = "proxy")]
@@ -10,7 +14,19 @@ pub(crate) mod sqlx_mysql;
 pub(crate) mod sqlx_postgres;
 #[cfg(feature = "sqlx-sqlite")]
 pub(crate) mod sqlx_sqlite;
+#[cfg(feature = "tokio-postgres")]
+pub(crate) mod tokio_postgres_db;
+#[cfg(all(
+    feature = "wasm",
+    target_arch = "wasm32",
+    not(target_feature = "atomics")
+))]
+pub(crate) mod wasm;
 
+#[cfg(feature = "duckdb")]
+pub use duckdb_db::*;
+#[cfg(feature = "libsql")]
+pub use libsql_db::*;
 #[cfg(feature = "mock")]
 pub use mock::*;
 #[cfg(feature = "proxy")]
@@ -23,3 +39,11 @@ pub use sqlx_mysql::*;
 pub use sqlx_postgres::*;
 #[cfg(feature = "sqlx-sqlite")]
 pub use sqlx_sqlite::*;
+#[cfg(feature = "tokio-postgres")]
+pub use tokio_postgres_db::*;
+#[cfg(all(
+    feature = "wasm",
+    target_arch = "wasm32",
+    not(target_feature = "atomics")
+))]
+pub use wasm::WasmDatabaseTrait;
@@ -105,6 +105,12 @@ impl ProxyDatabaseConnection {
         self.proxy.rollback().await
     }
 
+    /// Roll back a transaction dropped without a commit or rollback
+    #[instrument(level = "trace")]
+    pub fn start_rollback(&self) {
+        self.proxy.start_rollback()
+    }
+
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
         self.proxy.ping().await
@@ -140,6 +146,7 @@ impl crate::DatabaseTransaction {
             Arc::new(Mutex::new(crate::InnerConnection::Proxy(inner))),
             backend,
             metric_callback,
+            Default::default(),
             None,
             None,
         )
@@ -14,7 +14,8 @@ use tracing::instrument;
 
 use crate::{
     debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
-    DatabaseTransaction, DbBackend, IsolationLevel, QueryStream, Statement, TransactionError,
+    DatabaseTransaction, DbBackend, IsolationLevel, LogSettings, QueryStream, Statement,
+    TransactionError,
 };
 
 use super::sqlx_common::*;
@@ -28,6 +29,8 @@ pub struct SqlxMySqlConnector;
 pub struct SqlxMySqlPoolConnection {
     pub(crate) pool: MySqlPool,
     metric_callback: Option<crate::metric::Callback>,
+    lock_err_callback: Option<crate::LockErrCallback>,
+    log_settings: LogSettings,
 }
 
 impl std::fmt::Debug for SqlxMySqlPoolConnection {
@@ -41,6 +44,8 @@ impl From<MySqlPool> for SqlxMySqlPoolConnection {
         SqlxMySqlPoolConnection {
             pool,
             metric_callback: None,
+            lock_err_callback: None,
+            log_settings: LogSettings::default(),
         }
     }
 }
@@ -65,7 +70,8 @@ impl SqlxMySqlConnector {
             .parse::<MySqlConnectOptions>()
             .map_err(sqlx_error_to_conn_err)?;
         use sqlx::ConnectOptions;
-        if !options.sqlx_logging {
+        if !options.sqlx_logging || options.statement_log_level_overrides {
+            // with overrides, the statements are logged by SeaORM instead
             opt = opt.disable_statement_logging();
         } else {
             opt = opt.log_statements(options.sqlx_logging_level);
@@ -76,6 +82,7 @@ impl SqlxMySqlConnector {
                 );
             }
         }
+        let log_settings = LogSettings::for_sqlx(&options);
         let pool = if options.connect_lazy {
             options.sqlx_pool_options().connect_lazy_with(opt)
         } else {
@@ -89,6 +96,8 @@ impl SqlxMySqlConnector {
             SqlxMySqlPoolConnection {
                 pool,
                 metric_callback: None,
+                lock_err_callback: None,
+                log_settings,
             },
         ))
     }
@@ -100,6 +109,8 @@ impl SqlxMySqlConnector {
         DatabaseConnection::SqlxMySqlPoolConnection(SqlxMySqlPoolConnection {
             pool,
             metric_callback: None,
+            lock_err_callback: None,
+            log_settings: LogSettings::default(),
         })
     }
 }
@@ -110,14 +121,18 @@ impl SqlxMySqlPoolConnection {
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.execute(&mut *conn).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
-            }
+        let stmt = &stmt;
+        crate::retry_on_lock_err(&self.lock_err_callback, stmt, move || async move {
+            let query = sqlx_query(stmt);
+            let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                match self.log_settings.run(stmt, query.execute(&mut *conn)).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
         })
+        .await
     }
 
     /// Execute an unprepared SQL statement on a MySQL backend
@@ -125,8 +140,9 @@ impl SqlxMySqlPoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
+        let stmt = Statement::from_string(DbBackend::MySql, sql);
         let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        match conn.execute(sql).await {
+        match self.log_settings.run(&stmt, conn.execute(sql)).await {
             Ok(res) => Ok(res.into()),
             Err(err) => Err(sqlx_error_to_exec_err(err)),
         }
@@ -137,17 +153,25 @@ impl SqlxMySqlPoolConnection {
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_one(&mut *conn).await {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(sqlx_error_to_query_err(err)),
-                },
-            }
+        let stmt = &stmt;
+        crate::retry_on_lock_err(&self.lock_err_callback, stmt, move || async move {
+            let query = sqlx_query(stmt);
+            let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                match self
+                    .log_settings
+                    .run(stmt, query.fetch_one(&mut *conn))
+                    .await
+                {
+                    Ok(row) => Ok(Some(row.into())),
+                    Err(err) => match err {
+                        sqlx::Error::RowNotFound => Ok(None),
+                        _ => Err(sqlx_error_to_query_err(err)),
+                    },
+                }
+            })
         })
+        .await
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
@@ -155,14 +179,22 @@ impl SqlxMySqlPoolConnection {
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_all(&mut *conn).await {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                Err(err) => Err(sqlx_error_to_query_err(err)),
-            }
+        let stmt = &stmt;
+        crate::retry_on_lock_err(&self.lock_err_callback, stmt, move || async move {
+            let query = sqlx_query(stmt);
+            let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                match self
+                    .log_settings
+                    .run(stmt, query.fetch_all(&mut *conn))
+                    .await
+                {
+                    Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+                    Err(err) => Err(sqlx_error_to_query_err(err)),
+                }
+            })
         })
+        .await
     }
 
     /// Stream the results of executing a SQL query
@@ -171,6 +203,7 @@ impl SqlxMySqlPoolConnection {
         debug_print!("{}", stmt);
 
         let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        self.log_settings.log(&stmt, None);
         Ok(QueryStream::from((
             conn,
             stmt,
@@ -189,6 +222,7 @@ impl SqlxMySqlPoolConnection {
         DatabaseTransaction::new_mysql(
             conn,
             self.metric_callback.clone(),
+            self.log_settings,
             isolation_level,
             access_mode,
         )
@@ -215,6 +249,7 @@ impl SqlxMySqlPoolConnection {
         let transaction = DatabaseTransaction::new_mysql(
             conn,
             self.metric_callback.clone(),
+            self.log_settings,
             isolation_level,
             access_mode,
         )
@@ -230,6 +265,13 @@ impl SqlxMySqlPoolConnection {
         self.metric_callback = Some(Arc::new(callback));
     }
 
+    pub(crate) fn set_lock_err_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&crate::LockErrInfo<'_>) -> crate::LockErrAction + Send + Sync + 'static,
+    {
+        self.lock_err_callback = Some(Arc::new(callback));
+    }
+
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
         let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
@@ -325,6 +367,7 @@ impl crate::DatabaseTransaction {
     pub(crate) async fn new_mysql(
         inner: PoolConnection<sqlx::MySql>,
         metric_callback: Option<crate::metric::Callback>,
+        log_settings: LogSettings,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<crate::DatabaseTransaction, DbErr> {
@@ -332,6 +375,7 @@ impl crate::DatabaseTransaction {
             Arc::new(Mutex::new(crate::InnerConnection::MySql(inner))),
             crate::DbBackend::MySql,
             metric_callback,
+            log_settings,
             isolation_level,
             access_mode,
         )
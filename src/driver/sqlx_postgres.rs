@@ -1,7 +1,7 @@
 use futures_util::lock::Mutex;
 use log::LevelFilter;
 use sea_query::Values;
-use std::{fmt::Write, future::Future, pin::Pin, sync::Arc};
+use std::{future::Future, pin::Pin, sync::Arc};
 
 use sqlx::{
     pool::PoolConnection,
@@ -14,7 +14,8 @@ use tracing::instrument;
 
 use crate::{
     debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
-    DatabaseTransaction, DbBackend, IsolationLevel, QueryStream, Statement, TransactionError,
+    DatabaseTransaction, DbBackend, IsolationLevel, LogSettings, QueryStream, Statement,
+    TransactionError,
 };
 
 use super::sqlx_common::*;
@@ -28,6 +29,10 @@ pub struct SqlxPostgresConnector;
 pub struct SqlxPostgresPoolConnection {
     pub(crate) pool: PgPool,
     metric_callback: Option<crate::metric::Callback>,
+    lock_err_callback: Option<crate::LockErrCallback>,
+    log_settings: LogSettings,
+    pub(crate) cockroach: bool,
+    pub(crate) serialization_retries: u32,
 }
 
 impl std::fmt::Debug for SqlxPostgresPoolConnection {
@@ -41,6 +46,10 @@ impl From<PgPool> for SqlxPostgresPoolConnection {
         SqlxPostgresPoolConnection {
             pool,
             metric_callback: None,
+            lock_err_callback: None,
+            log_settings: LogSettings::default(),
+            cockroach: false,
+            serialization_retries: 0,
         }
     }
 }
@@ -65,7 +74,8 @@ impl SqlxPostgresConnector {
             .parse::<PgConnectOptions>()
             .map_err(sqlx_error_to_conn_err)?;
         use sqlx::ConnectOptions;
-        if !options.sqlx_logging {
+        if !options.sqlx_logging || options.statement_log_level_overrides {
+            // with overrides, the statements are logged by SeaORM instead
             opt = opt.disable_statement_logging();
         } else {
             opt = opt.log_statements(options.sqlx_logging_level);
@@ -76,25 +86,11 @@ impl SqlxPostgresConnector {
                 );
             }
         }
-        let set_search_path_sql = options.schema_search_path.as_ref().map(|schema| {
-            let mut string = "SET search_path = ".to_owned();
-            if schema.starts_with('"') {
-                write!(&mut string, "{schema}").unwrap();
-            } else {
-                for (i, schema) in schema.split(',').enumerate() {
-                    if i > 0 {
-                        write!(&mut string, ",").unwrap();
-                    }
-                    if schema.starts_with('"') {
-                        write!(&mut string, "{schema}").unwrap();
-                    } else {
-                        write!(&mut string, "\"{schema}\"").unwrap();
-                    }
-                }
-            }
-            string
-        });
+        let log_settings = LogSettings::for_sqlx(&options);
+        let set_search_path_sql = options.set_search_path_sql();
         let lazy = options.connect_lazy;
+        let cockroach = options.cockroach;
+        let serialization_retries = options.get_serialization_retries();
         let mut pool_options = options.sqlx_pool_options();
         if let Some(sql) = set_search_path_sql {
             pool_options = pool_options.after_connect(move |conn, _| {
@@ -118,6 +114,10 @@ impl SqlxPostgresConnector {
             SqlxPostgresPoolConnection {
                 pool,
                 metric_callback: None,
+                lock_err_callback: None,
+                log_settings,
+                cockroach,
+                serialization_retries,
             },
         ))
     }
@@ -129,6 +129,10 @@ impl SqlxPostgresConnector {
         DatabaseConnection::SqlxPostgresPoolConnection(SqlxPostgresPoolConnection {
             pool,
             metric_callback: None,
+            lock_err_callback: None,
+            log_settings: LogSettings::default(),
+            cockroach: false,
+            serialization_retries: 0,
         })
     }
 }
@@ -139,14 +143,18 @@ impl SqlxPostgresPoolConnection {
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.execute(&mut *conn).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
-            }
+        let stmt = &stmt;
+        self.retry(stmt, move || async move {
+            let query = sqlx_query(stmt);
+            let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                match self.log_settings.run(stmt, query.execute(&mut *conn)).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
         })
+        .await
     }
 
     /// Execute an unprepared SQL statement on a PostgreSQL backend
@@ -154,8 +162,9 @@ impl SqlxPostgresPoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
+        let stmt = Statement::from_string(DbBackend::Postgres, sql);
         let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        match conn.execute(sql).await {
+        match self.log_settings.run(&stmt, conn.execute(sql)).await {
             Ok(res) => Ok(res.into()),
             Err(err) => Err(sqlx_error_to_exec_err(err)),
         }
@@ -166,17 +175,25 @@ impl SqlxPostgresPoolConnection {
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_one(&mut *conn).await {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(sqlx_error_to_query_err(err)),
-                },
-            }
+        let stmt = &stmt;
+        self.retry(stmt, move || async move {
+            let query = sqlx_query(stmt);
+            let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                match self
+                    .log_settings
+                    .run(stmt, query.fetch_one(&mut *conn))
+                    .await
+                {
+                    Ok(row) => Ok(Some(row.into())),
+                    Err(err) => match err {
+                        sqlx::Error::RowNotFound => Ok(None),
+                        _ => Err(sqlx_error_to_query_err(err)),
+                    },
+                }
+            })
         })
+        .await
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
@@ -184,14 +201,22 @@ impl SqlxPostgresPoolConnection {
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_all(&mut *conn).await {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                Err(err) => Err(sqlx_error_to_query_err(err)),
-            }
+        let stmt = &stmt;
+        self.retry(stmt, move || async move {
+            let query = sqlx_query(stmt);
+            let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                match self
+                    .log_settings
+                    .run(stmt, query.fetch_all(&mut *conn))
+                    .await
+                {
+                    Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+                    Err(err) => Err(sqlx_error_to_query_err(err)),
+                }
+            })
         })
+        .await
     }
 
     /// Stream the results of executing a SQL query
@@ -200,6 +225,7 @@ impl SqlxPostgresPoolConnection {
         debug_print!("{}", stmt);
 
         let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        self.log_settings.log(&stmt, None);
         Ok(QueryStream::from((
             conn,
             stmt,
@@ -218,6 +244,7 @@ impl SqlxPostgresPoolConnection {
         DatabaseTransaction::new_postgres(
             conn,
             self.metric_callback.clone(),
+            self.log_settings,
             isolation_level,
             access_mode,
         )
@@ -244,6 +271,7 @@ impl SqlxPostgresPoolConnection {
         let transaction = DatabaseTransaction::new_postgres(
             conn,
             self.metric_callback.clone(),
+            self.log_settings,
             isolation_level,
             access_mode,
         )
@@ -252,6 +280,18 @@ impl SqlxPostgresPoolConnection {
         transaction.run(callback).await
     }
 
+    /// Retry a standalone statement on lock errors and serialization failures
+    async fn retry<T, F, Fut>(&self, stmt: &Statement, f: F) -> Result<T, DbErr>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, DbErr>>,
+    {
+        crate::retry_on_serialization_failure(self.serialization_retries, || {
+            crate::retry_on_lock_err(&self.lock_err_callback, stmt, &f)
+        })
+        .await
+    }
+
     pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
     where
         F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
@@ -259,6 +299,13 @@ impl SqlxPostgresPoolConnection {
         self.metric_callback = Some(Arc::new(callback));
     }
 
+    pub(crate) fn set_lock_err_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&crate::LockErrInfo<'_>) -> crate::LockErrAction + Send + Sync + 'static,
+    {
+        self.lock_err_callback = Some(Arc::new(callback));
+    }
+
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
         let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
@@ -357,6 +404,7 @@ impl crate::DatabaseTransaction {
     pub(crate) async fn new_postgres(
         inner: PoolConnection<sqlx::Postgres>,
         metric_callback: Option<crate::metric::Callback>,
+        log_settings: LogSettings,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<crate::DatabaseTransaction, DbErr> {
@@ -364,6 +412,7 @@ impl crate::DatabaseTransaction {
             Arc::new(Mutex::new(crate::InnerConnection::Postgres(inner))),
             crate::DbBackend::Postgres,
             metric_callback,
+            log_settings,
             isolation_level,
             access_mode,
         )
@@ -14,8 +14,8 @@ use tracing::{instrument, warn};
 
 use crate::{
     debug_print, error::*, executor::*, sqlx_error_to_exec_err, AccessMode, ConnectOptions,
-    DatabaseConnection, DatabaseTransaction, IsolationLevel, QueryStream, Statement,
-    TransactionError,
+    DatabaseConnection, DatabaseTransaction, DbBackend, IsolationLevel, LogSettings, QueryStream,
+    Statement, TransactionError,
 };
 
 use super::sqlx_common::*;
@@ -29,6 +29,8 @@ pub struct SqlxSqliteConnector;
 pub struct SqlxSqlitePoolConnection {
     pub(crate) pool: SqlitePool,
     metric_callback: Option<crate::metric::Callback>,
+    lock_err_callback: Option<crate::LockErrCallback>,
+    log_settings: LogSettings,
 }
 
 impl std::fmt::Debug for SqlxSqlitePoolConnection {
@@ -42,6 +44,8 @@ impl From<SqlitePool> for SqlxSqlitePoolConnection {
         SqlxSqlitePoolConnection {
             pool,
             metric_callback: None,
+            lock_err_callback: None,
+            log_settings: LogSettings::default(),
         }
     }
 }
@@ -69,8 +73,23 @@ impl SqlxSqliteConnector {
         if let Some(sqlcipher_key) = &options.sqlcipher_key {
             opt = opt.pragma("key", sqlcipher_key.clone());
         }
+        for (key, value) in &options.sqlite_pragmas {
+            opt = opt.pragma(key.clone(), value.clone());
+        }
+        for (name, entry_point) in &options.sqlite_extensions {
+            opt = match entry_point {
+                Some(entry_point) => {
+                    opt.extension_with_entrypoint(name.clone(), entry_point.clone())
+                }
+                None => opt.extension(name.clone()),
+            };
+        }
+        if let Some(busy_timeout) = options.sqlite_busy_timeout {
+            opt = opt.busy_timeout(busy_timeout);
+        }
         use sqlx::ConnectOptions;
-        if !options.sqlx_logging {
+        if !options.sqlx_logging || options.statement_log_level_overrides {
+            // with overrides, the statements are logged by SeaORM instead
             opt = opt.disable_statement_logging();
         } else {
             opt = opt.log_statements(options.sqlx_logging_level);
@@ -81,6 +100,7 @@ impl SqlxSqliteConnector {
                 );
             }
         }
+        let log_settings = LogSettings::for_sqlx(&options);
 
         if options.get_max_connections().is_none() {
             options.max_connections(1);
@@ -99,6 +119,8 @@ impl SqlxSqliteConnector {
         let pool = SqlxSqlitePoolConnection {
             pool,
             metric_callback: None,
+            lock_err_callback: None,
+            log_settings,
         };
 
         #[cfg(feature = "sqlite-use-returning-for-3_35")]
@@ -117,6 +139,8 @@ impl SqlxSqliteConnector {
         DatabaseConnection::SqlxSqlitePoolConnection(SqlxSqlitePoolConnection {
             pool,
             metric_callback: None,
+            lock_err_callback: None,
+            log_settings: LogSettings::default(),
         })
     }
 }
@@ -127,14 +151,18 @@ impl SqlxSqlitePoolConnection {
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.execute(&mut *conn).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
-            }
+        let stmt = &stmt;
+        crate::retry_on_lock_err(&self.lock_err_callback, stmt, move || async move {
+            let query = sqlx_query(stmt);
+            let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                match self.log_settings.run(stmt, query.execute(&mut *conn)).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
         })
+        .await
     }
 
     /// Execute an unprepared SQL statement on a SQLite backend
@@ -142,8 +170,9 @@ impl SqlxSqlitePoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
+        let stmt = Statement::from_string(DbBackend::Sqlite, sql);
         let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        match conn.execute(sql).await {
+        match self.log_settings.run(&stmt, conn.execute(sql)).await {
             Ok(res) => Ok(res.into()),
             Err(err) => Err(sqlx_error_to_exec_err(err)),
         }
@@ -154,17 +183,25 @@ impl SqlxSqlitePoolConnection {
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_one(&mut *conn).await {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(sqlx_error_to_query_err(err)),
-                },
-            }
+        let stmt = &stmt;
+        crate::retry_on_lock_err(&self.lock_err_callback, stmt, move || async move {
+            let query = sqlx_query(stmt);
+            let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                match self
+                    .log_settings
+                    .run(stmt, query.fetch_one(&mut *conn))
+                    .await
+                {
+                    Ok(row) => Ok(Some(row.into())),
+                    Err(err) => match err {
+                        sqlx::Error::RowNotFound => Ok(None),
+                        _ => Err(sqlx_error_to_query_err(err)),
+                    },
+                }
+            })
         })
+        .await
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
@@ -172,14 +209,22 @@ impl SqlxSqlitePoolConnection {
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_all(&mut *conn).await {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                Err(err) => Err(sqlx_error_to_query_err(err)),
-            }
+        let stmt = &stmt;
+        crate::retry_on_lock_err(&self.lock_err_callback, stmt, move || async move {
+            let query = sqlx_query(stmt);
+            let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+            crate::metric::metric!(self.metric_callback, stmt, {
+                match self
+                    .log_settings
+                    .run(stmt, query.fetch_all(&mut *conn))
+                    .await
+                {
+                    Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+                    Err(err) => Err(sqlx_error_to_query_err(err)),
+                }
+            })
         })
+        .await
     }
 
     /// Stream the results of executing a SQL query
@@ -188,6 +233,7 @@ impl SqlxSqlitePoolConnection {
         debug_print!("{}", stmt);
 
         let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        self.log_settings.log(&stmt, None);
         Ok(QueryStream::from((
             conn,
             stmt,
@@ -206,6 +252,7 @@ impl SqlxSqlitePoolConnection {
         DatabaseTransaction::new_sqlite(
             conn,
             self.metric_callback.clone(),
+            self.log_settings,
             isolation_level,
             access_mode,
         )
@@ -232,6 +279,7 @@ impl SqlxSqlitePoolConnection {
         let transaction = DatabaseTransaction::new_sqlite(
             conn,
             self.metric_callback.clone(),
+            self.log_settings,
             isolation_level,
             access_mode,
         )
@@ -247,6 +295,13 @@ impl SqlxSqlitePoolConnection {
         self.metric_callback = Some(Arc::new(callback));
     }
 
+    pub(crate) fn set_lock_err_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&crate::LockErrInfo<'_>) -> crate::LockErrAction + Send + Sync + 'static,
+    {
+        self.lock_err_callback = Some(Arc::new(callback));
+    }
+
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
         let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
@@ -376,6 +431,7 @@ impl crate::DatabaseTransaction {
     pub(crate) async fn new_sqlite(
         inner: PoolConnection<sqlx::Sqlite>,
         metric_callback: Option<crate::metric::Callback>,
+        log_settings: LogSettings,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<crate::DatabaseTransaction, DbErr> {
@@ -383,6 +439,7 @@ impl crate::DatabaseTransaction {
             Arc::new(Mutex::new(crate::InnerConnection::Sqlite(inner))),
             crate::DbBackend::Sqlite,
             metric_callback,
+            log_settings,
             isolation_level,
             access_mode,
         )
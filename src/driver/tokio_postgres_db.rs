@@ -0,0 +1,1304 @@
+use bytes::BytesMut;
+use deadpool_postgres::{
+    Hook, HookError, Manager, ManagerConfig, Object, Pool, PoolError, RecyclingMethod, Runtime,
+};
+use futures_util::{lock::Mutex, StreamExt, TryStreamExt};
+use sea_query::{ArrayType, Value};
+use std::{error::Error as StdError, future::Future, pin::Pin, sync::Arc};
+use tokio_postgres::{
+    types::{to_sql_checked, Format, FromSql, IsNull, Kind, ToSql, Type},
+    NoTls, Row, SimpleQueryMessage,
+};
+use tracing::instrument;
+
+use crate::{
+    debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
+    DatabaseTransaction, DbBackend, IsolationLevel, LogSettings, QueryStream, Statement,
+    TransactionError,
+};
+
+type BoxError = Box<dyn StdError + Sync + Send>;
+
+/// Defines the [tokio_postgres] connector
+#[derive(Debug)]
+pub struct TokioPostgresConnector;
+
+/// Defines a pool of [tokio_postgres] connections managed by [deadpool_postgres].
+///
+/// Entities and queries work as they do over SQLx. For what SeaORM doesn't cover, such as
+/// binary `COPY`, pipelining by awaiting several statements on one client concurrently, or
+/// custom types implementing [ToSql] and [FromSql], take a client from [Self::pool].
+#[derive(Clone)]
+pub struct TokioPostgresConnection {
+    pool: Pool,
+    metric_callback: Option<crate::metric::Callback>,
+    log_settings: LogSettings,
+}
+
+impl std::fmt::Debug for TokioPostgresConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TokioPostgresConnection {{ pool: {:?} }}",
+            self.pool.status()
+        )
+    }
+}
+
+/// A row returned by a [tokio_postgres] connection
+#[derive(Debug, Clone)]
+pub struct TokioPostgresRow {
+    row: Row,
+}
+
+/// The result of executing a statement on a [tokio_postgres] connection
+#[derive(Debug)]
+pub(crate) struct TokioPostgresExecResult {
+    pub(crate) rows_affected: u64,
+}
+
+impl TokioPostgresConnector {
+    /// Check if the URI provided corresponds to `postgres://` for a PostgreSQL database
+    pub fn accepts(string: &str) -> bool {
+        string.starts_with("postgres://") && string.parse::<tokio_postgres::Config>().is_ok()
+    }
+
+    /// Create a pool of connections to a PostgreSQL database
+    #[instrument(level = "trace")]
+    pub async fn connect(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
+        let mut config = options
+            .url
+            .parse::<tokio_postgres::Config>()
+            .map_err(tokio_postgres_error_to_conn_err)?;
+        if let Some(timeout) = options.connect_timeout {
+            config.connect_timeout(timeout);
+        }
+        let recycling_method = if options.test_before_acquire {
+            RecyclingMethod::Verified
+        } else {
+            RecyclingMethod::Fast
+        };
+        let manager = Manager::from_config(config, NoTls, ManagerConfig { recycling_method });
+
+        let mut builder = Pool::builder(manager)
+            .runtime(Runtime::Tokio1)
+            .create_timeout(options.connect_timeout)
+            .wait_timeout(options.acquire_timeout);
+        if let Some(max_connections) = options.max_connections {
+            builder = builder.max_size(max_connections as usize);
+        }
+        if let Some(sql) = options.set_search_path_sql() {
+            builder = builder.post_create(Hook::async_fn(move |client, _| {
+                let sql = sql.clone();
+                Box::pin(
+                    async move { client.batch_execute(&sql).await.map_err(HookError::Backend) },
+                )
+            }));
+        }
+        let pool = builder.build().map_err(|e| conn_err(e.to_string()))?;
+        if !options.connect_lazy {
+            // open a first connection to fail early if the database is unreachable
+            let _ = pool.get().await.map_err(pool_error_to_conn_err)?;
+        }
+
+        Ok(DatabaseConnection::TokioPostgresConnection(
+            TokioPostgresConnection {
+                pool,
+                metric_callback: None,
+                log_settings: LogSettings::new(&options),
+            },
+        ))
+    }
+}
+
+impl TokioPostgresConnector {
+    /// Instantiate a [deadpool_postgres] pool to a [DatabaseConnection]
+    pub fn from_tokio_postgres_pool(pool: Pool) -> DatabaseConnection {
+        DatabaseConnection::TokioPostgresConnection(TokioPostgresConnection {
+            pool,
+            metric_callback: None,
+            log_settings: LogSettings::default(),
+        })
+    }
+}
+
+impl TokioPostgresConnection {
+    async fn acquire(&self) -> Result<Object, DbErr> {
+        self.pool.get().await.map_err(pool_error_to_conn_err)
+    }
+
+    /// The pool of [tokio_postgres] clients
+    pub fn pool(&self) -> &Pool {
+        &self.pool
+    }
+
+    /// Execute a [Statement] on a PostgreSQL database
+    #[instrument(level = "trace")]
+    pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+
+        let client = self.acquire().await?;
+        crate::metric::metric!(self.metric_callback, &stmt, {
+            self.log_settings.run(&stmt, execute(&client, &stmt)).await
+        })
+    }
+
+    /// Execute an unprepared SQL statement on a PostgreSQL database
+    #[instrument(level = "trace")]
+    pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+
+        let stmt = Statement::from_string(DbBackend::Postgres, sql);
+        let client = self.acquire().await?;
+        self.log_settings
+            .run(&stmt, execute_unprepared(&client, sql))
+            .await
+    }
+
+    /// Get one result from a SQL query. Returns [Option::None] if no match was found
+    #[instrument(level = "trace")]
+    pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let client = self.acquire().await?;
+        crate::metric::metric!(self.metric_callback, &stmt, {
+            self.log_settings
+                .run(&stmt, query_one(&client, &stmt))
+                .await
+        })
+    }
+
+    /// Get the results of a query returning them as a Vec<[QueryResult]>
+    #[instrument(level = "trace")]
+    pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let client = self.acquire().await?;
+        crate::metric::metric!(self.metric_callback, &stmt, {
+            self.log_settings
+                .run(&stmt, query_all(&client, &stmt))
+                .await
+        })
+    }
+
+    /// Stream the results of executing a SQL query
+    #[instrument(level = "trace")]
+    pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
+        debug_print!("{}", stmt);
+
+        let client = self.acquire().await?;
+        self.log_settings.log(&stmt, None);
+        Ok(QueryStream::build(
+            stmt,
+            crate::InnerConnection::TokioPostgres(TokioPostgresInnerConnection::new(client)),
+            self.metric_callback.clone(),
+        ))
+    }
+
+    /// Bundle a set of SQL statements that execute together.
+    #[instrument(level = "trace")]
+    pub async fn begin(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        let client = self.acquire().await?;
+        DatabaseTransaction::new_tokio_postgres(
+            client,
+            self.metric_callback.clone(),
+            self.log_settings,
+            isolation_level,
+            access_mode,
+        )
+        .await
+    }
+
+    /// Create a PostgreSQL transaction
+    #[instrument(level = "trace", skip(callback))]
+    pub async fn transaction<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'b> FnOnce(
+                &'b DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'b>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        let transaction = self
+            .begin(isolation_level, access_mode)
+            .await
+            .map_err(TransactionError::Connection)?;
+        transaction.run(callback).await
+    }
+
+    pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
+    {
+        self.metric_callback = Some(Arc::new(callback));
+    }
+
+    /// Checks if a connection to the database is still valid.
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        let client = self.acquire().await?;
+        client
+            .batch_execute("SELECT 1")
+            .await
+            .map_err(tokio_postgres_error_to_conn_err)
+    }
+
+    /// Explicitly close the PostgreSQL connection.
+    /// See [`Self::close_by_ref`] for usage with references.
+    pub async fn close(self) -> Result<(), DbErr> {
+        self.close_by_ref().await
+    }
+
+    /// Explicitly close the PostgreSQL connection
+    pub async fn close_by_ref(&self) -> Result<(), DbErr> {
+        self.pool.close();
+        Ok(())
+    }
+}
+
+/// The client held by a transaction or a stream
+pub(crate) struct TokioPostgresInnerConnection {
+    // only taken when dropped; boxed as the pooled client is large
+    client: Option<Box<Object>>,
+    depth: usize,
+    // rollbacks of dropped transactions, run before the next statement
+    pending_rollbacks: Vec<String>,
+}
+
+impl std::fmt::Debug for TokioPostgresInnerConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TokioPostgresInnerConnection {{ depth: {} }}",
+            self.depth
+        )
+    }
+}
+
+impl Drop for TokioPostgresInnerConnection {
+    fn drop(&mut self) {
+        if self.depth > 0 || !self.pending_rollbacks.is_empty() {
+            // a transaction is still open; rather than returning the client to the pool,
+            // close its connection and let the server roll the transaction back
+            if let Some(client) = self.client.take() {
+                drop(Object::take(*client));
+            }
+        }
+    }
+}
+
+impl TokioPostgresInnerConnection {
+    fn new(client: Object) -> Self {
+        Self {
+            client: Some(Box::new(client)),
+            depth: 0,
+            pending_rollbacks: Vec::new(),
+        }
+    }
+
+    fn client(&self) -> &Object {
+        self.client.as_deref().expect("client is only taken on drop")
+    }
+
+    async fn ready(&mut self) -> Result<&Object, DbErr> {
+        for sql in std::mem::take(&mut self.pending_rollbacks) {
+            self.client()
+                .batch_execute(&sql)
+                .await
+                .map_err(tokio_postgres_error_to_exec_err)?;
+        }
+        Ok(self.client())
+    }
+
+    pub(crate) async fn execute(&mut self, stmt: &Statement) -> Result<ExecResult, DbErr> {
+        execute(self.ready().await?, stmt).await
+    }
+
+    pub(crate) async fn execute_unprepared(&mut self, sql: &str) -> Result<ExecResult, DbErr> {
+        execute_unprepared(self.ready().await?, sql).await
+    }
+
+    pub(crate) async fn query_one(
+        &mut self,
+        stmt: &Statement,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        query_one(self.ready().await?, stmt).await
+    }
+
+    pub(crate) async fn query_all(&mut self, stmt: &Statement) -> Result<Vec<QueryResult>, DbErr> {
+        query_all(self.ready().await?, stmt).await
+    }
+
+    /// Stream the rows of a query as the server sends them
+    pub(crate) fn fetch<'a>(
+        &'a mut self,
+        stmt: &'a Statement,
+    ) -> impl futures_util::Stream<Item = Result<QueryResult, DbErr>> + Send + 'a {
+        futures_util::stream::once(async move {
+            let client = self.ready().await?;
+            let params = params(stmt);
+            let prepared = prepare(client, stmt, tokio_postgres_error_to_query_err).await?;
+            client
+                .query_raw(&prepared, params.iter())
+                .await
+                .map_err(tokio_postgres_error_to_query_err)
+        })
+        .map_ok(|rows| {
+            rows.map_ok(|row| TokioPostgresRow { row }.into())
+                .map_err(tokio_postgres_error_to_query_err)
+        })
+        .try_flatten()
+    }
+
+    pub(crate) async fn begin(&mut self) -> Result<(), DbErr> {
+        let sql = match self.depth {
+            0 => "BEGIN".to_owned(),
+            depth => format!("SAVEPOINT sea_orm_savepoint_{depth}"),
+        };
+        self.batch_execute(&sql).await?;
+        self.depth += 1;
+        Ok(())
+    }
+
+    pub(crate) async fn commit(&mut self) -> Result<(), DbErr> {
+        let sql = match self.depth {
+            1 => "COMMIT".to_owned(),
+            depth => format!("RELEASE SAVEPOINT sea_orm_savepoint_{}", depth - 1),
+        };
+        self.batch_execute(&sql).await?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    pub(crate) async fn rollback(&mut self) -> Result<(), DbErr> {
+        let sql = self.rollback_sql();
+        self.batch_execute(&sql).await?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    pub(crate) fn start_rollback(&mut self) {
+        if self.depth > 0 {
+            let sql = self.rollback_sql();
+            self.pending_rollbacks.push(sql);
+            self.depth -= 1;
+        }
+    }
+
+    fn rollback_sql(&self) -> String {
+        match self.depth {
+            1 => "ROLLBACK".to_owned(),
+            depth => format!("ROLLBACK TO SAVEPOINT sea_orm_savepoint_{}", depth - 1),
+        }
+    }
+
+    async fn batch_execute(&mut self, sql: &str) -> Result<(), DbErr> {
+        self.ready()
+            .await?
+            .batch_execute(sql)
+            .await
+            .map_err(tokio_postgres_error_to_query_err)
+    }
+}
+
+/// Prepare a statement declaring the types of its parameters, so that the server converts
+/// them like it does for SQLx, e.g. an `INTEGER` value compared with a `BIGINT` column
+async fn prepare(
+    client: &Object,
+    stmt: &Statement,
+    map_err: fn(tokio_postgres::Error) -> DbErr,
+) -> Result<tokio_postgres::Statement, DbErr> {
+    let types: Vec<Type> = params(stmt).iter().map(|param| pg_type(param.0)).collect();
+    client
+        .prepare_typed_cached(&stmt.sql, &types)
+        .await
+        .map_err(map_err)
+}
+
+async fn execute(client: &Object, stmt: &Statement) -> Result<ExecResult, DbErr> {
+    let prepared = prepare(client, stmt, tokio_postgres_error_to_exec_err).await?;
+    let rows_affected = client
+        .execute_raw(&prepared, params(stmt).iter())
+        .await
+        .map_err(tokio_postgres_error_to_exec_err)?;
+    Ok(TokioPostgresExecResult { rows_affected }.into())
+}
+
+async fn execute_unprepared(client: &Object, sql: &str) -> Result<ExecResult, DbErr> {
+    let messages = client
+        .simple_query(sql)
+        .await
+        .map_err(tokio_postgres_error_to_exec_err)?;
+    let rows_affected = messages
+        .iter()
+        .map(|message| match message {
+            SimpleQueryMessage::CommandComplete(rows) => *rows,
+            _ => 0,
+        })
+        .sum();
+    Ok(TokioPostgresExecResult { rows_affected }.into())
+}
+
+async fn query_one(client: &Object, stmt: &Statement) -> Result<Option<QueryResult>, DbErr> {
+    let prepared = prepare(client, stmt, tokio_postgres_error_to_query_err).await?;
+    let rows = client
+        .query_raw(&prepared, params(stmt).iter())
+        .await
+        .map_err(tokio_postgres_error_to_query_err)?;
+    futures_util::pin_mut!(rows);
+    let row = rows
+        .try_next()
+        .await
+        .map_err(tokio_postgres_error_to_query_err)?;
+    Ok(row.map(|row| TokioPostgresRow { row }.into()))
+}
+
+async fn query_all(client: &Object, stmt: &Statement) -> Result<Vec<QueryResult>, DbErr> {
+    let prepared = prepare(client, stmt, tokio_postgres_error_to_query_err).await?;
+    client
+        .query_raw(&prepared, params(stmt).iter())
+        .await
+        .map_err(tokio_postgres_error_to_query_err)?
+        .map(|row| {
+            row.map(|row| TokioPostgresRow { row }.into())
+                .map_err(tokio_postgres_error_to_query_err)
+        })
+        .try_collect()
+        .await
+}
+
+impl TokioPostgresRow {
+    /// The names of the columns of the row
+    pub fn column_names(&self) -> Vec<String> {
+        self.row
+            .columns()
+            .iter()
+            .map(|column| column.name().to_owned())
+            .collect()
+    }
+
+    /// Access the underlying [tokio_postgres::Row], e.g. to decode a custom type with [FromSql]
+    pub fn row(&self) -> &Row {
+        &self.row
+    }
+
+    fn get_raw<I: crate::ColIdx>(&self, index: I) -> Result<(&Type, Option<&[u8]>), DbErr> {
+        let pos = if let Some(name) = index.as_str() {
+            self.row.columns().iter().position(|c| c.name() == name)
+        } else if let Some(pos) = index.as_usize() {
+            (*pos < self.row.len()).then_some(*pos)
+        } else {
+            unreachable!("Missing ColIdx implementation for TokioPostgresRow");
+        };
+        let pos = pos.ok_or_else(|| query_err(format!("No column for ColIdx {index:?}")))?;
+        let Raw(raw) = self
+            .row
+            .try_get(pos)
+            .map_err(tokio_postgres_error_to_query_err)?;
+        Ok((self.row.columns()[pos].type_(), raw))
+    }
+
+    /// Get a value of the row, or [Option::None] if it is `NULL`
+    pub fn try_get<T, I: crate::ColIdx>(&self, index: I) -> Result<Option<T>, DbErr>
+    where
+        T: sea_query::ValueType,
+    {
+        match self.get_raw(index)? {
+            (_, None) => Ok(None),
+            (ty, Some(raw)) => {
+                <T as sea_query::ValueType>::try_from(from_pg_value(ty, raw, T::array_type())?)
+                    .map(Some)
+                    .map_err(type_err)
+            }
+        }
+    }
+
+    /// Get an array value of the row, or [Option::None] if it is `NULL`
+    #[cfg(feature = "postgres-array")]
+    pub fn try_get_array<T, I: crate::ColIdx>(&self, index: I) -> Result<Option<Vec<T>>, DbErr>
+    where
+        T: sea_query::ValueType,
+    {
+        let (ty, raw) = match self.get_raw(index)? {
+            (_, None) => return Ok(None),
+            (ty, Some(raw)) => (ty, raw),
+        };
+        let Kind::Array(member) = ty.kind() else {
+            return Err(mismatch(ty, "an array"));
+        };
+        Vec::<Raw>::from_sql(ty, raw)
+            .map_err(type_err)?
+            .into_iter()
+            .map(|Raw(item)| match item {
+                None => Err(type_err("Unexpected NULL in array")),
+                Some(raw) => <T as sea_query::ValueType>::try_from(from_pg_value(
+                    member,
+                    raw,
+                    T::array_type(),
+                )?)
+                .map_err(type_err),
+            })
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+
+    /// An iterator over the column names and values of the row. Values of types without
+    /// a [Value] variant are converted to their text representation when possible.
+    pub fn into_column_value_tuples(self) -> impl Iterator<Item = (String, Value)> {
+        let columns = self.column_names();
+        columns.into_iter().enumerate().map(move |(i, column)| {
+            let value = match self.get_raw(i) {
+                Ok((ty, Some(raw))) => {
+                    from_pg_value(ty, raw, natural_type(ty)).unwrap_or(Value::String(None))
+                }
+                _ => Value::String(None),
+            };
+            (column, value)
+        })
+    }
+}
+
+impl From<TokioPostgresRow> for QueryResult {
+    fn from(row: TokioPostgresRow) -> QueryResult {
+        QueryResult {
+            row: QueryResultRow::TokioPostgres(row),
+        }
+    }
+}
+
+impl From<TokioPostgresExecResult> for ExecResult {
+    fn from(result: TokioPostgresExecResult) -> ExecResult {
+        ExecResult {
+            result: ExecResultHolder::TokioPostgres(result),
+        }
+    }
+}
+
+/// The encoded bytes of a column, whatever its type
+struct Raw<'a>(Option<&'a [u8]>);
+
+impl<'a> FromSql<'a> for Raw<'a> {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        Ok(Raw(Some(raw)))
+    }
+
+    fn from_sql_null(_: &Type) -> Result<Self, BoxError> {
+        Ok(Raw(None))
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+}
+
+/// A parameter of a [Statement], declared with the type given by [pg_type]
+#[derive(Debug)]
+struct Param<'a>(&'a Value);
+
+fn params(stmt: &Statement) -> Vec<Param<'_>> {
+    stmt.values
+        .iter()
+        .flat_map(|values| values.0.iter())
+        .map(Param)
+        .collect()
+}
+
+/// The PostgreSQL type of a parameter, matching the types SQLx binds values with
+fn pg_type(value: &Value) -> Type {
+    match value {
+        Value::Bool(_) => element_type(&ArrayType::Bool),
+        Value::TinyInt(_) => element_type(&ArrayType::TinyInt),
+        Value::SmallInt(_) => element_type(&ArrayType::SmallInt),
+        Value::Int(_) => element_type(&ArrayType::Int),
+        Value::BigInt(_) => element_type(&ArrayType::BigInt),
+        Value::TinyUnsigned(_) => element_type(&ArrayType::TinyUnsigned),
+        Value::SmallUnsigned(_) => element_type(&ArrayType::SmallUnsigned),
+        Value::Unsigned(_) => element_type(&ArrayType::Unsigned),
+        Value::BigUnsigned(_) => element_type(&ArrayType::BigUnsigned),
+        Value::Float(_) => element_type(&ArrayType::Float),
+        Value::Double(_) => element_type(&ArrayType::Double),
+        Value::String(_) => element_type(&ArrayType::String),
+        Value::Char(_) => element_type(&ArrayType::Char),
+        Value::Bytes(_) => element_type(&ArrayType::Bytes),
+        #[cfg(feature = "with-json")]
+        Value::Json(_) => element_type(&ArrayType::Json),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(_) => element_type(&ArrayType::ChronoDate),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(_) => element_type(&ArrayType::ChronoTime),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(_) => element_type(&ArrayType::ChronoDateTime),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(_) => element_type(&ArrayType::ChronoDateTimeUtc),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(_) => element_type(&ArrayType::ChronoDateTimeLocal),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(_) => {
+            element_type(&ArrayType::ChronoDateTimeWithTimeZone)
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(_) => element_type(&ArrayType::TimeDate),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(_) => element_type(&ArrayType::TimeTime),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(_) => element_type(&ArrayType::TimeDateTime),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(_) => element_type(&ArrayType::TimeDateTimeWithTimeZone),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(_) => element_type(&ArrayType::Uuid),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(_) => element_type(&ArrayType::Decimal),
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(_) => element_type(&ArrayType::BigDecimal),
+        #[cfg(feature = "postgres-array")]
+        Value::Array(ty, _) => array_type(&element_type(ty)),
+        #[allow(unreachable_patterns)]
+        _ => Type::UNKNOWN,
+    }
+}
+
+fn element_type(ty: &ArrayType) -> Type {
+    match ty {
+        ArrayType::Bool => Type::BOOL,
+        ArrayType::TinyInt | ArrayType::SmallInt | ArrayType::TinyUnsigned => Type::INT2,
+        ArrayType::Int | ArrayType::SmallUnsigned => Type::INT4,
+        ArrayType::BigInt | ArrayType::Unsigned | ArrayType::BigUnsigned => Type::INT8,
+        ArrayType::Float => Type::FLOAT4,
+        ArrayType::Double => Type::FLOAT8,
+        ArrayType::String | ArrayType::Char => Type::TEXT,
+        ArrayType::Bytes => Type::BYTEA,
+        #[cfg(feature = "with-json")]
+        ArrayType::Json => Type::JSONB,
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDate => Type::DATE,
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoTime => Type::TIME,
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTime => Type::TIMESTAMP,
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeUtc
+        | ArrayType::ChronoDateTimeLocal
+        | ArrayType::ChronoDateTimeWithTimeZone => Type::TIMESTAMPTZ,
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDate => Type::DATE,
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeTime => Type::TIME,
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTime => Type::TIMESTAMP,
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTimeWithTimeZone => Type::TIMESTAMPTZ,
+        #[cfg(feature = "with-uuid")]
+        ArrayType::Uuid => Type::UUID,
+        #[cfg(feature = "with-rust_decimal")]
+        ArrayType::Decimal => Type::NUMERIC,
+        #[cfg(feature = "with-bigdecimal")]
+        ArrayType::BigDecimal => Type::NUMERIC,
+        #[allow(unreachable_patterns)]
+        _ => Type::UNKNOWN,
+    }
+}
+
+#[cfg(feature = "postgres-array")]
+fn array_type(ty: &Type) -> Type {
+    match *ty {
+        Type::BOOL => Type::BOOL_ARRAY,
+        Type::INT2 => Type::INT2_ARRAY,
+        Type::INT4 => Type::INT4_ARRAY,
+        Type::INT8 => Type::INT8_ARRAY,
+        Type::FLOAT4 => Type::FLOAT4_ARRAY,
+        Type::FLOAT8 => Type::FLOAT8_ARRAY,
+        Type::TEXT => Type::TEXT_ARRAY,
+        Type::BYTEA => Type::BYTEA_ARRAY,
+        Type::JSONB => Type::JSONB_ARRAY,
+        Type::DATE => Type::DATE_ARRAY,
+        Type::TIME => Type::TIME_ARRAY,
+        Type::TIMESTAMP => Type::TIMESTAMP_ARRAY,
+        Type::TIMESTAMPTZ => Type::TIMESTAMPTZ_ARRAY,
+        Type::UUID => Type::UUID_ARRAY,
+        Type::NUMERIC => Type::NUMERIC_ARRAY,
+        _ => Type::UNKNOWN,
+    }
+}
+
+impl ToSql for Param<'_> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        #[allow(unused_macros)]
+        macro_rules! bind {
+            ($v: expr, $f: expr) => {
+                match $v {
+                    Some(v) => {
+                        $f(v)?;
+                        Ok(IsNull::No)
+                    }
+                    None => Ok(IsNull::Yes),
+                }
+            };
+        }
+
+        match self.0 {
+            Value::Bool(v) => v.to_sql(ty, out),
+            Value::TinyInt(v) => v.map(i16::from).to_sql(ty, out),
+            Value::SmallInt(v) => v.to_sql(ty, out),
+            Value::Int(v) => v.to_sql(ty, out),
+            Value::BigInt(v) => v.to_sql(ty, out),
+            Value::TinyUnsigned(v) => v.map(i16::from).to_sql(ty, out),
+            Value::SmallUnsigned(v) => v.map(i32::from).to_sql(ty, out),
+            Value::Unsigned(v) => v.map(i64::from).to_sql(ty, out),
+            Value::BigUnsigned(v) => v.map(i64::try_from).transpose()?.to_sql(ty, out),
+            Value::Float(v) => v.to_sql(ty, out),
+            Value::Double(v) => v.to_sql(ty, out),
+            Value::String(v) => v.as_deref().map(String::as_str).to_sql(ty, out),
+            Value::Char(v) => v.map(|v| v.to_string()).to_sql(ty, out),
+            Value::Bytes(v) => v.as_deref().map(Vec::as_slice).to_sql(ty, out),
+            #[cfg(feature = "with-json")]
+            Value::Json(v) => bind!(v, |v: &serde_json::Value| {
+                if *ty == Type::JSONB {
+                    // the version of the JSONB binary format
+                    out.extend_from_slice(&[1]);
+                }
+                serde_json::to_vec(v).map(|json| out.extend_from_slice(&json))
+            }),
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDate(v) => bind!(v, |v: &chrono::NaiveDate| {
+                put_i32(
+                    out,
+                    chrono::Datelike::num_days_from_ce(v) - PG_EPOCH_DAYS_FROM_CE,
+                )
+            }),
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoTime(v) => bind!(v, |v: &chrono::NaiveTime| {
+                put_i64(
+                    out,
+                    (*v - chrono::NaiveTime::MIN)
+                        .num_microseconds()
+                        .expect("less than a day"),
+                )
+            }),
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTime(v) => bind!(v, |v: &chrono::NaiveDateTime| {
+                put_i64(out, v.and_utc().timestamp_micros() - PG_EPOCH_UNIX_MICROS)
+            }),
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTimeUtc(v) => bind!(v, |v: &chrono::DateTime<chrono::Utc>| {
+                put_i64(out, v.timestamp_micros() - PG_EPOCH_UNIX_MICROS)
+            }),
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTimeLocal(v) => bind!(v, |v: &chrono::DateTime<chrono::Local>| {
+                put_i64(out, v.timestamp_micros() - PG_EPOCH_UNIX_MICROS)
+            }),
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTimeWithTimeZone(v) => {
+                bind!(v, |v: &chrono::DateTime<chrono::FixedOffset>| {
+                    put_i64(out, v.timestamp_micros() - PG_EPOCH_UNIX_MICROS)
+                })
+            }
+            #[cfg(feature = "with-time")]
+            Value::TimeDate(v) => bind!(v, |v: &time::Date| {
+                put_i32(out, v.to_julian_day() - PG_EPOCH_JULIAN_DAY)
+            }),
+            #[cfg(feature = "with-time")]
+            Value::TimeTime(v) => bind!(v, |v: &time::Time| {
+                put_i64(out, (*v - time::Time::MIDNIGHT).whole_microseconds() as i64)
+            }),
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTime(v) => bind!(v, |v: &time::PrimitiveDateTime| {
+                put_i64(out, time_micros(v.assume_utc()))
+            }),
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTimeWithTimeZone(v) => bind!(v, |v: &time::OffsetDateTime| {
+                put_i64(out, time_micros(*v))
+            }),
+            #[cfg(feature = "with-uuid")]
+            Value::Uuid(v) => bind!(v, |v: &uuid::Uuid| {
+                out.extend_from_slice(v.as_bytes());
+                Ok::<_, BoxError>(())
+            }),
+            // decimals are sent as text, see `encode_format`
+            #[cfg(feature = "with-rust_decimal")]
+            Value::Decimal(v) => bind!(v, |v: &rust_decimal::Decimal| {
+                out.extend_from_slice(v.to_string().as_bytes());
+                Ok::<_, BoxError>(())
+            }),
+            #[cfg(feature = "with-bigdecimal")]
+            Value::BigDecimal(v) => bind!(v, |v: &bigdecimal::BigDecimal| {
+                out.extend_from_slice(v.to_string().as_bytes());
+                Ok::<_, BoxError>(())
+            }),
+            #[cfg(feature = "postgres-array")]
+            Value::Array(_, Some(items)) if matches!(self.encode_format(ty), Format::Text) => {
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|item| match item {
+                        #[cfg(feature = "with-rust_decimal")]
+                        Value::Decimal(Some(v)) => v.to_string(),
+                        #[cfg(feature = "with-bigdecimal")]
+                        Value::BigDecimal(Some(v)) => v.to_string(),
+                        _ => "NULL".to_owned(),
+                    })
+                    .collect();
+                out.extend_from_slice(format!("{{{}}}", items.join(",")).as_bytes());
+                Ok(IsNull::No)
+            }
+            #[cfg(feature = "postgres-array")]
+            Value::Array(_, Some(items)) => {
+                items.iter().map(Param).collect::<Vec<_>>().to_sql(ty, out)
+            }
+            #[cfg(feature = "postgres-array")]
+            Value::Array(_, None) => Ok(IsNull::Yes),
+            #[allow(unreachable_patterns)]
+            value => Err(format!("{value:?} unsupported by tokio-postgres").into()),
+        }
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+
+    fn encode_format(&self, _: &Type) -> Format {
+        match self.0 {
+            #[cfg(feature = "with-rust_decimal")]
+            Value::Decimal(_) => Format::Text,
+            #[cfg(feature = "with-bigdecimal")]
+            Value::BigDecimal(_) => Format::Text,
+            #[cfg(all(feature = "postgres-array", feature = "with-rust_decimal"))]
+            Value::Array(ArrayType::Decimal, _) => Format::Text,
+            #[cfg(all(feature = "postgres-array", feature = "with-bigdecimal"))]
+            Value::Array(ArrayType::BigDecimal, _) => Format::Text,
+            _ => Format::Binary,
+        }
+    }
+
+    to_sql_checked!();
+}
+
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+fn put_i32(out: &mut BytesMut, v: i32) -> Result<(), BoxError> {
+    out.extend_from_slice(&v.to_be_bytes());
+    Ok(())
+}
+
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+fn put_i64(out: &mut BytesMut, v: i64) -> Result<(), BoxError> {
+    out.extend_from_slice(&v.to_be_bytes());
+    Ok(())
+}
+
+/// Microseconds since 2000-01-01 00:00:00 UTC
+#[cfg(feature = "with-time")]
+fn time_micros(v: time::OffsetDateTime) -> i64 {
+    (v.unix_timestamp_nanos() / 1_000) as i64 - PG_EPOCH_UNIX_MICROS
+}
+
+/// PostgreSQL counts dates and timestamps from 2000-01-01
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+const PG_EPOCH_UNIX_MICROS: i64 = 946_684_800_000_000;
+#[cfg(feature = "with-chrono")]
+const PG_EPOCH_DAYS_FROM_CE: i32 = 730_120;
+#[cfg(feature = "with-time")]
+const PG_EPOCH_JULIAN_DAY: i32 = 2_451_545;
+
+/// The [Value] variant a column of type `ty` is converted to without being asked for one
+fn natural_type(ty: &Type) -> ArrayType {
+    match *ty {
+        Type::BOOL => ArrayType::Bool,
+        Type::CHAR => ArrayType::TinyInt,
+        Type::INT2 => ArrayType::SmallInt,
+        Type::INT4 => ArrayType::Int,
+        Type::INT8 => ArrayType::BigInt,
+        Type::OID => ArrayType::Unsigned,
+        Type::FLOAT4 => ArrayType::Float,
+        Type::FLOAT8 => ArrayType::Double,
+        Type::BYTEA => ArrayType::Bytes,
+        #[cfg(feature = "with-json")]
+        Type::JSON | Type::JSONB => ArrayType::Json,
+        #[cfg(feature = "with-chrono")]
+        Type::DATE => ArrayType::ChronoDate,
+        #[cfg(feature = "with-chrono")]
+        Type::TIME => ArrayType::ChronoTime,
+        #[cfg(feature = "with-chrono")]
+        Type::TIMESTAMP => ArrayType::ChronoDateTime,
+        #[cfg(feature = "with-chrono")]
+        Type::TIMESTAMPTZ => ArrayType::ChronoDateTimeWithTimeZone,
+        #[cfg(feature = "with-uuid")]
+        Type::UUID => ArrayType::Uuid,
+        #[cfg(feature = "with-rust_decimal")]
+        Type::NUMERIC => ArrayType::Decimal,
+        _ => ArrayType::String,
+    }
+}
+
+/// Convert a non-`NULL` column of type `ty` to the [Value] variant of `want`
+fn from_pg_value(ty: &Type, raw: &[u8], want: ArrayType) -> Result<Value, DbErr> {
+    macro_rules! integer {
+        ($variant: ident, $type: ty) => {{
+            let v = as_integer(ty, raw)?;
+            Value::$variant(Some(<$type>::try_from(v).map_err(|e| {
+                DbErr::TryIntoErr {
+                    from: "i64",
+                    into: stringify!($type),
+                    source: Box::new(e),
+                }
+            })?))
+        }};
+    }
+
+    Ok(match want {
+        ArrayType::Bool => Value::Bool(Some(match *ty {
+            Type::BOOL => decode(ty, raw)?,
+            _ => as_integer(ty, raw)? != 0,
+        })),
+        ArrayType::TinyInt => integer!(TinyInt, i8),
+        ArrayType::SmallInt => integer!(SmallInt, i16),
+        ArrayType::Int => integer!(Int, i32),
+        ArrayType::BigInt => integer!(BigInt, i64),
+        ArrayType::TinyUnsigned => integer!(TinyUnsigned, u8),
+        ArrayType::SmallUnsigned => integer!(SmallUnsigned, u16),
+        ArrayType::Unsigned => integer!(Unsigned, u32),
+        ArrayType::BigUnsigned => integer!(BigUnsigned, u64),
+        ArrayType::Float => Value::Float(Some(as_double(ty, raw)? as f32)),
+        ArrayType::Double => Value::Double(Some(as_double(ty, raw)?)),
+        ArrayType::String => Value::String(Some(Box::new(as_text(ty, raw)?))),
+        ArrayType::Char => Value::Char(as_text(ty, raw)?.chars().next()),
+        ArrayType::Bytes => Value::Bytes(Some(Box::new(raw.to_vec()))),
+        #[cfg(feature = "with-json")]
+        ArrayType::Json => Value::Json(Some(Box::new(match *ty {
+            Type::JSONB => match raw.split_first() {
+                Some((1, json)) => serde_json::from_slice(json).map_err(json_err)?,
+                _ => return Err(type_err("Unsupported JSONB format")),
+            },
+            _ => serde_json::from_str(&as_text(ty, raw)?).map_err(json_err)?,
+        }))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDate => Value::ChronoDate(Some(Box::new(match *ty {
+            Type::DATE => {
+                let days: i32 = be_bytes(ty, raw).map(i32::from_be_bytes)?;
+                PG_EPOCH_DAYS_FROM_CE
+                    .checked_add(days)
+                    .and_then(chrono::NaiveDate::from_num_days_from_ce_opt)
+                    .ok_or_else(|| type_err(format!("Date {days} out of range")))?
+            }
+            Type::TIMESTAMP | Type::TIMESTAMPTZ => chrono_date_time(ty, raw)?.date_naive(),
+            _ => chrono::NaiveDate::parse_from_str(&as_text(ty, raw)?, "%F").map_err(type_err)?,
+        }))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoTime => Value::ChronoTime(Some(Box::new(match *ty {
+            Type::TIME => {
+                let micros = be_bytes(ty, raw).map(i64::from_be_bytes)?;
+                chrono::NaiveTime::MIN + chrono::Duration::microseconds(micros)
+            }
+            _ => {
+                chrono::NaiveTime::parse_from_str(&as_text(ty, raw)?, "%T%.f").map_err(type_err)?
+            }
+        }))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTime => {
+            Value::ChronoDateTime(Some(Box::new(chrono_date_time(ty, raw)?.naive_utc())))
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeUtc => {
+            Value::ChronoDateTimeUtc(Some(Box::new(chrono_date_time(ty, raw)?)))
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeLocal => Value::ChronoDateTimeLocal(Some(Box::new(
+            chrono_date_time(ty, raw)?.with_timezone(&chrono::Local),
+        ))),
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeWithTimeZone => Value::ChronoDateTimeWithTimeZone(Some(Box::new(
+            chrono_date_time(ty, raw)?.fixed_offset(),
+        ))),
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDate => Value::TimeDate(Some(Box::new(match *ty {
+            Type::DATE => {
+                let days: i32 = be_bytes(ty, raw).map(i32::from_be_bytes)?;
+                PG_EPOCH_JULIAN_DAY
+                    .checked_add(days)
+                    .and_then(|day| time::Date::from_julian_day(day).ok())
+                    .ok_or_else(|| type_err(format!("Date {days} out of range")))?
+            }
+            _ => time_date_time(ty, raw)?.date(),
+        }))),
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeTime => Value::TimeTime(Some(Box::new(match *ty {
+            Type::TIME => {
+                let micros = be_bytes(ty, raw).map(i64::from_be_bytes)?;
+                time::Time::MIDNIGHT + time::Duration::microseconds(micros)
+            }
+            _ => return Err(mismatch(ty, "TIME")),
+        }))),
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTime => {
+            let v = time_date_time(ty, raw)?;
+            Value::TimeDateTime(Some(Box::new(time::PrimitiveDateTime::new(
+                v.date(),
+                v.time(),
+            ))))
+        }
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTimeWithTimeZone => {
+            Value::TimeDateTimeWithTimeZone(Some(Box::new(time_date_time(ty, raw)?)))
+        }
+        #[cfg(feature = "with-uuid")]
+        ArrayType::Uuid => Value::Uuid(Some(Box::new(match *ty {
+            Type::UUID => uuid::Uuid::from_slice(raw).map_err(type_err)?,
+            _ => uuid::Uuid::parse_str(&as_text(ty, raw)?).map_err(type_err)?,
+        }))),
+        #[cfg(feature = "with-rust_decimal")]
+        ArrayType::Decimal => Value::Decimal(Some(Box::new(match *ty {
+            Type::FLOAT4 | Type::FLOAT8 => {
+                rust_decimal::Decimal::try_from(as_double(ty, raw)?).map_err(type_err)?
+            }
+            _ => decimal_text(ty, raw)?.parse().map_err(type_err)?,
+        }))),
+        #[cfg(feature = "with-bigdecimal")]
+        ArrayType::BigDecimal => Value::BigDecimal(Some(Box::new(match *ty {
+            Type::FLOAT4 | Type::FLOAT8 => {
+                bigdecimal::BigDecimal::try_from(as_double(ty, raw)?).map_err(type_err)?
+            }
+            _ => decimal_text(ty, raw)?.parse().map_err(type_err)?,
+        }))),
+        #[allow(unreachable_patterns)]
+        ty => return Err(type_err(format!("{ty:?} unsupported by tokio-postgres"))),
+    })
+}
+
+fn mismatch(ty: &Type, expected: &str) -> DbErr {
+    type_err(format!("Expected {expected}, found {ty}"))
+}
+
+fn decode<'a, T: FromSql<'a>>(ty: &Type, raw: &'a [u8]) -> Result<T, DbErr> {
+    T::from_sql(ty, raw).map_err(type_err)
+}
+
+fn be_bytes<const N: usize>(ty: &Type, raw: &[u8]) -> Result<[u8; N], DbErr> {
+    raw.try_into()
+        .map_err(|_| type_err(format!("Invalid {ty} of {} bytes", raw.len())))
+}
+
+fn as_integer(ty: &Type, raw: &[u8]) -> Result<i64, DbErr> {
+    Ok(match *ty {
+        Type::BOOL => decode::<bool>(ty, raw)? as i64,
+        Type::CHAR => decode::<i8>(ty, raw)? as i64,
+        Type::INT2 => decode::<i16>(ty, raw)? as i64,
+        Type::INT4 => decode::<i32>(ty, raw)? as i64,
+        Type::INT8 => decode::<i64>(ty, raw)?,
+        Type::OID => decode::<u32>(ty, raw)? as i64,
+        _ => return Err(mismatch(ty, "an integer")),
+    })
+}
+
+fn as_double(ty: &Type, raw: &[u8]) -> Result<f64, DbErr> {
+    match *ty {
+        Type::FLOAT4 => Ok(decode::<f32>(ty, raw)? as f64),
+        Type::FLOAT8 => decode(ty, raw),
+        Type::NUMERIC => numeric_text(raw)?.parse().map_err(type_err),
+        _ => as_integer(ty, raw).map(|v| v as f64),
+    }
+}
+
+fn as_text(ty: &Type, raw: &[u8]) -> Result<String, DbErr> {
+    match *ty {
+        Type::NUMERIC => numeric_text(raw),
+        Type::JSONB => match raw.split_first() {
+            Some((1, json)) => std::str::from_utf8(json)
+                .map(ToOwned::to_owned)
+                .map_err(type_err),
+            _ => Err(type_err("Unsupported JSONB format")),
+        },
+        Type::UUID => {
+            let bytes: [u8; 16] = be_bytes(ty, raw)?;
+            Ok(bytes
+                .iter()
+                .enumerate()
+                .fold(String::new(), |mut s, (i, b)| {
+                    if matches!(i, 4 | 6 | 8 | 10) {
+                        s.push('-');
+                    }
+                    s.push_str(&format!("{b:02x}"));
+                    s
+                }))
+        }
+        // text, enums and other types sent as text, e.g. `citext`
+        _ if <&str as FromSql>::accepts(ty)
+            || *ty == Type::JSON
+            || matches!(ty.kind(), Kind::Enum(_)) =>
+        {
+            std::str::from_utf8(raw)
+                .map(ToOwned::to_owned)
+                .map_err(type_err)
+        }
+        _ => Err(mismatch(ty, "a text")),
+    }
+}
+
+#[cfg(any(feature = "with-rust_decimal", feature = "with-bigdecimal"))]
+fn decimal_text(ty: &Type, raw: &[u8]) -> Result<String, DbErr> {
+    match *ty {
+        Type::NUMERIC => numeric_text(raw),
+        _ if <&str as FromSql>::accepts(ty) => as_text(ty, raw),
+        _ => as_integer(ty, raw).map(|v| v.to_string()),
+    }
+}
+
+/// Decode the binary format of `NUMERIC`: base 10000 digits with the weight of the first
+/// one, a sign and the number of decimal digits after the point
+fn numeric_text(raw: &[u8]) -> Result<String, DbErr> {
+    let word = |i: usize| -> Result<i16, DbErr> {
+        raw.get(2 * i..2 * i + 2)
+            .map(|b| i16::from_be_bytes([b[0], b[1]]))
+            .ok_or_else(|| type_err("Invalid NUMERIC"))
+    };
+    let ndigits = word(0)? as usize;
+    let weight = word(1)? as i32;
+    let sign = word(2)? as u16;
+    let scale = word(3)? as u16 as usize;
+    let digit = |i: i32| -> Result<i16, DbErr> {
+        if (0..ndigits as i32).contains(&i) {
+            word(4 + i as usize)
+        } else {
+            Ok(0)
+        }
+    };
+
+    let mut text = match sign {
+        0x0000 => String::new(),
+        0x4000 => "-".to_owned(),
+        0xC000 => return Ok("NaN".to_owned()),
+        0xD000 => return Ok("Infinity".to_owned()),
+        0xF000 => return Ok("-Infinity".to_owned()),
+        _ => return Err(type_err("Invalid NUMERIC sign")),
+    };
+    if weight < 0 {
+        text.push('0');
+    }
+    for i in 0..=weight {
+        match i {
+            0 => text.push_str(&digit(i)?.to_string()),
+            _ => text.push_str(&format!("{:04}", digit(i)?)),
+        }
+    }
+    if scale > 0 {
+        let mut fraction = String::new();
+        for i in 0..(scale as i32 + 3) / 4 {
+            fraction.push_str(&format!("{:04}", digit(weight + 1 + i)?));
+        }
+        fraction.truncate(scale);
+        text.push('.');
+        text.push_str(&fraction);
+    }
+    Ok(text)
+}
+
+/// `TIMESTAMP` values are read as UTC
+#[cfg(feature = "with-chrono")]
+fn chrono_date_time(ty: &Type, raw: &[u8]) -> Result<chrono::DateTime<chrono::Utc>, DbErr> {
+    let micros = match *ty {
+        Type::TIMESTAMP | Type::TIMESTAMPTZ => be_bytes(ty, raw).map(i64::from_be_bytes)?,
+        Type::DATE => be_bytes(ty, raw).map(i32::from_be_bytes)? as i64 * 86_400_000_000,
+        _ => {
+            return chrono::DateTime::parse_from_rfc3339(&as_text(ty, raw)?)
+                .map(|v| v.with_timezone(&chrono::Utc))
+                .map_err(type_err)
+        }
+    };
+    let micros = micros
+        .checked_add(PG_EPOCH_UNIX_MICROS)
+        .ok_or_else(|| type_err(format!("Timestamp {micros} out of range")))?;
+    chrono::DateTime::from_timestamp(
+        micros.div_euclid(1_000_000),
+        micros.rem_euclid(1_000_000) as u32 * 1_000,
+    )
+    .ok_or_else(|| type_err(format!("Timestamp {micros} out of range")))
+}
+
+/// See [chrono_date_time]
+#[cfg(feature = "with-time")]
+fn time_date_time(ty: &Type, raw: &[u8]) -> Result<time::OffsetDateTime, DbErr> {
+    let micros = match *ty {
+        Type::TIMESTAMP | Type::TIMESTAMPTZ => be_bytes(ty, raw).map(i64::from_be_bytes)?,
+        Type::DATE => be_bytes(ty, raw).map(i32::from_be_bytes)? as i64 * 86_400_000_000,
+        _ => {
+            return time::OffsetDateTime::parse(
+                &as_text(ty, raw)?,
+                &time::format_description::well_known::Rfc3339,
+            )
+            .map_err(type_err)
+        }
+    };
+    time::OffsetDateTime::from_unix_timestamp_nanos(
+        (micros as i128 + PG_EPOCH_UNIX_MICROS as i128) * 1_000,
+    )
+    .map_err(type_err)
+}
+
+fn pool_error_to_conn_err(err: PoolError) -> DbErr {
+    match err {
+        PoolError::Backend(e) => tokio_postgres_error_to_conn_err(e),
+        PoolError::Timeout(_) => DbErr::ConnectionAcquire(ConnAcquireErr::Timeout),
+        PoolError::Closed => DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed),
+        e => conn_err(e.to_string()),
+    }
+}
+
+/// Converts a [tokio_postgres::Error] execution error to a [DbErr]
+pub fn tokio_postgres_error_to_exec_err(err: tokio_postgres::Error) -> DbErr {
+    DbErr::Exec(RuntimeErr::TokioPostgresError(err))
+}
+
+/// Converts a [tokio_postgres::Error] query error to a [DbErr]
+pub fn tokio_postgres_error_to_query_err(err: tokio_postgres::Error) -> DbErr {
+    DbErr::Query(RuntimeErr::TokioPostgresError(err))
+}
+
+/// Converts a [tokio_postgres::Error] connection error to a [DbErr]
+pub fn tokio_postgres_error_to_conn_err(err: tokio_postgres::Error) -> DbErr {
+    DbErr::Conn(RuntimeErr::TokioPostgresError(err))
+}
+
+pub(crate) async fn set_transaction_config(
+    conn: &mut TokioPostgresInnerConnection,
+    isolation_level: Option<IsolationLevel>,
+    access_mode: Option<AccessMode>,
+) -> Result<(), DbErr> {
+    if let Some(isolation_level) = isolation_level {
+        conn.execute_unprepared(&format!(
+            "SET TRANSACTION ISOLATION LEVEL {isolation_level}"
+        ))
+        .await?;
+    }
+    if let Some(access_mode) = access_mode {
+        conn.execute_unprepared(&format!("SET TRANSACTION {access_mode}"))
+            .await?;
+    }
+    Ok(())
+}
+
+impl crate::DatabaseTransaction {
+    pub(crate) async fn new_tokio_postgres(
+        inner: Object,
+        metric_callback: Option<crate::metric::Callback>,
+        log_settings: LogSettings,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<crate::DatabaseTransaction, DbErr> {
+        Self::begin(
+            Arc::new(Mutex::new(crate::InnerConnection::TokioPostgres(
+                TokioPostgresInnerConnection::new(inner),
+            ))),
+            crate::DbBackend::Postgres,
+            metric_callback,
+            log_settings,
+            isolation_level,
+            access_mode,
+        )
+        .await
+    }
+}
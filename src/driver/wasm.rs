@@ -0,0 +1,111 @@
+//! Browser-side databases for `wasm32-unknown-unknown`.
+//!
+//! Implement [WasmDatabaseTrait] over a SQLite build that runs in the browser,
+//! such as sql.js or wa-sqlite backed by OPFS, and pass it to
+//! [Database::connect_wasm](crate::Database::connect_wasm). The same entities
+//! used on the server can then be queried client-side.
+//!
+//! ```ignore
+//! #[derive(Debug)]
+//! struct SqlJs(js_sys::Object);
+//!
+//! #[async_trait::async_trait(?Send)]
+//! impl WasmDatabaseTrait for SqlJs {
+//!     async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+//!         // call `db.exec(sql, params)` and convert each row into a `ProxyRow`
+//!     }
+//!
+//!     async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+//!         // call `db.run(sql, params)`, then read `changes()` and `last_insert_rowid()`
+//!     }
+//! }
+//!
+//! let db = Database::connect_wasm(DbBackend::Sqlite, SqlJs(handle)).await?;
+//! ```
+
+use crate::{error::*, ProxyDatabaseTrait, ProxyExecResult, ProxyRow, Statement};
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Defines an async database driver running inside the browser.
+///
+/// Unlike [ProxyDatabaseTrait], neither the driver nor its futures have to be [Send],
+/// so JavaScript handles and promises can be held and awaited directly.
+#[async_trait::async_trait(?Send)]
+pub trait WasmDatabaseTrait: Debug {
+    /// Execute a query and return the query results
+    async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr>;
+
+    /// Execute a command and report the number of rows affected
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr>;
+
+    /// Begin a transaction
+    async fn begin(&self) {}
+
+    /// Commit a transaction
+    async fn commit(&self) {}
+
+    /// Rollback a transaction
+    async fn rollback(&self) {}
+
+    /// Ping the database, it should return an error if the database is not available
+    async fn ping(&self) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
+
+/// Adapts a [WasmDatabaseTrait] to the [ProxyDatabaseTrait] the connection is built on
+#[derive(Debug)]
+pub(crate) struct WasmProxy(pub(crate) Box<dyn WasmDatabaseTrait>);
+
+// SAFETY: this module is only compiled for wasm32 without the `atomics` target feature,
+// where there is a single thread and values can never actually be sent across threads.
+unsafe impl Send for WasmProxy {}
+unsafe impl Sync for WasmProxy {}
+
+struct AssertSend<F>(F);
+
+// SAFETY: see `WasmProxy`
+unsafe impl<F> Send for AssertSend<F> {}
+
+impl<F> Future for AssertSend<F>
+where
+    F: Future + Unpin,
+{
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+#[async_trait::async_trait]
+impl ProxyDatabaseTrait for WasmProxy {
+    async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+        AssertSend(self.0.query(statement)).await
+    }
+
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+        AssertSend(self.0.execute(statement)).await
+    }
+
+    async fn begin(&self) {
+        AssertSend(self.0.begin()).await
+    }
+
+    async fn commit(&self) {
+        AssertSend(self.0.commit()).await
+    }
+
+    async fn rollback(&self) {
+        AssertSend(self.0.rollback()).await
+    }
+
+    async fn ping(&self) -> Result<(), DbErr> {
+        AssertSend(self.0.ping()).await
+    }
+}
@@ -509,6 +509,7 @@ mod tests {
             db_type = "String(StringLen::None)",
             enum_name = "conflicting_string_values"
         )]
+        #[allow(dead_code)]
         pub enum ConflictingStringValues {
             #[sea_orm(string_value = "")]
             Member1,
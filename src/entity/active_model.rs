@@ -1,19 +1,21 @@
 use crate::{
-    error::*, ConnectionTrait, DeleteResult, EntityTrait, Iterable, PrimaryKeyArity,
-    PrimaryKeyToColumn, PrimaryKeyTrait, Value,
+    error::*, Changeset, ColumnTrait, ConnectionTrait, DeleteResult, EntityName, EntityTrait,
+    Iterable, ModelTrait, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait,
+    TransactionCallback, TransactionOutcome, TransactionTrait, ValidationErrors, Value,
 };
 use async_trait::async_trait;
-use sea_query::{Nullable, ValueTuple};
-use std::fmt::Debug;
+use sea_query::{FromValueTuple, Nullable, SimpleExpr, ValueTuple};
+use std::{collections::HashMap, fmt::Debug};
 
 pub use ActiveValue::{NotSet, Set, Unchanged};
 
 /// Defines a stateful value used in ActiveModel.
 ///
-/// There are three possible state represented by three enum variants.
+/// There are four possible state represented by four enum variants.
 /// - [ActiveValue::Set]: A defined [Value] actively being set
 /// - [ActiveValue::Unchanged]: A defined [Value] remain unchanged
 /// - [ActiveValue::NotSet]: An undefined [Value]
+/// - [ActiveValue::SetExpr]: An SQL expression actively being set, evaluated by the database
 ///
 /// The stateful value is useful when constructing UPDATE SQL statement,
 /// see an example below.
@@ -22,7 +24,7 @@ pub use ActiveValue::{NotSet, Set, Unchanged};
 ///
 /// ```
 /// use sea_orm::tests_cfg::{cake, fruit};
-/// use sea_orm::{entity::*, query::*, DbBackend};
+/// use sea_orm::{entity::*, query::*, sea_query::Expr, DbBackend};
 ///
 /// // The code snipped below does an UPDATE operation on a `ActiveValue`
 /// assert_eq!(
@@ -35,6 +37,18 @@ pub use ActiveValue::{NotSet, Set, Unchanged};
 ///     .to_string(),
 ///     r#"UPDATE "fruit" SET "name" = 'Orange' WHERE "fruit"."id" = 1"#
 /// );
+///
+/// // A column can be assigned an expression, while the others are bound values
+/// assert_eq!(
+///     Update::one(fruit::ActiveModel {
+///         id: ActiveValue::set(1),
+///         name: ActiveValue::expr(Expr::cust("upper(name)")),
+///         cake_id: ActiveValue::set(Some(2)),
+///     })
+///     .build(DbBackend::Postgres)
+///     .to_string(),
+///     r#"UPDATE "fruit" SET "name" = upper(name), "cake_id" = 2 WHERE "fruit"."id" = 1"#
+/// );
 /// ```
 #[derive(Clone, Debug)]
 pub enum ActiveValue<V>
@@ -47,6 +61,10 @@ where
     Unchanged(V),
     /// An undefined [Value]
     NotSet,
+    /// An SQL expression actively being set, like `now()` or `DEFAULT`, evaluated by the
+    /// database on insert or update, or compared with on the primary key of an update or
+    /// delete. The ActiveModel has no [Value] for it.
+    SetExpr(SimpleExpr),
 }
 
 /// Defines an not set operation on an [ActiveValue]
@@ -79,6 +97,9 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// Set the Value into an ActiveModel
     fn set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: Value);
 
+    /// Set an SQL expression into an ActiveModel, see [ActiveValue::SetExpr]
+    fn set_expr(&mut self, c: <Self::Entity as EntityTrait>::Column, expr: SimpleExpr);
+
     /// Set the state of an [ActiveValue] to the not set state
     fn not_set(&mut self, c: <Self::Entity as EntityTrait>::Column);
 
@@ -101,6 +122,15 @@ pub trait ActiveModelTrait: Clone + Debug {
         self
     }
 
+    /// Check the values of the ActiveModel, see [ValidationErrors]. `insert`, `update` and
+    /// `save` call it after `before_save`.
+    ///
+    /// `DeriveActiveModel` implements it with the rules declared on the fields, followed by
+    /// [ActiveModelBehavior::validate_model]. There are no rules by default.
+    fn validate_values(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+
     /// Get the primary key of the ActiveModel
     ///
     /// # Panics
@@ -262,10 +292,15 @@ pub trait ActiveModelTrait: Clone + Debug {
         C: ConnectionTrait,
     {
         let am = ActiveModelBehavior::before_save(self, db, true).await?;
+        am.validate_values().map_err(DbErr::Validation)?;
         let model = <Self::Entity as EntityTrait>::insert(am)
             .exec_with_returning(db)
             .await?;
-        Self::after_save(model, db, true).await
+        let model = crate::record_version(model, false, db).await?;
+        let model = Self::after_save(model, db, true).await?;
+        db.on_transaction_end(transaction_hooks::<Self>(&model, true))
+                .await;
+        Ok(model)
     }
 
     /// Perform the `UPDATE` operation on an ActiveModel
@@ -384,8 +419,13 @@ pub trait ActiveModelTrait: Clone + Debug {
         C: ConnectionTrait,
     {
         let am = ActiveModelBehavior::before_save(self, db, false).await?;
+        am.validate_values().map_err(DbErr::Validation)?;
         let model: <Self::Entity as EntityTrait>::Model = Self::Entity::update(am).exec(db).await?;
-        Self::after_save(model, db, false).await
+        let model = crate::record_version(model, true, db).await?;
+        let model = Self::after_save(model, db, false).await?;
+        db.on_transaction_end(transaction_hooks::<Self>(&model, false))
+                .await;
+        Ok(model)
     }
 
     /// Insert the model if primary key is `NotSet`, update otherwise.
@@ -412,6 +452,172 @@ pub trait ActiveModelTrait: Clone + Debug {
         Ok(res.into_active_model())
     }
 
+    /// Save many models in one transaction: the models with a primary key `NotSet` are
+    /// inserted with one statement, or one statement each on backends without `RETURNING`,
+    /// and the others updated with another, see [`EntityTrait::update_batch`]. Returns the saved models in the order they were given.
+    ///
+    /// Fails with [DbErr::StaleObject], rolling back all of the changes, if the row of a model
+    /// with a [version column](crate::ColumnDef::version) was updated since it was read.
+    ///
+    /// # Example (Postgres)
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([
+    /// #         [cake::Model { id: 3, name: "Lemon Tart".to_owned() }],
+    /// #         [cake::Model { id: 1, name: "Apple Pie".to_owned() }],
+    /// #     ])
+    /// #     .append_exec_results([MockExecResult {
+    /// #         last_insert_id: 0,
+    /// #         rows_affected: 1,
+    /// #     }])
+    /// #     .into_connection();
+    /// #
+    /// let apple = cake::ActiveModel {
+    ///     id: Unchanged(1),
+    ///     name: Set("Apple Pie".to_owned()),
+    /// };
+    /// let lemon = cake::ActiveModel {
+    ///     id: NotSet,
+    ///     name: Set("Lemon Tart".to_owned()),
+    /// };
+    ///
+    /// let saved = cake::ActiveModel::save_many([apple, lemon], &db).await?;
+    /// assert_eq!(saved[0].id, Unchanged(1));
+    /// assert_eq!(saved[1].id, Unchanged(3));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::many([
+    ///         Statement::from_string(DbBackend::Postgres, "BEGIN"),
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"INSERT INTO "cake" ("name") VALUES ($1) RETURNING "id", "name""#,
+    ///             ["Lemon Tart".into()]
+    ///         ),
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"UPDATE "cake" SET "name" = (CASE WHEN ("cake"."id" = $1) THEN $2 ELSE "cake"."name" END) WHERE "cake"."id" IN ($3)"#,
+    ///             [1.into(), "Apple Pie".into(), 1.into()]
+    ///         ),
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN ($1)"#,
+    ///             [1.into()]
+    ///         ),
+    ///         Statement::from_string(DbBackend::Postgres, "COMMIT"),
+    ///     ])]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn save_many<'a, C, I>(models: I, db: &'a C) -> Result<Vec<Self>, DbErr>
+    where
+        <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
+        Self: ActiveModelBehavior + 'a,
+        C: ConnectionTrait + TransactionTrait,
+        I: IntoIterator<Item = Self> + Send,
+    {
+        type ValueType<A> = <<<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType;
+
+        let models: Vec<Self> = models.into_iter().collect();
+        let mut saved = Vec::with_capacity(models.len());
+        saved.resize_with(models.len(), || None);
+        let txn = db.begin().await?;
+
+        let (mut inserts, mut updates) = (Vec::new(), Vec::new());
+        for (idx, model) in models.into_iter().enumerate() {
+            let insert = <Self::Entity as EntityTrait>::PrimaryKey::iter()
+                .any(|key| model.is_not_set(key.into_column()));
+            let model = ActiveModelBehavior::before_save(model, &txn, insert).await?;
+            model.validate_values().map_err(DbErr::Validation)?;
+            if insert {
+                inserts.push((idx, model));
+            } else {
+                updates.push((idx, model));
+            }
+        }
+
+        if !inserts.is_empty() {
+            let (idxs, models): (Vec<_>, Vec<_>) = inserts.into_iter().unzip();
+            let inserted = if txn.support_returning() {
+                <Self::Entity as EntityTrait>::insert_many(models)
+                    .exec_with_returning_many(&txn)
+                    .await?
+            } else {
+                // without `RETURNING`, only the key of a single inserted row is known
+                let mut inserted = Vec::with_capacity(models.len());
+                for model in models {
+                    inserted.push(
+                        <Self::Entity as EntityTrait>::insert(model)
+                            .exec_with_returning(&txn)
+                            .await?,
+                    );
+                }
+                inserted
+            };
+            for (idx, model) in idxs.into_iter().zip(inserted) {
+                saved[idx] = Some((model, true));
+            }
+        }
+
+        if !updates.is_empty() {
+            let mut positions = HashMap::new();
+            let mut keys = Vec::with_capacity(updates.len());
+            for (idx, model) in &updates {
+                let key = model
+                    .get_primary_key_value()
+                    .ok_or(DbErr::UpdateGetPrimaryKey)?;
+                positions.insert(key.clone().into_iter().collect::<Vec<Value>>(), *idx);
+                keys.push(ValueType::<Self>::from_value_tuple(key));
+            }
+            let versioned = updates.iter().any(|(_, model)| {
+                <Self::Entity as EntityTrait>::Column::iter()
+                    .any(|col| col.def().is_version() && model.get(col).into_value().is_some())
+            });
+            let update = <Self::Entity as EntityTrait>::update_batch(
+                updates.into_iter().map(|(_, model)| model),
+            );
+            let noop = update.query.get_values().is_empty();
+            let res = update.exec(&txn).await?;
+            if versioned && !noop && (res.rows_affected as usize) < positions.len() {
+                return Err(DbErr::StaleObject);
+            }
+            let found = <Self::Entity as EntityTrait>::find_by_ids(keys)
+                .all(&txn)
+                .await?;
+            if found.len() < positions.len() {
+                return Err(DbErr::RecordNotUpdated);
+            }
+            for model in found {
+                let key: Vec<Value> = <Self::Entity as EntityTrait>::PrimaryKey::iter()
+                    .map(|key| model.get(key.into_column()))
+                    .collect();
+                if let Some(idx) = positions.get(&key) {
+                    saved[*idx] = Some((model, false));
+                }
+            }
+        }
+
+        let mut result = Vec::with_capacity(saved.len());
+        for (model, insert) in saved.into_iter().flatten() {
+            let model = crate::record_version(model, !insert, &txn).await?;
+            let model = Self::after_save(model, &txn, insert).await?;
+            txn.on_transaction_end(transaction_hooks::<Self>(&model, insert))
+                    .await;
+            result.push(model.into_active_model());
+        }
+        txn.commit().await?;
+        Ok(result)
+    }
+
     /// Delete an active model by its primary key
     ///
     /// # Example
@@ -463,10 +669,86 @@ pub trait ActiveModelTrait: Clone + Debug {
         let am = ActiveModelBehavior::before_delete(self, db).await?;
         let am_clone = am.clone();
         let delete_res = Self::Entity::delete(am).exec(db).await?;
+        let entity = <Self::Entity as Default>::default();
+        if entity.versioned() && delete_res.rows_affected > 0 {
+            let keys = <Self::Entity as EntityTrait>::PrimaryKey::iter()
+                .filter_map(|key| {
+                    let col = key.into_column();
+                    am_clone.get(col).into_value().map(|value| (col, value))
+                })
+                .collect();
+            crate::close_version(entity, keys, db).await?;
+        }
         ActiveModelBehavior::after_delete(am_clone, db).await?;
         Ok(delete_res)
     }
 
+    /// Like [`ActiveModelTrait::save`], but in a transaction begun on the connection. The hooks
+    /// of [`ActiveModelBehavior`] receive the transaction, so what they read and write in other
+    /// tables commits or rolls back together with the model.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model {
+    /// #         id: 15,
+    /// #         name: "Apple Pie".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// let apple = cake::ActiveModel {
+    ///     name: Set("Apple Pie".to_owned()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(apple.save_atomic(&db).await?.id, Unchanged(15));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::many([
+    ///         Statement::from_string(DbBackend::Postgres, "BEGIN"),
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"INSERT INTO "cake" ("name") VALUES ($1) RETURNING "id", "name""#,
+    ///             ["Apple Pie".into()]
+    ///         ),
+    ///         Statement::from_string(DbBackend::Postgres, "COMMIT"),
+    ///     ])]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn save_atomic<'a, C>(self, db: &'a C) -> Result<Self, DbErr>
+    where
+        <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
+        Self: ActiveModelBehavior + 'a,
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let txn = db.begin().await?;
+        let am = self.save(&txn).await?;
+        txn.commit().await?;
+        Ok(am)
+    }
+
+    /// Like [`ActiveModelTrait::delete`], but in a transaction begun on the connection, which
+    /// the hooks of [`ActiveModelBehavior`] receive
+    async fn delete_atomic<'a, C>(self, db: &'a C) -> Result<DeleteResult, DbErr>
+    where
+        Self: ActiveModelBehavior + 'a,
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let txn = db.begin().await?;
+        let res = self.delete(&txn).await?;
+        txn.commit().await?;
+        Ok(res)
+    }
+
     /// Set the corresponding attributes in the ActiveModel from a JSON value
     ///
     /// Note that this method will not alter the primary key values in ActiveModel.
@@ -492,6 +774,7 @@ pub trait ActiveModelTrait: Clone + Debug {
         for (col, active_value) in primary_key_values {
             match active_value {
                 ActiveValue::Unchanged(v) | ActiveValue::Set(v) => self.set(col, v),
+                ActiveValue::SetExpr(expr) => self.set_expr(col, expr),
                 NotSet => self.not_set(col),
             }
         }
@@ -499,6 +782,77 @@ pub trait ActiveModelTrait: Clone + Debug {
         Ok(())
     }
 
+    /// Set the attributes in the ActiveModel from the fields of a JSON object, applied with a
+    /// [JsonPolicy](crate::JsonPolicy). Unlike [`ActiveModelTrait::set_from_json`], the
+    /// attributes absent from the object keep their values.
+    ///
+    /// The object is deserialized into a Model together with the values of the other attributes,
+    /// so the fields the Model can't do without must be given unless the ActiveModel has them.
+    /// Like [`ActiveModelTrait::set_from_json`], this method will not alter the primary key
+    /// values in ActiveModel.
+    #[cfg(feature = "with-json")]
+    fn set_from_json_with(
+        &mut self,
+        json: serde_json::Value,
+        policy: &crate::JsonPolicy,
+    ) -> Result<(), DbErr>
+    where
+        <<Self as ActiveModelTrait>::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
+        for<'de> <<Self as ActiveModelTrait>::Entity as EntityTrait>::Model:
+            serde::de::Deserialize<'de>,
+    {
+        use crate::{Iden, Iterable};
+
+        let serde_json::Value::Object(patch) = json else {
+            return Err(json_err("expected a JSON object"));
+        };
+        let columns: Vec<(<Self::Entity as EntityTrait>::Column, String)> =
+            <<Self::Entity as EntityTrait>::Column>::iter()
+                .map(|col| (col, col.to_string()))
+                .collect();
+        if policy.denies_unknown_fields() {
+            if let Some(key) = patch
+                .keys()
+                .find(|key| !columns.iter().any(|(_, name)| name == *key))
+            {
+                return Err(json_err(format!("unknown field `{key}`")));
+            }
+        }
+
+        // Combine the current values with the patched ones, to deserialize a whole Model
+        let mut object = serde_json::Map::new();
+        let mut patched = Vec::new();
+        for (col, name) in columns {
+            let current = self
+                .get(col)
+                .into_value()
+                .map(|value| sea_query::sea_value_to_json_value(&value));
+            let value = match patch.get(&name) {
+                Some(value) => match policy.apply(current.clone(), value) {
+                    Some(value) => {
+                        patched.push(col);
+                        Some(value)
+                    }
+                    None => current,
+                },
+                None => current,
+            };
+            if let Some(value) = value {
+                object.insert(name, value);
+            }
+        }
+        let model: <Self::Entity as EntityTrait>::Model =
+            serde_json::from_value(serde_json::Value::Object(object)).map_err(json_err)?;
+
+        for col in patched {
+            if <Self::Entity as EntityTrait>::PrimaryKey::from_column(col).is_none() {
+                self.set(col, model.get(col));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Create ActiveModel from a JSON value
     #[cfg(feature = "with-json")]
     fn from_json(json: serde_json::Value) -> Result<Self, DbErr>
@@ -535,10 +889,16 @@ pub trait ActiveModelTrait: Clone + Debug {
         Ok(am)
     }
 
-    /// Return `true` if any attribute of `ActiveModel` is `Set`
+    /// Return `true` if any attribute of `ActiveModel` is `Set` or `SetExpr`
     fn is_changed(&self) -> bool {
         <Self::Entity as EntityTrait>::Column::iter()
-            .any(|col| self.get(col).is_set() && !self.get(col).is_unchanged())
+            .any(|col| self.get(col).is_set() || self.get(col).is_expr())
+    }
+
+    /// The columns whose values would change if the ActiveModel was saved over a model, see
+    /// [Changeset]. Columns that are `NotSet` are left out.
+    fn diff(&self, model: &<Self::Entity as EntityTrait>::Model) -> Changeset {
+        Changeset::from_active_model(self, model)
     }
 }
 
@@ -571,13 +931,17 @@ pub trait ActiveModelTrait: Clone + Debug {
 /// See module level docs [crate::entity] for a full example
 #[allow(unused_variables)]
 #[async_trait]
-pub trait ActiveModelBehavior: ActiveModelTrait {
+pub trait ActiveModelBehavior: ActiveModelTrait + 'static {
     /// Create a new ActiveModel with default values. Also used by `Default::default()`.
     fn new() -> Self {
         <Self as ActiveModelTrait>::default()
     }
 
-    /// Will be called before `ActiveModel::insert`, `ActiveModel::update`, and `ActiveModel::save`
+    /// Will be called before `ActiveModel::insert`, `ActiveModel::update`, and `ActiveModel::save`.
+    ///
+    /// `db` is the connection the operation runs on, so the hook can read and write other
+    /// tables with it. With [`ActiveModelTrait::save_atomic`] it is the transaction of the
+    /// operation.
     async fn before_save<C>(self, db: &C, insert: bool) -> Result<Self, DbErr>
     where
         C: ConnectionTrait,
@@ -585,6 +949,11 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
         Ok(self)
     }
 
+    /// Will be called by the [ActiveModelTrait::validate_values] of `DeriveActiveModel` after
+    /// the rules declared on the fields, to add the errors of checks that need code, like
+    /// comparing two fields
+    fn validate_model(&self, errors: &mut ValidationErrors) {}
+
     /// Will be called after `ActiveModel::insert`, `ActiveModel::update`, and `ActiveModel::save`
     async fn after_save<C>(
         model: <Self::Entity as EntityTrait>::Model,
@@ -597,6 +966,20 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
         Ok(model)
     }
 
+    /// Will be called after the transaction `ActiveModel::insert`, `ActiveModel::update`, or
+    /// `ActiveModel::save` ran in commits, with the model returned by `after_save`. Side effects
+    /// that must not happen if the transaction rolls back, like sending an email, belong here.
+    ///
+    /// A save outside a transaction commits on its own, so the hook is called right after it.
+    async fn after_commit(model: <Self::Entity as EntityTrait>::Model, insert: bool) {}
+
+    /// Will be called after the transaction `ActiveModel::insert`, `ActiveModel::update`, or
+    /// `ActiveModel::save` ran in rolls back, with the model returned by `after_save`.
+    ///
+    /// Not called if the transaction is dropped without a commit or rollback, see
+    /// [ConnectionTrait::on_transaction_end].
+    async fn after_rollback(model: <Self::Entity as EntityTrait>::Model, insert: bool) {}
+
     /// Will be called before `ActiveModel::delete`
     async fn before_delete<C>(self, db: &C) -> Result<Self, DbErr>
     where
@@ -614,6 +997,21 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
     }
 }
 
+/// The callback running the transaction hooks of an ActiveModel with a saved model
+fn transaction_hooks<A>(
+    model: &<A::Entity as EntityTrait>::Model,
+    insert: bool,
+) -> TransactionCallback
+where
+    A: ActiveModelBehavior + Send,
+{
+    let model = model.clone();
+    Box::new(move |outcome| match outcome {
+        TransactionOutcome::Committed => A::after_commit(model, insert),
+        TransactionOutcome::RolledBack => A::after_rollback(model, insert),
+    })
+}
+
 /// A Trait for any type that can be converted into an ActiveModel
 pub trait IntoActiveModel<A>
 where
@@ -786,12 +1184,25 @@ where
         matches!(self, Self::NotSet)
     }
 
+    /// Create an [ActiveValue::SetExpr]
+    pub fn expr<T>(expr: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        Self::SetExpr(expr.into())
+    }
+
+    /// Check if the [ActiveValue] is [ActiveValue::SetExpr]
+    pub fn is_expr(&self) -> bool {
+        matches!(self, Self::SetExpr(_))
+    }
+
     /// Get the mutable value an [ActiveValue]
     /// also setting itself to [ActiveValue::NotSet]
     pub fn take(&mut self) -> Option<V> {
         match std::mem::take(self) {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some(value),
-            ActiveValue::NotSet => None,
+            ActiveValue::NotSet | ActiveValue::SetExpr(_) => None,
         }
     }
 
@@ -799,11 +1210,12 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if it is [ActiveValue::NotSet]
+    /// Panics if it is [ActiveValue::NotSet] or [ActiveValue::SetExpr]
     pub fn unwrap(self) -> V {
         match self {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => value,
             ActiveValue::NotSet => panic!("Cannot unwrap ActiveValue::NotSet"),
+            ActiveValue::SetExpr(_) => panic!("Cannot unwrap ActiveValue::SetExpr"),
         }
     }
 
@@ -811,7 +1223,7 @@ where
     pub fn into_value(self) -> Option<Value> {
         match self {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some(value.into()),
-            ActiveValue::NotSet => None,
+            ActiveValue::NotSet | ActiveValue::SetExpr(_) => None,
         }
     }
 
@@ -821,15 +1233,16 @@ where
             Self::Set(value) => ActiveValue::set(value.into()),
             Self::Unchanged(value) => ActiveValue::unchanged(value.into()),
             Self::NotSet => ActiveValue::not_set(),
+            Self::SetExpr(expr) => ActiveValue::SetExpr(expr),
         }
     }
 
     /// Reset the value from [ActiveValue::Unchanged] to [ActiveValue::Set],
-    /// leaving [ActiveValue::NotSet] untouched.
+    /// leaving [ActiveValue::NotSet] and [ActiveValue::SetExpr] untouched.
     pub fn reset(&mut self) {
-        *self = match self.take() {
-            Some(value) => ActiveValue::Set(value),
-            None => ActiveValue::NotSet,
+        *self = match std::mem::take(self) {
+            ActiveValue::Set(value) | ActiveValue::Unchanged(value) => ActiveValue::Set(value),
+            other => other,
         };
     }
 
@@ -870,7 +1283,8 @@ where
         }
     }
 
-    /// Get the inner value, unless `self` is [NotSet][ActiveValue::NotSet].
+    /// Get the inner value, unless `self` is [NotSet][ActiveValue::NotSet] or
+    /// [SetExpr][ActiveValue::SetExpr].
     ///
     /// There's also a panicking version: [ActiveValue::as_ref].
     ///
@@ -886,7 +1300,7 @@ where
     pub fn try_as_ref(&self) -> Option<&V> {
         match self {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some(value),
-            ActiveValue::NotSet => None,
+            ActiveValue::NotSet | ActiveValue::SetExpr(_) => None,
         }
     }
 }
@@ -897,13 +1311,14 @@ where
 {
     /// # Panics
     ///
-    /// Panics if it is [ActiveValue::NotSet].
+    /// Panics if it is [ActiveValue::NotSet] or [ActiveValue::SetExpr].
     ///
     /// See [ActiveValue::try_as_ref] for a fallible non-panicking version.
     fn as_ref(&self) -> &V {
         match self {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => value,
             ActiveValue::NotSet => panic!("Cannot borrow ActiveValue::NotSet"),
+            ActiveValue::SetExpr(_) => panic!("Cannot borrow ActiveValue::SetExpr"),
         }
     }
 }
@@ -917,6 +1332,7 @@ where
             (ActiveValue::Set(l), ActiveValue::Set(r)) => l == r,
             (ActiveValue::Unchanged(l), ActiveValue::Unchanged(r)) => l == r,
             (ActiveValue::NotSet, ActiveValue::NotSet) => true,
+            (ActiveValue::SetExpr(l), ActiveValue::SetExpr(r)) => l == r,
             _ => false,
         }
     }
@@ -931,6 +1347,7 @@ where
             ActiveValue::Set(value) => ActiveValue::set(Some(value)),
             ActiveValue::Unchanged(value) => ActiveValue::unchanged(Some(value)),
             ActiveValue::NotSet => ActiveValue::not_set(),
+            ActiveValue::SetExpr(expr) => ActiveValue::SetExpr(expr),
         }
     }
 }
@@ -1355,6 +1772,109 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(all(feature = "with-json", feature = "macros"))]
+    fn test_active_model_set_from_json_with() -> Result<(), DbErr> {
+        use crate::JsonPolicy;
+
+        mod profile {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+            use serde::Deserialize;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize)]
+            #[sea_orm(table_name = "profile")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub name: String,
+                pub bio: Option<String>,
+                pub settings: Json,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let mut profile = profile::Model {
+            id: 1,
+            name: "Alice".to_owned(),
+            bio: Some("Hello".to_owned()),
+            settings: json!({ "theme": "dark", "lang": "en", "editor": { "tabs": 4 } }),
+        }
+        .into_active_model();
+
+        profile.set_from_json_with(
+            json!({ "id": 2, "bio": null, "unknown": true }),
+            &JsonPolicy::new(),
+        )?;
+        assert_eq!(profile.id, ActiveValue::Unchanged(1));
+        assert_eq!(profile.name, ActiveValue::Unchanged("Alice".to_owned()));
+        assert_eq!(profile.bio, ActiveValue::Set(None));
+
+        let policy = JsonPolicy::new().merge_patch();
+        profile.set_from_json_with(
+            json!({
+                "bio": "Hi",
+                "settings": { "lang": null, "editor": { "wrap": true } },
+            }),
+            &policy,
+        )?;
+        assert_eq!(profile.bio, ActiveValue::Set(Some("Hi".to_owned())));
+        assert_eq!(
+            profile.settings,
+            ActiveValue::Set(json!({ "theme": "dark", "editor": { "tabs": 4, "wrap": true } }))
+        );
+
+        profile.set_from_json_with(json!({ "settings": null }), &policy)?;
+        assert_eq!(profile.settings, ActiveValue::Set(serde_json::Value::Null));
+
+        assert_eq!(
+            profile.set_from_json_with(json!([]), &policy),
+            Err(DbErr::Json("expected a JSON object".to_owned()))
+        );
+        assert_eq!(
+            profile.set_from_json_with(
+                json!({ "unknown": true }),
+                &policy.clone().deny_unknown_fields()
+            ),
+            Err(DbErr::Json("unknown field `unknown`".to_owned()))
+        );
+
+        let mut profile = profile::ActiveModel {
+            id: ActiveValue::Set(3),
+            ..Default::default()
+        };
+        profile.set_from_json_with(
+            json!({ "name": "Bob", "settings": { "theme": "light" } }),
+            &policy.null_as_not_set(),
+        )?;
+        assert_eq!(profile.name, ActiveValue::Set("Bob".to_owned()));
+        assert_eq!(profile.bio, ActiveValue::NotSet);
+        assert_eq!(
+            profile.settings,
+            ActiveValue::Set(json!({ "theme": "light" }))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chainable_setters() {
+        let fruit = fruit::ActiveModel::new().name("Apple").cake_id(1);
+        assert_eq!(
+            fruit,
+            fruit::ActiveModel {
+                id: ActiveValue::NotSet,
+                name: ActiveValue::Set("Apple".to_owned()),
+                cake_id: ActiveValue::Set(Some(1)),
+            }
+        );
+        assert_eq!(fruit.cake_id(None).cake_id, ActiveValue::Set(None::<i32>));
+    }
+
     #[test]
     fn test_active_model_is_changed() {
         let mut fruit: fruit::ActiveModel = Default::default();
@@ -1425,6 +1945,112 @@ mod tests {
         );
     }
 
+    #[smol_potat::test]
+    #[cfg(feature = "macros")]
+    async fn test_after_commit_and_rollback() -> Result<(), DbErr> {
+        use crate::*;
+
+        mod post {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+            use std::sync::Mutex;
+
+            pub static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "post")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub title: String,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            #[async_trait::async_trait]
+            impl ActiveModelBehavior for ActiveModel {
+                async fn after_commit(model: Model, insert: bool) {
+                    let event = format!("commit {} {insert}", model.title);
+                    EVENTS.lock().unwrap().push(event);
+                }
+
+                async fn after_rollback(model: Model, insert: bool) {
+                    let event = format!("rollback {} {insert}", model.title);
+                    EVENTS.lock().unwrap().push(event);
+                }
+            }
+        }
+
+        let post = |id: i32, title: &str| post::Model {
+            id,
+            title: title.to_owned(),
+        };
+        let events = || std::mem::take(&mut *post::EVENTS.lock().unwrap());
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                [post(1, "first")],
+                [post(2, "second")],
+                [post(3, "third")],
+                [post(4, "fourth")],
+                [post(5, "fifth")],
+            ])
+            .into_connection();
+
+        let txn = db.begin().await?;
+        post::ActiveModel {
+            title: Set("first".to_owned()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        assert!(events().is_empty());
+        txn.commit().await?;
+        assert_eq!(events(), ["commit first true"]);
+
+        let txn = db.begin().await?;
+        post(2, "second")
+            .into_active_model()
+            .reset_all()
+            .update(&txn)
+            .await?;
+        txn.rollback().await?;
+        assert_eq!(events(), ["rollback second false"]);
+
+        post::ActiveModel {
+            title: Set("third".to_owned()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+        assert_eq!(events(), ["commit third true"]);
+
+        let txn = db.begin().await?;
+        let nested = txn.begin().await?;
+        post::ActiveModel {
+            title: Set("fourth".to_owned()),
+            ..Default::default()
+        }
+        .insert(&nested)
+        .await?;
+        nested.commit().await?;
+        assert!(events().is_empty());
+        txn.commit().await?;
+        assert_eq!(events(), ["commit fourth true"]);
+
+        let txn = db.begin().await?;
+        post::ActiveModel {
+            title: Set("fifth".to_owned()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        drop(txn);
+        assert!(events().is_empty());
+
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn test_reset_2() -> Result<(), DbErr> {
         use crate::*;
@@ -1,9 +1,13 @@
 use crate::{
-    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne,
-    FromQueryResult, Insert, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related,
-    RelationBuilder, RelationTrait, RelationType, Select, Update, UpdateMany, UpdateOne,
+    ActiveModelBehavior, ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend, Delete, DeleteMany,
+    DeleteOne, FromQueryResult, Insert, IntoSimpleExpr, ModelTrait, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryFilter, Related, RelationBuilder, RelationTrait, RelationType, Select,
+    SelectRecursive, Truncate, Update, UpdateMany, UpdateOne,
+};
+use sea_query::{
+    Alias, CaseStatement, Condition, Expr, FromValueTuple, Iden, IntoIden, IntoTableRef,
+    IntoValueTuple, TableRef, Value, ValueTuple,
 };
-use sea_query::{Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
 use std::fmt::Debug;
 pub use strum::IntoEnumIterator as Iterable;
 
@@ -28,6 +32,97 @@ pub trait EntityName: IdenStatic + Default {
     /// Get the name of the table
     fn table_name(&self) -> &str;
 
+    /// Method to get the column scoping rows to a tenant, defaults to [Option::None] if not set.
+    /// See [TenantContext](crate::TenantContext)
+    fn tenant_key(&self) -> Option<&str> {
+        None
+    }
+
+    /// Method to get the filter applied to every select of the entity, defaults to [Option::None]
+    /// if not set.
+    ///
+    /// Set with `#[sea_orm(default_scope = "path::to::fn")]`, naming a function returning a
+    /// condition. The scope is applied by [EntityTrait::find], the selects of related models and
+    /// loaders, and joins to the entity. Select without it with [EntityTrait::unscoped].
+    fn default_scope(&self) -> Option<Condition> {
+        None
+    }
+
+    /// Whether the history of the rows is kept, defaults to `false`.
+    ///
+    /// Set with `#[sea_orm(versioned)]`. Every version of a row written by
+    /// [ActiveModelTrait::insert], [ActiveModelTrait::update] and [ActiveModelTrait::delete] is
+    /// kept in `<table>_history`, created with
+    /// [Schema::create_history_table_from_entity](crate::Schema::create_history_table_from_entity),
+    /// and read with [Select::as_of]. `update_many` and `delete_many` are not recorded. Write in a
+    /// transaction to keep the history consistent with the table.
+    ///
+    /// ```
+    /// # use sea_orm::{entity::*, error::*, *};
+    /// # mod post {
+    /// #     use sea_orm::entity::prelude::*;
+    /// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    /// #     #[sea_orm(table_name = "post", versioned)]
+    /// #     pub struct Model {
+    /// #         #[sea_orm(primary_key)]
+    /// #         pub id: i32,
+    /// #         pub title: String,
+    /// #     }
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[post::Model {
+    /// #         id: 1,
+    /// #         title: "Hello, World!".to_owned(),
+    /// #     }]])
+    /// #     .append_exec_results([
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 1 },
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 1 },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// post::ActiveModel {
+    ///     id: Unchanged(1),
+    ///     title: Set("Hello, World!".to_owned()),
+    /// }
+    /// .update(&db)
+    /// .await?;
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"UPDATE "post" SET "title" = $1 WHERE "post"."id" = $2 RETURNING "id", "title""#,
+    ///             ["Hello, World!".into(), 1i32.into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"UPDATE "post_history" SET "valid_to" = CURRENT_TIMESTAMP WHERE "valid_to" IS NULL AND "id" = $1"#,
+    ///             [1i32.into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"INSERT INTO "post_history" ("id", "title", "valid_from") VALUES ($1, $2, CURRENT_TIMESTAMP)"#,
+    ///             [1i32.into(), "Hello, World!".into()]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn versioned(&self) -> bool {
+        false
+    }
+
     /// Get the name of the module from the invoking `self.table_name()`
     fn module_name(&self) -> &str {
         self.table_name()
@@ -177,6 +272,112 @@ pub trait EntityTrait: EntityName {
         Select::new()
     }
 
+    /// Construct select statement like [Self::find], without the
+    /// [default scope](EntityName::default_scope) of the entity, and including the expired rows
+    /// of an entity with an [expiry column](crate::ExpirySweeper)
+    fn unscoped() -> Select<Self> {
+        Select::new_unscoped()
+    }
+
+    /// Construct select statement like [Self::find], including the soft deleted rows.
+    ///
+    /// An entity with a column marked `#[sea_orm(soft_delete)]`, holding the time a row was
+    /// deleted at, is soft deleted: [Self::delete], [Self::delete_many] and the like set the
+    /// column to `CURRENT_TIMESTAMP` instead of deleting the rows, and the selects of the entity,
+    /// of related models, loaders and joins to it only return the rows where it is `NULL`.
+    /// Select the deleted rows with [Self::only_deleted], and undelete them with
+    /// [Self::restore]. A [Deleter](crate::Deleter) deletes the rows for good.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, *};
+    /// # mod post {
+    /// #     use sea_orm::entity::prelude::*;
+    /// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    /// #     #[sea_orm(table_name = "post")]
+    /// #     pub struct Model {
+    /// #         #[sea_orm(primary_key)]
+    /// #         pub id: i32,
+    /// #         pub title: String,
+    /// #         #[sea_orm(soft_delete)]
+    /// #         pub deleted_at: Option<DateTimeUtc>,
+    /// #     }
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 1 },
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 1 },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// assert_eq!(
+    ///     post::Entity::find().build(DbBackend::Postgres).to_string(),
+    ///     r#"SELECT "post"."id", "post"."title", "post"."deleted_at" FROM "post" WHERE "post"."deleted_at" IS NULL"#
+    /// );
+    /// assert_eq!(
+    ///     post::Entity::with_deleted().build(DbBackend::Postgres).to_string(),
+    ///     r#"SELECT "post"."id", "post"."title", "post"."deleted_at" FROM "post""#
+    /// );
+    /// assert_eq!(
+    ///     post::Entity::only_deleted().build(DbBackend::Postgres).to_string(),
+    ///     r#"SELECT "post"."id", "post"."title", "post"."deleted_at" FROM "post" WHERE "post"."deleted_at" IS NOT NULL"#
+    /// );
+    ///
+    /// post::Entity::delete_by_id(1).exec(&db).await?;
+    /// post::Entity::restore()
+    ///     .filter(post::Column::Id.eq(1))
+    ///     .exec(&db)
+    ///     .await?;
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"UPDATE "post" SET "deleted_at" = CURRENT_TIMESTAMP WHERE ("post"."id" = $1) AND "post"."deleted_at" IS NULL"#,
+    ///             [1.into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"UPDATE "post" SET "deleted_at" = NULL WHERE "post"."deleted_at" IS NOT NULL AND "post"."id" = $1"#,
+    ///             [1.into()]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_deleted() -> Select<Self> {
+        Select::new_with_deleted()
+    }
+
+    /// Construct select statement like [Self::find], only selecting the soft deleted rows, see
+    /// [Self::with_deleted]. It fails to execute if the entity has no soft delete column.
+    fn only_deleted() -> Select<Self> {
+        Select::new_with_deleted()
+            .refuse(crate::soft_delete_refusal::<Self>())
+            .filter(crate::deleted_condition::<Self>())
+    }
+
+    /// Undelete the soft deleted rows, see [Self::with_deleted]. Add filters to restore only
+    /// some of them. It fails to execute if the entity has no soft delete column.
+    fn restore() -> UpdateMany<Self> {
+        let (col, value) = crate::restore_value::<Self>();
+        let mut restore = Self::update_many()
+            .col_expr(col, value)
+            .filter(crate::deleted_condition::<Self>());
+        restore.refusal = restore.refusal.or(crate::soft_delete_refusal::<Self>());
+        restore
+    }
+
     /// Find a model by primary key
     ///
     /// # Example
@@ -288,6 +489,136 @@ pub trait EntityTrait: EntityName {
         select
     }
 
+    /// Find models by a list of primary keys, with `IN` on the primary key column, or a row-value
+    /// `(a, b) IN ((..), (..))` for a composite primary key. An empty list matches no rows.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, cake_filling}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find_by_ids([1, 2, 3])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN (1, 2, 3)"#
+    /// );
+    /// assert_eq!(
+    ///     cake_filling::Entity::find_by_ids([(2, 3), (4, 5)])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+    ///         r#"WHERE ("cake_filling"."cake_id", "cake_filling"."filling_id") IN ((2, 3), (4, 5))"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if arity of input values don't match arity of primary key
+    fn find_by_ids<I, T>(values: I) -> Select<Self>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    {
+        Self::find().filter(ids_condition::<Self, _, _>(values, true))
+    }
+
+    /// Like [`EntityTrait::find_by_ids`], but for a composite primary key only uses a row-value
+    /// `IN` on the backends reading it through the primary key index, Postgres and SQLite, and
+    /// an `OR` of the keys on MySQL.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake_filling, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake_filling::Entity::find_by_ids_for(DbBackend::MySql, [(2, 3), (4, 5)])
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `cake_filling`.`cake_id`, `cake_filling`.`filling_id` FROM `cake_filling`",
+    ///         "WHERE (`cake_filling`.`cake_id` = 2 AND `cake_filling`.`filling_id` = 3)",
+    ///         "OR (`cake_filling`.`cake_id` = 4 AND `cake_filling`.`filling_id` = 5)",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if arity of input values don't match arity of primary key
+    fn find_by_ids_for<I, T>(backend: DbBackend, values: I) -> Select<Self>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    {
+        let row_value = backend != DbBackend::MySql;
+        Self::find().filter(ids_condition::<Self, _, _>(values, row_value))
+    }
+
+    /// Find a row and its descendants in a table linking each row to its parent in `parent_col`,
+    /// with a `WITH RECURSIVE` query. The rows come with their depth below the row, from 0 for
+    /// the row itself.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, DbBackend};
+    /// # mod category {
+    /// #     use sea_orm::entity::prelude::*;
+    /// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    /// #     #[sea_orm(table_name = "category")]
+    /// #     pub struct Model {
+    /// #         #[sea_orm(primary_key)]
+    /// #         pub id: i32,
+    /// #         pub parent_id: Option<i32>,
+    /// #     }
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    ///
+    /// assert_eq!(
+    ///     category::Entity::find_descendants(1, category::Column::ParentId)
+    ///         .max_depth(3)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"WITH RECURSIVE "tree" ("id", "parent_id", "depth") AS"#,
+    ///         r#"(SELECT "category"."id", "category"."parent_id", 0 AS "depth" FROM "category""#,
+    ///         r#"WHERE "category"."id" = 1"#,
+    ///         r#"UNION ALL (SELECT "category"."id", "category"."parent_id", "tree"."depth" + 1 AS "depth""#,
+    ///         r#"FROM "category" INNER JOIN "tree" ON "category"."parent_id" = "tree"."id""#,
+    ///         r#"WHERE "tree"."depth" < 3))"#,
+    ///         r#"SELECT * FROM "tree" ORDER BY "depth" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    ///
+    /// Only for a primary key of a single column, whose value converts into a [Value].
+    ///
+    /// ```compile_fail
+    /// # use sea_orm::{tests_cfg::*, *};
+    /// cake_filling::Entity::find_descendants((1, 2), cake_filling::Column::CakeId);
+    /// ```
+    fn find_descendants<T>(id: T, parent_col: Self::Column) -> SelectRecursive<Self>
+    where
+        T: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+        <Self::PrimaryKey as PrimaryKeyTrait>::ValueType: Into<Value>,
+    {
+        SelectRecursive::descendants(id, parent_col)
+    }
+
+    /// Find a row and its ancestors in a table linking each row to its parent in `parent_col`,
+    /// with a `WITH RECURSIVE` query, like [Self::find_descendants]. The rows come with their
+    /// depth above the row, from 0 for the row itself up to the root.
+    fn find_ancestors<T>(id: T, parent_col: Self::Column) -> SelectRecursive<Self>
+    where
+        T: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+        <Self::PrimaryKey as PrimaryKeyTrait>::ValueType: Into<Value>,
+    {
+        SelectRecursive::ancestors(id, parent_col)
+    }
+
     /// Insert a model into database
     ///
     /// # Example (Postgres)
@@ -796,6 +1127,141 @@ pub trait EntityTrait: EntityName {
         Update::many(Self::default())
     }
 
+    /// Update many rows with different values in one statement, from active models with their
+    /// primary keys. Each column set to a value or an expression in any of the models is updated
+    /// with a `CASE` on the primary key; the rows of the models where it is not set keep their
+    /// value. The
+    /// [`updated_at`](crate::TimestampClock) columns of the rows of models setting any value are
+    /// stamped, unless the model sets them too.
+    ///
+    /// Like [EntityTrait::update], the rows of a model with a
+    /// [version column](crate::ColumnDef::version) are only updated if they still have the
+    /// version of the model, and the version is incremented. Fewer rows are then affected than
+    /// models were given if some are stale.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// let apple = fruit::ActiveModel {
+    ///     id: Unchanged(1),
+    ///     name: Set("Apple".to_owned()),
+    ///     ..Default::default()
+    /// };
+    /// let pear = fruit::ActiveModel {
+    ///     id: Unchanged(2),
+    ///     name: Set("Pear".to_owned()),
+    ///     cake_id: Set(Some(3)),
+    /// };
+    /// assert_eq!(
+    ///     fruit::Entity::update_batch([apple, pear])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"UPDATE "fruit" SET"#,
+    ///         r#""name" = (CASE WHEN ("fruit"."id" = 1) THEN 'Apple' WHEN ("fruit"."id" = 2) THEN 'Pear' ELSE "fruit"."name" END),"#,
+    ///         r#""cake_id" = (CASE WHEN ("fruit"."id" = 2) THEN 3 ELSE "fruit"."cake_id" END)"#,
+    ///         r#"WHERE "fruit"."id" IN (1, 2)"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the primary key of a model is not set
+    fn update_batch<A, I>(models: I) -> UpdateMany<Self>
+    where
+        A: ActiveModelTrait<Entity = Self>,
+        I: IntoIterator<Item = A>,
+    {
+        let models: Vec<A> = models.into_iter().collect();
+        let keys: Vec<ValueTuple> = models
+            .iter()
+            .map(|model| {
+                model
+                    .get_primary_key_value()
+                    .expect("primary key of the model is not set")
+            })
+            .collect();
+        let key_condition = |key: &ValueTuple| {
+            Self::PrimaryKey::iter()
+                .zip(key.clone())
+                .fold(Condition::all(), |when, (pk, value)| {
+                    when.add(pk.into_column().eq(value))
+                })
+        };
+        let updated = |col: Self::Column| {
+            Self::PrimaryKey::from_column(col).is_none() && !col.def().is_version()
+        };
+        // like `Update::one`, the `updated_at` columns of the rows that are updated are stamped
+        // unless the model sets them
+        let changed: Vec<bool> = models
+            .iter()
+            .map(|model| {
+                Self::Column::iter().any(|col| {
+                    updated(col) && (model.get(col).is_set() || model.get(col).is_expr())
+                })
+            })
+            .collect();
+        let mut update = Self::update_many();
+        for col in Self::Column::iter().filter(|col| updated(*col)) {
+            let stamp = crate::auto_timestamp(col, false);
+            let mut case: Option<CaseStatement> = None;
+            for ((model, key), changed) in models.iter().zip(&keys).zip(&changed) {
+                let then = match (model.get(col), &stamp) {
+                    (ActiveValue::Set(value), _) => col.save_as(Expr::val(value)),
+                    (ActiveValue::SetExpr(expr), _) => expr,
+                    (_, Some(stamp)) if *changed => stamp.clone(),
+                    _ => continue,
+                };
+                let when = key_condition(key);
+                case = Some(match case {
+                    Some(case) => case.case(when, then),
+                    None => Expr::case(when, then),
+                });
+            }
+            if let Some(case) = case {
+                let otherwise = Expr::col((Self::default(), col));
+                update = update.col_expr(col, case.finally(otherwise).into());
+            }
+        }
+
+        // Like `Update::one`, only update the rows whose version column still has the version
+        // of their model, and increment it
+        let versions: Vec<Self::Column> = Self::Column::iter()
+            .filter(|col| col.def().is_version())
+            .collect();
+        let versioned = !versions.is_empty()
+            && !update.query.get_values().is_empty()
+            && models.iter().any(|model| {
+                versions
+                    .iter()
+                    .any(|col| model.get(*col).into_value().is_some())
+            });
+        if !versioned {
+            let keys = keys
+                .into_iter()
+                .map(<Self::PrimaryKey as PrimaryKeyTrait>::ValueType::from_value_tuple);
+            return update.filter(ids_condition::<Self, _, _>(keys, true));
+        }
+        for col in &versions {
+            update = update.col_expr(*col, Expr::expr(col.into_expr()).add(1));
+        }
+        let rows = models
+            .iter()
+            .zip(&keys)
+            .fold(Condition::any(), |rows, (model, key)| {
+                let row = versions.iter().fold(key_condition(key), |row, col| {
+                    match model.get(*col).into_value() {
+                        Some(version) => row.add(col.eq(version)),
+                        None => row,
+                    }
+                });
+                rows.add(row)
+            });
+        update.filter(rows)
+    }
+
     /// Delete a model from database
     ///
     /// - To apply where conditions / filters, see [`QueryFilter`](crate::query::QueryFilter)
@@ -901,6 +1367,34 @@ pub trait EntityTrait: EntityName {
         Delete::many(Self::default())
     }
 
+    /// Remove all rows of the table, faster than a [`EntityTrait::delete_many`] without a
+    /// filter: `TRUNCATE TABLE` on Postgres and MySQL, and `DELETE FROM` on SQLite
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let truncate = cake::Entity::truncate().restart_identity();
+    ///
+    /// assert_eq!(
+    ///     truncate.build(DbBackend::Postgres)[0].to_string(),
+    ///     r#"TRUNCATE TABLE "cake" RESTART IDENTITY"#
+    /// );
+    /// assert_eq!(
+    ///     truncate
+    ///         .build(DbBackend::Sqlite)
+    ///         .iter()
+    ///         .map(ToString::to_string)
+    ///         .collect::<Vec<_>>(),
+    ///     [
+    ///         r#"DELETE FROM "cake""#,
+    ///         r#"DELETE FROM "sqlite_sequence" WHERE "name" = 'cake'"#,
+    ///     ]
+    /// );
+    /// ```
+    fn truncate() -> Truncate<Self> {
+        Truncate::new()
+    }
+
     /// Delete a model based on primary key
     ///
     /// # Example
@@ -999,6 +1493,49 @@ pub trait EntityTrait: EntityName {
     }
 }
 
+/// Match rows by a list of primary keys, with a row-value `IN` or an `OR` of the keys
+/// for a composite primary key
+fn ids_condition<E, I, T>(values: I, row_value: bool) -> Condition
+where
+    E: EntityTrait,
+    I: IntoIterator<Item = T>,
+    T: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+{
+    let columns: Vec<E::Column> = E::PrimaryKey::iter().map(|key| key.into_column()).collect();
+    let keys: Vec<Vec<Value>> = values
+        .into_iter()
+        .map(|value| {
+            let key: Vec<Value> = value.into().into_value_tuple().into_iter().collect();
+            if key.len() != columns.len() {
+                panic!("primary key arity mismatch");
+            }
+            key
+        })
+        .collect();
+    if let [column] = columns.as_slice() {
+        let values = keys.into_iter().flatten();
+        return Condition::all().add(column.is_in(values));
+    }
+    if row_value {
+        return Condition::all().add(
+            Expr::tuple(columns.iter().map(|c| c.into_simple_expr()))
+                .in_tuples(keys.into_iter().map(ValueTuple::Many)),
+        );
+    }
+    if keys.is_empty() {
+        return Condition::all().add(Expr::val(1).eq(2));
+    }
+    keys.into_iter().fold(Condition::any(), |condition, key| {
+        let key = columns
+            .iter()
+            .zip(key)
+            .fold(Condition::all(), |key, (column, value)| {
+                key.add(column.eq(value))
+            });
+        condition.add(key)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -1025,6 +1562,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_by_ids() {
+        use crate::tests_cfg::{cake, cake_filling};
+        use crate::{entity::*, query::*, DbBackend};
+        assert_eq!(
+            cake::Entity::find_by_ids(Vec::<i32>::new())
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE 1 = 2"#,
+        );
+        assert_eq!(
+            cake_filling::Entity::find_by_ids_for(DbBackend::Sqlite, [(1, 2)])
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling" WHERE ("cake_filling"."cake_id", "cake_filling"."filling_id") IN ((1, 2))"#,
+        );
+        assert_eq!(
+            cake_filling::Entity::find_by_ids_for(DbBackend::MySql, Vec::<(i32, i32)>::new())
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `cake_filling`.`cake_id`, `cake_filling`.`filling_id` FROM `cake_filling` WHERE 1 = 2",
+        );
+    }
+
     #[test]
     #[cfg(feature = "macros")]
     fn entity_model_1() {
@@ -0,0 +1,138 @@
+#[cfg(feature = "with-json")]
+use crate::JsonValue;
+use crate::{ActiveModelTrait, EntityTrait, IdenStatic, Iterable, ModelTrait, Value};
+#[cfg(feature = "with-json")]
+use serde::{Serialize, Serializer};
+
+/// The change of the value of a column
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    /// The name of the column
+    pub column: String,
+    /// The value before the change
+    pub old: Value,
+    /// The value after the change
+    pub new: Value,
+}
+
+/// The columns whose values differ between two versions of a model, in the order of the
+/// columns, see [ModelTrait::diff] and [ActiveModelTrait::diff].
+///
+/// With the `with-json` feature it serializes to a JSON object of the columns, each with its
+/// `old` and `new` value.
+///
+/// ```
+/// # use sea_orm::{entity::*, tests_cfg::cake};
+/// let old = cake::Model {
+///     id: 1,
+///     name: "Cheese Cake".to_owned(),
+/// };
+/// let new = cake::Model {
+///     id: 1,
+///     name: "Chocolate Cake".to_owned(),
+/// };
+///
+/// let changeset = new.diff(&old);
+/// assert_eq!(changeset.len(), 1);
+/// assert_eq!(changeset.get("name").unwrap().old, "Cheese Cake".into());
+/// assert_eq!(
+///     changeset.to_json(),
+///     serde_json::json!({ "name": { "old": "Cheese Cake", "new": "Chocolate Cake" } })
+/// );
+///
+/// let mut cake = old.clone().into_active_model();
+/// cake.name = Set("Cheese Cake".to_owned());
+/// assert!(cake.diff(&old).is_empty());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Changeset {
+    changes: Vec<Change>,
+}
+
+impl Changeset {
+    /// Compare the values of the columns of two models
+    pub(crate) fn from_models<M>(new: &M, old: &M) -> Self
+    where
+        M: ModelTrait,
+    {
+        let changes = <M::Entity as EntityTrait>::Column::iter()
+            .filter_map(|col| change(col, old.get(col), new.get(col)))
+            .collect();
+        Self { changes }
+    }
+
+    /// Compare the values of the columns of an ActiveModel with a model. Only the values that
+    /// are `Set` or `Unchanged` are compared.
+    pub(crate) fn from_active_model<A>(new: &A, old: &<A::Entity as EntityTrait>::Model) -> Self
+    where
+        A: ActiveModelTrait,
+    {
+        let changes = <A::Entity as EntityTrait>::Column::iter()
+            .filter_map(|col| change(col, old.get(col), new.get(col).into_value()?))
+            .collect();
+        Self { changes }
+    }
+
+    /// Whether no column changed
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The number of columns that changed
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// The change of a column, by its name
+    pub fn get(&self, column: &str) -> Option<&Change> {
+        self.changes.iter().find(|change| change.column == column)
+    }
+
+    /// The changes, in the order of the columns
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// The changes as a JSON object of the columns, each with its `old` and `new` value
+    #[cfg(feature = "with-json")]
+    pub fn to_json(&self) -> JsonValue {
+        let changes = self.changes.iter().map(|change| {
+            let value = serde_json::json!({
+                "old": sea_query::sea_value_to_json_value(&change.old),
+                "new": sea_query::sea_value_to_json_value(&change.new),
+            });
+            (change.column.clone(), value)
+        });
+        JsonValue::Object(changes.collect())
+    }
+}
+
+impl IntoIterator for Changeset {
+    type Item = Change;
+    type IntoIter = std::vec::IntoIter<Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+#[cfg(feature = "with-json")]
+impl Serialize for Changeset {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_json().serialize(serializer)
+    }
+}
+
+fn change<C>(col: C, old: Value, new: Value) -> Option<Change>
+where
+    C: IdenStatic,
+{
+    (old != new).then(|| Change {
+        column: col.as_str().to_owned(),
+        old,
+        new,
+    })
+}
@@ -1,4 +1,4 @@
-use crate::{DbBackend, EntityName, Iden, IdenStatic, IntoSimpleExpr, Iterable};
+use crate::{DbBackend, EntityName, Iden, IdenStatic, IntoSimpleExpr, Iterable, TimestampClock};
 use sea_query::{
     Alias, BinOper, DynIden, Expr, IntoIden, SeaRc, SelectStatement, SimpleExpr, Value,
 };
@@ -17,6 +17,12 @@ pub struct ColumnDef {
     pub(crate) indexed: bool,
     pub(crate) default: Option<SimpleExpr>,
     pub(crate) comment: Option<String>,
+    pub(crate) expires_at: bool,
+    pub(crate) soft_delete: bool,
+    pub(crate) masked: Option<Vec<String>>,
+    pub(crate) created_at: Option<TimestampClock>,
+    pub(crate) updated_at: Option<TimestampClock>,
+    pub(crate) version: bool,
 }
 
 macro_rules! bind_oper {
@@ -316,6 +322,12 @@ impl ColumnTypeTrait for ColumnType {
             indexed: false,
             default: None,
             comment: None,
+            expires_at: false,
+            soft_delete: false,
+            masked: None,
+            created_at: None,
+            updated_at: None,
+            version: false,
         }
     }
 
@@ -371,6 +383,52 @@ impl ColumnDef {
         self
     }
 
+    /// Mark the column as the time the row expires at, see [ExpirySweeper](crate::ExpirySweeper)
+    pub fn expires_at(mut self) -> Self {
+        self.expires_at = true;
+        self
+    }
+
+    /// Mark the column as the time the row was soft deleted at, see [EntityTrait::with_deleted](crate::EntityTrait::with_deleted)
+    pub fn soft_delete(mut self) -> Self {
+        self.soft_delete = true;
+        self
+    }
+
+    /// Set the column to the current time when a row is inserted, see [TimestampClock]
+    pub fn created_at(mut self, clock: TimestampClock) -> Self {
+        self.created_at = Some(clock);
+        self
+    }
+
+    /// Set the column to the current time when a row is inserted or updated, see [TimestampClock]
+    pub fn updated_at(mut self, clock: TimestampClock) -> Self {
+        self.updated_at = Some(clock);
+        self
+    }
+
+    /// Mark the column as the version of the row for optimistic locking. Updating a model checks
+    /// that the row still has the version of the model and increments it, failing with
+    /// [DbErr::StaleObject](crate::DbErr::StaleObject) if it does not.
+    pub fn version(mut self) -> Self {
+        self.version = true;
+        self
+    }
+
+    /// Mask the values of the column, see [UnmaskContext](crate::UnmaskContext)
+    pub fn masked(mut self) -> Self {
+        self.masked.get_or_insert_with(Vec::new);
+        self
+    }
+
+    /// Mask the values of the column, unless unmasked for a role
+    pub fn masked_unless(mut self, role: &str) -> Self {
+        self.masked
+            .get_or_insert_with(Vec::new)
+            .push(role.to_owned());
+        self
+    }
+
     /// Set the default value
     pub fn default_value<T>(mut self, value: T) -> Self
     where
@@ -408,6 +466,41 @@ impl ColumnDef {
     pub fn is_unique(&self) -> bool {
         self.unique
     }
+
+    /// Returns true if the column is the time the row expires at
+    pub fn is_expires_at(&self) -> bool {
+        self.expires_at
+    }
+
+    /// Returns true if the column is the time the row was soft deleted at
+    pub fn is_soft_delete(&self) -> bool {
+        self.soft_delete
+    }
+
+    /// Get the clock of the column if it is set when a row is inserted
+    pub fn get_created_at(&self) -> Option<TimestampClock> {
+        self.created_at
+    }
+
+    /// Get the clock of the column if it is set when a row is inserted or updated
+    pub fn get_updated_at(&self) -> Option<TimestampClock> {
+        self.updated_at
+    }
+
+    /// Returns true if the column is the version of the row for optimistic locking
+    pub fn is_version(&self) -> bool {
+        self.version
+    }
+
+    /// Returns true if the values of the column are masked
+    pub fn is_masked(&self) -> bool {
+        self.masked.is_some()
+    }
+
+    /// Get the roles the column is unmasked for, none if any unmask context unmasks it
+    pub fn get_unmask_roles(&self) -> &[String] {
+        self.masked.as_deref().unwrap_or_default()
+    }
 }
 
 struct Text;
@@ -415,13 +508,13 @@ struct TextArray;
 
 impl Iden for Text {
     fn unquoted(&self, s: &mut dyn std::fmt::Write) {
-        write!(s, "text").unwrap();
+        write!(s, "text").expect("Failed to write");
     }
 }
 
 impl Iden for TextArray {
     fn unquoted(&self, s: &mut dyn std::fmt::Write) {
-        write!(s, "text[]").unwrap();
+        write!(s, "text[]").expect("Failed to write");
     }
 }
 
@@ -0,0 +1,317 @@
+use crate::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, Iterable, PrimaryKeyToColumn, QueryFilter,
+    QuerySelect, QueryTrait,
+};
+use sea_query::{Alias, Asterisk, Condition, DynIden, Expr, IntoIden, Query, SimpleExpr};
+use std::{
+    collections::hash_map::RandomState,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    marker::PhantomData,
+    time::Duration,
+};
+
+/// Deletes the expired rows of an entity in batches.
+///
+/// The column marked with `#[sea_orm(expires_at)]` holds the time a row expires at. Selects of
+/// the entity only return the rows that have not expired, with `expires_at > CURRENT_TIMESTAMP`,
+/// or `expires_at IS NULL` for a nullable column, whose rows without an expiry never expire.
+/// Select expired rows with [EntityTrait::unscoped].
+///
+/// A sweep deletes up to [ExpirySweeper::batch_size] rows per statement, and at most
+/// [ExpirySweeper::max_batches] statements, so a backlog of expired rows doesn't hold locks
+/// for long. [ExpirySweeper::run] sweeps in the background, at an interval with random jitter
+/// so the sweepers of several instances don't line up.
+///
+/// ```
+/// # use sea_orm::{entity::*, query::*, DbBackend};
+/// # mod session {
+/// #     use sea_orm::entity::prelude::*;
+/// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #     #[sea_orm(table_name = "session")]
+/// #     pub struct Model {
+/// #         #[sea_orm(primary_key)]
+/// #         pub id: i32,
+/// #         pub token: String,
+/// #         #[sea_orm(expires_at)]
+/// #         pub expires_at: DateTimeUtc,
+/// #     }
+/// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// #     pub enum Relation {}
+/// #     impl ActiveModelBehavior for ActiveModel {}
+/// # }
+/// use sea_orm::ExpirySweeper;
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///     session::Entity::find().build(DbBackend::Postgres).to_string(),
+///     r#"SELECT "session"."id", "session"."token", "session"."expires_at" FROM "session" WHERE "session"."expires_at" > CURRENT_TIMESTAMP"#
+/// );
+///
+/// let sweeper = ExpirySweeper::<session::Entity>::new()
+///     .batch_size(500)
+///     .max_batches(20)
+///     .interval(Duration::from_secs(60))
+///     .jitter(Duration::from_secs(10));
+/// // tokio::spawn(sweeper.run(db, tokio::time::sleep));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExpirySweeper<E>
+where
+    E: EntityTrait,
+{
+    batch_size: u64,
+    max_batches: u64,
+    interval: Duration,
+    jitter: Duration,
+    entity: PhantomData<E>,
+}
+
+impl<E> Default for ExpirySweeper<E>
+where
+    E: EntityTrait,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> ExpirySweeper<E>
+where
+    E: EntityTrait,
+{
+    /// Create a sweeper deleting 1000 rows per batch, up to 100 batches per sweep, every
+    /// minute with up to 10 seconds of jitter
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity has no column marked with `#[sea_orm(expires_at)]`
+    pub fn new() -> Self {
+        assert!(
+            expires_at_column::<E>().is_some(),
+            "`{}` has no `expires_at` column",
+            E::default().table_name()
+        );
+        Self {
+            batch_size: 1000,
+            max_batches: 100,
+            interval: Duration::from_secs(60),
+            jitter: Duration::from_secs(10),
+            entity: PhantomData,
+        }
+    }
+
+    /// Set the number of rows deleted per statement
+    pub fn batch_size(mut self, batch_size: u64) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set the number of statements per sweep
+    pub fn max_batches(mut self, max_batches: u64) -> Self {
+        self.max_batches = max_batches.max(1);
+        self
+    }
+
+    /// Set the time between sweeps
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the most random time added to the interval
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Delete expired rows in batches, until a batch deletes less than the batch size or the
+    /// batch limit is reached, and return the number of rows deleted
+    pub async fn sweep<C>(&self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut deleted = 0;
+        for _ in 0..self.max_batches {
+            let rows = E::delete_many()
+                .filter(self.expired_batch())
+                .exec(db)
+                .await?
+                .rows_affected;
+            deleted += rows;
+            if rows < self.batch_size {
+                break;
+            }
+        }
+        Ok(deleted)
+    }
+
+    /// Sweep forever, sleeping between sweeps with the `sleep` of the async runtime, e.g.
+    /// `tokio::time::sleep`. Failed sweeps are logged and retried at the next interval.
+    pub async fn run<C, S, F>(self, db: C, sleep: S)
+    where
+        C: ConnectionTrait,
+        S: Fn(Duration) -> F,
+        F: Future<Output = ()>,
+    {
+        loop {
+            match self.sweep(&db).await {
+                Ok(deleted) => tracing::debug!(
+                    "Deleted {deleted} expired rows of `{}`",
+                    E::default().table_name()
+                ),
+                Err(err) => tracing::warn!(
+                    "Failed to delete expired rows of `{}`: {err}",
+                    E::default().table_name()
+                ),
+            }
+            sleep(self.interval + random_up_to(self.jitter)).await;
+        }
+    }
+
+    /// `primary key IN (SELECT * FROM (SELECT primary key ... LIMIT n) AS expired)`, the
+    /// derived table lets MySQL limit the subquery and select from the table deleted from
+    fn expired_batch(&self) -> SimpleExpr {
+        let entity = E::default();
+        let col = expires_at_column::<E>().expect("checked by new");
+        let keys: Vec<E::Column> = E::PrimaryKey::iter().map(|key| key.into_column()).collect();
+        let batch = E::unscoped()
+            .select_only()
+            .columns(keys.iter().copied())
+            .filter(Expr::col((entity, col)).lte(Expr::current_timestamp()))
+            .limit(self.batch_size)
+            .into_query();
+        let subquery = Query::select()
+            .column(Asterisk)
+            .from_subquery(batch, Alias::new("expired"))
+            .to_owned();
+        match keys.as_slice() {
+            [key] => Expr::col((entity, *key)).in_subquery(subquery),
+            keys => Expr::tuple(keys.iter().map(|key| Expr::col((entity, *key)).into()))
+                .in_subquery(subquery),
+        }
+    }
+}
+
+/// A random duration up to `max`
+fn random_up_to(max: Duration) -> Duration {
+    let nanos = max.as_nanos().min(u64::MAX as u128) as u64;
+    if nanos == 0 {
+        return max;
+    }
+    let random = RandomState::new().build_hasher().finish();
+    Duration::from_nanos(random % (nanos + 1))
+}
+
+/// The column marked with `#[sea_orm(expires_at)]`
+pub(crate) fn expires_at_column<E>() -> Option<E::Column>
+where
+    E: EntityTrait,
+{
+    E::Column::iter().find(|col| col.def().is_expires_at())
+}
+
+/// The condition selecting the rows of an entity that have not expired
+pub(crate) fn expiry_condition<E>() -> Option<Condition>
+where
+    E: EntityTrait,
+{
+    expiry_condition_on::<E>(E::default().into_iden())
+}
+
+/// The [expiry_condition] of the rows of `tbl`, the table of the entity or an alias of it
+pub(crate) fn expiry_condition_on<E>(tbl: DynIden) -> Option<Condition>
+where
+    E: EntityTrait,
+{
+    let col = expires_at_column::<E>()?;
+    let expires_at = Expr::col((tbl, col));
+    let unexpired = Condition::all().add(expires_at.clone().gt(Expr::current_timestamp()));
+    Some(if col.def().is_null() {
+        Condition::any().add(expires_at.is_null()).add(unexpired)
+    } else {
+        unexpired
+    })
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{DbBackend, MockDatabase, MockExecResult, Transaction};
+    use pretty_assertions::assert_eq;
+
+    mod session {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "session")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(expires_at)]
+            pub expires_at: DateTimeUtc,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn deleted(rows_affected: u64) -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        }
+    }
+
+    #[smol_potat::test]
+    async fn sweep_in_batches() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([deleted(2), deleted(2), deleted(1)])
+            .into_connection();
+        let sweeper = ExpirySweeper::<session::Entity>::new().batch_size(2);
+
+        assert_eq!(sweeper.sweep(&db).await?, 5);
+
+        let delete = Transaction::from_sql_and_values(
+            DbBackend::MySql,
+            [
+                "DELETE FROM `session` WHERE `session`.`id` IN",
+                "(SELECT * FROM (SELECT `session`.`id` FROM `session`",
+                "WHERE `session`.`expires_at` <= CURRENT_TIMESTAMP LIMIT ?) AS `expired`)",
+            ]
+            .join(" "),
+            [2u64.into()],
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [delete.clone(), delete.clone(), delete]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn sweep_up_to_max_batches() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([deleted(3), deleted(3)])
+            .into_connection();
+        let sweeper = ExpirySweeper::<session::Entity>::new()
+            .batch_size(3)
+            .max_batches(2);
+
+        assert_eq!(sweeper.sweep(&db).await?, 6);
+        assert_eq!(db.into_transaction_log().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "`cake` has no `expires_at` column")]
+    fn sweeper_needs_expires_at() {
+        ExpirySweeper::<crate::tests_cfg::cake::Entity>::new();
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{
+    ActiveModelBehavior, ActiveModelTrait, ConnectionTrait, DbErr, EntityTrait, IntoActiveModel,
+    Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, Value,
+};
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A pseudo random number generator for [Fake] values. It is not suitable for anything
+/// but test data.
+#[derive(Debug, Clone)]
+pub struct FakeRng {
+    state: u64,
+}
+
+/// A type that can produce a random value of itself for test data
+pub trait Fake: Sized {
+    /// Produce a random value
+    fn fake(rng: &mut FakeRng) -> Self;
+}
+
+/// An ActiveModel that can be filled with [Fake] values, derived with `DeriveFactory`
+pub trait FakeActiveModel: ActiveModelTrait {
+    /// An ActiveModel with every field set to a random value. Nullable fields are set to `None`.
+    fn fake(rng: &mut FakeRng) -> Self;
+
+    /// Create a [Factory] for this ActiveModel
+    fn factory() -> Factory<Self>
+    where
+        Self: Sized,
+    {
+        Factory::new()
+    }
+}
+
+/// Builds ActiveModels with random values, some of them can be overridden.
+/// Auto-increment primary keys are left for the database to generate, and non-null
+/// foreign keys have to be set with [Factory::set].
+///
+/// ```
+/// # use sea_orm::{entity::*, error::*, tests_cfg::*, DbConn};
+/// # async fn function(db: &DbConn) -> Result<(), DbErr> {
+/// let cake = cake::ActiveModel::factory().create(db).await?;
+///
+/// let fruits = fruit::ActiveModel::factory()
+///     .set(fruit::Column::CakeId, cake.id)
+///     .create_many(db, 3)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Factory<A>
+where
+    A: ActiveModelTrait,
+{
+    overrides: Vec<(<A::Entity as EntityTrait>::Column, Value)>,
+    rng: FakeRng,
+}
+
+impl FakeRng {
+    /// Create a generator seeded differently on every call
+    pub fn new() -> Self {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        Self::seeded(nanos ^ COUNTER.fetch_add(1, Ordering::Relaxed).rotate_left(32))
+    }
+
+    /// Create a generator producing the same values for the same seed
+    pub fn seeded(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// The next random number (SplitMix64)
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A random number in `0..n`, `n` must not be zero
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Pick a random item, `None` if there are none
+    pub fn choose<I>(&mut self, iter: I) -> Option<I::Item>
+    where
+        I: IntoIterator,
+    {
+        let mut items: Vec<_> = iter.into_iter().collect();
+        if items.is_empty() {
+            return None;
+        }
+        let i = self.below(items.len() as u64) as usize;
+        Some(items.swap_remove(i))
+    }
+}
+
+impl Default for FakeRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Factory<A>
+where
+    A: FakeActiveModel,
+{
+    /// Create a factory without overrides
+    pub fn new() -> Self {
+        Self {
+            overrides: Vec::new(),
+            rng: FakeRng::new(),
+        }
+    }
+
+    /// Use the same generator seed on every run
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = FakeRng::seeded(seed);
+        self
+    }
+
+    /// Set a column to this value instead of a random one
+    pub fn set<V>(mut self, column: <A::Entity as EntityTrait>::Column, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.overrides.push((column, value.into()));
+        self
+    }
+
+    /// Build an ActiveModel
+    pub fn build(&mut self) -> A {
+        let mut am = A::fake(&mut self.rng);
+        if <<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment() {
+            for key in <<A::Entity as EntityTrait>::PrimaryKey as Iterable>::iter() {
+                am.not_set(key.into_column());
+            }
+        }
+        for (column, value) in self.overrides.iter() {
+            am.set(*column, value.clone());
+        }
+        am
+    }
+
+    /// Build an ActiveModel and insert it
+    pub async fn create<C>(&mut self, db: &C) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
+    where
+        A: ActiveModelBehavior + Send,
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait,
+    {
+        self.build().insert(db).await
+    }
+
+    /// Build and insert `n` ActiveModels one by one
+    pub async fn create_many<C>(
+        &mut self,
+        db: &C,
+        n: usize,
+    ) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
+    where
+        A: ActiveModelBehavior + Send,
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait,
+    {
+        let mut models = Vec::with_capacity(n);
+        for _ in 0..n {
+            models.push(self.create(db).await?);
+        }
+        Ok(models)
+    }
+}
+
+impl<A> Default for Factory<A>
+where
+    A: FakeActiveModel,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Nullable fields are left empty, so that nullable foreign keys stay valid
+impl<T> Fake for Option<T> {
+    fn fake(_: &mut FakeRng) -> Self {
+        None
+    }
+}
+
+macro_rules! fake_integer {
+    ( $($ty:ty),* ) => {
+        $(
+            impl Fake for $ty {
+                fn fake(rng: &mut FakeRng) -> Self {
+                    (rng.below((<$ty>::MAX as u64).min(1_000_000)) + 1) as $ty
+                }
+            }
+        )*
+    };
+}
+
+fake_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl Fake for f32 {
+    fn fake(rng: &mut FakeRng) -> Self {
+        rng.below(100_000) as f32 / 100.0
+    }
+}
+
+impl Fake for f64 {
+    fn fake(rng: &mut FakeRng) -> Self {
+        rng.below(100_000) as f64 / 100.0
+    }
+}
+
+impl Fake for bool {
+    fn fake(rng: &mut FakeRng) -> Self {
+        rng.below(2) == 1
+    }
+}
+
+impl Fake for String {
+    fn fake(rng: &mut FakeRng) -> Self {
+        (0..10)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect()
+    }
+}
+
+impl Fake for Vec<u8> {
+    fn fake(rng: &mut FakeRng) -> Self {
+        rng.next_u64().to_le_bytes().to_vec()
+    }
+}
+
+#[cfg(feature = "with-json")]
+impl Fake for serde_json::Value {
+    fn fake(rng: &mut FakeRng) -> Self {
+        serde_json::json!({ "fake": String::fake(rng) })
+    }
+}
+
+/// Days from 2000-01-01 within 30 years
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+fn fake_days(rng: &mut FakeRng) -> i64 {
+    rng.below(365 * 30) as i64
+}
+
+#[cfg(feature = "with-chrono")]
+mod fake_chrono {
+    use super::*;
+    use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+    impl Fake for NaiveDate {
+        fn fake(rng: &mut FakeRng) -> Self {
+            NaiveDate::from_ymd_opt(2000, 1, 1).expect("Valid date")
+                + chrono::Duration::days(fake_days(rng))
+        }
+    }
+
+    impl Fake for NaiveTime {
+        fn fake(rng: &mut FakeRng) -> Self {
+            NaiveTime::from_num_seconds_from_midnight_opt(rng.below(86_400) as u32, 0)
+                .expect("Valid time")
+        }
+    }
+
+    impl Fake for NaiveDateTime {
+        fn fake(rng: &mut FakeRng) -> Self {
+            NaiveDate::fake(rng).and_time(NaiveTime::fake(rng))
+        }
+    }
+
+    impl Fake for DateTime<Utc> {
+        fn fake(rng: &mut FakeRng) -> Self {
+            NaiveDateTime::fake(rng).and_utc()
+        }
+    }
+
+    impl Fake for DateTime<FixedOffset> {
+        fn fake(rng: &mut FakeRng) -> Self {
+            DateTime::<Utc>::fake(rng).fixed_offset()
+        }
+    }
+
+    impl Fake for DateTime<Local> {
+        fn fake(rng: &mut FakeRng) -> Self {
+            DateTime::<Utc>::fake(rng).with_timezone(&Local)
+        }
+    }
+}
+
+#[cfg(feature = "with-time")]
+mod fake_time {
+    use super::*;
+    use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+    impl Fake for Date {
+        fn fake(rng: &mut FakeRng) -> Self {
+            // 2000-01-01
+            Date::from_julian_day(2_451_545 + fake_days(rng) as i32).expect("Valid date")
+        }
+    }
+
+    impl Fake for Time {
+        fn fake(rng: &mut FakeRng) -> Self {
+            let secs = rng.below(86_400);
+            Time::from_hms(
+                (secs / 3600) as u8,
+                (secs / 60 % 60) as u8,
+                (secs % 60) as u8,
+            )
+            .expect("Valid time")
+        }
+    }
+
+    impl Fake for PrimitiveDateTime {
+        fn fake(rng: &mut FakeRng) -> Self {
+            PrimitiveDateTime::new(Date::fake(rng), Time::fake(rng))
+        }
+    }
+
+    impl Fake for OffsetDateTime {
+        fn fake(rng: &mut FakeRng) -> Self {
+            PrimitiveDateTime::fake(rng).assume_utc()
+        }
+    }
+}
+
+#[cfg(feature = "with-uuid")]
+impl Fake for uuid::Uuid {
+    fn fake(rng: &mut FakeRng) -> Self {
+        let bytes = ((rng.next_u64() as u128) << 64 | rng.next_u64() as u128).to_le_bytes();
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+}
+
+#[cfg(feature = "with-rust_decimal")]
+impl Fake for rust_decimal::Decimal {
+    fn fake(rng: &mut FakeRng) -> Self {
+        rust_decimal::Decimal::new(rng.below(100_000) as i64, 2)
+    }
+}
+
+#[cfg(feature = "with-bigdecimal")]
+impl Fake for bigdecimal::BigDecimal {
+    fn fake(rng: &mut FakeRng) -> Self {
+        bigdecimal::BigDecimal::new((rng.below(100_000) as i64).into(), 2)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use crate::{entity::*, tests_cfg::*, FakeRng};
+
+    #[test]
+    fn build() {
+        let am = cake::ActiveModel::factory().seed(1).build();
+        assert!(am.id.is_not_set());
+        assert!(matches!(&am.name, ActiveValue::Set(name) if name.len() == 10));
+
+        let am = fruit::ActiveModel::factory()
+            .set(fruit::Column::CakeId, 3)
+            .build();
+        assert_eq!(am.cake_id, ActiveValue::Set(Some(3)));
+
+        let mut a = FakeRng::seeded(42);
+        let mut b = FakeRng::seeded(42);
+        assert_eq!(
+            (0..8).map(|_| a.next_u64()).collect::<Vec<_>>(),
+            (0..8).map(|_| b.next_u64()).collect::<Vec<_>>()
+        );
+    }
+}
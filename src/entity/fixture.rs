@@ -0,0 +1,296 @@
+use crate::{
+    ActiveModelBehavior, ActiveModelTrait, ConnectionTrait, DbErr, EntityName, EntityTrait,
+    Factory, FakeActiveModel, IntoActiveModel, ModelTrait, Related, Value,
+};
+use std::{any::Any, any::TypeId, collections::HashMap, str::FromStr};
+
+/// A declarative description of related models to insert for testing. Every model of
+/// a child fixture gets its foreign key set to the parent model it is inserted under.
+///
+/// ```
+/// # use sea_orm::{entity::*, error::*, tests_cfg::*, DbConn, Fixture};
+/// # async fn function(db: &DbConn) -> Result<(), DbErr> {
+/// // two cakes each with three fruits
+/// let seeded = Fixture::new(cake::ActiveModel::factory())
+///     .named("cheese")
+///     .with(Fixture::new(fruit::ActiveModel::factory()).count(3))
+///     .count(2)
+///     .insert(db)
+///     .await?;
+///
+/// assert_eq!(seeded.all::<cake::Entity>().len(), 2);
+/// assert_eq!(seeded.all::<fruit::Entity>().len(), 6);
+/// let cheese = seeded.get::<cake::Entity>("cheese").unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub struct Fixture<A, C>
+where
+    A: ActiveModelTrait,
+{
+    factory: Factory<A>,
+    count: usize,
+    name: Option<String>,
+    children: Vec<Box<dyn FixtureNode<A::Entity, C>>>,
+}
+
+/// The models inserted by a [Fixture], looked up by their entity
+#[derive(Debug, Default)]
+pub struct Seeded {
+    models: HashMap<TypeId, Vec<Box<dyn Any + Send>>>,
+    names: HashMap<(TypeId, String), usize>,
+}
+
+#[async_trait::async_trait]
+trait FixtureNode<P, C>: std::fmt::Debug + Send
+where
+    P: EntityTrait,
+{
+    async fn insert_under(
+        &mut self,
+        db: &C,
+        parent: &P::Model,
+        seeded: &mut Seeded,
+    ) -> Result<(), DbErr>;
+}
+
+struct Child<A, C>
+where
+    A: ActiveModelTrait,
+{
+    fixture: Fixture<A, C>,
+}
+
+impl<A, C> Fixture<A, C>
+where
+    A: FakeActiveModel + ActiveModelBehavior + Send + 'static,
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A> + Sync,
+    C: ConnectionTrait + 'static,
+{
+    /// Insert one model built by the factory
+    pub fn new(factory: Factory<A>) -> Self {
+        Self {
+            factory,
+            count: 1,
+            name: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Insert this many models, each with its own children
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Name the first model inserted, to find it with [Seeded::get]
+    pub fn named<T>(mut self, name: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Insert the models of the child fixture under every model of this fixture.
+    /// The child entity must have a direct relation to this entity.
+    pub fn with<B>(mut self, child: Fixture<B, C>) -> Self
+    where
+        B: FakeActiveModel + ActiveModelBehavior + Send + 'static,
+        B::Entity: Related<A::Entity>,
+        <B::Entity as EntityTrait>::Model: IntoActiveModel<B> + Sync,
+    {
+        self.children.push(Box::new(Child { fixture: child }));
+        self
+    }
+
+    /// Insert the models, parents before children
+    pub async fn insert(self, db: &C) -> Result<Seeded, DbErr> {
+        let mut seeded = Seeded::default();
+        self.insert_into(db, &mut seeded).await?;
+        Ok(seeded)
+    }
+
+    /// Insert the models, adding them to the models inserted by other fixtures
+    pub async fn insert_into(mut self, db: &C, seeded: &mut Seeded) -> Result<(), DbErr> {
+        self.insert_with(db, &[], seeded).await
+    }
+
+    async fn insert_with(
+        &mut self,
+        db: &C,
+        foreign_key: &[(<A::Entity as EntityTrait>::Column, Value)],
+        seeded: &mut Seeded,
+    ) -> Result<(), DbErr> {
+        for i in 0..self.count {
+            let mut am = self.factory.build();
+            for (column, value) in foreign_key {
+                am.set(*column, value.clone());
+            }
+            let model = am.insert(db).await?;
+            let name = self.name.as_ref().filter(|_| i == 0);
+            seeded.push::<A::Entity>(name, model.clone());
+            for child in self.children.iter_mut() {
+                child.insert_under(db, &model, seeded).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<A, C> std::fmt::Debug for Fixture<A, C>
+where
+    A: ActiveModelTrait,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Fixture")
+            .field("factory", &self.factory)
+            .field("count", &self.count)
+            .field("name", &self.name)
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+impl<A, C> std::fmt::Debug for Child<A, C>
+where
+    A: ActiveModelTrait,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fixture.fmt(f)
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, A, C> FixtureNode<P, C> for Child<A, C>
+where
+    P: EntityTrait,
+    P::Model: Sync,
+    A: FakeActiveModel + ActiveModelBehavior + Send + 'static,
+    A::Entity: Related<P>,
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A> + Sync,
+    C: ConnectionTrait + 'static,
+{
+    async fn insert_under(
+        &mut self,
+        db: &C,
+        parent: &P::Model,
+        seeded: &mut Seeded,
+    ) -> Result<(), DbErr> {
+        let rel = <A::Entity as Related<P>>::to();
+        if <A::Entity as Related<P>>::via().is_some() || rel.is_owner {
+            return Err(DbErr::Custom(format!(
+                "{} has no foreign key to {}",
+                A::Entity::default().table_name(),
+                P::default().table_name(),
+            )));
+        }
+        let foreign_key = rel
+            .from_col
+            .into_iter()
+            .zip(rel.to_col)
+            .map(|(from, to)| {
+                let from = column_from_str::<A::Entity>(&from.to_string())?;
+                let to = column_from_str::<P>(&to.to_string())?;
+                Ok((from, parent.get(to)))
+            })
+            .collect::<Result<Vec<_>, DbErr>>()?;
+        self.fixture.insert_with(db, &foreign_key, seeded).await
+    }
+}
+
+fn column_from_str<E>(s: &str) -> Result<E::Column, DbErr>
+where
+    E: EntityTrait,
+{
+    <E::Column as FromStr>::from_str(s)
+        .map_err(|_| DbErr::Custom(format!("Failed at mapping string to column {s}")))
+}
+
+impl Seeded {
+    fn push<E>(&mut self, name: Option<&String>, model: E::Model)
+    where
+        E: EntityTrait,
+    {
+        let models = self.models.entry(TypeId::of::<E>()).or_default();
+        if let Some(name) = name {
+            self.names
+                .entry((TypeId::of::<E>(), name.clone()))
+                .or_insert(models.len());
+        }
+        models.push(Box::new(model));
+    }
+
+    /// All models of the entity, in the order they were inserted
+    pub fn all<E>(&self) -> Vec<&E::Model>
+    where
+        E: EntityTrait,
+    {
+        self.models
+            .get(&TypeId::of::<E>())
+            .into_iter()
+            .flatten()
+            .filter_map(|model| model.downcast_ref())
+            .collect()
+    }
+
+    /// The model given this name with [Fixture::named]
+    pub fn get<E>(&self, name: &str) -> Option<&E::Model>
+    where
+        E: EntityTrait,
+    {
+        let i = self.names.get(&(TypeId::of::<E>(), name.to_owned()))?;
+        self.models.get(&TypeId::of::<E>())?.get(*i)?.downcast_ref()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use crate::{entity::*, tests_cfg::*, DbBackend, DbErr, Fixture, MockDatabase};
+
+    #[smol_potat::test]
+    async fn insert_related() -> Result<(), DbErr> {
+        let cake = |id: i32| cake::Model {
+            id,
+            name: format!("Cake {id}"),
+        };
+        let fruit = |id: i32, cake_id: i32| fruit::Model {
+            id,
+            name: format!("Fruit {id}"),
+            cake_id: Some(cake_id),
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake(1)]])
+            .append_query_results([[fruit(1, 1)], [fruit(2, 1)]])
+            .append_query_results([[cake(2)]])
+            .append_query_results([[fruit(3, 2)], [fruit(4, 2)]])
+            .into_connection();
+
+        let seeded = Fixture::new(cake::ActiveModel::factory())
+            .named("first")
+            .with(Fixture::new(fruit::ActiveModel::factory()).count(2))
+            .count(2)
+            .insert(&db)
+            .await?;
+
+        assert_eq!(seeded.all::<cake::Entity>(), [&cake(1), &cake(2)]);
+        assert_eq!(seeded.all::<fruit::Entity>().len(), 4);
+        assert_eq!(seeded.get::<cake::Entity>("first"), Some(&cake(1)));
+        assert_eq!(seeded.get::<cake::Entity>("second"), None);
+
+        let log = db.into_transaction_log();
+        let cake_ids: Vec<_> = log
+            .iter()
+            .flat_map(|txn| txn.statements())
+            .filter(|stmt| stmt.sql.starts_with(r#"INSERT INTO "fruit""#))
+            .map(|stmt| stmt.values.as_ref().unwrap().0[1].clone())
+            .collect();
+        assert_eq!(
+            cake_ids,
+            [1, 1, 2, 2].map(|id| Value::Int(Some(id))).to_vec()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    ColumnTrait, EntityTrait, ModelTrait, PrimaryKeyTrait, QueryFilter, QueryOrder, Select,
+    SelectRecursive,
+};
+use sea_query::Value;
+
+/// A Model in a hierarchy stored as an adjacency list, each row holding the primary key of its
+/// parent in a column of the same table, derived with `DeriveHierarchy`.
+///
+/// Roots, parents and children are found with plain filters. Subtrees and ancestors are found
+/// with `WITH RECURSIVE` queries, which stop at the row they started from should the rows link
+/// back to it in a cycle, see [SelectRecursive::skip_cycles].
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, DbBackend, HierarchyModel};
+/// # mod category {
+/// #     use sea_orm::{entity::prelude::*, DeriveHierarchy};
+/// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveHierarchy)]
+/// #     #[sea_orm(table_name = "category")]
+/// #     pub struct Model {
+/// #         #[sea_orm(primary_key)]
+/// #         pub id: i32,
+/// #         #[sea_orm(parent_key)]
+/// #         pub parent_id: Option<i32>,
+/// #     }
+/// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// #     pub enum Relation {}
+/// #     impl ActiveModelBehavior for ActiveModel {}
+/// # }
+///
+/// let shoes = category::Model {
+///     id: 4,
+///     parent_id: Some(1),
+/// };
+///
+/// assert_eq!(
+///     category::Model::find_roots()
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "category"."id", "category"."parent_id" FROM "category""#,
+///         r#"WHERE "category"."parent_id" IS NULL ORDER BY "category"."id" ASC"#,
+///     ]
+///     .join(" ")
+/// );
+/// assert_eq!(
+///     shoes.children().build(DbBackend::Postgres).to_string(),
+///     [
+///         r#"SELECT "category"."id", "category"."parent_id" FROM "category""#,
+///         r#"WHERE "category"."parent_id" = 4 ORDER BY "category"."id" ASC"#,
+///     ]
+///     .join(" ")
+/// );
+/// assert_eq!(
+///     shoes.find_subtree(2).build(DbBackend::Postgres).to_string(),
+///     [
+///         r#"WITH RECURSIVE "tree" ("id", "parent_id", "depth") AS"#,
+///         r#"(SELECT "category"."id", "category"."parent_id", 0 AS "depth" FROM "category""#,
+///         r#"WHERE "category"."id" = 4"#,
+///         r#"UNION ALL (SELECT "category"."id", "category"."parent_id", "tree"."depth" + 1 AS "depth""#,
+///         r#"FROM "category" INNER JOIN "tree" ON "category"."parent_id" = "tree"."id""#,
+///         r#"WHERE "category"."id" <> 4 AND "tree"."depth" < 2))"#,
+///         r#"SELECT * FROM "tree" ORDER BY "depth" ASC"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+///
+/// The primary key must be a single column.
+pub trait HierarchyModel: ModelTrait
+where
+    <<Self::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType: Into<Value>,
+{
+    /// The column holding the primary key of the parent, `NULL` for a root
+    fn parent_column() -> <Self::Entity as EntityTrait>::Column;
+
+    /// Select the rows without a parent
+    fn find_roots() -> Select<Self::Entity> {
+        <Self::Entity as EntityTrait>::find()
+            .filter(Self::parent_column().is_null())
+            .order_by_asc(SelectRecursive::<Self::Entity>::key())
+    }
+
+    /// Select the parent of this row
+    fn parent(&self) -> Select<Self::Entity> {
+        <Self::Entity as EntityTrait>::find()
+            .filter(SelectRecursive::<Self::Entity>::key().eq(self.get(Self::parent_column())))
+    }
+
+    /// Select the children of this row
+    fn children(&self) -> Select<Self::Entity> {
+        let key = SelectRecursive::<Self::Entity>::key();
+        <Self::Entity as EntityTrait>::find()
+            .filter(Self::parent_column().eq(self.get(key)))
+            .order_by_asc(key)
+    }
+
+    /// Select this row and its descendants down to `depth` levels below it, each with its
+    /// depth
+    fn find_subtree(&self, depth: u32) -> SelectRecursive<Self::Entity> {
+        let key = SelectRecursive::<Self::Entity>::key();
+        SelectRecursive::descendants_of(self.get(key), Self::parent_column())
+            .skip_cycles()
+            .max_depth(depth)
+    }
+
+    /// Select this row and its ancestors up to the root, each with its depth above this row.
+    /// A cycle above this row is not detected, bound the query with
+    /// [SelectRecursive::max_depth] if the rows may contain one.
+    fn ancestors(&self) -> SelectRecursive<Self::Entity> {
+        let key = SelectRecursive::<Self::Entity>::key();
+        SelectRecursive::ancestors_of(self.get(key), Self::parent_column()).skip_cycles()
+    }
+}
@@ -0,0 +1,103 @@
+use crate::JsonValue;
+
+/// How [ActiveModelTrait::set_from_json_with](crate::ActiveModelTrait::set_from_json_with)
+/// applies a JSON object to an ActiveModel, as the body of a `PATCH` request.
+///
+/// The columns absent from the object keep their values. By default fields that are not
+/// columns are ignored and `null` sets a column to `NULL`. With [JsonPolicy::merge_patch] the
+/// object is applied as an [RFC 7396](https://www.rfc-editor.org/rfc/rfc7396) merge patch,
+/// merging objects into the current values of JSON columns and removing their members that
+/// are `null` in the patch.
+///
+/// ```
+/// # use sea_orm::{entity::*, tests_cfg::fruit, DbErr, JsonPolicy};
+/// # fn main() -> Result<(), DbErr> {
+/// let mut apple = fruit::Model {
+///     id: 1,
+///     name: "Apple".to_owned(),
+///     cake_id: Some(2),
+/// }
+/// .into_active_model();
+///
+/// let policy = JsonPolicy::new().deny_unknown_fields().null_as_not_set();
+/// apple.set_from_json_with(serde_json::json!({ "name": "Green Apple", "cake_id": null }), &policy)?;
+/// assert_eq!(apple.name, Set("Green Apple".to_owned()));
+/// assert_eq!(apple.cake_id, Unchanged(Some(2)));
+///
+/// assert_eq!(
+///     apple.set_from_json_with(serde_json::json!({ "colour": "green" }), &policy),
+///     Err(DbErr::Json("unknown field `colour`".to_owned()))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonPolicy {
+    deny_unknown_fields: bool,
+    null_as_not_set: bool,
+    merge_patch: bool,
+}
+
+impl JsonPolicy {
+    /// Ignore unknown fields, set columns to `NULL` for `null`, and replace the values of JSON
+    /// columns
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fail with [DbErr::Json](crate::DbErr::Json) on a field that is not a column
+    pub fn deny_unknown_fields(mut self) -> Self {
+        self.deny_unknown_fields = true;
+        self
+    }
+
+    /// Leave the columns that are `null` in the object unchanged, instead of setting them to
+    /// `NULL`
+    pub fn null_as_not_set(mut self) -> Self {
+        self.null_as_not_set = true;
+        self
+    }
+
+    /// Apply the object as an RFC 7396 merge patch, see [JsonPolicy]
+    pub fn merge_patch(mut self) -> Self {
+        self.merge_patch = true;
+        self
+    }
+
+    pub(crate) fn denies_unknown_fields(&self) -> bool {
+        self.deny_unknown_fields
+    }
+
+    /// The new value of a column given in the object, or `None` to leave it unchanged
+    pub(crate) fn apply(&self, current: Option<JsonValue>, patch: &JsonValue) -> Option<JsonValue> {
+        if patch.is_null() && self.null_as_not_set {
+            return None;
+        }
+        if !self.merge_patch {
+            return Some(patch.clone());
+        }
+        let mut value = current.unwrap_or(JsonValue::Null);
+        merge_patch(&mut value, patch);
+        Some(value)
+    }
+}
+
+/// Apply an RFC 7396 merge patch to a JSON value
+fn merge_patch(target: &mut JsonValue, patch: &JsonValue) {
+    let JsonValue::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = JsonValue::Object(Default::default());
+    }
+    if let JsonValue::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.as_str()).or_insert(JsonValue::Null), value);
+            }
+        }
+    }
+}
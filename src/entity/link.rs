@@ -46,3 +46,48 @@ pub trait Linked {
         select
     }
 }
+
+/// Chain relations into a link starting from the Entity `E`, as generated by `DeriveLinked`.
+///
+/// Each relation is taken in the direction that continues from the table reached so far,
+/// so a `belongs_to` relation declared on an intermediate entity can be listed as is and
+/// will be reversed when walked from the other side.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, link_through, tests_cfg::*};
+///
+/// let link = link_through::<cake::Entity>([
+///     cake_filling::Relation::Cake.def(),
+///     cake_filling::Relation::Filling.def(),
+///     filling::Relation::Vendor.def(),
+/// ]);
+///
+/// assert_eq!(
+///     format!("{link:?}"),
+///     format!(
+///         "{:?}",
+///         vec![
+///             cake_filling::Relation::Cake.def().rev(),
+///             cake_filling::Relation::Filling.def(),
+///             filling::Relation::Vendor.def(),
+///         ]
+///     )
+/// );
+/// ```
+pub fn link_through<E>(rels: impl IntoIterator<Item = RelationDef>) -> Vec<LinkDef>
+where
+    E: EntityTrait,
+{
+    let mut tbl = E::default().table_ref();
+    rels.into_iter()
+        .map(|rel| {
+            let rel = if rel.from_tbl != tbl && rel.to_tbl == tbl {
+                rel.rev()
+            } else {
+                rel
+            };
+            tbl = rel.to_tbl.clone();
+            rel
+        })
+        .collect()
+}
@@ -0,0 +1,306 @@
+use crate::{ConnectionTrait, DbErr, EntityTrait, Select, TryGetableFromJson, Value};
+use sea_query::{ArrayType, ColumnType, Nullable, ValueType, ValueTypeErr};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// A locale with the locales to fall back to, in order.
+///
+/// A locale falls back to its language first, so `de-AT` resolves `de-AT`, then `de`.
+///
+/// ```
+/// use sea_orm::Locale;
+///
+/// let locale = Locale::new("de-AT").fallback("en");
+/// assert_eq!(locale.chain(), ["de-AT", "de", "en"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    chain: Vec<String>,
+}
+
+impl Locale {
+    /// Create a locale
+    pub fn new(locale: &str) -> Self {
+        Self { chain: Vec::new() }.fallback(locale)
+    }
+
+    /// Fall back to another locale, and its language
+    pub fn fallback(mut self, locale: &str) -> Self {
+        let mut locale = locale;
+        loop {
+            if !self.chain.iter().any(|l| l == locale) {
+                self.chain.push(locale.to_owned());
+            }
+            match locale.rsplit_once(['-', '_']) {
+                Some((language, _)) => locale = language,
+                None => break,
+            }
+        }
+        self
+    }
+
+    /// The locales to resolve, in order
+    pub fn chain(&self) -> &[String] {
+        &self.chain
+    }
+}
+
+impl From<&str> for Locale {
+    fn from(locale: &str) -> Self {
+        Self::new(locale)
+    }
+}
+
+/// The values of a translatable column, by locale, stored as a JSON object.
+///
+/// ```
+/// use sea_orm::{Locale, Translations};
+///
+/// let title = Translations::new()
+///     .with("en", "Apple Pie")
+///     .with("de", "Apfelkuchen");
+///
+/// assert_eq!(title.resolve(&Locale::new("de-AT")), Some("Apfelkuchen"));
+/// assert_eq!(title.resolve(&Locale::new("fr").fallback("en")), Some("Apple Pie"));
+/// assert_eq!(title.resolve(&Locale::new("fr")), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Translations(BTreeMap<String, String>);
+
+impl Translations {
+    /// Create an empty set of translations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the value in a locale
+    pub fn with<L, V>(mut self, locale: L, value: V) -> Self
+    where
+        L: Into<String>,
+        V: Into<String>,
+    {
+        self.insert(locale, value);
+        self
+    }
+
+    /// Set the value in a locale, returning the previous value
+    pub fn insert<L, V>(&mut self, locale: L, value: V) -> Option<String>
+    where
+        L: Into<String>,
+        V: Into<String>,
+    {
+        self.0.insert(locale.into(), value.into())
+    }
+
+    /// Get the value in exactly this locale
+    pub fn get(&self, locale: &str) -> Option<&str> {
+        self.0.get(locale).map(String::as_str)
+    }
+
+    /// Get the value in the first locale of the chain that has one
+    pub fn resolve(&self, locale: &Locale) -> Option<&str> {
+        self.resolve_entry(locale).map(|(_, value)| value.as_str())
+    }
+
+    /// The value of localized translations, see [Localize]
+    pub fn text(&self) -> Option<&str> {
+        self.0.values().next().map(String::as_str)
+    }
+
+    /// Iterate over the locales and values
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(l, v)| (l.as_str(), v.as_str()))
+    }
+
+    fn resolve_entry(&self, locale: &Locale) -> Option<(&String, &String)> {
+        locale
+            .chain()
+            .iter()
+            .find_map(|locale| self.0.get_key_value(locale))
+    }
+}
+
+/// A Model with translatable columns, derived with `DeriveLocalize` from the columns marked
+/// `#[sea_orm(translatable)]`
+pub trait Localize {
+    /// Keep only the value resolved for the locale in every translatable column
+    fn localize(&mut self, locale: &Locale);
+}
+
+impl Localize for Translations {
+    /// Keep only the resolved value, under the locale it was found in. Nothing is kept when no
+    /// locale of the chain has a value.
+    fn localize(&mut self, locale: &Locale) {
+        self.0 = self
+            .resolve_entry(locale)
+            .map(|(l, v)| (l.clone(), v.clone()))
+            .into_iter()
+            .collect();
+    }
+}
+
+impl<T> Localize for Option<T>
+where
+    T: Localize,
+{
+    fn localize(&mut self, locale: &Locale) {
+        if let Some(inner) = self {
+            inner.localize(locale);
+        }
+    }
+}
+
+impl Serialize for Translations {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Translations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        BTreeMap::deserialize(deserializer).map(Self)
+    }
+}
+
+impl TryGetableFromJson for Translations {}
+
+impl From<Translations> for Value {
+    fn from(source: Translations) -> Self {
+        Value::Json(serde_json::to_value(source).ok().map(Box::new))
+    }
+}
+
+impl ValueType for Translations {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Json(Some(json)) => serde_json::from_value(*json).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Translations".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Json
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Json
+    }
+}
+
+impl Nullable for Translations {
+    fn null() -> Value {
+        Value::Json(None)
+    }
+}
+
+#[cfg(feature = "postgres-array")]
+impl sea_query::value::with_array::NotU8 for Translations {}
+
+/// A select whose Models are localized after they are fetched, from [Select::localized]
+#[derive(Clone, Debug)]
+pub struct Localized<E>
+where
+    E: EntityTrait,
+{
+    select: Select<E>,
+    locale: Locale,
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+    E::Model: Localize,
+{
+    /// Resolve the translatable columns of the Models for a locale, falling back along its
+    /// chain.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, *};
+    /// # mod post {
+    /// #     use sea_orm::{entity::prelude::*, DeriveLocalize, Translations};
+    /// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveLocalize)]
+    /// #     #[sea_orm(table_name = "post")]
+    /// #     pub struct Model {
+    /// #         #[sea_orm(primary_key)]
+    /// #         pub id: i32,
+    /// #         #[sea_orm(translatable)]
+    /// #         pub title: Translations,
+    /// #     }
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[post::Model {
+    /// #         id: 1,
+    /// #         title: Translations::new()
+    /// #             .with("en", "Apple Pie")
+    /// #             .with("de", "Apfelkuchen"),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// let post = post::Entity::find()
+    ///     .localized(Locale::new("de-AT").fallback("en"))
+    ///     .one(&db)
+    ///     .await?
+    ///     .unwrap();
+    ///
+    /// assert_eq!(post.title.text(), Some("Apfelkuchen"));
+    /// assert_eq!(post.title, Translations::new().with("de", "Apfelkuchen"));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn localized<L>(self, locale: L) -> Localized<E>
+    where
+        L: Into<Locale>,
+    {
+        Localized {
+            select: self,
+            locale: locale.into(),
+        }
+    }
+}
+
+impl<E> Localized<E>
+where
+    E: EntityTrait,
+    E::Model: Localize,
+{
+    /// Get one localized Model from the SELECT query
+    pub async fn one<C>(self, db: &C) -> Result<Option<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut model = self.select.one(db).await?;
+        model.localize(&self.locale);
+        Ok(model)
+    }
+
+    /// Get all localized Models from the SELECT query
+    pub async fn all<C>(self, db: &C) -> Result<Vec<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut models = self.select.all(db).await?;
+        for model in models.iter_mut() {
+            model.localize(&self.locale);
+        }
+        Ok(models)
+    }
+}
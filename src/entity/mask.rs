@@ -0,0 +1,566 @@
+use crate::{ColumnDef, ColumnTrait};
+#[cfg(feature = "with-json")]
+use crate::{DbErr, EntityTrait, IdenStatic, Iterable, JsonValue, ModelTrait};
+use sea_query::Value;
+#[cfg(feature = "with-json")]
+use serde::ser::Error;
+use serde::{Serialize, Serializer};
+use std::{
+    cell::RefCell,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+thread_local! {
+    static CURRENT: RefCell<Option<UnmaskContext>> = const { RefCell::new(None) };
+    static LOGGED: RefCell<Vec<Value>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The value masked columns are replaced with
+pub const MASKED_VALUE: &str = "***";
+
+/// Permission to see the values of masked columns.
+///
+/// Columns marked with `#[sea_orm(masked)]` are redacted in the JSON of models, from
+/// [Select::into_json](crate::Select::into_json), [Masked] and [Loaded](crate::Loaded), unless
+/// the JSON is produced in an unmask scope. Columns marked with `#[sea_orm(masked = "hr, admin")]`
+/// are only unmasked by a context with one of the roles.
+///
+/// Models themselves hold the real values. `DeriveEntityModel` implements `Debug` for a Model
+/// with masked columns, redacting them, so the Model must not derive `Debug` itself. The
+/// `Serialize` it implements with `#[sea_orm(serde(..))]` redacts them as well, unlike a derived
+/// one. The values of masked columns that are inserted or updated from an ActiveModel are
+/// redacted in the statement logs.
+///
+/// ```
+/// # use sea_orm::{entity::*, query::*, DbBackend, Masked, UnmaskContext};
+/// # mod employee {
+/// #     use sea_orm::entity::prelude::*;
+/// #     use serde::Serialize;
+/// #     #[derive(Clone, PartialEq, DeriveEntityModel, Serialize)]
+/// #     #[sea_orm(table_name = "employee")]
+/// #     pub struct Model {
+/// #         #[sea_orm(primary_key)]
+/// #         pub id: i32,
+/// #         pub name: String,
+/// #         #[sea_orm(masked = "hr")]
+/// #         pub ssn: String,
+/// #     }
+/// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// #     pub enum Relation {}
+/// #     impl ActiveModelBehavior for ActiveModel {}
+/// # }
+/// let alice = employee::Model {
+///     id: 1,
+///     name: "Alice".to_owned(),
+///     ssn: "123-45-6789".to_owned(),
+/// };
+///
+/// assert_eq!(
+///     format!("{:?}", Masked::new(&alice)),
+///     r#"{"id":1,"name":"Alice","ssn":"***"}"#
+/// );
+/// assert_eq!(
+///     format!("{alice:?}"),
+///     r#"Model { id: 1, name: "Alice", ssn: "***" }"#
+/// );
+///
+/// UnmaskContext::new().role("hr").sync_scope(|| {
+///     assert_eq!(
+///         format!("{:?}", Masked::new(&alice)),
+///         r#"{"id":1,"name":"Alice","ssn":"123-45-6789"}"#
+///     );
+///     assert_eq!(
+///         format!("{alice:?}"),
+///         r#"Model { id: 1, name: "Alice", ssn: "123-45-6789" }"#
+///     );
+/// });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnmaskContext {
+    roles: Vec<String>,
+}
+
+/// A future running with an [UnmaskContext], see [UnmaskContext::scope]
+#[derive(Debug)]
+pub struct UnmaskScope<F> {
+    context: Option<UnmaskContext>,
+    future: Pin<Box<F>>,
+}
+
+impl UnmaskContext {
+    /// Create a context unmasking the columns that are masked without roles
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a role, unmasking the columns masked for it
+    pub fn role(mut self, role: &str) -> Self {
+        self.roles.push(role.to_owned());
+        self
+    }
+
+    /// The roles of the context
+    pub fn roles(&self) -> &[String] {
+        &self.roles
+    }
+
+    /// The unmask context of the current scope, if any
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Whether the values of a column are visible in this context
+    pub fn can_see(&self, def: &ColumnDef) -> bool {
+        let roles = def.get_unmask_roles();
+        roles.is_empty() || roles.iter().any(|role| self.roles.contains(role))
+    }
+
+    /// Run a future with this context as the current unmask context
+    pub fn scope<F>(self, future: F) -> UnmaskScope<F>
+    where
+        F: Future,
+    {
+        UnmaskScope {
+            context: Some(self),
+            future: Box::pin(future),
+        }
+    }
+
+    /// Run a closure with this context as the current unmask context
+    pub fn sync_scope<F, T>(self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let _guard = Guard::enter(Some(self));
+        f()
+    }
+}
+
+impl<F> Future for UnmaskScope<F>
+where
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let _guard = Guard::enter(this.context.clone());
+        this.future.as_mut().poll(cx)
+    }
+}
+
+/// Restores the previous unmask context when dropped, so scopes nest and survive panics
+struct Guard {
+    previous: Option<UnmaskContext>,
+}
+
+impl Guard {
+    fn enter(context: Option<UnmaskContext>) -> Self {
+        Self {
+            previous: CURRENT.with(|current| current.replace(context)),
+        }
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Whether the values of a column are redacted in the current unmask context
+pub(crate) fn is_redacted(def: &ColumnDef) -> bool {
+    def.is_masked() && !UnmaskContext::current().is_some_and(|context| context.can_see(def))
+}
+
+/// A future executing statements that bind the values of masked columns, see [redact_logged]
+#[derive(Debug)]
+pub(crate) struct RedactLogged<F> {
+    values: Vec<Value>,
+    future: Pin<Box<F>>,
+}
+
+/// Run a future executing statements that bind the values of masked columns, so that the
+/// statements it logs have the values redacted
+pub(crate) fn redact_logged<F>(mut values: Vec<Value>, future: F) -> RedactLogged<F>
+where
+    F: Future,
+{
+    // NULL is no secret, and would redact every NULL bound
+    values.retain(|value| *value != value.as_null());
+    RedactLogged {
+        values,
+        future: Box::pin(future),
+    }
+}
+
+impl<F> Future for RedactLogged<F>
+where
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let _guard = LoggedGuard::enter(&this.values);
+        this.future.as_mut().poll(cx)
+    }
+}
+
+/// Restores the previous values to redact in statement logs when dropped
+struct LoggedGuard {
+    len: usize,
+}
+
+impl LoggedGuard {
+    fn enter(values: &[Value]) -> Self {
+        LOGGED.with(|logged| {
+            let mut logged = logged.borrow_mut();
+            let len = logged.len();
+            logged.extend_from_slice(values);
+            Self { len }
+        })
+    }
+}
+
+impl Drop for LoggedGuard {
+    fn drop(&mut self) {
+        LOGGED.with(|logged| logged.borrow_mut().truncate(self.len));
+    }
+}
+
+/// Replace the values of masked columns bound by the statements being executed with
+/// [MASKED_VALUE], before a statement is logged
+pub(crate) fn redact_logged_values(values: &mut [Value]) {
+    LOGGED.with(|logged| {
+        let logged = logged.borrow();
+        if logged.is_empty() {
+            return;
+        }
+        for value in values.iter_mut() {
+            if logged.contains(value) {
+                *value = MASKED_VALUE.into();
+            }
+        }
+    });
+}
+
+/// The masked columns of an entity that can't be seen in the current unmask context
+#[cfg(feature = "with-json")]
+pub(crate) fn redacted_columns<E>() -> Vec<E::Column>
+where
+    E: EntityTrait,
+{
+    E::Column::iter()
+        .filter(|col| is_redacted(&col.def()))
+        .collect()
+}
+
+/// The value of a field of a Model, debug printed and serialized as [MASKED_VALUE] if its
+/// column is redacted in the current unmask context. Used by `DeriveEntityModel`.
+#[doc(hidden)]
+pub struct MaskedValue<'a, C, T>
+where
+    T: ?Sized,
+{
+    column: C,
+    value: &'a T,
+}
+
+impl<'a, C, T> MaskedValue<'a, C, T>
+where
+    C: ColumnTrait,
+    T: ?Sized,
+{
+    /// Wrap the value of a column
+    pub fn new(column: C, value: &'a T) -> Self {
+        Self { column, value }
+    }
+}
+
+impl<C, T> fmt::Debug for MaskedValue<'_, C, T>
+where
+    C: ColumnTrait,
+    T: fmt::Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if is_redacted(&self.column.def()) {
+            fmt::Debug::fmt(MASKED_VALUE, f)
+        } else {
+            fmt::Debug::fmt(self.value, f)
+        }
+    }
+}
+
+impl<C, T> Serialize for MaskedValue<'_, C, T>
+where
+    C: ColumnTrait,
+    T: Serialize + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if is_redacted(&self.column.def()) {
+            serializer.serialize_str(MASKED_VALUE)
+        } else {
+            self.value.serialize(serializer)
+        }
+    }
+}
+
+/// Replace the values of the redacted columns of an entity in a JSON object, by column name
+#[cfg(feature = "with-json")]
+pub(crate) fn redact_json<E>(json: &mut JsonValue)
+where
+    E: EntityTrait,
+{
+    let JsonValue::Object(object) = json else {
+        return;
+    };
+    for col in redacted_columns::<E>() {
+        if let Some(value) = object.get_mut(col.as_str()) {
+            *value = MASKED_VALUE.into();
+        }
+    }
+}
+
+/// A model serialized, and debug printed as JSON, with its masked columns redacted, see
+/// [UnmaskContext].
+///
+/// Fields are matched to columns by column name, so keep the serialized field names the same as
+/// the column names.
+#[cfg(feature = "with-json")]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Masked<'a, M> {
+    model: &'a M,
+}
+
+#[cfg(feature = "with-json")]
+impl<'a, M> Masked<'a, M>
+where
+    M: ModelTrait + Serialize,
+{
+    /// Wrap a model
+    pub fn new(model: &'a M) -> Self {
+        Self { model }
+    }
+
+    /// Wrap models
+    pub fn all(models: &'a [M]) -> Vec<Self> {
+        models.iter().map(Self::new).collect()
+    }
+
+    /// The JSON of the model, with the redacted columns replaced by [MASKED_VALUE]
+    pub fn to_json(&self) -> Result<JsonValue, DbErr> {
+        let mut json =
+            serde_json::to_value(self.model).map_err(|err| DbErr::Json(err.to_string()))?;
+        redact_json::<M::Entity>(&mut json);
+        Ok(json)
+    }
+}
+
+#[cfg(feature = "with-json")]
+impl<M> Serialize for Masked<'_, M>
+where
+    M: ModelTrait + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_json()
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "with-json")]
+impl<M> fmt::Debug for Masked<'_, M>
+where
+    M: ModelTrait + Serialize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_json() {
+            Ok(json) => write!(f, "{json}"),
+            Err(err) => write!(f, "<{err}>"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "macros", feature = "with-json"))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    mod employee {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+        use serde::Serialize;
+
+        #[derive(Clone, PartialEq, DeriveEntityModel, Serialize)]
+        #[sea_orm(table_name = "employee")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(masked)]
+            pub email: String,
+            #[sea_orm(masked = "hr, payroll")]
+            pub ssn: Option<String>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod account {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "account")]
+        #[sea_orm(serde(rename_all = "camelCase"))]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(masked = "admin")]
+            pub password_hash: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn alice() -> employee::Model {
+        employee::Model {
+            id: 1,
+            email: "alice@example.com".to_owned(),
+            ssn: None,
+        }
+    }
+
+    #[test]
+    fn masked_by_default() {
+        assert_eq!(
+            Masked::new(&alice()).to_json().unwrap(),
+            json!({ "id": 1, "email": "***", "ssn": "***" })
+        );
+        assert_eq!(
+            serde_json::to_value(Masked::all(&[alice()])).unwrap(),
+            json!([{ "id": 1, "email": "***", "ssn": "***" }])
+        );
+    }
+
+    #[test]
+    fn unmasked_by_role() {
+        UnmaskContext::new().sync_scope(|| {
+            assert_eq!(
+                Masked::new(&alice()).to_json().unwrap(),
+                json!({ "id": 1, "email": "alice@example.com", "ssn": "***" })
+            );
+        });
+        UnmaskContext::new().role("payroll").sync_scope(|| {
+            assert_eq!(
+                Masked::new(&alice()).to_json().unwrap(),
+                json!({ "id": 1, "email": "alice@example.com", "ssn": null })
+            );
+        });
+        assert_eq!(UnmaskContext::current(), None);
+    }
+
+    #[test]
+    fn derived_debug_and_serialize() {
+        let account = account::Model {
+            id: 1,
+            password_hash: "$argon2id$v=19$...".to_owned(),
+        };
+        assert_eq!(
+            format!("{:?}", alice()),
+            r#"Model { id: 1, email: "***", ssn: "***" }"#
+        );
+        assert_eq!(
+            serde_json::to_value(&account).unwrap(),
+            json!({ "id": 1, "passwordHash": "***" })
+        );
+        UnmaskContext::new().role("admin").sync_scope(|| {
+            assert_eq!(
+                format!("{:?}", alice()),
+                r#"Model { id: 1, email: "alice@example.com", ssn: "***" }"#
+            );
+            assert_eq!(
+                serde_json::to_value(&account).unwrap(),
+                json!({ "id": 1, "passwordHash": "$argon2id$v=19$..." })
+            );
+        });
+        // deserializing is not affected
+        assert_eq!(
+            serde_json::from_value::<account::Model>(json!({ "id": 1, "passwordHash": "hash" }))
+                .unwrap()
+                .password_hash,
+            "hash"
+        );
+    }
+
+    #[smol_potat::test]
+    async fn redacted_in_statement_log() {
+        use crate::{DbBackend, EntityTrait, IntoActiveModel, QueryTrait};
+
+        let insert = employee::Entity::insert(alice().into_active_model());
+        let statement = insert.build(DbBackend::Postgres);
+        let sql = r#"INSERT INTO "employee" ("id", "email", "ssn") VALUES (1, 'alice@example.com', NULL)"#;
+        assert_eq!(statement.to_string(), sql);
+        assert_eq!(
+            redact_logged(insert.masked, async { statement.to_string() }).await,
+            r#"INSERT INTO "employee" ("id", "email", "ssn") VALUES (1, '***', NULL)"#
+        );
+        assert_eq!(statement.to_string(), sql);
+    }
+
+    #[smol_potat::test]
+    async fn unmasked_in_scope() {
+        let json = UnmaskContext::new()
+            .scope(async { Masked::new(&alice()).to_json() })
+            .await
+            .unwrap();
+        assert_eq!(json["email"], "alice@example.com");
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn select_into_json() -> Result<(), DbErr> {
+        use crate::{DbBackend, EntityTrait, MockDatabase, Value};
+
+        let row = maplit::btreemap! {
+            "id" => Into::<Value>::into(1),
+            "email" => Into::<Value>::into("alice@example.com"),
+            "ssn" => Into::<Value>::into("123-45-6789"),
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[row.clone()], [row]])
+            .into_connection();
+
+        assert_eq!(
+            employee::Entity::find().into_json().all(&db).await?,
+            [json!({ "id": 1, "email": "***", "ssn": "***" })]
+        );
+        assert_eq!(
+            UnmaskContext::new()
+                .role("hr")
+                .scope(employee::Entity::find().into_json().one(&db))
+                .await?,
+            Some(json!({ "id": 1, "email": "alice@example.com", "ssn": "123-45-6789" }))
+        );
+
+        Ok(())
+    }
+}
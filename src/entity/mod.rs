@@ -99,24 +99,60 @@
 mod active_enum;
 mod active_model;
 mod base_entity;
+mod changeset;
 mod column;
+mod expiry;
+mod factory;
+mod fixture;
+mod hierarchy;
 mod identity;
+#[cfg(feature = "with-json")]
+mod json_policy;
 mod link;
+#[cfg(feature = "with-json")]
+mod localize;
+mod mask;
 mod model;
+mod obfuscated_id;
 mod partial_model;
 /// Re-export common types from the entity
 pub mod prelude;
 mod primary_key;
 mod relation;
+mod soft_delete;
+mod state_machine;
+mod tenant;
+mod timestamp;
+mod tree;
+mod validate;
+mod versioned;
 
 pub use active_enum::*;
 pub use active_model::*;
 pub use base_entity::*;
+pub use changeset::*;
 pub use column::*;
+pub use expiry::*;
+pub use factory::*;
+pub use fixture::*;
+pub use hierarchy::*;
 pub use identity::*;
+#[cfg(feature = "with-json")]
+pub use json_policy::*;
 pub use link::*;
+#[cfg(feature = "with-json")]
+pub use localize::*;
+pub use mask::*;
 pub use model::*;
+pub use obfuscated_id::*;
 pub use partial_model::*;
 // pub use prelude::*;
 pub use primary_key::*;
 pub use relation::*;
+pub(crate) use soft_delete::*;
+pub use state_machine::*;
+pub use tenant::*;
+pub use timestamp::*;
+pub use tree::*;
+pub use validate::*;
+pub use versioned::*;
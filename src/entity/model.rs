@@ -1,7 +1,7 @@
 use crate::{
-    ActiveModelBehavior, ActiveModelTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
-    IntoActiveModel, Linked, QueryFilter, QueryResult, Related, Select, SelectModel, SelectorRaw,
-    Statement, TryGetError,
+    ActiveModelBehavior, ActiveModelTrait, Changeset, ConnectionTrait, DbErr, DeleteResult,
+    EntityTrait, IntoActiveModel, Linked, QueryFilter, QueryResult, Related, Select, SelectModel,
+    SelectorRaw, StateMachine, Statement, TransitionError, TryGetError, TryGetable,
 };
 use async_trait::async_trait;
 pub use sea_query::Value;
@@ -19,6 +19,12 @@ pub trait ModelTrait: Clone + Send + Debug {
     /// Set the [Value] of a column in an Entity
     fn set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: Value);
 
+    /// The columns whose values differ from those of an older version of the model, see
+    /// [Changeset]
+    fn diff(&self, old: &Self) -> Changeset {
+        Changeset::from_models(self, old)
+    }
+
     /// Find related Models
     fn find_related<R>(&self, _: R) -> Select<R>
     where
@@ -46,6 +52,138 @@ pub trait ModelTrait: Clone + Send + Debug {
     {
         self.into_active_model().delete(db).await
     }
+
+    /// Move the [StateMachine] in a column to another state, returning the updated model.
+    ///
+    /// The row is only updated if it is still in the state of the model, so of two concurrent
+    /// transitions from the same state one fails with [TransitionError::Stale].
+    ///
+    /// ```
+    /// # use sea_orm::{entity::prelude::*, error::*, *};
+    /// # mod order {
+    /// #     use sea_orm::{entity::prelude::*, DeriveStateMachine};
+    /// #     #[derive(Clone, Debug, PartialEq, EnumIter, DeriveActiveEnum, DeriveStateMachine)]
+    /// #     #[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+    /// #     #[transition(from = "Pending", to = "Paid")]
+    /// #     pub enum Status {
+    /// #         #[sea_orm(string_value = "pending")]
+    /// #         Pending,
+    /// #         #[sea_orm(string_value = "paid")]
+    /// #         Paid,
+    /// #     }
+    /// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    /// #     #[sea_orm(table_name = "order")]
+    /// #     pub struct Model {
+    /// #         #[sea_orm(primary_key)]
+    /// #         pub id: i32,
+    /// #         pub status: Status,
+    /// #     }
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    /// # use order::Status;
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 1 },
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 0 },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// let order = order::Model { id: 1, status: Status::Pending };
+    ///
+    /// let paid = order.clone().transition(order::Column::Status, Status::Paid, &db).await;
+    /// assert_eq!(paid.unwrap().status, Status::Paid);
+    ///
+    /// // another transaction got there first
+    /// let paid = order.clone().transition(order::Column::Status, Status::Paid, &db).await;
+    /// assert!(matches!(paid, Err(TransitionError::Stale { .. })));
+    ///
+    /// // not an allowed transition
+    /// let order = order::Model { id: 1, status: Status::Paid };
+    /// let pending = order.transition(order::Column::Status, Status::Pending, &db).await;
+    /// assert!(matches!(pending, Err(TransitionError::NotAllowed { .. })));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log()[0],
+    ///     Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"UPDATE "order" SET "status" = $1 WHERE "order"."status" = $2 AND "order"."id" = $3"#,
+    ///         ["paid".into(), "pending".into(), 1i32.into()]
+    ///     )
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn transition<'a, S, C>(
+        self,
+        col: <Self::Entity as EntityTrait>::Column,
+        to: S,
+        db: &'a C,
+    ) -> Result<Self, TransitionError<S>>
+    where
+        S: StateMachine + 'a,
+        C: ConnectionTrait,
+    {
+        crate::transition(self, col, to, db).await
+    }
+
+    /// Add to a numeric column of the row of this model in the database, without reading it
+    /// first, and return the new value. Uses `RETURNING` where supported; on MySQL the column
+    /// is selected after the update. The model itself is left as it is.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "cake_id" => Value::from(5),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// let fruit = fruit::Model {
+    ///     id: 1,
+    ///     name: "Apple".to_owned(),
+    ///     cake_id: Some(3),
+    /// };
+    ///
+    /// let cake_id: i32 = fruit.increment(fruit::Column::CakeId, 2, &db).await?;
+    /// assert_eq!(cake_id, 5);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"UPDATE "fruit" SET "cake_id" = "fruit"."cake_id" + $1 WHERE "fruit"."id" = $2 RETURNING "cake_id""#,
+    ///         [2i32.into(), 1i32.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn increment<'a, T, C>(
+        &self,
+        col: <Self::Entity as EntityTrait>::Column,
+        by: T,
+        db: &'a C,
+    ) -> Result<T, DbErr>
+    where
+        T: Into<Value> + TryGetable + Send + 'a,
+        C: ConnectionTrait,
+    {
+        crate::increment(self, col, by, db).await
+    }
 }
 
 /// A Trait for implementing a [QueryResult]
@@ -83,6 +221,15 @@ pub trait FromQueryResult: Sized {
         Self::from_query_result(res, pre).map_err(TryGetError::DbErr)
     }
 
+    /// Merge the next row into this one when they are the same, returning the next row
+    /// otherwise. `all` merges consecutive rows.
+    ///
+    /// Derived for structs with `#[sea_orm(nested)]` `Vec` fields: the items of the next row are
+    /// appended when the other fields are equal, so a one-to-many join hydrates a collection.
+    fn merge_row(&mut self, next: Self) -> Option<Self> {
+        Some(next)
+    }
+
     /// ```
     /// # use sea_orm::{error::*, tests_cfg::*, *};
     /// #
@@ -162,6 +309,32 @@ impl<T: FromQueryResult> FromQueryResult for Option<T> {
     }
 }
 
+/// One item per row, none when the nested columns are null, see [FromQueryResult::merge_row]
+impl<T: FromQueryResult> FromQueryResult for Vec<T> {
+    fn from_query_result(res: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        Ok(Self::from_query_result_nullable(res, pre)?)
+    }
+
+    fn from_query_result_nullable(res: &QueryResult, pre: &str) -> Result<Self, TryGetError> {
+        match T::from_query_result_nullable(res, pre) {
+            Ok(v) => Ok(vec![v]),
+            Err(TryGetError::Null(_)) => Ok(Vec::new()),
+            Err(err @ TryGetError::DbErr(_)) => Err(err),
+        }
+    }
+
+    fn merge_row(&mut self, next: Self) -> Option<Self> {
+        for item in next {
+            let item = match self.last_mut() {
+                Some(last) => last.merge_row(item),
+                None => Some(item),
+            };
+            self.extend(item);
+        }
+        None
+    }
+}
+
 /// A Trait for any type that can be converted into an Model
 pub trait TryIntoModel<M>
 where
@@ -0,0 +1,320 @@
+use crate::{ColIdx, DbErr, QueryResult, TryFromU64, TryGetError, TryGetable};
+use sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr, sync::OnceLock};
+
+static CODEC: OnceLock<IdCodec> = OnceLock::new();
+
+/// The alphabet of the default [IdCodec]
+pub const DEFAULT_ID_ALPHABET: &str =
+    "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Encodes integers to short strings and back, for [ObfuscatedId].
+///
+/// The alphabet is shuffled by the salt, and again by the first character of each encoded id,
+/// so consecutive ids don't look consecutive. This hides the ids, it does not encrypt them.
+///
+/// ```
+/// use sea_orm::IdCodec;
+///
+/// let codec = IdCodec::new("my salt").unwrap();
+/// let encoded = codec.encode(42);
+/// assert_ne!(encoded, codec.encode(43));
+/// assert_eq!(codec.decode(&encoded), Some(42));
+/// assert_eq!(IdCodec::default().decode(&encoded), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdCodec {
+    alphabet: Vec<char>,
+}
+
+impl Default for IdCodec {
+    fn default() -> Self {
+        Self {
+            alphabet: DEFAULT_ID_ALPHABET.chars().collect(),
+        }
+    }
+}
+
+impl IdCodec {
+    /// Create a codec with the default alphabet and a salt
+    pub fn new(salt: &str) -> Result<Self, DbErr> {
+        Self::with_alphabet(DEFAULT_ID_ALPHABET, salt)
+    }
+
+    /// Create a codec with an alphabet of at least 16 distinct characters and a salt
+    pub fn with_alphabet(alphabet: &str, salt: &str) -> Result<Self, DbErr> {
+        let mut chars: Vec<char> = Vec::new();
+        for c in alphabet.chars() {
+            if chars.contains(&c) {
+                return Err(DbErr::Custom(format!(
+                    "Id alphabet contains `{c}` more than once"
+                )));
+            }
+            chars.push(c);
+        }
+        if chars.len() < 16 {
+            return Err(DbErr::Custom(
+                "Id alphabet must have at least 16 characters".to_owned(),
+            ));
+        }
+        shuffle(&mut chars, &salt.chars().collect::<Vec<_>>());
+        Ok(Self { alphabet: chars })
+    }
+
+    /// Use this codec for every [ObfuscatedId]. Fails with the codec if one was set already, or
+    /// an [ObfuscatedId] was encoded or decoded with the default codec.
+    pub fn install(self) -> Result<(), Self> {
+        CODEC.set(self)
+    }
+
+    /// The codec used by [ObfuscatedId]
+    pub fn global() -> &'static Self {
+        CODEC.get_or_init(Self::default)
+    }
+
+    /// Encode an integer
+    pub fn encode(&self, n: u64) -> String {
+        let base = self.alphabet.len() as u64;
+        let lottery = self.alphabet[(n % base) as usize];
+        let alphabet = self.alphabet_for(lottery);
+
+        let mut digits = Vec::new();
+        let mut rest = n;
+        loop {
+            digits.push(alphabet[(rest % base) as usize]);
+            rest /= base;
+            if rest == 0 {
+                break;
+            }
+        }
+        std::iter::once(lottery)
+            .chain(digits.into_iter().rev())
+            .collect()
+    }
+
+    /// Decode an encoded integer, `None` if it was not encoded by this codec
+    pub fn decode(&self, s: &str) -> Option<u64> {
+        let mut chars = s.chars();
+        let lottery = chars.next()?;
+        if !self.alphabet.contains(&lottery) {
+            return None;
+        }
+        let alphabet = self.alphabet_for(lottery);
+        let base = alphabet.len() as u64;
+
+        let mut n: u64 = 0;
+        let mut any = false;
+        for c in chars {
+            let digit = alphabet.iter().position(|a| *a == c)? as u64;
+            n = n.checked_mul(base)?.checked_add(digit)?;
+            any = true;
+        }
+        (any && self.encode(n) == s).then_some(n)
+    }
+
+    fn alphabet_for(&self, lottery: char) -> Vec<char> {
+        let mut alphabet = self.alphabet.clone();
+        let mut salt = vec![lottery];
+        salt.extend_from_slice(&self.alphabet);
+        shuffle(&mut alphabet, &salt);
+        alphabet
+    }
+}
+
+/// The consistent shuffle of hashids
+fn shuffle(alphabet: &mut [char], salt: &[char]) {
+    if salt.is_empty() {
+        return;
+    }
+    let mut v = 0;
+    let mut p = 0;
+    for i in (1..alphabet.len()).rev() {
+        v %= salt.len();
+        let a = salt[v] as usize;
+        p += a;
+        let j = (a + v + p) % i;
+        alphabet.swap(i, j);
+        v += 1;
+    }
+}
+
+/// An integer id, stored as is, that is displayed and serialized encoded by the global
+/// [IdCodec], so sequential ids aren't exposed in public APIs.
+///
+/// It is created from the integer, or parsed from the encoded string, so `find_by_id` takes
+/// either form.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, query::*, DbBackend, ObfuscatedId};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "user")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: ObfuscatedId<i64>,
+///     pub name: String,
+/// }
+/// #
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+///
+/// let id = ObfuscatedId::new(7i64);
+/// let encoded = id.to_string();
+/// assert_eq!(encoded.parse::<ObfuscatedId<i64>>().unwrap(), id);
+///
+/// let by_id = Entity::find_by_id(7);
+/// let by_encoded = Entity::find_by_id(encoded.parse::<ObfuscatedId<i64>>().unwrap());
+/// assert_eq!(
+///     by_id.build(DbBackend::Postgres).to_string(),
+///     r#"SELECT "user"."id", "user"."name" FROM "user" WHERE "user"."id" = 7"#
+/// );
+/// assert_eq!(
+///     by_encoded.build(DbBackend::Postgres).to_string(),
+///     by_id.build(DbBackend::Postgres).to_string()
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObfuscatedId<T>(T);
+
+impl<T> ObfuscatedId<T> {
+    /// Wrap an integer id
+    pub fn new(id: T) -> Self {
+        Self(id)
+    }
+
+    /// The integer id
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for ObfuscatedId<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for ObfuscatedId<T> {
+    fn from(id: T) -> Self {
+        Self(id)
+    }
+}
+
+macro_rules! obfuscated_id {
+    ( $type: ty ) => {
+        impl ObfuscatedId<$type> {
+            /// The id encoded by the global [IdCodec]
+            pub fn encode(&self) -> String {
+                IdCodec::global().encode(self.0 as u64)
+            }
+        }
+
+        impl fmt::Display for ObfuscatedId<$type> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.encode())
+            }
+        }
+
+        impl FromStr for ObfuscatedId<$type> {
+            type Err = DbErr;
+
+            fn from_str(s: &str) -> Result<Self, DbErr> {
+                IdCodec::global()
+                    .decode(s)
+                    .and_then(|n| <$type as TryFrom<_>>::try_from(n as i64).ok())
+                    .map(Self)
+                    .ok_or_else(|| DbErr::Type(format!("`{s}` is not an obfuscated id")))
+            }
+        }
+
+        impl Serialize for ObfuscatedId<$type> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(&self.encode())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for ObfuscatedId<$type> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl de::Visitor<'_> for Visitor {
+                    type Value = ObfuscatedId<$type>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str("an obfuscated id")
+                    }
+
+                    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                        s.parse().map_err(E::custom)
+                    }
+
+                    fn visit_i64<E: de::Error>(self, n: i64) -> Result<Self::Value, E> {
+                        <$type as TryFrom<_>>::try_from(n).map(ObfuscatedId).map_err(E::custom)
+                    }
+
+                    fn visit_u64<E: de::Error>(self, n: u64) -> Result<Self::Value, E> {
+                        <$type as TryFrom<_>>::try_from(n).map(ObfuscatedId).map_err(E::custom)
+                    }
+                }
+
+                deserializer.deserialize_any(Visitor)
+            }
+        }
+
+        impl From<ObfuscatedId<$type>> for Value {
+            fn from(id: ObfuscatedId<$type>) -> Self {
+                id.0.into()
+            }
+        }
+
+        impl ValueType for ObfuscatedId<$type> {
+            fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+                <$type as ValueType>::try_from(v).map(Self)
+            }
+
+            fn type_name() -> String {
+                format!("ObfuscatedId<{}>", stringify!($type))
+            }
+
+            fn array_type() -> ArrayType {
+                <$type as ValueType>::array_type()
+            }
+
+            fn column_type() -> ColumnType {
+                <$type as ValueType>::column_type()
+            }
+        }
+
+        impl Nullable for ObfuscatedId<$type> {
+            fn null() -> Value {
+                <$type as Nullable>::null()
+            }
+        }
+
+        impl TryGetable for ObfuscatedId<$type> {
+            fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+                <$type as TryGetable>::try_get_by(res, index).map(Self)
+            }
+        }
+
+        impl TryFromU64 for ObfuscatedId<$type> {
+            fn try_from_u64(n: u64) -> Result<Self, DbErr> {
+                <$type as TryFromU64>::try_from_u64(n).map(Self)
+            }
+        }
+    };
+}
+
+obfuscated_id!(i32);
+obfuscated_id!(i64);
@@ -1,4 +1,5 @@
 use crate::{FromQueryResult, SelectColumns};
+use std::marker::PhantomData;
 
 /// A trait for a part of [Model](super::model::ModelTrait)
 pub trait PartialModelTrait: FromQueryResult {
@@ -27,3 +28,63 @@ impl<T: PartialModelTrait> PartialModelTrait for Option<T> {
         T::select_cols_nested(select, prefix)
     }
 }
+
+impl<T: PartialModelTrait> PartialModelTrait for Vec<T> {
+    fn select_cols<S: SelectColumns>(select: S) -> S {
+        Self::select_cols_nested(select, None)
+    }
+
+    fn select_cols_nested<S: SelectColumns>(select: S, prefix: Option<&str>) -> S {
+        T::select_cols_nested(select, prefix)
+    }
+}
+
+/// A [PartialModelTrait] whose columns are selected with a context of the query, for
+/// expressions with runtime parameters, like the fields `#[sea_orm(from_expr_fn = "..")]` of a
+/// `DerivePartialModel` with `#[sea_orm(context = "..")]`
+pub trait PartialModelWithContext: FromQueryResult {
+    /// The context the columns are selected with
+    type Context;
+
+    /// Select specific columns this [PartialModel] needs, with the context
+    fn select_cols_with<S: SelectColumns>(select: S, context: &Self::Context) -> S;
+
+    /// Used when nesting these structs into each other, with the context
+    fn select_cols_nested_with<S: SelectColumns>(
+        select: S,
+        prefix: Option<&str>,
+        context: &Self::Context,
+    ) -> S;
+}
+
+/// Whether a field of a partial model can hold the values of a column, whose Rust type is `T` in
+/// `PhantomData<T>`: the same type, or either one wrapped in an [Option]. Checked by
+/// `DerivePartialModel` for the columns of entities derived with `DeriveEntityModel`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the field of type `{F}` can't hold the column of type `{Self}`",
+    label = "the type of the field doesn't match the column"
+)]
+pub trait PartialModelField<F> {}
+
+impl<T> PartialModelField<T> for PhantomData<T> {}
+
+impl<T> PartialModelField<Option<T>> for PhantomData<T> {}
+
+impl<T> PartialModelField<T> for PhantomData<Option<T>> {}
+
+/// A column of unknown Rust type, not checked
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct UncheckedColumn;
+
+impl<F> PartialModelField<F> for UncheckedColumn {}
+
+/// Check at compile time that a field of a partial model can hold a column, see
+/// [PartialModelField]
+#[doc(hidden)]
+pub fn check_partial_model_field<F, C>(_: C)
+where
+    C: PartialModelField<F>,
+{
+}
@@ -105,6 +105,7 @@ impl_pk_arity!(12, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
 mod tests {
     #[test]
     #[cfg(feature = "macros")]
+    #[allow(dead_code)]
     fn test_composite_primary_key() {
         mod primary_key_of_1 {
             use crate as sea_orm;
@@ -1,8 +1,10 @@
-use crate::{unpack_table_ref, EntityTrait, Identity, IdentityOf, Iterable, QuerySelect, Select};
+use crate::{
+    unpack_table_ref, EntityTrait, Identity, IdentityOf, Iterable, QuerySelect, Schema, Select,
+};
 use core::marker::PhantomData;
 use sea_query::{
-    Alias, Condition, ConditionType, DynIden, ForeignKeyCreateStatement, IntoIden, JoinType, SeaRc,
-    TableForeignKey, TableRef,
+    Alias, Condition, ConditionType, DynIden, ForeignKeyCreateStatement, IndexCreateStatement,
+    IntoIden, JoinType, SeaRc, TableCreateStatement, TableForeignKey, TableRef, Value,
 };
 use std::fmt::Debug;
 
@@ -70,6 +72,60 @@ pub struct RelationDef {
     pub fk_name: Option<String>,
     /// Condition type of join on expression
     pub condition_type: ConditionType,
+    /// The type column of a polymorphic relation, set with [RelationBuilder::morph]
+    pub(crate) morph: Option<Morph>,
+    /// The entity of `from_tbl`, if the relation is built from the entity types
+    pub(crate) from_entity: Option<EntityRef>,
+    /// The entity of `to_tbl`, if the relation is built from the entity types
+    pub(crate) to_entity: Option<EntityRef>,
+}
+
+/// An entity type, erased to walk the relations between entities, e.g. to create the tables
+/// of related entities in [TestDb](crate::testing::TestDb)
+#[derive(Clone, Copy)]
+#[cfg_attr(not(feature = "sqlx-sqlite"), allow(dead_code))]
+pub(crate) struct EntityRef {
+    /// The name of the table, qualified by the schema if it has one
+    pub(crate) table_name: fn() -> String,
+    pub(crate) create_table: fn(&Schema) -> TableCreateStatement,
+    pub(crate) create_indexes: fn(&Schema) -> Vec<IndexCreateStatement>,
+    pub(crate) relations: fn() -> Vec<RelationDef>,
+}
+
+impl EntityRef {
+    pub(crate) fn of<E>() -> Self
+    where
+        E: EntityTrait,
+    {
+        Self {
+            table_name: || {
+                let entity = E::default();
+                match entity.schema_name() {
+                    Some(schema) => format!("{schema}.{}", entity.table_name()),
+                    None => entity.table_name().to_owned(),
+                }
+            },
+            create_table: |schema| schema.create_table_from_entity(E::default()),
+            create_indexes: |schema| schema.create_index_from_entity(E::default()),
+            relations: || E::Relation::iter().map(|rel| rel.def()).collect(),
+        }
+    }
+}
+
+/// The type column of a polymorphic relation, where a pair of a type and an id column relates
+/// a row to one of several entities, like a comment on either a post or a photo. The relation
+/// only holds for the rows whose type column has the value naming the related entity.
+///
+/// It is set on the `belongs_to` side, with `#[sea_orm(morph_type, morph_value)]`; the reverse
+/// `has_one` or `has_many` relations carry it over. No foreign key is created for it.
+#[derive(Debug, Clone)]
+pub struct Morph {
+    /// The type column
+    pub col: DynIden,
+    /// The value of the type column naming the related entity
+    pub value: Value,
+    /// Whether the type column is in the `from_tbl` of the relation, instead of `to_tbl`
+    pub in_from_tbl: bool,
 }
 
 impl std::fmt::Debug for RelationDef {
@@ -84,7 +140,9 @@ impl std::fmt::Debug for RelationDef {
             .field("on_delete", &self.on_delete)
             .field("on_update", &self.on_update);
         debug_on_condition(&mut d, &self.on_condition);
-        d.field("fk_name", &self.fk_name).finish()
+        d.field("fk_name", &self.fk_name)
+            .field("morph", &self.morph)
+            .finish()
     }
 }
 
@@ -126,6 +184,9 @@ where
     on_condition: Option<Box<dyn Fn(DynIden, DynIden) -> Condition + Send + Sync>>,
     fk_name: Option<String>,
     condition_type: ConditionType,
+    morph: Option<Morph>,
+    from_entity: Option<EntityRef>,
+    to_entity: Option<EntityRef>,
 }
 
 impl<E, R> std::fmt::Debug for RelationBuilder<E, R>
@@ -145,7 +206,9 @@ where
             .field("on_delete", &self.on_delete)
             .field("on_update", &self.on_update);
         debug_on_condition(&mut d, &self.on_condition);
-        d.field("fk_name", &self.fk_name).finish()
+        d.field("fk_name", &self.fk_name)
+            .field("morph", &self.morph)
+            .finish()
     }
 }
 
@@ -164,9 +227,20 @@ impl RelationDef {
             on_condition: self.on_condition,
             fk_name: None,
             condition_type: self.condition_type,
+            morph: self.morph.map(|morph| Morph {
+                in_from_tbl: !morph.in_from_tbl,
+                ..morph
+            }),
+            from_entity: self.to_entity,
+            to_entity: self.from_entity,
         }
     }
 
+    /// The type column of a polymorphic relation, if it is one
+    pub fn morph(&self) -> Option<&Morph> {
+        self.morph.as_ref()
+    }
+
     /// Express the relation from a table alias.
     ///
     /// This is a shorter and more discoverable equivalent to modifying `from_tbl` field by hand.
@@ -316,6 +390,9 @@ where
             on_condition: None,
             fk_name: None,
             condition_type: ConditionType::All,
+            morph: None,
+            from_entity: Some(EntityRef::of::<E>()),
+            to_entity: Some(EntityRef::of::<R>()),
         }
     }
 
@@ -328,11 +405,14 @@ where
             from_col: Some(rel.from_col),
             to_col: Some(rel.to_col),
             is_owner,
-            on_delete: None,
-            on_update: None,
+            on_delete: rel.on_delete,
+            on_update: rel.on_update,
             on_condition: None,
             fk_name: None,
             condition_type: ConditionType::All,
+            morph: rel.morph,
+            from_entity: rel.from_entity,
+            to_entity: rel.to_entity,
         }
     }
 
@@ -389,6 +469,20 @@ where
         self.condition_type = condition_type;
         self
     }
+
+    /// Make the relation polymorphic: it only holds for the rows whose type column has the
+    /// value naming the related entity, see [Morph]
+    pub fn morph<V>(mut self, col: E::Column, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.morph = Some(Morph {
+            col: col.into_iden(),
+            value: value.into(),
+            in_from_tbl: true,
+        });
+        self
+    }
 }
 
 impl<E, R> From<RelationBuilder<E, R>> for RelationDef
@@ -409,6 +503,9 @@ where
             on_condition: b.on_condition,
             fk_name: b.fk_name,
             condition_type: b.condition_type,
+            morph: b.morph,
+            from_entity: b.from_entity,
+            to_entity: b.to_entity,
         }
     }
 }
@@ -456,29 +553,18 @@ impl From<RelationDef> for ForeignKeyCreateStatement {
 
 /// Creates a column definition for example to update a table.
 /// ```
-/// use sea_query::{Alias, IntoIden, MysqlQueryBuilder, TableAlterStatement, TableRef, ConditionType};
-/// use sea_orm::{EnumIter, Iden, Identity, PrimaryKeyTrait, RelationDef, RelationTrait, RelationType};
+/// use sea_query::{Alias, IntoIden, MysqlQueryBuilder, TableAlterStatement, TableRef};
+/// use sea_orm::{tests_cfg::cake_filling, RelationTrait};
 ///
-/// let relation = RelationDef {
-///     rel_type: RelationType::HasOne,
-///     from_tbl: TableRef::Table(Alias::new("foo").into_iden()),
-///     to_tbl: TableRef::Table(Alias::new("bar").into_iden()),
-///     from_col: Identity::Unary(Alias::new("bar_id").into_iden()),
-///     to_col: Identity::Unary(Alias::new("bar_id").into_iden()),
-///     is_owner: false,
-///     on_delete: None,
-///     on_update: None,
-///     on_condition: None,
-///     fk_name: Some("foo-bar".to_string()),
-///     condition_type: ConditionType::All,
-/// };
+/// let mut relation = cake_filling::Relation::Cake.def();
+/// relation.fk_name = Some("foo-bar".to_string());
 ///
 /// let mut alter_table = TableAlterStatement::new()
-///     .table(TableRef::Table(Alias::new("foo").into_iden()))
+///     .table(TableRef::Table(Alias::new("cake_filling").into_iden()))
 ///     .add_foreign_key(&mut relation.into()).take();
 /// assert_eq!(
 ///     alter_table.to_string(MysqlQueryBuilder::default()),
-///     "ALTER TABLE `foo` ADD CONSTRAINT `foo-bar` FOREIGN KEY (`bar_id`) REFERENCES `bar` (`bar_id`)"
+///     "ALTER TABLE `cake_filling` ADD CONSTRAINT `foo-bar` FOREIGN KEY (`cake_id`) REFERENCES `cake` (`id`)"
 /// );
 /// ```
 impl From<RelationDef> for TableForeignKey {
@@ -0,0 +1,179 @@
+use crate::{ColumnTrait, EntityTrait, Iterable, Refusal};
+use sea_query::{Alias, Condition, DynIden, Expr, IntoIden, Keyword, SimpleExpr};
+
+/// Stands in for the soft delete column of an entity without one, in the selects of its deleted
+/// rows and in its restores. It matches no rows, and the query carries the
+/// [soft_delete_refusal] that refuses to run it.
+const NO_SOFT_DELETE: &str = "NULL /* no soft delete column */";
+
+/// The column marked with `#[sea_orm(soft_delete)]`
+pub(crate) fn soft_delete_column<E>() -> Option<E::Column>
+where
+    E: EntityTrait,
+{
+    E::Column::iter().find(|col| col.def().is_soft_delete())
+}
+
+/// The condition selecting the rows of an entity that have not been soft deleted
+pub(crate) fn soft_delete_condition<E>() -> Option<Condition>
+where
+    E: EntityTrait,
+{
+    soft_delete_condition_on::<E>(E::default().into_iden())
+}
+
+/// The [soft_delete_condition] of the rows of `tbl`, the table of the entity or an alias of it
+pub(crate) fn soft_delete_condition_on<E>(tbl: DynIden) -> Option<Condition>
+where
+    E: EntityTrait,
+{
+    let col = soft_delete_column::<E>()?;
+    Some(Condition::all().add(Expr::col((tbl, col)).is_null()))
+}
+
+/// The condition selecting the rows of an entity that have been soft deleted, matching no rows if
+/// the entity has no soft delete column
+pub(crate) fn deleted_condition<E>() -> Condition
+where
+    E: EntityTrait,
+{
+    let deleted = match soft_delete_column::<E>() {
+        Some(col) => Expr::col((E::default(), col)).is_not_null(),
+        None => Expr::cust(NO_SOFT_DELETE),
+    };
+    Condition::all().add(deleted)
+}
+
+/// The column and value undeleting the soft deleted rows of an entity, a stand-in if the entity
+/// has no soft delete column
+pub(crate) fn restore_value<E>() -> (DynIden, SimpleExpr)
+where
+    E: EntityTrait,
+{
+    match soft_delete_column::<E>() {
+        Some(col) => (col.into_iden(), SimpleExpr::Keyword(Keyword::Null)),
+        None => (
+            Alias::new("deleted_at").into_iden(),
+            Expr::cust(NO_SOFT_DELETE),
+        ),
+    }
+}
+
+/// The refusal of a statement on the deleted rows of an entity without a soft delete column
+pub(crate) fn soft_delete_refusal<E>() -> Option<Refusal>
+where
+    E: EntityTrait,
+{
+    soft_delete_column::<E>()
+        .is_none()
+        .then_some(Refusal::NoSoftDelete)
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "mock", feature = "macros"))]
+mod tests {
+    use crate::{entity::prelude::*, *};
+    use pretty_assertions::assert_eq;
+
+    mod post {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub title: String,
+            #[sea_orm(soft_delete)]
+            pub deleted_at: Option<DateTimeUtc>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod note {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "note")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[smol_potat::test]
+    async fn soft_delete_on_mysql() -> Result<(), DbErr> {
+        let deleted_at: DateTimeUtc = "2024-01-02T03:04:05Z".parse().unwrap();
+        let post = |id| post::Model {
+            id,
+            title: "Draft".to_owned(),
+            deleted_at: Some(deleted_at),
+        };
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([vec![post(1), post(2)], vec![post(1), post(2)]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        let deleted = post::Entity::delete_many()
+            .filter(
+                Condition::any()
+                    .add(post::Column::Id.eq(1))
+                    .add(post::Column::Title.eq("Draft")),
+            )
+            .exec_with_returning(&db)
+            .await?;
+        assert_eq!(deleted, [post(1), post(2)]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `post`.`id`, `post`.`title`, `post`.`deleted_at` FROM `post` WHERE (`post`.`id` = ? OR `post`.`title` = ?) AND `post`.`deleted_at` IS NULL FOR UPDATE",
+                    [1.into(), "Draft".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "UPDATE `post` SET `deleted_at` = CURRENT_TIMESTAMP WHERE (`post`.`id` = ? OR `post`.`title` = ?) AND `post`.`deleted_at` IS NULL",
+                    [1.into(), "Draft".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `post`.`id`, `post`.`title`, `post`.`deleted_at` FROM `post` WHERE `post`.`id` = ? OR `post`.`id` = ?",
+                    [1.into(), 2.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn deleted_rows_without_soft_delete_column() {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        assert!(matches!(
+            note::Entity::only_deleted().all(&db).await,
+            Err(DbErr::Custom(_))
+        ));
+        assert!(matches!(
+            note::Entity::restore().exec(&db).await,
+            Err(DbErr::Custom(_))
+        ));
+        assert_eq!(db.into_transaction_log(), []);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    ActiveEnum, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, IdenStatic, Iterable, ModelTrait,
+    PrimaryKeyToColumn, QueryFilter,
+};
+use sea_query::{Expr, Value, ValueType};
+use std::fmt::Debug;
+
+/// An [ActiveEnum] with the transitions allowed between its states, derived with
+/// `DeriveStateMachine`.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, DeriveStateMachine, StateMachine};
+///
+/// #[derive(Clone, Debug, PartialEq, EnumIter, DeriveActiveEnum, DeriveStateMachine)]
+/// #[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+/// #[transition(from = "Pending", to = "Paid")]
+/// #[transition(from = "Pending", to = "Cancelled")]
+/// #[transition(from = "Paid", to = "Refunded")]
+/// pub enum Status {
+///     #[sea_orm(string_value = "pending")]
+///     Pending,
+///     #[sea_orm(string_value = "paid")]
+///     Paid,
+///     #[sea_orm(string_value = "cancelled")]
+///     Cancelled,
+///     #[sea_orm(string_value = "refunded")]
+///     Refunded,
+/// }
+///
+/// assert!(Status::can_transition(&Status::Pending, &Status::Paid));
+/// assert!(!Status::can_transition(&Status::Cancelled, &Status::Paid));
+/// assert_eq!(Status::Pending.next_states(), [Status::Paid, Status::Cancelled]);
+/// ```
+pub trait StateMachine: ActiveEnum + ValueType + Into<Value> + Clone + Debug + Send + Sync {
+    /// Whether a transition from one state to another is allowed
+    fn can_transition(from: &Self, to: &Self) -> bool;
+
+    /// The states a transition from this state is allowed to
+    fn next_states(&self) -> Vec<Self> {
+        Self::iter()
+            .filter(|to| Self::can_transition(self, to))
+            .collect()
+    }
+}
+
+/// An error from [ModelTrait::transition]
+#[derive(Debug, thiserror::Error)]
+pub enum TransitionError<S>
+where
+    S: StateMachine,
+{
+    /// The transition is not allowed by the state machine
+    #[error("Transition from {from:?} to {to:?} is not allowed")]
+    NotAllowed {
+        /// The state of the model
+        from: S,
+        /// The requested state
+        to: S,
+    },
+    /// The row was no longer in the state of the model, it was changed concurrently or deleted
+    #[error("Transition from {from:?} to {to:?} failed, the row is no longer {from:?}")]
+    Stale {
+        /// The state of the model
+        from: S,
+        /// The requested state
+        to: S,
+    },
+    /// An error from the database
+    #[error("{0}")]
+    Db(#[from] DbErr),
+}
+
+pub(crate) async fn transition<M, S, C>(
+    mut model: M,
+    col: <M::Entity as EntityTrait>::Column,
+    to: S,
+    db: &C,
+) -> Result<M, TransitionError<S>>
+where
+    M: ModelTrait,
+    S: StateMachine,
+    C: ConnectionTrait,
+{
+    let from = <S as ValueType>::try_from(model.get(col)).map_err(|_| {
+        DbErr::Type(format!(
+            "Column `{}` is not a `{}`",
+            col.as_str(),
+            S::name().to_string()
+        ))
+    })?;
+    if !S::can_transition(&from, &to) {
+        return Err(TransitionError::NotAllowed { from, to });
+    }
+
+    let mut update = M::Entity::update_many()
+        .col_expr(col, col.save_as(Expr::val(to.clone())))
+        .filter(col.eq(from.clone()));
+    for key in <M::Entity as EntityTrait>::PrimaryKey::iter() {
+        let key = key.into_column();
+        update = update.filter(key.eq(model.get(key)));
+    }
+    if update.exec(db).await?.rows_affected == 0 {
+        return Err(TransitionError::Stale { from, to });
+    }
+
+    model.set(col, to.into());
+    Ok(model)
+}
@@ -0,0 +1,258 @@
+use crate::{ActiveModelTrait, ColumnTrait, EntityTrait, IdenStatic, Iterable, Refusal, Value};
+use sea_query::{BinOper, DynIden, Expr, IntoIden, SimpleExpr};
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+thread_local! {
+    static CURRENT: RefCell<Scope> = const { RefCell::new(Scope::Unset) };
+}
+
+/// Stands in for the tenant condition, and the tenant key of inserted rows, of a statement built
+/// outside of a tenant scope. It matches no rows, and the query carries the [tenant_refusal] that
+/// refuses to run it.
+const NO_TENANT: &str = "NULL /* no TenantContext */";
+
+/// The tenant scope of the current thread
+#[derive(Debug, Clone, PartialEq)]
+enum Scope {
+    /// Outside of any scope: statements on tenant scoped entities fail
+    Unset,
+    /// Explicitly unscoped, see [TenantContext::unscoped]: statements are not scoped
+    Unscoped,
+    Tenant(TenantContext),
+}
+
+/// The tenant a unit of work runs on behalf of.
+///
+/// Entities marked with `#[sea_orm(tenant_key = "tenant_id")]` are scoped to the current tenant:
+/// `find`, `update_many`, `delete_many`, `update` and `delete` are filtered by the tenant key,
+/// and `insert` stamps it. Outside of a tenant scope these statements fail closed: they match
+/// no rows, and executing them returns an error. Run cross tenant work in
+/// [TenantContext::unscoped] instead.
+///
+/// Joins to a tenant scoped entity, and so related queries and loaders, are scoped too.
+///
+/// The tenant is read when a query is constructed, so build queries inside the scope.
+///
+/// ```
+/// # use sea_orm::{entity::*, query::*, DbBackend, TenantContext};
+/// # mod post {
+/// #     use sea_orm::entity::prelude::*;
+/// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #     #[sea_orm(table_name = "post", tenant_key = "tenant_id")]
+/// #     pub struct Model {
+/// #         #[sea_orm(primary_key)]
+/// #         pub id: i32,
+/// #         pub tenant_id: i32,
+/// #         pub title: String,
+/// #     }
+/// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// #     pub enum Relation {}
+/// #     impl ActiveModelBehavior for ActiveModel {}
+/// # }
+/// TenantContext::new(7).sync_scope(|| {
+///     assert_eq!(
+///         post::Entity::find().build(DbBackend::Postgres).to_string(),
+///         r#"SELECT "post"."id", "post"."tenant_id", "post"."title" FROM "post" WHERE "post"."tenant_id" = 7"#
+///     );
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TenantContext {
+    tenant: Value,
+}
+
+/// A future running with a [TenantContext], see [TenantContext::scope]
+#[derive(Debug)]
+pub struct TenantScope<F> {
+    scope: Scope,
+    future: Pin<Box<F>>,
+}
+
+impl TenantContext {
+    /// Create a context for the tenant identified by the value of the tenant key
+    pub fn new<V>(tenant: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        Self {
+            tenant: tenant.into(),
+        }
+    }
+
+    /// The value of the tenant key
+    pub fn tenant(&self) -> &Value {
+        &self.tenant
+    }
+
+    /// The tenant of the current scope, if any
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| match &*current.borrow() {
+            Scope::Tenant(context) => Some(context.clone()),
+            Scope::Unset | Scope::Unscoped => None,
+        })
+    }
+
+    /// Run a future with this tenant as the current tenant
+    pub fn scope<F>(self, future: F) -> TenantScope<F>
+    where
+        F: Future,
+    {
+        TenantScope {
+            scope: Scope::Tenant(self),
+            future: Box::pin(future),
+        }
+    }
+
+    /// Run a future without a current tenant, e.g. for cross tenant maintenance
+    pub fn unscoped<F>(future: F) -> TenantScope<F>
+    where
+        F: Future,
+    {
+        TenantScope {
+            scope: Scope::Unscoped,
+            future: Box::pin(future),
+        }
+    }
+
+    /// Run a closure with this tenant as the current tenant
+    pub fn sync_scope<F, T>(self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let _guard = Guard::enter(Scope::Tenant(self));
+        f()
+    }
+
+    /// Run a closure without a current tenant, see [TenantContext::unscoped]
+    pub fn sync_unscoped<F, T>(f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let _guard = Guard::enter(Scope::Unscoped);
+        f()
+    }
+}
+
+impl<F> Future for TenantScope<F>
+where
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let _guard = Guard::enter(this.scope.clone());
+        this.future.as_mut().poll(cx)
+    }
+}
+
+/// Restores the previous tenant when dropped, so scopes nest and survive panics
+struct Guard {
+    previous: Scope,
+}
+
+impl Guard {
+    fn enter(scope: Scope) -> Self {
+        Self {
+            previous: CURRENT.with(|current| current.replace(scope)),
+        }
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let previous = std::mem::replace(&mut self.previous, Scope::Unset);
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// The tenant key column of an entity
+///
+/// # Panics
+///
+/// Panics if the entity has a tenant key that is not one of its columns
+pub(crate) fn tenant_column<E>() -> Option<E::Column>
+where
+    E: EntityTrait,
+{
+    let entity = E::default();
+    let key = entity.tenant_key()?;
+    Some(
+        E::Column::iter()
+            .find(|col| col.as_str() == key)
+            .unwrap_or_else(|| {
+                panic!(
+                    "tenant key `{key}` is not a column of `{}`",
+                    entity.table_name()
+                )
+            }),
+    )
+}
+
+/// The condition scoping an entity to the current tenant, matching no rows outside of a tenant
+/// scope
+pub(crate) fn tenant_condition<E>() -> Option<SimpleExpr>
+where
+    E: EntityTrait,
+{
+    tenant_condition_on::<E>(E::default().into_iden())
+}
+
+/// The [tenant_condition] of the rows of `tbl`, the table of the entity or an alias of it
+pub(crate) fn tenant_condition_on<E>(tbl: DynIden) -> Option<SimpleExpr>
+where
+    E: EntityTrait,
+{
+    let col = tenant_column::<E>()?;
+    CURRENT.with(|current| match &*current.borrow() {
+        Scope::Tenant(context) => {
+            let tenant = col.save_as(Expr::val(context.tenant.clone()));
+            Some(Expr::col((tbl, col)).binary(BinOper::Equal, tenant))
+        }
+        Scope::Unscoped => None,
+        Scope::Unset => Some(Expr::cust(NO_TENANT)),
+    })
+}
+
+/// The value to insert into a column, replaced for the tenant key outside of a tenant scope, where
+/// the insert carries the [tenant_refusal]
+pub(crate) fn tenant_insert_value<E>(
+    col: E::Column,
+    value: Option<SimpleExpr>,
+) -> Option<SimpleExpr>
+where
+    E: EntityTrait,
+{
+    let unset = CURRENT.with(|current| *current.borrow() == Scope::Unset);
+    if unset && E::default().tenant_key() == Some(col.as_str()) {
+        Some(Expr::cust(NO_TENANT))
+    } else {
+        value
+    }
+}
+
+/// The refusal of a statement on a tenant scoped entity built outside of a tenant scope
+pub(crate) fn tenant_refusal<E>() -> Option<Refusal>
+where
+    E: EntityTrait,
+{
+    let unset = CURRENT.with(|current| *current.borrow() == Scope::Unset);
+    (unset && E::default().tenant_key().is_some()).then_some(Refusal::NoTenant)
+}
+
+/// Set the tenant key of an ActiveModel to the current tenant
+pub(crate) fn stamp_tenant<A>(am: &mut A)
+where
+    A: ActiveModelTrait,
+{
+    if let Some(col) = tenant_column::<A::Entity>() {
+        if let Some(context) = TenantContext::current() {
+            am.set(col, context.tenant);
+        }
+    }
+}
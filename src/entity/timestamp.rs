@@ -0,0 +1,217 @@
+use crate::ColumnTrait;
+use sea_query::{ColumnType, Keyword, SimpleExpr, Value};
+
+/// Where the time of a `created_at` or `updated_at` column comes from.
+///
+/// A column marked `#[sea_orm(created_at)]` is set when a row is inserted, and one marked
+/// `#[sea_orm(updated_at)]` when a row is inserted and whenever it is updated, unless the
+/// ActiveModel has a value `Set` for it. `#[sea_orm(created_at = "client")]` takes the time
+/// from the clock of the application instead of the database.
+///
+/// ```
+/// # use sea_orm::{entity::*, query::*, DbBackend};
+/// # mod post {
+/// #     use sea_orm::entity::prelude::*;
+/// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #     #[sea_orm(table_name = "post")]
+/// #     pub struct Model {
+/// #         #[sea_orm(primary_key)]
+/// #         pub id: i32,
+/// #         pub title: String,
+/// #         #[sea_orm(created_at)]
+/// #         pub created_at: DateTimeUtc,
+/// #         #[sea_orm(updated_at)]
+/// #         pub updated_at: DateTimeUtc,
+/// #     }
+/// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// #     pub enum Relation {}
+/// #     impl ActiveModelBehavior for ActiveModel {}
+/// # }
+/// let post = post::ActiveModel {
+///     title: Set("Hello".to_owned()),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     post::Entity::insert(post.clone())
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     r#"INSERT INTO "post" ("title", "created_at", "updated_at") VALUES ('Hello', CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)"#
+/// );
+///
+/// assert_eq!(
+///     post::Entity::update(post::ActiveModel {
+///         id: Unchanged(1),
+///         ..post
+///     })
+///     .build(DbBackend::Postgres)
+///     .to_string(),
+///     r#"UPDATE "post" SET "title" = 'Hello', "updated_at" = CURRENT_TIMESTAMP WHERE "post"."id" = 1"#
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampClock {
+    /// `CURRENT_TIMESTAMP` of the database
+    Database,
+    /// The current time of the application, bound as a value. Needs the `with-chrono` or
+    /// `with-time` feature and a timestamp column; the database clock is used otherwise.
+    Client,
+}
+
+/// The time to set a `created_at` or `updated_at` column to, if the column is one on an
+/// insert, or an `updated_at` column on an update
+pub(crate) fn auto_timestamp<C>(col: C, insert: bool) -> Option<SimpleExpr>
+where
+    C: ColumnTrait,
+{
+    let def = col.def();
+    let clock = match (def.created_at, def.updated_at) {
+        (Some(clock), _) if insert => clock,
+        (_, Some(clock)) => clock,
+        _ => return None,
+    };
+    let now = match clock {
+        TimestampClock::Database => None,
+        TimestampClock::Client => client_now(def.get_column_type()),
+    };
+    Some(match now {
+        Some(now) => SimpleExpr::Value(now),
+        None => SimpleExpr::Keyword(Keyword::CurrentTimestamp),
+    })
+}
+
+#[cfg(feature = "with-chrono")]
+fn client_now(col_type: &ColumnType) -> Option<Value> {
+    let now = chrono::Utc::now();
+    match col_type {
+        ColumnType::DateTime | ColumnType::Timestamp => Some(now.naive_utc().into()),
+        ColumnType::TimestampWithTimeZone => Some(now.into()),
+        _ => None,
+    }
+}
+
+#[cfg(all(feature = "with-time", not(feature = "with-chrono")))]
+fn client_now(col_type: &ColumnType) -> Option<Value> {
+    let now = time::OffsetDateTime::now_utc();
+    match col_type {
+        ColumnType::DateTime | ColumnType::Timestamp => {
+            Some(time::PrimitiveDateTime::new(now.date(), now.time()).into())
+        }
+        ColumnType::TimestampWithTimeZone => Some(now.into()),
+        _ => None,
+    }
+}
+
+#[cfg(not(any(feature = "with-chrono", feature = "with-time")))]
+fn client_now(_: &ColumnType) -> Option<Value> {
+    None
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use crate::{entity::prelude::*, ActiveValue::*, DbBackend, QueryTrait};
+    use pretty_assertions::assert_eq;
+
+    mod post {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub title: String,
+            #[sea_orm(created_at)]
+            pub created_at: DateTimeUtc,
+            #[sea_orm(updated_at = "client")]
+            pub updated_at: DateTime,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn stamp_timestamps() {
+        let created_at: DateTimeUtc = "2024-01-02T03:04:05Z".parse().unwrap();
+        let stmt = post::Entity::insert_many([
+            post::ActiveModel {
+                title: Set("First".to_owned()),
+                created_at: Set(created_at),
+                ..Default::default()
+            },
+            post::ActiveModel {
+                title: Set("Second".to_owned()),
+                ..Default::default()
+            },
+        ])
+        .build(DbBackend::Postgres);
+        assert_eq!(
+            stmt.sql,
+            r#"INSERT INTO "post" ("title", "created_at", "updated_at") VALUES ($1, $2, $3), ($4, CURRENT_TIMESTAMP, $5)"#
+        );
+        let values = stmt.values.unwrap().0;
+        assert_eq!(values[1], created_at.into());
+        assert!(matches!(values[2], Value::ChronoDateTime(Some(_))));
+        assert!(matches!(values[4], Value::ChronoDateTime(Some(_))));
+
+        let stmt = post::Entity::update(post::ActiveModel {
+            id: Unchanged(1),
+            title: Set("Edited".to_owned()),
+            ..Default::default()
+        })
+        .build(DbBackend::Postgres);
+        assert_eq!(
+            stmt.sql,
+            r#"UPDATE "post" SET "title" = $1, "updated_at" = $2 WHERE "post"."id" = $3"#
+        );
+
+        assert_eq!(
+            post::Entity::update(post::ActiveModel {
+                id: Unchanged(1),
+                ..Default::default()
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"UPDATE "post" SET  WHERE "post"."id" = 1"#
+        );
+
+        // the rows of the models setting nothing keep their `updated_at`
+        let updated_at = created_at.naive_utc();
+        let stmt = post::Entity::update_batch([
+            post::ActiveModel {
+                id: Unchanged(1),
+                title: Set("Edited".to_owned()),
+                ..Default::default()
+            },
+            post::ActiveModel {
+                id: Unchanged(2),
+                title: Set("Edited".to_owned()),
+                updated_at: Set(updated_at),
+                ..Default::default()
+            },
+            post::ActiveModel {
+                id: Unchanged(3),
+                ..Default::default()
+            },
+        ])
+        .build(DbBackend::Postgres);
+        assert_eq!(
+            stmt.sql,
+            [
+                r#"UPDATE "post" SET"#,
+                r#""title" = (CASE WHEN ("post"."id" = $1) THEN $2 WHEN ("post"."id" = $3) THEN $4 ELSE "post"."title" END),"#,
+                r#""updated_at" = (CASE WHEN ("post"."id" = $5) THEN $6 WHEN ("post"."id" = $7) THEN $8 ELSE "post"."updated_at" END)"#,
+                r#"WHERE "post"."id" IN ($9, $10, $11)"#,
+            ]
+            .join(" ")
+        );
+        let values = stmt.values.unwrap().0;
+        assert!(matches!(values[5], Value::ChronoDateTime(Some(_))));
+        assert_eq!(values[7], updated_at.into());
+    }
+}
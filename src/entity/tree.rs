@@ -0,0 +1,251 @@
+use crate::{
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, DbErr,
+    EntityTrait, IntoActiveModel, Iterable, ModelTrait, PrimaryKeyToColumn, QueryFilter,
+    QueryOrder, Select,
+};
+use async_trait::async_trait;
+use sea_query::{Alias, Expr, Func, SimpleExpr};
+
+/// A Model in a tree, stored as a materialized path, derived with `DeriveTree`.
+///
+/// The path of a node is the path of its parent followed by the
+/// [segment](TreeModel::tree_segment) of the node and a `/`, e.g. `1/4/9/`. Ancestors and
+/// descendants are then found with plain comparisons and `LIKE`, on every backend.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, DbBackend, TreeModel};
+/// # mod category {
+/// #     use sea_orm::{entity::prelude::*, DeriveTree};
+/// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveTree)]
+/// #     #[sea_orm(table_name = "category")]
+/// #     pub struct Model {
+/// #         #[sea_orm(primary_key)]
+/// #         pub id: i32,
+/// #         pub name: String,
+/// #         #[sea_orm(tree_path)]
+/// #         pub path: String,
+/// #     }
+/// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// #     pub enum Relation {}
+/// #     impl ActiveModelBehavior for ActiveModel {}
+/// # }
+///
+/// let shoes = category::Model {
+///     id: 4,
+///     name: "Shoes".to_owned(),
+///     path: "1/4/".to_owned(),
+/// };
+/// assert_eq!(shoes.depth(), 1);
+///
+/// assert_eq!(
+///     shoes.ancestors().build(DbBackend::Postgres).to_string(),
+///     [
+///         r#"SELECT "category"."id", "category"."name", "category"."path" FROM "category""#,
+///         r#"WHERE "category"."path" IN ('1/') ORDER BY "category"."path" ASC"#,
+///     ]
+///     .join(" ")
+/// );
+/// assert_eq!(
+///     shoes.descendants().build(DbBackend::Postgres).to_string(),
+///     [
+///         r#"SELECT "category"."id", "category"."name", "category"."path" FROM "category""#,
+///         r#"WHERE "category"."path" LIKE '1/4/%' AND "category"."path" <> '1/4/'"#,
+///         r#"ORDER BY "category"."path" ASC"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[async_trait]
+pub trait TreeModel: ModelTrait + Sync {
+    /// The column holding the path
+    fn tree_path_column() -> <Self::Entity as EntityTrait>::Column;
+
+    /// The path of this node
+    fn tree_path(&self) -> &str;
+
+    /// The segment identifying this node in paths, derived from the primary key
+    fn tree_segment(&self) -> String;
+
+    /// The number of ancestors of this node
+    fn depth(&self) -> usize {
+        self.tree_path().matches('/').count().saturating_sub(1)
+    }
+
+    /// The paths of the ancestors of this node, from the root down
+    fn ancestor_paths(&self) -> Vec<String> {
+        let path = self.tree_path();
+        path.match_indices('/')
+            .map(|(i, _)| path[..=i].to_owned())
+            .filter(|ancestor| ancestor != path)
+            .collect()
+    }
+
+    /// Select the nodes without a parent
+    fn roots() -> Select<Self::Entity> {
+        let col = Self::tree_path_column();
+        <Self::Entity as EntityTrait>::find()
+            .filter(col.not_like("%/%/"))
+            .order_by_asc(col)
+    }
+
+    /// Select the parent of this node
+    fn parent(&self) -> Select<Self::Entity> {
+        <Self::Entity as EntityTrait>::find()
+            .filter(Self::tree_path_column().is_in(self.ancestor_paths().pop()))
+    }
+
+    /// Select the ancestors of this node, from the root down
+    fn ancestors(&self) -> Select<Self::Entity> {
+        let col = Self::tree_path_column();
+        <Self::Entity as EntityTrait>::find()
+            .filter(col.is_in(self.ancestor_paths()))
+            .order_by_asc(col)
+    }
+
+    /// Select the children of this node
+    fn children(&self) -> Select<Self::Entity> {
+        let col = Self::tree_path_column();
+        let path = self.tree_path();
+        <Self::Entity as EntityTrait>::find()
+            .filter(col.like(format!("{path}%/")))
+            .filter(col.not_like(format!("{path}%/%/")))
+            .order_by_asc(col)
+    }
+
+    /// Select the descendants of this node, each one after its parent
+    fn descendants(&self) -> Select<Self::Entity> {
+        let col = Self::tree_path_column();
+        let path = self.tree_path();
+        <Self::Entity as EntityTrait>::find()
+            .filter(col.starts_with(path))
+            .filter(col.ne(path))
+            .order_by_asc(col)
+    }
+
+    /// Insert a node under a parent, or as a root, and set its path.
+    ///
+    /// The path depends on the primary key, so the row is inserted and then updated; run this
+    /// in a transaction if the row should never be seen without its path.
+    async fn insert_node<'a, A, C>(
+        am: A,
+        parent: Option<&'a Self>,
+        db: &'a C,
+    ) -> Result<Self, DbErr>
+    where
+        Self: IntoActiveModel<A>,
+        Self::Entity: EntityTrait<Model = Self>,
+        A: ActiveModelTrait<Entity = Self::Entity> + ActiveModelBehavior + Send + 'a,
+        C: ConnectionTrait,
+    {
+        let col = Self::tree_path_column();
+        let parent_path = parent.map(|parent| parent.tree_path()).unwrap_or_default();
+        let mut am = am;
+        am.set(col, parent_path.into());
+        let mut model = am.insert(db).await?;
+
+        let path = format!("{parent_path}{}/", model.tree_segment());
+        let mut update = <Self::Entity as EntityTrait>::update_many()
+            .col_expr(col, Expr::val(path.as_str()).into());
+        for key in <Self::Entity as EntityTrait>::PrimaryKey::iter() {
+            let key = key.into_column();
+            update = update.filter(key.eq(model.get(key)));
+        }
+        update.exec(db).await?;
+        model.set(col, path.into());
+        Ok(model)
+    }
+
+    /// Move this node and its descendants under another parent, or to the roots, returning the
+    /// moved node. Paths are rewritten in a single `UPDATE`.
+    ///
+    /// ```
+    /// # use sea_orm::{entity::prelude::*, error::*, *};
+    /// # mod category {
+    /// #     use sea_orm::{entity::prelude::*, DeriveTree};
+    /// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveTree)]
+    /// #     #[sea_orm(table_name = "category")]
+    /// #     pub struct Model {
+    /// #         #[sea_orm(primary_key)]
+    /// #         pub id: i32,
+    /// #         #[sea_orm(tree_path)]
+    /// #         pub path: String,
+    /// #     }
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([MockExecResult {
+    /// #         last_insert_id: 0,
+    /// #         rows_affected: 3,
+    /// #     }])
+    /// #     .into_connection();
+    /// #
+    /// let shoes = category::Model { id: 4, path: "1/4/".to_owned() };
+    /// let sale = category::Model { id: 7, path: "7/".to_owned() };
+    ///
+    /// let shoes = shoes.move_subtree(Some(&sale), &db).await?;
+    /// assert_eq!(shoes.path, "7/4/");
+    ///
+    /// // a node cannot be moved under its own descendant
+    /// let boots = category::Model { id: 9, path: "7/4/9/".to_owned() };
+    /// assert!(shoes.clone().move_subtree(Some(&boots), &db).await.is_err());
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"UPDATE "category" SET "path" = $1 || SUBSTR("path", $2) WHERE "category"."path" LIKE $3"#,
+    ///         ["7/4/".into(), 5i64.into(), "1/4/%".into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn move_subtree<'a, C>(self, parent: Option<&'a Self>, db: &'a C) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let col = Self::tree_path_column();
+        let old_path = self.tree_path().to_owned();
+        let parent_path = parent.map(|parent| parent.tree_path()).unwrap_or_default();
+        if parent_path.starts_with(&old_path) {
+            return Err(DbErr::Custom(format!(
+                "Cannot move `{old_path}` under its own subtree `{parent_path}`"
+            )));
+        }
+        let new_path = format!("{parent_path}{}/", self.tree_segment());
+        if new_path == old_path {
+            return Ok(self);
+        }
+
+        let rest: SimpleExpr = Func::cust(Alias::new("SUBSTR"))
+            .arg(Expr::col(col))
+            .arg(old_path.chars().count() as i64 + 1)
+            .into();
+        let moved = match db.get_database_backend() {
+            DbBackend::MySql => Func::cust(Alias::new("CONCAT"))
+                .arg(new_path.as_str())
+                .arg(rest)
+                .into(),
+            DbBackend::Postgres | DbBackend::Sqlite => {
+                Expr::cust_with_exprs("$1 || $2", [Expr::val(new_path.as_str()).into(), rest])
+            }
+        };
+        <Self::Entity as EntityTrait>::update_many()
+            .col_expr(col, moved)
+            .filter(col.starts_with(&old_path))
+            .exec(db)
+            .await?;
+
+        let mut model = self;
+        model.set(col, new_path.into());
+        Ok(model)
+    }
+}
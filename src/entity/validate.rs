@@ -0,0 +1,313 @@
+use std::fmt;
+
+/// A value that failed a validation rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The field of the ActiveModel
+    pub field: String,
+    /// The rule that failed, like `length` or `range`
+    pub code: String,
+    /// A message describing the failure
+    pub message: String,
+}
+
+/// The validation errors of an ActiveModel, returned by
+/// [ActiveModelTrait::validate_values](crate::ActiveModelTrait::validate_values).
+///
+/// `DeriveActiveModel` checks the rules declared on the fields on the values that are `Set`,
+/// then calls [ActiveModelBehavior::validate_model](crate::ActiveModelBehavior::validate_model)
+/// for checks that need code. `insert`, `update` and `save` validate the ActiveModel after
+/// `before_save`, failing with [DbErr::Validation](crate::DbErr::Validation) without running
+/// any statement.
+///
+/// ```
+/// # use sea_orm::{entity::*, ValidationErrors};
+/// # mod post {
+/// #     use sea_orm::entity::prelude::*;
+/// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #     #[sea_orm(table_name = "post")]
+/// #     pub struct Model {
+/// #         #[sea_orm(primary_key)]
+/// #         pub id: i32,
+/// #         #[sea_orm(validate(length(min = 1, max = 255)))]
+/// #         pub title: String,
+/// #         #[sea_orm(validate(range(min = 0, max = 5)))]
+/// #         pub rating: Option<i32>,
+/// #     }
+/// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// #     pub enum Relation {}
+/// #     impl ActiveModelBehavior for ActiveModel {}
+/// # }
+/// let post = post::ActiveModel {
+///     title: Set(String::new()),
+///     rating: Set(Some(7)),
+///     ..Default::default()
+/// };
+///
+/// let errors = post.validate_values().unwrap_err();
+/// assert_eq!(
+///     errors.to_string(),
+///     "title must have at least 1 characters; rating must be at most 5"
+/// );
+/// assert_eq!(errors.errors()[0].field, "title");
+/// assert_eq!(errors.errors()[0].code, "length");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationError>,
+}
+
+/// A value with a length, for the `length` rule
+pub trait ValidateLength {
+    /// The length of the value, in characters for strings; `None` skips the rule
+    fn validation_length(&self) -> Option<usize>;
+}
+
+/// A numeric value, for the `range` rule
+pub trait ValidateRange {
+    /// The value as a float; `None` skips the rule
+    fn validation_value(&self) -> Option<f64>;
+}
+
+impl ValidationErrors {
+    /// Create an empty set of errors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an error of a field
+    pub fn add<F, C, M>(&mut self, field: F, code: C, message: M)
+    where
+        F: Into<String>,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.errors.push(ValidationError {
+            field: field.into(),
+            code: code.into(),
+            message: message.into(),
+        });
+    }
+
+    /// Whether there are no errors
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The errors, in the order they were added
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// The errors of a field
+    pub fn field<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a ValidationError> {
+        self.errors.iter().filter(move |error| error.field == field)
+    }
+
+    /// `Ok` if there are no errors
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Check the `length` rule on a value
+    pub fn check_length<V>(
+        &mut self,
+        field: &str,
+        value: &V,
+        min: Option<usize>,
+        max: Option<usize>,
+    ) where
+        V: ValidateLength + ?Sized,
+    {
+        let Some(len) = value.validation_length() else {
+            return;
+        };
+        if let Some(min) = min.filter(|min| len < *min) {
+            self.add(
+                field,
+                "length",
+                format!("{field} must have at least {min} characters"),
+            );
+        } else if let Some(max) = max.filter(|max| len > *max) {
+            self.add(
+                field,
+                "length",
+                format!("{field} must have at most {max} characters"),
+            );
+        }
+    }
+
+    /// Check the `range` rule on a value
+    pub fn check_range<V>(&mut self, field: &str, value: &V, min: Option<f64>, max: Option<f64>)
+    where
+        V: ValidateRange + ?Sized,
+    {
+        let Some(value) = value.validation_value() else {
+            return;
+        };
+        if let Some(min) = min.filter(|min| value < *min) {
+            self.add(field, "range", format!("{field} must be at least {min}"));
+        } else if let Some(max) = max.filter(|max| value > *max) {
+            self.add(field, "range", format!("{field} must be at most {max}"));
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            f.write_str(&error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl ValidateLength for str {
+    fn validation_length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl ValidateLength for String {
+    fn validation_length(&self) -> Option<usize> {
+        self.as_str().validation_length()
+    }
+}
+
+impl<T> ValidateLength for Vec<T> {
+    fn validation_length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T> ValidateLength for Option<T>
+where
+    T: ValidateLength,
+{
+    fn validation_length(&self) -> Option<usize> {
+        self.as_ref().and_then(T::validation_length)
+    }
+}
+
+macro_rules! validate_range_as_f64 {
+    ($($type: ty),*) => {
+        $(
+            impl ValidateRange for $type {
+                fn validation_value(&self) -> Option<f64> {
+                    Some(*self as f64)
+                }
+            }
+        )*
+    };
+}
+
+validate_range_as_f64!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+impl<T> ValidateRange for Option<T>
+where
+    T: ValidateRange,
+{
+    fn validation_value(&self) -> Option<f64> {
+        self.as_ref().and_then(T::validation_value)
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "mock", feature = "macros"))]
+mod tests {
+    use crate::{entity::prelude::*, DbBackend, MockDatabase, Set, ValidationError};
+    use pretty_assertions::assert_eq;
+
+    mod post {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(validate(length(min = 1, max = 8)))]
+            pub title: String,
+            #[sea_orm(validate(range(min = -1, max = 5)))]
+            pub rating: Option<i32>,
+            pub draft: bool,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {
+            fn validate_model(&self, errors: &mut ValidationErrors) {
+                if self.draft.try_as_ref() == Some(&false)
+                    && self.rating.try_as_ref() == Some(&None)
+                {
+                    errors.add("rating", "required", "a published post must be rated");
+                }
+            }
+        }
+    }
+
+    #[smol_potat::test]
+    async fn validate_before_save() {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let post = post::ActiveModel {
+            id: Set(1),
+            title: Set("Validating models".to_owned()),
+            rating: Set(Some(-2)),
+            draft: Set(true),
+        };
+        let error = |field: &str, code: &str, message: &str| ValidationError {
+            field: field.to_owned(),
+            code: code.to_owned(),
+            message: message.to_owned(),
+        };
+        assert_eq!(
+            post.validate_values().unwrap_err().errors(),
+            [
+                error("title", "length", "title must have at most 8 characters"),
+                error("rating", "range", "rating must be at least -1"),
+            ]
+        );
+        match post.insert(&db).await {
+            Err(DbErr::Validation(errors)) => assert_eq!(
+                errors.errors(),
+                [
+                    error("title", "length", "title must have at most 8 characters"),
+                    error("rating", "range", "rating must be at least -1"),
+                ]
+            ),
+            result => panic!("expected a validation error, got {result:?}"),
+        }
+
+        let post = post::ActiveModel {
+            id: Set(1),
+            rating: Set(None),
+            draft: Set(false),
+            ..Default::default()
+        };
+        let err = post.update(&db).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation failed: a published post must be rated"
+        );
+        assert!(matches!(err, DbErr::Validation(errors) if errors.field("rating").count() == 1));
+
+        let post = post::ActiveModel {
+            title: Set("Draft".to_owned()),
+            rating: Set(Some(5)),
+            ..Default::default()
+        };
+        assert_eq!(post.validate_values(), Ok(()));
+
+        assert!(db.into_transaction_log().is_empty());
+    }
+}
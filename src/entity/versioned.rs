@@ -0,0 +1,142 @@
+use crate::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityName, EntityTrait, Iterable, ModelTrait,
+    PrimaryKeyToColumn, Select, Value,
+};
+use sea_query::{
+    Alias, Condition, DynIden, Expr, IntoIden, IntoTableRef, Query, SimpleExpr, TableRef,
+};
+
+/// The column of a history table holding when a version of a row was written
+pub const HISTORY_VALID_FROM: &str = "valid_from";
+
+/// The column of a history table holding when a version of a row was replaced or deleted,
+/// `NULL` for the current version
+pub const HISTORY_VALID_TO: &str = "valid_to";
+
+/// The history table of an entity, `<table>_history` in the schema of the entity
+pub fn history_table_ref<E>(entity: &E) -> TableRef
+where
+    E: EntityName,
+{
+    let table = Alias::new(format!("{}_history", entity.table_name()));
+    match entity.schema_name() {
+        Some(schema) => (Alias::new(schema).into_iden(), table.into_iden()).into_table_ref(),
+        None => table.into_table_ref(),
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Select the rows of a [versioned](EntityName::versioned) entity as they were at a point in
+    /// time, from its history table.
+    ///
+    /// ```
+    /// # use sea_orm::{entity::*, query::*, DbBackend};
+    /// # mod post {
+    /// #     use sea_orm::entity::prelude::*;
+    /// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    /// #     #[sea_orm(table_name = "post", versioned)]
+    /// #     pub struct Model {
+    /// #         #[sea_orm(primary_key)]
+    /// #         pub id: i32,
+    /// #         pub title: String,
+    /// #     }
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    /// assert_eq!(
+    ///     post::Entity::find_by_id(1)
+    ///         .as_of("2024-01-01 00:00:00")
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "post"."id", "post"."title" FROM "post_history" AS "post""#,
+    ///         r#"WHERE "post"."id" = 1"#,
+    ///         r#"AND "post"."valid_from" <= '2024-01-01 00:00:00'"#,
+    ///         r#"AND ("post"."valid_to" IS NULL OR "post"."valid_to" > '2024-01-01 00:00:00')"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn as_of<T>(mut self, at: T) -> Self
+    where
+        T: Into<Value>,
+    {
+        let entity = E::default();
+        let at = at.into();
+        let valid_from = Expr::col((entity, Alias::new(HISTORY_VALID_FROM)));
+        let valid_to = Expr::col((entity, Alias::new(HISTORY_VALID_TO)));
+        self.query
+            .from_clear()
+            .from_as(history_table_ref(&entity), entity)
+            .and_where(valid_from.lte(at.clone()))
+            .cond_where(
+                Condition::any()
+                    .add(valid_to.clone().is_null())
+                    .add(valid_to.gt(at)),
+            );
+        self
+    }
+}
+
+/// Close the current version of a row, after it was updated or deleted
+pub(crate) async fn close_version<E, C>(
+    entity: E,
+    keys: Vec<(E::Column, Value)>,
+    db: &C,
+) -> Result<(), DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    let mut update = Query::update();
+    update
+        .table(history_table_ref(&entity))
+        .value(Alias::new(HISTORY_VALID_TO), Expr::current_timestamp())
+        .and_where(Expr::col(Alias::new(HISTORY_VALID_TO)).is_null());
+    for (col, value) in keys {
+        update.and_where(Expr::col(col).eq(col.save_as(Expr::val(value))));
+    }
+    db.execute(db.get_database_backend().build(&update)).await?;
+    Ok(())
+}
+
+/// Write a version of a row, after it was inserted or updated
+pub(crate) async fn record_version<M, C>(model: M, replaced: bool, db: &C) -> Result<M, DbErr>
+where
+    M: ModelTrait,
+    C: ConnectionTrait,
+{
+    let entity = <M::Entity as Default>::default();
+    if !entity.versioned() {
+        return Ok(model);
+    }
+    if replaced {
+        let keys = <M::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| {
+                let col = key.into_column();
+                (col, model.get(col))
+            })
+            .collect();
+        close_version(entity, keys, db).await?;
+    }
+
+    let columns: Vec<_> = <M::Entity as EntityTrait>::Column::iter().collect();
+    let mut values: Vec<SimpleExpr> = columns
+        .iter()
+        .map(|col| col.save_as(Expr::val(model.get(*col))))
+        .collect();
+    values.push(Expr::current_timestamp().into());
+    let mut idens: Vec<DynIden> = columns.into_iter().map(|col| col.into_iden()).collect();
+    idens.push(Alias::new(HISTORY_VALID_FROM).into_iden());
+    let mut insert = Query::insert();
+    insert
+        .into_table(history_table_ref(&entity))
+        .columns(idens)
+        .values_panic(values);
+    db.execute(db.get_database_backend().build(&insert)).await?;
+    Ok(model)
+}
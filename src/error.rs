@@ -73,6 +73,15 @@ pub enum DbErr {
     /// May be the table is empty or the record does not exist
     #[error("None of the records are updated")]
     RecordNotUpdated,
+    /// The row was not updated because its version column no longer has the version of the
+    /// model: it was updated or deleted since the model was read
+    #[error("The record was changed or deleted since it was read")]
+    StaleObject,
+    /// The ActiveModel was not saved because it failed
+    /// [ActiveModelTrait::validate_values](crate::ActiveModelTrait::validate_values), which
+    /// returned the rules that failed for each field
+    #[error("Validation failed: {0}")]
+    Validation(crate::ValidationErrors),
 }
 
 /// An error from trying to get a row from a Model
@@ -102,6 +111,22 @@ pub enum RuntimeErr {
     #[cfg(feature = "sqlx-dep")]
     #[error("{0}")]
     SqlxError(#[source] sqlx::error::Error),
+    /// libSQL Error
+    #[cfg(feature = "libsql")]
+    #[error("{0}")]
+    LibsqlError(#[source] libsql::Error),
+    /// DuckDB Error
+    #[cfg(feature = "duckdb")]
+    #[error("{0}")]
+    DuckdbError(#[source] duckdb::Error),
+    /// tokio-postgres Error
+    #[cfg(feature = "tokio-postgres")]
+    #[error("{0}")]
+    TokioPostgresError(#[source] tokio_postgres::Error),
+    /// Error injected by [MockFault](crate::MockFault), reported by [DbErr::sql_err] as is
+    #[cfg(feature = "mock")]
+    #[error("{0}")]
+    Mock(SqlErr),
     /// Error generated from within SeaORM
     #[error("{0}")]
     Internal(String),
@@ -120,6 +145,32 @@ impl Eq for DbErr {}
 #[error("Failed to match \"{0}\" as Column")]
 pub struct ColumnFromStrErr(pub String);
 
+/// Why a statement must not run, found when it is built and carried by the query until it is
+/// executed. The statement itself is built to match no rows.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Refusal {
+    /// A tenant scoped entity, outside of a [TenantContext](crate::TenantContext) scope
+    #[error("a tenant scoped entity was queried outside of a TenantContext scope")]
+    NoTenant,
+    /// The deleted rows of an entity without a soft delete column
+    #[error("the deleted rows of an entity without a soft delete column were queried")]
+    NoSoftDelete,
+}
+
+impl From<Refusal> for DbErr {
+    fn from(refusal: Refusal) -> Self {
+        DbErr::Custom(refusal.to_string())
+    }
+}
+
+/// Fail with the refusal of a query, if any, before executing it
+pub(crate) fn check_refusal(refusal: Option<Refusal>) -> Result<(), DbErr> {
+    match refusal {
+        Some(refusal) => Err(refusal.into()),
+        None => Ok(()),
+    }
+}
+
 #[allow(dead_code)]
 pub(crate) fn conn_err<T>(s: T) -> DbErr
 where
@@ -170,6 +221,16 @@ pub enum SqlErr {
     /// Error for Foreign key constraint
     #[error("Foreign Key Constraint Violated: {0}")]
     ForeignKeyConstraintViolation(String),
+    /// The database detected a deadlock and aborted the statement (or its transaction)
+    #[error("Deadlock: {0}")]
+    Deadlock(String),
+    /// The statement gave up waiting to acquire a lock
+    #[error("Lock Timeout: {0}")]
+    LockTimeout(String),
+    /// The database aborted the transaction because it could not be serialized with
+    /// concurrent transactions. Running the whole transaction again may succeed
+    #[error("Serialization Failure: {0}")]
+    SerializationFailure(String),
 }
 
 #[allow(dead_code)]
@@ -213,6 +274,10 @@ impl DbErr {
                         1216 | 1217 | 1451 | 1452 | 1557 | 1761 | 1762 => {
                             return Some(SqlErr::ForeignKeyConstraintViolation(e.message().into()))
                         }
+                        // 1213 Deadlock found when trying to get lock; try restarting transaction
+                        1213 => return Some(SqlErr::Deadlock(e.message().into())),
+                        // 1205 Lock wait timeout exceeded; try restarting transaction
+                        1205 => return Some(SqlErr::LockTimeout(e.message().into())),
                         _ => return None,
                     }
                 }
@@ -227,6 +292,13 @@ impl DbErr {
                         "23503" => {
                             return Some(SqlErr::ForeignKeyConstraintViolation(e.message().into()))
                         }
+                        // deadlock_detected
+                        "40P01" => return Some(SqlErr::Deadlock(e.message().into())),
+                        // lock_not_available, raised when `lock_timeout` expires or `NOWAIT` fails
+                        "55P03" => return Some(SqlErr::LockTimeout(e.message().into())),
+                        // serialization_failure, which CockroachDB reports for every
+                        // retryable transaction
+                        "40001" => return Some(SqlErr::SerializationFailure(e.message().into())),
                         _ => return None,
                     }
                 }
@@ -241,11 +313,72 @@ impl DbErr {
                         "787" => {
                             return Some(SqlErr::ForeignKeyConstraintViolation(e.message().into()))
                         }
+                        // SQLITE_BUSY, SQLITE_BUSY_RECOVERY, SQLITE_BUSY_SNAPSHOT,
+                        // SQLITE_BUSY_TIMEOUT, SQLITE_LOCKED and SQLITE_LOCKED_SHAREDCACHE;
+                        // SQLite reports every lock conflict as busy
+                        "5" | "261" | "517" | "773" | "6" | "262" => {
+                            return Some(SqlErr::LockTimeout(e.message().into()))
+                        }
                         _ => return None,
                     }
                 }
             }
         }
+        #[cfg(feature = "libsql")]
+        if let DbErr::Exec(RuntimeErr::LibsqlError(e)) | DbErr::Query(RuntimeErr::LibsqlError(e)) =
+            self
+        {
+            return match crate::driver::libsql_db::libsql_error_code(e) {
+                // error code 1555 refers to the primary key's unique constraint violation
+                // error code 2067 refers to the UNIQUE unique constraint violation
+                Some(1555 | 2067) => Some(SqlErr::UniqueConstraintViolation(e.to_string())),
+                Some(787) => Some(SqlErr::ForeignKeyConstraintViolation(e.to_string())),
+                // SQLITE_BUSY, SQLITE_BUSY_RECOVERY, SQLITE_BUSY_SNAPSHOT, SQLITE_BUSY_TIMEOUT,
+                // SQLITE_LOCKED and SQLITE_LOCKED_SHAREDCACHE
+                Some(5 | 261 | 517 | 773 | 6 | 262) => Some(SqlErr::LockTimeout(e.to_string())),
+                _ => None,
+            };
+        }
+        #[cfg(feature = "duckdb")]
+        if let DbErr::Exec(RuntimeErr::DuckdbError(e)) | DbErr::Query(RuntimeErr::DuckdbError(e)) =
+            self
+        {
+            // DuckDB only reports the kind of constraint in the message
+            let message = e.to_string();
+            return if !message.contains("Constraint Error") {
+                None
+            } else if message.contains("foreign key") {
+                Some(SqlErr::ForeignKeyConstraintViolation(message))
+            } else if message.contains("Duplicate key") {
+                Some(SqlErr::UniqueConstraintViolation(message))
+            } else {
+                None
+            };
+        }
+        #[cfg(feature = "tokio-postgres")]
+        if let DbErr::Exec(RuntimeErr::TokioPostgresError(e))
+        | DbErr::Query(RuntimeErr::TokioPostgresError(e)) = self
+        {
+            use tokio_postgres::error::SqlState;
+            return match e.code() {
+                Some(&SqlState::UNIQUE_VIOLATION) => {
+                    Some(SqlErr::UniqueConstraintViolation(e.to_string()))
+                }
+                Some(&SqlState::FOREIGN_KEY_VIOLATION) => {
+                    Some(SqlErr::ForeignKeyConstraintViolation(e.to_string()))
+                }
+                Some(&SqlState::T_R_DEADLOCK_DETECTED) => Some(SqlErr::Deadlock(e.to_string())),
+                Some(&SqlState::LOCK_NOT_AVAILABLE) => Some(SqlErr::LockTimeout(e.to_string())),
+                Some(&SqlState::T_R_SERIALIZATION_FAILURE) => {
+                    Some(SqlErr::SerializationFailure(e.to_string()))
+                }
+                _ => None,
+            };
+        }
+        #[cfg(feature = "mock")]
+        if let DbErr::Exec(RuntimeErr::Mock(e)) | DbErr::Query(RuntimeErr::Mock(e)) = self {
+            return Some(e.clone());
+        }
         None
     }
 }
@@ -0,0 +1,188 @@
+use crate::{
+    error::*, EntityTrait, IntoSimpleExpr, QuerySelect, ReadConnectionTrait, Select, TryGetable,
+};
+use sea_query::{Func, SimpleExpr};
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Count the distinct non-null values of a column or expression
+    ///
+    /// Like the other aggregates here, it runs over all the rows the select matches,
+    /// ignoring its order, limit and offset.
+    pub async fn count_distinct<A, C>(self, expr: A, db: &C) -> Result<u64, DbErr>
+    where
+        A: IntoSimpleExpr,
+        C: ReadConnectionTrait,
+    {
+        let count: Option<i64> = self
+            .aggregate(Func::count_distinct(expr.into_simple_expr()).into(), db)
+            .await?;
+        Ok(count.unwrap_or_default() as u64)
+    }
+
+    /// Sum a column or expression, `None` if no row has a value
+    ///
+    /// The type of the result depends on the backend and the column: integer columns sum to a
+    /// `Decimal` on Postgres and MySQL, and to an `i64` on SQLite.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Sqlite)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "value" => Into::<Value>::into(6i64),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let total: Option<i64> = fruit::Entity::find()
+    ///     .filter(fruit::Column::Name.contains("apple"))
+    ///     .sum(fruit::Column::Id, &db)
+    ///     .await?;
+    /// assert_eq!(total, Some(6));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Sqlite,
+    ///         r#"SELECT SUM("fruit"."id") AS "value" FROM "fruit" WHERE "fruit"."name" LIKE ? LIMIT ?"#,
+    ///         ["%apple%".into(), 1u64.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sum<T, A, C>(self, expr: A, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        A: IntoSimpleExpr,
+        C: ReadConnectionTrait,
+    {
+        self.aggregate(Func::sum(expr.into_simple_expr()).into(), db)
+            .await
+    }
+
+    /// Average a column or expression, `None` if no row has a value
+    ///
+    /// The average of an integer column is a `Decimal` on Postgres and MySQL, and an `f64`
+    /// on SQLite.
+    pub async fn avg<T, A, C>(self, expr: A, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        A: IntoSimpleExpr,
+        C: ReadConnectionTrait,
+    {
+        self.aggregate(Func::avg(expr.into_simple_expr()).into(), db)
+            .await
+    }
+
+    /// The smallest value of a column or expression, `None` if no row has a value
+    pub async fn min<T, A, C>(self, expr: A, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        A: IntoSimpleExpr,
+        C: ReadConnectionTrait,
+    {
+        self.aggregate(Func::min(expr.into_simple_expr()).into(), db)
+            .await
+    }
+
+    /// The largest value of a column or expression, `None` if no row has a value
+    pub async fn max<T, A, C>(self, expr: A, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        A: IntoSimpleExpr,
+        C: ReadConnectionTrait,
+    {
+        self.aggregate(Func::max(expr.into_simple_expr()).into(), db)
+            .await
+    }
+
+    async fn aggregate<T, C>(mut self, expr: SimpleExpr, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        C: ReadConnectionTrait,
+    {
+        self.query.clear_order_by().reset_limit().reset_offset();
+        let value = self
+            .select_only()
+            .expr_as(expr, "value")
+            .into_tuple::<Option<T>>()
+            .one(db)
+            .await?;
+        Ok(value.flatten())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn aggregates() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "value" => Value::from(2i64),
+            }]])
+            .append_query_results([[maplit::btreemap! {
+                "value" => Value::from(None::<i32>),
+            }]])
+            .append_query_results([[maplit::btreemap! {
+                "value" => Value::from(Some("Apple")),
+            }]])
+            .into_connection();
+
+        let select = fruit::Entity::find()
+            .filter(fruit::Column::CakeId.is_not_null())
+            .order_by_asc(fruit::Column::Id)
+            .limit(10);
+        assert_eq!(
+            select
+                .clone()
+                .count_distinct(fruit::Column::CakeId, &db)
+                .await?,
+            2
+        );
+        assert_eq!(
+            select
+                .clone()
+                .max::<i32, _, _>(fruit::Column::CakeId, &db)
+                .await?,
+            None
+        );
+        assert_eq!(
+            select.min(fruit::Column::Name, &db).await?,
+            Some("Apple".to_owned())
+        );
+
+        let sql = |expr: &str| {
+            Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                format!(
+                    r#"SELECT {expr} AS "value" FROM "fruit" WHERE "fruit"."cake_id" IS NOT NULL LIMIT $1"#
+                ),
+                [1u64.into()],
+            )
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                sql(r#"COUNT(DISTINCT "fruit"."cake_id")"#),
+                sql(r#"MAX("fruit"."cake_id")"#),
+                sql(r#"MIN("fruit"."name")"#),
+            ]
+        );
+
+        Ok(())
+    }
+}
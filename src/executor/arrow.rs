@@ -0,0 +1,469 @@
+use crate::{
+    error::*, ColumnTrait, ConnectionTrait, EntityTrait, IdenStatic, Iterable, PinBoxStream,
+    QueryResult, QueryTrait, Select, StreamTrait,
+};
+use arrow_array::{builder::*, ArrayRef, RecordBatch};
+use arrow_schema::{Field, Schema};
+use async_stream::try_stream;
+use futures_util::TryStreamExt;
+use sea_query::ColumnType;
+use std::sync::Arc;
+
+/// The precision and scale of decimal columns that do not specify them
+#[cfg(feature = "with-rust_decimal")]
+const DEFAULT_DECIMAL: (u32, u32) = (38, 10);
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Fetch the result of the query as an Arrow [RecordBatch], with an Arrow column for
+    /// every column of the entity, typed after its [ColumnType]. The result set must include
+    /// every column of the entity, as it does unless it is changed with `select_only`.
+    ///
+    /// The batch can be handed to any Arrow based tool, e.g. a Polars `DataFrame`.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model {
+    /// #         id: 1,
+    /// #         name: "New York Cheese".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let batch = cake::Entity::find().into_arrow(&db).await?;
+    ///
+    /// assert_eq!(batch.num_rows(), 1);
+    /// assert_eq!(batch.schema().field(1).name(), "name");
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn into_arrow<C>(self, db: &C) -> Result<RecordBatch, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut batch = ArrowBatch::new::<E>()?;
+        check_refusal(self.refusal)?;
+        let stmt = self.build(db.get_database_backend());
+        let rows = db.query_all(stmt).await?;
+        for row in rows.iter() {
+            batch.append(row)?;
+        }
+        batch.finish()
+    }
+
+    /// Stream the result of the query as Arrow [RecordBatch]es of up to `batch_size` rows,
+    /// as with [Select::into_arrow]
+    pub async fn stream_arrow<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        batch_size: usize,
+    ) -> Result<PinBoxStream<'b, Result<RecordBatch, DbErr>>, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+    {
+        let mut batch = ArrowBatch::new::<E>()?;
+        check_refusal(self.refusal)?;
+        let stmt = self.build(db.get_database_backend());
+        let mut rows = Box::pin(db.stream(stmt).await?);
+        let batch_size = batch_size.max(1);
+        Ok(Box::pin(try_stream! {
+            let mut len = 0;
+            while let Some(row) = rows.try_next().await? {
+                batch.append(&row)?;
+                len += 1;
+                if len == batch_size {
+                    yield batch.finish()?;
+                    len = 0;
+                }
+            }
+            if len > 0 {
+                yield batch.finish()?;
+            }
+        }))
+    }
+}
+
+/// The rows collected so far, column by column
+struct ArrowBatch {
+    columns: Vec<ArrowColumn>,
+}
+
+struct ArrowColumn {
+    name: String,
+    nullable: bool,
+    builder: ColumnBuilder,
+}
+
+impl ArrowBatch {
+    fn new<E>() -> Result<Self, DbErr>
+    where
+        E: EntityTrait,
+    {
+        let mut columns = Vec::new();
+        for column in E::Column::iter() {
+            let def = column.def();
+            columns.push(ArrowColumn {
+                name: column.as_str().to_owned(),
+                nullable: def.is_null(),
+                builder: ColumnBuilder::new(def.get_column_type())?,
+            });
+        }
+        Ok(Self { columns })
+    }
+
+    fn append(&mut self, row: &QueryResult) -> Result<(), DbErr> {
+        for column in self.columns.iter_mut() {
+            column.builder.append(row, &column.name)?;
+        }
+        Ok(())
+    }
+
+    /// Take the rows collected so far
+    fn finish(&mut self) -> Result<RecordBatch, DbErr> {
+        let (fields, arrays): (Vec<_>, Vec<_>) = self
+            .columns
+            .iter_mut()
+            .map(|column| {
+                let array = column.builder.finish();
+                let field = Field::new(&column.name, array.data_type().clone(), column.nullable);
+                (field, array)
+            })
+            .unzip();
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+            .map_err(|err| DbErr::Type(err.to_string()))
+    }
+}
+
+enum ColumnBuilder {
+    Int8(Int8Builder),
+    Int16(Int16Builder),
+    Int32(Int32Builder),
+    Int64(Int64Builder),
+    UInt8(UInt8Builder),
+    UInt16(UInt16Builder),
+    UInt32(UInt32Builder),
+    UInt64(UInt64Builder),
+    Float32(Float32Builder),
+    Float64(Float64Builder),
+    Boolean(BooleanBuilder),
+    String(StringBuilder),
+    Binary(BinaryBuilder),
+    #[cfg(feature = "with-json")]
+    Json(StringBuilder),
+    #[cfg(feature = "with-uuid")]
+    Uuid(FixedSizeBinaryBuilder),
+    #[cfg(feature = "with-rust_decimal")]
+    Decimal(Decimal128Builder, u32),
+    #[cfg(feature = "with-chrono")]
+    Date(Date32Builder),
+    #[cfg(feature = "with-chrono")]
+    Time(Time64MicrosecondBuilder),
+    #[cfg(feature = "with-chrono")]
+    DateTime(TimestampMicrosecondBuilder),
+    #[cfg(feature = "with-chrono")]
+    DateTimeWithTimeZone(TimestampMicrosecondBuilder),
+}
+
+impl ColumnBuilder {
+    fn new(col_type: &ColumnType) -> Result<Self, DbErr> {
+        Ok(match col_type {
+            ColumnType::TinyInteger => Self::Int8(Default::default()),
+            ColumnType::SmallInteger => Self::Int16(Default::default()),
+            ColumnType::Integer => Self::Int32(Default::default()),
+            ColumnType::BigInteger => Self::Int64(Default::default()),
+            ColumnType::TinyUnsigned => Self::UInt8(Default::default()),
+            ColumnType::SmallUnsigned => Self::UInt16(Default::default()),
+            ColumnType::Unsigned => Self::UInt32(Default::default()),
+            ColumnType::BigUnsigned => Self::UInt64(Default::default()),
+            ColumnType::Float => Self::Float32(Default::default()),
+            ColumnType::Double => Self::Float64(Default::default()),
+            ColumnType::Boolean => Self::Boolean(Default::default()),
+            ColumnType::Char(_)
+            | ColumnType::String(_)
+            | ColumnType::Text
+            | ColumnType::Enum { .. }
+            | ColumnType::Custom(_) => Self::String(Default::default()),
+            ColumnType::Binary(_) | ColumnType::VarBinary(_) | ColumnType::Blob => {
+                Self::Binary(Default::default())
+            }
+            #[cfg(feature = "with-json")]
+            ColumnType::Json | ColumnType::JsonBinary => Self::Json(Default::default()),
+            #[cfg(feature = "with-uuid")]
+            ColumnType::Uuid => Self::Uuid(FixedSizeBinaryBuilder::new(16)),
+            #[cfg(feature = "with-rust_decimal")]
+            ColumnType::Decimal(precision) | ColumnType::Money(precision) => {
+                let (precision, scale) = precision.unwrap_or(DEFAULT_DECIMAL);
+                let builder = Decimal128Builder::new()
+                    .with_precision_and_scale(precision as u8, scale as i8)
+                    .map_err(|err| DbErr::Type(err.to_string()))?;
+                Self::Decimal(builder, scale)
+            }
+            #[cfg(feature = "with-chrono")]
+            ColumnType::Date => Self::Date(Default::default()),
+            #[cfg(feature = "with-chrono")]
+            ColumnType::Time => Self::Time(Default::default()),
+            #[cfg(feature = "with-chrono")]
+            ColumnType::DateTime | ColumnType::Timestamp => Self::DateTime(Default::default()),
+            #[cfg(feature = "with-chrono")]
+            ColumnType::TimestampWithTimeZone => Self::DateTimeWithTimeZone(
+                TimestampMicrosecondBuilder::new().with_timezone("+00:00"),
+            ),
+            _ => {
+                return Err(DbErr::Type(format!(
+                    "{col_type:?} columns cannot be exported to Arrow"
+                )))
+            }
+        })
+    }
+
+    fn append(&mut self, row: &QueryResult, col: &str) -> Result<(), DbErr> {
+        macro_rules! append {
+            ( $builder: expr, $type: ty ) => {
+                $builder.append_option(row.try_get::<Option<$type>>("", col)?)
+            };
+            ( $builder: expr, $type: ty, $map: expr ) => {
+                $builder.append_option(row.try_get::<Option<$type>>("", col)?.map($map))
+            };
+        }
+
+        match self {
+            Self::Int8(builder) => append!(builder, i8),
+            Self::Int16(builder) => append!(builder, i16),
+            Self::Int32(builder) => append!(builder, i32),
+            Self::Int64(builder) => append!(builder, i64),
+            Self::UInt8(builder) => append!(builder, u8),
+            Self::UInt16(builder) => append!(builder, u16),
+            Self::UInt32(builder) => append!(builder, u32),
+            Self::UInt64(builder) => append!(builder, u64),
+            Self::Float32(builder) => append!(builder, f32),
+            Self::Float64(builder) => append!(builder, f64),
+            Self::Boolean(builder) => append!(builder, bool),
+            Self::String(builder) => append!(builder, String),
+            Self::Binary(builder) => append!(builder, Vec<u8>),
+            #[cfg(feature = "with-json")]
+            Self::Json(builder) => append!(builder, serde_json::Value, |json| json.to_string()),
+            #[cfg(feature = "with-uuid")]
+            Self::Uuid(builder) => match row.try_get::<Option<uuid::Uuid>>("", col)? {
+                Some(uuid) => builder
+                    .append_value(uuid.as_bytes())
+                    .map_err(|err| DbErr::Type(err.to_string()))?,
+                None => builder.append_null(),
+            },
+            #[cfg(feature = "with-rust_decimal")]
+            Self::Decimal(builder, scale) => {
+                append!(builder, rust_decimal::Decimal, |mut decimal| {
+                    decimal.rescale(*scale);
+                    decimal.mantissa()
+                })
+            }
+            #[cfg(feature = "with-chrono")]
+            Self::Date(builder) => append!(
+                builder,
+                chrono::NaiveDate,
+                arrow_array::types::Date32Type::from_naive_date
+            ),
+            #[cfg(feature = "with-chrono")]
+            Self::Time(builder) => append!(builder, chrono::NaiveTime, |time| {
+                use chrono::Timelike;
+                time.num_seconds_from_midnight() as i64 * 1_000_000
+                    + time.nanosecond() as i64 / 1_000
+            }),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTime(builder) => append!(builder, chrono::NaiveDateTime, |datetime| {
+                datetime.and_utc().timestamp_micros()
+            }),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTimeWithTimeZone(builder) => {
+                append!(builder, chrono::DateTime<chrono::FixedOffset>, |datetime| {
+                    datetime.timestamp_micros()
+                })
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            Self::Int8(builder) => Arc::new(builder.finish()),
+            Self::Int16(builder) => Arc::new(builder.finish()),
+            Self::Int32(builder) => Arc::new(builder.finish()),
+            Self::Int64(builder) => Arc::new(builder.finish()),
+            Self::UInt8(builder) => Arc::new(builder.finish()),
+            Self::UInt16(builder) => Arc::new(builder.finish()),
+            Self::UInt32(builder) => Arc::new(builder.finish()),
+            Self::UInt64(builder) => Arc::new(builder.finish()),
+            Self::Float32(builder) => Arc::new(builder.finish()),
+            Self::Float64(builder) => Arc::new(builder.finish()),
+            Self::Boolean(builder) => Arc::new(builder.finish()),
+            Self::String(builder) => Arc::new(builder.finish()),
+            Self::Binary(builder) => Arc::new(builder.finish()),
+            #[cfg(feature = "with-json")]
+            Self::Json(builder) => Arc::new(builder.finish()),
+            #[cfg(feature = "with-uuid")]
+            Self::Uuid(builder) => Arc::new(builder.finish()),
+            #[cfg(feature = "with-rust_decimal")]
+            Self::Decimal(builder, _) => Arc::new(builder.finish()),
+            #[cfg(feature = "with-chrono")]
+            Self::Date(builder) => Arc::new(builder.finish()),
+            #[cfg(feature = "with-chrono")]
+            Self::Time(builder) => Arc::new(builder.finish()),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTime(builder) => Arc::new(builder.finish()),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTimeWithTimeZone(builder) => Arc::new(builder.finish()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(
+    feature = "mock",
+    feature = "with-json",
+    feature = "with-uuid",
+    feature = "with-rust_decimal",
+    feature = "with-chrono"
+))]
+mod tests {
+    use crate::{entity::prelude::*, DbBackend, MockDatabase};
+    use arrow_array::{
+        cast::AsArray,
+        types::{Date32Type, Decimal128Type, Int32Type, TimestampMicrosecondType},
+    };
+    use arrow_schema::{DataType, TimeUnit};
+    use futures_util::TryStreamExt;
+
+    mod order {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "order")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub customer: Option<String>,
+            #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+            pub total: Decimal,
+            pub placed_on: Date,
+            pub placed_at: DateTimeWithTimeZone,
+            pub token: Uuid,
+            pub details: Json,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn order(id: i32, customer: Option<&str>) -> order::Model {
+        order::Model {
+            id,
+            customer: customer.map(ToOwned::to_owned),
+            total: "12.5".parse().unwrap(),
+            placed_on: "2024-01-02".parse().unwrap(),
+            placed_at: "2024-01-02T03:04:05Z".parse().unwrap(),
+            token: Uuid::nil(),
+            details: serde_json::json!({ "gift": true }),
+        }
+    }
+
+    #[smol_potat::test]
+    async fn into_arrow() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[order(1, Some("Bob")), order(2, None)]])
+            .into_connection();
+
+        let batch = order::Entity::find().into_arrow(&db).await?;
+        let schema = batch.schema();
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(
+            schema
+                .fields()
+                .iter()
+                .map(|field| (
+                    field.name().as_str(),
+                    field.data_type().clone(),
+                    field.is_nullable()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("id", DataType::Int32, false),
+                ("customer", DataType::Utf8, true),
+                ("total", DataType::Decimal128(10, 2), false),
+                ("placed_on", DataType::Date32, false),
+                (
+                    "placed_at",
+                    DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into())),
+                    false
+                ),
+                ("token", DataType::FixedSizeBinary(16), false),
+                ("details", DataType::Utf8, false),
+            ]
+        );
+        assert_eq!(
+            batch.column(0).as_primitive::<Int32Type>().values(),
+            &[1, 2]
+        );
+        assert_eq!(
+            batch
+                .column(1)
+                .as_string::<i32>()
+                .iter()
+                .collect::<Vec<_>>(),
+            [Some("Bob"), None]
+        );
+        assert_eq!(
+            batch.column(2).as_primitive::<Decimal128Type>().value(0),
+            1250
+        );
+        assert_eq!(batch.column(3).as_primitive::<Date32Type>().value(0), 19724);
+        assert_eq!(
+            batch
+                .column(4)
+                .as_primitive::<TimestampMicrosecondType>()
+                .value(0),
+            1_704_164_645_000_000
+        );
+        assert_eq!(
+            batch.column(6).as_string::<i32>().value(0),
+            r#"{"gift":true}"#
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn stream_arrow() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[order(1, None), order(2, None), order(3, None)]])
+            .into_connection();
+
+        let batches: Vec<_> = order::Entity::find()
+            .stream_arrow(&db, 2)
+            .await?
+            .try_collect()
+            .await?;
+
+        assert_eq!(
+            batches
+                .iter()
+                .map(|batch| batch.num_rows())
+                .collect::<Vec<_>>(),
+            [2, 1]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,521 @@
+use crate::{
+    error::*, ColumnTrait, ConnectionTrait, EntityTrait, IdenStatic, Iterable, ModelTrait, Select,
+    Value,
+};
+use sea_query::{ColumnType, Expr, Query, SimpleExpr};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::{Read, Write},
+    str::FromStr,
+};
+
+/// Export and import the rows of an entity as CSV
+///
+/// The CSV has a header row naming the columns. Values are written in their text form,
+/// dates and times as ISO 8601, and NULL as an empty field.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model {
+/// #         id: 1,
+/// #         name: "New York Cheese".to_owned(),
+/// #     }]])
+/// #     .append_exec_results([MockExecResult {
+/// #         last_insert_id: 2,
+/// #         rows_affected: 1,
+/// #     }])
+/// #     .into_connection();
+/// #
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake, CsvImportOptions, CsvTrait};
+///
+/// let mut csv = Vec::new();
+/// cake::Entity::export_csv(&db, &mut csv, cake::Entity::find()).await?;
+/// assert_eq!(csv, b"id,name\n1,New York Cheese\n");
+///
+/// let report = cake::Entity::import_csv(
+///     &db,
+///     "name\nChocolate Forest\n".as_bytes(),
+///     &CsvImportOptions::new(),
+/// )
+/// .await?;
+/// assert_eq!(report.inserted, 1);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait CsvTrait: EntityTrait {
+    /// Write the result of a select as CSV, returning the number of rows written
+    async fn export_csv<C, W>(db: &C, writer: W, select: Select<Self>) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+        W: Write + Send,
+    {
+        let models = select.all(db).await?;
+        let columns: Vec<Self::Column> = Self::Column::iter().collect();
+        let mut writer = csv::Writer::from_writer(writer);
+        writer
+            .write_record(columns.iter().map(|col| col.as_str()))
+            .map_err(csv_err)?;
+        for model in models.iter() {
+            let mut record = Vec::with_capacity(columns.len());
+            for col in columns.iter() {
+                record.push(
+                    format_value(model.get(*col))
+                        .map_err(|err| DbErr::Type(format!("{}: {err}", col.as_str())))?,
+                );
+            }
+            writer.write_record(&record).map_err(csv_err)?;
+        }
+        writer
+            .flush()
+            .map_err(|err| DbErr::Custom(err.to_string()))?;
+        Ok(models.len() as u64)
+    }
+
+    /// Insert the rows of a CSV in batches. The header row is mapped to columns by name,
+    /// or as given by [CsvImportOptions::map_header]; the columns missing from it are left
+    /// to their default.
+    ///
+    /// Each field is parsed according to the type of its column. An empty field is NULL for
+    /// nullable columns and an empty string for text columns. The rows failing to parse or
+    /// to insert are skipped and reported in [CsvImportReport::errors]; a failing batch is
+    /// retried row by row to find them. Note that a failing statement aborts a Postgres
+    /// transaction, so the retries only work outside of one.
+    async fn import_csv<C, R>(
+        db: &C,
+        reader: R,
+        options: &CsvImportOptions,
+    ) -> Result<CsvImportReport, DbErr>
+    where
+        C: ConnectionTrait,
+        R: Read + Send,
+    {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(options.delimiter)
+            .from_reader(reader);
+        let mut columns = Vec::new();
+        for header in reader.headers().map_err(csv_err)?.iter() {
+            let name = options.headers.get(header).map_or(header, String::as_str);
+            match Self::Column::from_str(name) {
+                Ok(col) => columns.push(Some(col)),
+                Err(_) if options.ignore_unknown_headers => columns.push(None),
+                Err(_) => {
+                    return Err(DbErr::Custom(format!(
+                        "CSV header \"{header}\" does not match any column"
+                    )))
+                }
+            }
+        }
+        let insert_columns: Vec<Self::Column> = columns.iter().flatten().copied().collect();
+        if insert_columns.is_empty() {
+            return Err(DbErr::Custom(
+                "CSV header has no columns to import".to_owned(),
+            ));
+        }
+
+        let mut report = CsvImportReport::default();
+        let mut batch = Vec::new();
+        for record in reader.records() {
+            let (line, row) = match record {
+                Ok(record) => {
+                    let line = record.position().map_or(0, |pos| pos.line());
+                    (line, parse_record(&columns, &record))
+                }
+                Err(err) => (
+                    err.position().map_or(0, |pos| pos.line()),
+                    Err(err.to_string()),
+                ),
+            };
+            match row {
+                Ok(row) => batch.push((line, row)),
+                Err(message) => report.errors.push(CsvRowError { line, message }),
+            }
+            if batch.len() >= options.batch_size.max(1) {
+                insert_rows::<Self, C>(
+                    db,
+                    &insert_columns,
+                    std::mem::take(&mut batch),
+                    &mut report,
+                )
+                .await;
+            }
+        }
+        insert_rows::<Self, C>(db, &insert_columns, batch, &mut report).await;
+        Ok(report)
+    }
+}
+
+impl<E> CsvTrait for E where E: EntityTrait {}
+
+/// The options of [CsvTrait::import_csv]
+#[derive(Debug, Clone)]
+pub struct CsvImportOptions {
+    pub(crate) batch_size: usize,
+    pub(crate) delimiter: u8,
+    pub(crate) headers: HashMap<String, String>,
+    pub(crate) ignore_unknown_headers: bool,
+}
+
+impl Default for CsvImportOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 500,
+            delimiter: b',',
+            headers: HashMap::new(),
+            ignore_unknown_headers: false,
+        }
+    }
+}
+
+impl CsvImportOptions {
+    /// Create the default options: batches of 500 rows, comma delimited
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of rows inserted per statement
+    pub fn batch_size(&mut self, value: usize) -> &mut Self {
+        self.batch_size = value;
+        self
+    }
+
+    /// Set the field delimiter
+    pub fn delimiter(&mut self, value: u8) -> &mut Self {
+        self.delimiter = value;
+        self
+    }
+
+    /// Import the CSV header `header` into the column named `column`
+    pub fn map_header<H, C>(&mut self, header: H, column: C) -> &mut Self
+    where
+        H: Into<String>,
+        C: Into<String>,
+    {
+        self.headers.insert(header.into(), column.into());
+        self
+    }
+
+    /// Skip the CSV headers that do not match any column, instead of failing
+    pub fn ignore_unknown_headers(&mut self, value: bool) -> &mut Self {
+        self.ignore_unknown_headers = value;
+        self
+    }
+}
+
+/// The outcome of [CsvTrait::import_csv]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CsvImportReport {
+    /// The number of rows inserted
+    pub inserted: u64,
+    /// The rows that failed to be parsed or inserted
+    pub errors: Vec<CsvRowError>,
+}
+
+/// A row of a CSV that failed to be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRowError {
+    /// The line of the row in the CSV, starting from 1
+    pub line: u64,
+    /// The reason of the failure
+    pub message: String,
+}
+
+fn csv_err(err: csv::Error) -> DbErr {
+    DbErr::Custom(format!("CSV error: {err}"))
+}
+
+fn parse_record<C>(
+    columns: &[Option<C>],
+    record: &csv::StringRecord,
+) -> Result<Vec<SimpleExpr>, String>
+where
+    C: ColumnTrait,
+{
+    let mut row = Vec::new();
+    for (col, field) in columns.iter().zip(record.iter()) {
+        let Some(col) = col else {
+            continue;
+        };
+        let value =
+            parse_value(&col.def(), field).map_err(|err| format!("{}: {err}", col.as_str()))?;
+        row.push(col.save_as(Expr::val(value)));
+    }
+    if row.len() < columns.iter().flatten().count() {
+        return Err("Missing fields".to_owned());
+    }
+    Ok(row)
+}
+
+async fn insert_rows<E, C>(
+    db: &C,
+    columns: &[E::Column],
+    rows: Vec<(u64, Vec<SimpleExpr>)>,
+    report: &mut CsvImportReport,
+) where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    let insert = |rows: &[(u64, Vec<SimpleExpr>)]| {
+        let mut insert = Query::insert();
+        insert
+            .into_table(E::default().table_ref())
+            .columns(columns.iter().copied());
+        for (_, row) in rows.iter() {
+            insert.values_panic(row.iter().cloned());
+        }
+        db.get_database_backend().build(&insert)
+    };
+
+    if rows.is_empty() {
+        return;
+    }
+    if rows.len() > 1 && db.execute(insert(&rows)).await.is_ok() {
+        report.inserted += rows.len() as u64;
+        return;
+    }
+    for row in rows.chunks(1) {
+        match db.execute(insert(row)).await {
+            Ok(_) => report.inserted += 1,
+            Err(err) => report.errors.push(CsvRowError {
+                line: row[0].0,
+                message: err.to_string(),
+            }),
+        }
+    }
+}
+
+fn display<T>(value: Option<T>) -> String
+where
+    T: Display,
+{
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn format_value(value: Value) -> Result<String, String> {
+    Ok(match value {
+        Value::Bool(v) => display(v),
+        Value::TinyInt(v) => display(v),
+        Value::SmallInt(v) => display(v),
+        Value::Int(v) => display(v),
+        Value::BigInt(v) => display(v),
+        Value::TinyUnsigned(v) => display(v),
+        Value::SmallUnsigned(v) => display(v),
+        Value::Unsigned(v) => display(v),
+        Value::BigUnsigned(v) => display(v),
+        Value::Float(v) => display(v),
+        Value::Double(v) => display(v),
+        Value::String(v) => display(v),
+        Value::Char(v) => display(v),
+        #[cfg(feature = "with-json")]
+        Value::Json(v) => display(v),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(v) => display(v),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(v) => display(v),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(v) => display(v.map(|v| v.format("%Y-%m-%dT%H:%M:%S%.f"))),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(v) => display(v.map(|v| v.to_rfc3339())),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(v) => display(v.map(|v| v.to_rfc3339())),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(v) => display(v.map(|v| v.to_rfc3339())),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(v) => display(v),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(v) => display(v),
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(v) => display(v),
+        value => return Err(format!("{value:?} cannot be written as CSV")),
+    })
+}
+
+fn parse<T>(field: Option<&str>) -> Result<Value, String>
+where
+    T: FromStr + Into<Value> + sea_query::Nullable,
+    T::Err: Display,
+{
+    let value = field
+        .map(|field| {
+            field
+                .parse::<T>()
+                .map_err(|err| format!("{err} in \"{field}\""))
+        })
+        .transpose()?;
+    Ok(value.into())
+}
+
+fn parse_value(def: &crate::ColumnDef, field: &str) -> Result<Value, String> {
+    let col_type = def.get_column_type();
+    let is_text = matches!(
+        col_type,
+        ColumnType::Char(_)
+            | ColumnType::String(_)
+            | ColumnType::Text
+            | ColumnType::Enum { .. }
+            | ColumnType::Custom(_)
+    );
+    let field = match field {
+        "" if def.is_null() => None,
+        "" if !is_text => return Err("Missing value".to_owned()),
+        field => Some(field),
+    };
+    match col_type {
+        ColumnType::TinyInteger => parse::<i8>(field),
+        ColumnType::SmallInteger => parse::<i16>(field),
+        ColumnType::Integer => parse::<i32>(field),
+        ColumnType::BigInteger => parse::<i64>(field),
+        ColumnType::TinyUnsigned => parse::<u8>(field),
+        ColumnType::SmallUnsigned => parse::<u16>(field),
+        ColumnType::Unsigned => parse::<u32>(field),
+        ColumnType::BigUnsigned => parse::<u64>(field),
+        ColumnType::Float => parse::<f32>(field),
+        ColumnType::Double => parse::<f64>(field),
+        ColumnType::Boolean => {
+            let value = field
+                .map(|field| match field.to_ascii_lowercase().as_str() {
+                    "true" | "t" | "1" => Ok(true),
+                    "false" | "f" | "0" => Ok(false),
+                    _ => Err(format!("invalid boolean \"{field}\"")),
+                })
+                .transpose()?;
+            Ok(value.into())
+        }
+        _ if is_text => parse::<String>(field),
+        #[cfg(feature = "with-json")]
+        ColumnType::Json | ColumnType::JsonBinary => parse::<serde_json::Value>(field),
+        #[cfg(feature = "with-uuid")]
+        ColumnType::Uuid => parse::<uuid::Uuid>(field),
+        #[cfg(feature = "with-rust_decimal")]
+        ColumnType::Decimal(_) | ColumnType::Money(_) => parse::<rust_decimal::Decimal>(field),
+        #[cfg(all(feature = "with-bigdecimal", not(feature = "with-rust_decimal")))]
+        ColumnType::Decimal(_) | ColumnType::Money(_) => parse::<bigdecimal::BigDecimal>(field),
+        #[cfg(feature = "with-chrono")]
+        ColumnType::Date => parse::<chrono::NaiveDate>(field),
+        #[cfg(feature = "with-chrono")]
+        ColumnType::Time => parse::<chrono::NaiveTime>(field),
+        #[cfg(feature = "with-chrono")]
+        ColumnType::DateTime | ColumnType::Timestamp => parse::<chrono::NaiveDateTime>(field),
+        #[cfg(feature = "with-chrono")]
+        ColumnType::TimestampWithTimeZone => parse::<chrono::DateTime<chrono::FixedOffset>>(field),
+        col_type => Err(format!("{col_type:?} columns cannot be read from CSV")),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::*, tests_cfg::fruit, DbBackend, MockDatabase, MockExecResult, Transaction,
+    };
+
+    fn exec_result(rows_affected: u64) -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        }
+    }
+
+    #[smol_potat::test]
+    async fn export_csv() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                fruit::Model {
+                    id: 1,
+                    name: "Apple, red".to_owned(),
+                    cake_id: Some(2),
+                },
+                fruit::Model {
+                    id: 2,
+                    name: "Banana".to_owned(),
+                    cake_id: None,
+                },
+            ]])
+            .into_connection();
+
+        let mut csv = Vec::new();
+        assert_eq!(
+            fruit::Entity::export_csv(&db, &mut csv, fruit::Entity::find()).await?,
+            2
+        );
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "id,name,cake_id\n1,\"Apple, red\",2\n2,Banana,\n"
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn import_csv() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([exec_result(2), exec_result(1)])
+            .into_connection();
+
+        let csv = "fruit,cake_id,note\nApple,1,x\nBanana,,y\nCherry,two,z\nDurian,3,w\n";
+        let report = fruit::Entity::import_csv(
+            &db,
+            csv.as_bytes(),
+            CsvImportOptions::new()
+                .batch_size(2)
+                .map_header("fruit", "name")
+                .ignore_unknown_headers(true),
+        )
+        .await?;
+
+        assert_eq!(
+            report,
+            CsvImportReport {
+                inserted: 3,
+                errors: vec![CsvRowError {
+                    line: 4,
+                    message: "cake_id: invalid digit found in string in \"two\"".to_owned(),
+                }],
+            }
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "fruit" ("name", "cake_id") VALUES ($1, $2), ($3, $4)"#,
+                    [
+                        "Apple".into(),
+                        1i32.into(),
+                        "Banana".into(),
+                        Option::<i32>::None.into()
+                    ]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "fruit" ("name", "cake_id") VALUES ($1, $2)"#,
+                    ["Durian".into(), 3i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn import_csv_unknown_header() {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        assert_eq!(
+            fruit::Entity::import_csv(&db, "name,note\n".as_bytes(), &CsvImportOptions::new())
+                .await,
+            Err(DbErr::Custom(
+                "CSV header \"note\" does not match any column".to_owned()
+            ))
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Identity, IdentityOf, IntoIdentity,
-    PartialModelTrait, PrimaryKeyToColumn, QueryOrder, QuerySelect, Select, SelectModel,
+    error::*, EntityTrait, FromQueryResult, Identity, IdentityOf, IntoIdentity, PartialModelTrait,
+    PrimaryKeyToColumn, QueryOrder, QuerySelect, ReadConnectionTrait, Select, SelectModel,
     SelectThree, SelectThreeModel, SelectTwo, SelectTwoModel, SelectorTrait,
 };
 use sea_query::{
@@ -22,6 +22,8 @@ where
     query: SelectStatement,
     table: DynIden,
     order_columns: Identity,
+    order_exprs: Vec<(SimpleExpr, bool)>,
+    column_orders: Vec<bool>,
     secondary_order_by: Vec<(DynIden, Identity)>,
     first: Option<u64>,
     last: Option<u64>,
@@ -29,6 +31,7 @@ where
     after: Option<ValueTuple>,
     sort_asc: bool,
     is_result_reversed: bool,
+    refusal: Option<Refusal>,
     phantom: PhantomData<S>,
 }
 
@@ -45,17 +48,26 @@ where
             query,
             table,
             order_columns: order_columns.into_identity(),
+            order_exprs: Vec::new(),
+            column_orders: Vec::new(),
             last: None,
             first: None,
             after: None,
             before: None,
             sort_asc: true,
             is_result_reversed: false,
+            refusal: None,
             phantom: PhantomData,
             secondary_order_by: Default::default(),
         }
     }
 
+    /// Carry the refusal of the query the cursor was made of
+    pub(crate) fn refuse(mut self, refusal: Option<Refusal>) -> Self {
+        self.refusal = refusal;
+        self
+    }
+
     /// Filter paginated result with corresponding column less than the input value
     pub fn before<V>(&mut self, values: V) -> &mut Self
     where
@@ -75,6 +87,18 @@ where
     }
 
     fn apply_filters(&mut self) -> &mut Self {
+        if !self.order_exprs.is_empty() || !self.column_orders.is_empty() {
+            if let Some(values) = self.after.clone() {
+                let condition = self.apply_expr_filter(values, false);
+                self.query.cond_where(condition);
+            }
+            if let Some(values) = self.before.clone() {
+                let condition = self.apply_expr_filter(values, true);
+                self.query.cond_where(condition);
+            }
+            return self;
+        }
+
         if let Some(values) = self.after.clone() {
             let condition = self.apply_filter(values, |c, v| {
                 let exp = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c)));
@@ -190,6 +214,92 @@ where
         }
     }
 
+    /// The same condition as [Cursor::apply_filter], over the order expressions followed by the
+    /// order columns, each compared in its own direction
+    fn apply_expr_filter(&self, values: ValueTuple, before: bool) -> Condition {
+        let keys: Vec<(SimpleExpr, bool)> = self
+            .order_exprs
+            .iter()
+            .cloned()
+            .chain(
+                self.order_columns
+                    .clone()
+                    .into_iter()
+                    .zip(self.column_asc())
+                    .map(|(col, asc)| (Expr::col((SeaRc::clone(&self.table), col)).into(), asc)),
+            )
+            .collect();
+        let values: Vec<Value> = values.into_iter().collect();
+        if keys.len() != values.len() {
+            panic!("column arity mismatch");
+        }
+
+        (1..=keys.len())
+            .rev()
+            .fold(Condition::any(), |cond_any, n| {
+                let inner_cond_all = keys.iter().zip(values.iter()).enumerate().take(n).fold(
+                    Condition::all(),
+                    |inner_cond_all, (i, ((key, asc), val))| {
+                        let key = Expr::expr(key.clone());
+                        let val = val.clone();
+                        let expr = if i != (n - 1) {
+                            key.eq(val)
+                        } else if *asc != before {
+                            key.gt(val)
+                        } else {
+                            key.lt(val)
+                        };
+                        inner_cond_all.add(expr)
+                    },
+                );
+                cond_any.add(inner_cond_all)
+            })
+    }
+
+    /// Order by expressions before the order columns, each ascending or descending regardless
+    /// of [Cursor::asc] and [Cursor::desc], which then only apply to the order columns. The
+    /// order columns break ties between rows with equal expressions, so they should be unique.
+    ///
+    /// The values passed to [Cursor::before] and [Cursor::after] are the values of the
+    /// expressions followed by the values of the order columns.
+    pub fn set_order_exprs<I, X>(&mut self, exprs: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (X, Order)>,
+        X: Into<SimpleExpr>,
+    {
+        self.order_exprs = exprs
+            .into_iter()
+            .map(|(expr, order)| (expr.into(), !matches!(order, Order::Desc)))
+            .collect();
+        self
+    }
+
+    /// Order each of the order columns in its own direction, regardless of [Cursor::asc] and
+    /// [Cursor::desc], e.g. `created_at` descending then `id` ascending. There must be one
+    /// direction per order column.
+    pub fn set_column_orders<I>(&mut self, orders: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Order>,
+    {
+        self.column_orders = orders
+            .into_iter()
+            .map(|order| !matches!(order, Order::Desc))
+            .collect();
+        self
+    }
+
+    /// Whether each of the order columns is ascending
+    fn column_asc(&self) -> Vec<bool> {
+        let arity = self.order_columns.clone().into_iter().len();
+        if self.column_orders.is_empty() {
+            return vec![self.sort_asc; arity];
+        }
+        if self.column_orders.len() != arity {
+            panic!("column arity mismatch");
+        }
+        self.column_orders.clone()
+    }
+
     /// Use ascending sort order
     pub fn asc(&mut self) -> &mut Self {
         self.sort_asc = true;
@@ -240,27 +350,52 @@ where
     fn apply_order_by(&mut self) -> &mut Self {
         self.query.clear_order_by();
         let ord = self.resolve_sort_order();
+        let column_asc = self.column_asc();
 
         let query = &mut self.query;
+        for (expr, asc) in self.order_exprs.iter() {
+            let ord = if *asc != self.is_result_reversed {
+                Order::Asc
+            } else {
+                Order::Desc
+            };
+            query.order_by_expr(expr.clone(), ord);
+        }
         let order = |query: &mut SelectStatement, col| {
             query.order_by((SeaRc::clone(&self.table), SeaRc::clone(col)), ord.clone());
         };
-        match &self.order_columns {
-            Identity::Unary(c1) => {
-                order(query, c1);
-            }
-            Identity::Binary(c1, c2) => {
-                order(query, c1);
-                order(query, c2);
-            }
-            Identity::Ternary(c1, c2, c3) => {
-                order(query, c1);
-                order(query, c2);
-                order(query, c3);
+        if !self.column_orders.is_empty() {
+            for (col, asc) in self
+                .order_columns
+                .clone()
+                .into_iter()
+                .zip(column_asc.iter())
+            {
+                let ord = if *asc != self.is_result_reversed {
+                    Order::Asc
+                } else {
+                    Order::Desc
+                };
+                query.order_by((SeaRc::clone(&self.table), col), ord);
             }
-            Identity::Many(vec) => {
-                for col in vec.iter() {
-                    order(query, col);
+        } else {
+            match &self.order_columns {
+                Identity::Unary(c1) => {
+                    order(query, c1);
+                }
+                Identity::Binary(c1, c2) => {
+                    order(query, c1);
+                    order(query, c2);
+                }
+                Identity::Ternary(c1, c2, c3) => {
+                    order(query, c1);
+                    order(query, c2);
+                    order(query, c3);
+                }
+                Identity::Many(vec) => {
+                    for col in vec.iter() {
+                        order(query, col);
+                    }
                 }
             }
         }
@@ -277,14 +412,15 @@ where
     /// Fetch the paginated result
     pub async fn all<C>(&mut self, db: &C) -> Result<Vec<S::Item>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.apply_limit();
         self.apply_order_by();
         self.apply_filters();
 
-        let stmt = db.get_database_backend().build(&self.query);
-        let rows = db.query_all(stmt).await?;
+        check_refusal(self.refusal)?;
+        let stmt = db.read_backend().build(&self.query);
+        let rows = db.read_all(stmt).await?;
         let mut buffer = Vec::with_capacity(rows.len());
         for row in rows.into_iter() {
             buffer.push(S::from_raw_query_result(row)?);
@@ -304,12 +440,15 @@ where
             query: self.query,
             table: self.table,
             order_columns: self.order_columns,
+            order_exprs: self.order_exprs,
+            column_orders: self.column_orders,
             last: self.last,
             first: self.first,
             after: self.after,
             before: self.before,
             sort_asc: self.sort_asc,
             is_result_reversed: self.is_result_reversed,
+            refusal: self.refusal,
             phantom: PhantomData,
             secondary_order_by: self.secondary_order_by,
         }
@@ -330,12 +469,15 @@ where
             query: self.query,
             table: self.table,
             order_columns: self.order_columns,
+            order_exprs: self.order_exprs,
+            column_orders: self.column_orders,
             last: self.last,
             first: self.first,
             after: self.after,
             before: self.before,
             sort_asc: self.sort_asc,
             is_result_reversed: self.is_result_reversed,
+            refusal: self.refusal,
             phantom: PhantomData,
             secondary_order_by: self.secondary_order_by,
         }
@@ -394,7 +536,76 @@ where
     where
         C: IntoIdentity,
     {
-        Cursor::new(self.query, SeaRc::new(E::default()), order_columns)
+        Cursor::new(self.query, SeaRc::new(E::default()), order_columns).refuse(self.refusal)
+    }
+
+    /// Convert into a cursor ordered by expressions, with the tiebreaker columns ordered last,
+    /// see [Cursor::set_order_exprs]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::cake};
+    ///
+    /// let score = Expr::col(cake::Column::Id).mul(10);
+    /// let mut cursor = cake::Entity::find()
+    ///     .column_as(score.clone(), "score")
+    ///     .cursor_by_exprs([(score, Order::Desc)], cake::Column::Id);
+    /// cursor.after((30, 3)).first(2);
+    /// ```
+    pub fn cursor_by_exprs<I, X, C>(self, exprs: I, tiebreaker: C) -> Cursor<SelectModel<M>>
+    where
+        I: IntoIterator<Item = (X, Order)>,
+        X: Into<SimpleExpr>,
+        C: IntoIdentity,
+    {
+        let mut cursor =
+            Cursor::new(self.query, SeaRc::new(E::default()), tiebreaker).refuse(self.refusal);
+        cursor.set_order_exprs(exprs);
+        cursor
+    }
+
+    /// Convert into a cursor ordered by columns, each in its own direction, see
+    /// [Cursor::set_column_orders]. The columns together should be unique.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// let mut cursor = fruit::Entity::find().cursor_by_orders([
+    ///     (fruit::Column::CakeId, Order::Desc),
+    ///     (fruit::Column::Id, Order::Asc),
+    /// ]);
+    /// cursor.after((2, 10)).first(5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no columns.
+    pub fn cursor_by_orders<I>(self, orders: I) -> Cursor<SelectModel<M>>
+    where
+        I: IntoIterator<Item = (E::Column, Order)>,
+    {
+        let (columns, orders): (Vec<DynIden>, Vec<Order>) = orders
+            .into_iter()
+            .map(|(col, order)| (SeaRc::new(col) as DynIden, order))
+            .unzip();
+        let mut columns = columns.into_iter();
+        let identity = match (
+            columns.next(),
+            columns.next(),
+            columns.next(),
+            columns.next(),
+        ) {
+            (Some(c1), None, _, _) => Identity::Unary(c1),
+            (Some(c1), Some(c2), None, _) => Identity::Binary(c1, c2),
+            (Some(c1), Some(c2), Some(c3), None) => Identity::Ternary(c1, c2, c3),
+            (Some(c1), Some(c2), Some(c3), Some(c4)) => {
+                Identity::Many([c1, c2, c3, c4].into_iter().chain(columns).collect())
+            }
+            (None, ..) => panic!("cursor_by_orders needs at least one column"),
+        };
+        let mut cursor =
+            Cursor::new(self.query, SeaRc::new(E::default()), identity).refuse(self.refusal);
+        cursor.set_column_orders(orders);
+        cursor
     }
 }
 
@@ -444,7 +655,8 @@ where
             self.query,
             SeaRc::new(E::default()),
             order_columns.identity_of(),
-        );
+        )
+        .refuse(self.refusal);
         cursor.set_secondary_order_by(primary_keys);
         cursor
     }
@@ -466,7 +678,8 @@ where
             self.query,
             SeaRc::new(F::default()),
             order_columns.identity_of(),
-        );
+        )
+        .refuse(self.refusal);
         cursor.set_secondary_order_by(primary_keys);
         cursor
     }
@@ -490,7 +703,8 @@ where
             self.query,
             SeaRc::new(E::default()),
             order_columns.identity_of(),
-        );
+        )
+        .refuse(self.refusal);
         {
             let primary_keys: Vec<(DynIden, Identity)> = <F::PrimaryKey as Iterable>::iter()
                 .map(|pk| {
@@ -2438,4 +2652,188 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn cursor_by_exprs_after() -> Result<(), DbErr> {
+        use crate::{Order, QuerySelect};
+        use sea_query::Expr;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<cake::Model>::new()])
+            .into_connection();
+
+        let score = Expr::col((cake::Entity, cake::Column::Id)).mul(10);
+        cake::Entity::find()
+            .select_only()
+            .column(cake::Column::Id)
+            .column(cake::Column::Name)
+            .cursor_by_exprs([(score, Order::Desc)], cake::Column::Id)
+            .after((30, 3))
+            .first(2)
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id", "cake"."name""#,
+                    r#"FROM "cake""#,
+                    r#"WHERE ("cake"."id" * $1 = $2 AND "cake"."id" > $3) OR "cake"."id" * $4 < $5"#,
+                    r#"ORDER BY "cake"."id" * $6 DESC, "cake"."id" ASC LIMIT $7"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [
+                    10_i32.into(),
+                    30_i32.into(),
+                    3_i32.into(),
+                    10_i32.into(),
+                    30_i32.into(),
+                    10_i32.into(),
+                    2_u64.into(),
+                ]
+            ),])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn cursor_by_exprs_last_before() -> Result<(), DbErr> {
+        use crate::{Order, QuerySelect};
+        use sea_query::Expr;
+
+        let models = [
+            cake::Model {
+                id: 2,
+                name: "Chocolate Forest".into(),
+            },
+            cake::Model {
+                id: 1,
+                name: "Cheese Cake".into(),
+            },
+        ];
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([models.clone()])
+            .into_connection();
+
+        let score = Expr::col((cake::Entity, cake::Column::Id)).mul(10);
+        let mut expected = models.clone();
+        expected.reverse();
+        assert_eq!(
+            cake::Entity::find()
+                .select_only()
+                .column(cake::Column::Id)
+                .column(cake::Column::Name)
+                .cursor_by_exprs([(score, Order::Desc)], cake::Column::Id)
+                .before((30, 3))
+                .last(2)
+                .all(&db)
+                .await?,
+            expected
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id", "cake"."name""#,
+                    r#"FROM "cake""#,
+                    r#"WHERE ("cake"."id" * $1 = $2 AND "cake"."id" < $3) OR "cake"."id" * $4 > $5"#,
+                    r#"ORDER BY "cake"."id" * $6 ASC, "cake"."id" DESC LIMIT $7"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [
+                    10_i32.into(),
+                    30_i32.into(),
+                    3_i32.into(),
+                    10_i32.into(),
+                    30_i32.into(),
+                    10_i32.into(),
+                    2_u64.into(),
+                ]
+            ),])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn cursor_by_orders_after() -> Result<(), DbErr> {
+        use crate::Order;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<fruit::Model>::new()])
+            .into_connection();
+
+        fruit::Entity::find()
+            .cursor_by_orders([
+                (fruit::Column::CakeId, Order::Desc),
+                (fruit::Column::Id, Order::Asc),
+            ])
+            .after((2, 10))
+            .first(5)
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                    r#"FROM "fruit""#,
+                    r#"WHERE ("fruit"."cake_id" = $1 AND "fruit"."id" > $2) OR "fruit"."cake_id" < $3"#,
+                    r#"ORDER BY "fruit"."cake_id" DESC, "fruit"."id" ASC LIMIT $4"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [2_i32.into(), 10_i32.into(), 2_i32.into(), 5_u64.into()]
+            ),])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn cursor_by_orders_last_before() -> Result<(), DbErr> {
+        use crate::Order;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<fruit::Model>::new()])
+            .into_connection();
+
+        fruit::Entity::find()
+            .cursor_by_orders([
+                (fruit::Column::CakeId, Order::Desc),
+                (fruit::Column::Id, Order::Asc),
+            ])
+            .before((2, 10))
+            .last(5)
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                    r#"FROM "fruit""#,
+                    r#"WHERE ("fruit"."cake_id" = $1 AND "fruit"."id" < $2) OR "fruit"."cake_id" > $3"#,
+                    r#"ORDER BY "fruit"."cake_id" ASC, "fruit"."id" DESC LIMIT $4"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [2_i32.into(), 10_i32.into(), 2_i32.into(), 5_u64.into()]
+            ),])]
+        );
+
+        Ok(())
+    }
 }
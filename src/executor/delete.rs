@@ -1,11 +1,14 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait,
-    Iterable,
+    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, DeleteMany, DeleteOne,
+    EntityTrait, Iterable, UpdateMany,
 };
-use sea_query::{DeleteStatement, Query};
-use std::future::Future;
+use sea_query::{DeleteStatement, Expr, Query};
+use std::{future::Future, marker::PhantomData};
 
-use super::{SelectModel, SelectorRaw};
+use super::{
+    returning::{select_affected, where_clause},
+    SelectModel, SelectorRaw,
+};
 
 /// Handles DELETE operations in a ActiveModel using [DeleteStatement]
 #[derive(Clone, Debug)]
@@ -30,14 +33,12 @@ where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        exec_delete_only::<A::Entity, _>(self.query, self.refusal, db)
     }
 
-    /// Execute an delete operation and return the deleted model
-    ///
-    /// # Panics
-    ///
-    /// Panics if the database backend does not support `DELETE RETURNING`
+    /// Execute an delete operation and return the deleted model. Uses `RETURNING` if supported,
+    /// otherwise the matching row is selected with `FOR UPDATE` before it is deleted; run it in a
+    /// transaction to keep the two statements atomic.
     pub fn exec_with_returning<C>(
         self,
         db: &'a C,
@@ -45,7 +46,7 @@ where
     where
         C: ConnectionTrait,
     {
-        exec_delete_with_returning_one::<A::Entity, _>(self.query, db)
+        exec_soft_delete_with_returning_one::<A::Entity, _>(self.query, self.refusal, db)
     }
 }
 
@@ -59,14 +60,12 @@ where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        exec_delete_only::<E, _>(self.query, self.refusal, db)
     }
 
-    /// Execute an delete operation and return the deleted model
-    ///
-    /// # Panics
-    ///
-    /// Panics if the database backend does not support `DELETE RETURNING`
+    /// Execute an delete operation and return the deleted models. Uses `RETURNING` if supported,
+    /// otherwise the matching rows are selected with `FOR UPDATE` before they are deleted; run it
+    /// in a transaction to keep the two statements atomic.
     pub fn exec_with_returning<C>(
         self,
         db: &C,
@@ -75,7 +74,7 @@ where
         E: EntityTrait,
         C: ConnectionTrait,
     {
-        exec_delete_with_returning_many::<E, _>(self.query, db)
+        exec_soft_delete_with_returning_many::<E, _>(self.query, self.refusal, db)
     }
 }
 
@@ -93,11 +92,9 @@ impl Deleter {
         exec_delete(self.query, db)
     }
 
-    /// Execute an delete operation and return the deleted model
-    ///
-    /// # Panics
-    ///
-    /// Panics if the database backend does not support `DELETE RETURNING`
+    /// Execute an delete operation and return the deleted models. Uses `RETURNING` if supported,
+    /// otherwise the matching rows are selected with `FOR UPDATE` before they are deleted; run it
+    /// in a transaction to keep the two statements atomic.
     pub fn exec_with_returning<E, C>(
         self,
         db: &C,
@@ -110,11 +107,76 @@ impl Deleter {
     }
 }
 
-async fn exec_delete_only<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
+async fn exec_delete_only<E, C>(
+    query: DeleteStatement,
+    refusal: Option<Refusal>,
+    db: &C,
+) -> Result<DeleteResult, DbErr>
 where
+    E: EntityTrait,
     C: ConnectionTrait,
 {
-    Deleter::new(query).exec(db).await
+    check_refusal(refusal)?;
+    match soft_delete::<E>(&query, db.get_database_backend()) {
+        Some(update) => Ok(DeleteResult {
+            rows_affected: update.exec(db).await?.rows_affected,
+        }),
+        None => Deleter::new(query).exec(db).await,
+    }
+}
+
+async fn exec_soft_delete_with_returning_one<E, C>(
+    query: DeleteStatement,
+    refusal: Option<Refusal>,
+    db: &C,
+) -> Result<Option<E::Model>, DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    check_refusal(refusal)?;
+    match soft_delete::<E>(&query, db.get_database_backend()) {
+        Some(update) => Ok(update.exec_with_returning(db).await?.into_iter().next()),
+        None => exec_delete_with_returning_one::<E, _>(query, db).await,
+    }
+}
+
+async fn exec_soft_delete_with_returning_many<E, C>(
+    query: DeleteStatement,
+    refusal: Option<Refusal>,
+    db: &C,
+) -> Result<Vec<E::Model>, DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    check_refusal(refusal)?;
+    match soft_delete::<E>(&query, db.get_database_backend()) {
+        Some(update) => update.exec_with_returning(db).await,
+        None => exec_delete_with_returning_many::<E, _>(query, db).await,
+    }
+}
+
+/// The `UPDATE` setting the soft delete column of the rows a `DELETE` would delete, if the
+/// entity has one. A [Deleter] always deletes the rows.
+fn soft_delete<E>(query: &DeleteStatement, backend: DbBackend) -> Option<UpdateMany<E>>
+where
+    E: EntityTrait,
+{
+    let col = crate::soft_delete_column::<E>()?;
+    let mut update = Query::update();
+    update
+        .table(E::default().table_ref())
+        .value(col, Expr::current_timestamp());
+    if let Some((sql, values)) = where_clause(query, backend) {
+        update.and_where(Expr::cust_with_values(sql, values));
+    }
+    update.and_where(Expr::col((E::default(), col)).is_null());
+    Some(UpdateMany {
+        query: update,
+        entity: PhantomData,
+        refusal: None,
+    })
 }
 
 async fn exec_delete<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
@@ -146,7 +208,13 @@ where
                 .one(db)
                 .await?
         }
-        false => unimplemented!("Database backend doesn't support RETURNING"),
+        false => {
+            let models = select_affected::<E, _>(&query, db.get_database_backend())
+                .one(db)
+                .await?;
+            exec_delete(query, db).await?;
+            models
+        }
     };
     Ok(models)
 }
@@ -171,7 +239,13 @@ where
                 .all(db)
                 .await?
         }
-        false => unimplemented!("Database backend doesn't support RETURNING"),
+        false => {
+            let models = select_affected::<E, _>(&query, db.get_database_backend())
+                .all(db)
+                .await?;
+            exec_delete(query, db).await?;
+            models
+        }
     };
     Ok(models)
 }
@@ -19,6 +19,15 @@ pub(crate) enum ExecResultHolder {
     /// Holds the result of executing an operation on a SQLite database
     #[cfg(feature = "sqlx-sqlite")]
     SqlxSqlite(sqlx::sqlite::SqliteQueryResult),
+    /// Holds the result of executing an operation on a libSQL database
+    #[cfg(feature = "libsql")]
+    Libsql(crate::driver::libsql_db::LibsqlExecResult),
+    /// Holds the result of executing an operation on a DuckDB database
+    #[cfg(feature = "duckdb")]
+    Duckdb(crate::driver::duckdb_db::DuckdbExecResult),
+    /// Holds the result of executing an operation on a tokio-postgres database
+    #[cfg(feature = "tokio-postgres")]
+    TokioPostgres(crate::driver::tokio_postgres_db::TokioPostgresExecResult),
     /// Holds the result of executing an operation on the Mock database
     #[cfg(feature = "mock")]
     Mock(crate::MockExecResult),
@@ -52,6 +61,22 @@ impl ExecResult {
                     last_insert_rowid as u64
                 }
             }
+            #[cfg(feature = "libsql")]
+            ExecResultHolder::Libsql(result) => {
+                if result.last_insert_rowid < 0 {
+                    unreachable!("negative last_insert_rowid")
+                } else {
+                    result.last_insert_rowid as u64
+                }
+            }
+            #[cfg(feature = "duckdb")]
+            ExecResultHolder::Duckdb(_) => {
+                panic!("Should not retrieve last_insert_id this way")
+            }
+            #[cfg(feature = "tokio-postgres")]
+            ExecResultHolder::TokioPostgres(_) => {
+                panic!("Should not retrieve last_insert_id this way")
+            }
             #[cfg(feature = "mock")]
             ExecResultHolder::Mock(result) => result.last_insert_id,
             #[cfg(feature = "proxy")]
@@ -70,6 +95,12 @@ impl ExecResult {
             ExecResultHolder::SqlxPostgres(result) => result.rows_affected(),
             #[cfg(feature = "sqlx-sqlite")]
             ExecResultHolder::SqlxSqlite(result) => result.rows_affected(),
+            #[cfg(feature = "libsql")]
+            ExecResultHolder::Libsql(result) => result.rows_affected,
+            #[cfg(feature = "duckdb")]
+            ExecResultHolder::Duckdb(result) => result.rows_affected,
+            #[cfg(feature = "tokio-postgres")]
+            ExecResultHolder::TokioPostgres(result) => result.rows_affected,
             #[cfg(feature = "mock")]
             ExecResultHolder::Mock(result) => result.rows_affected,
             #[cfg(feature = "proxy")]
@@ -0,0 +1,266 @@
+use crate::{
+    error::*, DbBackend, EntityTrait, JsonValue, ReadConnectionTrait, Select, Selector,
+    SelectorTrait, Statement,
+};
+use sea_query::SelectStatement;
+use std::fmt;
+
+/// The plan of a query, as given by the database
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryPlan {
+    /// The plan: the document of `EXPLAIN (FORMAT JSON)` on Postgres and `EXPLAIN FORMAT=JSON`
+    /// on MySQL, and the rows of `EXPLAIN QUERY PLAN` with their `id`, `parent` and `detail`
+    /// on SQLite. `EXPLAIN ANALYZE` on MySQL only has a text form, kept as a string.
+    pub plan: JsonValue,
+}
+
+impl QueryPlan {
+    /// Whether the plan reads from an index of this name
+    pub fn uses_index(&self, index: &str) -> bool {
+        fn walk(value: &JsonValue, index: &str) -> bool {
+            match value {
+                JsonValue::Object(map) => {
+                    map.iter().any(|(key, value)| match (key.as_str(), value) {
+                        ("Index Name" | "key", JsonValue::String(name)) if name == index => true,
+                        ("detail", JsonValue::String(detail)) => {
+                            detail.contains(&format!("INDEX {index}"))
+                        }
+                        _ => walk(value, index),
+                    })
+                }
+                JsonValue::Array(values) => values.iter().any(|value| walk(value, index)),
+                JsonValue::String(text) => text.contains(&format!("index {index}")),
+                _ => false,
+            }
+        }
+        walk(&self.plan, index)
+    }
+}
+
+impl fmt::Display for QueryPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.plan {
+            JsonValue::String(text) => f.write_str(text),
+            plan => write!(f, "{plan:#}"),
+        }
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Get the plan the database would run the query with, without running it
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "QUERY PLAN" => Value::from(serde_json::json!([
+    /// #             { "Plan": { "Node Type": "Index Scan", "Index Name": "cake_pkey" } }
+    /// #         ])),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// let plan = cake::Entity::find_by_id(1).explain(&db).await?;
+    /// assert!(plan.uses_index("cake_pkey"));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"EXPLAIN (FORMAT JSON) SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1"#,
+    ///         [1.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn explain<C>(&self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ReadConnectionTrait,
+    {
+        explain(db, &self.query, false).await
+    }
+
+    /// Run the query and get the plan with the actual timings and row counts. Not supported
+    /// on SQLite.
+    pub async fn explain_analyze<C>(&self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ReadConnectionTrait,
+    {
+        explain(db, &self.query, true).await
+    }
+}
+
+impl<S> Selector<S>
+where
+    S: SelectorTrait,
+{
+    /// Get the plan the database would run the query with, without running it
+    pub async fn explain<C>(&self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ReadConnectionTrait,
+    {
+        explain(db, &self.query, false).await
+    }
+
+    /// Run the query and get the plan with the actual timings and row counts. Not supported
+    /// on SQLite.
+    pub async fn explain_analyze<C>(&self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ReadConnectionTrait,
+    {
+        explain(db, &self.query, true).await
+    }
+}
+
+async fn explain<C>(db: &C, query: &SelectStatement, analyze: bool) -> Result<QueryPlan, DbErr>
+where
+    C: ReadConnectionTrait,
+{
+    let backend = db.read_backend();
+    let prefix = match (backend, analyze) {
+        (DbBackend::Postgres, false) => "EXPLAIN (FORMAT JSON)",
+        (DbBackend::Postgres, true) => "EXPLAIN (ANALYZE, FORMAT JSON)",
+        (DbBackend::MySql, false) => "EXPLAIN FORMAT=JSON",
+        (DbBackend::MySql, true) => "EXPLAIN ANALYZE",
+        (DbBackend::Sqlite, false) => "EXPLAIN QUERY PLAN",
+        (DbBackend::Sqlite, true) => {
+            return Err(query_err("SQLite does not support EXPLAIN ANALYZE"));
+        }
+    };
+    let stmt = backend.build(query);
+    let stmt = Statement {
+        sql: format!("{prefix} {}", stmt.sql),
+        ..stmt
+    };
+    let rows = db.read_all(stmt).await?;
+    let plan = match backend {
+        DbBackend::Postgres => rows
+            .first()
+            .ok_or_else(|| query_err("EXPLAIN returned no rows"))?
+            .try_get("", "QUERY PLAN")?,
+        DbBackend::MySql => {
+            let text: String = rows
+                .first()
+                .ok_or_else(|| query_err("EXPLAIN returned no rows"))?
+                .try_get("", "EXPLAIN")?;
+            if analyze {
+                JsonValue::String(text)
+            } else {
+                serde_json::from_str(&text).map_err(json_err)?
+            }
+        }
+        DbBackend::Sqlite => rows
+            .iter()
+            .map(|row| {
+                Ok(serde_json::json!({
+                    "id": row.try_get::<i64>("", "id")?,
+                    "parent": row.try_get::<i64>("", "parent")?,
+                    "detail": row.try_get::<String>("", "detail")?,
+                }))
+            })
+            .collect::<Result<_, DbErr>>()?,
+    };
+    Ok(QueryPlan { plan })
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::prelude::*, tests_cfg::*, DbBackend, MockDatabase, QuerySelect, Statement,
+        Transaction,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn explain_sqlite() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[maplit::btreemap! {
+                "id" => Value::from(2i64),
+                "parent" => Value::from(0i64),
+                "detail" => Value::from("SEARCH fruit USING INDEX idx_fruit_cake_id (cake_id=?)"),
+            }]])
+            .into_connection();
+
+        let plan = fruit::Entity::find()
+            .filter(fruit::Column::CakeId.eq(1))
+            .select_only()
+            .column(fruit::Column::Id)
+            .explain(&db)
+            .await?;
+        assert!(plan.uses_index("idx_fruit_cake_id"));
+        assert!(!plan.uses_index("idx_fruit_name"));
+        assert_eq!(
+            plan.plan,
+            serde_json::json!([{
+                "id": 2,
+                "parent": 0,
+                "detail": "SEARCH fruit USING INDEX idx_fruit_cake_id (cake_id=?)",
+            }])
+        );
+
+        assert_eq!(
+            fruit::Entity::find().explain_analyze(&db).await,
+            Err(DbErr::Query(RuntimeErr::Internal(
+                "SQLite does not support EXPLAIN ANALYZE".to_owned()
+            )))
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::one(Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                r#"EXPLAIN QUERY PLAN SELECT "fruit"."id" FROM "fruit" WHERE "fruit"."cake_id" = ?"#,
+                [1.into()]
+            ))]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn explain_mysql() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[maplit::btreemap! {
+                "EXPLAIN" => Value::from(r#"{"query_block": {"table": {"table_name": "cake", "access_type": "const", "key": "PRIMARY"}}}"#),
+            }]])
+            .append_query_results([[maplit::btreemap! {
+                "EXPLAIN" => Value::from("-> Rows fetched before execution  (cost=0..0 rows=1) (actual time=0..0 rows=1 loops=1)"),
+            }]])
+            .into_connection();
+
+        let select = cake::Entity::find_by_id(1);
+        assert!(select.explain(&db).await?.uses_index("PRIMARY"));
+        assert_eq!(
+            select.explain_analyze(&db).await?.to_string(),
+            "-> Rows fetched before execution  (cost=0..0 rows=1) (actual time=0..0 rows=1 loops=1)"
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::one(Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    "EXPLAIN FORMAT=JSON SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ?",
+                    [1.into()]
+                )),
+                Transaction::one(Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    "EXPLAIN ANALYZE SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ?",
+                    [1.into()]
+                )),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,646 @@
+use crate::{
+    query::column_value, unpack_table_ref, ActiveModelBehavior, ActiveModelTrait, ColumnTrait,
+    ConnectionTrait, DbErr, EntityTrait, IdenStatic, Identity, IntoActiveModel, Iterable,
+    JsonValue, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related, Value,
+};
+use async_trait::async_trait;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    marker::PhantomData,
+    path::Path,
+};
+
+/// An entity and the related entities to export with its rows, and import into another
+/// database, with the foreign keys between them preserved.
+///
+/// Rows are exported as JSON objects by column name, see [GraphDump]. On import, the rows of
+/// an entity with an auto-increment primary key get new keys, and the foreign keys of the
+/// rows imported after them are remapped to the new keys. Pass a transaction to
+/// [EntityGraph::import] to import all rows or none.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let source = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model { id: 1, name: "Cheese Cake".to_owned() }]])
+/// #     .append_query_results([[fruit::Model { id: 7, name: "Lemon".to_owned(), cake_id: Some(1) }]])
+/// #     .into_connection();
+/// # let target = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model { id: 21, name: "Cheese Cake".to_owned() }]])
+/// #     .append_query_results([[fruit::Model { id: 37, name: "Lemon".to_owned(), cake_id: Some(21) }]])
+/// #     .into_connection();
+/// #
+/// use sea_orm::{EntityGraph, GraphDump};
+///
+/// let graph = EntityGraph::new::<cake::Entity>().include::<cake::Entity, fruit::Entity>();
+///
+/// let dump = graph.export(&source, [1]).await?;
+/// assert_eq!(
+///     dump.rows("fruit"),
+///     [serde_json::json!({ "id": 7, "name": "Lemon", "cake_id": 1 })]
+/// );
+///
+/// let roots = graph.import(&target, &dump).await?;
+/// assert_eq!(roots, [serde_json::json!({ "id": 21, "name": "Cheese Cake" })]);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct EntityGraph<C>
+where
+    C: ConnectionTrait,
+{
+    nodes: Vec<Node<C>>,
+}
+
+/// Rows exported by an [EntityGraph], by table name.
+///
+/// A table is written as JSON Lines, one row per line, so a dump can be kept as a `.jsonl`
+/// file per table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphDump {
+    tables: BTreeMap<String, Vec<JsonValue>>,
+}
+
+struct Node<C> {
+    entity: Box<dyn GraphEntity<C>>,
+    edge: Option<Edge>,
+}
+
+/// The relation a node was included through
+struct Edge {
+    parent: usize,
+    /// The column of the parent
+    from_col: String,
+    /// The column of the included entity
+    to_col: String,
+    /// Whether the parent owns the included entity, which then holds the foreign key
+    is_owner: bool,
+}
+
+/// A foreign key between two tables of a graph
+struct ForeignKey {
+    table: String,
+    column: String,
+    ref_table: String,
+    ref_column: String,
+}
+
+#[async_trait]
+trait GraphEntity<C>: Send + Sync
+where
+    C: ConnectionTrait,
+{
+    fn table(&self) -> String;
+
+    /// The first column of the primary key
+    fn primary_key(&self) -> String;
+
+    /// The primary key of a row, to tell rows apart
+    fn key(&self, row: &JsonValue) -> String;
+
+    async fn fetch(
+        &self,
+        db: &C,
+        column: &str,
+        values: &[JsonValue],
+    ) -> Result<Vec<JsonValue>, DbErr>;
+
+    /// Insert a row, without the primary key if it is auto-increment, returning the row as
+    /// inserted
+    async fn insert(&self, db: &C, row: &JsonValue) -> Result<JsonValue, DbErr>;
+}
+
+struct GraphEntityOf<E>(PhantomData<E>);
+
+#[async_trait]
+impl<C, E> GraphEntity<C> for GraphEntityOf<E>
+where
+    C: ConnectionTrait,
+    E: EntityTrait,
+    E::Model: IntoActiveModel<E::ActiveModel>,
+    E::ActiveModel: ActiveModelBehavior + Send,
+{
+    fn table(&self) -> String {
+        E::default().table_name().to_owned()
+    }
+
+    fn primary_key(&self) -> String {
+        E::PrimaryKey::iter()
+            .next()
+            .map(|key| key.into_column().as_str().to_owned())
+            .unwrap_or_default()
+    }
+
+    fn key(&self, row: &JsonValue) -> String {
+        E::PrimaryKey::iter()
+            .map(|key| row[key.into_column().as_str()].to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    async fn fetch(
+        &self,
+        db: &C,
+        column: &str,
+        values: &[JsonValue],
+    ) -> Result<Vec<JsonValue>, DbErr> {
+        if values.is_empty() {
+            return Ok(Vec::new());
+        }
+        let col = find_column::<E>(column)?;
+        let values = values
+            .iter()
+            .map(|value| column_value(col, value))
+            .collect::<Result<Vec<_>, _>>()?;
+        E::find()
+            .filter(col.is_in(values))
+            .into_json()
+            .all(db)
+            .await
+    }
+
+    async fn insert(&self, db: &C, row: &JsonValue) -> Result<JsonValue, DbErr> {
+        let auto_increment = <E::PrimaryKey as PrimaryKeyTrait>::auto_increment();
+        let mut am = <E::ActiveModel as ActiveModelTrait>::default();
+        for col in E::Column::iter() {
+            if auto_increment && E::PrimaryKey::from_column(col).is_some() {
+                continue;
+            }
+            if let Some(value) = row.get(col.as_str()) {
+                am.set(col, column_value(col, value)?);
+            }
+        }
+        let model = am.insert(db).await?;
+        Ok(E::Column::iter()
+            .map(|col| {
+                let value = sea_query::sea_value_to_json_value(&model.get(col));
+                (col.as_str().to_owned(), value)
+            })
+            .collect::<serde_json::Map<_, _>>()
+            .into())
+    }
+}
+
+impl<C> EntityGraph<C>
+where
+    C: ConnectionTrait,
+{
+    /// Create a graph of the root entity, whose rows are exported by primary key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the primary key of the entity has more than one column.
+    pub fn new<E>() -> Self
+    where
+        E: EntityTrait,
+        E::Model: IntoActiveModel<E::ActiveModel>,
+        E::ActiveModel: ActiveModelBehavior + Send,
+    {
+        assert!(
+            E::PrimaryKey::iter().count() == 1,
+            "the root entity `{}` of an EntityGraph must have a single column primary key",
+            E::default().table_name()
+        );
+        Self {
+            nodes: vec![Node {
+                entity: Box::new(GraphEntityOf::<E>(PhantomData)),
+                edge: None,
+            }],
+        }
+    }
+
+    /// Include the rows of `R` related to the rows of `P`, which must be in the graph already.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `P` is not in the graph, or the relation is through another entity or on
+    /// more than one column.
+    pub fn include<P, R>(mut self) -> Self
+    where
+        P: EntityTrait + Related<R>,
+        R: EntityTrait,
+        R::Model: IntoActiveModel<R::ActiveModel>,
+        R::ActiveModel: ActiveModelBehavior + Send,
+    {
+        let parent_table = P::default().table_name().to_owned();
+        let table = R::default().table_name().to_owned();
+        let parent = self
+            .nodes
+            .iter()
+            .position(|node| node.entity.table() == parent_table)
+            .unwrap_or_else(|| panic!("`{parent_table}` is not in the EntityGraph"));
+        let rel = <P as Related<R>>::to();
+        assert!(
+            <P as Related<R>>::via().is_none()
+                && unpack_table_ref(&rel.from_tbl).to_string() == parent_table
+                && unpack_table_ref(&rel.to_tbl).to_string() == table,
+            "`{parent_table}` is related to `{table}` through another entity, include it first"
+        );
+        let (Identity::Unary(from_col), Identity::Unary(to_col)) = (&rel.from_col, &rel.to_col)
+        else {
+            panic!("the relation from `{parent_table}` to `{table}` has more than one column");
+        };
+        self.nodes.push(Node {
+            entity: Box::new(GraphEntityOf::<R>(PhantomData)),
+            edge: Some(Edge {
+                parent,
+                from_col: from_col.to_string(),
+                to_col: to_col.to_string(),
+                is_owner: rel.is_owner,
+            }),
+        });
+        self
+    }
+
+    /// Export the root rows with the primary keys, and their related rows
+    pub async fn export<I, V>(&self, db: &C, ids: I) -> Result<GraphDump, DbErr>
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        let ids: Vec<JsonValue> = ids
+            .into_iter()
+            .map(|id| sea_query::sea_value_to_json_value(&id.into()))
+            .collect();
+        let mut dump = GraphDump::default();
+        let mut seen: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut rows_of: Vec<Vec<JsonValue>> = Vec::with_capacity(self.nodes.len());
+        for node in self.nodes.iter() {
+            let rows = match &node.edge {
+                None => {
+                    let key = node.entity.primary_key();
+                    node.entity.fetch(db, &key, &ids).await?
+                }
+                Some(edge) => {
+                    let mut values: Vec<JsonValue> = Vec::new();
+                    for row in rows_of[edge.parent].iter() {
+                        match row.get(&edge.from_col) {
+                            Some(value) if !value.is_null() && !values.contains(value) => {
+                                values.push(value.clone())
+                            }
+                            _ => {}
+                        }
+                    }
+                    node.entity.fetch(db, &edge.to_col, &values).await?
+                }
+            };
+            let table = node.entity.table();
+            let seen = seen.entry(table.clone()).or_default();
+            for row in rows.iter() {
+                if seen.insert(node.entity.key(row)) {
+                    dump.push(&table, row.clone());
+                }
+            }
+            rows_of.push(rows);
+        }
+        Ok(dump)
+    }
+
+    /// Import the rows of a dump, parents before the rows referencing them, and return the
+    /// root rows as inserted
+    pub async fn import(&self, db: &C, dump: &GraphDump) -> Result<Vec<JsonValue>, DbErr> {
+        let foreign_keys = self.foreign_keys();
+        let mut remap: HashMap<(&str, &str), HashMap<String, JsonValue>> = HashMap::new();
+        let mut done: HashSet<String> = HashSet::new();
+        let mut roots = Vec::new();
+        for i in self.import_order() {
+            let entity = &self.nodes[i].entity;
+            let table = entity.table();
+            if !done.insert(table.clone()) {
+                continue;
+            }
+            for row in dump.rows(&table) {
+                let mut new_row = row.clone();
+                for fk in foreign_keys.iter().filter(|fk| fk.table == table) {
+                    let new_value = remap
+                        .get(&(fk.ref_table.as_str(), fk.ref_column.as_str()))
+                        .and_then(|keys| keys.get(&row[&fk.column].to_string()));
+                    if let Some(new_value) = new_value {
+                        new_row[&fk.column] = new_value.clone();
+                    }
+                }
+                let inserted = entity.insert(db, &new_row).await?;
+                for fk in foreign_keys.iter().filter(|fk| fk.ref_table == table) {
+                    remap
+                        .entry((fk.ref_table.as_str(), fk.ref_column.as_str()))
+                        .or_default()
+                        .insert(
+                            row[&fk.ref_column].to_string(),
+                            inserted[&fk.ref_column].clone(),
+                        );
+                }
+                if i == 0 {
+                    roots.push(inserted);
+                }
+            }
+        }
+        Ok(roots)
+    }
+
+    fn foreign_keys(&self) -> Vec<ForeignKey> {
+        self.nodes
+            .iter()
+            .filter_map(|node| {
+                let edge = node.edge.as_ref()?;
+                let table = node.entity.table();
+                let parent_table = self.nodes[edge.parent].entity.table();
+                Some(if edge.is_owner {
+                    ForeignKey {
+                        table,
+                        column: edge.to_col.clone(),
+                        ref_table: parent_table,
+                        ref_column: edge.from_col.clone(),
+                    }
+                } else {
+                    ForeignKey {
+                        table: parent_table,
+                        column: edge.from_col.clone(),
+                        ref_table: table,
+                        ref_column: edge.to_col.clone(),
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// The nodes in an order where the rows a foreign key references are imported first
+    fn import_order(&self) -> Vec<usize> {
+        let mut deps: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let Some(edge) = &node.edge {
+                if edge.is_owner {
+                    deps[i].push(edge.parent);
+                } else {
+                    deps[edge.parent].push(i);
+                }
+            }
+        }
+
+        fn visit(i: usize, deps: &[Vec<usize>], visited: &mut [bool], order: &mut Vec<usize>) {
+            if visited[i] {
+                return;
+            }
+            visited[i] = true;
+            for dep in deps[i].iter() {
+                visit(*dep, deps, visited, order);
+            }
+            order.push(i);
+        }
+
+        let mut visited = vec![false; self.nodes.len()];
+        let mut order = Vec::with_capacity(self.nodes.len());
+        for i in 0..self.nodes.len() {
+            visit(i, &deps, &mut visited, &mut order);
+        }
+        order
+    }
+}
+
+impl<C> std::fmt::Debug for EntityGraph<C>
+where
+    C: ConnectionTrait,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.nodes.iter().map(|node| node.entity.table()))
+            .finish()
+    }
+}
+
+fn find_column<E>(name: &str) -> Result<E::Column, DbErr>
+where
+    E: EntityTrait,
+{
+    E::Column::iter()
+        .find(|col| col.as_str() == name)
+        .ok_or_else(|| {
+            DbErr::Custom(format!(
+                "`{}` has no column `{name}`",
+                E::default().table_name()
+            ))
+        })
+}
+
+impl GraphDump {
+    /// The tables with rows
+    pub fn tables(&self) -> impl Iterator<Item = &str> {
+        self.tables.keys().map(String::as_str)
+    }
+
+    /// The rows of a table
+    pub fn rows(&self, table: &str) -> &[JsonValue] {
+        self.tables
+            .get(table)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Add a row to a table
+    pub fn push(&mut self, table: &str, row: JsonValue) {
+        self.tables.entry(table.to_owned()).or_default().push(row);
+    }
+
+    /// The rows of a table as JSON Lines
+    pub fn to_jsonl(&self, table: &str) -> String {
+        self.rows(table)
+            .iter()
+            .map(|row| format!("{row}\n"))
+            .collect()
+    }
+
+    /// Add the rows of a table from JSON Lines
+    pub fn read_jsonl(&mut self, table: &str, jsonl: &str) -> Result<(), DbErr> {
+        for line in jsonl.lines().filter(|line| !line.trim().is_empty()) {
+            let row: JsonValue =
+                serde_json::from_str(line).map_err(|err| DbErr::Json(err.to_string()))?;
+            if !row.is_object() {
+                return Err(DbErr::Json(format!("A row of `{table}` is not an object")));
+            }
+            self.push(table, row);
+        }
+        Ok(())
+    }
+
+    /// Write a `<table>.jsonl` file per table in a directory
+    pub fn write_dir<P>(&self, dir: P) -> Result<(), DbErr>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir).map_err(|err| DbErr::Custom(err.to_string()))?;
+        for table in self.tables() {
+            fs::write(dir.join(format!("{table}.jsonl")), self.to_jsonl(table))
+                .map_err(|err| DbErr::Custom(err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Read the `.jsonl` files of a directory, a table per file
+    pub fn read_dir<P>(dir: P) -> Result<Self, DbErr>
+    where
+        P: AsRef<Path>,
+    {
+        let mut dump = Self::default();
+        for entry in fs::read_dir(dir).map_err(|err| DbErr::Custom(err.to_string()))? {
+            let path = entry.map_err(|err| DbErr::Custom(err.to_string()))?.path();
+            if path.extension() != Some("jsonl".as_ref()) {
+                continue;
+            }
+            let Some(table) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let jsonl = fs::read_to_string(&path).map_err(|err| DbErr::Custom(err.to_string()))?;
+            dump.read_jsonl(table, &jsonl)?;
+        }
+        Ok(dump)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{tests_cfg::*, DbBackend, MockDatabase, Statement, Transaction};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn fruit(id: i32, name: &str, cake_id: i32) -> fruit::Model {
+        fruit::Model {
+            id,
+            name: name.to_owned(),
+            cake_id: Some(cake_id),
+        }
+    }
+
+    #[smol_potat::test]
+    async fn export_and_import_children() -> Result<(), DbErr> {
+        let source = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .append_query_results([[fruit(7, "Lemon", 1), fruit(8, "Lime", 1)]])
+            .into_connection();
+        let graph = EntityGraph::new::<cake::Entity>().include::<cake::Entity, fruit::Entity>();
+
+        let dump = graph.export(&source, [1]).await?;
+        assert_eq!(dump.tables().collect::<Vec<_>>(), ["cake", "fruit"]);
+        assert_eq!(
+            dump.rows("fruit"),
+            [
+                json!({ "id": 7, "name": "Lemon", "cake_id": 1 }),
+                json!({ "id": 8, "name": "Lime", "cake_id": 1 }),
+            ]
+        );
+        assert_eq!(
+            source.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN ($1)"#,
+                    [1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE "fruit"."cake_id" IN ($1)"#,
+                    [1i32.into()]
+                ),
+            ]
+        );
+
+        let mut copy = GraphDump::default();
+        for table in dump.tables() {
+            copy.read_jsonl(table, &dump.to_jsonl(table))?;
+        }
+        assert_eq!(copy, dump);
+
+        let dir = std::env::temp_dir().join("sea-orm-graph-dump");
+        copy.write_dir(&dir)?;
+        assert_eq!(GraphDump::read_dir(&dir)?, dump);
+
+        let target = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 21,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .append_query_results([[fruit(37, "Lemon", 21)]])
+            .append_query_results([[fruit(38, "Lime", 21)]])
+            .into_connection();
+        assert_eq!(
+            graph.import(&target, &copy).await?,
+            [json!({ "id": 21, "name": "Cheese Cake" })]
+        );
+
+        let insert_fruit = |name: &str| {
+            Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "fruit" ("name", "cake_id") VALUES ($1, $2) RETURNING "id", "name", "cake_id""#,
+                [name.into(), 21i32.into()],
+            )])
+        };
+        assert_eq!(
+            target.into_transaction_log(),
+            [
+                Transaction::many([Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1) RETURNING "id", "name""#,
+                    ["Cheese Cake".into()],
+                )]),
+                insert_fruit("Lemon"),
+                insert_fruit("Lime"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn import_parents_first() -> Result<(), DbErr> {
+        let source = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[fruit(7, "Lemon", 1)]])
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .into_connection();
+        let graph = EntityGraph::new::<fruit::Entity>().include::<fruit::Entity, cake::Entity>();
+        let dump = graph.export(&source, [7]).await?;
+
+        let target = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 21,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .append_query_results([[fruit(37, "Lemon", 21)]])
+            .into_connection();
+        assert_eq!(
+            graph.import(&target, &dump).await?,
+            [json!({ "id": 37, "name": "Lemon", "cake_id": 21 })]
+        );
+
+        let log = target.into_transaction_log();
+        assert_eq!(
+            log[1],
+            Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "fruit" ("name", "cake_id") VALUES ($1, $2) RETURNING "id", "name", "cake_id""#,
+                ["Lemon".into(), 21i32.into()],
+            )])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "`fruit` is not in the EntityGraph")]
+    fn include_unknown_parent() {
+        let _: EntityGraph<crate::DatabaseConnection> =
+            EntityGraph::new::<cake::Entity>().include::<fruit::Entity, cake::Entity>();
+    }
+}
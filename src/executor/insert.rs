@@ -1,10 +1,10 @@
 use crate::{
     error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, Insert,
-    IntoActiveModel, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, SelectModel, SelectorRaw,
-    TryFromU64, TryInsert,
+    InsertIgnore, IntoActiveModel, Iterable, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait,
+    SelectModel, SelectorRaw, TransactionTrait, TryFromU64, TryInsert,
 };
-use sea_query::{FromValueTuple, Iden, InsertStatement, Query, ValueTuple};
-use std::{future::Future, marker::PhantomData};
+use sea_query::{FromValueTuple, Iden, InsertStatement, IntoValueTuple, Query, Value, ValueTuple};
+use std::{collections::HashMap, future::Future, marker::PhantomData};
 
 type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
 
@@ -15,7 +15,10 @@ where
     A: ActiveModelTrait,
 {
     primary_key: Option<ValueTuple>,
+    primary_keys: Vec<Option<ValueTuple>>,
+    rows: Vec<InsertStatement>,
     query: InsertStatement,
+    refusal: Option<Refusal>,
     model: PhantomData<A>,
 }
 
@@ -29,6 +32,15 @@ where
     pub last_insert_id: <PrimaryKey<A> as PrimaryKeyTrait>::ValueType,
 }
 
+/// The result of an INSERT skipping conflicting rows, see [`Insert::on_conflict_ignore`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InsertIgnoreResult {
+    /// The number of rows inserted
+    pub inserted: u64,
+    /// The number of rows skipped for conflicting with an existing row
+    pub skipped: u64,
+}
+
 /// The types of results for an INSERT operation
 #[derive(Debug)]
 pub enum TryInsertResult<T> {
@@ -131,18 +143,15 @@ where
         }
     }
 
-    /// Execute an insert operation and return all inserted models
-    ///
-    /// # Panics
-    ///
-    /// Panics if the database backend does not support `INSERT RETURNING`.
+    /// Execute an insert operation and return all inserted models, in the order they were
+    /// added, see [Insert::exec_with_returning_many]
     pub async fn exec_with_returning_many<'a, C>(
         self,
         db: &'a C,
     ) -> Result<TryInsertResult<Vec<<A::Entity as EntityTrait>::Model>>, DbErr>
     where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
-        C: ConnectionTrait,
+        C: ConnectionTrait + TransactionTrait,
         A: 'a,
     {
         if self.insert_struct.columns.is_empty() {
@@ -158,6 +167,31 @@ where
     }
 }
 
+impl<A> InsertIgnore<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Execute the insert, counting the rows skipped as those added but not inserted. An
+    /// insert from a select has no rows added, so reports no rows skipped.
+    pub async fn exec<C>(self, db: &C) -> Result<InsertIgnoreResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if self.insert.columns.is_empty() {
+            return Ok(InsertIgnoreResult::default());
+        }
+        check_refusal(self.insert.refusal)?;
+        let rows = self.insert.primary_keys.len() as u64;
+        let statement = self.build(db.get_database_backend());
+        let result = crate::redact_logged(self.insert.masked, db.execute(statement)).await?;
+        let inserted = result.rows_affected();
+        Ok(InsertIgnoreResult {
+            inserted,
+            skipped: rows.saturating_sub(inserted),
+        })
+    }
+}
+
 impl<A> Insert<A>
 where
     A: ActiveModelTrait,
@@ -180,7 +214,10 @@ where
                 }));
             query.returning(returning);
         }
-        Inserter::<A>::new(self.primary_key, query).exec(db)
+        let future = Inserter::<A>::new(self.primary_key, query)
+            .refuse(self.refusal)
+            .exec(db);
+        crate::redact_logged(self.masked, future)
     }
 
     /// Execute an insert operation without returning (don't use `RETURNING` syntax)
@@ -194,7 +231,10 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_without_returning(db)
+        let future = Inserter::<A>::new(self.primary_key, self.query)
+            .refuse(self.refusal)
+            .exec_without_returning(db);
+        crate::redact_logged(self.masked, future)
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if supported)
@@ -210,7 +250,10 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db)
+        let future = Inserter::<A>::new(self.primary_key, self.query)
+            .refuse(self.refusal)
+            .exec_with_returning(db);
+        crate::redact_logged(self.masked, future)
     }
 
     /// Execute an insert operation and return primary keys of inserted models
@@ -227,24 +270,34 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning_keys(db)
+        let future = Inserter::<A>::new(self.primary_key, self.query)
+            .refuse(self.refusal)
+            .exec_with_returning_keys(db);
+        crate::redact_logged(self.masked, future)
     }
 
-    /// Execute an insert operation and return all inserted models
-    ///
-    /// # Panics
+    /// Execute an insert operation and return all inserted models, in the order they were
+    /// added. Uses `RETURNING` if supported, otherwise selects the inserted rows after the
+    /// insert by their primary keys.
     ///
-    /// Panics if the database backend does not support `INSERT RETURNING`.
+    /// Without `RETURNING`, e.g. on MySQL, the rows are inserted in one statement if the
+    /// primary keys of all models are set. Otherwise, as the keys of the rows given by
+    /// auto-increment can't be told from the last insert id, the rows are inserted one by one
+    /// in a transaction, taking the last insert id of each.
     pub fn exec_with_returning_many<'a, C>(
         self,
         db: &'a C,
     ) -> impl Future<Output = Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>> + 'a
     where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
-        C: ConnectionTrait,
+        C: ConnectionTrait + TransactionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning_many(db)
+        let future = Inserter::<A>::new(self.primary_key, self.query)
+            .refuse(self.refusal)
+            .with_rows(self.primary_keys, self.rows)
+            .exec_with_returning_many(db);
+        crate::redact_logged(self.masked, future)
     }
 }
 
@@ -256,18 +309,40 @@ where
     pub fn new(primary_key: Option<ValueTuple>, query: InsertStatement) -> Self {
         Self {
             primary_key,
+            primary_keys: Vec::new(),
+            rows: Vec::new(),
             query,
+            refusal: None,
             model: PhantomData,
         }
     }
 
+    /// Refuse to execute an insert that was refused when it was built
+    pub(crate) fn refuse(mut self, refusal: Option<Refusal>) -> Self {
+        self.refusal = refusal;
+        self
+    }
+
+    /// The primary keys of the rows inserted, as far as the models had them, and the statements
+    /// inserting each row on its own, to select the rows again after inserting them on a
+    /// backend without `RETURNING`
+    pub(crate) fn with_rows(
+        mut self,
+        primary_keys: Vec<Option<ValueTuple>>,
+        rows: Vec<InsertStatement>,
+    ) -> Self {
+        self.primary_keys = primary_keys;
+        self.rows = rows;
+        self
+    }
+
     /// Execute an insert operation, returning the last inserted id
     pub fn exec<'a, C>(self, db: &'a C) -> impl Future<Output = Result<InsertResult<A>, DbErr>> + 'a
     where
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert(self.primary_key, self.query, db)
+        exec_insert(self.primary_key, self.query, self.refusal, db)
     }
 
     /// Execute an insert operation
@@ -279,7 +354,7 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert_without_returning(self.query, db)
+        exec_insert_without_returning(self.query, self.refusal, db)
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if supported)
@@ -292,7 +367,7 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert_with_returning::<A, _>(self.primary_key, self.query, db)
+        exec_insert_with_returning::<A, _>(self.primary_key, self.query, self.refusal, db)
     }
 
     /// Execute an insert operation and return primary keys of inserted models
@@ -309,36 +384,47 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert_with_returning_keys::<A, _>(self.query, db)
+        exec_insert_with_returning_keys::<A, _>(self.query, self.refusal, db)
     }
 
-    /// Execute an insert operation and return all inserted models
-    ///
-    /// # Panics
+    /// Execute an insert operation and return all inserted models, in the order they were
+    /// added. Uses `RETURNING` if supported, otherwise selects the inserted rows after the
+    /// insert by their primary keys.
     ///
-    /// Panics if the database backend does not support `INSERT RETURNING`.
+    /// Without `RETURNING`, e.g. on MySQL, the rows are inserted in one statement if the
+    /// primary keys of all models are set. Otherwise, as the keys of the rows given by
+    /// auto-increment can't be told from the last insert id, the rows are inserted one by one
+    /// in a transaction, taking the last insert id of each.
     pub fn exec_with_returning_many<'a, C>(
         self,
         db: &'a C,
     ) -> impl Future<Output = Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>> + 'a
     where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
-        C: ConnectionTrait,
+        C: ConnectionTrait + TransactionTrait,
         A: 'a,
     {
-        exec_insert_with_returning_many::<A, _>(self.query, db)
+        exec_insert_with_returning_many::<A, _>(
+            self.primary_keys,
+            self.rows,
+            self.query,
+            self.refusal,
+            db,
+        )
     }
 }
 
 async fn exec_insert<A, C>(
     primary_key: Option<ValueTuple>,
     statement: InsertStatement,
+    refusal: Option<Refusal>,
     db: &C,
 ) -> Result<InsertResult<A>, DbErr>
 where
     C: ConnectionTrait,
     A: ActiveModelTrait,
 {
+    check_refusal(refusal)?;
     type ValueTypeOf<A> = <PrimaryKey<A> as PrimaryKeyTrait>::ValueType;
 
     let db_backend = db.get_database_backend();
@@ -387,11 +473,13 @@ where
 
 async fn exec_insert_without_returning<C>(
     insert_statement: InsertStatement,
+    refusal: Option<Refusal>,
     db: &C,
 ) -> Result<u64, DbErr>
 where
     C: ConnectionTrait,
 {
+    check_refusal(refusal)?;
     let db_backend = db.get_database_backend();
     let insert_statement = db_backend.build(&insert_statement);
     let exec_result = db.execute(insert_statement).await?;
@@ -401,6 +489,7 @@ where
 async fn exec_insert_with_returning<A, C>(
     primary_key: Option<ValueTuple>,
     mut insert_statement: InsertStatement,
+    refusal: Option<Refusal>,
     db: &C,
 ) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
 where
@@ -408,6 +497,7 @@ where
     C: ConnectionTrait,
     A: ActiveModelTrait,
 {
+    check_refusal(refusal)?;
     let db_backend = db.get_database_backend();
     let found = match db.support_returning() {
         true => {
@@ -424,7 +514,7 @@ where
             .await?
         }
         false => {
-            let insert_res = exec_insert::<A, _>(primary_key, insert_statement, db).await?;
+            let insert_res = exec_insert::<A, _>(primary_key, insert_statement, None, db).await?;
             <A::Entity as EntityTrait>::find_by_id(insert_res.last_insert_id)
                 .one(db)
                 .await?
@@ -440,6 +530,7 @@ where
 
 async fn exec_insert_with_returning_keys<A, C>(
     mut insert_statement: InsertStatement,
+    refusal: Option<Refusal>,
     db: &C,
 ) -> Result<Vec<<PrimaryKey<A> as PrimaryKeyTrait>::ValueType>, DbErr>
 where
@@ -447,6 +538,7 @@ where
     C: ConnectionTrait,
     A: ActiveModelTrait,
 {
+    check_refusal(refusal)?;
     let db_backend = db.get_database_backend();
     match db.support_returning() {
         true => {
@@ -475,29 +567,101 @@ where
 }
 
 async fn exec_insert_with_returning_many<A, C>(
+    primary_keys: Vec<Option<ValueTuple>>,
+    rows: Vec<InsertStatement>,
     mut insert_statement: InsertStatement,
+    refusal: Option<Refusal>,
     db: &C,
 ) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
 where
     <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
-    C: ConnectionTrait,
+    C: ConnectionTrait + TransactionTrait,
     A: ActiveModelTrait,
 {
+    check_refusal(refusal)?;
     let db_backend = db.get_database_backend();
-    match db.support_returning() {
-        true => {
-            let returning = Query::returning().exprs(
-                <A::Entity as EntityTrait>::Column::iter()
-                    .map(|c| c.select_as(c.into_returning_expr(db_backend))),
-            );
-            insert_statement.returning(returning);
-            let insert_statement = db_backend.build(&insert_statement);
-            SelectorRaw::<SelectModel<<A::Entity as EntityTrait>::Model>>::from_statement(
-                insert_statement,
-            )
-            .all(db)
-            .await
+    if db.support_returning() {
+        let returning = Query::returning().exprs(
+            <A::Entity as EntityTrait>::Column::iter()
+                .map(|c| c.select_as(c.into_returning_expr(db_backend))),
+        );
+        insert_statement.returning(returning);
+        let insert_statement = db_backend.build(&insert_statement);
+        return SelectorRaw::<SelectModel<<A::Entity as EntityTrait>::Model>>::from_statement(
+            insert_statement,
+        )
+        .all(db)
+        .await;
+    }
+
+    // Without `RETURNING`, the inserted rows are selected by their primary keys. The last
+    // insert id is only the key of the first row of an auto-increment insert: the keys of the
+    // other rows are consecutive only with some server settings (`innodb_autoinc_lock_mode`,
+    // `auto_increment_increment`) and if no row conflicts, which cannot be checked here. So
+    // the rows are then inserted one by one.
+    if let Some(keys) = primary_keys.iter().cloned().collect::<Option<Vec<_>>>() {
+        let statement = db_backend.build(&insert_statement);
+        let res = db.execute(statement).await?;
+        if res.rows_affected() == 0 {
+            return Ok(Vec::new());
         }
-        false => unimplemented!("Database backend doesn't support RETURNING"),
+        return find_inserted::<A, _>(keys, db).await;
+    }
+    if rows.len() != primary_keys.len() {
+        return Err(DbErr::UnpackInsertId);
+    }
+
+    let txn = db.begin().await?;
+    let mut keys = Vec::with_capacity(rows.len());
+    for (row, key) in rows.iter().zip(primary_keys) {
+        let statement = db_backend.build(row);
+        let res = txn.execute(statement).await?;
+        if res.rows_affected() == 0 {
+            continue;
+        }
+        keys.push(match key {
+            Some(key) => key,
+            None => {
+                <PrimaryKey<A> as PrimaryKeyTrait>::ValueType::try_from_u64(res.last_insert_id())
+                    .map_err(|_| DbErr::UnpackInsertId)?
+                    .into_value_tuple()
+            }
+        });
+    }
+    let models = find_inserted::<A, _>(keys, &txn).await?;
+    txn.commit().await?;
+    Ok(models)
+}
+
+/// Select the inserted rows by their primary keys, in the order of the keys
+async fn find_inserted<A, C>(
+    keys: Vec<ValueTuple>,
+    db: &C,
+) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    type ValueTypeOf<A> = <PrimaryKey<A> as PrimaryKeyTrait>::ValueType;
+
+    if keys.is_empty() {
+        return Ok(Vec::new());
     }
+    let order: HashMap<Vec<Value>, usize> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| (key.clone().into_iter().collect(), i))
+        .collect();
+    let mut models = <A::Entity as EntityTrait>::find_by_ids(
+        keys.into_iter().map(ValueTypeOf::<A>::from_value_tuple),
+    )
+    .all(db)
+    .await?;
+    models.sort_by_key(|model| {
+        let key: Vec<Value> = PrimaryKey::<A>::iter()
+            .map(|key| model.get(key.into_column()))
+            .collect();
+        order.get(&key).copied()
+    });
+    Ok(models)
 }
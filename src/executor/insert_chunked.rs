@@ -0,0 +1,263 @@
+use crate::{
+    error::*, ActiveModelTrait, ConnectionTrait, DbBackend, EntityTrait, Insert, IntoActiveModel,
+    Iterable, TransactionTrait,
+};
+use sea_query::OnConflict;
+use std::marker::PhantomData;
+
+/// Insert any number of models, split into as many `INSERT` statements as needed to stay
+/// within the bind parameter limit of the backend
+#[derive(Debug)]
+pub struct InsertChunked<A, I>
+where
+    A: ActiveModelTrait,
+{
+    models: I,
+    chunk_size: Option<usize>,
+    transaction: bool,
+    on_conflict: Option<OnConflict>,
+    model: PhantomData<A>,
+}
+
+/// The most bind parameters a single statement can have on a backend. For SQLite this is the
+/// limit since 3.32; older builds only allow 999 and need a smaller chunk size.
+fn max_bind_params(backend: DbBackend) -> usize {
+    match backend {
+        DbBackend::Postgres | DbBackend::MySql => 65535,
+        DbBackend::Sqlite => 32766,
+    }
+}
+
+impl<A> Insert<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Insert models from an iterator of any length, in chunks that fit the bind parameter
+    /// limit of the backend. The models are only taken from the iterator one chunk at a time.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 2 },
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 1 },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// let cakes = (1..=3).map(|id| cake::ActiveModel {
+    ///     id: NotSet,
+    ///     name: Set(format!("Cake {id}")),
+    /// });
+    ///
+    /// let rows = Insert::many_chunked(cakes)
+    ///     .chunk_size(2)
+    ///     .exec(&db)
+    ///     .await?;
+    /// assert_eq!(rows, 3);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"INSERT INTO "cake" ("name") VALUES ($1), ($2)"#,
+    ///             ["Cake 1".into(), "Cake 2".into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"INSERT INTO "cake" ("name") VALUES ($1)"#,
+    ///             ["Cake 3".into()]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn many_chunked<M, I>(models: I) -> InsertChunked<A, I::IntoIter>
+    where
+        M: IntoActiveModel<A>,
+        I: IntoIterator<Item = M>,
+    {
+        InsertChunked {
+            models: models.into_iter(),
+            chunk_size: None,
+            transaction: false,
+            on_conflict: None,
+            model: PhantomData,
+        }
+    }
+}
+
+impl<A, I> InsertChunked<A, I>
+where
+    A: ActiveModelTrait,
+    I: Iterator,
+    I::Item: IntoActiveModel<A>,
+{
+    /// Insert at most this many rows per statement. A chunk is still made smaller if its
+    /// rows would exceed the bind parameter limit.
+    pub fn chunk_size(mut self, value: usize) -> Self {
+        self.chunk_size = Some(value.max(1));
+        self
+    }
+
+    /// Insert all chunks in one transaction, so that either all or none of the models are
+    /// inserted. Off by default, when each chunk commits on its own.
+    pub fn transaction(mut self, value: bool) -> Self {
+        self.transaction = value;
+        self
+    }
+
+    /// Set the `ON CONFLICT` clause of every chunk
+    pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
+        self.on_conflict = Some(on_conflict);
+        self
+    }
+
+    /// Execute the inserts, returning the total number of rows affected
+    pub async fn exec<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait + TransactionTrait,
+    {
+        if self.transaction {
+            let txn = db.begin().await?;
+            let rows = self.exec_chunks(&txn).await?;
+            txn.commit().await?;
+            Ok(rows)
+        } else {
+            self.exec_chunks(db).await
+        }
+    }
+
+    async fn exec_chunks<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait,
+    {
+        let limit = max_bind_params(db.get_database_backend());
+        let max_rows = self.chunk_size.unwrap_or(usize::MAX);
+        let columns = || <A::Entity as EntityTrait>::Column::iter();
+        let mut used: Vec<bool> = columns().map(|_| false).collect();
+        let mut chunk: Vec<A> = Vec::new();
+        let mut rows = 0;
+
+        let on_conflict = self.on_conflict.as_ref();
+        for model in self.models {
+            let am = model.into_active_model();
+            let has_value: Vec<bool> = columns().map(|col| !am.get(col).is_not_set()).collect();
+            // every row binds a value for each column used by any row of the chunk
+            let width = used
+                .iter()
+                .zip(&has_value)
+                .filter(|(a, b)| **a || **b)
+                .count()
+                .max(1);
+            if !chunk.is_empty() && (chunk.len() >= max_rows || (chunk.len() + 1) * width > limit) {
+                rows += insert(chunk.drain(..), on_conflict, db).await?;
+                used.fill(false);
+            }
+            for (used, has_value) in used.iter_mut().zip(has_value) {
+                *used |= has_value;
+            }
+            chunk.push(am);
+        }
+        if !chunk.is_empty() {
+            rows += insert(chunk.drain(..), on_conflict, db).await?;
+        }
+        Ok(rows)
+    }
+}
+
+async fn insert<A, C>(
+    chunk: impl Iterator<Item = A>,
+    on_conflict: Option<&OnConflict>,
+    db: &C,
+) -> Result<u64, DbErr>
+where
+    A: ActiveModelTrait,
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+    C: ConnectionTrait,
+{
+    let mut insert = Insert::many(chunk);
+    if let Some(on_conflict) = on_conflict {
+        insert = insert.on_conflict(on_conflict.clone());
+    }
+    insert.exec_without_returning(db).await
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn insert_in_chunks() -> Result<(), DbErr> {
+        let cake = |id: i32| cake::Model {
+            id,
+            name: format!("Cake {id}"),
+        };
+        let chunk = |rows_affected| MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        };
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([chunk(16383), chunk(16383), chunk(234)])
+            .into_connection();
+
+        // two columns per row, so at most 16383 rows fit in 32766 parameters
+        let rows = Insert::many_chunked((1..=33000).map(cake))
+            .exec(&db)
+            .await?;
+        assert_eq!(rows, 33000);
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 3);
+        for (txn, len) in log.iter().zip([16383, 16383, 234]) {
+            assert_eq!(txn.statements().len(), 1);
+            assert_eq!(
+                txn.statements()[0].values.as_ref().unwrap().0.len(),
+                2 * len
+            );
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([chunk(2), chunk(1)])
+            .into_connection();
+
+        let rows = Insert::many_chunked((1..=3).map(cake))
+            .chunk_size(2)
+            .transaction(true)
+            .exec(&db)
+            .await?;
+        assert_eq!(rows, 3);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2), ($3, $4)"#,
+                    [1.into(), "Cake 1".into(), 2.into(), "Cake 2".into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2)"#,
+                    [3.into(), "Cake 3".into()]
+                ),
+                Statement::from_string(DbBackend::Postgres, "COMMIT"),
+            ])]
+        );
+
+        Ok(())
+    }
+}
@@ -1,16 +1,34 @@
+mod aggregate;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "csv")]
+mod csv;
 mod cursor;
 mod delete;
 mod execute;
+#[cfg(feature = "with-json")]
+mod explain;
+#[cfg(feature = "with-json")]
+mod graph;
 mod insert;
+mod insert_chunked;
 mod paginator;
 mod query;
+mod returning;
 mod select;
 mod update;
 
+#[cfg(feature = "csv")]
+pub use csv::*;
 pub use cursor::*;
 pub use delete::*;
 pub use execute::*;
+#[cfg(feature = "with-json")]
+pub use explain::*;
+#[cfg(feature = "with-json")]
+pub use graph::*;
 pub use insert::*;
+pub use insert_chunked::*;
 pub use paginator::*;
 pub use query::*;
 pub use select::*;
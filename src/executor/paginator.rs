@@ -1,10 +1,11 @@
 use crate::{
-    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Select, SelectModel,
+    error::*, DbBackend, EntityTrait, FromQueryResult, ReadConnectionTrait, Select, SelectModel,
     SelectThree, SelectThreeModel, SelectTwo, SelectTwoModel, Selector, SelectorRaw, SelectorTrait,
 };
 use async_stream::stream;
 use futures_util::Stream;
 use sea_query::{Alias, Expr, SelectStatement};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::{marker::PhantomData, pin::Pin};
 
 /// Pin a Model so that stream operations can be performed on the model
@@ -14,13 +15,14 @@ pub type PinBoxStream<'db, Item> = Pin<Box<dyn Stream<Item = Item> + 'db>>;
 #[derive(Clone, Debug)]
 pub struct Paginator<'db, C, S>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     S: SelectorTrait + 'db,
 {
     pub(crate) query: SelectStatement,
     pub(crate) page: u64,
     pub(crate) page_size: u64,
     pub(crate) db: &'db C,
+    pub(crate) refusal: Option<Refusal>,
     pub(crate) selector: PhantomData<S>,
 }
 
@@ -33,11 +35,158 @@ pub struct ItemsAndPagesNumber {
     pub number_of_pages: u64,
 }
 
+/// The names of the fields of a serialized [Page]. Implement it on a marker type to match the
+/// envelope of an API, overriding only the names that differ.
+///
+/// ```
+/// use sea_orm::{Page, PageFields};
+///
+/// struct CamelCase;
+///
+/// impl PageFields for CamelCase {
+///     const ITEMS: &'static str = "data";
+///     const PER_PAGE: &'static str = "perPage";
+///     const NUM_ITEMS: &'static str = "totalItems";
+///     const NUM_PAGES: &'static str = "totalPages";
+///     const HAS_NEXT: &'static str = "hasNext";
+///     const HAS_PREV: &'static str = "hasPrev";
+/// }
+///
+/// let page = Page::new(vec!["a", "b"], 0, 2, 3).with_fields::<CamelCase>();
+/// assert_eq!(
+///     serde_json::to_value(&page).unwrap(),
+///     serde_json::json!({
+///         "data": ["a", "b"],
+///         "page": 0,
+///         "perPage": 2,
+///         "totalItems": 3,
+///         "totalPages": 2,
+///         "hasNext": true,
+///         "hasPrev": false,
+///     })
+/// );
+/// ```
+pub trait PageFields {
+    /// The items of the page
+    const ITEMS: &'static str = "items";
+    /// The page index, starting from zero
+    const PAGE: &'static str = "page";
+    /// The page size
+    const PER_PAGE: &'static str = "per_page";
+    /// The total number of items
+    const NUM_ITEMS: &'static str = "num_items";
+    /// The total number of pages
+    const NUM_PAGES: &'static str = "num_pages";
+    /// Whether there is a page after this one
+    const HAS_NEXT: &'static str = "has_next";
+    /// Whether there is a page before this one
+    const HAS_PREV: &'static str = "has_prev";
+}
+
+/// The default names of the fields of a serialized [Page]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DefaultPageFields;
+
+impl PageFields for DefaultPageFields {}
+
+/// A page of items with the totals of its [Paginator], serialized with the field names of
+/// [PageFields]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Page<T, F = DefaultPageFields> {
+    /// The items of the page
+    pub items: Vec<T>,
+    /// The page index, starting from zero
+    pub page: u64,
+    /// The page size
+    pub per_page: u64,
+    /// The total number of items
+    pub num_items: u64,
+    /// The total number of pages
+    pub num_pages: u64,
+    /// Whether there is a page after this one
+    pub has_next: bool,
+    /// Whether there is a page before this one
+    pub has_prev: bool,
+    fields: PhantomData<F>,
+}
+
+impl<T> Page<T> {
+    /// Create a page from its items, index, page size and the total number of items
+    pub fn new(items: Vec<T>, page: u64, per_page: u64, num_items: u64) -> Self {
+        let num_pages = num_items.div_ceil(per_page.max(1));
+        Self {
+            items,
+            page,
+            per_page,
+            num_items,
+            num_pages,
+            has_next: page + 1 < num_pages,
+            has_prev: page > 0,
+            fields: PhantomData,
+        }
+    }
+}
+
+impl<T, F> Page<T, F> {
+    /// Serialize the page with other field names
+    pub fn with_fields<G>(self) -> Page<T, G>
+    where
+        G: PageFields,
+    {
+        self.map_items(|items| items)
+    }
+
+    /// Convert the items, e.g. Models to API responses
+    pub fn map<U, M>(self, f: M) -> Page<U, F>
+    where
+        M: FnMut(T) -> U,
+    {
+        self.map_items(|items| items.into_iter().map(f).collect())
+    }
+
+    fn map_items<U, G, M>(self, f: M) -> Page<U, G>
+    where
+        M: FnOnce(Vec<T>) -> Vec<U>,
+    {
+        Page {
+            items: f(self.items),
+            page: self.page,
+            per_page: self.per_page,
+            num_items: self.num_items,
+            num_pages: self.num_pages,
+            has_next: self.has_next,
+            has_prev: self.has_prev,
+            fields: PhantomData,
+        }
+    }
+}
+
+impl<T, F> Serialize for Page<T, F>
+where
+    T: Serialize,
+    F: PageFields,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut page = serializer.serialize_struct("Page", 7)?;
+        page.serialize_field(F::ITEMS, &self.items)?;
+        page.serialize_field(F::PAGE, &self.page)?;
+        page.serialize_field(F::PER_PAGE, &self.per_page)?;
+        page.serialize_field(F::NUM_ITEMS, &self.num_items)?;
+        page.serialize_field(F::NUM_PAGES, &self.num_pages)?;
+        page.serialize_field(F::HAS_NEXT, &self.has_next)?;
+        page.serialize_field(F::HAS_PREV, &self.has_prev)?;
+        page.end()
+    }
+}
+
 // LINT: warn if paginator is used without an order by clause
 
 impl<'db, C, S> Paginator<'db, C, S>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     S: SelectorTrait + 'db,
 {
     /// Fetch a specific page; page index starts from zero
@@ -48,9 +197,10 @@ where
             .limit(self.page_size)
             .offset(self.page_size * page)
             .to_owned();
-        let builder = self.db.get_database_backend();
+        check_refusal(self.refusal)?;
+        let builder = self.db.read_backend();
         let stmt = builder.build(&query);
-        let rows = self.db.query_all(stmt).await?;
+        let rows = self.db.read_all(stmt).await?;
         let mut buffer = Vec::with_capacity(rows.len());
         for row in rows.into_iter() {
             // TODO: Error handling
@@ -66,7 +216,8 @@ where
 
     /// Get the total number of items
     pub async fn num_items(&self) -> Result<u64, DbErr> {
-        let builder = self.db.get_database_backend();
+        check_refusal(self.refusal)?;
+        let builder = self.db.read_backend();
         let stmt = SelectStatement::new()
             .expr(Expr::cust("COUNT(*) AS num_items"))
             .from_subquery(
@@ -80,7 +231,7 @@ where
             )
             .to_owned();
         let stmt = builder.build(&stmt);
-        let result = match self.db.query_one(stmt).await? {
+        let result = match self.db.read_one(stmt).await? {
             Some(res) => res,
             None => return Ok(0),
         };
@@ -109,6 +260,52 @@ where
         })
     }
 
+    /// Fetch a specific page with the totals of the paginator; page index starts from zero
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(all(feature = "mock", feature = "macros"))]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model {
+    /// #         id: 3,
+    /// #         name: "Chocolate Forest".to_owned(),
+    /// #     }]])
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "num_items" => Into::<Value>::into(5i64),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let page = cake::Entity::find()
+    ///     .order_by_asc(cake::Column::Id)
+    ///     .paginate(&db, 2)
+    ///     .fetch_page_with_totals(1)
+    ///     .await?;
+    ///
+    /// assert_eq!(page.items.len(), 1);
+    /// assert_eq!((page.page, page.per_page), (1, 2));
+    /// assert_eq!((page.num_items, page.num_pages), (5, 3));
+    /// assert!(page.has_next && page.has_prev);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_page_with_totals(&self, page: u64) -> Result<Page<S::Item>, DbErr> {
+        let items = self.fetch_page(page).await?;
+        let num_items = self.num_items().await?;
+        Ok(Page::new(items, page, self.page_size, num_items))
+    }
+
+    /// Fetch the current page with the totals of the paginator
+    pub async fn fetch_with_totals(&self) -> Result<Page<S::Item>, DbErr> {
+        self.fetch_page_with_totals(self.page).await
+    }
+
     /// Compute the number of pages for the current page
     fn compute_pages_number(&self, num_items: u64) -> u64 {
         (num_items / self.page_size) + (num_items % self.page_size > 0) as u64
@@ -210,7 +407,7 @@ where
 /// A Trait for any type that can paginate results
 pub trait PaginatorTrait<'db, C>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
 {
     /// Select operation
     type Selector: SelectorTrait + Send + Sync + 'db;
@@ -229,7 +426,7 @@ where
 
 impl<'db, C, S> PaginatorTrait<'db, C> for Selector<S>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     S: SelectorTrait + Send + Sync + 'db,
 {
     type Selector = S;
@@ -241,6 +438,7 @@ where
             page: 0,
             page_size,
             db,
+            refusal: self.refusal,
             selector: PhantomData,
         }
     }
@@ -248,7 +446,7 @@ where
 
 impl<'db, C, S> PaginatorTrait<'db, C> for SelectorRaw<S>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     S: SelectorTrait + Send + Sync + 'db,
 {
     type Selector = S;
@@ -267,6 +465,7 @@ where
             page: 0,
             page_size,
             db,
+            refusal: None,
             selector: PhantomData,
         }
     }
@@ -274,7 +473,7 @@ where
 
 impl<'db, C, M, E> PaginatorTrait<'db, C> for Select<E>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     E: EntityTrait<Model = M>,
     M: FromQueryResult + Sized + Send + Sync + 'db,
 {
@@ -287,7 +486,7 @@ where
 
 impl<'db, C, M, N, E, F> PaginatorTrait<'db, C> for SelectTwo<E, F>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     E: EntityTrait<Model = M>,
     F: EntityTrait<Model = N>,
     M: FromQueryResult + Sized + Send + Sync + 'db,
@@ -302,7 +501,7 @@ where
 
 impl<'db, C, M, N, O, E, F, G> PaginatorTrait<'db, C> for SelectThree<E, F, G>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     E: EntityTrait<Model = M>,
     F: EntityTrait<Model = N>,
     G: EntityTrait<Model = O>,
@@ -789,6 +988,87 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn fetch_page_with_totals() -> Result<(), DbErr> {
+        let (_, pages) = setup();
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([pages[1].clone()])
+            .append_query_results([[maplit::btreemap! {
+                "num_items" => Into::<Value>::into(3i64),
+            }]])
+            .into_connection();
+
+        let paginator = fruit::Entity::find().paginate(&db, 2);
+        let page = paginator.fetch_page_with_totals(1).await?;
+
+        assert_eq!(page.items, pages[1]);
+        assert_eq!(page.page, 1);
+        assert_eq!(page.per_page, 2);
+        assert_eq!(page.num_items, 3);
+        assert_eq!(page.num_pages, 2);
+        assert!(!page.has_next);
+        assert!(page.has_prev);
+
+        let select = SelectStatement::new()
+            .exprs([
+                Expr::col((fruit::Entity, fruit::Column::Id)),
+                Expr::col((fruit::Entity, fruit::Column::Name)),
+                Expr::col((fruit::Entity, fruit::Column::CakeId)),
+            ])
+            .from(fruit::Entity)
+            .to_owned();
+        let count = SelectStatement::new()
+            .expr(Expr::cust("COUNT(*) AS num_items"))
+            .from_subquery(select.clone(), Alias::new("sub_query"))
+            .to_owned();
+
+        let query_builder = db.get_database_backend();
+        let stmts = [
+            query_builder.build(select.clone().offset(2).limit(2)),
+            query_builder.build(&count),
+        ];
+
+        assert_eq!(db.into_transaction_log(), Transaction::wrap(stmts));
+        Ok(())
+    }
+
+    #[test]
+    fn page_totals() {
+        let empty = Page::<i32>::new(vec![], 0, 10, 0);
+        assert_eq!(empty.num_pages, 0);
+        assert!(!empty.has_next);
+        assert!(!empty.has_prev);
+
+        let first = Page::new(vec![1, 2], 0, 2, 5);
+        assert_eq!(first.num_pages, 3);
+        assert!(first.has_next);
+        assert!(!first.has_prev);
+
+        let last = Page::new(vec![5], 2, 2, 5).map(|i| i.to_string());
+        assert_eq!(last.items, ["5"]);
+        assert_eq!(last.num_pages, 3);
+        assert!(!last.has_next);
+        assert!(last.has_prev);
+    }
+
+    #[test]
+    #[cfg(feature = "with-json")]
+    fn page_serialize() {
+        let page = Page::new(vec!["a"], 0, 1, 1);
+        assert_eq!(
+            serde_json::to_value(&page).unwrap(),
+            serde_json::json!({
+                "items": ["a"],
+                "page": 0,
+                "per_page": 1,
+                "num_items": 1,
+                "num_pages": 1,
+                "has_next": false,
+                "has_prev": false,
+            })
+        );
+    }
+
     #[smol_potat::test]
     #[should_panic]
     async fn error() {
@@ -27,6 +27,12 @@ pub(crate) enum QueryResultRow {
     SqlxPostgres(sqlx::postgres::PgRow),
     #[cfg(feature = "sqlx-sqlite")]
     SqlxSqlite(sqlx::sqlite::SqliteRow),
+    #[cfg(feature = "libsql")]
+    Libsql(crate::LibsqlRow),
+    #[cfg(feature = "duckdb")]
+    Duckdb(crate::DuckdbRow),
+    #[cfg(feature = "tokio-postgres")]
+    TokioPostgres(crate::TokioPostgresRow),
     #[cfg(feature = "mock")]
     Mock(crate::MockRow),
     #[cfg(feature = "proxy")]
@@ -139,6 +145,12 @@ impl QueryResult {
         Ok(T::try_get_many_by_index(self)?)
     }
 
+    /// Whether the result set has a column of the prefixed column name
+    pub fn has_column(&self, pre: &str, col: &str) -> bool {
+        let name = format!("{pre}{col}");
+        self.column_names().iter().any(|column| column == &name)
+    }
+
     /// Retrieves the names of the columns in the result set
     pub fn column_names(&self) -> Vec<String> {
         #[cfg(feature = "sqlx-dep")]
@@ -157,6 +169,12 @@ impl QueryResult {
             QueryResultRow::SqlxSqlite(row) => {
                 row.columns().iter().map(|c| c.name().to_string()).collect()
             }
+            #[cfg(feature = "libsql")]
+            QueryResultRow::Libsql(row) => row.column_names().to_vec(),
+            #[cfg(feature = "duckdb")]
+            QueryResultRow::Duckdb(row) => row.column_names().to_vec(),
+            #[cfg(feature = "tokio-postgres")]
+            QueryResultRow::TokioPostgres(row) => row.column_names(),
             #[cfg(feature = "mock")]
             QueryResultRow::Mock(row) => row
                 .clone()
@@ -204,6 +222,34 @@ impl QueryResult {
         }
     }
 
+    /// Access the underlying `LibsqlRow` if we use the libSQL backend.
+    #[cfg(feature = "libsql")]
+    pub fn try_as_libsql_row(&self) -> Option<&crate::LibsqlRow> {
+        match &self.row {
+            QueryResultRow::Libsql(libsql_row) => Some(libsql_row),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+    /// Access the underlying `DuckdbRow` if we use the DuckDB backend.
+    #[cfg(feature = "duckdb")]
+    pub fn try_as_duckdb_row(&self) -> Option<&crate::DuckdbRow> {
+        match &self.row {
+            QueryResultRow::Duckdb(duckdb_row) => Some(duckdb_row),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+    /// Access the underlying `TokioPostgresRow` if we use the tokio-postgres backend.
+    #[cfg(feature = "tokio-postgres")]
+    pub fn try_as_tokio_postgres_row(&self) -> Option<&crate::TokioPostgresRow> {
+        match &self.row {
+            QueryResultRow::TokioPostgres(tokio_postgres_row) => Some(tokio_postgres_row),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
     /// Access the underlying `MockRow` if we use a mock.
     #[cfg(feature = "mock")]
     pub fn try_as_mock_row(&self) -> Option<&crate::MockRow> {
@@ -235,6 +281,12 @@ impl fmt::Debug for QueryResultRow {
             Self::SqlxPostgres(_) => write!(f, "QueryResultRow::SqlxPostgres cannot be inspected"),
             #[cfg(feature = "sqlx-sqlite")]
             Self::SqlxSqlite(_) => write!(f, "QueryResultRow::SqlxSqlite cannot be inspected"),
+            #[cfg(feature = "libsql")]
+            Self::Libsql(row) => write!(f, "{row:?}"),
+            #[cfg(feature = "duckdb")]
+            Self::Duckdb(row) => write!(f, "{row:?}"),
+            #[cfg(feature = "tokio-postgres")]
+            Self::TokioPostgres(row) => write!(f, "{row:?}"),
             #[cfg(feature = "mock")]
             Self::Mock(row) => write!(f, "{row:?}"),
             #[cfg(feature = "proxy")]
@@ -378,6 +430,21 @@ macro_rules! try_getable_all {
                         .try_get::<Option<$type>, _>(idx.as_sqlx_sqlite_index())
                         .map_err(|e| sqlx_error_to_query_err(e).into())
                         .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "libsql")]
+                    QueryResultRow::Libsql(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "duckdb")]
+                    QueryResultRow::Duckdb(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "tokio-postgres")]
+                    QueryResultRow::TokioPostgres(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
                     #[cfg(feature = "mock")]
                     QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
                         debug_print!("{:#?}", e.to_string());
@@ -418,6 +485,21 @@ macro_rules! try_getable_unsigned {
                         .try_get::<Option<$type>, _>(idx.as_sqlx_sqlite_index())
                         .map_err(|e| sqlx_error_to_query_err(e).into())
                         .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "libsql")]
+                    QueryResultRow::Libsql(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "duckdb")]
+                    QueryResultRow::Duckdb(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "tokio-postgres")]
+                    QueryResultRow::TokioPostgres(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
                     #[cfg(feature = "mock")]
                     QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
                         debug_print!("{:#?}", e.to_string());
@@ -459,6 +541,21 @@ macro_rules! try_getable_mysql {
                         stringify!($type)
                     ))
                     .into()),
+                    #[cfg(feature = "libsql")]
+                    QueryResultRow::Libsql(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "duckdb")]
+                    QueryResultRow::Duckdb(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "tokio-postgres")]
+                    QueryResultRow::TokioPostgres(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
                     #[cfg(feature = "mock")]
                     QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
                         debug_print!("{:#?}", e.to_string());
@@ -505,6 +602,21 @@ macro_rules! try_getable_date_time {
                             .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)))
                             .map(|v| v.into())
                     }
+                    #[cfg(feature = "libsql")]
+                    QueryResultRow::Libsql(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "duckdb")]
+                    QueryResultRow::Duckdb(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "tokio-postgres")]
+                    QueryResultRow::TokioPostgres(row) => row
+                        .try_get::<$type, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
                     #[cfg(feature = "mock")]
                     QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
                         debug_print!("{:#?}", e.to_string());
@@ -603,6 +715,21 @@ impl TryGetable for Decimal {
                     None => Err(err_null_idx_col(idx)),
                 }
             }
+            #[cfg(feature = "libsql")]
+            QueryResultRow::Libsql(row) => row
+                .try_get::<Decimal, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "duckdb")]
+            QueryResultRow::Duckdb(row) => row
+                .try_get::<Decimal, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "tokio-postgres")]
+            QueryResultRow::TokioPostgres(row) => row
+                .try_get::<Decimal, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
             #[cfg(feature = "mock")]
             #[allow(unused_variables)]
             QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
@@ -656,6 +783,21 @@ impl TryGetable for BigDecimal {
                     None => Err(err_null_idx_col(idx)),
                 }
             }
+            #[cfg(feature = "libsql")]
+            QueryResultRow::Libsql(row) => row
+                .try_get::<BigDecimal, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "duckdb")]
+            QueryResultRow::Duckdb(row) => row
+                .try_get::<BigDecimal, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "tokio-postgres")]
+            QueryResultRow::TokioPostgres(row) => row
+                .try_get::<BigDecimal, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
             #[cfg(feature = "mock")]
             #[allow(unused_variables)]
             QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
@@ -723,6 +865,21 @@ macro_rules! try_getable_uuid {
                         .try_get::<Option<uuid::Uuid>, _>(idx.as_sqlx_sqlite_index())
                         .map_err(|e| sqlx_error_to_query_err(e).into())
                         .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "libsql")]
+                    QueryResultRow::Libsql(row) => row
+                        .try_get::<uuid::Uuid, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "duckdb")]
+                    QueryResultRow::Duckdb(row) => row
+                        .try_get::<uuid::Uuid, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "tokio-postgres")]
+                    QueryResultRow::TokioPostgres(row) => row
+                        .try_get::<uuid::Uuid, _>(idx)
+                        .map_err(Into::into)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
                     #[cfg(feature = "mock")]
                     #[allow(unused_variables)]
                     QueryResultRow::Mock(row) => row.try_get::<uuid::Uuid, _>(idx).map_err(|e| {
@@ -783,6 +940,21 @@ impl TryGetable for u32 {
                 .try_get::<Option<u32>, _>(idx.as_sqlx_sqlite_index())
                 .map_err(|e| sqlx_error_to_query_err(e).into())
                 .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "libsql")]
+            QueryResultRow::Libsql(row) => row
+                .try_get::<u32, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "duckdb")]
+            QueryResultRow::Duckdb(row) => row
+                .try_get::<u32, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "tokio-postgres")]
+            QueryResultRow::TokioPostgres(row) => row
+                .try_get::<u32, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
             #[cfg(feature = "mock")]
             #[allow(unused_variables)]
             QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
@@ -830,6 +1002,21 @@ impl TryGetable for String {
                 .try_get::<Option<String>, _>(idx.as_sqlx_sqlite_index())
                 .map_err(|e| sqlx_error_to_query_err(e).into())
                 .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "libsql")]
+            QueryResultRow::Libsql(row) => row
+                .try_get::<String, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "duckdb")]
+            QueryResultRow::Duckdb(row) => row
+                .try_get::<String, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+            #[cfg(feature = "tokio-postgres")]
+            QueryResultRow::TokioPostgres(row) => row
+                .try_get::<String, _>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
             #[cfg(feature = "mock")]
             QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
                 debug_print!("{:#?}", e.to_string());
@@ -879,6 +1066,22 @@ mod postgres_array {
                             stringify!($type)
                         ))
                         .into()),
+                        #[cfg(feature = "libsql")]
+                        QueryResultRow::Libsql(_) => Err(type_err(format!(
+                            "{} unsupported by libsql",
+                            stringify!($type)
+                        ))
+                        .into()),
+                        #[cfg(feature = "duckdb")]
+                        QueryResultRow::Duckdb(row) => row
+                            .try_get_array::<$type, _>(idx)
+                            .map_err(Into::into)
+                            .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                        #[cfg(feature = "tokio-postgres")]
+                        QueryResultRow::TokioPostgres(row) => row
+                            .try_get_array::<$type, _>(idx)
+                            .map_err(Into::into)
+                            .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
                         #[cfg(feature = "mock")]
                         #[allow(unused_variables)]
                         QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
@@ -971,6 +1174,22 @@ mod postgres_array {
                             stringify!($type)
                         ))
                         .into()),
+                        #[cfg(feature = "libsql")]
+                        QueryResultRow::Libsql(_) => Err(type_err(format!(
+                            "{} unsupported by libsql",
+                            stringify!($type)
+                        ))
+                        .into()),
+                        #[cfg(feature = "duckdb")]
+                        QueryResultRow::Duckdb(row) => row
+                            .try_get_array::<uuid::Uuid, _>(idx)
+                            .map_err(Into::into)
+                            .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                        #[cfg(feature = "tokio-postgres")]
+                        QueryResultRow::TokioPostgres(row) => row
+                            .try_get_array::<uuid::Uuid, _>(idx)
+                            .map_err(Into::into)
+                            .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
                         #[cfg(feature = "mock")]
                         QueryResultRow::Mock(row) => {
                             row.try_get::<Vec<uuid::Uuid>, _>(idx).map_err(|e| {
@@ -1033,6 +1252,20 @@ mod postgres_array {
                     stringify!($type)
                 ))
                 .into()),
+                #[cfg(feature = "libsql")]
+                QueryResultRow::Libsql(_) => {
+                    Err(type_err(format!("{} unsupported by libsql", stringify!($type))).into())
+                }
+                #[cfg(feature = "duckdb")]
+                QueryResultRow::Duckdb(row) => row
+                    .try_get_array::<u32, _>(idx)
+                    .map_err(Into::into)
+                    .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                #[cfg(feature = "tokio-postgres")]
+                QueryResultRow::TokioPostgres(row) => row
+                    .try_get_array::<u32, _>(idx)
+                    .map_err(Into::into)
+                    .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
                 #[cfg(feature = "mock")]
                 #[allow(unused_variables)]
                 QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
@@ -1070,6 +1303,14 @@ impl TryGetable for pgvector::Vector {
             QueryResultRow::SqlxSqlite(_) => {
                 Err(type_err("Vector unsupported by sqlx-sqlite").into())
             }
+            #[cfg(feature = "libsql")]
+            QueryResultRow::Libsql(_) => Err(type_err("Vector unsupported by libsql").into()),
+            #[cfg(feature = "duckdb")]
+            QueryResultRow::Duckdb(_) => Err(type_err("Vector unsupported by DuckDB").into()),
+            #[cfg(feature = "tokio-postgres")]
+            QueryResultRow::TokioPostgres(_) => {
+                Err(type_err("Vector unsupported by tokio-postgres").into())
+            }
             #[cfg(feature = "mock")]
             QueryResultRow::Mock(row) => row.try_get::<pgvector::Vector, _>(idx).map_err(|e| {
                 debug_print!("{:#?}", e.to_string());
@@ -1286,6 +1527,24 @@ where
                 .try_get::<Option<sqlx::types::Json<Self>>, _>(idx.as_sqlx_sqlite_index())
                 .map_err(|e| sqlx_error_to_query_err(e).into())
                 .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)).map(|json| json.0)),
+            #[cfg(feature = "libsql")]
+            QueryResultRow::Libsql(row) => row
+                .try_get::<serde_json::Value, I>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)))
+                .and_then(|json| serde_json::from_value(json).map_err(|e| json_err(e).into())),
+            #[cfg(feature = "duckdb")]
+            QueryResultRow::Duckdb(row) => row
+                .try_get::<serde_json::Value, I>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)))
+                .and_then(|json| serde_json::from_value(json).map_err(|e| json_err(e).into())),
+            #[cfg(feature = "tokio-postgres")]
+            QueryResultRow::TokioPostgres(row) => row
+                .try_get::<serde_json::Value, I>(idx)
+                .map_err(Into::into)
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)))
+                .and_then(|json| serde_json::from_value(json).map_err(|e| json_err(e).into())),
             #[cfg(feature = "mock")]
             QueryResultRow::Mock(row) => row
                 .try_get::<serde_json::Value, I>(idx)
@@ -0,0 +1,308 @@
+use crate::{
+    ColumnTrait, Condition, DbBackend, EntityTrait, Iterable, ModelTrait, PrimaryKeyToColumn,
+    QueryFilter, QuerySelect, Select,
+};
+use sea_query::{
+    BinOper, ConditionHolder, EscapeBuilder, Expr, MysqlQueryBuilder, Oper, OperLeftAssocDecider,
+    PostgresQueryBuilder, PrecedenceDecider, QueryBuilder, QueryStatementBuilder, Quote,
+    QuotedBuilder, SelectStatement, SimpleExpr, SqlWriter, SqlWriterValues, SqliteQueryBuilder,
+    SubQueryStatement, TableRefBuilder, UnionType, Value, WindowStatement,
+};
+use std::cell::RefCell;
+
+/// A query builder recording the `WHERE` clause of an `UPDATE` or `DELETE`, rendered by another
+/// query builder
+struct WhereCapture<B> {
+    builder: B,
+    condition: RefCell<Option<(String, Vec<Value>)>>,
+}
+
+impl<B> QuotedBuilder for WhereCapture<B>
+where
+    B: QueryBuilder,
+{
+    fn quote(&self) -> Quote {
+        self.builder.quote()
+    }
+}
+
+impl<B> EscapeBuilder for WhereCapture<B> where B: QueryBuilder {}
+
+impl<B> TableRefBuilder for WhereCapture<B> where B: QueryBuilder {}
+
+impl<B> PrecedenceDecider for WhereCapture<B>
+where
+    B: QueryBuilder,
+{
+    fn inner_expr_well_known_greater_precedence(&self, inner: &SimpleExpr, outer: &Oper) -> bool {
+        self.builder
+            .inner_expr_well_known_greater_precedence(inner, outer)
+    }
+}
+
+impl<B> OperLeftAssocDecider for WhereCapture<B>
+where
+    B: QueryBuilder,
+{
+    fn well_known_left_associative(&self, op: &BinOper) -> bool {
+        self.builder.well_known_left_associative(op)
+    }
+}
+
+impl<B> QueryBuilder for WhereCapture<B>
+where
+    B: QueryBuilder,
+{
+    fn prepare_query_statement(&self, query: &SubQueryStatement, sql: &mut dyn SqlWriter) {
+        self.builder.prepare_query_statement(query, sql);
+    }
+
+    fn prepare_union_statement(
+        &self,
+        union_type: UnionType,
+        select_statement: &SelectStatement,
+        sql: &mut dyn SqlWriter,
+    ) {
+        self.builder
+            .prepare_union_statement(union_type, select_statement, sql);
+    }
+
+    fn prepare_window_statement(&self, window: &WindowStatement, sql: &mut dyn SqlWriter) {
+        self.builder.prepare_window_statement(window, sql);
+    }
+
+    fn prepare_value(&self, value: &Value, sql: &mut dyn SqlWriter) {
+        self.builder.prepare_value(value, sql);
+    }
+
+    fn prepare_condition(&self, condition: &ConditionHolder, keyword: &str, _: &mut dyn SqlWriter) {
+        let (placeholder, numbered) = self.builder.placeholder();
+        let mut sql = SqlWriterValues::new(placeholder, numbered);
+        self.builder.prepare_condition(condition, keyword, &mut sql);
+        let (sql, values) = sql.into_parts();
+        if let Some(sql) = sql.strip_prefix(&format!(" {keyword} ")) {
+            *self.condition.borrow_mut() = Some((sql.to_owned(), values.0));
+        }
+    }
+}
+
+/// The `WHERE` clause of an `UPDATE` or `DELETE` statement, rendered by a query builder to be
+/// used in [Expr::cust_with_values]
+fn capture_where<B, Q>(query: &Q, builder: B) -> Option<(String, Vec<Value>)>
+where
+    B: QueryBuilder,
+    Q: QueryStatementBuilder,
+{
+    let capture = WhereCapture {
+        builder,
+        condition: RefCell::new(None),
+    };
+    query.build_any(&capture);
+    capture.condition.into_inner()
+}
+
+/// The `WHERE` clause of an `UPDATE` or `DELETE` statement for a backend, see [capture_where]
+pub(super) fn where_clause<Q>(query: &Q, backend: DbBackend) -> Option<(String, Vec<Value>)>
+where
+    Q: QueryStatementBuilder,
+{
+    match backend {
+        DbBackend::MySql => capture_where(query, MysqlQueryBuilder),
+        DbBackend::Postgres => capture_where(query, PostgresQueryBuilder),
+        DbBackend::Sqlite => capture_where(query, SqliteQueryBuilder),
+    }
+}
+
+/// Select the rows an `UPDATE` or `DELETE` statement would affect, by its `WHERE` clause,
+/// rendered for the backend. On MySQL and Postgres the rows are locked with `FOR UPDATE`, which
+/// only holds until the end of the enclosing transaction, so the emulation is only atomic when
+/// run inside one. SQLite has no row locks, but serializes writes to the whole database.
+///
+/// Default scopes, expiry and soft deletes are not applied, as `RETURNING` would return every
+/// row the statement touched.
+pub(super) fn select_affected<E, Q>(query: &Q, backend: DbBackend) -> Select<E>
+where
+    E: EntityTrait,
+    Q: QueryStatementBuilder,
+{
+    let select = match backend {
+        DbBackend::MySql | DbBackend::Postgres => Select::<E>::new_unscoped().lock_exclusive(),
+        DbBackend::Sqlite => Select::<E>::new_unscoped(),
+    };
+    match where_clause(query, backend) {
+        Some((sql, values)) => select.filter(Expr::cust_with_values(sql, values)),
+        None => select,
+    }
+}
+
+/// Select models again by their primary keys, without default scopes, expiry or soft deletes
+pub(super) fn select_by_primary_keys<E>(models: &[E::Model]) -> Select<E>
+where
+    E: EntityTrait,
+{
+    let condition = models.iter().fold(Condition::any(), |condition, model| {
+        let key = E::PrimaryKey::iter().fold(Condition::all(), |key, pk| {
+            let col = pk.into_column();
+            key.add(col.eq(model.get(col)))
+        });
+        condition.add(key)
+    });
+    Select::<E>::new_unscoped().filter(condition)
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+    use sea_query::Expr;
+
+    #[smol_potat::test]
+    async fn emulate_returning_on_mysql() -> Result<(), DbErr> {
+        let cheese = |id| cake::Model {
+            id,
+            name: "Cheese Cake".to_owned(),
+        };
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([
+                vec![cake::Model {
+                    id: 1,
+                    name: "Cheesecake".to_owned(),
+                }],
+                vec![cheese(1)],
+                vec![cheese(1), cheese(2)],
+            ])
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 2,
+                },
+            ])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::update_many()
+                .col_expr(cake::Column::Name, Expr::value("Cheese Cake"))
+                .filter(cake::Column::Name.contains("Cheese"))
+                .exec_with_returning(&db)
+                .await?,
+            [cheese(1)]
+        );
+
+        assert_eq!(
+            cake::Entity::delete_many()
+                .filter(cake::Column::Id.lt(3))
+                .exec_with_returning(&db)
+                .await?,
+            [cheese(1), cheese(2)]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE ? FOR UPDATE",
+                    ["%Cheese%".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "UPDATE `cake` SET `name` = ? WHERE `cake`.`name` LIKE ?",
+                    ["Cheese Cake".into(), "%Cheese%".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ?",
+                    [1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` < ? FOR UPDATE",
+                    [3i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "DELETE FROM `cake` WHERE `cake`.`id` < ?",
+                    [3i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+    #[smol_potat::test]
+    #[cfg(not(feature = "sqlite-use-returning-for-3_35"))]
+    async fn emulate_returning_on_sqlite() -> Result<(), DbErr> {
+        let cheese = |id| cake::Model {
+            id,
+            name: "Cheese Cake".to_owned(),
+        };
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([vec![cheese(1)], vec![cheese(1)], vec![cheese(2)]])
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::update_many()
+                .col_expr(cake::Column::Name, Expr::value("Cheese Cake"))
+                .filter(cake::Column::Name.contains("Cheese"))
+                .exec_with_returning(&db)
+                .await?,
+            [cheese(1)]
+        );
+
+        assert_eq!(
+            cake::Entity::delete_many()
+                .filter(cake::Column::Id.eq(2))
+                .exec_with_returning(&db)
+                .await?,
+            [cheese(2)]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE ?"#,
+                    ["%Cheese%".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"UPDATE "cake" SET "name" = ? WHERE "cake"."name" LIKE ?"#,
+                    ["Cheese Cake".into(), "%Cheese%".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = ?"#,
+                    [1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = ?"#,
+                    [2i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"DELETE FROM "cake" WHERE "cake"."id" = ?"#,
+                    [2i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
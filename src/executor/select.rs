@@ -1,13 +1,24 @@
 use crate::{
-    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic, Iterable,
-    ModelTrait, PartialModelTrait, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait,
-    QueryResult, QuerySelect, Select, SelectA, SelectB, SelectC, SelectThree, SelectTwo,
-    SelectTwoMany, Statement, StreamTrait, TryGetableMany,
+    error::*, DbBackend, EntityTrait, FromQueryResult, IdenStatic, Iterable, ModelTrait,
+    PartialModelTrait, PartialModelWithContext, PrimaryKeyArity, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryResult, QuerySelect, ReadConnectionTrait, Select, SelectA, SelectB,
+    SelectC, SelectDerived, SelectThree, SelectTwo, SelectTwoMany, Statement, StreamTrait,
+    TryGetableMany,
 };
 use futures_util::{Stream, TryStreamExt};
-use sea_query::{SelectStatement, Value};
+use sea_query::{
+    BinOper, EscapeBuilder, LockClause, Oper, OperLeftAssocDecider, OrderExpr,
+    PostgresQueryBuilder, PrecedenceDecider, Query, QueryBuilder, Quote, QuotedBuilder,
+    SelectDistinct, SelectExpr, SelectStatement, SimpleExpr, SqlWriter, SubQueryStatement,
+    TableRefBuilder, UnionType, Value, WindowStatement,
+};
 use std::collections::HashMap;
-use std::{hash::Hash, marker::PhantomData, pin::Pin};
+use std::{
+    cell::{Cell, RefCell},
+    hash::Hash,
+    marker::PhantomData,
+    pin::Pin,
+};
 
 #[cfg(feature = "with-json")]
 use crate::JsonValue;
@@ -19,6 +30,7 @@ where
     S: SelectorTrait,
 {
     pub(crate) query: SelectStatement,
+    pub(crate) refusal: Option<Refusal>,
     selector: S,
 }
 
@@ -40,6 +52,12 @@ pub trait SelectorTrait {
 
     /// The method to perform a query on a Model
     fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr>;
+
+    /// Merge the item of the next row into the item of this one, see
+    /// [FromQueryResult::merge_row]
+    fn merge_item(_item: &mut Self::Item, next: Self::Item) -> Option<Self::Item> {
+        Some(next)
+    }
 }
 
 /// Get tuple from query result based on a list of column identifiers
@@ -92,6 +110,17 @@ where
     model: PhantomData<(M, N, O)>,
 }
 
+/// Helper class to handle query result for 1 Model as JSON, with the masked columns of the
+/// entity redacted, see [UnmaskContext](crate::UnmaskContext)
+#[cfg(feature = "with-json")]
+#[derive(Clone, Debug)]
+pub struct SelectMaskedJson<E>
+where
+    E: EntityTrait,
+{
+    entity: PhantomData<E>,
+}
+
 impl<T, C> SelectorTrait for SelectGetableValue<T, C>
 where
     T: TryGetableMany,
@@ -125,6 +154,10 @@ where
     fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
         M::from_query_result(&res, "")
     }
+
+    fn merge_item(item: &mut Self::Item, next: Self::Item) -> Option<Self::Item> {
+        item.merge_row(next)
+    }
 }
 
 impl<M, N> SelectorTrait for SelectTwoModel<M, N>
@@ -159,6 +192,191 @@ where
     }
 }
 
+#[cfg(feature = "with-json")]
+impl<E> SelectorTrait for SelectMaskedJson<E>
+where
+    E: EntityTrait,
+{
+    type Item = JsonValue;
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        let mut json = JsonValue::from_query_result(&res, "")?;
+        crate::redact_json::<E>(&mut json);
+        Ok(json)
+    }
+}
+
+/// A query builder recording the clauses of the top level `SELECT` that not every backend
+/// renders: row locking, which SQLite drops silently, and `DISTINCT ON`, which only Postgres
+/// supports, with the `ORDER BY` it must agree with. It also records the selected expressions
+/// and the `ORDER BY` expressions.
+#[derive(Default)]
+struct SelectInspector {
+    exprs: RefCell<Vec<SelectExpr>>,
+    locked: Cell<bool>,
+    distinct_on: RefCell<Vec<String>>,
+    orders: RefCell<Vec<String>>,
+    order_exprs: RefCell<Vec<OrderExpr>>,
+}
+
+impl QuotedBuilder for SelectInspector {
+    fn quote(&self) -> Quote {
+        PostgresQueryBuilder.quote()
+    }
+}
+
+impl EscapeBuilder for SelectInspector {}
+
+impl TableRefBuilder for SelectInspector {}
+
+impl PrecedenceDecider for SelectInspector {
+    fn inner_expr_well_known_greater_precedence(&self, inner: &SimpleExpr, outer: &Oper) -> bool {
+        PostgresQueryBuilder.inner_expr_well_known_greater_precedence(inner, outer)
+    }
+}
+
+impl OperLeftAssocDecider for SelectInspector {
+    fn well_known_left_associative(&self, op: &BinOper) -> bool {
+        PostgresQueryBuilder.well_known_left_associative(op)
+    }
+}
+
+impl QueryBuilder for SelectInspector {
+    fn prepare_query_statement(&self, query: &SubQueryStatement, sql: &mut dyn SqlWriter) {
+        PostgresQueryBuilder.prepare_query_statement(query, sql);
+    }
+
+    fn prepare_union_statement(
+        &self,
+        union_type: UnionType,
+        select_statement: &SelectStatement,
+        sql: &mut dyn SqlWriter,
+    ) {
+        PostgresQueryBuilder.prepare_union_statement(union_type, select_statement, sql);
+    }
+
+    fn prepare_window_statement(&self, window: &WindowStatement, sql: &mut dyn SqlWriter) {
+        PostgresQueryBuilder.prepare_window_statement(window, sql);
+    }
+
+    fn prepare_value(&self, value: &Value, sql: &mut dyn SqlWriter) {
+        PostgresQueryBuilder.prepare_value(value, sql);
+    }
+
+    fn prepare_select_expr(&self, select_expr: &SelectExpr, _: &mut dyn SqlWriter) {
+        self.exprs.borrow_mut().push(select_expr.clone());
+    }
+
+    fn prepare_select_distinct(&self, select_distinct: &SelectDistinct, _: &mut dyn SqlWriter) {
+        if let SelectDistinct::DistinctOn(cols) = select_distinct {
+            let mut distinct_on = self.distinct_on.borrow_mut();
+            for col in cols {
+                let mut sql = String::new();
+                PostgresQueryBuilder.prepare_column_ref(col, &mut sql);
+                distinct_on.push(sql);
+            }
+        }
+    }
+
+    fn prepare_order_expr(&self, order_expr: &OrderExpr, _: &mut dyn SqlWriter) {
+        let mut sql = String::new();
+        PostgresQueryBuilder.prepare_order_expr(order_expr, &mut sql);
+        self.orders.borrow_mut().push(sql);
+        self.order_exprs.borrow_mut().push(order_expr.clone());
+    }
+
+    fn prepare_select_lock(&self, _: &LockClause, _: &mut dyn SqlWriter) {
+        self.locked.set(true);
+    }
+}
+
+/// Fail before running a statement the backend would run differently than it reads: with row
+/// locking on SQLite, with `DISTINCT ON` on MySQL and SQLite, or on Postgres with `DISTINCT ON`
+/// expressions not leading the `ORDER BY`
+fn check_select(backend: DbBackend, query: &SelectStatement) -> Result<(), DbErr> {
+    let inspector = SelectInspector::default();
+    query.build_any(&inspector);
+    let err = |msg: &str| Err(DbErr::Query(RuntimeErr::Internal(msg.to_owned())));
+
+    if inspector.locked.get() && backend == DbBackend::Sqlite {
+        return err("SQLite does not support row locking");
+    }
+    let distinct_on = inspector.distinct_on.into_inner();
+    if distinct_on.is_empty() {
+        return Ok(());
+    }
+    if backend != DbBackend::Postgres {
+        return err(&format!("{backend:?} does not support DISTINCT ON"));
+    }
+    let orders = inspector.orders.into_inner();
+    let leading = orders.iter().take(distinct_on.len()).all(|order| {
+        let expr = [" ASC", " DESC"]
+            .iter()
+            .find_map(|dir| order.rsplit_once(dir))
+            .map_or(order.as_str(), |(expr, _)| expr);
+        distinct_on.iter().any(|col| same_column(expr, col))
+    });
+    if !leading {
+        return err("DISTINCT ON expressions must match the leftmost ORDER BY expressions");
+    }
+    Ok(())
+}
+
+/// The expressions selected by the top level `SELECT`
+pub(crate) fn select_exprs(query: &SelectStatement) -> Vec<SelectExpr> {
+    let inspector = SelectInspector::default();
+    query.build_any(&inspector);
+    inspector.exprs.into_inner()
+}
+
+/// The `ORDER BY` expressions of the top level `SELECT`
+pub(crate) fn select_orders(query: &SelectStatement) -> Vec<OrderExpr> {
+    let inspector = SelectInspector::default();
+    query.build_any(&inspector);
+    inspector.order_exprs.into_inner()
+}
+
+/// Whether two rendered columns are the same, one of them possibly without its table
+fn same_column(a: &str, b: &str) -> bool {
+    a == b
+        || (!a.contains('.') && b.ends_with(&format!(".{a}")))
+        || (!b.contains('.') && a.ends_with(&format!(".{b}")))
+}
+
+/// Find where the top level `FROM` clause of a `SELECT` ends, skipping over
+/// quoted identifiers, string literals and parenthesized subqueries
+fn end_of_from_clause(sql: &str) -> usize {
+    const CLAUSES: [&str; 11] = [
+        " WHERE ",
+        " GROUP BY ",
+        " HAVING ",
+        " WINDOW ",
+        " UNION ",
+        " EXCEPT ",
+        " INTERSECT ",
+        " ORDER BY ",
+        " LIMIT ",
+        " OFFSET ",
+        " FOR ",
+    ];
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in sql.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ' ') if depth == 0 && CLAUSES.iter().any(|k| sql[i..].starts_with(k)) => {
+                return i
+            }
+            _ => {}
+        }
+    }
+    sql.len()
+}
+
 impl<E> Select<E>
 where
     E: EntityTrait,
@@ -179,10 +397,20 @@ where
     {
         Selector {
             query: self.query,
+            refusal: self.refusal,
             selector: SelectModel { model: PhantomData },
         }
     }
 
+    /// Read the data as it was at the given time on CockroachDB.
+    /// See [Selector::as_of_system_time].
+    pub fn as_of_system_time<T>(self, time: T) -> SelectorRaw<SelectModel<E::Model>>
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.into_model().as_of_system_time(time)
+    }
+
     /// Return a [Selector] from `Self` that wraps a [SelectModel] with a [PartialModel](PartialModelTrait)
     ///
     /// ```
@@ -222,12 +450,65 @@ where
         M::select_cols(QuerySelect::select_only(self)).into_model::<M>()
     }
 
-    /// Get a selectable Model as a [JsonValue] for SQL JSON operations
+    /// Return a [Selector] from `Self` that wraps a [SelectModel] with a
+    /// [PartialModel](PartialModelWithContext) selected with a context, for expressions with
+    /// runtime parameters
+    ///
+    /// ```
+    /// # #[cfg(feature = "macros")]
+    /// # {
+    /// use sea_orm::{
+    ///     entity::*,
+    ///     query::*,
+    ///     tests_cfg::cake::{self, Entity as Cake},
+    ///     DbBackend, DerivePartialModel, FromQueryResult,
+    /// };
+    /// use sea_query::{Expr, SimpleExpr};
+    ///
+    /// struct Params {
+    ///     offset: i32,
+    /// }
+    ///
+    /// fn id_with_offset(params: &Params) -> SimpleExpr {
+    ///     Expr::col((Cake, cake::Column::Id)).add(params.offset)
+    /// }
+    ///
+    /// #[derive(DerivePartialModel, FromQueryResult)]
+    /// #[sea_orm(entity = "Cake", context = "Params")]
+    /// struct PartialCake {
+    ///     name: String,
+    ///     #[sea_orm(from_expr_fn = "id_with_offset")]
+    ///     shifted_id: i32,
+    /// }
+    ///
+    /// let params = Params { offset: 10 };
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .into_partial_model_with::<PartialCake>(&params)
+    ///         .into_statement(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."name" AS "name", "cake"."id" + 10 AS "shifted_id" FROM "cake""#
+    /// );
+    /// # }
+    /// ```
+    pub fn into_partial_model_with<M>(self, context: &M::Context) -> Selector<SelectModel<M>>
+    where
+        M: PartialModelWithContext,
+    {
+        M::select_cols_with(QuerySelect::select_only(self), context).into_model::<M>()
+    }
+
+    /// Get a selectable Model as a [JsonValue] for SQL JSON operations, with the masked columns
+    /// redacted unless unmasked by the [UnmaskContext](crate::UnmaskContext)
     #[cfg(feature = "with-json")]
-    pub fn into_json(self) -> Selector<SelectModel<JsonValue>> {
+    pub fn into_json(self) -> Selector<SelectMaskedJson<E>> {
         Selector {
             query: self.query,
-            selector: SelectModel { model: PhantomData },
+            refusal: self.refusal,
+            selector: SelectMaskedJson {
+                entity: PhantomData,
+            },
         }
     }
 
@@ -338,7 +619,7 @@ where
         T: TryGetableMany,
         C: strum::IntoEnumIterator + sea_query::Iden,
     {
-        Selector::<SelectGetableValue<T, C>>::with_columns(self.query)
+        Selector::<SelectGetableValue<T, C>>::with_columns(self.query).refuse(self.refusal)
     }
 
     /// ```
@@ -436,13 +717,13 @@ where
     where
         T: TryGetableMany,
     {
-        Selector::<SelectGetableTuple<T>>::into_tuple(self.query)
+        Selector::<SelectGetableTuple<T>>::into_tuple(self.query).refuse(self.refusal)
     }
 
     /// Get one Model from the SELECT query
     pub async fn one<C>(self, db: &C) -> Result<Option<E::Model>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().one(db).await
     }
@@ -450,18 +731,83 @@ where
     /// Get all Models from the SELECT query
     pub async fn all<C>(self, db: &C) -> Result<Vec<E::Model>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().all(db).await
     }
 
+    /// Get one Model from the SELECT query, with the count selected as `alias` by
+    /// [Select::with_count_of]
+    pub async fn one_with_count<C>(
+        mut self,
+        alias: &str,
+        db: &C,
+    ) -> Result<Option<(E::Model, i64)>, DbErr>
+    where
+        C: ReadConnectionTrait,
+    {
+        QuerySelect::query(&mut self).limit(1);
+        Ok(self.all_with_count(alias, db).await?.into_iter().next())
+    }
+
+    /// Get all Models from the SELECT query, each with the count selected as `alias` by
+    /// [Select::with_count_of]
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "id" => Value::from(1),
+    /// #         "name" => Value::from("Cheese Cake"),
+    /// #         "fruit_count" => Value::from(3i64),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// let cakes = cake::Entity::find()
+    ///     .with_count_of(fruit::Entity, "fruit_count")
+    ///     .all_with_count("fruit_count", &db)
+    ///     .await?;
+    /// assert_eq!(
+    ///     cakes,
+    ///     [(cake::Model { id: 1, name: "Cheese Cake".to_owned() }, 3)]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn all_with_count<C>(self, alias: &str, db: &C) -> Result<Vec<(E::Model, i64)>, DbErr>
+    where
+        C: ReadConnectionTrait,
+    {
+        let backend = db.read_backend();
+        check_select(backend, &self.query)?;
+        check_refusal(self.refusal)?;
+        let stmt = backend.build(&self.query);
+        let rows = db.read_all(stmt).await?;
+        rows.iter()
+            .map(|row| {
+                Ok((
+                    E::Model::from_query_result(row, "")?,
+                    row.try_get("", alias)?,
+                ))
+            })
+            .collect()
+    }
+
     /// Stream the results of a SELECT operation on a Model
     pub async fn stream<'a: 'b, 'b, C>(
         self,
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<E::Model, DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
     {
         self.into_model().stream(db).await
     }
@@ -472,13 +818,35 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<M, DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         M: PartialModelTrait + Send + 'b,
     {
         self.into_partial_model().stream(db).await
     }
 }
 
+impl SelectDerived {
+    /// Return a [Selector] from `Self` that wraps a [SelectModel]
+    pub fn into_model<M>(self) -> Selector<SelectModel<M>>
+    where
+        M: FromQueryResult,
+    {
+        Selector {
+            query: self.query,
+            refusal: None,
+            selector: SelectModel { model: PhantomData },
+        }
+    }
+
+    /// Return a [Selector] from `Self` that wraps a [SelectGetableTuple]
+    pub fn into_tuple<T>(self) -> Selector<SelectGetableTuple<T>>
+    where
+        T: TryGetableMany,
+    {
+        Selector::<SelectGetableTuple<T>>::into_tuple(self.query)
+    }
+}
+
 impl<E, F> SelectTwo<E, F>
 where
     E: EntityTrait,
@@ -492,6 +860,7 @@ where
     {
         Selector {
             query: self.query,
+            refusal: self.refusal,
             selector: SelectTwoModel { model: PhantomData },
         }
     }
@@ -513,6 +882,7 @@ where
     pub fn into_json(self) -> Selector<SelectTwoModel<JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            refusal: self.refusal,
             selector: SelectTwoModel { model: PhantomData },
         }
     }
@@ -520,7 +890,7 @@ where
     /// Get one Model from the Select query
     pub async fn one<C>(self, db: &C) -> Result<Option<(E::Model, Option<F::Model>)>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().one(db).await
     }
@@ -528,7 +898,7 @@ where
     /// Get all Models from the Select query
     pub async fn all<C>(self, db: &C) -> Result<Vec<(E::Model, Option<F::Model>)>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().all(db).await
     }
@@ -539,7 +909,7 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<(E::Model, Option<F::Model>), DbErr>> + 'b, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
     {
         self.into_model().stream(db).await
     }
@@ -550,7 +920,7 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<(M, Option<N>), DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         M: PartialModelTrait + Send + 'b,
         N: PartialModelTrait + Send + 'b,
     {
@@ -571,6 +941,7 @@ where
     {
         Selector {
             query: self.query,
+            refusal: self.refusal,
             selector: SelectTwoModel { model: PhantomData },
         }
     }
@@ -592,6 +963,7 @@ where
     pub fn into_json(self) -> Selector<SelectTwoModel<JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            refusal: self.refusal,
             selector: SelectTwoModel { model: PhantomData },
         }
     }
@@ -602,7 +974,7 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<(E::Model, Option<F::Model>), DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
     {
         self.into_model().stream(db).await
     }
@@ -613,7 +985,7 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<(M, Option<N>), DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         M: PartialModelTrait + Send + 'b,
         N: PartialModelTrait + Send + 'b,
     {
@@ -630,7 +1002,7 @@ where
     /// > See https://www.sea-ql.org/SeaORM/docs/basic-crud/select#lazy-loading for details.
     pub async fn all<C>(self, db: &C) -> Result<Vec<(E::Model, Vec<F::Model>)>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         let rows = self.into_model().all(db).await?;
         Ok(consolidate_query_result::<E, F>(rows))
@@ -661,6 +1033,7 @@ where
     {
         Selector {
             query: self.query,
+            refusal: self.refusal,
             selector: SelectThreeModel { model: PhantomData },
         }
     }
@@ -683,6 +1056,7 @@ where
     pub fn into_json(self) -> Selector<SelectThreeModel<JsonValue, JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            refusal: self.refusal,
             selector: SelectThreeModel { model: PhantomData },
         }
     }
@@ -693,7 +1067,7 @@ where
         db: &C,
     ) -> Result<Option<(E::Model, Option<F::Model>, Option<G::Model>)>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().one(db).await
     }
@@ -704,7 +1078,7 @@ where
         db: &C,
     ) -> Result<Vec<(E::Model, Option<F::Model>, Option<G::Model>)>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().all(db).await
     }
@@ -718,7 +1092,7 @@ where
         DbErr,
     >
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
     {
         self.into_model().stream(db).await
     }
@@ -729,7 +1103,7 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<(M, Option<N>, Option<O>), DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         M: PartialModelTrait + Send + 'b,
         N: PartialModelTrait + Send + 'b,
         O: PartialModelTrait + Send + 'b,
@@ -751,6 +1125,7 @@ where
     {
         Selector {
             query,
+            refusal: None,
             selector: SelectGetableValue {
                 columns: PhantomData,
                 model: PhantomData,
@@ -765,20 +1140,29 @@ where
     {
         Selector {
             query,
+            refusal: None,
             selector: SelectGetableTuple { model: PhantomData },
         }
     }
 
-    fn into_selector_raw<C>(self, db: &C) -> SelectorRaw<S>
+    /// Carry the refusal of the query the selector was made of
+    pub(crate) fn refuse(mut self, refusal: Option<Refusal>) -> Self {
+        self.refusal = refusal;
+        self
+    }
+
+    fn into_selector_raw<C>(self, db: &C) -> Result<SelectorRaw<S>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
-        let builder = db.get_database_backend();
+        let builder = db.read_backend();
+        check_select(builder, &self.query)?;
+        check_refusal(self.refusal)?;
         let stmt = builder.build(&self.query);
-        SelectorRaw {
+        Ok(SelectorRaw {
             stmt,
             selector: self.selector,
-        }
+        })
     }
 
     /// Get the SQL statement
@@ -786,21 +1170,67 @@ where
         builder.build(&self.query)
     }
 
+    /// Read the data as it was at the given time on CockroachDB, by adding an
+    /// `AS OF SYSTEM TIME` clause after the `FROM` clause. The time can be a timestamp,
+    /// an interval relative to now such as `"-10s"`, or a function call like
+    /// `Expr::cust("follower_read_timestamp()")`.
+    ///
+    /// The statement is built for PostgreSQL, so this has to be the last query method called.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .left_join(fruit::Entity)
+    ///         .filter(cake::Column::Name.contains("cheese"))
+    ///         .order_by_asc(cake::Column::Id)
+    ///         .into_model::<cake::Model>()
+    ///         .as_of_system_time("-10s")
+    ///         .into_statement()
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+    ///         r#"AS OF SYSTEM TIME '-10s'"#,
+    ///         r#"WHERE "cake"."name" LIKE '%cheese%'"#,
+    ///         r#"ORDER BY "cake"."id" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn as_of_system_time<T>(self, time: T) -> SelectorRaw<S>
+    where
+        T: Into<SimpleExpr>,
+    {
+        let time = Query::select().expr(time).to_string(PostgresQueryBuilder);
+        let mut stmt = DbBackend::Postgres.build(&self.query);
+        let pos = end_of_from_clause(&stmt.sql);
+        stmt.sql.insert_str(
+            pos,
+            &format!(" AS OF SYSTEM TIME {}", time.trim_start_matches("SELECT ")),
+        );
+        SelectorRaw {
+            stmt,
+            selector: self.selector,
+        }
+    }
+
     /// Get an item from the Select query
     pub async fn one<C>(mut self, db: &C) -> Result<Option<S::Item>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.query.limit(1);
-        self.into_selector_raw(db).one(db).await
+        self.into_selector_raw(db)?.one(db).await
     }
 
     /// Get all items from the Select query
     pub async fn all<C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
-        self.into_selector_raw(db).all(db).await
+        self.into_selector_raw(db)?.all(db).await
     }
 
     /// Stream the results of the Select operation
@@ -809,11 +1239,11 @@ where
         db: &'a C,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<S::Item, DbErr>> + 'b + Send>>, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         S: 'b,
         S::Item: Send,
     {
-        self.into_selector_raw(db).stream(db).await
+        self.into_selector_raw(db)?.stream(db).await
     }
 }
 
@@ -1033,9 +1463,9 @@ where
     /// ```
     pub async fn one<C>(self, db: &C) -> Result<Option<S::Item>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
-        let row = db.query_one(self.stmt).await?;
+        let row = db.read_one(self.stmt).await?;
         match row {
             Some(row) => Ok(Some(S::from_raw_query_result(row)?)),
             None => Ok(None),
@@ -1084,12 +1514,17 @@ where
     /// ```
     pub async fn all<C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
-        let rows = db.query_all(self.stmt).await?;
+        let rows = db.read_all(self.stmt).await?;
         let mut models = Vec::new();
         for row in rows.into_iter() {
-            models.push(S::from_raw_query_result(row)?);
+            let model = S::from_raw_query_result(row)?;
+            let model = match models.last_mut() {
+                Some(last) => S::merge_item(last, model),
+                None => Some(model),
+            };
+            models.extend(model);
         }
         Ok(models)
     }
@@ -1100,7 +1535,7 @@ where
         db: &'a C,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<S::Item, DbErr>> + 'b + Send>>, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         S: 'b,
         S::Item: Send,
     {
@@ -1938,4 +2373,120 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    pub async fn lock_on_sqlite() -> Result<(), sea_orm::DbErr> {
+        use sea_orm::tests_cfg::*;
+        use sea_orm::{DbBackend, DbErr, EntityTrait, IntoMockRow, MockDatabase, QuerySelect};
+        use sea_query::{LockBehavior, LockType};
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[cake_model(1).into_mock_row()]])
+            .into_connection();
+
+        assert!(matches!(
+            Cake::find().lock_exclusive().all(&db).await,
+            Err(DbErr::Query(_))
+        ));
+        assert!(matches!(
+            Cake::find()
+                .lock_with_tables_behavior(LockType::Update, [Cake], LockBehavior::SkipLocked)
+                .one(&db)
+                .await,
+            Err(DbErr::Query(_))
+        ));
+        assert_eq!(Cake::find().all(&db).await?, [cake_model(1)]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    pub async fn distinct_on_order() -> Result<(), sea_orm::DbErr> {
+        use sea_orm::tests_cfg::*;
+        use sea_orm::{
+            DbBackend, DbErr, EntityTrait, IntoMockRow, MockDatabase, QueryOrder, QuerySelect,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[fruit_model(4, Some(1)).into_mock_row()]])
+            .into_connection();
+
+        assert!(matches!(
+            Fruit::find()
+                .distinct_on([fruit::Column::CakeId])
+                .order_by_desc(fruit::Column::Id)
+                .all(&db)
+                .await,
+            Err(DbErr::Query(_))
+        ));
+        assert_eq!(
+            Fruit::find()
+                .distinct_on([fruit::Column::CakeId])
+                .order_by_asc(fruit::Column::CakeId)
+                .order_by_desc(fruit::Column::Id)
+                .all(&db)
+                .await?,
+            [fruit_model(4, Some(1))]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert!(matches!(
+            Fruit::find()
+                .distinct_on([fruit::Column::CakeId])
+                .all(&db)
+                .await,
+            Err(DbErr::Query(_))
+        ));
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    pub async fn one_with_count() -> Result<(), sea_orm::DbErr> {
+        use sea_orm::tests_cfg::*;
+        use sea_orm::{DbBackend, EntityTrait, MockDatabase, Transaction, Value};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "id" => Value::from(1),
+                "name" => Value::from("apple cake"),
+                "filling_count" => Value::from(2i64),
+            }]])
+            .append_query_results([Vec::<std::collections::BTreeMap<&str, Value>>::new()])
+            .into_connection();
+
+        assert_eq!(
+            Cake::find()
+                .with_count_of(Filling, "filling_count")
+                .one_with_count("filling_count", &db)
+                .await?,
+            Some((cake_model(1), 2))
+        );
+        assert_eq!(
+            Cake::find()
+                .with_count_of(Filling, "filling_count")
+                .one_with_count("filling_count", &db)
+                .await?,
+            None
+        );
+
+        let sql = [
+            r#"SELECT "cake"."id", "cake"."name","#,
+            r#"(SELECT COUNT(*) FROM "filling""#,
+            r#"INNER JOIN "cake_filling" ON "cake_filling"."filling_id" = "filling"."id""#,
+            r#"WHERE "cake"."id" = "cake_filling"."cake_id") AS "filling_count""#,
+            r#"FROM "cake" LIMIT $1"#,
+        ]
+        .join(" ");
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(DbBackend::Postgres, &sql, [1u64.into()]),
+                Transaction::from_sql_and_values(DbBackend::Postgres, &sql, [1u64.into()]),
+            ]
+        );
+
+        Ok(())
+    }
 }
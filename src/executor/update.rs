@@ -1,14 +1,18 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel,
-    Iterable, PrimaryKeyTrait, SelectModel, SelectorRaw, UpdateMany, UpdateOne,
+    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IdenStatic,
+    IntoActiveModel, Iterable, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter,
+    QuerySelect, QueryTrait, SelectModel, SelectorRaw, TryGetable, UpdateMany, UpdateOne,
 };
-use sea_query::{FromValueTuple, Query, UpdateStatement};
+use sea_query::{FromValueTuple, Query, UpdateStatement, Value};
+
+use super::returning::{select_affected, select_by_primary_keys};
 
 /// Defines an update operation
 #[derive(Clone, Debug)]
 pub struct Updater {
     query: UpdateStatement,
     check_record_exists: bool,
+    refusal: Option<Refusal>,
 }
 
 /// The result of an update operation on an ActiveModel
@@ -28,9 +32,18 @@ where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
         C: ConnectionTrait,
     {
-        Updater::new(self.query)
-            .exec_update_and_return_updated(self.model, db)
-            .await
+        let versioned = self.is_versioned();
+        let masked = <A::Entity as EntityTrait>::Column::iter()
+            .filter(|col| col.def().is_masked())
+            .filter_map(|col| self.model.get(col).into_value())
+            .collect();
+        let future = Updater::new(self.query)
+            .refuse(self.refusal)
+            .exec_update_and_return_updated(self.model, db);
+        match crate::redact_logged(masked, future).await {
+            Err(DbErr::RecordNotUpdated) if versioned => Err(DbErr::StaleObject),
+            result => result,
+        }
     }
 }
 
@@ -43,19 +56,19 @@ where
     where
         C: ConnectionTrait,
     {
-        Updater::new(self.query).exec(db).await
+        Updater::new(self.query).refuse(self.refusal).exec(db).await
     }
 
-    /// Execute an update operation and return the updated model (use `RETURNING` syntax if supported)
-    ///
-    /// # Panics
-    ///
-    /// Panics if the database backend does not support `UPDATE RETURNING`.
+    /// Execute an update operation and return the updated models. Uses `RETURNING` if supported,
+    /// otherwise the matching rows are selected with `FOR UPDATE` before the update and selected
+    /// again by their primary keys after; run it in a transaction to keep the three statements
+    /// atomic, as the lock is released right away outside of one.
     pub async fn exec_with_returning<C>(self, db: &'a C) -> Result<Vec<E::Model>, DbErr>
     where
         C: ConnectionTrait,
     {
         Updater::new(self.query)
+            .refuse(self.refusal)
             .exec_update_with_returning::<E, _>(db)
             .await
     }
@@ -67,9 +80,16 @@ impl Updater {
         Self {
             query,
             check_record_exists: false,
+            refusal: None,
         }
     }
 
+    /// Refuse to execute an update that was refused when it was built
+    pub(crate) fn refuse(mut self, refusal: Option<Refusal>) -> Self {
+        self.refusal = refusal;
+        self
+    }
+
     /// Check if a record exists on the ActiveModel to perform the update operation on
     pub fn check_record_exists(mut self) -> Self {
         self.check_record_exists = true;
@@ -84,6 +104,7 @@ impl Updater {
         if self.is_noop() {
             return Ok(UpdateResult::default());
         }
+        check_refusal(self.refusal)?;
         let builder = db.get_database_backend();
         let statement = builder.build(&self.query);
         let result = db.execute(statement).await?;
@@ -119,11 +140,12 @@ impl Updater {
                     Column::<A>::iter().map(|c| c.select_as(c.into_returning_expr(db_backend))),
                 );
                 self.query.returning(returning);
-                let found: Option<Model<A>> = SelectorRaw::<SelectModel<Model<A>>>::from_statement(
-                    db_backend.build(&self.query),
-                )
-                .one(db)
-                .await?;
+                check_refusal(self.refusal)?;
+                let statement = db_backend.build(&self.query);
+                let found: Option<Model<A>> =
+                    SelectorRaw::<SelectModel<Model<A>>>::from_statement(statement)
+                        .one(db)
+                        .await?;
                 // If we got `None` then we are updating a row that does not exist.
                 match found {
                     Some(model) => Ok(model),
@@ -154,14 +176,24 @@ impl Updater {
                     E::Column::iter().map(|c| c.select_as(c.into_returning_expr(db_backend))),
                 );
                 self.query.returning(returning);
-                let models: Vec<E::Model> = SelectorRaw::<SelectModel<E::Model>>::from_statement(
-                    db_backend.build(&self.query),
-                )
-                .all(db)
-                .await?;
+                check_refusal(self.refusal)?;
+                let statement = db_backend.build(&self.query);
+                let models: Vec<E::Model> =
+                    SelectorRaw::<SelectModel<E::Model>>::from_statement(statement)
+                        .all(db)
+                        .await?;
                 Ok(models)
             }
-            false => unimplemented!("Database backend doesn't support RETURNING"),
+            false => {
+                let models = select_affected::<E, _>(&self.query, db.get_database_backend())
+                    .all(db)
+                    .await?;
+                self.exec(db).await?;
+                if models.is_empty() {
+                    return Ok(models);
+                }
+                select_by_primary_keys::<E>(&models).all(db).await
+            }
         }
     }
 
@@ -170,6 +202,52 @@ impl Updater {
     }
 }
 
+/// Add to a column of the row of a model, returning the new value: with `RETURNING` if
+/// supported, otherwise by selecting the column after the update
+pub(crate) async fn increment<M, T, C>(
+    model: &M,
+    col: <M::Entity as EntityTrait>::Column,
+    by: T,
+    db: &C,
+) -> Result<T, DbErr>
+where
+    M: ModelTrait,
+    T: Into<Value> + TryGetable,
+    C: ConnectionTrait,
+{
+    let mut update = M::Entity::update_many().inc(col, by);
+    let mut select = M::Entity::find().select_only().column(col);
+    for key in <M::Entity as EntityTrait>::PrimaryKey::iter() {
+        let key = key.into_column();
+        update = update.filter(key.eq(model.get(key)));
+        select = select.filter(key.eq(model.get(key)));
+    }
+
+    if db.support_returning() {
+        check_refusal(update.refusal)?;
+        let mut query = update.into_query();
+        query.returning_col(col);
+        let statement = db.get_database_backend().build(&query);
+        let row = db
+            .query_one(statement)
+            .await?
+            .ok_or(DbErr::RecordNotUpdated)?;
+        row.try_get("", col.as_str())
+    } else {
+        let refusal = update.refusal;
+        Updater::new(update.into_query())
+            .refuse(refusal)
+            .check_record_exists()
+            .exec(db)
+            .await?;
+        select
+            .into_tuple::<T>()
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotUpdated)
+    }
+}
+
 async fn find_updated_model_by_id<A, C>(
     model: A,
     db: &C,
@@ -356,4 +434,198 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn increment_on_mysql() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+            ])
+            .append_query_results([[maplit::btreemap! {
+                "cake_id" => Into::<Value>::into(4),
+            }]])
+            .into_connection();
+
+        let fruit = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: Some(3),
+        };
+        assert_eq!(fruit.increment(fruit::Column::CakeId, 1, &db).await, Ok(4));
+        assert_eq!(
+            fruit.increment(fruit::Column::CakeId, 1, &db).await,
+            Err(DbErr::RecordNotUpdated)
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "UPDATE `fruit` SET `cake_id` = `fruit`.`cake_id` + ? WHERE `fruit`.`id` = ?",
+                    [1i32.into(), 1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `fruit`.`cake_id` FROM `fruit` WHERE `fruit`.`id` = ? LIMIT ?",
+                    [1i32.into(), 1u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "UPDATE `fruit` SET `cake_id` = `fruit`.`cake_id` + ? WHERE `fruit`.`id` = ?",
+                    [1i32.into(), 1i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "macros")]
+    mod document {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "document")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub body: String,
+            #[sea_orm(version)]
+            pub version: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[cfg(feature = "macros")]
+    #[smol_potat::test]
+    async fn update_with_version() -> Result<(), DbErr> {
+        let doc = |version| document::Model {
+            id: 1,
+            body: "Draft".to_owned(),
+            version,
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([vec![doc(4)], vec![]])
+            .into_connection();
+
+        let mut model = doc(3).into_active_model();
+        model.body = Set("Draft".to_owned());
+        // a version that is `Set` is checked like one that is unchanged
+        model.version = Set(3);
+        assert_eq!(model.clone().update(&db).await?, doc(4));
+        assert_eq!(model.update(&db).await, Err(DbErr::StaleObject));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "document" SET "body" = $1, "version" = "document"."version" + $2 WHERE "document"."id" = $3 AND "document"."version" = $4 RETURNING "id", "body", "version""#,
+                    ["Draft".into(), 1i32.into(), 1i32.into(), 3i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "document" SET "body" = $1, "version" = "document"."version" + $2 WHERE "document"."id" = $3 AND "document"."version" = $4 RETURNING "id", "body", "version""#,
+                    ["Draft".into(), 1i32.into(), 1i32.into(), 3i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+    #[cfg(feature = "macros")]
+    #[smol_potat::test]
+    async fn update_batch_with_version() -> Result<(), DbErr> {
+        let doc = |id, version| document::Model {
+            id,
+            body: "Draft".to_owned(),
+            version,
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 2,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .append_query_results([[doc(1, 4), doc(2, 8)]])
+            .into_connection();
+
+        let models = || {
+            [doc(1, 3), doc(2, 7)].map(|model| {
+                let mut model = model.into_active_model();
+                model.body = Set("Draft".to_owned());
+                model
+            })
+        };
+        assert_eq!(
+            document::ActiveModel::save_many(models(), &db).await?,
+            [doc(1, 4).into_active_model(), doc(2, 8).into_active_model()]
+        );
+        // one of the rows was updated since it was read
+        assert_eq!(
+            document::ActiveModel::save_many(models(), &db).await,
+            Err(DbErr::StaleObject)
+        );
+
+        let update = [
+            r#"UPDATE "document" SET"#,
+            r#""body" = (CASE WHEN ("document"."id" = $1) THEN $2 WHEN ("document"."id" = $3) THEN $4 ELSE "document"."body" END),"#,
+            r#""version" = "document"."version" + $5"#,
+            r#"WHERE ("document"."id" = $6 AND "document"."version" = $7) OR ("document"."id" = $8 AND "document"."version" = $9)"#,
+        ]
+        .join(" ");
+        let update_values = || {
+            [
+                1i32.into(),
+                "Draft".into(),
+                2i32.into(),
+                "Draft".into(),
+                1i32.into(),
+                1i32.into(),
+                3i32.into(),
+                2i32.into(),
+                7i32.into(),
+            ]
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(DbBackend::Postgres, &update, update_values()),
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"SELECT "document"."id", "document"."body", "document"."version" FROM "document" WHERE "document"."id" IN ($1, $2)"#,
+                        [1i32.into(), 2i32.into()]
+                    ),
+                    Statement::from_string(DbBackend::Postgres, "COMMIT"),
+                ]),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(DbBackend::Postgres, &update, update_values()),
+                    Statement::from_string(DbBackend::Postgres, "ROLLBACK"),
+                ]),
+            ]
+        );
+
+        Ok(())
+    }
 }
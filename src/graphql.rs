@@ -0,0 +1,843 @@
+use crate::{
+    unpack_table_ref, ColumnTrait, ColumnType, Condition, DatabaseConnection, DbBackend,
+    EntityTrait, Iden, Iterable, ModelTrait, Order, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, RelationType, Value,
+};
+use async_graphql::{
+    dataloader::{DataLoader, Loader},
+    dynamic::{
+        Enum, Field, FieldFuture, FieldValue, InputObject, InputValue, Object, ResolverContext,
+        Schema, SchemaBuilder, TypeRef,
+    },
+    Error, Value as GraphQLValue,
+};
+use futures_util::future::BoxFuture;
+use std::{any::Any, collections::HashMap, fmt, str::FromStr, sync::Arc};
+
+pub use async_graphql;
+
+/// A model resolved by the schema, downcast to the Model of its entity
+type Row = Arc<dyn Any + Send + Sync>;
+
+type SelectFn = Arc<
+    dyn Fn(Arc<DatabaseConnection>, Select) -> BoxFuture<'static, Result<Vec<Keyed>, Error>>
+        + Send
+        + Sync,
+>;
+
+type GetFn = Arc<dyn Fn(&Row, &str) -> Result<Value, Error> + Send + Sync>;
+
+/// A model along with the values of the columns it was looked up by
+type Keyed = (Vec<Value>, Row);
+
+/// An entity exposed as a GraphQL object type, implemented by `DeriveEntityModel` for a Model
+/// marked `#[sea_orm(graphql)]`.
+///
+/// The object type is named after the table in upper camel case, with a field per column named
+/// after the field of the Model in lower camel case. `#[sea_orm(graphql(name = "..."))]` on a
+/// field renames it, and `#[sea_orm(graphql(skip))]` leaves it out of the object type, and of
+/// the filter and ordering inputs.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, graphql::GraphQLEntity};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "user_account", graphql)]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(graphql(name = "login"))]
+///     pub user_name: String,
+///     #[sea_orm(graphql(skip))]
+///     pub password_hash: String,
+/// }
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// # impl ActiveModelBehavior for ActiveModel {}
+///
+/// assert_eq!(Entity::graphql_name(), "UserAccount");
+/// assert_eq!(Entity::graphql_field(Column::UserName), Some("login"));
+/// assert_eq!(Entity::graphql_field(Column::PasswordHash), None);
+/// ```
+pub trait GraphQLEntity: EntityTrait {
+    /// The name of the object type
+    fn graphql_name() -> &'static str;
+
+    /// The name of the field of a column, `None` for a column left out
+    fn graphql_field(column: Self::Column) -> Option<&'static str>;
+}
+
+/// A set of entities exposed as a dynamic GraphQL schema. Every entity becomes an object
+/// type with a field per column and per relation, and a query field taking a filter, an
+/// ordering, a limit and an offset, see [GraphQLEntity].
+///
+/// Relations are resolved in batches by a [RelationLoader] if one is added to the request
+/// data, and with a query per model otherwise.
+///
+/// ```
+/// # use sea_orm::{graphql::*, tests_cfg::*, DbConn};
+/// # async fn function(db: DbConn) -> Result<(), async_graphql::dynamic::SchemaError> {
+/// let entities = GraphQLEntities::new(db)
+///     .register::<cake::Entity>()
+///     .register::<fruit::Entity>();
+/// let schema = entities.schema_builder().finish()?;
+///
+/// let request = async_graphql::Request::new(
+///     r#"{
+///         cake(filter: { name: { contains: "Cheese" } }, orderBy: { id: ASC }, limit: 10) {
+///             id
+///             name
+///             fruit { name }
+///         }
+///     }"#,
+/// )
+/// .data(entities.loader(|fut| tokio::spawn(fut)));
+/// let response = schema.execute(request).await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct GraphQLEntities {
+    db: Arc<DatabaseConnection>,
+    entities: Vec<EntityDef>,
+}
+
+/// Loads the models related to other models in batches, to be added to the data of
+/// every request. Created by [GraphQLEntities::loader].
+#[derive(Debug)]
+pub struct RelationLoader {
+    registry: Registry,
+}
+
+/// The key of the models related to a model, loaded by a [RelationLoader]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelatedKey {
+    table: String,
+    columns: Vec<String>,
+    values: Vec<Value>,
+}
+
+#[derive(Debug, Clone)]
+struct Registry {
+    db: Arc<DatabaseConnection>,
+    entities: Arc<Vec<EntityDef>>,
+}
+
+#[derive(Clone)]
+struct EntityDef {
+    table: String,
+    name: String,
+    columns: Vec<ColumnInfo>,
+    relations: Vec<RelationInfo>,
+    select: SelectFn,
+    get: GetFn,
+}
+
+#[derive(Debug, Clone)]
+struct ColumnInfo {
+    column: String,
+    field: String,
+    scalar: Scalar,
+    nullable: bool,
+}
+
+#[derive(Debug, Clone)]
+struct RelationInfo {
+    field: String,
+    to_tbl: String,
+    from_col: Vec<String>,
+    to_col: Vec<String>,
+    many: bool,
+}
+
+#[derive(Debug, Default)]
+struct Select {
+    filter: Option<GraphQLValue>,
+    order_by: Option<GraphQLValue>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+    keys: Option<(Vec<String>, Vec<Vec<Value>>)>,
+}
+
+/// The GraphQL scalar a column is exposed as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scalar {
+    Int,
+    Float,
+    Boolean,
+    String,
+}
+
+const SCALARS: [Scalar; 4] = [Scalar::Int, Scalar::Float, Scalar::Boolean, Scalar::String];
+
+const ORDER_DIRECTION: &str = "OrderDirection";
+
+impl GraphQLEntities {
+    /// Create an empty set of entities queried through this connection
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self {
+            db: Arc::new(db),
+            entities: Vec::new(),
+        }
+    }
+
+    /// Expose an entity. Relations are exposed if the related entity is registered too.
+    pub fn register<E>(mut self) -> Self
+    where
+        E: GraphQLEntity,
+        E::Model: Sync,
+    {
+        self.entities.push(EntityDef::new::<E>());
+        self
+    }
+
+    /// A schema builder with the types of the entities and a `Query` root type
+    /// registered, to add any other types to
+    pub fn schema_builder(&self) -> SchemaBuilder {
+        let registry = self.registry();
+        let mut builder = Schema::build("Query", None, None)
+            .register(Enum::new(ORDER_DIRECTION).item("ASC").item("DESC"));
+        for scalar in SCALARS {
+            builder = builder.register(scalar.filter_input());
+        }
+        let mut query = Object::new("Query");
+        for entity in registry.entities.iter() {
+            builder = builder
+                .register(entity.object(&registry))
+                .register(entity.filter_input())
+                .register(entity.order_by_input());
+            query = query.field(entity.query_field(&registry));
+        }
+        builder.register(query)
+    }
+
+    /// A loader for the data of a request, resolving relations in batches. The loader
+    /// spawns its loading tasks with the spawner, e.g. `tokio::spawn`.
+    pub fn loader<S, R>(&self, spawner: S) -> DataLoader<RelationLoader>
+    where
+        S: Fn(BoxFuture<'static, ()>) -> R + Send + Sync + 'static,
+    {
+        DataLoader::new(
+            RelationLoader {
+                registry: self.registry(),
+            },
+            spawner,
+        )
+    }
+
+    fn registry(&self) -> Registry {
+        Registry {
+            db: self.db.clone(),
+            entities: Arc::new(self.entities.clone()),
+        }
+    }
+}
+
+impl Loader<RelatedKey> for RelationLoader {
+    type Value = Vec<Row>;
+    type Error = Error;
+
+    async fn load(&self, keys: &[RelatedKey]) -> Result<HashMap<RelatedKey, Vec<Row>>, Error> {
+        self.registry.load(keys).await
+    }
+}
+
+impl Registry {
+    fn entity(&self, table: &str) -> Option<&EntityDef> {
+        self.entities.iter().find(|entity| entity.table == table)
+    }
+
+    /// Load the models of the keys with a query per related entity
+    async fn load(&self, keys: &[RelatedKey]) -> Result<HashMap<RelatedKey, Vec<Row>>, Error> {
+        let mut groups: HashMap<(&str, &[String]), Vec<Vec<Value>>> = HashMap::new();
+        for key in keys {
+            groups
+                .entry((&key.table, &key.columns))
+                .or_default()
+                .push(key.values.clone());
+        }
+
+        let mut loaded = HashMap::new();
+        for ((table, columns), values) in groups {
+            let entity = self
+                .entity(table)
+                .ok_or_else(|| Error::new(format!("Entity of table {table} is not registered")))?;
+            let select = Select {
+                keys: Some((columns.to_vec(), values.clone())),
+                ..Default::default()
+            };
+            let mut rows: HashMap<Vec<Value>, Vec<Row>> = HashMap::new();
+            for (key, row) in (entity.select)(self.db.clone(), select).await? {
+                rows.entry(key).or_default().push(row);
+            }
+            for values in values {
+                let rows = rows.get(&values).cloned().unwrap_or_default();
+                let key = RelatedKey {
+                    table: table.to_owned(),
+                    columns: columns.to_vec(),
+                    values,
+                };
+                loaded.insert(key, rows);
+            }
+        }
+        Ok(loaded)
+    }
+}
+
+impl EntityDef {
+    fn new<E>() -> Self
+    where
+        E: GraphQLEntity,
+        E::Model: Sync,
+    {
+        let table = E::default().table_name().to_owned();
+        let columns = E::Column::iter()
+            .filter_map(|column| {
+                let def = column.def();
+                Some(ColumnInfo {
+                    column: column.to_string(),
+                    field: E::graphql_field(column)?.to_owned(),
+                    scalar: Scalar::of(def.get_column_type()),
+                    nullable: def.is_null(),
+                })
+            })
+            .collect();
+        let relations = E::Relation::iter()
+            .map(|relation| {
+                let def = relation.def();
+                RelationInfo {
+                    field: lower_first(&format!("{relation:?}")),
+                    to_tbl: unpack_table_ref(&def.to_tbl).to_string(),
+                    from_col: def.from_col.into_iter().map(|c| c.to_string()).collect(),
+                    to_col: def.to_col.into_iter().map(|c| c.to_string()).collect(),
+                    many: def.rel_type == RelationType::HasMany,
+                }
+            })
+            .collect();
+        Self {
+            name: E::graphql_name().to_owned(),
+            table,
+            columns,
+            relations,
+            select: Arc::new(|db, select| Box::pin(select_models::<E>(db, select))),
+            get: Arc::new(|row, column| {
+                let model = row
+                    .downcast_ref::<E::Model>()
+                    .ok_or_else(|| Error::new("Unexpected model type"))?;
+                Ok(model.get(column_from_str::<E>(column)?))
+            }),
+        }
+    }
+
+    fn object(&self, registry: &Registry) -> Object {
+        let mut object = Object::new(&self.name);
+        for column in self.columns.iter() {
+            let ty = match column.nullable {
+                true => TypeRef::named(column.scalar.type_name()),
+                false => TypeRef::named_nn(column.scalar.type_name()),
+            };
+            let get = self.get.clone();
+            let name = column.column.clone();
+            object = object.field(Field::new(&column.field, ty, move |ctx| {
+                let get = get.clone();
+                let name = name.clone();
+                FieldFuture::new(async move {
+                    let row = ctx.parent_value.try_downcast_ref::<Row>()?;
+                    Ok(Some(FieldValue::value(to_graphql_value(get(row, &name)?))))
+                })
+            }));
+        }
+        for relation in self.relations.iter() {
+            let Some(related) = registry.entity(&relation.to_tbl) else {
+                continue;
+            };
+            let ty = match relation.many {
+                true => TypeRef::named_nn_list_nn(&related.name),
+                false => TypeRef::named(&related.name),
+            };
+            let get = self.get.clone();
+            let relation = relation.clone();
+            let registry = registry.clone();
+            object = object.field(Field::new(relation.field.clone(), ty, move |ctx| {
+                let get = get.clone();
+                let relation = relation.clone();
+                let registry = registry.clone();
+                FieldFuture::new(async move {
+                    let row = ctx.parent_value.try_downcast_ref::<Row>()?;
+                    let rows = load_related(&ctx, &registry, &relation, &get, row).await?;
+                    match relation.many {
+                        true => Ok(Some(FieldValue::list(
+                            rows.into_iter().map(FieldValue::owned_any),
+                        ))),
+                        false => Ok(rows.into_iter().next().map(FieldValue::owned_any)),
+                    }
+                })
+            }));
+        }
+        object
+    }
+
+    fn query_field(&self, registry: &Registry) -> Field {
+        let select = self.select.clone();
+        let db = registry.db.clone();
+        Field::new(
+            lower_first(&self.name),
+            TypeRef::named_nn_list_nn(&self.name),
+            move |ctx| {
+                let select = select.clone();
+                let db = db.clone();
+                FieldFuture::new(async move {
+                    let args = Select {
+                        filter: ctx.args.get("filter").map(|v| v.as_value().clone()),
+                        order_by: ctx.args.get("orderBy").map(|v| v.as_value().clone()),
+                        limit: ctx.args.get("limit").map(|v| v.u64()).transpose()?,
+                        offset: ctx.args.get("offset").map(|v| v.u64()).transpose()?,
+                        keys: None,
+                    };
+                    let rows = select(db, args).await?;
+                    Ok(Some(FieldValue::list(
+                        rows.into_iter().map(|(_, row)| FieldValue::owned_any(row)),
+                    )))
+                })
+            },
+        )
+        .argument(InputValue::new(
+            "filter",
+            TypeRef::named(format!("{}Filter", self.name)),
+        ))
+        .argument(InputValue::new(
+            "orderBy",
+            TypeRef::named(format!("{}OrderBy", self.name)),
+        ))
+        .argument(InputValue::new("limit", TypeRef::named(TypeRef::INT)))
+        .argument(InputValue::new("offset", TypeRef::named(TypeRef::INT)))
+    }
+
+    fn filter_input(&self) -> InputObject {
+        let name = format!("{}Filter", self.name);
+        let mut input = InputObject::new(&name);
+        for column in self.columns.iter() {
+            input = input.field(InputValue::new(
+                &column.field,
+                TypeRef::named(format!("{}Filter", column.scalar.type_name())),
+            ));
+        }
+        input
+            .field(InputValue::new("and", TypeRef::named_nn_list(&name)))
+            .field(InputValue::new("or", TypeRef::named_nn_list(&name)))
+    }
+
+    fn order_by_input(&self) -> InputObject {
+        let mut input = InputObject::new(format!("{}OrderBy", self.name));
+        for column in self.columns.iter() {
+            input = input.field(InputValue::new(
+                &column.field,
+                TypeRef::named(ORDER_DIRECTION),
+            ));
+        }
+        input
+    }
+}
+
+impl fmt::Debug for EntityDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EntityDef")
+            .field("table", &self.table)
+            .field("name", &self.name)
+            .field("columns", &self.columns)
+            .field("relations", &self.relations)
+            .finish()
+    }
+}
+
+impl Scalar {
+    fn of(column_type: &ColumnType) -> Self {
+        match column_type {
+            ColumnType::TinyInteger
+            | ColumnType::SmallInteger
+            | ColumnType::Integer
+            | ColumnType::BigInteger
+            | ColumnType::TinyUnsigned
+            | ColumnType::SmallUnsigned
+            | ColumnType::Unsigned
+            | ColumnType::BigUnsigned => Self::Int,
+            ColumnType::Float | ColumnType::Double => Self::Float,
+            ColumnType::Boolean => Self::Boolean,
+            _ => Self::String,
+        }
+    }
+
+    fn type_name(self) -> &'static str {
+        match self {
+            Self::Int => TypeRef::INT,
+            Self::Float => TypeRef::FLOAT,
+            Self::Boolean => TypeRef::BOOLEAN,
+            Self::String => TypeRef::STRING,
+        }
+    }
+
+    fn filter_input(self) -> InputObject {
+        let ty = self.type_name();
+        let mut input = InputObject::new(format!("{ty}Filter"));
+        for op in ["eq", "ne", "gt", "gte", "lt", "lte"] {
+            input = input.field(InputValue::new(op, TypeRef::named(ty)));
+        }
+        for op in ["isIn", "isNotIn"] {
+            input = input.field(InputValue::new(op, TypeRef::named_nn_list(ty)));
+        }
+        input = input.field(InputValue::new("isNull", TypeRef::named(TypeRef::BOOLEAN)));
+        if self == Self::String {
+            for op in ["contains", "startsWith", "endsWith", "like"] {
+                input = input.field(InputValue::new(op, TypeRef::named(ty)));
+            }
+        }
+        input
+    }
+
+    /// Convert an input value to a value of the column
+    fn value(self, value: &GraphQLValue) -> Result<Value, Error> {
+        match (self, value) {
+            (Self::Int, GraphQLValue::Number(n)) if n.is_i64() => Ok(n.as_i64().into()),
+            (Self::Int, GraphQLValue::Number(n)) if n.is_u64() => Ok(n.as_u64().into()),
+            (Self::Float, GraphQLValue::Number(n)) => Ok(n.as_f64().into()),
+            (Self::Boolean, GraphQLValue::Boolean(b)) => Ok((*b).into()),
+            (Self::String, GraphQLValue::String(s)) => Ok(s.as_str().into()),
+            _ => Err(Error::new(format!(
+                "Expected {} but got {value}",
+                self.type_name()
+            ))),
+        }
+    }
+}
+
+async fn select_models<E>(db: Arc<DatabaseConnection>, select: Select) -> Result<Vec<Keyed>, Error>
+where
+    E: GraphQLEntity,
+    E::Model: Sync,
+{
+    let mut query = E::find();
+    if let Some(filter) = &select.filter {
+        query = query.filter(filter_condition::<E>(filter)?);
+    }
+    if let Some(GraphQLValue::Object(order_by)) = &select.order_by {
+        for (field, direction) in order_by {
+            let order = match direction {
+                GraphQLValue::Enum(name) if name.as_str() == "DESC" => Order::Desc,
+                _ => Order::Asc,
+            };
+            query = query.order_by(column_from_field::<E>(field)?, order);
+        }
+    }
+    let mut key_columns = Vec::new();
+    if let Some((columns, keys)) = &select.keys {
+        for column in columns {
+            key_columns.push(column_from_str::<E>(column)?);
+        }
+        let mut condition = Condition::any();
+        for key in keys {
+            let mut all = Condition::all();
+            for (column, value) in key_columns.iter().zip(key) {
+                all = all.add(column.eq(value.clone()));
+            }
+            condition = condition.add(all);
+        }
+        query = query.filter(condition);
+    }
+
+    let models = query
+        .limit(select.limit)
+        .offset(select.offset)
+        .all(db.as_ref())
+        .await?;
+    Ok(models
+        .into_iter()
+        .map(|model| {
+            let key = key_columns
+                .iter()
+                .map(|column| model.get(*column))
+                .collect();
+            (key, Arc::new(model) as Row)
+        })
+        .collect())
+}
+
+/// The models related to a model, loaded in a batch if there is a loader in the request
+async fn load_related(
+    ctx: &ResolverContext<'_>,
+    registry: &Registry,
+    relation: &RelationInfo,
+    get: &GetFn,
+    row: &Row,
+) -> Result<Vec<Row>, Error> {
+    let values = relation
+        .from_col
+        .iter()
+        .map(|column| get(row, column))
+        .collect::<Result<Vec<_>, _>>()?;
+    // a null foreign key has nothing related
+    if values.iter().any(|value| *value == value.as_null()) {
+        return Ok(Vec::new());
+    }
+    let key = RelatedKey {
+        table: relation.to_tbl.clone(),
+        columns: relation.to_col.clone(),
+        values,
+    };
+    let rows = match ctx.data_opt::<DataLoader<RelationLoader>>() {
+        Some(loader) => loader.load_one(key).await?,
+        None => registry.load(std::slice::from_ref(&key)).await?.remove(&key),
+    };
+    Ok(rows.unwrap_or_default())
+}
+
+fn filter_condition<E>(filter: &GraphQLValue) -> Result<Condition, Error>
+where
+    E: GraphQLEntity,
+{
+    let mut condition = Condition::all();
+    let GraphQLValue::Object(filter) = filter else {
+        return Ok(condition);
+    };
+    for (field, value) in filter {
+        match field.as_str() {
+            "and" | "or" => {
+                let mut inner = match field.as_str() {
+                    "and" => Condition::all(),
+                    _ => Condition::any(),
+                };
+                if let GraphQLValue::List(filters) = value {
+                    for filter in filters {
+                        inner = inner.add(filter_condition::<E>(filter)?);
+                    }
+                }
+                condition = condition.add(inner);
+            }
+            field => {
+                let column = column_from_field::<E>(field)?;
+                let scalar = Scalar::of(column.def().get_column_type());
+                let GraphQLValue::Object(ops) = value else {
+                    continue;
+                };
+                for (op, operand) in ops {
+                    if *operand == GraphQLValue::Null {
+                        continue;
+                    }
+                    let values = || -> Result<Vec<Value>, Error> {
+                        match operand {
+                            GraphQLValue::List(values) => {
+                                values.iter().map(|v| scalar.value(v)).collect()
+                            }
+                            _ => Err(Error::new(format!("Expected a list but got {operand}"))),
+                        }
+                    };
+                    let string = || match operand {
+                        GraphQLValue::String(s) => Ok(s.as_str()),
+                        _ => Err(Error::new(format!("Expected a string but got {operand}"))),
+                    };
+                    condition = condition.add(match op.as_str() {
+                        "eq" => column.eq(scalar.value(operand)?),
+                        "ne" => column.ne(scalar.value(operand)?),
+                        "gt" => column.gt(scalar.value(operand)?),
+                        "gte" => column.gte(scalar.value(operand)?),
+                        "lt" => column.lt(scalar.value(operand)?),
+                        "lte" => column.lte(scalar.value(operand)?),
+                        "isIn" => column.is_in(values()?),
+                        "isNotIn" => column.is_not_in(values()?),
+                        "isNull" => match operand {
+                            GraphQLValue::Boolean(true) => column.is_null(),
+                            _ => column.is_not_null(),
+                        },
+                        "contains" => column.contains(string()?),
+                        "startsWith" => column.starts_with(string()?),
+                        "endsWith" => column.ends_with(string()?),
+                        "like" => column.like(string()?),
+                        op => return Err(Error::new(format!("Unknown filter {op}"))),
+                    });
+                }
+            }
+        }
+    }
+    Ok(condition)
+}
+
+fn column_from_field<E>(field: &str) -> Result<E::Column, Error>
+where
+    E: GraphQLEntity,
+{
+    E::Column::iter()
+        .find(|column| E::graphql_field(*column) == Some(field))
+        .ok_or_else(|| Error::new(format!("Unknown field {field}")))
+}
+
+fn column_from_str<E>(s: &str) -> Result<E::Column, Error>
+where
+    E: EntityTrait,
+{
+    E::Column::from_str(s).map_err(|_| Error::new(format!("Unknown column {s}")))
+}
+
+/// Numbers, booleans and strings are converted as is, other values are rendered as
+/// their SQL literal
+fn to_graphql_value(value: Value) -> GraphQLValue {
+    if value == value.as_null() {
+        return GraphQLValue::Null;
+    }
+    match value {
+        Value::Bool(Some(v)) => v.into(),
+        Value::TinyInt(Some(v)) => v.into(),
+        Value::SmallInt(Some(v)) => v.into(),
+        Value::Int(Some(v)) => v.into(),
+        Value::BigInt(Some(v)) => v.into(),
+        Value::TinyUnsigned(Some(v)) => v.into(),
+        Value::SmallUnsigned(Some(v)) => v.into(),
+        Value::Unsigned(Some(v)) => v.into(),
+        Value::BigUnsigned(Some(v)) => v.into(),
+        Value::Float(Some(v)) => v.into(),
+        Value::Double(Some(v)) => v.into(),
+        Value::String(Some(v)) => (*v).into(),
+        Value::Char(Some(v)) => v.to_string().into(),
+        value => {
+            let literal = DbBackend::Postgres
+                .get_query_builder()
+                .value_to_string(&value);
+            match literal
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+            {
+                Some(s) => s.to_owned().into(),
+                None => literal.into(),
+            }
+        }
+    }
+}
+
+/// `CakeFilling` to `cakeFilling`
+fn lower_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use super::*;
+    use crate::{tests_cfg::*, MockDatabase};
+    use async_graphql::Request;
+    use pretty_assertions::assert_eq;
+
+    const QUERY: &str = r#"{
+        cake(
+            filter: { name: { contains: "C" }, or: [{ id: { eq: 1 } }, { id: { gt: 1 } }] }
+            orderBy: { id: DESC }
+            limit: 2
+        ) {
+            id
+            name
+            fruit { name cakeId }
+        }
+    }"#;
+
+    /// Two cakes, and the fruits of both for every fruit query
+    fn entities(fruit_queries: usize) -> GraphQLEntities {
+        let cake = |id: i32, name: &str| cake::Model {
+            id,
+            name: name.to_owned(),
+        };
+        let fruit = |id: i32, name: &str, cake_id: i32| fruit::Model {
+            id,
+            name: name.to_owned(),
+            cake_id: Some(cake_id),
+        };
+        let fruits = vec![
+            fruit(1, "Apple", 1),
+            fruit(2, "Banana", 2),
+            fruit(3, "Cherry", 1),
+        ];
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake(2, "Chocolate"), cake(1, "Cheese")]])
+            .append_query_results(vec![fruits; fruit_queries])
+            .into_connection();
+        GraphQLEntities::new(db)
+            .register::<cake::Entity>()
+            .register::<fruit::Entity>()
+    }
+
+    fn expected() -> serde_json::Value {
+        serde_json::json!({
+            "cake": [
+                {
+                    "id": 2,
+                    "name": "Chocolate",
+                    "fruit": [{ "name": "Banana", "cakeId": 2 }]
+                },
+                {
+                    "id": 1,
+                    "name": "Cheese",
+                    "fruit": [
+                        { "name": "Apple", "cakeId": 1 },
+                        { "name": "Cherry", "cakeId": 1 }
+                    ]
+                }
+            ]
+        })
+    }
+
+    fn sql(entities: GraphQLEntities) -> Vec<String> {
+        let db = Arc::try_unwrap(entities.db).expect("Not shared");
+        db.into_transaction_log()
+            .iter()
+            .flat_map(|txn| txn.statements())
+            .map(|stmt| stmt.sql.clone())
+            .collect()
+    }
+
+    #[smol_potat::test]
+    async fn query_with_loader() -> Result<(), Error> {
+        let entities = entities(1);
+        let schema = entities.schema_builder().finish()?;
+
+        let request = Request::new(QUERY).data(entities.loader(|fut| smol::spawn(fut).detach()));
+        let response = schema.execute(request).await;
+        assert_eq!(response.errors, []);
+        assert_eq!(response.data.into_json()?, expected());
+
+        drop(schema);
+        assert_eq!(
+            sql(entities),
+            [
+                [
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    r#"WHERE "cake"."name" LIKE $1 AND ("cake"."id" = $2 OR "cake"."id" > $3)"#,
+                    r#"ORDER BY "cake"."id" DESC LIMIT $4"#,
+                ]
+                .join(" "),
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"WHERE "fruit"."cake_id" = $1 OR "fruit"."cake_id" = $2"#,
+                ]
+                .join(" "),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn query_without_loader() -> Result<(), Error> {
+        let entities = entities(2);
+        let schema = entities.schema_builder().finish()?;
+
+        let response = schema.execute(QUERY).await;
+        assert_eq!(response.errors, []);
+        assert_eq!(response.data.into_json()?, expected());
+
+        // a query per cake
+        drop(schema);
+        assert_eq!(sql(entities).len(), 3);
+
+        Ok(())
+    }
+}
@@ -364,6 +364,10 @@
     html_logo_url = "https://raw.githubusercontent.com/SeaQL/sea-query/master/docs/SeaQL icon dark.png"
 )]
 
+/// Binary serialization of models and values with bincode
+#[cfg(feature = "bincode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+pub mod bincode;
 mod database;
 mod docs;
 mod driver;
@@ -373,16 +377,28 @@ pub mod entity;
 pub mod error;
 /// This module performs execution of queries on a Model or ActiveModel
 mod executor;
+/// Exposes entities as a GraphQL schema
+#[cfg(feature = "graphql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "graphql")))]
+pub mod graphql;
 /// Holds types and methods to perform metric collection
 pub mod metric;
 /// Holds types and methods to perform queries
 pub mod query;
 /// Holds types that defines the schemas of an Entity
 pub mod schema;
+/// Helpers to set up databases for testing
+#[cfg(feature = "sqlx-sqlite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlx-sqlite")))]
+pub mod testing;
 #[doc(hidden)]
 #[cfg(all(feature = "macros", feature = "tests-cfg"))]
 pub mod tests_cfg;
 mod util;
+/// Extractors for web frameworks
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "axum", feature = "actix-web"))))]
+pub mod web;
 
 pub use database::*;
 #[allow(unused_imports)]
@@ -396,9 +412,11 @@ pub use schema::*;
 #[cfg(feature = "macros")]
 pub use sea_orm_macros::{
     DeriveActiveEnum, DeriveActiveModel, DeriveActiveModelBehavior, DeriveColumn,
-    DeriveCustomColumn, DeriveDisplay, DeriveEntity, DeriveEntityModel, DeriveIden,
-    DeriveIntoActiveModel, DeriveMigrationName, DeriveModel, DerivePartialModel, DerivePrimaryKey,
-    DeriveRelatedEntity, DeriveRelation, DeriveValueType, FromJsonQueryResult, FromQueryResult,
+    DeriveCustomColumn, DeriveDisplay, DeriveEntity, DeriveEntityModel, DeriveFactory,
+    DeriveHierarchy, DeriveIden, DeriveIntoActiveModel, DeriveLinked, DeriveLocalize,
+    DeriveMigrationName, DeriveModel, DeriveMorphTo, DerivePartialModel, DerivePrimaryKey,
+    DeriveRelatedEntity, DeriveRelation, DeriveStateMachine, DeriveTree, DeriveValueType,
+    FromJsonQueryResult, FromQueryResult,
 };
 
 pub use sea_query;
@@ -409,3 +427,6 @@ pub use strum;
 
 #[cfg(feature = "sqlx-dep")]
 pub use sqlx;
+
+#[cfg(feature = "utoipa")]
+pub use utoipa;
@@ -0,0 +1,84 @@
+use crate::{ColumnTrait, DbBackend};
+use sea_query::{extension::postgres::PgExpr, BinOper, Expr, Func, SimpleExpr};
+
+/// Case-insensitive matching on a text column: `ILIKE` on Postgres, and `LIKE` between the
+/// lowercased column and pattern on MySQL and SQLite.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake, CaseInsensitiveColumn, DbBackend};
+///
+/// let find = |db: DbBackend| {
+///     cake::Entity::find()
+///         .filter(cake::Column::Name.ilike(db, "cheese%"))
+///         .build(db)
+///         .to_string()
+/// };
+///
+/// assert_eq!(
+///     find(DbBackend::Postgres),
+///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" ILIKE 'cheese%'"#
+/// );
+/// assert_eq!(
+///     find(DbBackend::MySql),
+///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE LOWER(`cake`.`name`) LIKE LOWER('cheese%')"
+/// );
+/// ```
+pub trait CaseInsensitiveColumn {
+    /// Whether the text matches a `LIKE` pattern, ignoring case
+    fn ilike<T>(self, backend: DbBackend, pattern: T) -> SimpleExpr
+    where
+        T: Into<String>;
+
+    /// Whether the text equals a string, ignoring case
+    fn eq_ci<T>(self, value: T) -> SimpleExpr
+    where
+        T: Into<String>;
+}
+
+impl<C> CaseInsensitiveColumn for C
+where
+    C: ColumnTrait,
+{
+    fn ilike<T>(self, backend: DbBackend, pattern: T) -> SimpleExpr
+    where
+        T: Into<String>,
+    {
+        let pattern = pattern.into();
+        match backend {
+            DbBackend::Postgres => self.into_expr().ilike(pattern),
+            DbBackend::MySql | DbBackend::Sqlite => Expr::expr(Func::lower(self.into_expr()))
+                .binary(BinOper::Like, Func::lower(Expr::val(pattern))),
+        }
+    }
+
+    fn eq_ci<T>(self, value: T) -> SimpleExpr
+    where
+        T: Into<String>,
+    {
+        Expr::expr(Func::lower(self.into_expr())).eq(Func::lower(Expr::val(value.into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests_cfg::cake, EntityTrait, QueryFilter, QueryTrait};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn sqlite() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter(cake::Column::Name.ilike(DbBackend::Sqlite, "%cheese%"))
+                .filter(cake::Column::Name.eq_ci("Cheese Cake"))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"WHERE LOWER("cake"."name") LIKE LOWER('%cheese%')"#,
+                r#"AND LOWER("cake"."name") = LOWER('Cheese Cake')"#,
+            ]
+            .join(" ")
+        );
+    }
+}
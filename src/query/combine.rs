@@ -1,6 +1,6 @@
 use crate::{
-    ColumnTrait, EntityTrait, IdenStatic, Iterable, QueryTrait, Select, SelectThree, SelectTwo,
-    SelectTwoMany,
+    ColumnTrait, EntityTrait, IdenStatic, Iterable, QueryTrait, Refusal, Select, SelectThree,
+    SelectTwo, SelectTwoMany,
 };
 use core::marker::PhantomData;
 use sea_query::{Alias, ColumnRef, Iden, Order, SeaRc, SelectExpr, SelectStatement, SimpleExpr};
@@ -79,7 +79,7 @@ where
         F: EntityTrait,
     {
         self = self.apply_alias(SelectA.as_str());
-        SelectTwo::new(self.into_query())
+        SelectTwo::new(self.query, self.refusal)
     }
 
     /// Makes a SELECT operation in conjunction to another relation
@@ -88,7 +88,7 @@ where
         F: EntityTrait,
     {
         self = self.apply_alias(SelectA.as_str());
-        SelectTwoMany::new(self.into_query())
+        SelectTwoMany::new(self.query, self.refusal)
     }
 }
 
@@ -102,17 +102,18 @@ where
     where
         G: EntityTrait,
     {
-        SelectThree::new(self.into_query())
+        SelectThree::new(self.query, self.refusal)
     }
 
-    pub(crate) fn new(query: SelectStatement) -> Self {
-        Self::new_without_prepare(query).prepare_select()
+    pub(crate) fn new(query: SelectStatement, refusal: Option<Refusal>) -> Self {
+        Self::new_without_prepare(query, refusal).prepare_select()
     }
 
-    pub(crate) fn new_without_prepare(query: SelectStatement) -> Self {
+    pub(crate) fn new_without_prepare(query: SelectStatement, refusal: Option<Refusal>) -> Self {
         Self {
             query,
             entity: PhantomData,
+            refusal,
         }
     }
 
@@ -127,16 +128,17 @@ where
     E: EntityTrait,
     F: EntityTrait,
 {
-    pub(crate) fn new(query: SelectStatement) -> Self {
-        Self::new_without_prepare(query)
+    pub(crate) fn new(query: SelectStatement, refusal: Option<Refusal>) -> Self {
+        Self::new_without_prepare(query, refusal)
             .prepare_select()
             .prepare_order_by()
     }
 
-    pub(crate) fn new_without_prepare(query: SelectStatement) -> Self {
+    pub(crate) fn new_without_prepare(query: SelectStatement, refusal: Option<Refusal>) -> Self {
         Self {
             query,
             entity: PhantomData,
+            refusal,
         }
     }
 
@@ -159,14 +161,15 @@ where
     F: EntityTrait,
     G: EntityTrait,
 {
-    pub(crate) fn new(query: SelectStatement) -> Self {
-        Self::new_without_prepare(query).prepare_select()
+    pub(crate) fn new(query: SelectStatement, refusal: Option<Refusal>) -> Self {
+        Self::new_without_prepare(query, refusal).prepare_select()
     }
 
-    pub(crate) fn new_without_prepare(query: SelectStatement) -> Self {
+    pub(crate) fn new_without_prepare(query: SelectStatement, refusal: Option<Refusal>) -> Self {
         Self {
             query,
             entity: PhantomData,
+            refusal,
         }
     }
 
@@ -1,6 +1,6 @@
 use crate::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyToColumn, QueryFilter, QueryTrait,
+    PrimaryKeyToColumn, QueryFilter, QueryTrait, Refusal,
 };
 use core::marker::PhantomData;
 use sea_query::DeleteStatement;
@@ -17,6 +17,7 @@ where
 {
     pub(crate) query: DeleteStatement,
     pub(crate) model: A,
+    pub(crate) refusal: Option<Refusal>,
 }
 
 /// Perform a delete operation on multiple models
@@ -27,6 +28,7 @@ where
 {
     pub(crate) query: DeleteStatement,
     pub(crate) entity: PhantomData<E>,
+    pub(crate) refusal: Option<Refusal>,
 }
 
 impl Delete {
@@ -71,6 +73,7 @@ impl Delete {
                 .from_table(A::Entity::default().table_ref())
                 .to_owned(),
             model: model.into_active_model(),
+            refusal: crate::tenant_refusal::<E>(),
         };
         myself.prepare()
     }
@@ -92,11 +95,16 @@ impl Delete {
     where
         E: EntityTrait,
     {
-        DeleteMany {
+        let delete = DeleteMany {
             query: DeleteStatement::new()
                 .from_table(entity.table_ref())
                 .to_owned(),
             entity: PhantomData,
+            refusal: crate::tenant_refusal::<E>(),
+        };
+        match crate::tenant_condition::<E>() {
+            Some(condition) => delete.filter(condition),
+            None => delete,
         }
     }
 }
@@ -113,9 +121,15 @@ where
                 ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
                     self = self.filter(col.eq(value));
                 }
+                ActiveValue::SetExpr(expr) => {
+                    self = self.filter(col.into_expr().eq(expr));
+                }
                 ActiveValue::NotSet => panic!("PrimaryKey is not set"),
             }
         }
+        if let Some(condition) = crate::tenant_condition::<A::Entity>() {
+            self = self.filter(condition);
+        }
         self
     }
 }
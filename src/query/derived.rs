@@ -0,0 +1,107 @@
+use crate::{EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use sea_query::{
+    Asterisk, DynIden, Expr, IntoCondition, IntoIden, JoinType, SeaRc, SelectStatement,
+};
+
+/// A select from a derived table: a subquery built with the entity API, selected from as
+/// `FROM (subquery) AS alias`. The outer query can be filtered, joined and ordered like any
+/// select, and decoded with [FromQueryResult](crate::FromQueryResult).
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend, SelectDerived};
+///
+/// let counts = SelectDerived::new(
+///     fruit::Entity::find()
+///         .select_only()
+///         .column(fruit::Column::CakeId)
+///         .column_as(fruit::Column::Id.count(), "fruits")
+///         .group_by(fruit::Column::CakeId),
+///     "counts",
+/// );
+/// let fruits = counts.col("fruits");
+///
+/// assert_eq!(
+///     counts
+///         .filter(fruits.clone().gt(2))
+///         .order_by_desc(fruits)
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "counts".* FROM"#,
+///         r#"(SELECT "fruit"."cake_id", COUNT("fruit"."id") AS "fruits" FROM "fruit" GROUP BY "fruit"."cake_id") AS "counts""#,
+///         r#"WHERE "counts"."fruits" > 2 ORDER BY "counts"."fruits" DESC"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct SelectDerived {
+    pub(crate) query: SelectStatement,
+    alias: DynIden,
+}
+
+impl SelectDerived {
+    /// Select all the columns of a subquery, as a table named `alias`. The subquery is taken as
+    /// a statement: one that would fail to execute, such as a select of a tenant scoped entity
+    /// outside of a [TenantContext](crate::TenantContext) scope, matches no rows here instead.
+    pub fn new<Q, A>(subquery: Q, alias: A) -> Self
+    where
+        Q: QueryTrait<QueryStatement = SelectStatement>,
+        A: IntoIden,
+    {
+        let alias = alias.into_iden();
+        let query = SelectStatement::new()
+            .column((SeaRc::clone(&alias), Asterisk))
+            .from_subquery(subquery.into_query(), SeaRc::clone(&alias))
+            .to_owned();
+        Self { query, alias }
+    }
+
+    /// A column of the derived table, to filter, order or select by
+    pub fn col<C>(&self, col: C) -> Expr
+    where
+        C: IntoIden,
+    {
+        Expr::col((SeaRc::clone(&self.alias), col.into_iden()))
+    }
+
+    /// Join an entity on a condition, usually between its columns and the columns of the
+    /// derived table
+    pub fn join_on<E, C>(mut self, join: JoinType, entity: E, condition: C) -> Self
+    where
+        E: EntityTrait,
+        C: IntoCondition,
+    {
+        self.query.join(join, entity.table_ref(), condition);
+        self
+    }
+}
+
+macro_rules! impl_query_trait {
+    ( $trait: ident ) => {
+        impl $trait for SelectDerived {
+            type QueryStatement = SelectStatement;
+
+            fn query(&mut self) -> &mut SelectStatement {
+                &mut self.query
+            }
+        }
+    };
+}
+
+impl_query_trait!(QuerySelect);
+impl_query_trait!(QueryFilter);
+impl_query_trait!(QueryOrder);
+
+impl QueryTrait for SelectDerived {
+    type QueryStatement = SelectStatement;
+    fn query(&mut self) -> &mut SelectStatement {
+        &mut self.query
+    }
+    fn as_query(&self) -> &SelectStatement {
+        &self.query
+    }
+    fn into_query(self) -> SelectStatement {
+        self.query
+    }
+}
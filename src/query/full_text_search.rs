@@ -0,0 +1,141 @@
+use crate::{ColumnTrait, DbBackend};
+use sea_query::{Expr, SimpleExpr};
+
+/// Full-text search on a text column, rendered for a backend: `to_tsvector @@ plainto_tsquery`
+/// on Postgres, `MATCH ... AGAINST` in natural language mode on MySQL, and `MATCH` on SQLite.
+///
+/// MySQL needs a `FULLTEXT` index on the column, and on SQLite the entity has to be an FTS5
+/// virtual table. Postgres uses the default text search configuration of the database.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, DbBackend, FullTextColumn};
+/// # mod post {
+/// #     use sea_orm::entity::prelude::*;
+/// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #     #[sea_orm(table_name = "post")]
+/// #     pub struct Model {
+/// #         #[sea_orm(primary_key)]
+/// #         pub id: i32,
+/// #         pub body: String,
+/// #     }
+/// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// #     pub enum Relation {}
+/// #     impl ActiveModelBehavior for ActiveModel {}
+/// # }
+///
+/// let find = |db: DbBackend| {
+///     post::Entity::find()
+///         .filter(post::Column::Body.matches(db, "sea orm"))
+///         .order_by_desc(post::Column::Body.text_rank(db, "sea orm"))
+///         .build(db)
+///         .to_string()
+/// };
+///
+/// assert_eq!(
+///     find(DbBackend::Postgres),
+///     [
+///         r#"SELECT "post"."id", "post"."body" FROM "post""#,
+///         r#"WHERE to_tsvector("post"."body") @@ plainto_tsquery('sea orm')"#,
+///         r#"ORDER BY ts_rank(to_tsvector("post"."body"), plainto_tsquery('sea orm')) DESC"#,
+///     ]
+///     .join(" ")
+/// );
+/// assert_eq!(
+///     find(DbBackend::MySql),
+///     [
+///         "SELECT `post`.`id`, `post`.`body` FROM `post`",
+///         "WHERE MATCH (`post`.`body`) AGAINST ('sea orm' IN NATURAL LANGUAGE MODE)",
+///         "ORDER BY MATCH (`post`.`body`) AGAINST ('sea orm' IN NATURAL LANGUAGE MODE) DESC",
+///     ]
+///     .join(" ")
+/// );
+/// ```
+pub trait FullTextColumn {
+    /// Whether the text matches a search query of plain words
+    fn matches(self, backend: DbBackend, query: &str) -> SimpleExpr;
+
+    /// How relevant the text is to a search query, higher is more relevant.
+    /// Use it in `order_by` or as the `from_expr` of a partial model field.
+    ///
+    /// On SQLite this is the negated `bm25` of the whole row, not only of the column.
+    fn text_rank(self, backend: DbBackend, query: &str) -> SimpleExpr;
+}
+
+impl<C> FullTextColumn for C
+where
+    C: ColumnTrait,
+{
+    fn matches(self, backend: DbBackend, query: &str) -> SimpleExpr {
+        let col: SimpleExpr = self.into_expr().into();
+        match backend {
+            DbBackend::Postgres => Expr::cust_with_exprs(
+                "to_tsvector($1) @@ plainto_tsquery($2)",
+                [col, query.into()],
+            ),
+            DbBackend::MySql => Expr::cust_with_exprs(
+                "MATCH (?) AGAINST (? IN NATURAL LANGUAGE MODE)",
+                [col, query.into()],
+            ),
+            DbBackend::Sqlite => Expr::cust_with_exprs("? MATCH ?", [col, query.into()]),
+        }
+    }
+
+    fn text_rank(self, backend: DbBackend, query: &str) -> SimpleExpr {
+        match backend {
+            DbBackend::Postgres => {
+                let col: SimpleExpr = self.into_expr().into();
+                Expr::cust_with_exprs(
+                    "ts_rank(to_tsvector($1), plainto_tsquery($2))",
+                    [col, query.into()],
+                )
+            }
+            DbBackend::MySql => self.matches(backend, query),
+            DbBackend::Sqlite => {
+                Expr::cust_with_exprs("-bm25(?)", [Expr::col(self.entity_name()).into()])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use super::*;
+    use crate::{EntityTrait, QueryFilter, QueryOrder, QueryTrait};
+    use pretty_assertions::assert_eq;
+
+    mod post {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub body: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn sqlite() {
+        assert_eq!(
+            post::Entity::find()
+                .filter(post::Column::Body.matches(DbBackend::Sqlite, "sea orm"))
+                .order_by_desc(post::Column::Body.text_rank(DbBackend::Sqlite, "sea orm"))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "post"."id", "post"."body" FROM "post""#,
+                r#"WHERE "post"."body" MATCH 'sea orm'"#,
+                r#"ORDER BY -bm25("post") DESC"#,
+            ]
+            .join(" ")
+        );
+    }
+}
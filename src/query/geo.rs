@@ -0,0 +1,214 @@
+use crate::{ColumnTrait, EntityTrait, Order, QueryFilter, QueryOrder, Select};
+use sea_query::{Alias, Expr, Func, SimpleExpr};
+
+/// The mean radius of the earth in meters, used by the haversine distance
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// A point on the earth, in degrees
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoPoint {
+    /// The latitude, positive to the north
+    pub lat: f64,
+    /// The longitude, positive to the east
+    pub lon: f64,
+}
+
+impl GeoPoint {
+    /// Create a point from its latitude and longitude
+    pub fn new(lat: f64, lon: f64) -> Self {
+        Self { lat, lon }
+    }
+}
+
+/// Where the location of a row is stored, to compute its distance to a point in meters.
+///
+/// A single column is a PostGIS `geography`, or a `geometry` in SRID 4326, and distances are
+/// computed by PostGIS. A pair of columns is the latitude and longitude in degrees, and
+/// distances are computed with the haversine formula on any backend; SQLite needs to be built
+/// with its math functions.
+///
+/// Geometry values are not decoded: declare a PostGIS column with
+/// `column_type = "custom(\"geography\")"` and `select_as = "text"` on a `String` field.
+pub trait GeoLocation {
+    /// The distance to a point in meters
+    fn distance_to(self, point: GeoPoint) -> SimpleExpr;
+
+    /// Whether the location is within a distance of a point
+    fn within_distance(self, point: GeoPoint, meters: f64) -> SimpleExpr;
+}
+
+impl<C> GeoLocation for C
+where
+    C: ColumnTrait,
+{
+    fn distance_to(self, point: GeoPoint) -> SimpleExpr {
+        Expr::cust_with_exprs(
+            "ST_Distance($1::geography, ST_SetSRID(ST_MakePoint($2, $3), 4326)::geography)",
+            [self.into_expr().into(), point.lon.into(), point.lat.into()],
+        )
+    }
+
+    fn within_distance(self, point: GeoPoint, meters: f64) -> SimpleExpr {
+        Expr::cust_with_exprs(
+            "ST_DWithin($1::geography, ST_SetSRID(ST_MakePoint($2, $3), 4326)::geography, $4)",
+            [
+                self.into_expr().into(),
+                point.lon.into(),
+                point.lat.into(),
+                meters.into(),
+            ],
+        )
+    }
+}
+
+impl<C> GeoLocation for (C, C)
+where
+    C: ColumnTrait,
+{
+    fn distance_to(self, point: GeoPoint) -> SimpleExpr {
+        let (lat, lon) = self;
+        let func = |name: &str, arg: SimpleExpr| -> SimpleExpr {
+            Func::cust(Alias::new(name)).arg(arg).into()
+        };
+        let radians = |expr: SimpleExpr| func("RADIANS", expr);
+        let sin_squared = |expr: SimpleExpr| {
+            let sin = func("SIN", Expr::expr(expr).div(2));
+            SimpleExpr::from(Func::cust(Alias::new("POWER")).args([sin, 2.into()]))
+        };
+        let lat_delta = Expr::expr(radians(lat.into_expr().into())).sub(radians(point.lat.into()));
+        let lon_delta = Expr::expr(radians(lon.into_expr().into())).sub(radians(point.lon.into()));
+        let a = Expr::expr(sin_squared(lat_delta)).add(
+            Expr::expr(func("COS", radians(point.lat.into())))
+                .mul(func("COS", radians(lat.into_expr().into())))
+                .mul(sin_squared(lon_delta)),
+        );
+        Expr::val(2.0 * EARTH_RADIUS_METERS).mul(func("ASIN", func("SQRT", a)))
+    }
+
+    fn within_distance(self, point: GeoPoint, meters: f64) -> SimpleExpr {
+        Expr::expr(self.distance_to(point)).lte(meters)
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Filter the rows with a location within a distance in meters of a point, see
+    /// [GeoLocation].
+    ///
+    /// ```
+    /// # use sea_orm::{entity::*, query::*, DbBackend, GeoPoint};
+    /// # mod shop {
+    /// #     use sea_orm::entity::prelude::*;
+    /// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    /// #     #[sea_orm(table_name = "shop")]
+    /// #     pub struct Model {
+    /// #         #[sea_orm(primary_key)]
+    /// #         pub id: i32,
+    /// #         pub lat: f64,
+    /// #         pub lon: f64,
+    /// #     }
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    /// let point = GeoPoint::new(51.5, -0.12);
+    /// assert_eq!(
+    ///     shop::Entity::find()
+    ///         .within_distance((shop::Column::Lat, shop::Column::Lon), point, 500.0)
+    ///         .order_by_distance((shop::Column::Lat, shop::Column::Lon), point, Order::Asc)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `shop`.`id`, `shop`.`lat`, `shop`.`lon` FROM `shop`",
+    ///         "WHERE 12742017.6 * ASIN(SQRT(POWER(SIN((RADIANS(`shop`.`lat`) - RADIANS(51.5)) / 2), 2)",
+    ///         "+ (COS(RADIANS(51.5)) * COS(RADIANS(`shop`.`lat`)) * POWER(SIN((RADIANS(`shop`.`lon`) - RADIANS(-0.12)) / 2), 2)))) <= 500",
+    ///         "ORDER BY 12742017.6 * ASIN(SQRT(POWER(SIN((RADIANS(`shop`.`lat`) - RADIANS(51.5)) / 2), 2)",
+    ///         "+ (COS(RADIANS(51.5)) * COS(RADIANS(`shop`.`lat`)) * POWER(SIN((RADIANS(`shop`.`lon`) - RADIANS(-0.12)) / 2), 2)))) ASC",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn within_distance<L>(self, location: L, point: GeoPoint, meters: f64) -> Self
+    where
+        L: GeoLocation,
+    {
+        self.filter(location.within_distance(point, meters))
+    }
+
+    /// Order the rows by the distance of their location to a point, see [GeoLocation]
+    pub fn order_by_distance<L>(self, location: L, point: GeoPoint, order: Order) -> Self
+    where
+        L: GeoLocation,
+    {
+        self.order_by(location.distance_to(point), order)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use super::*;
+    use crate::{DbBackend, QueryTrait};
+    use pretty_assertions::assert_eq;
+
+    mod shop {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "shop")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(column_type = "custom(\"geography\")")]
+            pub location: String,
+            pub lat: f64,
+            pub lon: f64,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn postgis() {
+        let point = GeoPoint::new(51.5, -0.12);
+        assert_eq!(
+            shop::Entity::find()
+                .within_distance(shop::Column::Location, point, 500.0)
+                .order_by_distance(shop::Column::Location, point, Order::Asc)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "shop"."id", "shop"."location", "shop"."lat", "shop"."lon" FROM "shop""#,
+                r#"WHERE ST_DWithin("shop"."location"::geography, ST_SetSRID(ST_MakePoint(-0.12, 51.5), 4326)::geography, 500)"#,
+                r#"ORDER BY ST_Distance("shop"."location"::geography, ST_SetSRID(ST_MakePoint(-0.12, 51.5), 4326)::geography) ASC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn haversine() {
+        let point = GeoPoint::new(51.5, -0.12);
+        let distance = [
+            r#"12742017.6 * ASIN(SQRT(POWER(SIN((RADIANS("shop"."lat") - RADIANS(51.5)) / 2), 2)"#,
+            r#"+ (COS(RADIANS(51.5)) * COS(RADIANS("shop"."lat")) * POWER(SIN((RADIANS("shop"."lon") - RADIANS(-0.12)) / 2), 2))))"#,
+        ]
+        .join(" ");
+        assert_eq!(
+            shop::Entity::find()
+                .within_distance((shop::Column::Lat, shop::Column::Lon), point, 1000.0)
+                .order_by_distance((shop::Column::Lat, shop::Column::Lon), point, Order::Desc)
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            format!(
+                r#"SELECT "shop"."id", "shop"."location", "shop"."lat", "shop"."lon" FROM "shop" WHERE {distance} <= 1000 ORDER BY {distance} DESC"#
+            )
+        );
+    }
+}
@@ -1,12 +1,15 @@
 use crate::{
     ColumnTrait, EntityTrait, Identity, IntoIdentity, IntoSimpleExpr, Iterable, ModelTrait,
-    PrimaryKeyToColumn, RelationDef,
+    PrimaryKeyToColumn, RelationDef, WindowExpr,
 };
 use sea_query::{
-    Alias, ConditionType, Expr, Iden, IntoCondition, IntoIden, LockBehavior, LockType,
-    NullOrdering, SeaRc, SelectExpr, SelectStatement, SimpleExpr, TableRef,
+    Alias, ConditionType, Expr, Iden, IntoCondition, IntoIden, IntoTableRef, NullOrdering, SeaRc,
+    SelectExpr, SelectStatement, SimpleExpr, TableRef,
+};
+pub use sea_query::{
+    Condition, ConditionalStatement, DynIden, JoinType, LockBehavior, LockType, Order,
+    OrderedStatement,
 };
-pub use sea_query::{Condition, ConditionalStatement, DynIden, JoinType, Order, OrderedStatement};
 
 use sea_query::IntoColumnRef;
 
@@ -52,7 +55,7 @@ pub trait QuerySelect: Sized {
     ///         .column(lunch_set::Column::Tea)
     ///         .build(DbBackend::Postgres)
     ///         .to_string(),
-    ///     r#"SELECT CAST("lunch_set"."tea" AS text) FROM "lunch_set""#
+    ///     r#"SELECT CAST("lunch_set"."tea" AS "text") FROM "lunch_set""#
     /// );
     /// assert_eq!(
     ///     lunch_set::Entity::find()
@@ -141,7 +144,7 @@ pub trait QuerySelect: Sized {
     ///         .columns([lunch_set::Column::Name, lunch_set::Column::Tea])
     ///         .build(DbBackend::Postgres)
     ///         .to_string(),
-    ///     r#"SELECT "lunch_set"."name", CAST("lunch_set"."tea" AS text) FROM "lunch_set""#
+    ///     r#"SELECT "lunch_set"."name", CAST("lunch_set"."tea" AS "text") FROM "lunch_set""#
     /// );
     /// assert_eq!(
     ///     lunch_set::Entity::find()
@@ -341,7 +344,9 @@ pub trait QuerySelect: Sized {
     }
 
     /// Add a DISTINCT ON expression
-    /// NOTE: this function is only supported by `sqlx-postgres`
+    /// NOTE: this function is only supported by `sqlx-postgres`; on other backends executing the
+    /// query fails. If the query is ordered, the DISTINCT ON columns must lead the ORDER BY,
+    /// which is checked before the query is executed.
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
     /// struct Input {
@@ -361,6 +366,26 @@ pub trait QuerySelect: Sized {
     ///     r#"SELECT DISTINCT ON ("cake"."name") "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE '%cheese%'"#
     /// );
     /// ```
+    ///
+    /// The latest fruit of each cake:
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .distinct_on([fruit::Column::CakeId])
+    ///         .order_by_asc(fruit::Column::CakeId)
+    ///         .order_by_desc(fruit::Column::Id)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT DISTINCT ON ("cake_id") "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+    ///         r#"FROM "fruit" ORDER BY "fruit"."cake_id" ASC, "fruit"."id" DESC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
     fn distinct_on<T, I>(mut self, cols: I) -> Self
     where
         T: IntoColumnRef,
@@ -447,7 +472,8 @@ pub trait QuerySelect: Sized {
         self
     }
 
-    /// Row locking with behavior (if supported).
+    /// Row locking with behavior, e.g. `NOWAIT` or `SKIP LOCKED`. Not supported by SQLite, where
+    /// executing the query fails.
     ///
     /// See [`SelectStatement::lock_with_behavior`](https://docs.rs/sea-query/*/sea_query/query/struct.SelectStatement.html#method.lock_with_behavior).
     fn lock_with_behavior(mut self, r#type: LockType, behavior: LockBehavior) -> Self {
@@ -455,6 +481,70 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Row locking of the rows of some of the tables of the query only, e.g. not of the joined
+    /// tables. Not supported by SQLite, where executing the query fails.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .inner_join(fruit::Entity)
+    ///         .lock_with_tables(LockType::Update, [cake::Entity])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"INNER JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+    ///         r#"FOR UPDATE OF "cake""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn lock_with_tables<T, I>(mut self, r#type: LockType, tables: I) -> Self
+    where
+        T: IntoTableRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.query().lock_with_tables(r#type, tables);
+        self
+    }
+
+    /// Row locking of the rows of some of the tables of the query only, with behavior. To pick
+    /// the next jobs of a queue that no other worker holds:
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .order_by_asc(cake::Column::Id)
+    ///         .limit(10)
+    ///         .lock_with_tables_behavior(LockType::Update, [cake::Entity], LockBehavior::SkipLocked)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `cake`.`id`, `cake`.`name` FROM `cake` ORDER BY `cake`.`id` ASC LIMIT 10",
+    ///         "FOR UPDATE OF `cake` SKIP LOCKED",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn lock_with_tables_behavior<T, I>(
+        mut self,
+        r#type: LockType,
+        tables: I,
+        behavior: LockBehavior,
+    ) -> Self
+    where
+        T: IntoTableRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.query()
+            .lock_with_tables_behavior(r#type, tables, behavior);
+        self
+    }
+
     /// Add an expression to the select expression list.
     /// ```
     /// use sea_orm::sea_query::Expr;
@@ -528,6 +618,31 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Select a window function over its window, see [WindowFunc](crate::WindowFunc)
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, Window, WindowFunc};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .expr_window_as(
+    ///             WindowFunc::rank().over(Window::new().order_by(cake::Column::Name, Order::Asc)),
+    ///             "rank"
+    ///         )
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"SELECT RANK() OVER (  ORDER BY "cake"."name" ASC ) AS "rank" FROM "cake""#
+    /// );
+    /// ```
+    fn expr_window_as<A>(mut self, expr: WindowExpr, alias: A) -> Self
+    where
+        A: IntoIdentity,
+    {
+        self.query()
+            .expr_window_as(expr.expr, expr.window, alias.into_identity());
+        self
+    }
+
     /// Same as `expr_as`. Here for legacy reasons.
     ///
     /// Select column.
@@ -881,12 +996,22 @@ pub(crate) fn join_condition(mut rel: RelationDef) -> Condition {
         ConditionType::Any => Condition::any(),
     };
 
-    condition = condition.add(join_tbl_on_condition(
+    let mut keys_condition = join_tbl_on_condition(
         SeaRc::clone(&from_tbl),
         SeaRc::clone(&to_tbl),
         owner_keys,
         foreign_keys,
-    ));
+    );
+    if let Some(morph) = rel.morph.take() {
+        let tbl = if morph.in_from_tbl {
+            &from_tbl
+        } else {
+            &to_tbl
+        };
+        keys_condition =
+            keys_condition.add(Expr::col((SeaRc::clone(tbl), morph.col)).eq(morph.value));
+    }
+    condition = condition.add(keys_condition);
     if let Some(f) = rel.on_condition.take() {
         condition = condition.add(f(from_tbl, to_tbl));
     }
@@ -901,7 +1026,7 @@ pub(crate) fn join_tbl_on_condition(
     foreign_keys: Identity,
 ) -> Condition {
     let mut cond = Condition::all();
-    for (owner_key, foreign_key) in owner_keys.into_iter().zip(foreign_keys.into_iter()) {
+    for (owner_key, foreign_key) in owner_keys.into_iter().zip(foreign_keys) {
         cond = cond.add(
             Expr::col((SeaRc::clone(&from_tbl), owner_key))
                 .equals((SeaRc::clone(&to_tbl), foreign_key)),
@@ -1,9 +1,12 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyTrait, QueryTrait,
+    select_exprs, ActiveModelTrait, ActiveValue, ColumnTrait, ColumnType, DbBackend, DbErr,
+    EntityName, EntityTrait, IdenStatic, IntoActiveModel, Iterable, PrimaryKeyTrait, QueryTrait,
+    Refusal, Select, Statement,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, InsertStatement, Keyword, OnConflict, SimpleExpr, Value, ValueTuple};
+use sea_query::{
+    ColumnRef, Expr, InsertStatement, Keyword, OnConflict, SimpleExpr, Value, ValueTuple,
+};
 
 /// Performs INSERT operations on a ActiveModel
 #[derive(Debug)]
@@ -14,6 +17,13 @@ where
     pub(crate) query: InsertStatement,
     pub(crate) columns: Vec<bool>,
     pub(crate) primary_key: Option<ValueTuple>,
+    pub(crate) primary_keys: Vec<Option<ValueTuple>>,
+    /// The statements inserting each row on its own, to insert the rows one by one where the
+    /// keys of a multi-row insert can't be known
+    pub(crate) rows: Vec<InsertStatement>,
+    /// The values of masked columns, redacted in the statement logs
+    pub(crate) masked: Vec<Value>,
+    pub(crate) refusal: Option<Refusal>,
     pub(crate) model: PhantomData<A>,
 }
 
@@ -38,10 +48,27 @@ where
                 .to_owned(),
             columns: Vec::new(),
             primary_key: None,
+            primary_keys: Vec::new(),
+            rows: Vec::new(),
+            masked: Vec::new(),
+            refusal: None,
             model: PhantomData,
         }
     }
 
+    fn row<C, V>(columns: C, values: V) -> InsertStatement
+    where
+        C: IntoIterator<Item = <A::Entity as EntityTrait>::Column>,
+        V: IntoIterator<Item = SimpleExpr>,
+    {
+        InsertStatement::new()
+            .into_table(A::Entity::default().table_ref())
+            .columns(columns)
+            .values_panic(values)
+            .or_default_values()
+            .to_owned()
+    }
+
     /// Insert one Model or ActiveModel
     ///
     /// Model
@@ -108,6 +135,79 @@ where
         Self::new().add_many(models)
     }
 
+    /// Insert the rows of a select, built with the query methods of another entity, into
+    /// columns of this entity. The select must have an expression for each column; a column
+    /// selected from the other entity must be of the same kind of type as the column it goes
+    /// into.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// let insert = Insert::<cake::ActiveModel>::from_select(
+    ///     [cake::Column::Id, cake::Column::Name],
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .columns([fruit::Column::Id, fruit::Column::Name])
+    ///         .filter(fruit::Column::CakeId.is_null()),
+    /// )?;
+    /// assert_eq!(
+    ///     insert.build(DbBackend::Postgres).to_string(),
+    ///     [
+    ///         r#"INSERT INTO "cake" ("id", "name")"#,
+    ///         r#"SELECT "fruit"."id", "fruit"."name" FROM "fruit" WHERE "fruit"."cake_id" IS NULL"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert!(Insert::<cake::ActiveModel>::from_select(
+    ///     [cake::Column::Name],
+    ///     fruit::Entity::find().select_only().column(fruit::Column::CakeId),
+    /// )
+    /// .is_err());
+    /// # Ok::<(), sea_orm::DbErr>(())
+    /// ```
+    pub fn from_select<F, I>(columns: I, select: Select<F>) -> Result<Self, DbErr>
+    where
+        F: EntityTrait,
+        I: IntoIterator<Item = <A::Entity as EntityTrait>::Column>,
+    {
+        let columns: Vec<_> = columns.into_iter().collect();
+        let exprs = select_exprs(&select.query);
+        if exprs.len() != columns.len() {
+            return Err(DbErr::Type(format!(
+                "{} columns to insert into, but the select has {} expressions",
+                columns.len(),
+                exprs.len()
+            )));
+        }
+        for (col, expr) in columns.iter().zip(&exprs) {
+            let Some(source) = source_column::<F>(&expr.expr) else {
+                continue;
+            };
+            let (to, from) = (col.def(), source.def());
+            if !same_kind(to.get_column_type(), from.get_column_type()) {
+                return Err(DbErr::Type(format!(
+                    "`{}` of type {:?} can't be inserted into `{}` of type {:?}",
+                    source.as_str(),
+                    from.get_column_type(),
+                    col.as_str(),
+                    to.get_column_type()
+                )));
+            }
+        }
+
+        let mut insert = Self::new();
+        insert.columns = <A::Entity as EntityTrait>::Column::iter()
+            .map(|c| columns.iter().any(|col| col.as_str() == c.as_str()))
+            .collect();
+        insert.query.columns(columns);
+        insert
+            .query
+            .select_from(select.query)
+            .map_err(|err| DbErr::Type(err.to_string()))?;
+        Ok(insert)
+    }
+
     /// Add a Model to Self
     ///
     /// # Panics
@@ -119,33 +219,45 @@ where
         M: IntoActiveModel<A>,
     {
         let mut am: A = m.into_active_model();
+        crate::stamp_tenant(&mut am);
+        self.refusal = self.refusal.or(crate::tenant_refusal::<A::Entity>());
         self.primary_key =
             if !<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment() {
                 am.get_primary_key_value()
             } else {
                 None
             };
+        self.primary_keys.push(am.get_primary_key_value());
         let mut columns = Vec::new();
         let mut values = Vec::new();
         let columns_empty = self.columns.is_empty();
         for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
-            let av = am.take(col);
-            let av_has_val = av.is_set() || av.is_unchanged();
+            let expr = match am.take(col) {
+                ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
+                    if col.def().is_masked() {
+                        self.masked.push(value.clone());
+                    }
+                    Some(col.save_as(Expr::val(value)))
+                }
+                ActiveValue::SetExpr(expr) => Some(expr),
+                ActiveValue::NotSet => crate::auto_timestamp(col, true),
+            };
+            let expr = crate::tenant_insert_value::<A::Entity>(col, expr);
+            let av_has_val = expr.is_some();
             if columns_empty {
                 self.columns.push(av_has_val);
             } else if self.columns[idx] != av_has_val {
                 panic!("columns mismatch");
             }
-            match av {
-                ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
-                    columns.push(col);
-                    values.push(col.save_as(Expr::val(value)));
-                }
-                ActiveValue::NotSet => {}
+            if let Some(expr) = expr {
+                columns.push(col);
+                values.push(expr);
             }
         }
+        self.rows.push(Self::row(columns.clone(), values.clone()));
         self.query.columns(columns);
         self.query.values_panic(values);
+        self.default_values();
         self
     }
 
@@ -169,7 +281,8 @@ where
         self
     }
 
-    /// Add many Models to Self
+    /// Add many Models to Self. If none of the models has a value set, a row of default values
+    /// is inserted for each; SQLite only inserts one such row in a statement.
     pub fn add_many<M, I>(mut self, models: I) -> Self
     where
         M: IntoActiveModel<A>,
@@ -190,18 +303,30 @@ where
                 } else {
                     None
                 };
+            self.primary_keys.push(am.get_primary_key_value());
             let mut values = Vec::with_capacity(columns.len());
             for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
                 let av = am.take(col);
                 match av {
                     ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
+                        if col.def().is_masked() {
+                            self.masked.push(value.clone());
+                        }
                         columns[idx] = Some(col); // mark the column as used
                         null_value[idx] = Some(value.as_null()); // store the null value with the correct type
                         values.push(col.save_as(Expr::val(value))); // same as add() above
                     }
-                    ActiveValue::NotSet => {
-                        values.push(SimpleExpr::Keyword(Keyword::Null)); // indicate a missing value
+                    ActiveValue::SetExpr(expr) => {
+                        columns[idx] = Some(col);
+                        values.push(expr);
                     }
+                    ActiveValue::NotSet => match crate::auto_timestamp(col, true) {
+                        Some(expr) => {
+                            columns[idx] = Some(col);
+                            values.push(expr);
+                        }
+                        None => values.push(SimpleExpr::Keyword(Keyword::Null)), // indicate a missing value
+                    },
                 }
             }
             all_values.push(values);
@@ -216,27 +341,44 @@ where
         }
 
         for values in all_values {
-            // since we've aligned the column set, this never panics
-            self.query
-                .values_panic(values.into_iter().enumerate().filter_map(|(i, v)| {
+            let values: Vec<_> = values
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, v)| {
                     if columns[i].is_some() {
                         // only if the column is used
                         if !matches!(v, SimpleExpr::Keyword(Keyword::Null)) {
                             // use the value expression
                             Some(v)
                         } else {
-                            // use null as standin, which must be Some
-                            null_value[i].clone().map(SimpleExpr::Value)
+                            // use null as standin, typed if any row has a value
+                            Some(null_value[i].clone().map_or(v, SimpleExpr::Value))
                         }
                     } else {
                         None
                     }
-                }));
+                })
+                .collect();
+            self.rows
+                .push(Self::row(columns.iter().cloned().flatten(), values.clone()));
+            // since we've aligned the column set, this never panics
+            self.query.values_panic(values);
         }
 
+        self.default_values();
         self
     }
 
+    /// Insert a row of default values for each model if none of them has a value set:
+    /// `DEFAULT VALUES` on SQLite, which only inserts one row, `VALUES (DEFAULT), ...` on
+    /// Postgres and `VALUES (), ...` on MySQL
+    fn default_values(&mut self) {
+        if !self.columns.is_empty() && !self.columns.contains(&true) {
+            let rows = u32::try_from(self.primary_keys.len()).unwrap_or(u32::MAX);
+            self.query.or_default_values_many(rows);
+        }
+    }
+
     /// On conflict
     ///
     /// on conflict do nothing
@@ -281,6 +423,9 @@ where
     /// );
     /// ```
     pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
+        for row in self.rows.iter_mut() {
+            row.on_conflict(on_conflict.clone());
+        }
         self.query.on_conflict(on_conflict);
         self
     }
@@ -333,18 +478,79 @@ where
     ///     r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO NOTHING"#,
     /// );
     /// ```
-    pub fn on_conflict_do_nothing(mut self) -> TryInsert<A>
+    pub fn on_conflict_do_nothing(self) -> TryInsert<A>
     where
         A: ActiveModelTrait,
     {
         let primary_keys = <A::Entity as EntityTrait>::PrimaryKey::iter();
-        self.query.on_conflict(
-            OnConflict::columns(primary_keys.clone())
-                .do_nothing_on(primary_keys)
-                .to_owned(),
-        );
+        TryInsert::from_insert(
+            self.on_conflict(
+                OnConflict::columns(primary_keys.clone())
+                    .do_nothing_on(primary_keys)
+                    .to_owned(),
+            ),
+        )
+    }
 
-        TryInsert::from_insert(self)
+    /// Skip the rows conflicting with an existing row on any unique constraint: `ON CONFLICT
+    /// DO NOTHING` on Postgres, `INSERT IGNORE` on MySQL and `INSERT OR IGNORE` on SQLite.
+    /// Executing it reports how many of the rows were inserted and how many skipped.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let insert = cake::Entity::insert_many([
+    ///     cake::ActiveModel {
+    ///         id: Set(1),
+    ///         name: Set("Apple Pie".to_owned()),
+    ///     },
+    ///     cake::ActiveModel {
+    ///         id: Set(2),
+    ///         name: Set("Orange Scone".to_owned()),
+    ///     },
+    /// ])
+    /// .on_conflict_ignore();
+    ///
+    /// assert_eq!(
+    ///     insert.build(DbBackend::Postgres).to_string(),
+    ///     r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Apple Pie'), (2, 'Orange Scone') ON CONFLICT DO NOTHING"#,
+    /// );
+    /// assert_eq!(
+    ///     insert.build(DbBackend::MySql).to_string(),
+    ///     "INSERT IGNORE INTO `cake` (`id`, `name`) VALUES (1, 'Apple Pie'), (2, 'Orange Scone')",
+    /// );
+    /// assert_eq!(
+    ///     insert.build(DbBackend::Sqlite).to_string(),
+    ///     r#"INSERT OR IGNORE INTO "cake" ("id", "name") VALUES (1, 'Apple Pie'), (2, 'Orange Scone')"#,
+    /// );
+    /// ```
+    pub fn on_conflict_ignore(self) -> InsertIgnore<A> {
+        InsertIgnore { insert: self }
+    }
+}
+
+/// An INSERT skipping the rows that conflict with existing rows, see [`Insert::on_conflict_ignore`]
+#[derive(Debug)]
+pub struct InsertIgnore<A>
+where
+    A: ActiveModelTrait,
+{
+    pub(crate) insert: Insert<A>,
+}
+
+impl<A> InsertIgnore<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Build the statement for a backend
+    pub fn build(&self, backend: DbBackend) -> Statement {
+        let stmt = backend.build(&self.insert.query);
+        let sql = match backend {
+            DbBackend::Postgres => format!("{} ON CONFLICT DO NOTHING", stmt.sql),
+            DbBackend::MySql => stmt.sql.replacen("INSERT", "INSERT IGNORE", 1),
+            DbBackend::Sqlite => stmt.sql.replacen("INSERT", "INSERT OR IGNORE", 1),
+        };
+        Statement { sql, ..stmt }
     }
 }
 
@@ -432,7 +638,7 @@ where
     }
 
     pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
-        self.insert_struct.query.on_conflict(on_conflict);
+        self.insert_struct = self.insert_struct.on_conflict(on_conflict);
         self
     }
 
@@ -462,6 +668,31 @@ where
         self.insert_struct.query
     }
 }
+/// The column of an entity a selected expression is, if it is a plain column of the entity
+fn source_column<F>(expr: &SimpleExpr) -> Option<F::Column>
+where
+    F: EntityTrait,
+{
+    let (table, column) = match expr {
+        SimpleExpr::Column(ColumnRef::Column(column)) => (None, column),
+        SimpleExpr::Column(ColumnRef::TableColumn(table, column)) => (Some(table), column),
+        _ => return None,
+    };
+    if table.is_some_and(|table| table.to_string() != F::default().table_name()) {
+        return None;
+    }
+    let column = column.to_string();
+    F::Column::iter().find(|col| col.as_str() == column)
+}
+
+/// Whether two column types are of the same kind, whatever their lengths and precisions
+fn same_kind(a: &ColumnType, b: &ColumnType) -> bool {
+    use ColumnType::*;
+
+    let text = |t: &ColumnType| matches!(t, Char(_) | String(_) | Text);
+    std::mem::discriminant(a) == std::mem::discriminant(b) || (text(a) && text(b))
+}
+
 #[cfg(test)]
 mod tests {
     use sea_query::OnConflict;
@@ -573,6 +804,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn insert_expr() {
+        use sea_query::Expr;
+
+        assert_eq!(
+            Insert::<cake::ActiveModel>::new()
+                .add(cake::ActiveModel {
+                    id: ActiveValue::expr(Expr::cust("nextval('cake_id_seq')")),
+                    name: ActiveValue::set("Apple Pie".to_owned()),
+                })
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"INSERT INTO "cake" ("id", "name") VALUES (nextval('cake_id_seq'), 'Apple Pie')"#,
+        );
+
+        let apple = cake_filling::ActiveModel {
+            cake_id: ActiveValue::set(2),
+            filling_id: ActiveValue::expr(Expr::cust("DEFAULT")),
+        };
+        let orange = cake_filling::ActiveModel {
+            cake_id: ActiveValue::expr(Expr::val(1).add(2)),
+            filling_id: ActiveValue::NotSet,
+        };
+        assert_eq!(
+            Insert::<cake_filling::ActiveModel>::new()
+                .add_many([apple, orange])
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"INSERT INTO "cake_filling" ("cake_id", "filling_id") VALUES (2, DEFAULT), (1 + 2, NULL)"#,
+        );
+    }
+
     #[test]
     fn insert_6() {
         let orange = cake::ActiveModel {
@@ -735,4 +998,263 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn insert_many_returning_on_mysql() -> Result<(), DbErr> {
+        use crate::{
+            tests_cfg::{cake, cake_filling},
+            DbBackend, MockDatabase, MockExecResult, Statement, Transaction,
+        };
+
+        let cake = |id, name: &str| cake::Model {
+            id,
+            name: name.to_owned(),
+        };
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 3,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 4,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 5,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 2,
+                },
+            ])
+            .append_query_results([[cake(4, "Lemon Tart"), cake(3, "Apple Pie")]])
+            .append_query_results([[cake(5, "Apple Pie")]])
+            .append_query_results([[
+                cake_filling::Model {
+                    cake_id: 2,
+                    filling_id: 1,
+                },
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 2,
+                },
+            ]])
+            .into_connection();
+
+        // the keys of the rows after the first are unknown, so the rows are inserted one by one
+        assert_eq!(
+            cake::Entity::insert_many([
+                cake::ActiveModel {
+                    name: Set("Apple Pie".to_owned()),
+                    ..Default::default()
+                },
+                cake::ActiveModel {
+                    name: Set("Lemon Tart".to_owned()),
+                    ..Default::default()
+                },
+            ])
+            .exec_with_returning_many(&db)
+            .await?,
+            [cake(3, "Apple Pie"), cake(4, "Lemon Tart")]
+        );
+
+        assert_eq!(
+            cake::Entity::insert_many([cake::ActiveModel {
+                name: Set("Apple Pie".to_owned()),
+                ..Default::default()
+            }])
+            .exec_with_returning_many(&db)
+            .await?,
+            [cake(5, "Apple Pie")]
+        );
+
+        assert_eq!(
+            cake_filling::Entity::insert_many([
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 2,
+                }
+                .into_active_model(),
+                cake_filling::Model {
+                    cake_id: 2,
+                    filling_id: 1,
+                }
+                .into_active_model(),
+            ])
+            .exec_with_returning_many(&db)
+            .await?
+            .into_iter()
+            .map(|model| (model.cake_id, model.filling_id))
+            .collect::<Vec<_>>(),
+            [(1, 2), (2, 1)]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(
+                        DbBackend::MySql,
+                        "INSERT INTO `cake` (`name`) VALUES (?)",
+                        ["Apple Pie".into()]
+                    ),
+                    Statement::from_sql_and_values(
+                        DbBackend::MySql,
+                        "INSERT INTO `cake` (`name`) VALUES (?)",
+                        ["Lemon Tart".into()]
+                    ),
+                    Statement::from_sql_and_values(
+                        DbBackend::MySql,
+                        "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` IN (?, ?)",
+                        [3i32.into(), 4i32.into()]
+                    ),
+                    Statement::from_string(DbBackend::MySql, "COMMIT"),
+                ]),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(
+                        DbBackend::MySql,
+                        "INSERT INTO `cake` (`name`) VALUES (?)",
+                        ["Apple Pie".into()]
+                    ),
+                    Statement::from_sql_and_values(
+                        DbBackend::MySql,
+                        "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` IN (?)",
+                    [5i32.into()]
+                    ),
+                    Statement::from_string(DbBackend::MySql, "COMMIT"),
+                ]),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "INSERT INTO `cake_filling` (`cake_id`, `filling_id`) VALUES (?, ?), (?, ?)",
+                    [1i32.into(), 2i32.into(), 2i32.into(), 1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        "SELECT `cake_filling`.`cake_id`, `cake_filling`.`filling_id` FROM `cake_filling`",
+                        "WHERE (`cake_filling`.`cake_id`, `cake_filling`.`filling_id`) IN ((?, ?), (?, ?))",
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [1i32.into(), 2i32.into(), 2i32.into(), 1i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_on_conflict_ignore() -> Result<(), DbErr> {
+        use crate::{
+            tests_cfg::cake, DbBackend, InsertIgnoreResult, MockDatabase, MockExecResult,
+            Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 3,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::insert_many((1..=3).map(|id| cake::ActiveModel {
+                id: Set(id),
+                name: Set(format!("Cake {id}")),
+            }))
+            .on_conflict_ignore()
+            .exec(&db)
+            .await?,
+            InsertIgnoreResult {
+                inserted: 2,
+                skipped: 1,
+            }
+        );
+        assert_eq!(
+            cake::Entity::insert_many(Vec::<cake::ActiveModel>::new())
+                .on_conflict_ignore()
+                .exec(&db)
+                .await?,
+            InsertIgnoreResult::default()
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                r#"INSERT OR IGNORE INTO "cake" ("id", "name") VALUES (?, ?), (?, ?), (?, ?)"#,
+                [
+                    1.into(),
+                    "Cake 1".into(),
+                    2.into(),
+                    "Cake 2".into(),
+                    3.into(),
+                    "Cake 3".into()
+                ]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_default_values() {
+        let insert = |rows: usize| {
+            cake::Entity::insert_many((0..rows).map(|_| cake::ActiveModel::default()))
+        };
+        assert_eq!(
+            insert(1).build(DbBackend::Postgres).to_string(),
+            r#"INSERT INTO "cake" VALUES (DEFAULT)"#
+        );
+        assert_eq!(
+            insert(2).build(DbBackend::Postgres).to_string(),
+            r#"INSERT INTO "cake" VALUES (DEFAULT), (DEFAULT)"#
+        );
+        assert_eq!(
+            insert(2).build(DbBackend::MySql).to_string(),
+            "INSERT INTO `cake` VALUES (), ()"
+        );
+        assert_eq!(
+            cake::Entity::insert(cake::ActiveModel::default())
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"INSERT INTO "cake" DEFAULT VALUES"#
+        );
+    }
+
+    #[smol_potat::test]
+    async fn insert_default_values_returning() -> Result<(), DbErr> {
+        use crate::{tests_cfg::cake, DbBackend, MockDatabase, Transaction};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "".to_owned(),
+            }]])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::insert(cake::ActiveModel::default())
+                .exec(&db)
+                .await?
+                .last_insert_id,
+            1
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" VALUES (DEFAULT) RETURNING "id""#,
+                []
+            )]
+        );
+
+        Ok(())
+    }
 }
@@ -1,9 +1,12 @@
 use crate::{
-    join_tbl_on_condition, unpack_table_ref, ColumnTrait, EntityTrait, IdenStatic, Iterable,
-    Linked, QuerySelect, Related, Select, SelectA, SelectB, SelectThree, SelectTwo, SelectTwoMany,
+    join_condition, join_tbl_on_condition, unpack_table_ref, ColumnTrait, EntityTrait, IdenStatic,
+    Iterable, Linked, PrimaryKeyToColumn, QuerySelect, Related, RelationDef, Select, SelectA,
+    SelectB, SelectThree, SelectTwo, SelectTwoMany,
 };
 pub use sea_query::JoinType;
-use sea_query::{Alias, Condition, Expr, IntoIden, SeaRc, SelectExpr};
+use sea_query::{
+    Alias, Asterisk, Condition, DynIden, Expr, IntoIden, Query, SeaRc, SelectExpr, SimpleExpr,
+};
 
 impl<E> Select<E>
 where
@@ -15,7 +18,11 @@ where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.join_join(JoinType::LeftJoin, E::to(), E::via())
+        self.refuse(crate::tenant_refusal::<R>()).join_join(
+            JoinType::LeftJoin,
+            scoped::<R>(E::to()),
+            E::via(),
+        )
     }
 
     /// Right Join with a Related Entity.
@@ -24,7 +31,11 @@ where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.join_join(JoinType::RightJoin, E::to(), E::via())
+        self.refuse(crate::tenant_refusal::<R>()).join_join(
+            JoinType::RightJoin,
+            scoped::<R>(E::to()),
+            E::via(),
+        )
     }
 
     /// Inner Join with a Related Entity.
@@ -33,7 +44,11 @@ where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.join_join(JoinType::InnerJoin, E::to(), E::via())
+        self.refuse(crate::tenant_refusal::<R>()).join_join(
+            JoinType::InnerJoin,
+            scoped::<R>(E::to()),
+            E::via(),
+        )
     }
 
     /// Join with an Entity Related to me.
@@ -62,14 +77,107 @@ where
         self.left_join(r).select_with(r)
     }
 
+    /// Keep the rows having at least one row of a Related Entity that passes a filter, with a
+    /// correlated `EXISTS` subquery. Unlike a join, every row is selected once however many
+    /// related rows match.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter_related(fruit::Entity, |q| q.filter(fruit::Column::Name.contains("apple")))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"WHERE EXISTS(SELECT 1 FROM "fruit""#,
+    ///         r#"WHERE "fruit"."name" LIKE '%apple%' AND "cake"."id" = "fruit"."cake_id")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn filter_related<R, F>(self, _: R, filter: F) -> Self
+    where
+        R: EntityTrait,
+        E: Related<R>,
+        F: FnOnce(Select<R>) -> Select<R>,
+    {
+        let related = related_subquery::<E, R, F>(filter);
+        crate::QueryFilter::filter(self.refuse(related.refusal), Expr::exists(related.query))
+    }
+
+    /// Keep the rows having no row of a Related Entity that passes a filter, with a correlated
+    /// `NOT EXISTS` subquery. See [Self::filter_related].
+    pub fn filter_not_related<R, F>(self, _: R, filter: F) -> Self
+    where
+        R: EntityTrait,
+        E: Related<R>,
+        F: FnOnce(Select<R>) -> Select<R>,
+    {
+        let related = related_subquery::<E, R, F>(filter);
+        crate::QueryFilter::filter(
+            self.refuse(related.refusal),
+            Expr::exists(related.query).not(),
+        )
+    }
+
+    /// Select the number of rows of a Related Entity of each row as `alias`, with a correlated
+    /// `COUNT` subquery. Read it into a custom model having a field named `alias`, or with
+    /// [Select::all_with_count] as a `(Model, i64)` tuple.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend, FromQueryResult};
+    ///
+    /// #[derive(Debug, FromQueryResult)]
+    /// struct CakeWithFruitCount {
+    ///     id: i32,
+    ///     name: String,
+    ///     fruit_count: i64,
+    /// }
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .with_count_of(fruit::Entity, "fruit_count")
+    ///         .into_model::<CakeWithFruitCount>()
+    ///         .into_statement(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name","#,
+    ///         r#"(SELECT COUNT(*) FROM "fruit" WHERE "cake"."id" = "fruit"."cake_id") AS "fruit_count""#,
+    ///         r#"FROM "cake""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn with_count_of<R>(self, _: R, alias: &str) -> Self
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        let Select {
+            query: mut count,
+            refusal,
+            ..
+        } = related_subquery::<E, R, _>(|select| select);
+        count.clear_selects().expr(Expr::col(Asterisk).count());
+        self.refuse(refusal).expr_as(
+            SimpleExpr::SubQuery(None, Box::new(count.into_sub_query_statement())),
+            alias,
+        )
+    }
+
     /// Left Join with a Linked Entity and select both Entity.
     pub fn find_also_linked<L, T>(self, l: L) -> SelectTwo<E, T>
     where
         L: Linked<FromEntity = E, ToEntity = T>,
         T: EntityTrait,
     {
-        let mut slf = self;
-        for (i, mut rel) in l.link().into_iter().enumerate() {
+        let mut slf = self.refuse(crate::tenant_refusal::<T>());
+        let hops = l.link().len();
+        for (i, rel) in l.link().into_iter().enumerate() {
+            // the entity of the last hop is the only one known
+            let mut rel = if i + 1 == hops { scoped::<T>(rel) } else { rel };
             let to_tbl = Alias::new(format!("r{i}")).into_iden();
             let from_tbl = if i > 0 {
                 Alias::new(format!("r{}", i - 1)).into_iden()
@@ -92,7 +200,7 @@ where
                 .join_as(JoinType::LeftJoin, table_ref, to_tbl, condition);
         }
         slf = slf.apply_alias(SelectA.as_str());
-        let mut select_two = SelectTwo::new_without_prepare(slf.query);
+        let mut select_two = SelectTwo::new_without_prepare(slf.query, slf.refusal);
         for col in <T::Column as Iterable>::iter() {
             let alias = format!("{}{}", SelectB.as_str(), col.as_str());
             let expr = Expr::col((
@@ -114,8 +222,11 @@ where
         L: Linked<FromEntity = E, ToEntity = T>,
         T: EntityTrait,
     {
-        let mut slf = self;
-        for (i, mut rel) in l.link().into_iter().enumerate() {
+        let mut slf = self.refuse(crate::tenant_refusal::<T>());
+        let hops = l.link().len();
+        for (i, rel) in l.link().into_iter().enumerate() {
+            // the entity of the last hop is the only one known
+            let mut rel = if i + 1 == hops { scoped::<T>(rel) } else { rel };
             let to_tbl = Alias::new(format!("r{i}")).into_iden();
             let from_tbl = if i > 0 {
                 Alias::new(format!("r{}", i - 1)).into_iden()
@@ -138,7 +249,7 @@ where
                 .join_as(JoinType::LeftJoin, table_ref, to_tbl, condition);
         }
         slf = slf.apply_alias(SelectA.as_str());
-        let mut select_two_many = SelectTwoMany::new_without_prepare(slf.query);
+        let mut select_two_many = SelectTwoMany::new_without_prepare(slf.query, slf.refusal);
         for col in <T::Column as Iterable>::iter() {
             let alias = format!("{}{}", SelectB.as_str(), col.as_str());
             let expr = Expr::col((
@@ -166,7 +277,8 @@ where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.join_join(JoinType::LeftJoin, E::to(), E::via())
+        self.refuse(crate::tenant_refusal::<R>())
+            .join_join(JoinType::LeftJoin, scoped::<R>(E::to()), E::via())
             .select_also(r)
     }
 
@@ -176,11 +288,86 @@ where
         R: EntityTrait,
         F: Related<R>,
     {
-        self.join_join(JoinType::LeftJoin, F::to(), F::via())
+        self.refuse(crate::tenant_refusal::<R>())
+            .join_join(JoinType::LeftJoin, scoped::<R>(F::to()), F::via())
             .select_also(r)
     }
 }
 
+/// Select the rows of a Related Entity passing a filter, correlated to the row of the outer query
+pub(super) fn related_subquery<E, R, F>(filter: F) -> Select<R>
+where
+    E: Related<R>,
+    R: EntityTrait,
+    F: FnOnce(Select<R>) -> Select<R>,
+{
+    let mut select = filter(R::find()).select_only().expr(Expr::cust("1"));
+    let correlation = match E::via() {
+        Some(via) => {
+            select = select.join_rev(JoinType::InnerJoin, E::to());
+            via
+        }
+        None => E::to(),
+    };
+    crate::QueryFilter::filter(select, join_condition(correlation))
+}
+
+/// Add the tenant, default scope, expiry and soft delete of the joined entity to the join
+/// condition of a relation, on the table or alias it is joined as
+fn scoped<R>(mut rel: RelationDef) -> RelationDef
+where
+    R: EntityTrait,
+{
+    if scope_condition::<R>(R::default().into_iden()).is_none() {
+        return rel;
+    }
+    let on_condition = rel.on_condition.take();
+    rel.on_condition(move |from_tbl, to_tbl| {
+        let condition = match &on_condition {
+            Some(f) => Condition::all().add(f(from_tbl, SeaRc::clone(&to_tbl))),
+            None => Condition::all(),
+        };
+        match scope_condition::<R>(to_tbl) {
+            Some(scope) => condition.add(scope),
+            None => condition,
+        }
+    })
+}
+
+/// The tenant, default scope, expiry and soft delete conditions of an entity on the rows of
+/// `tbl`, the table of the entity or an alias of it
+fn scope_condition<R>(tbl: DynIden) -> Option<Condition>
+where
+    R: EntityTrait,
+{
+    let entity = R::default();
+    // the default scope names the table, so an alias is scoped through its primary key
+    let default_scope = entity.default_scope().map(|scope| {
+        if tbl.to_string() == entity.table_name() {
+            return scope;
+        }
+        let mut rows = Query::select();
+        rows.expr(Expr::cust("1"))
+            .from(entity.table_ref())
+            .cond_where(scope);
+        for key in R::PrimaryKey::iter() {
+            let col = key.into_column();
+            rows.and_where(Expr::col((R::default(), col)).equals((SeaRc::clone(&tbl), col)));
+        }
+        Condition::all().add(Expr::exists(rows))
+    });
+    [
+        crate::tenant_condition_on::<R>(SeaRc::clone(&tbl))
+            .map(|condition| Condition::all().add(condition)),
+        default_scope,
+        crate::expiry_condition_on::<R>(SeaRc::clone(&tbl)),
+        crate::soft_delete_condition_on::<R>(tbl),
+    ]
+    .into_iter()
+    .flatten()
+    .reduce(|scope, condition| scope.add(condition))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::{cake, cake_filling, cake_filling_price, entity_linked, filling, fruit};
@@ -663,4 +850,56 @@ mod tests {
             .join(" ")
         );
     }
+
+    #[test]
+    fn filter_related_many_to_many() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_related(filling::Entity, |q| q.filter(filling::Column::Id.gt(2)))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE EXISTS(SELECT 1 FROM `filling`",
+                "INNER JOIN `cake_filling` ON `cake_filling`.`filling_id` = `filling`.`id`",
+                "WHERE `filling`.`id` > 2 AND `cake`.`id` = `cake_filling`.`cake_id`)",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_not_related() {
+        assert_eq!(
+            fruit::Entity::find()
+                .filter_not_related(cake::Entity, |q| q)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                "WHERE NOT EXISTS(SELECT 1 FROM `cake` WHERE `fruit`.`cake_id` = `cake`.`id`)",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn with_count_of_many_to_many() {
+        assert_eq!(
+            cake::Entity::find()
+                .with_count_of(filling::Entity, "filling_count")
+                .with_count_of(fruit::Entity, "fruit_count")
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name`,",
+                "(SELECT COUNT(*) FROM `filling`",
+                "INNER JOIN `cake_filling` ON `cake_filling`.`filling_id` = `filling`.`id`",
+                "WHERE `cake`.`id` = `cake_filling`.`cake_id`) AS `filling_count`,",
+                "(SELECT COUNT(*) FROM `fruit` WHERE `cake`.`id` = `fruit`.`cake_id`) AS `fruit_count`",
+                "FROM `cake`",
+            ]
+            .join(" ")
+        );
+    }
 }
@@ -211,6 +211,42 @@ impl FromQueryResult for JsonValue {
                 }
                 Ok(JsonValue::Object(map))
             }
+            #[cfg(feature = "libsql")]
+            crate::QueryResultRow::Libsql(row) => {
+                for (column, value) in row.clone().into_column_value_tuples() {
+                    let col = if !column.starts_with(pre) {
+                        continue;
+                    } else {
+                        column.replacen(pre, "", 1)
+                    };
+                    map.insert(col, sea_query::sea_value_to_json_value(&value));
+                }
+                Ok(JsonValue::Object(map))
+            }
+            #[cfg(feature = "duckdb")]
+            crate::QueryResultRow::Duckdb(row) => {
+                for (column, value) in row.clone().into_column_value_tuples() {
+                    let col = if !column.starts_with(pre) {
+                        continue;
+                    } else {
+                        column.replacen(pre, "", 1)
+                    };
+                    map.insert(col, sea_query::sea_value_to_json_value(&value));
+                }
+                Ok(JsonValue::Object(map))
+            }
+            #[cfg(feature = "tokio-postgres")]
+            crate::QueryResultRow::TokioPostgres(row) => {
+                for (column, value) in row.clone().into_column_value_tuples() {
+                    let col = if !column.starts_with(pre) {
+                        continue;
+                    } else {
+                        column.replacen(pre, "", 1)
+                    };
+                    map.insert(col, sea_query::sea_value_to_json_value(&value));
+                }
+                Ok(JsonValue::Object(map))
+            }
             #[cfg(feature = "mock")]
             crate::QueryResultRow::Mock(row) => {
                 for (column, value) in row.clone().into_column_value_tuples() {
@@ -0,0 +1,322 @@
+use crate::{ColumnTrait, DbBackend, JsonValue};
+use sea_query::{Expr, SimpleExpr, Value};
+
+/// Filters on the content of a JSON column, rendered for a backend: `jsonb_extract_path_text`
+/// and `@>` on Postgres, and `JSON_EXTRACT` and `JSON_CONTAINS` on MySQL and SQLite.
+///
+/// Paths are JSONPath like `$.settings.locale` or `$.tags[0]`, of keys and array indices.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, DbBackend, JsonColumn};
+/// # mod user {
+/// #     use sea_orm::entity::prelude::*;
+/// #     #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #     #[sea_orm(table_name = "user")]
+/// #     pub struct Model {
+/// #         #[sea_orm(primary_key)]
+/// #         pub id: i32,
+/// #         pub meta: Json,
+/// #     }
+/// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// #     pub enum Relation {}
+/// #     impl ActiveModelBehavior for ActiveModel {}
+/// # }
+///
+/// let find = |db: DbBackend| {
+///     user::Entity::find()
+///         .filter(user::Column::Meta.json_path(db, "$.settings.locale").eq("en"))
+///         .build(db)
+///         .to_string()
+/// };
+///
+/// assert_eq!(
+///     find(DbBackend::Postgres),
+///     [
+///         r#"SELECT "user"."id", "user"."meta" FROM "user""#,
+///         r#"WHERE (jsonb_extract_path_text(CAST("user"."meta" AS jsonb), 'settings', 'locale')) = 'en'"#,
+///     ]
+///     .join(" ")
+/// );
+/// assert_eq!(
+///     find(DbBackend::MySql),
+///     [
+///         "SELECT `user`.`id`, `user`.`meta` FROM `user`",
+///         "WHERE (JSON_UNQUOTE(JSON_EXTRACT(`user`.`meta`, '$.settings.locale'))) = 'en'",
+///     ]
+///     .join(" ")
+/// );
+/// ```
+pub trait JsonColumn {
+    /// The value at a path, as text; on SQLite, numbers and booleans stay numbers
+    fn json_path(self, backend: DbBackend, path: &str) -> Expr;
+
+    /// Whether the JSON document contains a value: the value itself, an element of an array,
+    /// or the members of an object. On SQLite, the arrays and objects nested in an array are
+    /// compared by containment too, through `json_each`.
+    fn json_contains(self, backend: DbBackend, value: JsonValue) -> SimpleExpr;
+
+    /// The number of elements of a JSON array
+    fn json_array_length(self, backend: DbBackend) -> Expr;
+}
+
+impl<C> JsonColumn for C
+where
+    C: ColumnTrait,
+{
+    fn json_path(self, backend: DbBackend, path: &str) -> Expr {
+        let col: SimpleExpr = self.into_expr().into();
+        let expr = match backend {
+            DbBackend::Postgres => {
+                let keys = json_path_keys(path);
+                if keys.is_empty() {
+                    Expr::cust_with_exprs("$1 #>> '{}'", [col])
+                } else {
+                    let placeholders = (2..keys.len() + 2)
+                        .map(|i| format!("${i}"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    Expr::cust_with_exprs(
+                        format!("jsonb_extract_path_text(CAST($1 AS jsonb), {placeholders})"),
+                        std::iter::once(col).chain(keys.into_iter().map(Into::into)),
+                    )
+                }
+            }
+            DbBackend::MySql => {
+                Expr::cust_with_exprs("JSON_UNQUOTE(JSON_EXTRACT(?, ?))", [col, path.into()])
+            }
+            DbBackend::Sqlite => Expr::cust_with_exprs("JSON_EXTRACT(?, ?)", [col, path.into()]),
+        };
+        Expr::expr(expr)
+    }
+
+    fn json_contains(self, backend: DbBackend, value: JsonValue) -> SimpleExpr {
+        let col: SimpleExpr = self.into_expr().into();
+        match backend {
+            DbBackend::Postgres => Expr::cust_with_exprs(
+                "CAST($1 AS jsonb) @> CAST($2 AS jsonb)",
+                [col, value.to_string().into()],
+            ),
+            DbBackend::MySql => {
+                Expr::cust_with_exprs("JSON_CONTAINS(?, ?)", [col, value.to_string().into()])
+            }
+            DbBackend::Sqlite => sqlite_json_contains(col, "$".to_owned(), value, 0),
+        }
+    }
+
+    fn json_array_length(self, backend: DbBackend) -> Expr {
+        let col: SimpleExpr = self.into_expr().into();
+        let expr = match backend {
+            DbBackend::Postgres => {
+                Expr::cust_with_exprs("jsonb_array_length(CAST($1 AS jsonb))", [col])
+            }
+            DbBackend::MySql => Expr::cust_with_exprs("JSON_LENGTH(?)", [col]),
+            DbBackend::Sqlite => Expr::cust_with_exprs("json_array_length(?)", [col]),
+        };
+        Expr::expr(expr)
+    }
+}
+
+/// Whether the JSON value at `path` of `doc` contains `value`, walking arrays with `json_each`
+/// aliased by `depth` to keep nested walks apart
+fn sqlite_json_contains(
+    doc: SimpleExpr,
+    path: String,
+    value: JsonValue,
+    depth: usize,
+) -> SimpleExpr {
+    let each = format!("j{depth}");
+    let json_type = |json_type: &str| {
+        Expr::cust_with_exprs(
+            format!("json_type(?, ?) = '{json_type}'"),
+            [doc.clone(), path.clone().into()],
+        )
+    };
+    let exists = |condition: SimpleExpr| {
+        Expr::cust_with_exprs(
+            format!("EXISTS(SELECT 1 FROM json_each(?, ?) AS {each} WHERE ?)"),
+            [doc.clone(), path.clone().into(), condition],
+        )
+    };
+    match value {
+        JsonValue::Object(members) => {
+            members
+                .into_iter()
+                .fold(json_type("object"), |cond, (key, value)| {
+                    let path = format!("{path}.\"{}\"", key.replace('"', "\\\""));
+                    cond.and(sqlite_json_contains(doc.clone(), path, value, depth))
+                })
+        }
+        JsonValue::Array(elements) => {
+            elements
+                .into_iter()
+                .fold(json_type("array"), |cond, element| {
+                    let element = match element {
+                        JsonValue::Array(_) | JsonValue::Object(_) => {
+                            let value = Expr::cust(format!("{each}.value"));
+                            sqlite_json_contains(value, "$".to_owned(), element, depth + 1)
+                        }
+                        scalar => sqlite_json_eq(&each, scalar),
+                    };
+                    cond.and(exists(element))
+                })
+        }
+        scalar => exists(sqlite_json_eq(&each, scalar)),
+    }
+}
+
+/// Whether the value of a `json_each` row equals a scalar
+fn sqlite_json_eq(each: &str, scalar: JsonValue) -> SimpleExpr {
+    let value: Value = match scalar {
+        JsonValue::Null => return Expr::cust(format!("{each}.type = 'null'")),
+        JsonValue::Bool(b) => b.into(),
+        JsonValue::Number(n) => match n.as_i64() {
+            Some(i) => i.into(),
+            None => n.as_f64().into(),
+        },
+        JsonValue::String(s) => s.into(),
+        value => value.to_string().into(),
+    };
+    Expr::cust(format!("{each}.value")).eq(value)
+}
+
+/// The keys and array indices of a JSONPath, e.g. `["tags", "0"]` for `$.tags[0]`
+fn json_path_keys(path: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    let mut chars = path.trim_start_matches('$').chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut key = String::new();
+        match c {
+            '.' if chars.peek() == Some(&'"') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                    key.push(c);
+                }
+            }
+            '.' => {
+                while let Some(c) = chars.next_if(|c| *c != '.' && *c != '[') {
+                    key.push(c);
+                }
+            }
+            '[' => {
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    key.push(c);
+                }
+                key = key.trim_matches('"').to_owned();
+            }
+            _ => continue,
+        }
+        keys.push(key);
+    }
+    keys
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use super::*;
+    use crate::{EntityTrait, QueryFilter, QueryTrait};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    mod user {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "user")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub meta: Json,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn path_keys() {
+        assert_eq!(json_path_keys("$"), Vec::<String>::new());
+        assert_eq!(json_path_keys("$.a.b"), ["a", "b"]);
+        assert_eq!(json_path_keys("$.tags[0].name"), ["tags", "0", "name"]);
+        assert_eq!(json_path_keys(r#"$."a.b"[1]"#), ["a.b", "1"]);
+    }
+
+    #[test]
+    fn contains_and_array_length() {
+        let find = |db: DbBackend| {
+            user::Entity::find()
+                .filter(user::Column::Meta.json_contains(db, json!({ "tags": ["new"] })))
+                .filter(user::Column::Meta.json_array_length(db).gt(2))
+                .build(db)
+                .to_string()
+        };
+        assert_eq!(
+            find(DbBackend::Postgres),
+            [
+                r#"SELECT "user"."id", "user"."meta" FROM "user""#,
+                r#"WHERE (CAST("user"."meta" AS jsonb) @> CAST(E'{\"tags\":[\"new\"]}' AS jsonb))"#,
+                r#"AND (jsonb_array_length(CAST("user"."meta" AS jsonb))) > 2"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            find(DbBackend::MySql),
+            [
+                "SELECT `user`.`id`, `user`.`meta` FROM `user`",
+                r#"WHERE (JSON_CONTAINS(`user`.`meta`, '{\"tags\":[\"new\"]}'))"#,
+                "AND (JSON_LENGTH(`user`.`meta`)) > 2",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn sqlite() {
+        assert_eq!(
+            user::Entity::find()
+                .filter(user::Column::Meta.json_contains(DbBackend::Sqlite, json!("new")))
+                .filter(user::Column::Meta.json_path(DbBackend::Sqlite, "$.tags[0]").eq("new"))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "user"."id", "user"."meta" FROM "user""#,
+                r#"WHERE (EXISTS(SELECT 1 FROM json_each("user"."meta", '$') AS j0 WHERE (j0.value) = 'new'))"#,
+                r#"AND (JSON_EXTRACT("user"."meta", '$.tags[0]')) = 'new'"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn sqlite_arrays_and_objects() {
+        assert_eq!(
+            user::Entity::find()
+                .filter(user::Column::Meta.json_contains(
+                    DbBackend::Sqlite,
+                    json!({ "tags": ["new", { "id": 1 }], "draft": null })
+                ))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "user"."id", "user"."meta" FROM "user""#,
+                r#"WHERE (json_type("user"."meta", '$') = 'object')"#,
+                r#"AND (EXISTS(SELECT 1 FROM json_each("user"."meta", '$."draft"') AS j0 WHERE j0.type = 'null'))"#,
+                r#"AND ((json_type("user"."meta", '$."tags"') = 'array')"#,
+                r#"AND (EXISTS(SELECT 1 FROM json_each("user"."meta", '$."tags"') AS j0 WHERE (j0.value) = 'new'))"#,
+                r#"AND (EXISTS(SELECT 1 FROM json_each("user"."meta", '$."tags"') AS j0"#,
+                r#"WHERE (json_type(j0.value, '$') = 'object')"#,
+                r#"AND (EXISTS(SELECT 1 FROM json_each(j0.value, '$."id"') AS j1 WHERE (j1.value) = 1)))))"#,
+            ]
+            .join(" ")
+        );
+    }
+}
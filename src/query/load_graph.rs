@@ -0,0 +1,308 @@
+use crate::{
+    error::*, DbErr, EntityOrSelect, EntityTrait, Loaded, LoaderTrait, ModelTrait,
+    ReadConnectionTrait, Related, RelationType, Select,
+};
+use async_trait::async_trait;
+use serde::Serialize;
+
+/// The relations to load with [LoaderTrait::load_graph]: a [LoadEdge], a tuple of them, or `()`
+/// for none. The [graph!](crate::graph) macro builds one from the names of the relations.
+#[async_trait]
+pub trait LoadGraph<M>: Send + Sync
+where
+    M: ModelTrait + Serialize + Sync + 'static,
+{
+    /// Load the related models of the models, and the relations of those, and attach them
+    async fn attach<C>(&self, loaded: Vec<Loaded<M>>, db: &C) -> Result<Vec<Loaded<M>>, DbErr>
+    where
+        C: ReadConnectionTrait;
+}
+
+/// A relation to load with [LoaderTrait::load_graph], attached by a name, with the relations of
+/// the related models to load in turn.
+///
+/// A `has_one` or `belongs_to` relation is attached as the related model or `null`, a
+/// `has_many` relation as an array. The related models of all the models are loaded in one
+/// query, like with [LoaderTrait], and so are the relations of those, level by level.
+#[derive(Clone, Debug)]
+pub struct LoadEdge<R, G = ()>
+where
+    R: EntityTrait,
+{
+    name: String,
+    select: Select<R>,
+    graph: G,
+}
+
+impl<R> LoadEdge<R>
+where
+    R: EntityTrait,
+{
+    /// Load the related models selected by `stmt`, attached by a name
+    pub fn new<S>(name: &str, stmt: S) -> Self
+    where
+        S: EntityOrSelect<R>,
+    {
+        Self {
+            name: name.to_owned(),
+            select: stmt.select(),
+            graph: (),
+        }
+    }
+}
+
+impl<R, G> LoadEdge<R, G>
+where
+    R: EntityTrait,
+{
+    /// Load the relations of the related models as well
+    pub fn with<H>(self, graph: H) -> LoadEdge<R, H> {
+        LoadEdge {
+            name: self.name,
+            select: self.select,
+            graph,
+        }
+    }
+}
+
+/// Build a [LoadGraph] for [LoaderTrait::load_graph] from relations, each given as the name to
+/// attach it by and the related entity, followed by the relations of the related models in
+/// braces.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[fruit::Model { id: 7, name: "Lemon".to_owned(), cake_id: Some(1) }]])
+/// #     .append_query_results([[cake::Model { id: 1, name: "Cheese Cake".to_owned() }]])
+/// #     .append_query_results([[
+/// #         fruit::Model { id: 7, name: "Lemon".to_owned(), cake_id: Some(1) },
+/// #         fruit::Model { id: 8, name: "Cherry".to_owned(), cake_id: Some(1) },
+/// #     ]])
+/// #     .into_connection();
+/// #
+/// use sea_orm::graph;
+///
+/// let fruits = fruit::Entity::find().all(&db).await?;
+/// let fruits = fruits
+///     .load_graph(graph!(cake: cake::Entity { fruits: fruit::Entity }), &db)
+///     .await?;
+///
+/// assert_eq!(
+///     serde_json::to_value(&fruits).unwrap(),
+///     serde_json::json!([{
+///         "id": 7,
+///         "name": "Lemon",
+///         "cake_id": 1,
+///         "cake": {
+///             "id": 1,
+///             "name": "Cheese Cake",
+///             "fruits": [
+///                 { "id": 7, "name": "Lemon", "cake_id": 1 },
+///                 { "id": 8, "name": "Cherry", "cake_id": 1 },
+///             ],
+///         },
+///     }])
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Relations loaded from a select, to filter or order the related models, are given as a
+/// [LoadEdge] instead, in a tuple with the others.
+#[macro_export]
+macro_rules! graph {
+    () => {
+        ()
+    };
+    ($($name: ident : $entity: path $({ $($graph: tt)* })?),+ $(,)?) => {
+        ($(
+            $crate::LoadEdge::new(stringify!($name), $entity)
+                $(.with($crate::graph!($($graph)*)))?,
+        )+)
+    };
+}
+
+#[async_trait]
+impl<M> LoadGraph<M> for ()
+where
+    M: ModelTrait + Serialize + Sync + 'static,
+{
+    async fn attach<C>(&self, loaded: Vec<Loaded<M>>, _: &C) -> Result<Vec<Loaded<M>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+    {
+        Ok(loaded)
+    }
+}
+
+#[async_trait]
+impl<M, R, G> LoadGraph<M> for LoadEdge<R, G>
+where
+    M: ModelTrait + Serialize + Sync + 'static,
+    M::Entity: Related<R>,
+    R: EntityTrait,
+    R::Model: Serialize + Send + Sync,
+    G: LoadGraph<R::Model>,
+{
+    async fn attach<C>(&self, loaded: Vec<Loaded<M>>, db: &C) -> Result<Vec<Loaded<M>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+    {
+        if <M::Entity as Related<R>>::via().is_some() {
+            return Err(query_err(format!(
+                "Can't load `{}`, a many to many relation, in a graph",
+                self.name
+            )));
+        }
+        let models: Vec<M> = loaded.iter().map(|loaded| loaded.model().clone()).collect();
+        match <M::Entity as Related<R>>::to().rel_type {
+            RelationType::HasOne => {
+                let related = models.load_one(self.select.clone(), db).await?;
+                let nested = Loaded::all(related.iter().flatten().cloned().collect());
+                let mut nested = self.graph.attach(nested, db).await?.into_iter();
+                let related: Vec<_> = related
+                    .iter()
+                    .map(|model| model.as_ref().and_then(|_| nested.next()))
+                    .collect();
+                Loaded::zip(loaded, &self.name, related)
+            }
+            RelationType::HasMany => {
+                let related = models.load_many(self.select.clone(), db).await?;
+                let lens: Vec<usize> = related.iter().map(Vec::len).collect();
+                let nested = Loaded::all(related.into_iter().flatten().collect());
+                let mut nested = self.graph.attach(nested, db).await?.into_iter();
+                let related: Vec<Vec<_>> = lens
+                    .into_iter()
+                    .map(|len| nested.by_ref().take(len).collect())
+                    .collect();
+                Loaded::zip(loaded, &self.name, related)
+            }
+        }
+    }
+}
+
+macro_rules! impl_load_graph_tuple {
+    ($($edge: ident),+) => {
+        #[async_trait]
+        impl<M, $($edge),+> LoadGraph<M> for ($($edge,)+)
+        where
+            M: ModelTrait + Serialize + Sync + 'static,
+            $($edge: LoadGraph<M>),+
+        {
+            #[allow(non_snake_case)]
+            async fn attach<C>(
+                &self,
+                loaded: Vec<Loaded<M>>,
+                db: &C,
+            ) -> Result<Vec<Loaded<M>>, DbErr>
+            where
+                C: ReadConnectionTrait,
+            {
+                let ($($edge,)+) = self;
+                $(let loaded = $edge.attach(loaded, db).await?;)+
+                Ok(loaded)
+            }
+        }
+    };
+}
+
+impl_load_graph_tuple!(A);
+impl_load_graph_tuple!(A, B);
+impl_load_graph_tuple!(A, B, C1);
+impl_load_graph_tuple!(A, B, C1, D);
+impl_load_graph_tuple!(A, B, C1, D, E);
+impl_load_graph_tuple!(A, B, C1, D, E, F);
+impl_load_graph_tuple!(A, B, C1, D, E, F, G);
+impl_load_graph_tuple!(A, B, C1, D, E, F, G, H);
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        tests_cfg::*, ColumnTrait, DbBackend, DbErr, EntityTrait, LoadEdge, LoaderTrait,
+        MockDatabase, QueryFilter,
+    };
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn cake_model(id: i32, name: &str) -> cake::Model {
+        cake::Model {
+            id,
+            name: name.to_owned(),
+        }
+    }
+
+    fn fruit_model(id: i32, name: &str, cake_id: Option<i32>) -> fruit::Model {
+        fruit::Model {
+            id,
+            name: name.to_owned(),
+            cake_id,
+        }
+    }
+
+    #[smol_potat::test]
+    async fn load_graph_by_level() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[fruit_model(7, "Lemon", Some(1))]])
+            .append_query_results([[
+                fruit_model(7, "Lemon", Some(1)),
+                fruit_model(8, "Cherry", Some(1)),
+            ]])
+            .append_query_results([[cake_model(1, "Cheese Cake")]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1, "Cheese Cake"), cake_model(2, "Lemon Cake")];
+        let cakes = cakes
+            .load_graph(
+                (
+                    LoadEdge::new(
+                        "lemons",
+                        fruit::Entity::find().filter(fruit::Column::Name.eq("Lemon")),
+                    ),
+                    graph!(fruits: fruit::Entity { cake: cake::Entity }),
+                ),
+                &db,
+            )
+            .await?;
+
+        let lemon = json!({ "id": 7, "name": "Lemon", "cake_id": 1 });
+        let cheese_cake = json!({ "id": 1, "name": "Cheese Cake" });
+        assert_eq!(
+            serde_json::to_value(&cakes).unwrap(),
+            json!([
+                {
+                    "id": 1,
+                    "name": "Cheese Cake",
+                    "lemons": [lemon],
+                    "fruits": [
+                        { "id": 7, "name": "Lemon", "cake_id": 1, "cake": cheese_cake },
+                        { "id": 8, "name": "Cherry", "cake_id": 1, "cake": cheese_cake },
+                    ],
+                },
+                { "id": 2, "name": "Lemon Cake", "lemons": [], "fruits": [] },
+            ])
+        );
+        assert_eq!(db.into_transaction_log().len(), 3);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn load_graph_empty() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let cakes = Vec::<cake::Model>::new()
+            .load_graph(graph!(fruits: fruit::Entity { cake: cake::Entity }), &db)
+            .await?;
+        assert!(cakes.is_empty());
+        assert!(db.into_transaction_log().is_empty());
+
+        Ok(())
+    }
+}
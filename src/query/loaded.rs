@@ -0,0 +1,261 @@
+use crate::{
+    redact_json, DbErr, EntityOrSelect, EntityTrait, JsonValue, LoaderTrait, Masked, ModelTrait,
+    ReadConnectionTrait, Related,
+};
+use serde::{ser::Error, Serialize, Serializer};
+
+/// A model with the related models loaded for it, serialized as the JSON object of the model
+/// with a field per relation.
+///
+/// Related models are attached by name, from the results of [LoaderTrait], in the order of the
+/// models they were loaded for. Attach `Loaded` models to nest deeper relations.
+///
+/// The masked columns of the model, and of the related models loaded for it, are redacted, see
+/// [UnmaskContext](crate::UnmaskContext). Wrap other models attached with [Loaded::with] in
+/// [Masked].
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model { id: 1, name: "Cheese Cake".to_owned() }]])
+/// #     .append_query_results([[fruit::Model { id: 7, name: "Lemon".to_owned(), cake_id: Some(1) }]])
+/// #     .into_connection();
+/// #
+/// use sea_orm::Loaded;
+///
+/// let cakes = cake::Entity::find().all(&db).await?;
+/// let cakes = Loaded::load_many(Loaded::all(cakes), "fruits", fruit::Entity, &db).await?;
+///
+/// assert_eq!(
+///     serde_json::to_value(&cakes).unwrap(),
+///     serde_json::json!([{
+///         "id": 1,
+///         "name": "Cheese Cake",
+///         "fruits": [{ "id": 7, "name": "Lemon", "cake_id": 1 }],
+///     }])
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Loaded<M> {
+    model: M,
+    related: Vec<(String, JsonValue)>,
+}
+
+impl<M> Loaded<M>
+where
+    M: ModelTrait + Serialize,
+{
+    /// Wrap a model, without related models
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            related: Vec::new(),
+        }
+    }
+
+    /// Wrap models, without related models
+    pub fn all(models: Vec<M>) -> Vec<Self> {
+        models.into_iter().map(Self::new).collect()
+    }
+
+    /// The model
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Unwrap the model
+    pub fn into_model(self) -> M {
+        self.model
+    }
+
+    /// The JSON of the related models attached by a name
+    pub fn related(&self, name: &str) -> Option<&JsonValue> {
+        self.related
+            .iter()
+            .find(|(related, _)| related == name)
+            .map(|(_, json)| json)
+    }
+
+    /// Attach related models by a name, replacing the ones attached by the name before
+    pub fn with<R>(mut self, name: &str, related: R) -> Result<Self, DbErr>
+    where
+        R: Serialize,
+    {
+        let json = serde_json::to_value(related).map_err(|err| DbErr::Json(err.to_string()))?;
+        self.related.retain(|(related, _)| related != name);
+        self.related.push((name.to_owned(), json));
+        Ok(self)
+    }
+
+    /// Attach the related models of each model by a name, `related` being in the order of the
+    /// models, as returned by [LoaderTrait]
+    pub fn zip<R>(loaded: Vec<Self>, name: &str, related: Vec<R>) -> Result<Vec<Self>, DbErr>
+    where
+        R: Serialize,
+    {
+        if loaded.len() != related.len() {
+            return Err(DbErr::Custom(format!(
+                "Can't attach `{name}`: {} related for {} models",
+                related.len(),
+                loaded.len()
+            )));
+        }
+        loaded
+            .into_iter()
+            .zip(related)
+            .map(|(loaded, related)| loaded.with(name, related))
+            .collect()
+    }
+
+    /// The JSON object of the model with the related models
+    pub fn to_json(&self) -> Result<JsonValue, DbErr> {
+        let mut json =
+            serde_json::to_value(&self.model).map_err(|err| DbErr::Json(err.to_string()))?;
+        redact_json::<M::Entity>(&mut json);
+        let JsonValue::Object(object) = &mut json else {
+            return Err(DbErr::Json(format!(
+                "Can't attach related models to {json}, which is not an object"
+            )));
+        };
+        for (name, related) in self.related.iter() {
+            object.insert(name.clone(), related.clone());
+        }
+        Ok(json)
+    }
+}
+
+impl<M> Loaded<M>
+where
+    M: ModelTrait + Serialize + Sync,
+{
+    /// Load the related model of each model with [LoaderTrait::load_one], and attach it by a
+    /// name
+    pub async fn load_one<R, S, C>(
+        loaded: Vec<Self>,
+        name: &str,
+        stmt: S,
+        db: &C,
+    ) -> Result<Vec<Self>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Serialize + Send + Sync,
+        S: EntityOrSelect<R>,
+        M::Entity: Related<R>,
+    {
+        let models: Vec<M> = loaded.iter().map(|loaded| loaded.model.clone()).collect();
+        let related = models.load_one(stmt, db).await?;
+        let related = related
+            .iter()
+            .map(|model| model.as_ref().map(Masked::new))
+            .collect();
+        Self::zip(loaded, name, related)
+    }
+
+    /// Load the related models of each model with [LoaderTrait::load_many], and attach them by
+    /// a name
+    pub async fn load_many<R, S, C>(
+        loaded: Vec<Self>,
+        name: &str,
+        stmt: S,
+        db: &C,
+    ) -> Result<Vec<Self>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Serialize + Send + Sync,
+        S: EntityOrSelect<R>,
+        M::Entity: Related<R>,
+    {
+        let models: Vec<M> = loaded.iter().map(|loaded| loaded.model.clone()).collect();
+        let related = models.load_many(stmt, db).await?;
+        let related = related.iter().map(|models| Masked::all(models)).collect();
+        Self::zip(loaded, name, related)
+    }
+}
+
+impl<M> Serialize for Loaded<M>
+where
+    M: ModelTrait + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_json()
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{tests_cfg::*, DbBackend, MockDatabase};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[smol_potat::test]
+    async fn nested() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[fruit::Model {
+                id: 7,
+                name: "Lemon".to_owned(),
+                cake_id: Some(1),
+            }]])
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .into_connection();
+
+        let fruits = Loaded::all(fruit::Entity::find().all(&db).await?);
+        let fruits = Loaded::load_one(fruits, "cake", cake::Entity, &db).await?;
+        let lemon = json!({
+            "id": 7,
+            "name": "Lemon",
+            "cake_id": 1,
+            "cake": { "id": 1, "name": "Cheese Cake" },
+        });
+        assert_eq!(serde_json::to_value(&fruits).unwrap(), json!([lemon]));
+
+        let cake = Loaded::new(cake::Model {
+            id: 2,
+            name: "Lemon Cake".to_owned(),
+        })
+        .with("fruits", &fruits)?;
+        assert_eq!(
+            cake.to_json()?,
+            json!({ "id": 2, "name": "Lemon Cake", "fruits": [lemon] })
+        );
+
+        let cake = cake.with("fruits", Vec::<fruit::Model>::new())?;
+        assert_eq!(cake.related("fruits"), Some(&json!([])));
+        assert_eq!(cake.related("fillings"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zip_mismatch() {
+        let cakes = Loaded::all(vec![cake::Model {
+            id: 1,
+            name: "Cheese Cake".to_owned(),
+        }]);
+        assert_eq!(
+            Loaded::zip(cakes, "fruits", Vec::<Vec<fruit::Model>>::new()),
+            Err(DbErr::Custom(
+                "Can't attach `fruits`: 0 related for 1 models".to_owned()
+            ))
+        );
+    }
+}
@@ -1,10 +1,22 @@
 use crate::{
-    error::*, Condition, ConnectionTrait, DbErr, EntityTrait, Identity, ModelTrait, QueryFilter,
-    Related, RelationType, Select,
+    error::*, select_orders, Condition, DbErr, EntityTrait, Identity, Linked, ModelTrait,
+    PartialModelTrait, QueryFilter, QueryOrder, QueryResult, QuerySelect, ReadConnectionTrait,
+    Related, RelationDef, RelationType, Select,
 };
 use async_trait::async_trait;
-use sea_query::{ColumnRef, DynIden, Expr, IntoColumnRef, SimpleExpr, TableRef, ValueTuple};
-use std::{collections::HashMap, str::FromStr};
+use sea_query::{
+    Alias, Asterisk, ColumnRef, DynIden, Expr, Func, IntoColumnRef, OverStatement, SelectStatement,
+    SimpleExpr, TableRef, Value, ValueTuple, WindowStatement,
+};
+use std::{collections::HashMap, marker::PhantomData, str::FromStr};
+#[cfg(feature = "with-json")]
+use {
+    crate::{LoadGraph, Loaded},
+    serde::Serialize,
+};
+
+/// Prefix of the aliases of the key columns selected along with a partial model
+const PARTIAL_KEY: &str = "sea_orm_key_";
 
 /// Entity, or a Select<Entity>; to be used as parameters in [`LoaderTrait`]
 pub trait EntityOrSelect<E: EntityTrait>: Send {
@@ -12,6 +24,21 @@ pub trait EntityOrSelect<E: EntityTrait>: Send {
     fn select(self) -> Select<E>;
 }
 
+/// An enum of the entities a polymorphic relation relates to, with a variant holding the Model
+/// of each, to load with [LoaderTrait::load_morph]. Derived with
+/// [DeriveMorphTo](crate::DeriveMorphTo), from the `belongs_to` relations marked with
+/// `morph_type` and `morph_value`, see [Morph](crate::Morph).
+#[async_trait]
+pub trait MorphTo<M>: Sized + Send
+where
+    M: ModelTrait + Sync,
+{
+    /// The related model of each model, of the entity named by its type column
+    async fn load<C>(models: &[M], db: &C) -> Result<Vec<Option<Self>>, DbErr>
+    where
+        C: ReadConnectionTrait;
+}
+
 /// This trait implements the Data Loader API
 #[async_trait]
 pub trait LoaderTrait {
@@ -21,19 +48,127 @@ pub trait LoaderTrait {
     /// Used to eager load has_one relations
     async fn load_one<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Option<R::Model>>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
         R: EntityTrait,
         R::Model: Send + Sync,
         S: EntityOrSelect<R>,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
 
+    /// Used to eager load has_one relations, with the related query built by a closure, see
+    /// [LoadSelect]
+    async fn load_one_with<R, S, F, C>(
+        &self,
+        stmt: S,
+        query: F,
+        db: &C,
+    ) -> Result<Vec<Option<R::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        F: FnOnce(LoadSelect<R>) -> LoadSelect<R> + Send,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
     /// Used to eager load has_many relations
     async fn load_many<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load has_many relations, with the related query built by a closure; its
+    /// limit and offset apply to the related models of each model, see [LoadSelect]
+    async fn load_many_with<R, S, F, C>(
+        &self,
+        stmt: S,
+        query: F,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
         R: EntityTrait,
         R::Model: Send + Sync,
         S: EntityOrSelect<R>,
+        F: FnOnce(LoadSelect<R>) -> LoadSelect<R> + Send,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load has_one relations into a partial model, selecting only its columns
+    /// and the columns the related models are matched by
+    async fn load_one_partial<P, R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Option<P>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        P: PartialModelTrait + Clone + Send,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load has_many relations into a partial model, selecting only its columns
+    /// and the columns the related models are grouped by
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(all(feature = "mock", feature = "macros"))]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[
+    /// #         maplit::btreemap! { "name" => Value::from("Apple"), "sea_orm_key_0" => Value::from(1) },
+    /// #         maplit::btreemap! { "name" => Value::from("Lemon"), "sea_orm_key_0" => Value::from(1) },
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DerivePartialModel};
+    ///
+    /// #[derive(Clone, Debug, PartialEq, DerivePartialModel, FromQueryResult)]
+    /// #[sea_orm(entity = "fruit::Entity")]
+    /// struct FruitName {
+    ///     name: String,
+    /// }
+    ///
+    /// let cakes = vec![
+    ///     cake::Model { id: 1, name: "Fruit Cake".to_owned() },
+    ///     cake::Model { id: 2, name: "Chocolate Cake".to_owned() },
+    /// ];
+    /// let fruits: Vec<Vec<FruitName>> = cakes.load_many_partial(fruit::Entity, &db).await?;
+    /// assert_eq!(
+    ///     fruits,
+    ///     [
+    ///         vec![
+    ///             FruitName { name: "Apple".to_owned() },
+    ///             FruitName { name: "Lemon".to_owned() },
+    ///         ],
+    ///         vec![],
+    ///     ]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         [
+    ///             r#"SELECT "fruit"."name" AS "name", "fruit"."cake_id" AS "sea_orm_key_0""#,
+    ///             r#"FROM "fruit" WHERE "fruit"."cake_id" IN ($1, $2)"#,
+    ///         ]
+    ///         .join(" ")
+    ///         .as_str(),
+    ///         [1.into(), 2.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn load_many_partial<P, R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<P>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        P: PartialModelTrait + Clone + Send,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
 
     /// Used to eager load many_to_many relations
@@ -44,13 +179,61 @@ pub trait LoaderTrait {
         db: &C,
     ) -> Result<Vec<Vec<R::Model>>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
         R: EntityTrait,
         R::Model: Send + Sync,
         S: EntityOrSelect<R>,
         V: EntityTrait,
         V::Model: Send + Sync,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load many_to_many relations, along with the rows of the junction table
+    /// linking each model to its related models, to read the columns of the junction table
+    /// like the quantity of a line item
+    async fn load_many_to_many_with_via<R, S, V, C>(
+        &self,
+        stmt: S,
+        via: V,
+        db: &C,
+    ) -> Result<Vec<Vec<(V::Model, R::Model)>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        V: EntityTrait,
+        V::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load the models reached through a chain of relations, see [Linked]
+    /// and [DeriveLinked](crate::DeriveLinked). The whole chain is joined in one query.
+    async fn load_linked<L, C>(
+        &self,
+        linked: L,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        L: Linked<FromEntity = <Self::Model as ModelTrait>::Entity> + Send,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync;
+
+    /// Used to eager load a polymorphic relation, as the enum of the entities it relates to,
+    /// see [MorphTo]. Each related entity is loaded in one query.
+    async fn load_morph<T, C>(&self, db: &C) -> Result<Vec<Option<T>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        T: MorphTo<Self::Model>,
+        Self::Model: Sync;
+
+    /// Used to eager load relations, and the relations of the related models in turn, see
+    /// [graph!](crate::graph). The related models of each level are loaded in one query per
+    /// relation, and attached to the models they belong to.
+    #[cfg(feature = "with-json")]
+    async fn load_graph<G, C>(&self, graph: G, db: &C) -> Result<Vec<Loaded<Self::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        G: LoadGraph<Self::Model>,
+        Self::Model: Serialize + Sync + 'static;
 }
 
 impl<E> EntityOrSelect<E> for E
@@ -71,6 +254,140 @@ where
     }
 }
 
+/// The query of the related models in [LoaderTrait::load_one_with] and
+/// [LoaderTrait::load_many_with], to filter and order them. A limit or offset applies to the
+/// related models of each model, numbered with `ROW_NUMBER()` in the order of the query, so
+/// the newest comments of each post still load in one query.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[fruit::Model { id: 2, name: "Banana".to_owned(), cake_id: Some(1) }]])
+/// #     .into_connection();
+/// #
+/// let cakes = vec![cake::Model { id: 1, name: "Banana Cake".to_owned() }];
+/// let fruits = cakes
+///     .load_many_with(
+///         fruit::Entity,
+///         |query| query.order_by_desc(fruit::Column::Id).limit(2).offset(1),
+///         &db,
+///     )
+///     .await?;
+///
+/// assert_eq!(fruits[0][0].name, "Banana");
+/// assert_eq!(
+///     db.into_transaction_log()[0].statements()[0].to_string(),
+///     [
+///         r#"SELECT "fruit".* FROM (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id","#,
+///         r#"ROW_NUMBER() OVER ( PARTITION BY "fruit"."cake_id" ORDER BY "fruit"."id" DESC ) AS "row_number""#,
+///         r#"FROM "fruit" WHERE "fruit"."cake_id" IN (1)) AS "fruit""#,
+///         r#"WHERE "fruit"."row_number" > 1 AND "fruit"."row_number" <= 3 ORDER BY "fruit"."row_number" ASC"#,
+///     ]
+///     .join(" ")
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LoadSelect<E>
+where
+    E: EntityTrait,
+{
+    select: Select<E>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+}
+
+impl<E> LoadSelect<E>
+where
+    E: EntityTrait,
+{
+    fn new(select: Select<E>) -> Self {
+        Self {
+            select,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// Load at most `limit` related models of each model
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skip the first `offset` related models of each model
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// The select of the related models matching the condition, numbering the related models
+    /// of each model to limit them
+    fn into_select(self, rel_def: &RelationDef, condition: Condition) -> Select<E> {
+        let mut select = self.select.filter(condition);
+        if self.limit.is_none() && self.offset.is_none() {
+            return select;
+        }
+
+        let mut window = WindowStatement::new();
+        for col in rel_def.to_col.clone() {
+            window.add_partition_by(SimpleExpr::Column(table_column(&rel_def.to_tbl, &col)));
+        }
+        for order in select_orders(&select.query) {
+            window.add_order_by(order);
+        }
+        let row_number = Alias::new("row_number");
+        select.query.clear_order_by().expr_window_as(
+            Func::cust(Alias::new("ROW_NUMBER")),
+            window,
+            row_number.clone(),
+        );
+
+        let alias = Alias::new(E::default().table_name());
+        let row_number = Expr::col((alias.clone(), row_number));
+        let offset = self.offset.unwrap_or_default();
+        let mut query = SelectStatement::new();
+        query
+            .column((alias.clone(), Asterisk))
+            .from_subquery(select.query, alias)
+            .and_where(row_number.clone().gt(offset));
+        if let Some(limit) = self.limit {
+            query.and_where(row_number.clone().lte(offset + limit));
+        }
+        query.order_by_expr(row_number.into(), sea_query::Order::Asc);
+        Select {
+            query,
+            entity: PhantomData,
+            refusal: select.refusal,
+        }
+    }
+}
+
+macro_rules! impl_load_select_trait {
+    ( $trait: ident ) => {
+        impl<E> $trait for LoadSelect<E>
+        where
+            E: EntityTrait,
+        {
+            type QueryStatement = SelectStatement;
+
+            fn query(&mut self) -> &mut SelectStatement {
+                &mut self.select.query
+            }
+        }
+    };
+}
+
+impl_load_select_trait!(QueryFilter);
+impl_load_select_trait!(QueryOrder);
+
 #[async_trait]
 impl<M> LoaderTrait for Vec<M>
 where
@@ -80,7 +397,7 @@ where
 
     async fn load_one<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Option<R::Model>>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
         R: EntityTrait,
         R::Model: Send + Sync,
         S: EntityOrSelect<R>,
@@ -89,9 +406,26 @@ where
         self.as_slice().load_one(stmt, db).await
     }
 
+    async fn load_one_with<R, S, F, C>(
+        &self,
+        stmt: S,
+        query: F,
+        db: &C,
+    ) -> Result<Vec<Option<R::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        F: FnOnce(LoadSelect<R>) -> LoadSelect<R> + Send,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        self.as_slice().load_one_with(stmt, query, db).await
+    }
+
     async fn load_many<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
         R: EntityTrait,
         R::Model: Send + Sync,
         S: EntityOrSelect<R>,
@@ -100,6 +434,45 @@ where
         self.as_slice().load_many(stmt, db).await
     }
 
+    async fn load_many_with<R, S, F, C>(
+        &self,
+        stmt: S,
+        query: F,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        F: FnOnce(LoadSelect<R>) -> LoadSelect<R> + Send,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        self.as_slice().load_many_with(stmt, query, db).await
+    }
+
+    async fn load_one_partial<P, R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Option<P>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        P: PartialModelTrait + Clone + Send,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        self.as_slice().load_one_partial(stmt, db).await
+    }
+
+    async fn load_many_partial<P, R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<P>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        P: PartialModelTrait + Clone + Send,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        self.as_slice().load_many_partial(stmt, db).await
+    }
+
     async fn load_many_to_many<R, S, V, C>(
         &self,
         stmt: S,
@@ -107,7 +480,7 @@ where
         db: &C,
     ) -> Result<Vec<Vec<R::Model>>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
         R: EntityTrait,
         R::Model: Send + Sync,
         S: EntityOrSelect<R>,
@@ -117,6 +490,58 @@ where
     {
         self.as_slice().load_many_to_many(stmt, via, db).await
     }
+
+    async fn load_many_to_many_with_via<R, S, V, C>(
+        &self,
+        stmt: S,
+        via: V,
+        db: &C,
+    ) -> Result<Vec<Vec<(V::Model, R::Model)>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        V: EntityTrait,
+        V::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        self.as_slice()
+            .load_many_to_many_with_via(stmt, via, db)
+            .await
+    }
+
+    async fn load_linked<L, C>(
+        &self,
+        linked: L,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        L: Linked<FromEntity = <Self::Model as ModelTrait>::Entity> + Send,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync,
+    {
+        self.as_slice().load_linked(linked, db).await
+    }
+
+    async fn load_morph<T, C>(&self, db: &C) -> Result<Vec<Option<T>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        T: MorphTo<Self::Model>,
+        Self::Model: Sync,
+    {
+        self.as_slice().load_morph(db).await
+    }
+
+    #[cfg(feature = "with-json")]
+    async fn load_graph<G, C>(&self, graph: G, db: &C) -> Result<Vec<Loaded<Self::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        G: LoadGraph<Self::Model>,
+        Self::Model: Serialize + Sync + 'static,
+    {
+        self.as_slice().load_graph(graph, db).await
+    }
 }
 
 #[async_trait]
@@ -128,20 +553,13 @@ where
 
     async fn load_one<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Option<R::Model>>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
         R: EntityTrait,
         R::Model: Send + Sync,
         S: EntityOrSelect<R>,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
     {
-        // we verify that is HasOne relation
-        if <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::via().is_some() {
-            return Err(query_err("Relation is ManytoMany instead of HasOne"));
-        }
-        let rel_def = <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::to();
-        if rel_def.rel_type == RelationType::HasMany {
-            return Err(query_err("Relation is HasMany instead of HasOne"));
-        }
+        let rel_def = has_one_def::<M, R>()?;
 
         if self.is_empty() {
             return Ok(Vec::new());
@@ -152,48 +570,93 @@ where
             .map(|model: &M| extract_key(&rel_def.from_col, model))
             .collect();
 
-        let condition = prepare_condition(&rel_def.to_tbl, &rel_def.to_col, &keys);
+        let condition = related_condition(&rel_def, self, &keys);
 
         let stmt = <Select<R> as QueryFilter>::filter(stmt.select(), condition);
 
         let data = stmt.all(db).await?;
 
-        let hashmap: HashMap<ValueTuple, <R as EntityTrait>::Model> = data.into_iter().fold(
-            HashMap::new(),
-            |mut acc, value: <R as EntityTrait>::Model| {
-                {
-                    let key = extract_key(&rel_def.to_col, &value);
-                    acc.insert(key, value);
-                }
+        Ok(group_one(&rel_def, self, &keys, keyed(&rel_def, data)))
+    }
 
-                acc
-            },
-        );
+    async fn load_one_with<R, S, F, C>(
+        &self,
+        stmt: S,
+        query: F,
+        db: &C,
+    ) -> Result<Vec<Option<R::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        F: FnOnce(LoadSelect<R>) -> LoadSelect<R> + Send,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let rel_def = has_one_def::<M, R>()?;
 
-        let result: Vec<Option<<R as EntityTrait>::Model>> =
-            keys.iter().map(|key| hashmap.get(key).cloned()).collect();
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
 
-        Ok(result)
+        let keys: Vec<ValueTuple> = self
+            .iter()
+            .map(|model: &M| extract_key(&rel_def.from_col, model))
+            .collect();
+
+        let condition = related_condition(&rel_def, self, &keys);
+
+        let stmt = query(LoadSelect::new(stmt.select())).into_select(&rel_def, condition);
+
+        let data = stmt.all(db).await?;
+
+        Ok(group_one(&rel_def, self, &keys, keyed(&rel_def, data)))
     }
 
     async fn load_many<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
         R: EntityTrait,
         R::Model: Send + Sync,
         S: EntityOrSelect<R>,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
     {
-        // we verify that is HasMany relation
+        let rel_def = has_many_def::<M, R>()?;
 
-        if <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::via().is_some() {
-            return Err(query_err("Relation is ManyToMany instead of HasMany"));
-        }
-        let rel_def = <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::to();
-        if rel_def.rel_type == RelationType::HasOne {
-            return Err(query_err("Relation is HasOne instead of HasMany"));
+        if self.is_empty() {
+            return Ok(Vec::new());
         }
 
+        let keys: Vec<ValueTuple> = self
+            .iter()
+            .map(|model: &M| extract_key(&rel_def.from_col, model))
+            .collect();
+
+        let condition = related_condition(&rel_def, self, &keys);
+
+        let stmt = <Select<R> as QueryFilter>::filter(stmt.select(), condition);
+
+        let data = stmt.all(db).await?;
+
+        Ok(group_many(&rel_def, self, &keys, keyed(&rel_def, data)))
+    }
+
+    async fn load_many_with<R, S, F, C>(
+        &self,
+        stmt: S,
+        query: F,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        F: FnOnce(LoadSelect<R>) -> LoadSelect<R> + Send,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let rel_def = has_many_def::<M, R>()?;
+
         if self.is_empty() {
             return Ok(Vec::new());
         }
@@ -203,36 +666,65 @@ where
             .map(|model: &M| extract_key(&rel_def.from_col, model))
             .collect();
 
-        let condition = prepare_condition(&rel_def.to_tbl, &rel_def.to_col, &keys);
+        let condition = related_condition(&rel_def, self, &keys);
 
-        let stmt = <Select<R> as QueryFilter>::filter(stmt.select(), condition);
+        let stmt = query(LoadSelect::new(stmt.select())).into_select(&rel_def, condition);
 
         let data = stmt.all(db).await?;
 
-        let mut hashmap: HashMap<ValueTuple, Vec<<R as EntityTrait>::Model>> =
-            keys.iter()
-                .fold(HashMap::new(), |mut acc, key: &ValueTuple| {
-                    acc.insert(key.clone(), Vec::new());
-                    acc
-                });
+        Ok(group_many(&rel_def, self, &keys, keyed(&rel_def, data)))
+    }
 
-        data.into_iter()
-            .for_each(|value: <R as EntityTrait>::Model| {
-                let key = extract_key(&rel_def.to_col, &value);
+    async fn load_one_partial<P, R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Option<P>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        P: PartialModelTrait + Clone + Send,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let rel_def = has_one_def::<M, R>()?;
 
-                let vec = hashmap
-                    .get_mut(&key)
-                    .expect("Failed at finding key on hashmap");
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
 
-                vec.push(value);
-            });
+        let keys: Vec<ValueTuple> = self
+            .iter()
+            .map(|model: &M| extract_key(&rel_def.from_col, model))
+            .collect();
+
+        let condition = related_condition(&rel_def, self, &keys);
 
-        let result: Vec<Vec<R::Model>> = keys
+        let data = load_partial::<P, R, C>(stmt.select(), &rel_def, condition, &keys, db).await?;
+
+        Ok(group_one(&rel_def, self, &keys, data))
+    }
+
+    async fn load_many_partial<P, R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<P>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        P: PartialModelTrait + Clone + Send,
+        R: EntityTrait,
+        S: EntityOrSelect<R>,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let rel_def = has_many_def::<M, R>()?;
+
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let keys: Vec<ValueTuple> = self
             .iter()
-            .map(|key: &ValueTuple| hashmap.get(key).cloned().unwrap_or_default())
+            .map(|model: &M| extract_key(&rel_def.from_col, model))
             .collect();
 
-        Ok(result)
+        let condition = related_condition(&rel_def, self, &keys);
+
+        let data = load_partial::<P, R, C>(stmt.select(), &rel_def, condition, &keys, db).await?;
+
+        Ok(group_many(&rel_def, self, &keys, data))
     }
 
     async fn load_many_to_many<R, S, V, C>(
@@ -242,7 +734,30 @@ where
         db: &C,
     ) -> Result<Vec<Vec<R::Model>>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        S: EntityOrSelect<R>,
+        V: EntityTrait,
+        V::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let result = self.load_many_to_many_with_via(stmt, via, db).await?;
+
+        Ok(result
+            .into_iter()
+            .map(|pairs| pairs.into_iter().map(|(_, model)| model).collect())
+            .collect())
+    }
+
+    async fn load_many_to_many_with_via<R, S, V, C>(
+        &self,
+        stmt: S,
+        via: V,
+        db: &C,
+    ) -> Result<Vec<Vec<(V::Model, R::Model)>>, DbErr>
+    where
+        C: ReadConnectionTrait,
         R: EntityTrait,
         R::Model: Send + Sync,
         S: EntityOrSelect<R>,
@@ -276,58 +791,355 @@ where
                 .map(|model: &M| extract_key(&via_rel.from_col, model))
                 .collect();
 
-            // Map of M::PK -> Vec<R::PK>
-            let mut keymap: HashMap<ValueTuple, Vec<ValueTuple>> = Default::default();
+            // Map of M::PK -> Vec<V::Model>
+            let mut keymap: HashMap<ValueTuple, Vec<V::Model>> = Default::default();
+
+            let keys: Vec<ValueTuple> = {
+                let condition = prepare_condition(&via_rel.to_tbl, &via_rel.to_col, &pkeys);
+                let stmt = V::find().filter(condition);
+                let data = stmt.all(db).await?;
+                data.into_iter().for_each(|model| {
+                    let pk = extract_key(&via_rel.to_col, &model);
+                    keymap.entry(pk).or_default().push(model);
+                });
+
+                keymap
+                    .values()
+                    .flatten()
+                    .map(|model| extract_key(&rel_def.from_col, model))
+                    .collect()
+            };
+
+            let condition = prepare_condition(&rel_def.to_tbl, &rel_def.to_col, &keys);
+
+            let stmt = <Select<R> as QueryFilter>::filter(stmt.select(), condition);
+
+            let data = stmt.all(db).await?;
+
+            // Map of R::PK -> R::Model
+            let data: HashMap<ValueTuple, <R as EntityTrait>::Model> = data
+                .into_iter()
+                .map(|model| {
+                    let key = extract_key(&rel_def.to_col, &model);
+                    (key, model)
+                })
+                .collect();
+
+            let result: Vec<Vec<(V::Model, R::Model)>> = pkeys
+                .into_iter()
+                .map(|pkey| {
+                    let vias = keymap.get(&pkey).cloned().unwrap_or_default();
+
+                    let models: Vec<_> = vias
+                        .into_iter()
+                        .filter_map(|via| {
+                            let fkey = extract_key(&rel_def.from_col, &via);
+                            data.get(&fkey).cloned().map(|model| (via, model))
+                        })
+                        .collect();
+
+                    models
+                })
+                .collect();
+
+            Ok(result)
+        } else {
+            return Err(query_err("Relation is not ManyToMany"));
+        }
+    }
+
+    async fn load_linked<L, C>(
+        &self,
+        linked: L,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        L: Linked<FromEntity = <Self::Model as ModelTrait>::Entity> + Send,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync,
+    {
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let Some(rel_def) = linked.link().into_iter().next() else {
+            return Err(query_err("Link is empty"));
+        };
+
+        let keys: Vec<ValueTuple> = self
+            .iter()
+            .map(|model| extract_key(&rel_def.from_col, model))
+            .collect();
+
+        let condition = prepare_condition(&rel_def.from_tbl, &rel_def.from_col, &keys);
+
+        let rows = <Self::Model as ModelTrait>::Entity::find()
+            .filter(condition)
+            .find_also_linked(linked)
+            .all(db)
+            .await?;
+
+        let mut hashmap: HashMap<ValueTuple, Vec<<L::ToEntity as EntityTrait>::Model>> =
+            HashMap::new();
+        for (model, related) in rows {
+            if let Some(related) = related {
+                hashmap
+                    .entry(extract_key(&rel_def.from_col, &model))
+                    .or_default()
+                    .push(related);
+            }
+        }
+
+        Ok(keys
+            .iter()
+            .map(|key| hashmap.get(key).cloned().unwrap_or_default())
+            .collect())
+    }
+
+    async fn load_morph<T, C>(&self, db: &C) -> Result<Vec<Option<T>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        T: MorphTo<Self::Model>,
+        Self::Model: Sync,
+    {
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        T::load(self, db).await
+    }
+
+    #[cfg(feature = "with-json")]
+    async fn load_graph<G, C>(&self, graph: G, db: &C) -> Result<Vec<Loaded<Self::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        G: LoadGraph<Self::Model>,
+        Self::Model: Serialize + Sync + 'static,
+    {
+        graph.attach(Loaded::all(self.to_vec()), db).await
+    }
+}
 
-            let keys: Vec<ValueTuple> = {
-                let condition = prepare_condition(&via_rel.to_tbl, &via_rel.to_col, &pkeys);
-                let stmt = V::find().filter(condition);
-                let data = stmt.all(db).await?;
-                data.into_iter().for_each(|model| {
-                    let pk = extract_key(&via_rel.to_col, &model);
-                    let entry = keymap.entry(pk).or_default();
+/// The relation to a `has_one` or `belongs_to` related entity
+fn has_one_def<M, R>() -> Result<RelationDef, DbErr>
+where
+    M: ModelTrait,
+    R: EntityTrait,
+    M::Entity: Related<R>,
+{
+    // we verify that is HasOne relation
+    if <M::Entity as Related<R>>::via().is_some() {
+        return Err(query_err("Relation is ManytoMany instead of HasOne"));
+    }
+    let rel_def = <M::Entity as Related<R>>::to();
+    if rel_def.rel_type == RelationType::HasMany {
+        return Err(query_err("Relation is HasMany instead of HasOne"));
+    }
+    Ok(rel_def)
+}
 
-                    let fk = extract_key(&rel_def.from_col, &model);
-                    entry.push(fk);
-                });
+/// The relation to a `has_many` related entity
+fn has_many_def<M, R>() -> Result<RelationDef, DbErr>
+where
+    M: ModelTrait,
+    R: EntityTrait,
+    M::Entity: Related<R>,
+{
+    // we verify that is HasMany relation
+    if <M::Entity as Related<R>>::via().is_some() {
+        return Err(query_err("Relation is ManyToMany instead of HasMany"));
+    }
+    let rel_def = <M::Entity as Related<R>>::to();
+    if rel_def.rel_type == RelationType::HasOne {
+        return Err(query_err("Relation is HasOne instead of HasMany"));
+    }
+    Ok(rel_def)
+}
 
-                keymap.values().flatten().cloned().collect()
-            };
+/// The condition on the related models of the models with the keys. For a polymorphic relation
+/// it only matches the related models of the type of the relation, and skips the keys of the
+/// models of other types.
+fn related_condition<M>(rel_def: &RelationDef, models: &[M], keys: &[ValueTuple]) -> Condition
+where
+    M: ModelTrait,
+{
+    let keys: Vec<ValueTuple> = keys
+        .iter()
+        .zip(models)
+        .filter(|(_, model)| morph_matches(rel_def, *model))
+        .map(|(key, _)| key.clone())
+        .collect();
+    let condition = prepare_condition(&rel_def.to_tbl, &rel_def.to_col, &keys);
+    match &rel_def.morph {
+        Some(morph) if !morph.in_from_tbl => condition
+            .add(Expr::col(table_column(&rel_def.to_tbl, &morph.col)).eq(morph.value.clone())),
+        _ => condition,
+    }
+}
 
-            let condition = prepare_condition(&rel_def.to_tbl, &rel_def.to_col, &keys);
+/// Whether a model is of the type of a polymorphic relation with the type column on its side;
+/// always true for other relations
+fn morph_matches<Model>(rel_def: &RelationDef, model: &Model) -> bool
+where
+    Model: ModelTrait,
+{
+    match &rel_def.morph {
+        Some(morph) if morph.in_from_tbl => {
+            let col =
+                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
+                    &morph.col.to_string(),
+                )
+                .unwrap_or_else(|_| panic!("Failed at mapping string to column"));
+            model.get(col) == morph.value
+        }
+        _ => true,
+    }
+}
 
-            let stmt = <Select<R> as QueryFilter>::filter(stmt.select(), condition);
+/// The related models keyed by the columns they are matched by
+fn keyed<Model>(rel_def: &RelationDef, data: Vec<Model>) -> Vec<(ValueTuple, Model)>
+where
+    Model: ModelTrait,
+{
+    data.into_iter()
+        .map(|model| (extract_key(&rel_def.to_col, &model), model))
+        .collect()
+}
 
-            let data = stmt.all(db).await?;
+/// The related model of each key
+fn group_one<M, T>(
+    rel_def: &RelationDef,
+    models: &[M],
+    keys: &[ValueTuple],
+    data: Vec<(ValueTuple, T)>,
+) -> Vec<Option<T>>
+where
+    M: ModelTrait,
+    T: Clone,
+{
+    let hashmap: HashMap<ValueTuple, T> = data.into_iter().collect();
+
+    keys.iter()
+        .zip(models)
+        .map(|(key, model)| {
+            if morph_matches(rel_def, model) {
+                hashmap.get(key).cloned()
+            } else {
+                None
+            }
+        })
+        .collect()
+}
 
-            // Map of R::PK -> R::Model
-            let data: HashMap<ValueTuple, <R as EntityTrait>::Model> = data
-                .into_iter()
-                .map(|model| {
-                    let key = extract_key(&rel_def.to_col, &model);
-                    (key, model)
-                })
-                .collect();
+/// The related models of each key
+fn group_many<M, T>(
+    rel_def: &RelationDef,
+    models: &[M],
+    keys: &[ValueTuple],
+    data: Vec<(ValueTuple, T)>,
+) -> Vec<Vec<T>>
+where
+    M: ModelTrait,
+    T: Clone,
+{
+    let mut hashmap: HashMap<ValueTuple, Vec<T>> =
+        keys.iter()
+            .fold(HashMap::new(), |mut acc, key: &ValueTuple| {
+                acc.insert(key.clone(), Vec::new());
+                acc
+            });
 
-            let result: Vec<Vec<R::Model>> = pkeys
-                .into_iter()
-                .map(|pkey| {
-                    let fkeys = keymap.get(&pkey).cloned().unwrap_or_default();
+    data.into_iter().for_each(|(key, value)| {
+        let vec = hashmap
+            .get_mut(&key)
+            .expect("Failed at finding key on hashmap");
+
+        vec.push(value);
+    });
+
+    keys.iter()
+        .zip(models)
+        .map(|(key, model)| {
+            if morph_matches(rel_def, model) {
+                hashmap.get(key).cloned().unwrap_or_default()
+            } else {
+                Vec::new()
+            }
+        })
+        .collect()
+}
 
-                    let models: Vec<_> = fkeys
-                        .into_iter()
-                        .filter_map(|fkey| data.get(&fkey).cloned())
-                        .collect();
+/// Select the columns of a partial model of the related entity, along with the columns the
+/// related models are matched by, and read them keyed by the latter. The key columns are read
+/// as values of the same types as the keys of the models.
+async fn load_partial<P, R, C>(
+    select: Select<R>,
+    rel_def: &RelationDef,
+    condition: Condition,
+    keys: &[ValueTuple],
+    db: &C,
+) -> Result<Vec<(ValueTuple, P)>, DbErr>
+where
+    C: ReadConnectionTrait,
+    P: PartialModelTrait,
+    R: EntityTrait,
+{
+    let mut select = P::select_cols(QuerySelect::select_only(select.filter(condition)));
+    for (i, col) in rel_def.to_col.clone().into_iter().enumerate() {
+        select = select.expr_as(
+            Expr::col(table_column(&rel_def.to_tbl, &col)),
+            format!("{PARTIAL_KEY}{i}"),
+        );
+    }
 
-                    models
-                })
-                .collect();
+    let hints: Vec<Value> = keys
+        .first()
+        .cloned()
+        .map(|key| key.into_iter().collect())
+        .unwrap_or_default();
+
+    let backend = db.read_backend();
+    let rows = db.read_all(backend.build(&select.query)).await?;
+    rows.iter()
+        .map(|row| {
+            let key = hints
+                .iter()
+                .enumerate()
+                .map(|(i, hint)| try_get_as(row, &format!("{PARTIAL_KEY}{i}"), hint))
+                .collect::<Result<Vec<_>, _>>()?;
+            let key = match key.len() {
+                1 => ValueTuple::One(key[0].clone()),
+                2 => ValueTuple::Two(key[0].clone(), key[1].clone()),
+                3 => ValueTuple::Three(key[0].clone(), key[1].clone(), key[2].clone()),
+                _ => ValueTuple::Many(key),
+            };
+            Ok((key, P::from_query_result(row, "")?))
+        })
+        .collect()
+}
 
-            Ok(result)
-        } else {
-            return Err(query_err("Relation is not ManyToMany"));
+/// Read a column as a value of the same type as `hint`
+fn try_get_as(row: &QueryResult, col: &str, hint: &Value) -> Result<Value, DbErr> {
+    Ok(match hint {
+        Value::Bool(_) => Value::Bool(row.try_get("", col)?),
+        Value::TinyInt(_) => Value::TinyInt(row.try_get("", col)?),
+        Value::SmallInt(_) => Value::SmallInt(row.try_get("", col)?),
+        Value::Int(_) => Value::Int(row.try_get("", col)?),
+        Value::BigInt(_) => Value::BigInt(row.try_get("", col)?),
+        Value::TinyUnsigned(_) => Value::TinyUnsigned(row.try_get("", col)?),
+        Value::SmallUnsigned(_) => Value::SmallUnsigned(row.try_get("", col)?),
+        Value::Unsigned(_) => Value::Unsigned(row.try_get("", col)?),
+        Value::BigUnsigned(_) => Value::BigUnsigned(row.try_get("", col)?),
+        Value::String(_) => Value::String(row.try_get::<Option<String>>("", col)?.map(Box::new)),
+        Value::Bytes(_) => Value::Bytes(row.try_get::<Option<Vec<u8>>>("", col)?.map(Box::new)),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(_) => Value::Uuid(row.try_get::<Option<uuid::Uuid>>("", col)?.map(Box::new)),
+        _ => {
+            return Err(query_err(format!(
+                "Unsupported type of key column {col} for a partial model: {hint:?}"
+            )))
         }
-    }
+    })
 }
 
 fn cmp_table_ref(left: &TableRef, right: &TableRef) -> bool {
@@ -605,6 +1417,154 @@ mod tests {
         assert_eq!(fruits, empty_vec);
     }
 
+    #[tokio::test]
+    async fn test_load_one_with() {
+        use sea_orm::{entity::prelude::*, tests_cfg::*, DbBackend, LoaderTrait, MockDatabase};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake_model(1)]])
+            .into_connection();
+
+        let fruits = vec![fruit_model(1, Some(1)), fruit_model(2, Some(2))];
+
+        let cakes = fruits
+            .load_one_with(
+                cake::Entity,
+                |query| query.filter(cake::Column::Name.contains("cake")),
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(cakes, [Some(cake_model(1)), None]);
+        assert_eq!(
+            db.into_transaction_log()[0].statements()[0].to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"WHERE "cake"."name" LIKE '%cake%' AND "cake"."id" IN (1, 2)"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_many_with() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTrait, MockDatabase, QueryOrder,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                fruit_model(3, Some(1)),
+                fruit_model(4, Some(2)),
+                fruit_model(2, Some(1)),
+            ]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1), cake_model(2), cake_model(3)];
+
+        let fruits = cakes
+            .load_many_with(
+                fruit::Entity,
+                |query| {
+                    query
+                        .filter(fruit::Column::Name.ne("Cherry"))
+                        .order_by_desc(fruit::Column::Id)
+                        .limit(2)
+                },
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fruits,
+            [
+                vec![fruit_model(3, Some(1)), fruit_model(2, Some(1))],
+                vec![fruit_model(4, Some(2))],
+                vec![],
+            ]
+        );
+        assert_eq!(
+            db.into_transaction_log()[0].statements()[0].to_string(),
+            [
+                r#"SELECT "fruit".* FROM (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id","#,
+                r#"ROW_NUMBER() OVER ( PARTITION BY "fruit"."cake_id" ORDER BY "fruit"."id" DESC ) AS "row_number""#,
+                r#"FROM "fruit" WHERE "fruit"."name" <> 'Cherry' AND "fruit"."cake_id" IN (1, 2, 3)) AS "fruit""#,
+                r#"WHERE "fruit"."row_number" > 0 AND "fruit"."row_number" <= 2 ORDER BY "fruit"."row_number" ASC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_one_partial() {
+        use sea_orm::{
+            tests_cfg::*, DbBackend, DerivePartialModel, FromQueryResult, LoaderTrait,
+            MockDatabase, Transaction, Value,
+        };
+
+        #[derive(Clone, Debug, PartialEq, DerivePartialModel, FromQueryResult)]
+        #[sea_orm(entity = "cake::Entity")]
+        struct CakeName {
+            name: String,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                maplit::btreemap! {
+                    "name" => Value::from("apple cake"),
+                    "sea_orm_key_0" => Value::from(1),
+                },
+                maplit::btreemap! {
+                    "name" => Value::from("orange cake"),
+                    "sea_orm_key_0" => Value::from(2),
+                },
+            ]])
+            .into_connection();
+
+        let fruits = vec![
+            fruit_model(1, Some(1)),
+            fruit_model(2, None),
+            fruit_model(3, Some(2)),
+            fruit_model(4, Some(1)),
+        ];
+
+        let cakes: Vec<Option<CakeName>> = fruits
+            .load_one_partial(cake::Entity, &db)
+            .await
+            .expect("Should return something");
+
+        let cake_name = |name: &str| {
+            Some(CakeName {
+                name: name.to_owned(),
+            })
+        };
+        assert_eq!(
+            cakes,
+            [
+                cake_name("apple cake"),
+                None,
+                cake_name("orange cake"),
+                cake_name("apple cake"),
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."name" AS "name", "cake"."id" AS "sea_orm_key_0""#,
+                    r#"FROM "cake" WHERE "cake"."id" IN ($1, $2, $3, $4)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into(), Value::Int(None), 2i32.into(), 1i32.into()]
+            )]
+        );
+    }
+
     #[tokio::test]
     async fn test_load_many_to_many_base() {
         use sea_orm::{tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase};
@@ -666,6 +1626,108 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_load_many_to_many_with_via() {
+        use sea_orm::{tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                cake_filling_model(1, 1).into_mock_row(),
+                cake_filling_model(1, 2).into_mock_row(),
+                cake_filling_model(2, 2).into_mock_row(),
+                cake_filling_model(2, 4).into_mock_row(),
+            ]])
+            .append_query_results([[filling_model(1), filling_model(2)]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1), cake_model(2), cake_model(3)];
+
+        let fillings = cakes
+            .load_many_to_many_with_via(Filling, CakeFilling, &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fillings,
+            vec![
+                vec![
+                    (cake_filling_model(1, 1), filling_model(1)),
+                    (cake_filling_model(1, 2), filling_model(2)),
+                ],
+                vec![(cake_filling_model(2, 2), filling_model(2))],
+                vec![],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_linked() {
+        use sea_orm::{
+            tests_cfg::*, DbBackend, DeriveLinked, IntoMockRow, LoaderTrait, MockDatabase,
+            Transaction,
+        };
+
+        #[derive(Debug, DeriveLinked)]
+        #[sea_orm(
+            from = "cake::Entity",
+            to = "vendor::Entity",
+            through = "cake_filling::Relation::Cake",
+            through = "cake_filling::Relation::Filling",
+            through = "filling::Relation::Vendor"
+        )]
+        struct CakeToVendor;
+
+        let vendor_model = |id: i32| vendor::Model {
+            id,
+            name: format!("vendor {id}"),
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                (cake_model(1), Some(vendor_model(1))).into_mock_row(),
+                (cake_model(1), Some(vendor_model(2))).into_mock_row(),
+                (cake_model(2), Some(vendor_model(1))).into_mock_row(),
+                (cake_model(3), None::<vendor::Model>).into_mock_row(),
+            ]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1), cake_model(2), cake_model(3), cake_model(4)];
+
+        let vendors = cakes
+            .load_linked(CakeToVendor, &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            vendors,
+            vec![
+                vec![vendor_model(1), vendor_model(2)],
+                vec![vendor_model(1)],
+                vec![],
+                vec![],
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                    r#""r2"."id" AS "B_id", "r2"."name" AS "B_name""#,
+                    r#"FROM "cake""#,
+                    r#"LEFT JOIN "cake_filling" AS "r0" ON "cake"."id" = "r0"."cake_id""#,
+                    r#"LEFT JOIN "filling" AS "r1" ON "r0"."filling_id" = "r1"."id""#,
+                    r#"LEFT JOIN "vendor" AS "r2" ON "r1"."vendor_id" = "r2"."id""#,
+                    r#"WHERE "cake"."id" IN ($1, $2, $3, $4)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into(), 2i32.into(), 3i32.into(), 4i32.into()]
+            )]
+        );
+    }
+
     #[tokio::test]
     async fn test_load_many_to_many_empty() {
         use sea_orm::{tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase};
@@ -688,4 +1750,214 @@ mod tests {
 
         assert_eq!(fillings, empty_vec);
     }
+
+    mod post {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub title: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(has_many = "super::comment::Entity")]
+            Comment,
+        }
+
+        impl Related<super::comment::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Comment.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod comment {
+        use crate as sea_orm;
+        use crate::{entity::prelude::*, tests_cfg::cake, DeriveMorphTo};
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "comment")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub commentable_type: String,
+            pub commentable_id: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::post::Entity",
+                from = "Column::CommentableId",
+                to = "super::post::Column::Id",
+                morph_type = "Column::CommentableType",
+                morph_value = "post"
+            )]
+            Post,
+            #[sea_orm(
+                belongs_to = "cake::Entity",
+                from = "Column::CommentableId",
+                to = "cake::Column::Id",
+                morph_type = "Column::CommentableType",
+                morph_value = "cake"
+            )]
+            Cake,
+        }
+
+        impl Related<super::post::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Post.def()
+            }
+        }
+
+        impl Related<cake::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Cake.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+
+        #[derive(Clone, Debug, PartialEq, DeriveMorphTo)]
+        pub enum Commentable {
+            Post(super::post::Model),
+            Cake(cake::Model),
+        }
+    }
+
+    fn comment_model(id: i32, commentable_type: &str, commentable_id: i32) -> comment::Model {
+        comment::Model {
+            id,
+            commentable_type: commentable_type.to_owned(),
+            commentable_id,
+        }
+    }
+
+    #[test]
+    fn test_morph_relation() {
+        use crate::{tests_cfg::cake, DbBackend, EntityTrait, QueryTrait, Schema};
+
+        assert_eq!(
+            comment::Entity::find()
+                .find_also_related(cake::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "comment"."id" AS "A_id", "comment"."commentable_type" AS "A_commentable_type","#,
+                r#""comment"."commentable_id" AS "A_commentable_id", "cake"."id" AS "B_id", "cake"."name" AS "B_name""#,
+                r#"FROM "comment" LEFT JOIN "cake" ON "comment"."commentable_id" = "cake"."id""#,
+                r#"AND "comment"."commentable_type" = 'cake'"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            post::Entity::find()
+                .find_with_related(comment::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "post"."id" AS "A_id", "post"."title" AS "A_title","#,
+                r#""comment"."id" AS "B_id", "comment"."commentable_type" AS "B_commentable_type","#,
+                r#""comment"."commentable_id" AS "B_commentable_id""#,
+                r#"FROM "post" LEFT JOIN "comment" ON "post"."id" = "comment"."commentable_id""#,
+                r#"AND "comment"."commentable_type" = 'post' ORDER BY "post"."id" ASC"#,
+            ]
+            .join(" ")
+        );
+
+        let create = Schema::new(DbBackend::Postgres).create_table_from_entity(comment::Entity);
+        assert!(!DbBackend::Postgres
+            .build(&create)
+            .to_string()
+            .contains("FOREIGN KEY"));
+    }
+
+    #[tokio::test]
+    async fn test_load_morph() {
+        use crate::{tests_cfg::cake, DbBackend, LoaderTrait, MockDatabase};
+        use comment::Commentable;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[post::Model {
+                id: 1,
+                title: "Hello".to_owned(),
+            }]])
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .into_connection();
+
+        let comments = vec![
+            comment_model(1, "post", 1),
+            comment_model(2, "cake", 1),
+            comment_model(3, "cake", 2),
+        ];
+
+        let commentables: Vec<Option<Commentable>> = comments
+            .load_morph(&db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            commentables,
+            [
+                Some(Commentable::Post(post::Model {
+                    id: 1,
+                    title: "Hello".to_owned(),
+                })),
+                Some(Commentable::Cake(cake::Model {
+                    id: 1,
+                    name: "Cheese Cake".to_owned(),
+                })),
+                None,
+            ]
+        );
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log[0].statements()[0].to_string(),
+            r#"SELECT "post"."id", "post"."title" FROM "post" WHERE "post"."id" IN (1)"#
+        );
+        assert_eq!(
+            log[1].statements()[0].to_string(),
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN (1, 2)"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_many_morph() {
+        use crate::{DbBackend, LoaderTrait, MockDatabase};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[comment_model(1, "post", 2)]])
+            .into_connection();
+
+        let posts = vec![post::Model {
+            id: 2,
+            title: "Hello".to_owned(),
+        }];
+
+        let comments = posts
+            .load_many(comment::Entity, &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(comments, [vec![comment_model(1, "post", 2)]]);
+        assert_eq!(
+            db.into_transaction_log()[0].statements()[0].to_string(),
+            [
+                r#"SELECT "comment"."id", "comment"."commentable_type", "comment"."commentable_id""#,
+                r#"FROM "comment" WHERE "comment"."commentable_id" IN (2)"#,
+                r#"AND "comment"."commentable_type" = 'post'"#,
+            ]
+            .join(" ")
+        );
+    }
 }
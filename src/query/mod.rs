@@ -1,27 +1,68 @@
+mod case_insensitive;
 pub(crate) mod combine;
 mod delete;
+mod derived;
+mod full_text_search;
+mod geo;
 mod helper;
 mod insert;
 mod join;
 #[cfg(feature = "with-json")]
 mod json;
+#[cfg(feature = "with-json")]
+mod json_filter;
+#[cfg(feature = "with-json")]
+mod load_graph;
+#[cfg(feature = "with-json")]
+mod loaded;
 mod loader;
+#[cfg(feature = "with-json")]
+mod query_def;
+mod recursive;
+#[cfg(feature = "with-json")]
+mod related_json;
+#[cfg(feature = "with-json")]
+mod rsql;
+mod sample;
 mod select;
 mod traits;
+mod truncate;
 mod update;
+mod upsert;
 mod util;
+mod window;
 
+pub use case_insensitive::*;
 pub use combine::{SelectA, SelectB, SelectC};
 pub use delete::*;
+pub use derived::*;
+pub use full_text_search::*;
+pub use geo::*;
 pub use helper::*;
 pub use insert::*;
 #[cfg(feature = "with-json")]
 pub use json::*;
+#[cfg(feature = "with-json")]
+pub use json_filter::*;
+#[cfg(feature = "with-json")]
+pub use load_graph::*;
+#[cfg(feature = "with-json")]
+pub use loaded::*;
 pub use loader::*;
+#[cfg(feature = "with-json")]
+pub use query_def::*;
+pub use recursive::*;
+#[cfg(feature = "with-json")]
+pub use related_json::*;
+#[cfg(feature = "with-json")]
+pub use rsql::*;
 pub use select::*;
 pub use traits::*;
+pub use truncate::*;
 pub use update::*;
+pub use upsert::*;
 pub use util::*;
+pub use window::*;
 
 pub use crate::{
     ConnectionTrait, CursorTrait, InsertResult, PaginatorTrait, Statement, StreamTrait,
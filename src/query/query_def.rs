@@ -0,0 +1,722 @@
+use crate::{
+    ColumnTrait, ColumnType, DbErr, EntityName, EntityTrait, IdenStatic, Iterable, JsonValue,
+    Order, QueryFilter, QueryOrder, QuerySelect, Select, Value,
+};
+use sea_query::{Condition, Nullable};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Map};
+use std::{fmt::Display, str::FromStr};
+
+/// A query on an entity that can be stored as JSON, and turned back into a [Select] later.
+///
+/// The JSON is an object with the `entity` (its table name), and optionally a `filter`, an
+/// `order_by` list of `{"column": .., "order": "asc" | "desc"}` objects, a `limit` and an
+/// `offset`. Column names and values are validated against the entity when the query is
+/// rebuilt, so a stored query can't select from another table or compare a column with a
+/// value of the wrong type.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, FilterDef, FilterOp, QueryDef};
+///
+/// let query = QueryDef::new(cake::Entity)
+///     .filter(FilterDef::column(cake::Column::Name, FilterOp::Like, "%cheese%"))
+///     .order_by(cake::Column::Id, Order::Desc)
+///     .limit(10);
+///
+/// let json = serde_json::to_string(&query).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"entity":"cake","filter":{"column":"name","op":"like","value":"%cheese%"},"limit":10,"order_by":[{"column":"id","order":"desc"}]}"#
+/// );
+///
+/// let query: QueryDef = serde_json::from_str(&json).unwrap();
+/// assert_eq!(
+///     query
+///         .to_select::<cake::Entity>()
+///         .unwrap()
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+///         r#"WHERE "cake"."name" LIKE '%cheese%'"#,
+///         r#"ORDER BY "cake"."id" DESC LIMIT 10"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryDef {
+    /// The table name of the entity
+    pub entity: String,
+    /// The filter of the query
+    pub filter: Option<FilterDef>,
+    /// The column names to order by, in order
+    pub order_by: Vec<(String, Order)>,
+    /// The maximum number of rows
+    pub limit: Option<u64>,
+    /// The number of rows to skip
+    pub offset: Option<u64>,
+}
+
+/// A filter of a [QueryDef], which is turned into a [Condition] on an entity
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterDef {
+    /// All of the filters hold, `{"all": [..]}`
+    All(Vec<FilterDef>),
+    /// Any of the filters holds, `{"any": [..]}`
+    Any(Vec<FilterDef>),
+    /// The filter does not hold, `{"not": ..}`
+    Not(Box<FilterDef>),
+    /// A column compared with a value, `{"column": .., "op": .., "value": ..}`. The value is
+    /// left out for [FilterOp::IsNull] and [FilterOp::IsNotNull], and is an array for
+    /// [FilterOp::In] and [FilterOp::NotIn].
+    Column {
+        /// The column name
+        column: String,
+        /// The comparison
+        op: FilterOp,
+        /// The value to compare with
+        value: JsonValue,
+    },
+}
+
+/// The comparison of a column in a [FilterDef]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum FilterOp {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Like,
+    NotLike,
+    In,
+    NotIn,
+    IsNull,
+    IsNotNull,
+}
+
+impl FilterOp {
+    const ALL: [Self; 12] = [
+        Self::Eq,
+        Self::Ne,
+        Self::Gt,
+        Self::Gte,
+        Self::Lt,
+        Self::Lte,
+        Self::Like,
+        Self::NotLike,
+        Self::In,
+        Self::NotIn,
+        Self::IsNull,
+        Self::IsNotNull,
+    ];
+
+    /// The name of the comparison in JSON
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Eq => "eq",
+            Self::Ne => "ne",
+            Self::Gt => "gt",
+            Self::Gte => "gte",
+            Self::Lt => "lt",
+            Self::Lte => "lte",
+            Self::Like => "like",
+            Self::NotLike => "not_like",
+            Self::In => "in",
+            Self::NotIn => "not_in",
+            Self::IsNull => "is_null",
+            Self::IsNotNull => "is_not_null",
+        }
+    }
+
+    fn has_value(&self) -> bool {
+        !matches!(self, Self::IsNull | Self::IsNotNull)
+    }
+}
+
+impl FromStr for FilterOp {
+    type Err = DbErr;
+
+    fn from_str(s: &str) -> Result<Self, DbErr> {
+        Self::ALL
+            .into_iter()
+            .find(|op| op.as_str() == s)
+            .ok_or_else(|| DbErr::Json(format!("Unknown filter op `{s}`")))
+    }
+}
+
+impl QueryDef {
+    /// Create a query selecting all rows of an entity
+    pub fn new<E>(entity: E) -> Self
+    where
+        E: EntityName,
+    {
+        Self {
+            entity: entity.table_name().to_owned(),
+            filter: None,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// Add a filter, which must hold together with the filters added before
+    pub fn filter(mut self, filter: FilterDef) -> Self {
+        self.filter = Some(match self.filter.take() {
+            None => filter,
+            Some(FilterDef::All(mut filters)) => {
+                filters.push(filter);
+                FilterDef::All(filters)
+            }
+            Some(existing) => FilterDef::All(vec![existing, filter]),
+        });
+        self
+    }
+
+    /// Order by a column, after the columns added before
+    pub fn order_by<C>(mut self, column: C, order: Order) -> Self
+    where
+        C: ColumnTrait,
+    {
+        self.order_by.push((column.as_str().to_owned(), order));
+        self
+    }
+
+    /// Limit the number of rows
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skip a number of rows
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Build the query on the entity it was defined on.
+    ///
+    /// Fails if the query is defined on another entity, or a column or value is invalid.
+    pub fn to_select<E>(&self) -> Result<Select<E>, DbErr>
+    where
+        E: EntityTrait,
+    {
+        let entity = E::default();
+        let table_name = entity.table_name();
+        if self.entity != table_name {
+            return Err(DbErr::Custom(format!(
+                "Query is defined on `{}`, not `{table_name}`",
+                self.entity
+            )));
+        }
+        let mut select = E::find();
+        if let Some(filter) = &self.filter {
+            select = select.filter(filter.to_condition::<E>()?);
+        }
+        for (column, order) in self.order_by.iter() {
+            select = select.order_by(find_column::<E>(column)?, order.clone());
+        }
+        if let Some(limit) = self.limit {
+            select = select.limit(limit);
+        }
+        if let Some(offset) = self.offset {
+            select = select.offset(offset);
+        }
+        Ok(select)
+    }
+
+    /// The JSON of the query
+    pub fn to_json(&self) -> Result<JsonValue, DbErr> {
+        let mut map = Map::new();
+        map.insert("entity".to_owned(), json!(self.entity));
+        if let Some(filter) = &self.filter {
+            map.insert("filter".to_owned(), filter.to_json());
+        }
+        if !self.order_by.is_empty() {
+            let order_by = self
+                .order_by
+                .iter()
+                .map(|(column, order)| {
+                    let order = match order {
+                        Order::Asc => "asc",
+                        Order::Desc => "desc",
+                        Order::Field(_) => {
+                            return Err(DbErr::Json(format!(
+                                "Ordering `{column}` by field values can't be saved"
+                            )))
+                        }
+                    };
+                    Ok(json!({ "column": column, "order": order }))
+                })
+                .collect::<Result<Vec<_>, DbErr>>()?;
+            map.insert("order_by".to_owned(), order_by.into());
+        }
+        if let Some(limit) = self.limit {
+            map.insert("limit".to_owned(), limit.into());
+        }
+        if let Some(offset) = self.offset {
+            map.insert("offset".to_owned(), offset.into());
+        }
+        Ok(map.into())
+    }
+
+    /// Read a query from its JSON
+    pub fn from_json(json: &JsonValue) -> Result<Self, DbErr> {
+        let map = json
+            .as_object()
+            .ok_or_else(|| DbErr::Json("Query must be an object".to_owned()))?;
+        let entity = map
+            .get("entity")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(|| DbErr::Json("Query must have an `entity`".to_owned()))?
+            .to_owned();
+        let filter = map.get("filter").map(FilterDef::from_json).transpose()?;
+        let order_by = match map.get("order_by") {
+            None => Vec::new(),
+            Some(JsonValue::Array(order_by)) => order_by
+                .iter()
+                .map(|item| {
+                    let column = item.get("column").and_then(JsonValue::as_str);
+                    let order = match item.get("order").and_then(JsonValue::as_str) {
+                        Some("asc") | None => Some(Order::Asc),
+                        Some("desc") => Some(Order::Desc),
+                        Some(_) => None,
+                    };
+                    match (column, order) {
+                        (Some(column), Some(order)) => Ok((column.to_owned(), order)),
+                        _ => Err(DbErr::Json(format!("Invalid `order_by` item {item}"))),
+                    }
+                })
+                .collect::<Result<_, DbErr>>()?,
+            Some(_) => return Err(DbErr::Json("`order_by` must be an array".to_owned())),
+        };
+        let count = |key| match map.get(key) {
+            None => Ok(None),
+            Some(value) => value
+                .as_u64()
+                .map(Some)
+                .ok_or_else(|| DbErr::Json(format!("`{key}` must be a non-negative integer"))),
+        };
+        Ok(Self {
+            entity,
+            filter,
+            order_by,
+            limit: count("limit")?,
+            offset: count("offset")?,
+        })
+    }
+}
+
+impl FilterDef {
+    /// Compare a column with a value
+    pub fn column<C, V>(column: C, op: FilterOp, value: V) -> Self
+    where
+        C: ColumnTrait,
+        V: Into<JsonValue>,
+    {
+        Self::Column {
+            column: column.as_str().to_owned(),
+            op,
+            value: value.into(),
+        }
+    }
+
+    /// All of the filters hold
+    pub fn all<I>(filters: I) -> Self
+    where
+        I: IntoIterator<Item = FilterDef>,
+    {
+        Self::All(filters.into_iter().collect())
+    }
+
+    /// Any of the filters holds
+    pub fn any<I>(filters: I) -> Self
+    where
+        I: IntoIterator<Item = FilterDef>,
+    {
+        Self::Any(filters.into_iter().collect())
+    }
+
+    /// The filter does not hold
+    pub fn negate(filter: FilterDef) -> Self {
+        Self::Not(Box::new(filter))
+    }
+
+    /// Build the condition on an entity.
+    ///
+    /// Fails if a column doesn't exist, or a value can't be converted to the type of its
+    /// column.
+    pub fn to_condition<E>(&self) -> Result<Condition, DbErr>
+    where
+        E: EntityTrait,
+    {
+        Ok(match self {
+            Self::All(filters) => filters.iter().try_fold(Condition::all(), |cond, filter| {
+                Ok::<_, DbErr>(cond.add(filter.to_condition::<E>()?))
+            })?,
+            Self::Any(filters) => filters.iter().try_fold(Condition::any(), |cond, filter| {
+                Ok::<_, DbErr>(cond.add(filter.to_condition::<E>()?))
+            })?,
+            Self::Not(filter) => filter.to_condition::<E>()?.not(),
+            Self::Column { column, op, value } => {
+                let col = find_column::<E>(column)?;
+                let value_of = |json: &JsonValue| match json {
+                    JsonValue::Null => Err(DbErr::Json(format!(
+                        "`{}` on `{column}` can't compare with null",
+                        op.as_str()
+                    ))),
+                    json => column_value(col, json),
+                };
+                let values = || match value {
+                    JsonValue::Array(values) => {
+                        values.iter().map(value_of).collect::<Result<Vec<_>, _>>()
+                    }
+                    _ => Err(DbErr::Json(format!(
+                        "`{}` on `{column}` needs an array",
+                        op.as_str()
+                    ))),
+                };
+                let pattern = || match value {
+                    JsonValue::String(pattern) => Ok(pattern.as_str()),
+                    _ => Err(DbErr::Json(format!(
+                        "`{}` on `{column}` needs a string",
+                        op.as_str()
+                    ))),
+                };
+                Condition::all().add(match op {
+                    FilterOp::Eq => col.eq(value_of(value)?),
+                    FilterOp::Ne => col.ne(value_of(value)?),
+                    FilterOp::Gt => col.gt(value_of(value)?),
+                    FilterOp::Gte => col.gte(value_of(value)?),
+                    FilterOp::Lt => col.lt(value_of(value)?),
+                    FilterOp::Lte => col.lte(value_of(value)?),
+                    FilterOp::Like => col.like(pattern()?),
+                    FilterOp::NotLike => col.not_like(pattern()?),
+                    FilterOp::In => col.is_in(values()?),
+                    FilterOp::NotIn => col.is_not_in(values()?),
+                    FilterOp::IsNull => col.is_null(),
+                    FilterOp::IsNotNull => col.is_not_null(),
+                })
+            }
+        })
+    }
+
+    /// The JSON of the filter
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            Self::All(filters) => {
+                json!({ "all": filters.iter().map(Self::to_json).collect::<Vec<_>>() })
+            }
+            Self::Any(filters) => {
+                json!({ "any": filters.iter().map(Self::to_json).collect::<Vec<_>>() })
+            }
+            Self::Not(filter) => json!({ "not": filter.to_json() }),
+            Self::Column { column, op, value } => {
+                let mut map = Map::new();
+                map.insert("column".to_owned(), json!(column));
+                map.insert("op".to_owned(), json!(op.as_str()));
+                if op.has_value() {
+                    map.insert("value".to_owned(), value.clone());
+                }
+                map.into()
+            }
+        }
+    }
+
+    /// Read a filter from its JSON
+    pub fn from_json(json: &JsonValue) -> Result<Self, DbErr> {
+        let invalid = || DbErr::Json(format!("Invalid filter {json}"));
+        let map = json.as_object().ok_or_else(invalid)?;
+        let filters = |filters: &JsonValue| {
+            filters
+                .as_array()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(Self::from_json)
+                .collect::<Result<Vec<_>, DbErr>>()
+        };
+        if let Some(all) = map.get("all") {
+            return Ok(Self::All(filters(all)?));
+        }
+        if let Some(any) = map.get("any") {
+            return Ok(Self::Any(filters(any)?));
+        }
+        if let Some(filter) = map.get("not") {
+            return Ok(Self::negate(Self::from_json(filter)?));
+        }
+        let column = map
+            .get("column")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(invalid)?;
+        let op: FilterOp = map
+            .get("op")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(invalid)?
+            .parse()?;
+        let value = match map.get("value") {
+            Some(value) => value.clone(),
+            None if !op.has_value() => JsonValue::Null,
+            None => return Err(invalid()),
+        };
+        Ok(Self::Column {
+            column: column.to_owned(),
+            op,
+            value,
+        })
+    }
+}
+
+impl Serialize for QueryDef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_json()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for QueryDef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::from_json(&JsonValue::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for FilterDef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_json().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FilterDef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::from_json(&JsonValue::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+fn find_column<E>(name: &str) -> Result<E::Column, DbErr>
+where
+    E: EntityTrait,
+{
+    E::Column::iter()
+        .find(|col| col.as_str() == name)
+        .ok_or_else(|| {
+            DbErr::Custom(format!(
+                "`{}` has no column `{name}`",
+                E::default().table_name()
+            ))
+        })
+}
+
+fn parse<T>(column: &str, text: Option<&str>) -> Result<Value, DbErr>
+where
+    T: FromStr + Into<Value> + Nullable,
+    T::Err: Display,
+{
+    let Some(text) = text else {
+        return Ok(T::null());
+    };
+    text.parse::<T>()
+        .map(Into::into)
+        .map_err(|err| DbErr::Type(format!("{err} in `{text}` for `{column}`")))
+}
+
+/// Convert a JSON value to a value of the type of a column. Numbers and booleans may also be
+/// given as strings, and `null` gives a null of the column's type.
+pub(crate) fn column_value<C>(col: C, json: &JsonValue) -> Result<Value, DbErr>
+where
+    C: ColumnTrait,
+{
+    let column = col.as_str();
+    let def = col.def();
+    let col_type = def.get_column_type();
+    if let ColumnType::Json | ColumnType::JsonBinary = col_type {
+        return Ok(match json {
+            JsonValue::Null => Value::Json(None),
+            json => json.clone().into(),
+        });
+    }
+    let text = match json {
+        JsonValue::Null => None,
+        JsonValue::String(s) => Some(s.clone()),
+        JsonValue::Number(n) => Some(n.to_string()),
+        JsonValue::Bool(b) => Some(b.to_string()),
+        _ => {
+            return Err(DbErr::Type(format!(
+                "{json} can't be converted for `{column}`"
+            )))
+        }
+    };
+    match col_type {
+        ColumnType::TinyInteger => parse::<i8>(column, text.as_deref()),
+        ColumnType::SmallInteger => parse::<i16>(column, text.as_deref()),
+        ColumnType::Integer => parse::<i32>(column, text.as_deref()),
+        ColumnType::BigInteger => parse::<i64>(column, text.as_deref()),
+        ColumnType::TinyUnsigned => parse::<u8>(column, text.as_deref()),
+        ColumnType::SmallUnsigned => parse::<u16>(column, text.as_deref()),
+        ColumnType::Unsigned => parse::<u32>(column, text.as_deref()),
+        ColumnType::BigUnsigned => parse::<u64>(column, text.as_deref()),
+        ColumnType::Float => parse::<f32>(column, text.as_deref()),
+        ColumnType::Double => parse::<f64>(column, text.as_deref()),
+        ColumnType::Boolean => parse::<bool>(column, text.as_deref()),
+        ColumnType::Char(_)
+        | ColumnType::String(_)
+        | ColumnType::Text
+        | ColumnType::Enum { .. }
+        | ColumnType::Custom(_) => match json {
+            JsonValue::Null => Ok(Value::String(None)),
+            JsonValue::String(s) => Ok(s.clone().into()),
+            _ => Err(DbErr::Type(format!(
+                "`{column}` needs a string, not {json}"
+            ))),
+        },
+        #[cfg(feature = "with-uuid")]
+        ColumnType::Uuid => parse::<uuid::Uuid>(column, text.as_deref()),
+        #[cfg(feature = "with-rust_decimal")]
+        ColumnType::Decimal(_) | ColumnType::Money(_) => {
+            parse::<rust_decimal::Decimal>(column, text.as_deref())
+        }
+        #[cfg(all(feature = "with-bigdecimal", not(feature = "with-rust_decimal")))]
+        ColumnType::Decimal(_) | ColumnType::Money(_) => {
+            parse::<bigdecimal::BigDecimal>(column, text.as_deref())
+        }
+        #[cfg(feature = "with-chrono")]
+        ColumnType::Date => parse::<chrono::NaiveDate>(column, text.as_deref()),
+        #[cfg(feature = "with-chrono")]
+        ColumnType::Time => parse::<chrono::NaiveTime>(column, text.as_deref()),
+        #[cfg(feature = "with-chrono")]
+        ColumnType::DateTime | ColumnType::Timestamp => {
+            parse::<chrono::NaiveDateTime>(column, text.as_deref())
+        }
+        #[cfg(feature = "with-chrono")]
+        ColumnType::TimestampWithTimeZone => {
+            parse::<chrono::DateTime<chrono::FixedOffset>>(column, text.as_deref())
+        }
+        col_type => Err(DbErr::Type(format!(
+            "`{column}` of type {col_type:?} can't be converted from JSON"
+        ))),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tests-cfg")]
+mod tests {
+    use super::*;
+    use crate::{tests_cfg::fruit, DbBackend, QueryTrait};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        let query = QueryDef::new(fruit::Entity)
+            .filter(FilterDef::any([
+                FilterDef::column(fruit::Column::CakeId, FilterOp::In, json!([1, "2"])),
+                FilterDef::negate(FilterDef::column(
+                    fruit::Column::CakeId,
+                    FilterOp::IsNull,
+                    JsonValue::Null,
+                )),
+            ]))
+            .filter(FilterDef::column(fruit::Column::Id, FilterOp::Gte, 3))
+            .order_by(fruit::Column::Name, Order::Asc)
+            .offset(20);
+
+        let json = query.to_json().unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "entity": "fruit",
+                "filter": {
+                    "all": [
+                        {
+                            "any": [
+                                { "column": "cake_id", "op": "in", "value": [1, "2"] },
+                                { "not": { "column": "cake_id", "op": "is_null" } },
+                            ]
+                        },
+                        { "column": "id", "op": "gte", "value": 3 },
+                    ]
+                },
+                "order_by": [{ "column": "name", "order": "asc" }],
+                "offset": 20,
+            })
+        );
+        assert_eq!(QueryDef::from_json(&json).unwrap(), query);
+
+        assert_eq!(
+            query
+                .to_select::<fruit::Entity>()
+                .unwrap()
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"WHERE ("fruit"."cake_id" IN (1, 2) OR (NOT "fruit"."cake_id" IS NULL))"#,
+                r#"AND "fruit"."id" >= 3"#,
+                r#"ORDER BY "fruit"."name" ASC OFFSET 20"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn validation() {
+        let select = |json: JsonValue| {
+            QueryDef::from_json(&json).and_then(|query| query.to_select::<fruit::Entity>())
+        };
+
+        assert_eq!(
+            select(json!({ "entity": "cake" })).unwrap_err(),
+            DbErr::Custom("Query is defined on `cake`, not `fruit`".to_owned())
+        );
+        assert_eq!(
+            select(json!({
+                "entity": "fruit",
+                "filter": { "column": "price", "op": "eq", "value": 1 },
+            }))
+            .unwrap_err(),
+            DbErr::Custom("`fruit` has no column `price`".to_owned())
+        );
+        assert!(matches!(
+            select(json!({
+                "entity": "fruit",
+                "filter": { "column": "id", "op": "eq", "value": "one" },
+            })),
+            Err(DbErr::Type(_))
+        ));
+        assert!(matches!(
+            select(json!({
+                "entity": "fruit",
+                "filter": { "column": "name", "op": "eq", "value": 1 },
+            })),
+            Err(DbErr::Type(_))
+        ));
+        assert_eq!(
+            select(json!({
+                "entity": "fruit",
+                "filter": { "column": "id", "op": "between", "value": 1 },
+            }))
+            .unwrap_err(),
+            DbErr::Json("Unknown filter op `between`".to_owned())
+        );
+        assert!(matches!(
+            select(json!({
+                "entity": "fruit",
+                "filter": { "column": "id", "op": "in", "value": 1 },
+            })),
+            Err(DbErr::Json(_))
+        ));
+    }
+}
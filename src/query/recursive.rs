@@ -0,0 +1,201 @@
+use crate::{
+    ColumnTrait, DbErr, EntityTrait, FromQueryResult, IdenStatic, Iterable, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryFilter, QueryResult, QueryTrait, ReadConnectionTrait, Refusal,
+    TryGetError,
+};
+use sea_query::{
+    Alias, Asterisk, CommonTableExpression, Expr, Order, Query, SelectStatement, SimpleExpr,
+    UnionType, Value, WithClause, WithQuery,
+};
+use std::marker::PhantomData;
+
+const TREE: &str = "tree";
+const DEPTH: &str = "depth";
+
+/// Select the descendants or the ancestors of a row in a table linking each row to its parent,
+/// with a `WITH RECURSIVE` query, constructed by
+/// [EntityTrait::find_descendants] and [EntityTrait::find_ancestors].
+///
+/// The row itself comes first at depth 0, followed by its children or its parent at depth 1, and
+/// so on.
+#[derive(Clone, Debug)]
+pub struct SelectRecursive<E>
+where
+    E: EntityTrait,
+{
+    start: Value,
+    anchor: SelectStatement,
+    step: SelectStatement,
+    query: WithQuery,
+    refusal: Option<Refusal>,
+    entity: PhantomData<E>,
+}
+
+/// A model selected by [SelectRecursive], with its distance from the row the query started from
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithDepth<M> {
+    /// The model
+    pub model: M,
+    /// The distance from the row the query started from, 0 for the row itself
+    pub depth: i64,
+}
+
+impl<E> SelectRecursive<E>
+where
+    E: EntityTrait,
+    <E::PrimaryKey as PrimaryKeyTrait>::ValueType: Into<Value>,
+{
+    pub(crate) fn descendants<T>(id: T, parent_col: E::Column) -> Self
+    where
+        T: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    {
+        Self::descendants_of(Self::start(id), parent_col)
+    }
+
+    pub(crate) fn ancestors<T>(id: T, parent_col: E::Column) -> Self
+    where
+        T: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    {
+        Self::ancestors_of(Self::start(id), parent_col)
+    }
+
+    pub(crate) fn descendants_of(start: Value, parent_col: E::Column) -> Self {
+        Self::new(
+            start,
+            Expr::col((E::default(), parent_col)).equals((Alias::new(TREE), Self::key())),
+        )
+    }
+
+    pub(crate) fn ancestors_of(start: Value, parent_col: E::Column) -> Self {
+        Self::new(
+            start,
+            Expr::col((E::default(), Self::key())).equals((Alias::new(TREE), parent_col)),
+        )
+    }
+
+    fn start<T>(id: T) -> Value
+    where
+        T: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    {
+        id.into().into()
+    }
+
+    /// The primary key column, single as its value converts into a [Value]
+    pub(crate) fn key() -> E::Column {
+        E::PrimaryKey::iter()
+            .next()
+            .expect("an entity has a primary key")
+            .into_column()
+    }
+
+    fn new(start: Value, on: SimpleExpr) -> Self {
+        let mut anchor = E::find().filter(Self::key().eq(start.clone())).into_query();
+        anchor.expr_as(Expr::val(0i64), Alias::new(DEPTH));
+
+        let mut step = E::find().into_query();
+        step.expr_as(
+            Expr::col((Alias::new(TREE), Alias::new(DEPTH))).add(1i64),
+            Alias::new(DEPTH),
+        )
+        .inner_join(Alias::new(TREE), on);
+
+        let query = Self::with_query(&anchor, &step);
+        Self {
+            start,
+            anchor,
+            step,
+            query,
+            refusal: crate::tenant_refusal::<E>(),
+            entity: PhantomData,
+        }
+    }
+
+    fn with_query(anchor: &SelectStatement, step: &SelectStatement) -> WithQuery {
+        let mut cte = CommonTableExpression::new();
+        cte.table_name(Alias::new(TREE))
+            .columns(E::Column::iter().map(|col| Alias::new(col.as_str())))
+            .column(Alias::new(DEPTH))
+            .query(
+                anchor
+                    .clone()
+                    .union(UnionType::All, step.clone())
+                    .to_owned(),
+            );
+        Query::select()
+            .column(Asterisk)
+            .from(Alias::new(TREE))
+            .order_by(Alias::new(DEPTH), Order::Asc)
+            .to_owned()
+            .with(WithClause::new().recursive(true).cte(cte).to_owned())
+    }
+
+    /// Stop at the rows `depth` away from the row the query started from, to bound the query
+    /// on large trees, or on rows linked in a cycle
+    pub fn max_depth(mut self, depth: u32) -> Self {
+        self.step
+            .and_where(Expr::col((Alias::new(TREE), Alias::new(DEPTH))).lt(depth as i64));
+        self.query = Self::with_query(&self.anchor, &self.step);
+        self
+    }
+
+    /// Stop at the row the query started from, should the rows link back to it in a cycle.
+    ///
+    /// As each row has a single parent, this keeps a walk down the descendants from visiting
+    /// a row twice. A walk up the ancestors can still enter a cycle above the row, which
+    /// [Self::max_depth] bounds.
+    pub fn skip_cycles(mut self) -> Self {
+        self.step
+            .and_where(Expr::col((E::default(), Self::key())).ne(self.start.clone()));
+        self.query = Self::with_query(&self.anchor, &self.step);
+        self
+    }
+
+    /// Get all the models, each with its depth
+    pub async fn all<C>(self, db: &C) -> Result<Vec<WithDepth<E::Model>>, DbErr>
+    where
+        C: ReadConnectionTrait,
+    {
+        crate::check_refusal(self.refusal)?;
+        WithDepth::<E::Model>::find_by_statement(self.build(db.read_backend()))
+            .all(db)
+            .await
+    }
+}
+
+impl<E> QueryTrait for SelectRecursive<E>
+where
+    E: EntityTrait,
+{
+    type QueryStatement = WithQuery;
+
+    fn query(&mut self) -> &mut WithQuery {
+        &mut self.query
+    }
+
+    fn as_query(&self) -> &WithQuery {
+        &self.query
+    }
+
+    fn into_query(self) -> WithQuery {
+        self.query
+    }
+}
+
+impl<M> FromQueryResult for WithDepth<M>
+where
+    M: FromQueryResult,
+{
+    fn from_query_result(row: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        Ok(Self {
+            model: M::from_query_result(row, pre)?,
+            depth: row.try_get(pre, DEPTH)?,
+        })
+    }
+
+    fn from_query_result_nullable(row: &QueryResult, pre: &str) -> Result<Self, TryGetError> {
+        Ok(Self {
+            model: M::from_query_result_nullable(row, pre)?,
+            depth: row.try_get_nullable(pre, DEPTH)?,
+        })
+    }
+}
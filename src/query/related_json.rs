@@ -0,0 +1,222 @@
+use super::{join::related_subquery, query_def::column_value};
+use crate::{
+    error::*, ActiveModelTrait, ColumnTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic,
+    Iterable, JsonValue, ReadConnectionTrait, Related, Select, Statement, TryIntoModel,
+};
+use sea_query::{Alias, Expr, Func, SimpleExpr};
+use std::marker::PhantomData;
+
+const RELATED: &str = "related_json";
+
+/// A select of models with the related models of each aggregated into a JSON array in the
+/// same row, to load both in a single query. See [Select::with_related_json].
+#[derive(Clone, Debug)]
+pub struct SelectWithRelatedJson<E, R>
+where
+    E: EntityTrait,
+    R: EntityTrait,
+{
+    select: Select<E>,
+    related: PhantomData<R>,
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Select the related models of each model as well, aggregated with a correlated subquery
+    /// into a JSON array: with `json_agg` on Postgres, `JSON_ARRAYAGG` on MySQL and
+    /// `json_group_array` on SQLite. Unlike the loader, it takes one round trip however many
+    /// models there are, at the cost of decoding the related models from JSON.
+    ///
+    /// The related models are decoded from JSON by the types of their columns. Columns without
+    /// a faithful JSON form, such as booleans on MySQL, given as `0` and `1`, may not decode.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "id" => Value::from(1),
+    /// #         "name" => Value::from("Cheese Cake"),
+    /// #         "related_json" => Value::from(serde_json::json!([
+    /// #             { "id": 7, "name": "Lemon", "cake_id": 1 },
+    /// #         ])),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// let cakes = cake::Entity::find()
+    ///     .with_related_json(fruit::Entity)
+    ///     .all(&db)
+    ///     .await?;
+    /// assert_eq!(
+    ///     cakes,
+    ///     [(
+    ///         cake::Model { id: 1, name: "Cheese Cake".to_owned() },
+    ///         vec![fruit::Model { id: 7, name: "Lemon".to_owned(), cake_id: Some(1) }],
+    ///     )]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         [
+    ///             r#"SELECT "cake"."id", "cake"."name","#,
+    ///             r#"(SELECT COALESCE(json_agg(json_build_object('id', "fruit"."id", 'name', "fruit"."name", 'cake_id', "fruit"."cake_id")), '[]')"#,
+    ///             r#"FROM "fruit" WHERE "cake"."id" = "fruit"."cake_id") AS "related_json""#,
+    ///             r#"FROM "cake""#,
+    ///         ]
+    ///         .join(" ")
+    ///         .as_str(),
+    ///         []
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_related_json<R>(self, _: R) -> SelectWithRelatedJson<E, R>
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        SelectWithRelatedJson {
+            select: self,
+            related: PhantomData,
+        }
+    }
+}
+
+impl<E, R> SelectWithRelatedJson<E, R>
+where
+    E: EntityTrait + Related<R>,
+    R: EntityTrait,
+{
+    /// Build the statement for a backend
+    pub fn build(&self, backend: DbBackend) -> Statement {
+        let mut related = related_subquery::<E, R, _>(|select| select).query;
+        related.clear_selects().expr(json_array_agg::<R>(backend));
+        let mut query = self.select.query.clone();
+        query.expr_as(
+            SimpleExpr::SubQuery(None, Box::new(related.into_sub_query_statement())),
+            Alias::new(RELATED),
+        );
+        backend.build(&query)
+    }
+
+    /// Get all the models, each with its related models
+    pub async fn all<C>(self, db: &C) -> Result<Vec<(E::Model, Vec<R::Model>)>, DbErr>
+    where
+        C: ReadConnectionTrait,
+        R::ActiveModel: TryIntoModel<R::Model>,
+    {
+        crate::check_refusal(self.select.refusal.or(crate::tenant_refusal::<R>()))?;
+        let rows = db.read_all(self.build(db.read_backend())).await?;
+        rows.iter()
+            .map(|row| {
+                let model = E::Model::from_query_result(row, "")?;
+                let related = related_models::<R>(row.try_get("", RELATED)?)?;
+                Ok((model, related))
+            })
+            .collect()
+    }
+}
+
+/// Decode a JSON array of objects by column name into models
+fn related_models<R>(json: JsonValue) -> Result<Vec<R::Model>, DbErr>
+where
+    R: EntityTrait,
+    R::ActiveModel: TryIntoModel<R::Model>,
+{
+    let json = match json {
+        // SQLite returns the array as text
+        JsonValue::String(text) => serde_json::from_str(&text).map_err(json_err)?,
+        json => json,
+    };
+    let JsonValue::Array(rows) = json else {
+        return Err(DbErr::Json(format!(
+            "{json} is not an array of related models"
+        )));
+    };
+    rows.iter()
+        .map(|row| {
+            let mut am = <R::ActiveModel as ActiveModelTrait>::default();
+            for col in R::Column::iter() {
+                let value = row.get(col.as_str()).unwrap_or(&JsonValue::Null);
+                am.set(col, column_value(col, value)?);
+            }
+            am.try_into_model()
+        })
+        .collect()
+}
+
+/// Aggregate the rows of an entity into a JSON array of objects by column name, an empty
+/// array if there are none
+fn json_array_agg<R>(backend: DbBackend) -> SimpleExpr
+where
+    R: EntityTrait,
+{
+    let fields = R::Column::iter().flat_map(|col| {
+        let name = Expr::cust(format!("'{}'", col.as_str().replace('\'', "''")));
+        [name, col.select_as(Expr::col((R::default(), col)))]
+    });
+    let (object, array_agg, empty) = match backend {
+        DbBackend::Postgres => ("json_build_object", "json_agg", Some("'[]'")),
+        DbBackend::MySql => ("JSON_OBJECT", "JSON_ARRAYAGG", Some("JSON_ARRAY()")),
+        DbBackend::Sqlite => ("json_object", "json_group_array", None),
+    };
+    let object = Func::cust(Alias::new(object)).args(fields);
+    let array: SimpleExpr = Func::cust(Alias::new(array_agg)).arg(object).into();
+    match empty {
+        Some(empty) => Func::coalesce([array, Expr::cust(empty)]).into(),
+        None => array,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests_cfg::*, DbBackend, EntityTrait};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn build_via() {
+        assert_eq!(
+            cake::Entity::find()
+                .with_related_json(filling::Entity)
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name","#,
+                r#"(SELECT json_group_array(json_object('id', "filling"."id", 'name', "filling"."name", 'vendor_id', "filling"."vendor_id"))"#,
+                r#"FROM "filling" INNER JOIN "cake_filling" ON "cake_filling"."filling_id" = "filling"."id""#,
+                r#"WHERE "cake"."id" = "cake_filling"."cake_id") AS "related_json""#,
+                r#"FROM "cake""#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn build_mysql() {
+        assert_eq!(
+            fruit::Entity::find()
+                .with_related_json(cake::Entity)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id`,",
+                "(SELECT COALESCE(JSON_ARRAYAGG(JSON_OBJECT('id', `cake`.`id`, 'name', `cake`.`name`)), JSON_ARRAY())",
+                "FROM `cake` WHERE `fruit`.`cake_id` = `cake`.`id`) AS `related_json`",
+                "FROM `fruit`",
+            ]
+            .join(" ")
+        );
+    }
+}
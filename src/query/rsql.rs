@@ -0,0 +1,745 @@
+use super::query_def::column_value;
+use crate::{
+    ColumnTrait, ColumnType, DbErr, EntityTrait, Iterable, Order, QueryFilter, QueryOrder, Select,
+    Value,
+};
+use sea_query::{Condition, Expr, LikeExpr, SimpleExpr};
+use serde_json::Value as JsonValue;
+use std::fmt;
+
+/// Parses filters and orderings from query strings, like
+/// `name==Apple*;cake_id=in=(1,2)&sort=-id`, against the columns of an entity that may be
+/// used.
+///
+/// The query string has a `filter` and a `sort` parameter, a part without a parameter name is
+/// the filter. The filter is in [RSQL](https://github.com/jirutka/rsql-parser):
+///
+/// - `;` is AND, `,` is OR, and parentheses group
+/// - `==`, `!=`, `=gt=` (`>`), `=ge=` (`>=`), `=lt=` (`<`), `=le=` (`<=`), `=in=` and `=out=`
+///   with a list of values in parentheses, and `=null=true` / `=null=false`
+/// - `==` and `!=` on a text column match a pattern if the value contains `*`
+/// - values with reserved characters are quoted, `'a,b'` or `"a,b"`
+///
+/// The sort is a list of columns separated by `,`, descending when prefixed with `-`.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend, RsqlParser};
+///
+/// let parser = RsqlParser::<fruit::Entity>::new([
+///     fruit::Column::Id,
+///     fruit::Column::Name,
+///     fruit::Column::CakeId,
+/// ]);
+/// let query = parser
+///     .parse("name==Apple*;cake_id=in=(1,2)&sort=-id")
+///     .unwrap();
+///
+/// assert_eq!(
+///     query
+///         .apply(fruit::Entity::find())
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+///         r#"WHERE "fruit"."name" LIKE 'Apple%' ESCAPE E'\\' AND "fruit"."cake_id" IN (1, 2)"#,
+///         r#"ORDER BY "fruit"."id" DESC"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RsqlParser<E>
+where
+    E: EntityTrait,
+{
+    columns: Vec<E::Column>,
+}
+
+/// The filter and ordering parsed by [RsqlParser]
+#[derive(Debug, Clone)]
+pub struct RsqlQuery<E>
+where
+    E: EntityTrait,
+{
+    /// The filter, an empty condition if there was none
+    pub condition: Condition,
+    /// The columns to order by, in order
+    pub order_by: Vec<(E::Column, Order)>,
+}
+
+/// An error from [RsqlParser]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RsqlError {
+    /// The filter is malformed
+    #[error("Syntax error at {position}: {message}")]
+    Syntax {
+        /// The byte offset in the filter
+        position: usize,
+        /// What was expected
+        message: String,
+    },
+    /// The query string has a parameter other than `filter` and `sort`
+    #[error("Unknown parameter `{0}`")]
+    UnknownParameter(String),
+    /// The comparison is not supported
+    #[error("Unknown operator `{0}`")]
+    UnknownOperator(String),
+    /// The entity has no such column
+    #[error("Unknown column `{0}`")]
+    UnknownColumn(String),
+    /// The column is not one of the columns that may be used
+    #[error("Column `{0}` is not allowed")]
+    ColumnNotAllowed(String),
+    /// The value can't be converted to the type of the column
+    #[error("Invalid value `{value}` for `{column}`: {message}")]
+    InvalidValue {
+        /// The column name
+        column: String,
+        /// The value
+        value: String,
+        /// Why the value is invalid
+        message: String,
+    },
+}
+
+impl<E> RsqlParser<E>
+where
+    E: EntityTrait,
+{
+    /// Create a parser allowing the columns
+    pub fn new<I>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = E::Column>,
+    {
+        Self {
+            columns: columns.into_iter().collect(),
+        }
+    }
+
+    /// Create a parser allowing every column of the entity
+    pub fn all_columns() -> Self {
+        Self::new(E::Column::iter())
+    }
+
+    /// Parse a query string with a `filter` and a `sort` parameter. The query string must be
+    /// percent-decoded already.
+    pub fn parse(&self, query: &str) -> Result<RsqlQuery<E>, RsqlError> {
+        let mut condition = Condition::all();
+        let mut order_by = Vec::new();
+        for part in query.split('&').filter(|part| !part.is_empty()) {
+            if let Some(sort) = part.strip_prefix("sort=") {
+                order_by.extend(self.parse_sort(sort)?);
+            } else if let Some(filter) = part.strip_prefix("filter=") {
+                condition = condition.add(self.parse_filter(filter)?);
+            } else if let Some((name, _)) = part.split_once('=').filter(|(name, value)| {
+                name.chars().all(|c| c.is_alphanumeric() || c == '_') && !value.contains('=')
+            }) {
+                return Err(RsqlError::UnknownParameter(name.to_owned()));
+            } else {
+                condition = condition.add(self.parse_filter(part)?);
+            }
+        }
+        Ok(RsqlQuery {
+            condition,
+            order_by,
+        })
+    }
+
+    /// Parse a filter
+    pub fn parse_filter(&self, filter: &str) -> Result<Condition, RsqlError> {
+        let mut parser = Parser {
+            input: filter,
+            pos: 0,
+            depth: 0,
+            columns: &self.columns,
+        };
+        let condition = parser.or()?;
+        match parser.peek() {
+            None => Ok(condition),
+            Some(c) => Err(parser.syntax(format!("unexpected `{c}`"))),
+        }
+    }
+
+    /// Parse a list of columns separated by `,`, descending when prefixed with `-`
+    pub fn parse_sort(&self, sort: &str) -> Result<Vec<(E::Column, Order)>, RsqlError> {
+        sort.split(',')
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                let (name, order) = match name.strip_prefix('-') {
+                    Some(name) => (name, Order::Desc),
+                    None => (name.strip_prefix('+').unwrap_or(name), Order::Asc),
+                };
+                Ok((find_column(&self.columns, name)?, order))
+            })
+            .collect()
+    }
+}
+
+impl<E> RsqlQuery<E>
+where
+    E: EntityTrait,
+{
+    /// Filter and order a query
+    pub fn apply<Q>(self, query: Q) -> Q
+    where
+        Q: QueryFilter + QueryOrder,
+    {
+        let mut query = query;
+        if !self.condition.is_empty() {
+            query = query.filter(self.condition);
+        }
+        for (column, order) in self.order_by {
+            query = query.order_by(column, order);
+        }
+        query
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Order by a list of columns separated by `,`, each optionally followed by `:asc` or
+    /// `:desc`, like `name:desc,id`. Any column of the entity may be used.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .filter_str("cake_id=1,name!=Apple")
+    ///         .unwrap()
+    ///         .order_by_str("name:desc,id")
+    ///         .unwrap()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+    ///         r#"WHERE "fruit"."cake_id" = 1 AND "fruit"."name" <> 'Apple'"#,
+    ///         r#"ORDER BY "fruit"."name" DESC, "fruit"."id" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn order_by_str(self, order_by: &str) -> Result<Self, RsqlError> {
+        let columns: Vec<_> = E::Column::iter().collect();
+        let mut query = self;
+        let mut position = 0;
+        for part in order_by.split(',') {
+            if !part.is_empty() {
+                let (name, order) = match part.split_once(':') {
+                    None => (part, Order::Asc),
+                    Some((name, order)) if order.eq_ignore_ascii_case("asc") => (name, Order::Asc),
+                    Some((name, order)) if order.eq_ignore_ascii_case("desc") => {
+                        (name, Order::Desc)
+                    }
+                    Some((name, _)) => {
+                        return Err(RsqlError::Syntax {
+                            position: position + name.len() + 1,
+                            message: "expected `asc` or `desc`".to_owned(),
+                        })
+                    }
+                };
+                query = query.order_by(find_column(&columns, name)?, order);
+            }
+            position += part.len() + 1;
+        }
+        Ok(query)
+    }
+
+    /// Filter by a list of `column=value` or `column!=value` separated by `,`, all of which
+    /// must hold. Values are converted to the type of the column, and any column of the
+    /// entity may be used. For anything more, use [RsqlParser].
+    pub fn filter_str(self, filter: &str) -> Result<Self, RsqlError> {
+        let columns: Vec<_> = E::Column::iter().collect();
+        let mut condition = Condition::all();
+        let mut position = 0;
+        for part in filter.split(',') {
+            if !part.is_empty() {
+                let Some((name, value)) = part.split_once('=') else {
+                    return Err(RsqlError::Syntax {
+                        position: position + part.len(),
+                        message: "expected `=`".to_owned(),
+                    });
+                };
+                let (name, op) = match name.strip_suffix('!') {
+                    Some(name) => (name, Operator::Ne),
+                    None => (name, Operator::Eq),
+                };
+                let column = find_column(&columns, name)?;
+                condition = condition.add(compare(column, op, convert(column, value)?));
+            }
+            position += part.len() + 1;
+        }
+        Ok(self.filter(condition))
+    }
+}
+
+impl From<RsqlError> for DbErr {
+    fn from(err: RsqlError) -> Self {
+        DbErr::Custom(err.to_string())
+    }
+}
+
+fn find_column<C>(allowed: &[C], name: &str) -> Result<C, RsqlError>
+where
+    C: ColumnTrait,
+{
+    let column = C::iter()
+        .find(|col| col.as_str() == name)
+        .ok_or_else(|| RsqlError::UnknownColumn(name.to_owned()))?;
+    if !allowed.iter().any(|col| col.as_str() == name) {
+        return Err(RsqlError::ColumnNotAllowed(name.to_owned()));
+    }
+    Ok(column)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    In,
+    Out,
+    Null,
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Gt => "=gt=",
+            Self::Ge => "=ge=",
+            Self::Lt => "=lt=",
+            Self::Le => "=le=",
+            Self::In => "=in=",
+            Self::Out => "=out=",
+            Self::Null => "=null=",
+        })
+    }
+}
+
+/// A recursive descent parser of RSQL
+struct Parser<'a, C> {
+    input: &'a str,
+    pos: usize,
+    /// The number of groups the parser is in
+    depth: usize,
+    columns: &'a [C],
+}
+
+const RESERVED: [char; 6] = ['"', '\'', '(', ')', ';', ','];
+
+/// The most groups nested in each other, to not overflow the stack of the parser on untrusted
+/// input
+const MAX_DEPTH: usize = 32;
+
+impl<C> Parser<'_, C>
+where
+    C: ColumnTrait,
+{
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn syntax(&self, message: String) -> RsqlError {
+        RsqlError::Syntax {
+            position: self.pos,
+            message,
+        }
+    }
+
+    fn take_while<F>(&mut self, f: F) -> &str
+    where
+        F: Fn(char) -> bool,
+    {
+        let start = self.pos;
+        let len = self.input[start..]
+            .find(|c: char| !f(c))
+            .unwrap_or(self.input.len() - start);
+        self.pos += len;
+        &self.input[start..self.pos]
+    }
+
+    fn or(&mut self) -> Result<Condition, RsqlError> {
+        let mut condition = Condition::any().add(self.and()?);
+        while self.eat(',') {
+            condition = condition.add(self.and()?);
+        }
+        Ok(condition)
+    }
+
+    fn and(&mut self) -> Result<Condition, RsqlError> {
+        let mut condition = Condition::all().add(self.constraint()?);
+        while self.eat(';') {
+            condition = condition.add(self.constraint()?);
+        }
+        Ok(condition)
+    }
+
+    fn constraint(&mut self) -> Result<Condition, RsqlError> {
+        if self.eat('(') {
+            if self.depth == MAX_DEPTH {
+                return Err(self.syntax(format!("more than {MAX_DEPTH} nested groups")));
+            }
+            self.depth += 1;
+            let condition = self.or()?;
+            if !self.eat(')') {
+                return Err(self.syntax("expected `)`".to_owned()));
+            }
+            self.depth -= 1;
+            return Ok(condition);
+        }
+
+        let name = self.take_while(|c| c.is_alphanumeric() || c == '_');
+        if name.is_empty() {
+            return Err(self.syntax("expected a column".to_owned()));
+        }
+        let name = name.to_owned();
+        let column = find_column(self.columns, &name)?;
+        let op = self.operator()?;
+        let expr = match op {
+            Operator::In | Operator::Out => {
+                if !self.eat('(') {
+                    return Err(self.syntax("expected `(`".to_owned()));
+                }
+                let mut values = vec![self.value()?];
+                while self.eat(',') {
+                    values.push(self.value()?);
+                }
+                if !self.eat(')') {
+                    return Err(self.syntax("expected `)`".to_owned()));
+                }
+                let values = values
+                    .into_iter()
+                    .map(|value| convert(column, &value))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if op == Operator::In {
+                    column.is_in(values)
+                } else {
+                    column.is_not_in(values)
+                }
+            }
+            Operator::Null => match self.value()?.as_str() {
+                "true" => column.is_null(),
+                "false" => column.is_not_null(),
+                value => {
+                    return Err(RsqlError::InvalidValue {
+                        column: name,
+                        value: value.to_owned(),
+                        message: "`=null=` takes `true` or `false`".to_owned(),
+                    })
+                }
+            },
+            _ => {
+                let value = self.value()?;
+                if matches!(op, Operator::Eq | Operator::Ne)
+                    && value.contains('*')
+                    && is_text(column)
+                {
+                    let pattern = like_pattern(&value);
+                    let expr = Expr::col((column.entity_name(), column));
+                    if op == Operator::Eq {
+                        expr.like(pattern)
+                    } else {
+                        expr.not_like(pattern)
+                    }
+                } else {
+                    compare(column, op, convert(column, &value)?)
+                }
+            }
+        };
+        Ok(Condition::all().add(expr))
+    }
+
+    fn operator(&mut self) -> Result<Operator, RsqlError> {
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let (op, len) = if rest.starts_with("==") {
+            (Operator::Eq, 2)
+        } else if rest.starts_with("!=") {
+            (Operator::Ne, 2)
+        } else if rest.starts_with(">=") {
+            (Operator::Ge, 2)
+        } else if rest.starts_with("<=") {
+            (Operator::Le, 2)
+        } else if rest.starts_with('>') {
+            (Operator::Gt, 1)
+        } else if rest.starts_with('<') {
+            (Operator::Lt, 1)
+        } else if let Some(named) = rest.strip_prefix('=') {
+            let Some(end) = named.find('=') else {
+                return Err(self.syntax("expected an operator".to_owned()));
+            };
+            let op = match &named[..end] {
+                "gt" => Operator::Gt,
+                "ge" => Operator::Ge,
+                "lt" => Operator::Lt,
+                "le" => Operator::Le,
+                "in" => Operator::In,
+                "out" => Operator::Out,
+                "null" => Operator::Null,
+                name => return Err(RsqlError::UnknownOperator(format!("={name}="))),
+            };
+            (op, end + 2)
+        } else {
+            return Err(self.syntax("expected an operator".to_owned()));
+        };
+        self.pos += len;
+        Ok(op)
+    }
+
+    fn value(&mut self) -> Result<String, RsqlError> {
+        let Some(quote) = self.peek().filter(|c| *c == '"' || *c == '\'') else {
+            let value = self.take_while(|c| !RESERVED.contains(&c) && !c.is_whitespace());
+            if value.is_empty() {
+                return Err(self.syntax("expected a value".to_owned()));
+            }
+            return Ok(value.to_owned());
+        };
+        self.pos += 1;
+        let mut value = String::new();
+        let mut chars = self.input[self.pos..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some((_, c)) => value.push(c),
+                    None => break,
+                },
+                c if c == quote => {
+                    self.pos += i + 1;
+                    return Ok(value);
+                }
+                c => value.push(c),
+            }
+        }
+        self.pos = self.input.len();
+        Err(self.syntax(format!("expected `{quote}`")))
+    }
+}
+
+fn is_text<C>(column: C) -> bool
+where
+    C: ColumnTrait,
+{
+    matches!(
+        column.def().get_column_type(),
+        ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text
+    )
+}
+
+/// `*` matches anything, and the wildcards of LIKE are escaped
+fn like_pattern(value: &str) -> LikeExpr {
+    let mut pattern = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '*' => pattern.push('%'),
+            '%' | '_' | '\\' => {
+                pattern.push('\\');
+                pattern.push(c);
+            }
+            c => pattern.push(c),
+        }
+    }
+    LikeExpr::new(pattern).escape('\\')
+}
+
+fn convert<C>(column: C, value: &str) -> Result<Value, RsqlError>
+where
+    C: ColumnTrait,
+{
+    column_value(column, &JsonValue::String(value.to_owned())).map_err(|err| {
+        RsqlError::InvalidValue {
+            column: column.as_str().to_owned(),
+            value: value.to_owned(),
+            message: match err {
+                DbErr::Type(message) => message,
+                err => err.to_string(),
+            },
+        }
+    })
+}
+
+fn compare<C>(column: C, op: Operator, value: Value) -> SimpleExpr
+where
+    C: ColumnTrait,
+{
+    match op {
+        Operator::Eq => column.eq(value),
+        Operator::Ne => column.ne(value),
+        Operator::Gt => column.gt(value),
+        Operator::Ge => column.gte(value),
+        Operator::Lt => column.lt(value),
+        Operator::Le => column.lte(value),
+        Operator::In | Operator::Out | Operator::Null => unreachable!("{op} takes no value"),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tests-cfg")]
+mod tests {
+    use super::*;
+    use crate::{tests_cfg::fruit, DbBackend, QueryTrait};
+    use pretty_assertions::assert_eq;
+
+    fn sql(query: &str) -> Result<String, RsqlError> {
+        let parser = RsqlParser::<fruit::Entity>::new([
+            fruit::Column::Id,
+            fruit::Column::Name,
+            fruit::Column::CakeId,
+        ]);
+        Ok(parser
+            .parse(query)?
+            .apply(fruit::Entity::find())
+            .build(DbBackend::Postgres)
+            .to_string()
+            .replace(
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                "",
+            ))
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(
+            sql("id=gt=1;id=le=10,name==Apple").unwrap(),
+            r#" WHERE ("fruit"."id" > 1 AND "fruit"."id" <= 10) OR "fruit"."name" = 'Apple'"#
+        );
+        assert_eq!(
+            sql("filter=id>=1;(name!='a,b';cake_id=null=false)").unwrap(),
+            r#" WHERE "fruit"."id" >= 1 AND ("fruit"."name" <> 'a,b' AND "fruit"."cake_id" IS NOT NULL)"#
+        );
+        assert_eq!(
+            sql("name!=*50%*;id=out=(1,\"2\")").unwrap(),
+            r#" WHERE "fruit"."name" NOT LIKE E'%50\\%%' ESCAPE E'\\' AND "fruit"."id" NOT IN (1, 2)"#
+        );
+        assert_eq!(
+            sql("sort=name,-id&cake_id==1").unwrap(),
+            r#" WHERE "fruit"."cake_id" = 1 ORDER BY "fruit"."name" ASC, "fruit"."id" DESC"#
+        );
+        assert_eq!(sql("").unwrap(), "");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            sql("price==1").unwrap_err(),
+            RsqlError::UnknownColumn("price".to_owned())
+        );
+        assert_eq!(
+            RsqlParser::<fruit::Entity>::new([fruit::Column::Id])
+                .parse("sort=name")
+                .unwrap_err(),
+            RsqlError::ColumnNotAllowed("name".to_owned())
+        );
+        assert_eq!(
+            sql("id=like=1").unwrap_err(),
+            RsqlError::UnknownOperator("=like=".to_owned())
+        );
+        assert_eq!(
+            sql("page=2").unwrap_err(),
+            RsqlError::UnknownParameter("page".to_owned())
+        );
+        assert_eq!(
+            sql("id==x").unwrap_err(),
+            RsqlError::InvalidValue {
+                column: "id".to_owned(),
+                value: "x".to_owned(),
+                message: "invalid digit found in string in `x` for `id`".to_owned(),
+            }
+        );
+        assert_eq!(
+            sql("(id==1").unwrap_err(),
+            RsqlError::Syntax {
+                position: 6,
+                message: "expected `)`".to_owned(),
+            }
+        );
+        assert_eq!(
+            sql("name=='Apple").unwrap_err(),
+            RsqlError::Syntax {
+                position: 12,
+                message: "expected `'`".to_owned(),
+            }
+        );
+        assert_eq!(
+            sql("id==1)").unwrap_err(),
+            RsqlError::Syntax {
+                position: 5,
+                message: "unexpected `)`".to_owned(),
+            }
+        );
+        let nested = |depth| format!("{}id==1{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(
+            sql(&nested(MAX_DEPTH)).unwrap(),
+            r#" WHERE "fruit"."id" = 1"#
+        );
+        assert_eq!(
+            sql(&nested(100_000)).unwrap_err(),
+            RsqlError::Syntax {
+                position: MAX_DEPTH + 1,
+                message: format!("more than {MAX_DEPTH} nested groups"),
+            }
+        );
+    }
+
+    #[test]
+    fn order_and_filter_str() {
+        assert_eq!(
+            fruit::Entity::find()
+                .filter_str("cake_id=1,name=Apple")
+                .unwrap()
+                .order_by_str("id:DESC")
+                .unwrap()
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"WHERE "fruit"."cake_id" = 1 AND "fruit"."name" = 'Apple'"#,
+                r#"ORDER BY "fruit"."id" DESC"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            fruit::Entity::find().order_by_str("price").unwrap_err(),
+            RsqlError::UnknownColumn("price".to_owned())
+        );
+        assert_eq!(
+            fruit::Entity::find()
+                .order_by_str("name,id:down")
+                .unwrap_err(),
+            RsqlError::Syntax {
+                position: 8,
+                message: "expected `asc` or `desc`".to_owned(),
+            }
+        );
+        assert_eq!(
+            fruit::Entity::find().filter_str("id=1,name").unwrap_err(),
+            RsqlError::Syntax {
+                position: 9,
+                message: "expected `=`".to_owned(),
+            }
+        );
+        assert_eq!(
+            fruit::Entity::find().filter_str("id=x").unwrap_err(),
+            RsqlError::InvalidValue {
+                column: "id".to_owned(),
+                value: "x".to_owned(),
+                message: "invalid digit found in string in `x` for `id`".to_owned(),
+            }
+        );
+    }
+}
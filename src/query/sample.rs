@@ -0,0 +1,70 @@
+use crate::{DbBackend, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Select};
+use sea_query::{
+    extension::postgres::{PostgresSelectStatementExt, SampleMethod},
+    Expr, Func, Order, SimpleExpr,
+};
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Pick `n` random rows, by ordering by `RANDOM()`, or `RAND()` on MySQL. This reads the
+    /// whole table; on a large Postgres table prefer [`Select::sample_percent`].
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .sample(3)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` ORDER BY RAND() ASC LIMIT 3"
+    /// );
+    /// ```
+    pub fn sample(self, n: u64) -> Self {
+        QueryOrder::order_by(self, SimpleExpr::from(Func::random()), Order::Asc).limit(n)
+    }
+
+    /// Pick each row with a chance of `percent` in 100: with `TABLESAMPLE BERNOULLI` on Postgres,
+    /// and by comparing a random number for each row elsewhere. The number of rows varies
+    /// from one run to the next.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let sample = |db: DbBackend| {
+    ///     cake::Entity::find()
+    ///         .sample_percent(db, 10.0)
+    ///         .build(db)
+    ///         .to_string()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     sample(DbBackend::Postgres),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" TABLESAMPLE BERNOULLI (10)"#
+    /// );
+    /// assert_eq!(
+    ///     sample(DbBackend::MySql),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE RAND() < 0.1"
+    /// );
+    /// assert_eq!(
+    ///     sample(DbBackend::Sqlite),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE ABS(RANDOM() % 1000000) < 100000"#
+    /// );
+    /// ```
+    pub fn sample_percent(mut self, backend: DbBackend, percent: f64) -> Self {
+        match backend {
+            DbBackend::Postgres => {
+                self.query
+                    .table_sample(SampleMethod::BERNOULLI, percent, None);
+                self
+            }
+            DbBackend::MySql => self.filter(Expr::expr(Func::random()).lt(percent / 100.0)),
+            DbBackend::Sqlite => self.filter(
+                Expr::expr(Func::abs(Expr::cust("RANDOM() % 1000000")))
+                    .lt((percent * 10000.0).round() as i64),
+            ),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use crate::{
+    ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Refusal,
+};
 use core::fmt::Debug;
 use core::marker::PhantomData;
 use sea_query::{Expr, IntoColumnRef, SelectStatement, SimpleExpr};
@@ -11,6 +13,7 @@ where
 {
     pub(crate) query: SelectStatement,
     pub(crate) entity: PhantomData<E>,
+    pub(crate) refusal: Option<Refusal>,
 }
 
 /// Defines a structure to perform a SELECT operation on two Models
@@ -22,6 +25,7 @@ where
 {
     pub(crate) query: SelectStatement,
     pub(crate) entity: PhantomData<(E, F)>,
+    pub(crate) refusal: Option<Refusal>,
 }
 
 /// Defines a structure to perform a SELECT operation on many Models
@@ -33,6 +37,7 @@ where
 {
     pub(crate) query: SelectStatement,
     pub(crate) entity: PhantomData<(E, F)>,
+    pub(crate) refusal: Option<Refusal>,
 }
 
 /// Defines a structure to perform a SELECT operation on two Models
@@ -45,6 +50,7 @@ where
 {
     pub(crate) query: SelectStatement,
     pub(crate) entity: PhantomData<(E, F, G)>,
+    pub(crate) refusal: Option<Refusal>,
 }
 
 /// Performs a conversion to [SimpleExpr]
@@ -105,6 +111,24 @@ macro_rules! impl_query_trait {
     };
 }
 
+macro_rules! impl_refuse {
+    ( $selector: ident < $( $entity: ident ),+ > ) => {
+        impl< $( $entity ),+ > $selector< $( $entity ),+ >
+        where
+            $( $entity: EntityTrait ),+
+        {
+            /// Carry a refusal found while building the query, keeping the first one
+            pub(crate) fn refuse(mut self, refusal: Option<Refusal>) -> Self {
+                self.refusal = self.refusal.or(refusal);
+                self
+            }
+        }
+    };
+}
+
+impl_refuse!(Select<E>);
+impl_refuse!(SelectTwo<E, F>);
+
 impl_query_trait!(QuerySelect);
 impl_query_trait!(QueryFilter);
 impl_query_trait!(QueryOrder);
@@ -135,12 +159,24 @@ where
     E: EntityTrait,
 {
     pub(crate) fn new() -> Self {
+        Self::new_with_deleted().prepare_soft_delete()
+    }
+
+    pub(crate) fn new_with_deleted() -> Self {
+        Self::new_unscoped()
+            .prepare_default_scope()
+            .prepare_expiry()
+    }
+
+    pub(crate) fn new_unscoped() -> Self {
         Self {
             query: SelectStatement::new(),
             entity: PhantomData,
+            refusal: None,
         }
         .prepare_select()
         .prepare_from()
+        .prepare_tenant()
     }
 
     fn prepare_select(mut self) -> Self {
@@ -158,6 +194,35 @@ where
         self.query.from(E::default().table_ref());
         self
     }
+
+    fn prepare_tenant(mut self) -> Self {
+        if let Some(condition) = crate::tenant_condition::<E>() {
+            self = self.filter(condition);
+        }
+        self.refusal = crate::tenant_refusal::<E>();
+        self
+    }
+
+    fn prepare_default_scope(mut self) -> Self {
+        if let Some(condition) = E::default().default_scope() {
+            self = self.filter(condition);
+        }
+        self
+    }
+
+    fn prepare_expiry(mut self) -> Self {
+        if let Some(condition) = crate::expiry_condition::<E>() {
+            self = self.filter(condition);
+        }
+        self
+    }
+
+    fn prepare_soft_delete(mut self) -> Self {
+        if let Some(condition) = crate::soft_delete_condition::<E>() {
+            self = self.filter(condition);
+        }
+        self
+    }
 }
 
 impl<E> QueryTrait for Select<E>
@@ -1,4 +1,6 @@
-use crate::{ColumnTrait, DbBackend, IntoIdentity, IntoSimpleExpr, QuerySelect, Statement};
+use crate::{
+    ColumnTrait, DbBackend, IntoIdentity, IntoSimpleExpr, QuerySelect, Statement, WindowExpr,
+};
 use sea_query::QueryStatementBuilder;
 
 /// A Trait for any type performing queries on a Model or ActiveModel
@@ -24,6 +26,24 @@ pub trait QueryTrait {
         )
     }
 
+    /// Render the query for snapshot testing, see [Statement::to_sql_string]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find_by_id(1).to_sql_string(DbBackend::Sqlite),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1"#,
+    ///         "-- $1: 1",
+    ///     ]
+    ///     .join("\n")
+    /// );
+    /// ```
+    fn to_sql_string(&self, db_backend: DbBackend) -> String {
+        self.build(db_backend).to_sql_string()
+    }
+
     /// Apply an operation on the [QueryTrait::QueryStatement] if the given `Option<T>` is `Some(_)`
     ///
     /// # Example
@@ -56,6 +76,34 @@ pub trait QueryTrait {
     }
 }
 
+/// Assert that a query renders as expected on a backend, see [QueryTrait::to_sql_string].
+/// Leading and trailing whitespace of every line of the expected string is ignored, so it
+/// can be indented along with the code.
+///
+/// ```
+/// use sea_orm::{assert_query_eq, entity::*, query::*, tests_cfg::cake, DbBackend};
+///
+/// assert_query_eq!(
+///     DbBackend::MySql,
+///     cake::Entity::find().filter(cake::Column::Name.contains("cheese")),
+///     r#"
+///         SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE $1
+///         -- $1: '%cheese%'
+///     "#
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_query_eq {
+    ($db_backend: expr, $query: expr, $expected: expr $(,)?) => {{
+        let expected: &str = $expected;
+        let expected: Vec<&str> = expected.trim().lines().map(str::trim).collect();
+        assert_eq!(
+            $crate::QueryTrait::to_sql_string(&$query, $db_backend),
+            expected.join("\n")
+        );
+    }};
+}
+
 /// Select specific column for partial model queries
 pub trait SelectColumns {
     /// Add a select column
@@ -70,6 +118,13 @@ pub trait SelectColumns {
     where
         C: IntoSimpleExpr,
         I: IntoIdentity;
+
+    /// Add a select window function with alias
+    ///
+    /// For more detail, please visit [QuerySelect::expr_window_as]
+    fn select_window_as<I>(self, expr: WindowExpr, alias: I) -> Self
+    where
+        I: IntoIdentity;
 }
 
 impl<S> SelectColumns for S
@@ -87,4 +142,43 @@ where
     {
         QuerySelect::column_as(self, col, alias)
     }
+
+    fn select_window_as<I>(self, expr: WindowExpr, alias: I) -> Self
+    where
+        I: IntoIdentity,
+    {
+        QuerySelect::expr_window_as(self, expr, alias)
+    }
+}
+
+/// An expression selected with alias by a partial model: an expression, or a [WindowExpr]
+pub trait IntoSelectExpr {
+    /// Add the expression to the select columns with alias
+    fn select_as<S, I>(self, select: S, alias: I) -> S
+    where
+        S: SelectColumns,
+        I: IntoIdentity;
+}
+
+impl<T> IntoSelectExpr for T
+where
+    T: IntoSimpleExpr,
+{
+    fn select_as<S, I>(self, select: S, alias: I) -> S
+    where
+        S: SelectColumns,
+        I: IntoIdentity,
+    {
+        select.select_column_as(self, alias)
+    }
+}
+
+impl IntoSelectExpr for WindowExpr {
+    fn select_as<S, I>(self, select: S, alias: I) -> S
+    where
+        S: SelectColumns,
+        I: IntoIdentity,
+    {
+        select.select_window_as(self, alias)
+    }
 }
@@ -0,0 +1,176 @@
+use crate::{ConnectionTrait, DbBackend, DbErr, EntityTrait, Statement};
+use sea_query::{Alias, Expr, Query, Table};
+use std::marker::PhantomData;
+
+/// Remove all rows of a table, see [`EntityTrait::truncate`]
+#[derive(Clone, Debug)]
+pub struct Truncate<E>
+where
+    E: EntityTrait,
+{
+    restart_identity: bool,
+    cascade: bool,
+    entity: PhantomData<E>,
+}
+
+impl<E> Truncate<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            restart_identity: false,
+            cascade: false,
+            entity: PhantomData,
+        }
+    }
+
+    /// Restart the sequences of the auto-increment columns. MySQL always does.
+    pub fn restart_identity(mut self) -> Self {
+        self.restart_identity = true;
+        self
+    }
+
+    /// Also truncate the tables with foreign keys to this table. Only on Postgres; on SQLite
+    /// the `ON DELETE` actions of the foreign keys apply instead, and MySQL can't truncate a
+    /// table referenced by a foreign key.
+    pub fn cascade(mut self) -> Self {
+        self.cascade = true;
+        self
+    }
+
+    /// Build the statements for a backend: `TRUNCATE TABLE` on Postgres and MySQL, and a
+    /// `DELETE` on SQLite, followed by removing the table from `sqlite_sequence` to restart
+    /// the identity.
+    pub fn build(&self, backend: DbBackend) -> Vec<Statement> {
+        let table = E::default().table_ref();
+        match backend {
+            DbBackend::Postgres | DbBackend::MySql => {
+                let stmt = backend.build(Table::truncate().table(table));
+                let mut sql = stmt.sql;
+                if backend == DbBackend::Postgres {
+                    if self.restart_identity {
+                        sql.push_str(" RESTART IDENTITY");
+                    }
+                    if self.cascade {
+                        sql.push_str(" CASCADE");
+                    }
+                }
+                vec![Statement { sql, ..stmt }]
+            }
+            DbBackend::Sqlite => {
+                let mut stmts = vec![backend.build(Query::delete().from_table(table))];
+                if self.restart_identity {
+                    stmts.push(
+                        backend.build(
+                            Query::delete()
+                                .from_table(Alias::new("sqlite_sequence"))
+                                .and_where(
+                                    Expr::col(Alias::new("name")).eq(E::default().table_name()),
+                                ),
+                        ),
+                    );
+                }
+                stmts
+            }
+        }
+    }
+
+    /// Execute the statements. On SQLite the identity is only restarted if `sqlite_sequence`
+    /// exists, which it does once a table with `AUTOINCREMENT` has been created.
+    pub async fn exec<C>(self, db: &C) -> Result<(), DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let backend = db.get_database_backend();
+        let mut stmts = self.build(backend).into_iter();
+        if let Some(stmt) = stmts.next() {
+            db.execute(stmt).await?;
+        }
+        if backend == DbBackend::Sqlite && self.restart_identity {
+            let found = db
+                .query_one(Statement::from_string(
+                    backend,
+                    "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'sqlite_sequence'",
+                ))
+                .await?;
+            if found.is_none() {
+                return Ok(());
+            }
+        }
+        for stmt in stmts {
+            db.execute(stmt).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn truncate_postgres() {
+        assert_eq!(
+            cake::Entity::truncate()
+                .restart_identity()
+                .cascade()
+                .build(DbBackend::Postgres),
+            [Statement::from_string(
+                DbBackend::Postgres,
+                r#"TRUNCATE TABLE "cake" RESTART IDENTITY CASCADE"#
+            )]
+        );
+        assert_eq!(
+            cake::Entity::truncate().cascade().build(DbBackend::MySql),
+            [Statement::from_string(
+                DbBackend::MySql,
+                "TRUNCATE TABLE `cake`"
+            )]
+        );
+    }
+
+    #[smol_potat::test]
+    async fn truncate_sqlite() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 3,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .append_query_results([[maplit::btreemap! {
+                "1" => Value::from(1),
+            }]])
+            .into_connection();
+
+        cake::Entity::truncate()
+            .restart_identity()
+            .exec(&db)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(DbBackend::Sqlite, r#"DELETE FROM "cake""#, []),
+                Transaction::one(Statement::from_string(
+                    DbBackend::Sqlite,
+                    "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'sqlite_sequence'",
+                )),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"DELETE FROM "sqlite_sequence" WHERE "name" = ?"#,
+                    ["cake".into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
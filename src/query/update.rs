@@ -1,9 +1,9 @@
 use crate::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    QueryFilter, QueryTrait, Refusal,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, IntoIden, SimpleExpr, UpdateStatement};
+use sea_query::{Expr, IntoIden, SimpleExpr, UpdateStatement, Value};
 
 /// Defines a structure to perform UPDATE query operations on a ActiveModel
 #[derive(Clone, Debug)]
@@ -17,6 +17,7 @@ where
 {
     pub(crate) query: UpdateStatement,
     pub(crate) model: A,
+    pub(crate) refusal: Option<Refusal>,
 }
 
 /// Defines an UPDATE operation on multiple ActiveModels
@@ -27,6 +28,7 @@ where
 {
     pub(crate) query: UpdateStatement,
     pub(crate) entity: PhantomData<E>,
+    pub(crate) refusal: Option<Refusal>,
 }
 
 impl Update {
@@ -55,9 +57,11 @@ impl Update {
                 .table(A::Entity::default().table_ref())
                 .to_owned(),
             model,
+            refusal: crate::tenant_refusal::<E>(),
         }
         .prepare_filters()
         .prepare_values()
+        .prepare_version()
     }
 
     /// Update many ActiveModel
@@ -78,9 +82,14 @@ impl Update {
     where
         E: EntityTrait,
     {
-        UpdateMany {
+        let update = UpdateMany {
             query: UpdateStatement::new().table(entity.table_ref()).to_owned(),
             entity: PhantomData,
+            refusal: crate::tenant_refusal::<E>(),
+        };
+        match crate::tenant_condition::<E>() {
+            Some(condition) => update.filter(condition),
+            None => update,
         }
     }
 }
@@ -96,15 +105,23 @@ where
                 ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
                     self = self.filter(col.eq(value));
                 }
+                ActiveValue::SetExpr(expr) => {
+                    self = self.filter(col.into_expr().eq(expr));
+                }
                 ActiveValue::NotSet => panic!("PrimaryKey is not set"),
             }
         }
+        if let Some(condition) = crate::tenant_condition::<A::Entity>() {
+            self = self.filter(condition);
+        }
         self
     }
 
     fn prepare_values(mut self) -> Self {
         for col in <A::Entity as EntityTrait>::Column::iter() {
-            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some() {
+            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some()
+                || col.def().is_version()
+            {
                 continue;
             }
             match self.model.get(col) {
@@ -112,11 +129,58 @@ where
                     let expr = col.save_as(Expr::val(value));
                     self.query.value(col, expr);
                 }
+                ActiveValue::SetExpr(expr) => {
+                    self.query.value(col, expr);
+                }
                 ActiveValue::Unchanged(_) | ActiveValue::NotSet => {}
             }
         }
+        stamp_updated_at(&mut self.query, &self.model);
+        self
+    }
+
+    /// Only update the row if its version column still has the version of the model, and
+    /// increment the version
+    fn prepare_version(mut self) -> Self {
+        if self.query.get_values().is_empty() {
+            return self;
+        }
+        for col in <A::Entity as EntityTrait>::Column::iter() {
+            if !col.def().is_version() {
+                continue;
+            }
+            if let Some(version) = self.model.get(col).into_value() {
+                let expr = Expr::expr(col.into_expr()).add(1);
+                self.query.value(col, expr);
+                self = self.filter(col.eq(version));
+            }
+        }
         self
     }
+
+    /// Whether the update checks the version of the row, see [ColumnDef::version](crate::ColumnDef::version)
+    pub(crate) fn is_versioned(&self) -> bool {
+        <A::Entity as EntityTrait>::Column::iter()
+            .any(|col| col.def().is_version() && self.model.get(col).into_value().is_some())
+    }
+}
+
+/// Set the `updated_at` columns the model sets no value or expression for, unless nothing is updated
+fn stamp_updated_at<A>(query: &mut UpdateStatement, model: &A)
+where
+    A: ActiveModelTrait,
+{
+    if query.get_values().is_empty() {
+        return;
+    }
+    for col in <A::Entity as EntityTrait>::Column::iter() {
+        if model.get(col).is_set() || model.get(col).is_expr() {
+            continue;
+        }
+        if let Some(expr) = crate::auto_timestamp(col, false) {
+            query.value(col, expr);
+        }
+    }
 }
 
 impl<A> QueryFilter for UpdateOne<A>
@@ -194,9 +258,13 @@ where
                     let expr = col.save_as(Expr::val(value));
                     self.query.value(col, expr);
                 }
+                ActiveValue::SetExpr(expr) => {
+                    self.query.value(col, expr);
+                }
                 ActiveValue::Unchanged(_) | ActiveValue::NotSet => {}
             }
         }
+        stamp_updated_at(&mut self.query, &model);
         self
     }
 
@@ -208,6 +276,45 @@ where
         self.query.value(col, expr);
         self
     }
+
+    /// Add an expression to the value of a column: `col = col + expr`
+    pub fn col_expr_add<T>(self, col: E::Column, expr: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        let expr = Expr::expr(col.into_expr()).add(expr);
+        self.col_expr(col, expr)
+    }
+
+    /// Increment a numeric column, without reading the rows first
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::update_many()
+    ///         .inc(fruit::Column::CakeId, 1)
+    ///         .filter(fruit::Column::Id.eq(2))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"UPDATE "fruit" SET "cake_id" = "fruit"."cake_id" + 1 WHERE "fruit"."id" = 2"#,
+    /// );
+    /// ```
+    pub fn inc<T>(self, col: E::Column, value: T) -> Self
+    where
+        T: Into<Value>,
+    {
+        self.col_expr_add(col, Expr::val(value))
+    }
+
+    /// Decrement a numeric column, without reading the rows first
+    pub fn dec<T>(self, col: E::Column, value: T) -> Self
+    where
+        T: Into<Value>,
+    {
+        let expr = Expr::expr(col.into_expr()).sub(Expr::val(value));
+        self.col_expr(col, expr)
+    }
 }
 
 #[cfg(test)]
@@ -311,7 +418,7 @@ mod tests {
                 .filter(lunch_set::Column::Tea.eq(Tea::BreakfastTea))
                 .build(DbBackend::Postgres)
                 .to_string(),
-            r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS tea) WHERE "lunch_set"."tea" = (CAST('BreakfastTea' AS tea))"#,
+            r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS "tea") WHERE "lunch_set"."tea" = (CAST('BreakfastTea' AS "tea"))"#,
         );
     }
 
@@ -325,7 +432,60 @@ mod tests {
             })
             .build(DbBackend::Postgres)
             .to_string(),
-            r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS tea) WHERE "lunch_set"."id" = 1"#,
+            r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS "tea") WHERE "lunch_set"."id" = 1"#,
+        );
+    }
+
+    #[test]
+    fn update_9() {
+        assert_eq!(
+            Update::many(fruit::Entity)
+                .dec(fruit::Column::CakeId, 1)
+                .col_expr_add(fruit::Column::Id, Expr::col(fruit::Column::CakeId))
+                .filter(fruit::Column::CakeId.gt(0))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "UPDATE `fruit` SET `cake_id` = `fruit`.`cake_id` - 1, `id` = `fruit`.`id` + `cake_id` WHERE `fruit`.`cake_id` > 0",
+        );
+    }
+
+    #[test]
+    fn update_10() {
+        assert_eq!(
+            Update::one(fruit::ActiveModel {
+                id: ActiveValue::expr(Expr::cust("currval('fruit_id_seq')")),
+                name: ActiveValue::set("Orange".to_owned()),
+                cake_id: ActiveValue::expr(Expr::col(fruit::Column::Id)),
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"UPDATE "fruit" SET "name" = 'Orange', "cake_id" = "id" WHERE "fruit"."id" = (currval('fruit_id_seq'))"#,
+        );
+    }
+    #[test]
+    fn update_11() {
+        assert_eq!(
+            fruit::Entity::update_batch([
+                fruit::ActiveModel {
+                    id: ActiveValue::unchanged(1),
+                    name: ActiveValue::set("Orange".to_owned()),
+                    cake_id: ActiveValue::expr(Expr::col(fruit::Column::CakeId).add(1)),
+                },
+                fruit::ActiveModel {
+                    id: ActiveValue::unchanged(2),
+                    name: ActiveValue::expr(Expr::cust("upper(\"name\")")),
+                    cake_id: ActiveValue::not_set(),
+                },
+            ])
+            .build(DbBackend::Postgres)
+            .to_string(),
+            [
+                r#"UPDATE "fruit" SET"#,
+                r#""name" = (CASE WHEN ("fruit"."id" = 1) THEN 'Orange' WHEN ("fruit"."id" = 2) THEN upper("name") ELSE "fruit"."name" END),"#,
+                r#""cake_id" = (CASE WHEN ("fruit"."id" = 1) THEN "cake_id" + 1 ELSE "fruit"."cake_id" END)"#,
+                r#"WHERE "fruit"."id" IN (1, 2)"#,
+            ]
+            .join(" "),
         );
     }
 }
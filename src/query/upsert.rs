@@ -0,0 +1,176 @@
+use crate::{ActiveModelTrait, ColumnTrait, EntityTrait, Insert, TryInsert};
+use sea_query::{Alias, ColumnRef, IntoColumnRef, IntoIden, OnConflict, SimpleExpr};
+use std::marker::PhantomData;
+
+/// The value a column would have had in the row proposed for insertion, to use in the
+/// expressions of an [Upsert]. It reads the `excluded` table of Postgres and SQLite; MySQL has
+/// no such table, use [Upsert::update_column] there.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Excluded<C>(pub C)
+where
+    C: ColumnTrait;
+
+impl<C> IntoColumnRef for Excluded<C>
+where
+    C: ColumnTrait,
+{
+    fn into_column_ref(self) -> ColumnRef {
+        ColumnRef::TableColumn(Alias::new("excluded").into_iden(), self.0.into_iden())
+    }
+}
+
+/// An `ON CONFLICT` clause on the columns of an entity, to pass to [Insert::upsert].
+/// Unlike [OnConflict], it only takes columns of the entity inserted into.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, Excluded, Upsert};
+/// use sea_query::Expr;
+///
+/// let orange = cake::ActiveModel {
+///     id: ActiveValue::set(2),
+///     name: ActiveValue::set("Orange".to_owned()),
+/// };
+/// assert_eq!(
+///     cake::Entity::insert(orange)
+///         .upsert(
+///             Upsert::column(cake::Column::Id)
+///                 .update_column(cake::Column::Name)
+///                 .value(cake::Column::Id, Expr::col(Excluded(cake::Column::Id)).add(1))
+///         )
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange')"#,
+///         r#"ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name", "id" = "excluded"."id" + 1"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Upsert<C>
+where
+    C: ColumnTrait,
+{
+    on_conflict: OnConflict,
+    columns: PhantomData<C>,
+}
+
+impl<C> Upsert<C>
+where
+    C: ColumnTrait,
+{
+    /// On conflict on a column
+    pub fn column(column: C) -> Self {
+        Self::columns([column])
+    }
+
+    /// On conflict on columns
+    pub fn columns<I>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        Self {
+            on_conflict: OnConflict::columns(columns),
+            columns: PhantomData,
+        }
+    }
+
+    /// Do nothing on conflict
+    pub fn do_nothing(mut self) -> Self {
+        self.on_conflict.do_nothing();
+        self
+    }
+
+    /// Update a column to the value of the row proposed for insertion
+    pub fn update_column(self, column: C) -> Self {
+        self.update_columns([column])
+    }
+
+    /// Update columns to the values of the row proposed for insertion
+    pub fn update_columns<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        self.on_conflict.update_columns(columns);
+        self
+    }
+
+    /// Update a column to an expression, which may read the row proposed for insertion
+    /// with [Excluded]
+    pub fn value<T>(mut self, column: C, value: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.on_conflict.value(column, value);
+        self
+    }
+
+    /// Only update the rows passing a condition. Not supported by MySQL.
+    pub fn action_and_where(mut self, condition: SimpleExpr) -> Self {
+        self.on_conflict.action_and_where(condition);
+        self
+    }
+}
+
+impl<C> From<Upsert<C>> for OnConflict
+where
+    C: ColumnTrait,
+{
+    fn from(upsert: Upsert<C>) -> Self {
+        upsert.on_conflict
+    }
+}
+
+impl<A> Insert<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Set an `ON CONFLICT` clause on the columns of the entity, see [Upsert]
+    pub fn upsert(self, upsert: Upsert<<A::Entity as EntityTrait>::Column>) -> Self {
+        self.on_conflict(upsert.into())
+    }
+}
+
+impl<A> TryInsert<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Set an `ON CONFLICT` clause on the columns of the entity, see [Upsert]
+    pub fn upsert(self, upsert: Upsert<<A::Entity as EntityTrait>::Column>) -> Self {
+        self.on_conflict(upsert.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests_cfg::cake, DbBackend, QueryTrait, Set};
+    use pretty_assertions::assert_eq;
+    use sea_query::Expr;
+
+    #[test]
+    fn upsert_sqlite() {
+        let insert = cake::Entity::insert(cake::ActiveModel {
+            id: Set(1),
+            name: Set("Apple Pie".to_owned()),
+        })
+        .do_nothing()
+        .upsert(
+            Upsert::column(cake::Column::Id)
+                .value(
+                    cake::Column::Id,
+                    Expr::col(cake::Column::Id).add(Expr::col(Excluded(cake::Column::Id))),
+                )
+                .action_and_where(Expr::col(Excluded(cake::Column::Name)).ne("")),
+        );
+        assert_eq!(
+            insert.build(DbBackend::Sqlite).to_string(),
+            [
+                r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Apple Pie')"#,
+                r#"ON CONFLICT ("id") DO UPDATE SET "id" = "id" + "excluded"."id""#,
+                r#"WHERE "excluded"."name" <> ''"#,
+            ]
+            .join(" ")
+        );
+    }
+}
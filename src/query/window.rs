@@ -0,0 +1,254 @@
+use crate::IntoSimpleExpr;
+use sea_query::{Alias, Expr, Func, Order, OverStatement, SimpleExpr, WindowStatement};
+pub use sea_query::{Frame, FrameType};
+
+/// The window of rows a [WindowFunc] is computed over: the partitions of the rows, the order of
+/// the rows in a partition, and the frame of rows around the current one.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, Window, WindowFunc};
+///
+/// assert_eq!(
+///     cake::Entity::find()
+///         .select_only()
+///         .column(cake::Column::Name)
+///         .expr_window_as(
+///             WindowFunc::row_number().over(
+///                 Window::new()
+///                     .partition_by(cake::Column::Name)
+///                     .order_by(cake::Column::Id, Order::Desc)
+///             ),
+///             "rank"
+///         )
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "cake"."name", ROW_NUMBER() OVER"#,
+///         r#"( PARTITION BY "cake"."name" ORDER BY "cake"."id" DESC ) AS "rank" FROM "cake""#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Window {
+    statement: WindowStatement,
+}
+
+impl Window {
+    /// An empty window, of all the rows
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Partition the rows by an expression
+    pub fn partition_by<T>(mut self, expr: T) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        self.statement.add_partition_by(expr.into_simple_expr());
+        self
+    }
+
+    /// Order the rows of a partition by an expression
+    pub fn order_by<T>(mut self, expr: T, order: Order) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        self.statement.order_by_expr(expr.into_simple_expr(), order);
+        self
+    }
+
+    /// Frame the rows from `start` to the current row
+    pub fn frame_start(mut self, r#type: FrameType, start: Frame) -> Self {
+        self.statement.frame_start(r#type, start);
+        self
+    }
+
+    /// Frame the rows between `start` and `end`
+    pub fn frame_between(mut self, r#type: FrameType, start: Frame, end: Frame) -> Self {
+        self.statement.frame_between(r#type, start, end);
+        self
+    }
+
+    /// Frame the physical rows between `start` and `end`
+    pub fn rows_between(self, start: Frame, end: Frame) -> Self {
+        self.frame_between(FrameType::Rows, start, end)
+    }
+
+    /// Frame the rows between `start` and `end`, by their values of the order
+    pub fn range_between(self, start: Frame, end: Frame) -> Self {
+        self.frame_between(FrameType::Range, start, end)
+    }
+}
+
+impl From<Window> for WindowStatement {
+    fn from(window: Window) -> Self {
+        window.statement
+    }
+}
+
+/// A function computed for each row over a [Window] of rows: a ranking, a value of another row,
+/// or an aggregate.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, Frame, Window, WindowFunc};
+///
+/// assert_eq!(
+///     cake::Entity::find()
+///         .select_only()
+///         .expr_window_as(
+///             WindowFunc::sum(cake::Column::Id).over(
+///                 Window::new()
+///                     .order_by(cake::Column::Id, Order::Asc)
+///                     .rows_between(Frame::UnboundedPreceding, Frame::CurrentRow)
+///             ),
+///             "running_total"
+///         )
+///         .expr_window_as(
+///             WindowFunc::lag(cake::Column::Name, 1)
+///                 .over(Window::new().order_by(cake::Column::Id, Order::Asc)),
+///             "previous_name"
+///         )
+///         .build(DbBackend::MySql)
+///         .to_string(),
+///     [
+///         r#"SELECT SUM(`cake`.`id`) OVER (  ORDER BY `cake`.`id` ASC"#,
+///         r#"ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW ) AS `running_total`,"#,
+///         r#"LAG(`cake`.`name`, 1) OVER (  ORDER BY `cake`.`id` ASC ) AS `previous_name`"#,
+///         r#"FROM `cake`"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowFunc {
+    expr: SimpleExpr,
+}
+
+impl WindowFunc {
+    /// Any function computed over a window, like an aggregate function
+    pub fn new<T>(expr: T) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self {
+            expr: expr.into_simple_expr(),
+        }
+    }
+
+    fn custom<I>(name: &str, args: I) -> Self
+    where
+        I: IntoIterator<Item = SimpleExpr>,
+    {
+        Self::new(SimpleExpr::from(Func::cust(Alias::new(name)).args(args)))
+    }
+
+    /// The number of the row in its partition, from 1
+    pub fn row_number() -> Self {
+        Self::custom("ROW_NUMBER", [])
+    }
+
+    /// The rank of the row in its partition, with gaps after the rows of the same rank
+    pub fn rank() -> Self {
+        Self::custom("RANK", [])
+    }
+
+    /// The rank of the row in its partition, without gaps
+    pub fn dense_rank() -> Self {
+        Self::custom("DENSE_RANK", [])
+    }
+
+    /// The value of the expression in the row `offset` rows before, in its partition
+    pub fn lag<T>(expr: T, offset: u32) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self::custom(
+            "LAG",
+            [expr.into_simple_expr(), Expr::cust(offset.to_string())],
+        )
+    }
+
+    /// The value of the expression in the row `offset` rows after, in its partition
+    pub fn lead<T>(expr: T, offset: u32) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self::custom(
+            "LEAD",
+            [expr.into_simple_expr(), Expr::cust(offset.to_string())],
+        )
+    }
+
+    /// The value of the expression in the first row of the frame
+    pub fn first_value<T>(expr: T) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self::custom("FIRST_VALUE", [expr.into_simple_expr()])
+    }
+
+    /// The value of the expression in the last row of the frame
+    pub fn last_value<T>(expr: T) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self::custom("LAST_VALUE", [expr.into_simple_expr()])
+    }
+
+    /// The sum of the expression over the frame
+    pub fn sum<T>(expr: T) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self::new(SimpleExpr::from(Func::sum(expr.into_simple_expr())))
+    }
+
+    /// The average of the expression over the frame
+    pub fn avg<T>(expr: T) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self::new(SimpleExpr::from(Func::avg(expr.into_simple_expr())))
+    }
+
+    /// The number of the rows of the frame where the expression is not null
+    pub fn count<T>(expr: T) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self::new(SimpleExpr::from(Func::count(expr.into_simple_expr())))
+    }
+
+    /// The minimum of the expression over the frame
+    pub fn min<T>(expr: T) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self::new(SimpleExpr::from(Func::min(expr.into_simple_expr())))
+    }
+
+    /// The maximum of the expression over the frame
+    pub fn max<T>(expr: T) -> Self
+    where
+        T: IntoSimpleExpr,
+    {
+        Self::new(SimpleExpr::from(Func::max(expr.into_simple_expr())))
+    }
+
+    /// Compute the function over a window
+    pub fn over(self, window: Window) -> WindowExpr {
+        WindowExpr {
+            expr: self.expr,
+            window: window.into(),
+        }
+    }
+}
+
+/// A [WindowFunc] over its [Window], selected by [QuerySelect::expr_window_as](crate::QuerySelect::expr_window_as),
+/// or by `from_expr` of a partial model
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowExpr {
+    pub(crate) expr: SimpleExpr,
+    pub(crate) window: WindowStatement,
+}
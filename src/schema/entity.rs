@@ -1,10 +1,12 @@
 use crate::{
-    ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, Iterable, PrimaryKeyArity,
-    PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
+    history_table_ref, ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, Iterable,
+    PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
+    HISTORY_VALID_FROM, HISTORY_VALID_TO,
 };
 use sea_query::{
     extension::postgres::{Type, TypeCreateStatement},
-    ColumnDef, Iden, Index, IndexCreateStatement, SeaRc, TableCreateStatement,
+    Alias, ColumnDef, Iden, Index, IndexCreateStatement, SeaRc, Table, TableCreateStatement,
+    TableTruncateStatement,
 };
 
 impl Schema {
@@ -29,7 +31,26 @@ impl Schema {
     where
         E: EntityTrait,
     {
-        create_table_from_entity(entity, self.backend)
+        create_table_from_entity(entity, self.backend, self.cockroach)
+    }
+
+    /// Truncates the table of an Entity. See [TableTruncateStatement] for more details.
+    /// SQLite has no `TRUNCATE`, see [EntityTrait::truncate] for any backend.
+    pub fn truncate_table_from_entity<E>(&self, entity: E) -> TableTruncateStatement
+    where
+        E: EntityTrait,
+    {
+        Table::truncate().table(entity.table_ref()).to_owned()
+    }
+
+    /// Creates the history table of a [versioned](crate::EntityName::versioned) Entity, with the
+    /// columns of the Entity and the period each version of a row was current.
+    /// See [Select::as_of](crate::Select::as_of)
+    pub fn create_history_table_from_entity<E>(&self, entity: E) -> TableCreateStatement
+    where
+        E: EntityTrait,
+    {
+        create_history_table_from_entity(entity, self.backend)
     }
 
     /// Creates the indexes from an Entity, returning an empty Vec if there are none
@@ -85,7 +106,7 @@ impl Schema {
     where
         E: EntityTrait,
     {
-        column_def_from_entity_column::<E>(column, self.backend)
+        column_def_from_entity_column::<E>(column, self.backend, self.cockroach)
     }
 }
 
@@ -153,7 +174,11 @@ where
     vec
 }
 
-pub(crate) fn create_table_from_entity<E>(entity: E, backend: DbBackend) -> TableCreateStatement
+pub(crate) fn create_table_from_entity<E>(
+    entity: E,
+    backend: DbBackend,
+    cockroach: bool,
+) -> TableCreateStatement
 where
     E: EntityTrait,
 {
@@ -164,7 +189,7 @@ where
     }
 
     for column in E::Column::iter() {
-        let mut column_def = column_def_from_entity_column::<E>(column, backend);
+        let mut column_def = column_def_from_entity_column::<E>(column, backend, cockroach);
         stmt.col(&mut column_def);
     }
 
@@ -178,7 +203,7 @@ where
 
     for relation in E::Relation::iter() {
         let relation = relation.def();
-        if relation.is_owner {
+        if relation.is_owner || relation.morph.is_some() {
             continue;
         }
         stmt.foreign_key(&mut relation.into());
@@ -187,22 +212,60 @@ where
     stmt.table(entity.table_ref()).take()
 }
 
-fn column_def_from_entity_column<E>(column: E::Column, backend: DbBackend) -> ColumnDef
+pub(crate) fn create_history_table_from_entity<E>(
+    entity: E,
+    backend: DbBackend,
+) -> TableCreateStatement
 where
     E: EntityTrait,
 {
-    let orm_column_def = column.def();
-    let types = match &orm_column_def.col_type {
+    let mut stmt = TableCreateStatement::new();
+
+    for column in E::Column::iter() {
+        let orm_column_def = column.def();
+        let mut column_def = ColumnDef::new_with_type(
+            column,
+            column_type_for_backend(orm_column_def.col_type, backend),
+        );
+        if !orm_column_def.null {
+            column_def.not_null();
+        }
+        stmt.col(&mut column_def);
+    }
+    stmt.col(
+        ColumnDef::new(Alias::new(HISTORY_VALID_FROM))
+            .timestamp()
+            .not_null(),
+    );
+    stmt.col(ColumnDef::new(Alias::new(HISTORY_VALID_TO)).timestamp());
+
+    stmt.table(history_table_ref(&entity)).take()
+}
+
+fn column_type_for_backend(col_type: ColumnType, backend: DbBackend) -> ColumnType {
+    match &col_type {
         ColumnType::Enum { name, variants } => match backend {
             DbBackend::MySql => {
                 let variants: Vec<String> = variants.iter().map(|v| v.to_string()).collect();
                 ColumnType::custom(format!("ENUM('{}')", variants.join("', '")).as_str())
             }
             DbBackend::Postgres => ColumnType::Custom(SeaRc::clone(name)),
-            DbBackend::Sqlite => orm_column_def.col_type,
+            DbBackend::Sqlite => col_type,
         },
-        _ => orm_column_def.col_type,
-    };
+        _ => col_type,
+    }
+}
+
+fn column_def_from_entity_column<E>(
+    column: E::Column,
+    backend: DbBackend,
+    cockroach: bool,
+) -> ColumnDef
+where
+    E: EntityTrait,
+{
+    let orm_column_def = column.def();
+    let types = column_type_for_backend(orm_column_def.col_type, backend);
     let mut column_def = ColumnDef::new_with_type(column, types);
     if !orm_column_def.null {
         column_def.not_null();
@@ -219,7 +282,11 @@ where
     for primary_key in E::PrimaryKey::iter() {
         if column.to_string() == primary_key.into_column().to_string() {
             if E::PrimaryKey::auto_increment() {
-                column_def.auto_increment();
+                if cockroach {
+                    column_def.extra("GENERATED BY DEFAULT AS IDENTITY");
+                } else {
+                    column_def.auto_increment();
+                }
             }
             if <<E::PrimaryKey as PrimaryKeyTrait>::ValueType as PrimaryKeyArity>::ARITY == 1 {
                 column_def.primary_key();
@@ -234,6 +301,117 @@ mod tests {
     use crate::{sea_query::*, tests_cfg::*, DbBackend, EntityName, Schema};
     use pretty_assertions::assert_eq;
 
+    mod tray {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "tray")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(has_many = "super::topping::Entity")]
+            Topping,
+        }
+
+        impl Related<super::topping::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Topping.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod topping {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "topping")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub tray_id: Option<i32>,
+            pub cake_id: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::tray::Entity",
+                from = "Column::TrayId",
+                to = "super::tray::Column::Id",
+                on_delete = "SET NULL",
+                on_update = "cascade"
+            )]
+            Tray,
+            #[sea_orm(
+                belongs_to = "crate::tests_cfg::cake::Entity",
+                from = "Column::CakeId",
+                to = "crate::tests_cfg::cake::Column::Id",
+                on_delete = "Restrict",
+                on_update = "NO ACTION"
+            )]
+            Cake,
+        }
+
+        impl Related<super::tray::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Tray.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn test_create_table_from_entity_foreign_key_actions() {
+        assert_eq!(
+            DbBackend::Postgres
+                .build(&Schema::new(DbBackend::Postgres).create_table_from_entity(topping::Entity))
+                .to_string(),
+            [
+                r#"CREATE TABLE "topping" ("#,
+                r#""id" serial NOT NULL PRIMARY KEY,"#,
+                r#""tray_id" integer,"#,
+                r#""cake_id" integer NOT NULL,"#,
+                r#"CONSTRAINT "fk-topping-tray_id" FOREIGN KEY ("tray_id") REFERENCES "tray" ("id")"#,
+                r#"ON DELETE SET NULL ON UPDATE CASCADE,"#,
+                r#"CONSTRAINT "fk-topping-cake_id" FOREIGN KEY ("cake_id") REFERENCES "cake" ("id")"#,
+                r#"ON DELETE RESTRICT ON UPDATE NO ACTION"#,
+                r#")"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_actions_of_reverse_relation() {
+        use crate::RelationTrait;
+
+        let has_many = tray::Relation::Topping.def();
+        assert!(matches!(has_many.on_delete, Some(ForeignKeyAction::SetNull)));
+        assert!(matches!(has_many.on_update, Some(ForeignKeyAction::Cascade)));
+
+        let foreign_key: TableForeignKey = has_many.rev().into();
+        let belongs_to: TableForeignKey = topping::Relation::Tray.def().into();
+        assert_eq!(
+            Table::alter()
+                .table(topping::Entity)
+                .add_foreign_key(&foreign_key)
+                .to_string(PostgresQueryBuilder),
+            Table::alter()
+                .table(topping::Entity)
+                .add_foreign_key(&belongs_to)
+                .to_string(PostgresQueryBuilder),
+        );
+    }
+
     #[test]
     fn test_create_table_from_entity_table_ref() {
         for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
@@ -286,6 +464,23 @@ mod tests {
             .to_owned()
     }
 
+    #[test]
+    fn test_create_table_from_entity_cockroach() {
+        let schema = Schema::new_cockroach();
+        assert_eq!(
+            DbBackend::Postgres
+                .build(&schema.create_table_from_entity(cake::Entity))
+                .to_string(),
+            r#"CREATE TABLE "cake" ( "id" integer NOT NULL GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY, "name" varchar NOT NULL )"#
+        );
+        assert_eq!(
+            DbBackend::Postgres
+                .build(&Schema::new(DbBackend::Postgres).create_table_from_entity(cake::Entity))
+                .to_string(),
+            r#"CREATE TABLE "cake" ( "id" serial NOT NULL PRIMARY KEY, "name" varchar NOT NULL )"#
+        );
+    }
+
     #[test]
     fn test_create_index_from_entity_table_ref() {
         for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
@@ -9,11 +9,26 @@ mod json;
 #[derive(Debug)]
 pub struct Schema {
     backend: DbBackend,
+    cockroach: bool,
 }
 
 impl Schema {
     /// Create a helper for a specific database backend
     pub fn new(backend: DbBackend) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            cockroach: false,
+        }
+    }
+
+    /// Create a helper for CockroachDB, which shares the PostgreSQL dialect.
+    ///
+    /// Auto-incrementing primary keys become identity columns, because CockroachDB
+    /// turns `serial` into a 64-bit `unique_rowid()` column regardless of the Rust type.
+    pub fn new_cockroach() -> Self {
+        Self {
+            backend: DbBackend::Postgres,
+            cockroach: true,
+        }
     }
 }
@@ -0,0 +1,231 @@
+use crate::{
+    entity::EntityRef, ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbBackend,
+    DbErr, EntityTrait, Schema,
+};
+use sea_query::{IndexCreateStatement, TableCreateStatement};
+use std::collections::VecDeque;
+
+/// An in-memory SQLite database with the tables of a set of entities and their related
+/// entities created, for writing integration tests without a database server.
+///
+/// ```
+/// # #[cfg(feature = "runtime-tokio")]
+/// # async fn function() -> Result<(), sea_orm::DbErr> {
+/// # use sea_orm::{entity::*, tests_cfg::*, testing::TestDb};
+/// let db = TestDb::from_entities((cake::Entity, fruit::Entity)).await?;
+///
+/// assert_eq!(cake::Entity::find().all(db.conn()).await?, []);
+///
+/// db.close().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TestDb {
+    db: DatabaseConnection,
+}
+
+/// A list of entities to create the tables of, implemented for every entity and tuples
+/// of up to 12 entities. The tables of the entities related to them are created as well,
+/// those referenced by a foreign key first.
+pub trait EntityList {
+    /// The statements creating the tables, foreign keys included
+    fn create_tables(&self, schema: &Schema) -> Vec<TableCreateStatement>;
+
+    /// The statements creating the indexes on the tables
+    fn create_indexes(&self, schema: &Schema) -> Vec<IndexCreateStatement>;
+}
+
+impl TestDb {
+    /// Connect to a fresh `sqlite::memory:` database and create the tables of the entities,
+    /// and of the entities related to them
+    pub async fn from_entities<L>(entities: L) -> Result<Self, DbErr>
+    where
+        L: EntityList,
+    {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        // the database only lives as long as a connection to it is open
+        options.max_connections(1).min_connections(1);
+        let db = Database::connect(options).await?;
+
+        let schema = Schema::new(DbBackend::Sqlite);
+        for stmt in entities.create_tables(&schema) {
+            db.execute(DbBackend::Sqlite.build(&stmt)).await?;
+        }
+        for stmt in entities.create_indexes(&schema) {
+            db.execute(DbBackend::Sqlite.build(&stmt)).await?;
+        }
+
+        Ok(Self { db })
+    }
+
+    /// Get the connection to the database
+    pub fn conn(&self) -> &DatabaseConnection {
+        &self.db
+    }
+
+    /// Close the connection, discarding the database
+    pub async fn close(self) -> Result<(), DbErr> {
+        self.db.close().await
+    }
+}
+
+impl<E> EntityList for E
+where
+    E: EntityTrait,
+{
+    fn create_tables(&self, schema: &Schema) -> Vec<TableCreateStatement> {
+        related_entities(vec![EntityRef::of::<E>()])
+            .iter()
+            .map(|entity| (entity.create_table)(schema))
+            .collect()
+    }
+
+    fn create_indexes(&self, schema: &Schema) -> Vec<IndexCreateStatement> {
+        related_entities(vec![EntityRef::of::<E>()])
+            .iter()
+            .flat_map(|entity| (entity.create_indexes)(schema))
+            .collect()
+    }
+}
+
+macro_rules! impl_entity_list {
+    ( $($T:ident : $N:tt),+ ) => {
+        impl<$($T),+> EntityList for ($($T,)+)
+        where
+            $($T: EntityTrait,)+
+        {
+            fn create_tables(&self, schema: &Schema) -> Vec<TableCreateStatement> {
+                related_entities(vec![$(EntityRef::of::<$T>()),+])
+                    .iter()
+                    .map(|entity| (entity.create_table)(schema))
+                    .collect()
+            }
+
+            fn create_indexes(&self, schema: &Schema) -> Vec<IndexCreateStatement> {
+                related_entities(vec![$(EntityRef::of::<$T>()),+])
+                    .iter()
+                    .flat_map(|entity| (entity.create_indexes)(schema))
+                    .collect()
+            }
+        }
+    };
+}
+
+/// The entities and every entity related to them, each after the entities it references
+/// by a foreign key. Entities of the same table are only taken once, and entities referencing
+/// each other are kept in the order they are found.
+fn related_entities(entities: Vec<EntityRef>) -> Vec<EntityRef> {
+    let mut found: Vec<(String, EntityRef)> = Vec::new();
+    let mut queue = VecDeque::from(entities);
+    while let Some(entity) = queue.pop_front() {
+        let table_name = (entity.table_name)();
+        if found.iter().any(|(t, _)| t == &table_name) {
+            continue;
+        }
+        queue.extend(
+            (entity.relations)()
+                .into_iter()
+                .filter_map(|rel| rel.to_entity),
+        );
+        found.push((table_name, entity));
+    }
+
+    let mut ordered = Vec::new();
+    for (table_name, _) in found.iter() {
+        order_entity(table_name, &found, &mut ordered, &mut Vec::new());
+    }
+    ordered.into_iter().map(|(_, entity)| entity).collect()
+}
+
+fn order_entity(
+    table_name: &String,
+    found: &[(String, EntityRef)],
+    ordered: &mut Vec<(String, EntityRef)>,
+    visiting: &mut Vec<String>,
+) {
+    if ordered.iter().any(|(t, _)| t == table_name) || visiting.contains(table_name) {
+        return;
+    }
+    let Some((_, entity)) = found.iter().find(|(t, _)| t == table_name) else {
+        return;
+    };
+    visiting.push(table_name.clone());
+    for rel in (entity.relations)() {
+        // the table of a `belongs_to` relation has the foreign key
+        if let (false, Some(to_entity)) = (rel.is_owner, rel.to_entity) {
+            order_entity(&(to_entity.table_name)(), found, ordered, visiting);
+        }
+    }
+    visiting.pop();
+    ordered.push((table_name.clone(), *entity));
+}
+
+impl_entity_list!(A: 0);
+impl_entity_list!(A: 0, B: 1);
+impl_entity_list!(A: 0, B: 1, C: 2);
+impl_entity_list!(A: 0, B: 1, C: 2, D: 3);
+impl_entity_list!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_entity_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_entity_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_entity_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+impl_entity_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8);
+impl_entity_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9);
+impl_entity_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10);
+impl_entity_list!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10, L: 11);
+
+#[cfg(test)]
+#[cfg(feature = "runtime-async-std")]
+mod tests {
+    use super::*;
+    use crate::{entity::*, tests_cfg::*, PaginatorTrait};
+
+    #[smol_potat::test]
+    async fn from_entities() -> Result<(), DbErr> {
+        let db = TestDb::from_entities((cake::Entity, fruit::Entity)).await?;
+
+        let cake = cake::ActiveModel {
+            name: Set("Chocolate".to_owned()),
+            ..Default::default()
+        }
+        .insert(db.conn())
+        .await?;
+        fruit::ActiveModel {
+            name: Set("Cherry".to_owned()),
+            cake_id: Set(Some(cake.id)),
+            ..Default::default()
+        }
+        .insert(db.conn())
+        .await?;
+
+        assert_eq!(cake.find_related(fruit::Entity).count(db.conn()).await?, 1);
+
+        db.close().await
+    }
+
+    #[smol_potat::test]
+    async fn from_entities_related() -> Result<(), DbErr> {
+        let schema = Schema::new(DbBackend::Sqlite);
+        let tables: Vec<_> = fruit::Entity
+            .create_tables(&schema)
+            .iter()
+            .map(|stmt| DbBackend::Sqlite.build(stmt).sql)
+            .collect();
+        assert_eq!(tables.len(), 2);
+        assert!(tables[0].starts_with(r#"CREATE TABLE "cake""#));
+        assert!(tables[1].starts_with(r#"CREATE TABLE "fruit""#));
+
+        let db = TestDb::from_entities(fruit::Entity).await?;
+
+        cake::ActiveModel {
+            name: Set("Chocolate".to_owned()),
+            ..Default::default()
+        }
+        .insert(db.conn())
+        .await?;
+
+        assert_eq!(cake::Entity::find().count(db.conn()).await?, 1);
+
+        db.close().await
+    }
+}
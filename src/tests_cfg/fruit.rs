@@ -4,9 +4,9 @@ use crate::entity::prelude::*;
 #[cfg(feature = "with-json")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveFactory)]
 #[cfg_attr(feature = "with-json", derive(Serialize, Deserialize))]
-#[sea_orm(table_name = "fruit")]
+#[sea_orm(table_name = "fruit", graphql)]
 pub struct Model {
     #[sea_orm(primary_key)]
     #[cfg_attr(feature = "with-json", serde(skip_deserializing))]
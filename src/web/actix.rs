@@ -0,0 +1,255 @@
+//! The extractors for actix-web
+//!
+//! ```
+//! use actix_web::{middleware::from_fn, web::Data, App, HttpResponse};
+//! use sea_orm::{
+//!     entity::*,
+//!     tests_cfg::cake,
+//!     web::{self, Db, Pagination, Sort, Txn},
+//!     DatabaseConnection,
+//! };
+//!
+//! async fn list_cakes(
+//!     db: Db,
+//!     pagination: Pagination,
+//!     sort: Sort<cake::Entity>,
+//! ) -> HttpResponse {
+//!     let query = sort.apply(cake::Entity::find());
+//!     HttpResponse::Ok().json(pagination.fetch(query, &*db).await.unwrap())
+//! }
+//!
+//! async fn rename_cake(txn: Txn) -> HttpResponse {
+//!     cake::ActiveModel {
+//!         id: Set(1),
+//!         name: Set("Chocolate Forest".to_owned()),
+//!     }
+//!     .update(&*txn)
+//!     .await
+//!     .unwrap();
+//!     HttpResponse::Ok().finish()
+//! }
+//!
+//! fn app(db: DatabaseConnection) {
+//!     App::new()
+//!         .app_data(Data::new(db))
+//!         .wrap(from_fn(web::actix::transaction))
+//!         .route("/cakes", actix_web::web::get().to(list_cakes))
+//!         .route("/cakes/1/rename", actix_web::web::get().to(rename_cake));
+//! }
+//! ```
+
+use super::{internal_error, Db, Pagination, Sort, SortParams, Txn, TxnSlot};
+use crate::{DatabaseConnection, EntityTrait};
+use actix_web::{
+    body::MessageBody,
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    error::{ErrorBadRequest, ErrorInternalServerError},
+    middleware::Next,
+    web::{Data, Query},
+    Error, FromRequest, HttpMessage, HttpRequest,
+};
+use futures_util::future::LocalBoxFuture;
+use std::{
+    future::{ready, Ready},
+    sync::Arc,
+};
+
+fn database(req: &HttpRequest) -> Result<Arc<DatabaseConnection>, Error> {
+    req.app_data::<Data<DatabaseConnection>>()
+        .map(|db| db.clone().into_inner())
+        .ok_or_else(|| ErrorInternalServerError("Missing web::Data<DatabaseConnection>"))
+}
+
+impl FromRequest for Db {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(database(req).map(Self))
+    }
+}
+
+impl FromRequest for Txn {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let db = database(req);
+        let slot = req.extensions().get::<TxnSlot>().cloned();
+        Box::pin(async move {
+            let slot = slot.ok_or_else(|| {
+                ErrorInternalServerError("Txn requires the transaction middleware")
+            })?;
+            slot.get_or_begin(&*db?)
+                .await
+                .map_err(|err| ErrorInternalServerError(internal_error(err)))
+        })
+    }
+}
+
+impl FromRequest for Pagination {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            Query::<Self>::from_query(req.query_string())
+                .map(Query::into_inner)
+                .map_err(Error::from),
+        )
+    }
+}
+
+impl<E> FromRequest for Sort<E>
+where
+    E: EntityTrait,
+{
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            Query::<SortParams>::from_query(req.query_string())
+                .map_err(Error::from)
+                .and_then(|params| params.into_inner().parse().map_err(ErrorBadRequest)),
+        )
+    }
+}
+
+/// The middleware committing the [Txn] of a request if the response is a success (2xx),
+/// and rolling it back otherwise. Add it with `actix_web::middleware::from_fn`.
+pub async fn transaction(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let slot = TxnSlot::default();
+    req.extensions_mut().insert(slot.clone());
+    let response = next.call(req).await;
+    let success = matches!(&response, Ok(response) if response.status().is_success());
+    slot.finish(success)
+        .await
+        .map_err(|err| ErrorInternalServerError(internal_error(err)))?;
+    response
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::*, tests_cfg::cake, DbBackend, DbErr, MockDatabase, Statement, Transaction,
+    };
+    use actix_web::{
+        http::StatusCode,
+        middleware::from_fn,
+        test::{call_service, init_service, TestRequest},
+        web, App, HttpResponse,
+    };
+
+    async fn list_cakes(db: Db, pagination: Pagination, sort: Sort<cake::Entity>) -> HttpResponse {
+        let query = sort.apply(cake::Entity::find());
+        let cakes = pagination.fetch(query, &*db).await.unwrap();
+        HttpResponse::Ok().body(cakes.len().to_string())
+    }
+
+    async fn rename_cake(txn: Txn) -> HttpResponse {
+        cake::ActiveModel {
+            id: Set(1),
+            name: Set("Chocolate Forest".to_owned()),
+        }
+        .update(&*txn)
+        .await
+        .unwrap();
+        HttpResponse::Ok().finish()
+    }
+
+    async fn fail(txn: Txn) -> HttpResponse {
+        rename_cake(txn).await;
+        HttpResponse::Conflict().finish()
+    }
+
+    async fn call(db: &Arc<DatabaseConnection>, uri: &str) -> StatusCode {
+        let app = init_service(
+            App::new()
+                .app_data(Data::from(db.clone()))
+                .wrap(from_fn(transaction))
+                .route("/cakes", web::get().to(list_cakes))
+                .route("/rename", web::get().to(rename_cake))
+                .route("/fail", web::get().to(fail)),
+        )
+        .await;
+        call_service(&app, TestRequest::get().uri(uri).to_request())
+            .await
+            .status()
+    }
+
+    fn cake_model() -> cake::Model {
+        cake::Model {
+            id: 1,
+            name: "Chocolate Forest".to_owned(),
+        }
+    }
+
+    fn update_log(end: &str) -> Transaction {
+        Transaction::many([
+            Statement::from_string(DbBackend::Postgres, "BEGIN"),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE "cake" SET "name" = $1 WHERE "cake"."id" = $2 RETURNING "id", "name""#,
+                ["Chocolate Forest".into(), 1i32.into()],
+            ),
+            Statement::from_string(DbBackend::Postgres, end),
+        ])
+    }
+
+    #[actix_rt::test]
+    async fn list_with_query_params() -> Result<(), DbErr> {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([[cake_model()]])
+                .into_connection(),
+        );
+
+        assert_eq!(
+            call(&db, "/cakes?page=2&per_page=5&sort=-name").await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            call(&db, "/cakes?sort=price").await,
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(call(&db, "/cakes?page=x").await, StatusCode::BAD_REQUEST);
+
+        let db = Arc::into_inner(db).unwrap();
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" ORDER BY "cake"."name" DESC LIMIT $1 OFFSET $2"#,
+                [5u64.into(), 10u64.into()],
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[actix_rt::test]
+    async fn commit_on_success() -> Result<(), DbErr> {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([[cake_model()], [cake_model()]])
+                .into_connection(),
+        );
+
+        assert_eq!(call(&db, "/rename").await, StatusCode::OK);
+        assert_eq!(call(&db, "/fail").await, StatusCode::CONFLICT);
+
+        let db = Arc::into_inner(db).unwrap();
+        assert_eq!(
+            db.into_transaction_log(),
+            [update_log("COMMIT"), update_log("ROLLBACK")]
+        );
+
+        Ok(())
+    }
+}
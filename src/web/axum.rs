@@ -0,0 +1,272 @@
+//! The extractors for axum
+//!
+//! ```
+//! use axum::{middleware, routing::get, Router};
+//! use sea_orm::{
+//!     entity::*,
+//!     tests_cfg::cake,
+//!     web::{self, Db, Pagination, Sort, Txn},
+//!     DatabaseConnection,
+//! };
+//! use std::sync::Arc;
+//!
+//! async fn list_cakes(
+//!     db: Db,
+//!     pagination: Pagination,
+//!     sort: Sort<cake::Entity>,
+//! ) -> String {
+//!     let query = sort.apply(cake::Entity::find());
+//!     let cakes = pagination.fetch(query, &*db).await.unwrap();
+//!     cakes.into_iter().map(|cake| cake.name).collect::<Vec<_>>().join("\n")
+//! }
+//!
+//! async fn rename_cake(txn: Txn) -> &'static str {
+//!     cake::ActiveModel {
+//!         id: Set(1),
+//!         name: Set("Chocolate Forest".to_owned()),
+//!     }
+//!     .update(&*txn)
+//!     .await
+//!     .unwrap();
+//!     "renamed"
+//! }
+//!
+//! fn app(db: DatabaseConnection) -> Router {
+//!     Router::new()
+//!         .route("/cakes", get(list_cakes))
+//!         .route("/cakes/1/rename", get(rename_cake))
+//!         .layer(middleware::from_fn(web::axum::transaction))
+//!         .with_state(Arc::new(db))
+//! }
+//! ```
+
+use super::{internal_error, Db, Pagination, Sort, SortParams, Txn, TxnSlot};
+use crate::{DatabaseConnection, EntityTrait};
+use axum::{
+    extract::{FromRef, FromRequestParts, Query, Request},
+    http::{request::Parts, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+/// The rejection of the extractors, a status code and a message
+pub type Rejection = (StatusCode, String);
+
+impl<S> FromRequestParts<S> for Db
+where
+    Arc<DatabaseConnection>: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Rejection;
+
+    async fn from_request_parts(_: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(Arc::from_ref(state)))
+    }
+}
+
+impl<S> FromRequestParts<S> for Txn
+where
+    Arc<DatabaseConnection>: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let slot = parts.extensions.get::<TxnSlot>().cloned().ok_or((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Txn requires the transaction middleware".to_owned(),
+        ))?;
+        slot.get_or_begin(&Arc::from_ref(state))
+            .await
+            .map_err(|err| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    internal_error(err).to_owned(),
+                )
+            })
+    }
+}
+
+impl<S> FromRequestParts<S> for Pagination
+where
+    S: Send + Sync,
+{
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(pagination) = Query::<Self>::from_request_parts(parts, state)
+            .await
+            .map_err(|err| (err.status(), err.body_text()))?;
+        Ok(pagination)
+    }
+}
+
+impl<S, E> FromRequestParts<S> for Sort<E>
+where
+    S: Send + Sync,
+    E: EntityTrait,
+{
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(params) = Query::<SortParams>::from_request_parts(parts, state)
+            .await
+            .map_err(|err| (err.status(), err.body_text()))?;
+        params
+            .parse()
+            .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))
+    }
+}
+
+/// The middleware committing the [Txn] of a request if the response is a success (2xx),
+/// and rolling it back otherwise. Add it with `axum::middleware::from_fn`.
+pub async fn transaction(mut request: Request, next: Next) -> Response {
+    let slot = TxnSlot::default();
+    request.extensions_mut().insert(slot.clone());
+    let response = next.run(request).await;
+    match slot.finish(response.status().is_success()).await {
+        Ok(()) => response,
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, internal_error(err)).into_response(),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::*, tests_cfg::cake, DbBackend, DbErr, MockDatabase, Statement, Transaction,
+    };
+    use axum::{body::Body, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    async fn list_cakes(db: Db, pagination: Pagination, sort: Sort<cake::Entity>) -> String {
+        let query = sort.apply(cake::Entity::find());
+        let cakes = pagination.fetch(query, &*db).await.unwrap();
+        cakes.len().to_string()
+    }
+
+    async fn rename_cake(txn: Txn) -> StatusCode {
+        cake::ActiveModel {
+            id: Set(1),
+            name: Set("Chocolate Forest".to_owned()),
+        }
+        .update(&*txn)
+        .await
+        .unwrap();
+        StatusCode::OK
+    }
+
+    async fn fail(txn: Txn) -> StatusCode {
+        rename_cake(txn).await;
+        StatusCode::CONFLICT
+    }
+
+    async fn leak(txn: Txn) -> StatusCode {
+        std::mem::forget(txn.clone());
+        StatusCode::OK
+    }
+
+    fn app(db: Arc<DatabaseConnection>) -> Router {
+        Router::new()
+            .route("/cakes", get(list_cakes))
+            .route("/rename", get(rename_cake))
+            .route("/fail", get(fail))
+            .route("/leak", get(leak))
+            .layer(middleware::from_fn(transaction))
+            .with_state(db)
+    }
+
+    async fn call(db: &Arc<DatabaseConnection>, uri: &str) -> StatusCode {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        app(db.clone()).oneshot(request).await.unwrap().status()
+    }
+
+    fn cake_model() -> cake::Model {
+        cake::Model {
+            id: 1,
+            name: "Chocolate Forest".to_owned(),
+        }
+    }
+
+    fn update_log(end: &str) -> Transaction {
+        Transaction::many([
+            Statement::from_string(DbBackend::Postgres, "BEGIN"),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE "cake" SET "name" = $1 WHERE "cake"."id" = $2 RETURNING "id", "name""#,
+                ["Chocolate Forest".into(), 1i32.into()],
+            ),
+            Statement::from_string(DbBackend::Postgres, end),
+        ])
+    }
+
+    #[smol_potat::test]
+    async fn list_with_query_params() -> Result<(), DbErr> {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([[cake_model()]])
+                .into_connection(),
+        );
+
+        assert_eq!(
+            call(&db, "/cakes?page=2&per_page=5&sort=-name").await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            call(&db, "/cakes?sort=price").await,
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(call(&db, "/cakes?page=x").await, StatusCode::BAD_REQUEST);
+
+        let db = Arc::into_inner(db).unwrap();
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" ORDER BY "cake"."name" DESC LIMIT $1 OFFSET $2"#,
+                [5u64.into(), 10u64.into()],
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn commit_on_success() -> Result<(), DbErr> {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([[cake_model()], [cake_model()]])
+                .into_connection(),
+        );
+
+        assert_eq!(call(&db, "/rename").await, StatusCode::OK);
+        assert_eq!(call(&db, "/fail").await, StatusCode::CONFLICT);
+
+        let db = Arc::into_inner(db).unwrap();
+        assert_eq!(
+            db.into_transaction_log(),
+            [update_log("COMMIT"), update_log("ROLLBACK")]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn hide_database_errors() -> Result<(), DbErr> {
+        let db = Arc::new(MockDatabase::new(DbBackend::Postgres).into_connection());
+
+        let request = Request::get("/leak").body(Body::empty()).unwrap();
+        let response = app(db).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(
+            axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap(),
+            "Internal Server Error"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,274 @@
+//! Enabled by the `axum` and `actix-web` features.
+//!
+//! - [Db] hands out the shared [DatabaseConnection]
+//! - [Txn] begins a transaction that is committed if the response is a success (2xx),
+//!   and rolled back otherwise. It requires the `transaction` middleware of the framework.
+//! - [Pagination] and [Sort] read the `page`, `per_page` and `sort` query parameters
+//!   and apply them to a query
+
+use crate::{
+    ColumnFromStrErr, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait,
+    Order, Paginator, PaginatorTrait, QueryOrder, SelectorTrait, TransactionTrait,
+};
+use serde::Deserialize;
+use std::{
+    fmt,
+    ops::Deref,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+#[cfg(feature = "actix-web")]
+#[cfg_attr(docsrs, doc(cfg(feature = "actix-web")))]
+pub mod actix;
+#[cfg(feature = "axum")]
+#[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+pub mod axum;
+
+/// The database connection of the application.
+///
+/// With axum, it is taken from the state by `FromRef`, with actix-web from
+/// `web::Data<DatabaseConnection>`.
+#[derive(Debug, Clone)]
+pub struct Db(pub Arc<DatabaseConnection>);
+
+impl Deref for Db {
+    type Target = DatabaseConnection;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A transaction spanning the request. It is committed by the `transaction` middleware if
+/// the handler responds with a success (2xx), and rolled back otherwise.
+///
+/// Extracting it more than once in a request gives the same transaction. It must not
+/// outlive the handler, or the response is turned into an error.
+#[derive(Debug, Clone)]
+pub struct Txn(pub Arc<DatabaseTransaction>);
+
+impl Deref for Txn {
+    type Target = DatabaseTransaction;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The transaction of a request, shared between the [Txn] extractor and the middleware
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TxnSlot(Arc<Mutex<Option<Arc<DatabaseTransaction>>>>);
+
+impl TxnSlot {
+    /// The transaction of the request, which is begun on first use
+    pub(crate) async fn get_or_begin(&self, db: &DatabaseConnection) -> Result<Txn, DbErr> {
+        if let Some(txn) = self.0.lock().expect("Fail to acquire lock").as_ref() {
+            return Ok(Txn(txn.clone()));
+        }
+        let txn = Arc::new(db.begin().await?);
+        let mut slot = self.0.lock().expect("Fail to acquire lock");
+        Ok(Txn(slot.get_or_insert(txn).clone()))
+    }
+
+    /// Commit the transaction if the request succeeded, or roll it back
+    pub(crate) async fn finish(&self, success: bool) -> Result<(), DbErr> {
+        let txn = self.0.lock().expect("Fail to acquire lock").take();
+        let Some(txn) = txn else {
+            return Ok(());
+        };
+        match Arc::try_unwrap(txn) {
+            Ok(txn) if success => txn.commit().await,
+            Ok(txn) => txn.rollback().await,
+            Err(_) => Err(DbErr::Custom(
+                "Transaction is still in use after the handler returned".to_owned(),
+            )),
+        }
+    }
+}
+
+/// Log a database error and give the body of the response instead, so that the error isn't
+/// leaked to the client
+pub(crate) fn internal_error(err: DbErr) -> &'static str {
+    tracing::error!("{err}");
+    "Internal Server Error"
+}
+
+/// The `page` and `per_page` query parameters. Pages start from zero, as with [Paginator].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct Pagination {
+    /// The page to fetch, starting from zero
+    pub page: u64,
+    /// The number of items per page, see [Pagination::page_size]
+    pub per_page: u64,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            page: 0,
+            per_page: Self::DEFAULT_PER_PAGE,
+        }
+    }
+}
+
+impl Pagination {
+    /// The number of items per page if `per_page` is not given
+    pub const DEFAULT_PER_PAGE: u64 = 20;
+
+    /// The maximum number of items per page
+    pub const MAX_PER_PAGE: u64 = 100;
+
+    /// The number of items per page, limited to between 1 and [Pagination::MAX_PER_PAGE]
+    pub fn page_size(&self) -> u64 {
+        self.per_page.clamp(1, Self::MAX_PER_PAGE)
+    }
+
+    /// Paginate a query by the page size
+    pub fn paginate<'db, C, S>(&self, query: S, db: &'db C) -> Paginator<'db, C, S::Selector>
+    where
+        C: ConnectionTrait,
+        S: PaginatorTrait<'db, C>,
+    {
+        query.paginate(db, self.page_size())
+    }
+
+    /// Fetch the page of a query
+    pub async fn fetch<'db, C, S>(
+        &self,
+        query: S,
+        db: &'db C,
+    ) -> Result<Vec<<S::Selector as SelectorTrait>::Item>, DbErr>
+    where
+        C: ConnectionTrait,
+        S: PaginatorTrait<'db, C>,
+    {
+        self.paginate(query, db).fetch_page(self.page).await
+    }
+}
+
+/// The `sort` query parameter, a comma separated list of columns of the entity, each
+/// descending if prefixed with `-`, e.g. `?sort=-created_at,name`
+pub struct Sort<E>
+where
+    E: EntityTrait,
+{
+    /// The columns to order by, in order
+    pub columns: Vec<(E::Column, Order)>,
+}
+
+impl<E> Sort<E>
+where
+    E: EntityTrait,
+{
+    /// Order a query by the columns
+    pub fn apply<Q>(&self, mut query: Q) -> Q
+    where
+        Q: QueryOrder,
+    {
+        for (column, order) in self.columns.iter() {
+            query = query.order_by(*column, order.clone());
+        }
+        query
+    }
+}
+
+impl<E> FromStr for Sort<E>
+where
+    E: EntityTrait,
+{
+    type Err = ColumnFromStrErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut columns = Vec::new();
+        for name in s.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let (name, order) = match name.strip_prefix('-') {
+                Some(name) => (name, Order::Desc),
+                None => (name.strip_prefix('+').unwrap_or(name), Order::Asc),
+            };
+            let column =
+                E::Column::from_str(name).map_err(|_| ColumnFromStrErr(name.to_owned()))?;
+            columns.push((column, order));
+        }
+        Ok(Self { columns })
+    }
+}
+
+impl<E> Default for Sort<E>
+where
+    E: EntityTrait,
+{
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+        }
+    }
+}
+
+impl<E> Clone for Sort<E>
+where
+    E: EntityTrait,
+{
+    fn clone(&self) -> Self {
+        Self {
+            columns: self.columns.clone(),
+        }
+    }
+}
+
+impl<E> fmt::Debug for Sort<E>
+where
+    E: EntityTrait,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sort")
+            .field("columns", &self.columns)
+            .finish()
+    }
+}
+
+/// The query parameters read by [Sort]
+#[derive(Deserialize)]
+pub(crate) struct SortParams {
+    pub(crate) sort: Option<String>,
+}
+
+impl SortParams {
+    pub(crate) fn parse<E>(self) -> Result<Sort<E>, ColumnFromStrErr>
+    where
+        E: EntityTrait,
+    {
+        self.sort.as_deref().unwrap_or_default().parse()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tests-cfg")]
+mod tests {
+    use super::*;
+    use crate::{tests_cfg::cake, DbBackend, EntityTrait, QueryTrait};
+
+    #[test]
+    fn sort_from_str() {
+        let sort: Sort<cake::Entity> = "-name, id".parse().unwrap();
+        assert_eq!(
+            sort.apply(cake::Entity::find())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" ORDER BY "cake"."name" DESC, "cake"."id" ASC"#
+        );
+        assert_eq!(
+            "id,price".parse::<Sort<cake::Entity>>().unwrap_err().0,
+            "price"
+        );
+    }
+
+    #[test]
+    fn pagination_page_size() {
+        let pagination = |per_page| Pagination { page: 0, per_page };
+        assert_eq!(pagination(0).page_size(), 1);
+        assert_eq!(pagination(50).page_size(), 50);
+        assert_eq!(pagination(1000).page_size(), Pagination::MAX_PER_PAGE);
+    }
+}
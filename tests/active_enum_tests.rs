@@ -120,9 +120,9 @@ pub async fn insert_active_enum(db: &DatabaseConnection) -> Result<(), DbErr> {
             r#"SELECT "active_enum"."id","#,
             r#""active_enum"."category","#,
             r#""active_enum"."color","#,
-            r#"CAST("active_enum"."tea" AS text)"#,
+            r#"CAST("active_enum"."tea" AS "text")"#,
             r#"FROM "public"."active_enum""#,
-            r#"WHERE "active_enum"."tea" IN (CAST('EverydayTea' AS tea), CAST('BreakfastTea' AS tea))"#,
+            r#"WHERE "active_enum"."tea" IN (CAST('EverydayTea' AS "tea"), CAST('BreakfastTea' AS "tea"))"#,
         ]
         .join(" ")
     );
@@ -154,10 +154,10 @@ pub async fn insert_active_enum(db: &DatabaseConnection) -> Result<(), DbErr> {
             r#"SELECT "active_enum"."id","#,
             r#""active_enum"."category","#,
             r#""active_enum"."color","#,
-            r#"CAST("active_enum"."tea" AS text)"#,
+            r#"CAST("active_enum"."tea" AS "text")"#,
             r#"FROM "public"."active_enum""#,
             r#"WHERE "active_enum"."tea" IS NOT NULL"#,
-            r#"AND "active_enum"."tea" NOT IN (CAST('BreakfastTea' AS tea))"#,
+            r#"AND "active_enum"."tea" NOT IN (CAST('BreakfastTea' AS "tea"))"#,
         ]
         .join(" ")
     );
@@ -316,7 +316,6 @@ pub async fn insert_active_enum_vec(db: &DatabaseConnection) -> Result<(), DbErr
         ActiveModel {
             id: Set(1),
             categories: Set(None),
-            ..Default::default()
         }
         .insert(db)
         .await?
@@ -335,7 +334,6 @@ pub async fn insert_active_enum_vec(db: &DatabaseConnection) -> Result<(), DbErr
     let _ = ActiveModel {
         id: Set(1),
         categories: Set(Some(vec![Category::Big, Category::Small])),
-        ..model.into_active_model()
     }
     .save(db)
     .await?;
@@ -663,7 +661,7 @@ mod tests {
         assert_eq!(
             _select.build(DbBackend::Postgres).to_string(),
             [
-                r#"SELECT "active_enum_child"."id", "active_enum_child"."parent_id", "active_enum_child"."category", "active_enum_child"."color", CAST("active_enum_child"."tea" AS text)"#,
+                r#"SELECT "active_enum_child"."id", "active_enum_child"."parent_id", "active_enum_child"."category", "active_enum_child"."color", CAST("active_enum_child"."tea" AS "text")"#,
                 r#"FROM "public"."active_enum_child""#,
                 r#"INNER JOIN "public"."active_enum" ON "active_enum"."id" = "active_enum_child"."parent_id""#,
                 r#"WHERE "active_enum"."id" = 1"#,
@@ -705,8 +703,8 @@ mod tests {
                 .build(DbBackend::Postgres)
                 .to_string(),
             [
-                r#"SELECT "active_enum"."id" AS "A_id", "active_enum"."category" AS "A_category", "active_enum"."color" AS "A_color", CAST("active_enum"."tea" AS text) AS "A_tea","#,
-                r#""active_enum_child"."id" AS "B_id", "active_enum_child"."parent_id" AS "B_parent_id", "active_enum_child"."category" AS "B_category", "active_enum_child"."color" AS "B_color", CAST("active_enum_child"."tea" AS text) AS "B_tea""#,
+                r#"SELECT "active_enum"."id" AS "A_id", "active_enum"."category" AS "A_category", "active_enum"."color" AS "A_color", CAST("active_enum"."tea" AS "text") AS "A_tea","#,
+                r#""active_enum_child"."id" AS "B_id", "active_enum_child"."parent_id" AS "B_parent_id", "active_enum_child"."category" AS "B_category", "active_enum_child"."color" AS "B_color", CAST("active_enum_child"."tea" AS "text") AS "B_tea""#,
                 r#"FROM "public"."active_enum""#,
                 r#"LEFT JOIN "public"."active_enum_child" ON "active_enum"."id" = "active_enum_child"."parent_id""#,
             ]
@@ -750,7 +748,7 @@ mod tests {
         assert_eq!(
             _select.build(DbBackend::Postgres).to_string(),
             [
-                r#"SELECT "active_enum_child"."id", "active_enum_child"."parent_id", "active_enum_child"."category", "active_enum_child"."color", CAST("active_enum_child"."tea" AS text)"#,
+                r#"SELECT "active_enum_child"."id", "active_enum_child"."parent_id", "active_enum_child"."category", "active_enum_child"."color", CAST("active_enum_child"."tea" AS "text")"#,
                 r#"FROM "public"."active_enum_child""#,
                 r#"INNER JOIN "public"."active_enum" AS "r0" ON "r0"."id" = "active_enum_child"."parent_id""#,
                 r#"WHERE "r0"."id" = 1"#,
@@ -792,8 +790,8 @@ mod tests {
                 .build(DbBackend::Postgres)
                 .to_string(),
             [
-                r#"SELECT "active_enum"."id" AS "A_id", "active_enum"."category" AS "A_category", "active_enum"."color" AS "A_color", CAST("active_enum"."tea" AS text) AS "A_tea","#,
-                r#""r0"."id" AS "B_id", "r0"."parent_id" AS "B_parent_id", "r0"."category" AS "B_category", "r0"."color" AS "B_color", CAST("r0"."tea" AS text) AS "B_tea""#,
+                r#"SELECT "active_enum"."id" AS "A_id", "active_enum"."category" AS "A_category", "active_enum"."color" AS "A_color", CAST("active_enum"."tea" AS "text") AS "A_tea","#,
+                r#""r0"."id" AS "B_id", "r0"."parent_id" AS "B_parent_id", "r0"."category" AS "B_category", "r0"."color" AS "B_color", CAST("r0"."tea" AS "text") AS "B_tea""#,
                 r#"FROM "public"."active_enum""#,
                 r#"LEFT JOIN "public"."active_enum_child" AS "r0" ON "active_enum"."id" = "r0"."parent_id""#,
             ]
@@ -838,7 +836,7 @@ mod tests {
         assert_eq!(
             _select.build(DbBackend::Postgres).to_string(),
             [
-                r#"SELECT "active_enum"."id", "active_enum"."category", "active_enum"."color", CAST("active_enum"."tea" AS text)"#,
+                r#"SELECT "active_enum"."id", "active_enum"."category", "active_enum"."color", CAST("active_enum"."tea" AS "text")"#,
                 r#"FROM "public"."active_enum""#,
                 r#"INNER JOIN "public"."active_enum_child" ON "active_enum_child"."parent_id" = "active_enum"."id""#,
                 r#"WHERE "active_enum_child"."id" = 1"#,
@@ -880,8 +878,8 @@ mod tests {
                 .build(DbBackend::Postgres)
                 .to_string(),
             [
-                r#"SELECT "active_enum_child"."id" AS "A_id", "active_enum_child"."parent_id" AS "A_parent_id", "active_enum_child"."category" AS "A_category", "active_enum_child"."color" AS "A_color", CAST("active_enum_child"."tea" AS text) AS "A_tea","#,
-                r#""active_enum"."id" AS "B_id", "active_enum"."category" AS "B_category", "active_enum"."color" AS "B_color", CAST("active_enum"."tea" AS text) AS "B_tea""#,
+                r#"SELECT "active_enum_child"."id" AS "A_id", "active_enum_child"."parent_id" AS "A_parent_id", "active_enum_child"."category" AS "A_category", "active_enum_child"."color" AS "A_color", CAST("active_enum_child"."tea" AS "text") AS "A_tea","#,
+                r#""active_enum"."id" AS "B_id", "active_enum"."category" AS "B_category", "active_enum"."color" AS "B_color", CAST("active_enum"."tea" AS "text") AS "B_tea""#,
                 r#"FROM "public"."active_enum_child""#,
                 r#"LEFT JOIN "public"."active_enum" ON "active_enum_child"."parent_id" = "active_enum"."id""#,
             ]
@@ -926,7 +924,7 @@ mod tests {
         assert_eq!(
             _select.build(DbBackend::Postgres).to_string(),
             [
-                r#"SELECT "active_enum"."id", "active_enum"."category", "active_enum"."color", CAST("active_enum"."tea" AS text)"#,
+                r#"SELECT "active_enum"."id", "active_enum"."category", "active_enum"."color", CAST("active_enum"."tea" AS "text")"#,
                 r#"FROM "public"."active_enum""#,
                 r#"INNER JOIN "public"."active_enum_child" AS "r0" ON "r0"."parent_id" = "active_enum"."id""#,
                 r#"WHERE "r0"."id" = 1"#,
@@ -968,8 +966,8 @@ mod tests {
                 .build(DbBackend::Postgres)
                 .to_string(),
             [
-                r#"SELECT "active_enum_child"."id" AS "A_id", "active_enum_child"."parent_id" AS "A_parent_id", "active_enum_child"."category" AS "A_category", "active_enum_child"."color" AS "A_color", CAST("active_enum_child"."tea" AS text) AS "A_tea","#,
-                r#""r0"."id" AS "B_id", "r0"."category" AS "B_category", "r0"."color" AS "B_color", CAST("r0"."tea" AS text) AS "B_tea""#,
+                r#"SELECT "active_enum_child"."id" AS "A_id", "active_enum_child"."parent_id" AS "A_parent_id", "active_enum_child"."category" AS "A_category", "active_enum_child"."color" AS "A_color", CAST("active_enum_child"."tea" AS "text") AS "A_tea","#,
+                r#""r0"."id" AS "B_id", "r0"."category" AS "B_category", "r0"."color" AS "B_color", CAST("r0"."tea" AS "text") AS "B_tea""#,
                 r#"FROM "public"."active_enum_child""#,
                 r#"LEFT JOIN "public"."active_enum" AS "r0" ON "active_enum_child"."parent_id" = "r0"."id""#,
             ]
@@ -0,0 +1,5 @@
+#[test]
+fn partial_model_field_types() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/partial_model_*.rs");
+}
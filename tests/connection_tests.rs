@@ -160,3 +160,33 @@ pub async fn connection_with_search_path_postgres() {
 
     ctx.delete().await;
 }
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+pub async fn connection_with_pragmas_sqlite() {
+    use sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+    let mut opt = sea_orm::ConnectOptions::new("sqlite::memory:");
+    opt.max_connections(2)
+        .sqlite_pragma("foreign_keys", "OFF")
+        .sqlite_pragma("cache_size", "-4000")
+        .sqlite_busy_timeout(std::time::Duration::from_millis(1500));
+
+    let db = sea_orm::Database::connect(opt).await.unwrap();
+
+    async fn pragma(db: &DatabaseConnection, name: &str) -> i64 {
+        let res = db
+            .query_one(Statement::from_string(
+                DbBackend::Sqlite,
+                format!("PRAGMA {name}"),
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        res.try_get_by_index(0).unwrap()
+    }
+
+    assert_eq!(pragma(&db, "foreign_keys").await, 0);
+    assert_eq!(pragma(&db, "cache_size").await, -4000);
+    assert_eq!(pragma(&db, "busy_timeout").await, 1500);
+}
@@ -0,0 +1,153 @@
+use sea_orm::{entity::*, query::*, DbBackend};
+
+mod author {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "author")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(has_many = "super::post::Entity")]
+        Post,
+    }
+
+    impl Related<super::post::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Post.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod post {
+    use sea_orm::{entity::prelude::*, sea_query::SimpleExpr};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "post", default_scope = "not_archived")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub author_id: i32,
+        pub status: String,
+    }
+
+    pub fn not_archived() -> SimpleExpr {
+        Column::Status.ne("archived")
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(
+            belongs_to = "super::author::Entity",
+            from = "Column::AuthorId",
+            to = "super::author::Column::Id"
+        )]
+        Author,
+    }
+
+    impl Related<super::author::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Author.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+fn sql<S: QueryTrait>(query: S) -> String {
+    query.build(DbBackend::Postgres).to_string()
+}
+
+#[test]
+fn find_is_scoped() {
+    assert_eq!(
+        sql(post::Entity::find().filter(post::Column::AuthorId.eq(1))),
+        r#"SELECT "post"."id", "post"."author_id", "post"."status" FROM "post" WHERE "post"."status" <> 'archived' AND "post"."author_id" = 1"#
+    );
+    assert_eq!(
+        sql(author::Entity::find()),
+        r#"SELECT "author"."id", "author"."name" FROM "author""#
+    );
+}
+
+#[test]
+fn unscoped() {
+    assert_eq!(
+        sql(post::Entity::unscoped().filter(post::Column::AuthorId.eq(1))),
+        r#"SELECT "post"."id", "post"."author_id", "post"."status" FROM "post" WHERE "post"."author_id" = 1"#
+    );
+}
+
+#[test]
+fn find_related_is_scoped() {
+    let author = author::Model {
+        id: 1,
+        name: "Alice".to_owned(),
+    };
+    assert_eq!(
+        sql(author.find_related(post::Entity)),
+        [
+            r#"SELECT "post"."id", "post"."author_id", "post"."status" FROM "post""#,
+            r#"INNER JOIN "author" ON "author"."id" = "post"."author_id""#,
+            r#"WHERE "post"."status" <> 'archived' AND "author"."id" = 1"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn join_is_scoped() {
+    assert_eq!(
+        sql(author::Entity::find().find_with_related(post::Entity)),
+        [
+            r#"SELECT "author"."id" AS "A_id", "author"."name" AS "A_name","#,
+            r#""post"."id" AS "B_id", "post"."author_id" AS "B_author_id", "post"."status" AS "B_status""#,
+            r#"FROM "author""#,
+            r#"LEFT JOIN "post" ON "author"."id" = "post"."author_id" AND "post"."status" <> 'archived'"#,
+            r#"ORDER BY "author"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        sql(post::Entity::find().inner_join(author::Entity)),
+        [
+            r#"SELECT "post"."id", "post"."author_id", "post"."status" FROM "post""#,
+            r#"INNER JOIN "author" ON "post"."author_id" = "author"."id""#,
+            r#"WHERE "post"."status" <> 'archived'"#,
+        ]
+        .join(" ")
+    );
+}
+
+pub struct AuthorPosts;
+
+impl Linked for AuthorPosts {
+    type FromEntity = author::Entity;
+    type ToEntity = post::Entity;
+
+    fn link(&self) -> Vec<RelationDef> {
+        vec![author::Relation::Post.def()]
+    }
+}
+
+#[test]
+fn linked_join_is_scoped() {
+    assert_eq!(
+        sql(author::Entity::find().find_also_linked(AuthorPosts)),
+        [
+            r#"SELECT "author"."id" AS "A_id", "author"."name" AS "A_name","#,
+            r#""r0"."id" AS "B_id", "r0"."author_id" AS "B_author_id", "r0"."status" AS "B_status""#,
+            r#"FROM "author""#,
+            r#"LEFT JOIN "post" AS "r0" ON "author"."id" = "r0"."author_id""#,
+            r#"AND EXISTS(SELECT 1 FROM "post" WHERE "post"."status" <> 'archived' AND "post"."id" = "r0"."id")"#,
+        ]
+        .join(" ")
+    );
+}
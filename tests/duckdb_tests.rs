@@ -0,0 +1,137 @@
+#![cfg(feature = "duckdb-bundled")]
+
+use futures_util::TryStreamExt;
+use sea_orm::{
+    entity::*, query::*, tests_cfg::*, ConnectionTrait, Database, DatabaseConnection, DbBackend,
+    DbErr, RlsContext, SqlErr, TransactionError, TransactionTrait,
+};
+
+// cargo test --features duckdb-bundled --test duckdb_tests
+async fn setup() -> Result<DatabaseConnection, DbErr> {
+    let db = Database::connect("duckdb::memory:").await?;
+    // DuckDB has no `serial`, so primary keys are assigned explicitly
+    db.execute_unprepared(
+        r#"CREATE TABLE "cake" ("id" INTEGER PRIMARY KEY, "name" VARCHAR NOT NULL)"#,
+    )
+    .await?;
+    Ok(db)
+}
+
+fn cake(id: i32, name: &str) -> cake::ActiveModel {
+    cake::ActiveModel {
+        id: Set(id),
+        name: Set(name.to_owned()),
+    }
+}
+
+#[tokio::test]
+async fn duckdb_crud() -> Result<(), DbErr> {
+    let db = setup().await?;
+    assert_eq!(db.get_database_backend(), DbBackend::Postgres);
+    assert!(db.is_duckdb());
+
+    cake(1, "Apple Pie").insert(&db).await?;
+    let cheese = cake(2, "Cheese Cake").insert(&db).await?;
+    assert_eq!(
+        cheese,
+        cake::Model {
+            id: 2,
+            name: "Cheese Cake".to_owned(),
+        }
+    );
+
+    let mut apple: cake::ActiveModel = cake::Entity::find_by_id(1)
+        .one(&db)
+        .await?
+        .expect("Apple Pie should exist")
+        .into();
+    apple.name = Set("Apple Crumble".to_owned());
+    apple.update(&db).await?;
+
+    assert_eq!(
+        cake::Entity::find()
+            .order_by_asc(cake::Column::Id)
+            .into_json()
+            .all(&db)
+            .await?,
+        [
+            serde_json::json!({ "id": 1, "name": "Apple Crumble" }),
+            serde_json::json!({ "id": 2, "name": "Cheese Cake" }),
+        ]
+    );
+
+    let names: Vec<String> = cake::Entity::find()
+        .order_by_desc(cake::Column::Id)
+        .stream(&db)
+        .await?
+        .map_ok(|cake| cake.name)
+        .try_collect()
+        .await?;
+    assert_eq!(names, ["Cheese Cake", "Apple Crumble"]);
+
+    let res = cake::Entity::delete_by_id(2).exec(&db).await?;
+    assert_eq!(res.rows_affected, 1);
+    assert_eq!(cake::Entity::find().count(&db).await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn duckdb_transaction() -> Result<(), DbErr> {
+    let db = setup().await?;
+
+    let txn = db.begin().await?;
+    cake(1, "Apple Pie").insert(&txn).await?;
+    txn.commit().await?;
+
+    {
+        let txn = db.begin().await?;
+        cake(2, "Chocolate Cake").insert(&txn).await?;
+        // dropped without committing
+    }
+
+    let cakes = cake::Entity::find().all(&db).await?;
+    assert_eq!(
+        cakes,
+        [cake::Model {
+            id: 1,
+            name: "Apple Pie".to_owned(),
+        }]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn duckdb_sql_err() -> Result<(), DbErr> {
+    let db = setup().await?;
+
+    cake(1, "Apple Pie").insert(&db).await?;
+    let err = cake(1, "Apple Pie")
+        .insert(&db)
+        .await
+        .expect_err("duplicate primary key");
+    assert!(matches!(
+        err.sql_err(),
+        Some(SqlErr::UniqueConstraintViolation(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn duckdb_refuses_rls() -> Result<(), DbErr> {
+    let db = setup().await?;
+
+    let res = db
+        .with_rls_context(RlsContext::new(42, "app_user"), |txn| {
+            Box::pin(async move { cake::Entity::find().all(txn).await })
+        })
+        .await;
+    assert!(matches!(
+        res,
+        Err(TransactionError::Connection(DbErr::Custom(_)))
+    ));
+
+    Ok(())
+}
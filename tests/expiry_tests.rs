@@ -0,0 +1,116 @@
+use sea_orm::{entity::*, query::*, DbBackend};
+
+mod user {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "user")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(has_many = "super::token::Entity")]
+        Token,
+    }
+
+    impl Related<super::token::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Token.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod token {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "token")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub user_id: i32,
+        #[sea_orm(expires_at)]
+        pub expires_at: Option<DateTimeUtc>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(
+            belongs_to = "super::user::Entity",
+            from = "Column::UserId",
+            to = "super::user::Column::Id"
+        )]
+        User,
+    }
+
+    impl Related<super::user::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::User.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+fn sql<S: QueryTrait>(query: S) -> String {
+    query.build(DbBackend::Postgres).to_string()
+}
+
+#[test]
+fn find_is_unexpired() {
+    assert_eq!(
+        sql(token::Entity::find().filter(token::Column::UserId.eq(1))),
+        [
+            r#"SELECT "token"."id", "token"."user_id", "token"."expires_at" FROM "token""#,
+            r#"WHERE ("token"."expires_at" IS NULL OR "token"."expires_at" > CURRENT_TIMESTAMP)"#,
+            r#"AND "token"."user_id" = 1"#,
+        ]
+        .join(" ")
+    );
+    assert!(token::Column::ExpiresAt.def().is_expires_at());
+    assert!(!token::Column::UserId.def().is_expires_at());
+}
+
+#[test]
+fn unscoped_includes_expired() {
+    assert_eq!(
+        sql(token::Entity::unscoped()),
+        r#"SELECT "token"."id", "token"."user_id", "token"."expires_at" FROM "token""#
+    );
+}
+
+#[test]
+fn related_is_unexpired() {
+    let user = user::Model {
+        id: 1,
+        name: "Alice".to_owned(),
+    };
+    assert_eq!(
+        sql(user.find_related(token::Entity)),
+        [
+            r#"SELECT "token"."id", "token"."user_id", "token"."expires_at" FROM "token""#,
+            r#"INNER JOIN "user" ON "user"."id" = "token"."user_id""#,
+            r#"WHERE ("token"."expires_at" IS NULL OR "token"."expires_at" > CURRENT_TIMESTAMP)"#,
+            r#"AND "user"."id" = 1"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        sql(user::Entity::find().find_with_related(token::Entity)),
+        [
+            r#"SELECT "user"."id" AS "A_id", "user"."name" AS "A_name","#,
+            r#""token"."id" AS "B_id", "token"."user_id" AS "B_user_id", "token"."expires_at" AS "B_expires_at""#,
+            r#"FROM "user""#,
+            r#"LEFT JOIN "token" ON "user"."id" = "token"."user_id""#,
+            r#"AND ("token"."expires_at" IS NULL OR "token"."expires_at" > CURRENT_TIMESTAMP)"#,
+            r#"ORDER BY "user"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+}
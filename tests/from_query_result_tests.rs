@@ -3,7 +3,7 @@
 use sea_orm::{
     prelude::*,
     query::{QueryOrder, QuerySelect},
-    FromQueryResult, JoinType, Set,
+    FromQueryResult, JoinType, Set, Statement,
 };
 
 use crate::common::TestContext;
@@ -192,6 +192,138 @@ async fn from_query_result_plain_model() {
     ctx.delete().await;
 }
 
+#[derive(FromQueryResult)]
+struct BakeryWithDefaults {
+    id: i32,
+    #[sea_orm(default)]
+    name: String,
+    #[sea_orm(from_alias = "profit_margin", default_expr = "-1.0")]
+    profit: f64,
+}
+
+#[sea_orm_macros::test]
+async fn from_query_result_default() {
+    let ctx = TestContext::new("from_query_result_default").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    let bakery: BakeryWithDefaults = bakery::Entity::find()
+        .into_model()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+
+    assert_eq!(bakery.id, 42);
+    assert_eq!(bakery.name, "cool little bakery");
+    assert_eq!(bakery.profit, 4.1);
+
+    let bakery: BakeryWithDefaults = bakery::Entity::find()
+        .select_only()
+        .column(bakery::Column::Id)
+        .into_model()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+
+    assert_eq!(bakery.id, 42);
+    assert_eq!(bakery.name, "");
+    assert_eq!(bakery.profit, -1.0);
+
+    ctx.delete().await;
+}
+
+#[derive(FromQueryResult)]
+struct BakeryTuple(i32, String, #[sea_orm(from_alias = "profit_margin")] f64);
+
+#[sea_orm_macros::test]
+async fn from_query_result_tuple() {
+    let ctx = TestContext::new("from_query_result_tuple").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    let bakery: BakeryTuple = bakery::Entity::find()
+        .select_only()
+        .column(bakery::Column::Id)
+        .column(bakery::Column::Name)
+        .column(bakery::Column::ProfitMargin)
+        .into_model()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+
+    assert_eq!(bakery.0, 42);
+    assert_eq!(bakery.1, "cool little bakery");
+    assert_eq!(bakery.2, 4.1);
+
+    ctx.delete().await;
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct Circle {
+    radius: f64,
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct Rectangle {
+    width: f64,
+    height: f64,
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+#[sea_orm(tag = "kind")]
+enum Shape {
+    #[sea_orm(tag_value = "circle")]
+    Circle(Circle),
+    #[sea_orm(tag_value = "rectangle")]
+    Rectangle(Rectangle),
+    Point,
+}
+
+#[sea_orm_macros::test]
+async fn from_query_result_enum() {
+    let ctx = TestContext::new("from_query_result_enum").await;
+    let backend = ctx.db.get_database_backend();
+
+    let shapes = Shape::find_by_statement(Statement::from_string(
+        backend,
+        "SELECT 'circle' AS kind, 2.0 AS radius, NULL AS width, NULL AS height \
+        UNION ALL SELECT 'rectangle', NULL, 3.0, 4.0 \
+        UNION ALL SELECT 'Point', NULL, NULL, NULL",
+    ))
+    .all(&ctx.db)
+    .await
+    .expect("succeeds to get the result");
+
+    assert_eq!(
+        shapes,
+        [
+            Shape::Circle(Circle { radius: 2.0 }),
+            Shape::Rectangle(Rectangle {
+                width: 3.0,
+                height: 4.0
+            }),
+            Shape::Point,
+        ]
+    );
+
+    let err =
+        Shape::find_by_statement(Statement::from_string(backend, "SELECT 'triangle' AS kind"))
+            .one(&ctx.db)
+            .await
+            .expect_err("fails on an unknown tag");
+    assert_eq!(
+        err,
+        DbErr::Type("unknown `kind` of `Shape`: triangle".to_owned())
+    );
+
+    ctx.delete().await;
+}
+
 #[derive(Debug, FromQueryResult)]
 struct WrongBakery {
     id: String,
@@ -0,0 +1,148 @@
+#![cfg(feature = "libsql")]
+
+use futures_util::TryStreamExt;
+use sea_orm::{
+    entity::*, query::*, tests_cfg::*, ConnectionTrait, Database, DatabaseConnection, DbBackend,
+    DbErr, Schema, SqlErr, TransactionTrait,
+};
+
+// cargo test --features libsql,runtime-tokio-native-tls --test libsql_tests
+async fn setup() -> Result<DatabaseConnection, DbErr> {
+    let db = Database::connect("libsql::memory:").await?;
+    let schema = Schema::new(DbBackend::Sqlite);
+    db.execute(
+        db.get_database_backend()
+            .build(&schema.create_table_from_entity(cake::Entity)),
+    )
+    .await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn libsql_crud() -> Result<(), DbErr> {
+    let db = setup().await?;
+    assert_eq!(db.get_database_backend(), DbBackend::Sqlite);
+
+    let res = cake::Entity::insert(cake::ActiveModel {
+        name: Set("Apple Pie".to_owned()),
+        ..Default::default()
+    })
+    .exec(&db)
+    .await?;
+    assert_eq!(res.last_insert_id, 1);
+
+    let cheese = cake::ActiveModel {
+        name: Set("Cheese Cake".to_owned()),
+        ..Default::default()
+    }
+    .insert(&db)
+    .await?;
+    assert_eq!(
+        cheese,
+        cake::Model {
+            id: 2,
+            name: "Cheese Cake".to_owned(),
+        }
+    );
+
+    let mut apple: cake::ActiveModel = cake::Entity::find_by_id(1)
+        .one(&db)
+        .await?
+        .expect("Apple Pie should exist")
+        .into();
+    apple.name = Set("Apple Crumble".to_owned());
+    apple.update(&db).await?;
+
+    assert_eq!(
+        cake::Entity::find()
+            .order_by_asc(cake::Column::Id)
+            .into_json()
+            .all(&db)
+            .await?,
+        [
+            serde_json::json!({ "id": 1, "name": "Apple Crumble" }),
+            serde_json::json!({ "id": 2, "name": "Cheese Cake" }),
+        ]
+    );
+
+    let names: Vec<String> = cake::Entity::find()
+        .order_by_desc(cake::Column::Id)
+        .stream(&db)
+        .await?
+        .map_ok(|cake| cake.name)
+        .try_collect()
+        .await?;
+    assert_eq!(names, ["Cheese Cake", "Apple Crumble"]);
+
+    let res = cake::Entity::delete_by_id(2).exec(&db).await?;
+    assert_eq!(res.rows_affected, 1);
+    assert_eq!(cake::Entity::find().count(&db).await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn libsql_transaction() -> Result<(), DbErr> {
+    let db = setup().await?;
+
+    let txn = db.begin().await?;
+    cake::ActiveModel {
+        name: Set("Apple Pie".to_owned()),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+    {
+        let nested = txn.begin().await?;
+        cake::ActiveModel {
+            name: Set("Cheese Cake".to_owned()),
+            ..Default::default()
+        }
+        .insert(&nested)
+        .await?;
+        nested.rollback().await?;
+    }
+    txn.commit().await?;
+
+    {
+        let txn = db.begin().await?;
+        cake::ActiveModel {
+            name: Set("Chocolate Cake".to_owned()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        // dropped without committing
+    }
+
+    let cakes = cake::Entity::find().all(&db).await?;
+    assert_eq!(
+        cakes,
+        [cake::Model {
+            id: 1,
+            name: "Apple Pie".to_owned(),
+        }]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn libsql_sql_err() -> Result<(), DbErr> {
+    let db = setup().await?;
+
+    let apple = cake::ActiveModel {
+        id: Set(1),
+        name: Set("Apple Pie".to_owned()),
+    };
+    apple.clone().insert(&db).await?;
+    let err = apple.insert(&db).await.expect_err("duplicate primary key");
+    assert!(matches!(
+        err.sql_err(),
+        Some(SqlErr::UniqueConstraintViolation(_))
+    ));
+
+    assert!(db.sync_replica().await.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use sea_orm::{entity::*, Locale, Localize, Translations};
+
+mod post {
+    use sea_orm::{entity::prelude::*, DeriveLocalize, Translations};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveLocalize)]
+    #[sea_orm(table_name = "post")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(translatable)]
+        pub title: Translations,
+        #[sea_orm(translatable, column_type = "JsonBinary", nullable)]
+        pub body: Option<Translations>,
+        pub slug: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[test]
+fn locale_chain() {
+    assert_eq!(Locale::new("en").chain(), ["en"]);
+    assert_eq!(
+        Locale::new("zh_Hant_TW")
+            .fallback("zh")
+            .fallback("en-US")
+            .chain(),
+        ["zh_Hant_TW", "zh_Hant", "zh", "en-US", "en"]
+    );
+}
+
+#[test]
+fn translatable_column_types() {
+    assert_eq!(
+        post::Column::Title.def().get_column_type(),
+        &ColumnType::Json
+    );
+    assert_eq!(
+        post::Column::Body.def().get_column_type(),
+        &ColumnType::JsonBinary
+    );
+}
+
+#[test]
+fn localize_model() {
+    let mut post = post::Model {
+        id: 1,
+        title: Translations::new()
+            .with("en", "Hello")
+            .with("fr", "Bonjour"),
+        body: Some(Translations::new().with("de", "Welt")),
+        slug: "hello".to_owned(),
+    };
+    post.localize(&Locale::new("fr-CA").fallback("de"));
+    assert_eq!(post.title, Translations::new().with("fr", "Bonjour"));
+    assert_eq!(post.body, Some(Translations::new().with("de", "Welt")));
+
+    post.localize(&Locale::new("es"));
+    assert_eq!(post.title.text(), None);
+    assert_eq!(post.body.as_ref().and_then(Translations::text), None);
+}
+
+#[test]
+fn translations_round_trip_as_json() {
+    let title = Translations::new().with("en", "Hello").with("de", "Hallo");
+    let value: Value = title.clone().into();
+    assert_eq!(
+        value,
+        Value::Json(Some(Box::new(
+            serde_json::json!({ "de": "Hallo", "en": "Hello" })
+        )))
+    );
+    assert_eq!(
+        <Translations as sea_orm::sea_query::ValueType>::try_from(value).unwrap(),
+        title
+    );
+}
@@ -0,0 +1,66 @@
+use sea_orm::entity::prelude::*;
+use serde_json::json;
+
+mod user {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "user")]
+    #[sea_orm(serde(rename_all = "camelCase", skip = "password_hash, r#type"))]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub user_name: String,
+        #[sea_orm(column_name = "mail", serde(rename = "mail", default))]
+        pub email_address: String,
+        pub password_hash: String,
+        pub r#type: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[test]
+fn serialize_model() {
+    let model = user::Model {
+        id: 1,
+        user_name: "Alice".to_owned(),
+        email_address: "alice@example.com".to_owned(),
+        password_hash: "hash".to_owned(),
+        r#type: "admin".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&model).unwrap(),
+        json!({
+            "id": 1,
+            "userName": "Alice",
+            "mail": "alice@example.com",
+        })
+    );
+    assert_eq!(user::Column::EmailAddress.to_string(), "mail");
+}
+
+#[test]
+fn deserialize_model() {
+    let model: user::Model = serde_json::from_value(json!({
+        "id": 1,
+        "userName": "Alice",
+        "passwordHash": "ignored",
+    }))
+    .unwrap();
+
+    assert_eq!(
+        model,
+        user::Model {
+            id: 1,
+            user_name: "Alice".to_owned(),
+            email_address: String::new(),
+            password_hash: String::new(),
+            r#type: String::new(),
+        }
+    );
+}
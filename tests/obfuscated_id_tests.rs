@@ -0,0 +1,82 @@
+use sea_orm::{DbErr, IdCodec, ObfuscatedId};
+use std::sync::Once;
+
+fn setup() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        IdCodec::new("obfuscated id tests")
+            .unwrap()
+            .install()
+            .unwrap();
+    });
+}
+
+#[test]
+fn round_trip() {
+    setup();
+    for id in [0, 1, 2, 61, 62, 63, 1_000_000, i64::MAX, -1, i64::MIN] {
+        let encoded = ObfuscatedId::new(id).to_string();
+        assert_eq!(
+            encoded.parse::<ObfuscatedId<i64>>().unwrap().into_inner(),
+            id
+        );
+    }
+    for id in [0, 42, i32::MAX, i32::MIN] {
+        let encoded = ObfuscatedId::new(id).to_string();
+        assert_eq!(
+            encoded.parse::<ObfuscatedId<i32>>().unwrap().into_inner(),
+            id
+        );
+    }
+}
+
+#[test]
+fn uses_installed_codec() {
+    setup();
+    let encoded = ObfuscatedId::new(1234i64).to_string();
+    assert_eq!(IdCodec::global().decode(&encoded), Some(1234));
+    assert_eq!(IdCodec::default().decode(&encoded), None);
+    assert!(IdCodec::default().install().is_err());
+}
+
+#[test]
+fn sequential_ids_are_not_sequential() {
+    setup();
+    let a = ObfuscatedId::new(100i64).to_string();
+    let b = ObfuscatedId::new(101i64).to_string();
+    assert_ne!(a[1..], b[1..]);
+}
+
+#[test]
+fn reject_invalid() {
+    setup();
+    for s in ["", "a", "!!!", "not an id at all"] {
+        assert!(matches!(
+            s.parse::<ObfuscatedId<i64>>(),
+            Err(DbErr::Type(_))
+        ));
+    }
+    let too_big = IdCodec::global().encode(u64::from(u32::MAX) + 1);
+    assert!(too_big.parse::<ObfuscatedId<i32>>().is_err());
+}
+
+#[test]
+fn invalid_alphabet() {
+    assert!(IdCodec::with_alphabet("0123456789", "").is_err());
+    assert!(IdCodec::with_alphabet("0123456789abcdefa", "").is_err());
+    assert!(IdCodec::with_alphabet("0123456789abcdef", "").is_ok());
+}
+
+#[test]
+fn serde() {
+    setup();
+    let id = ObfuscatedId::new(99i64);
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, format!("\"{id}\""));
+    assert_eq!(
+        serde_json::from_str::<ObfuscatedId<i64>>(&json).unwrap(),
+        id
+    );
+    assert_eq!(serde_json::from_str::<ObfuscatedId<i64>>("99").unwrap(), id);
+    assert!(serde_json::from_str::<ObfuscatedId<i64>>("\"?\"").is_err());
+}
@@ -2,8 +2,10 @@
 
 use entity::{Column, Entity};
 use sea_orm::{
-    prelude::*, sea_query::Alias, DerivePartialModel, FromQueryResult, IntoActiveModel, JoinType,
-    NotSet, QueryOrder, QuerySelect, Set,
+    prelude::*,
+    sea_query::{Alias, SimpleExpr},
+    DerivePartialModel, Frame, FromQueryResult, IntoActiveModel, JoinType, NotSet, Order,
+    QueryOrder, QuerySelect, Set, Window, WindowFunc,
 };
 
 use crate::common::TestContext;
@@ -39,7 +41,7 @@ struct SimpleTest {
 }
 
 #[derive(FromQueryResult, DerivePartialModel)]
-#[sea_orm(entity = "<entity::Model as ModelTrait>::Entity")]
+#[sea_orm(entity = "<entity::Model as ModelTrait>::Entity", skip_type_check)]
 struct EntityNameNotAIdent {
     #[sea_orm(from_col = "foo2")]
     foo: i32,
@@ -48,7 +50,7 @@ struct EntityNameNotAIdent {
 }
 
 #[derive(FromQueryResult, DerivePartialModel)]
-#[sea_orm(entity = "Entity")]
+#[sea_orm(entity = "Entity", skip_type_check)]
 struct FieldFromDiffNameColumnTest {
     #[sea_orm(from_col = "foo2")]
     foo: i32,
@@ -220,6 +222,107 @@ async fn partial_model_flat() {
     ctx.delete().await;
 }
 
+struct IdParams {
+    offset: i32,
+}
+
+fn id_with_offset(params: &IdParams) -> SimpleExpr {
+    Expr::col((bakery::Entity, bakery::Column::Id)).add(params.offset)
+}
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "bakery::Entity", from_query_result, context = "IdParams")]
+struct BakeryWithParams {
+    #[sea_orm(nested)]
+    basics: Bakery,
+    #[sea_orm(from_expr_fn = "id_with_offset")]
+    shifted_id: i32,
+}
+
+#[sea_orm_macros::test]
+async fn partial_model_with_context() {
+    let ctx = TestContext::new("partial_model_with_context").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    for offset in [1, 100] {
+        let bakery: BakeryWithParams = bakery::Entity::find()
+            .into_partial_model_with(&IdParams { offset })
+            .one(&ctx.db)
+            .await
+            .expect("succeeds to get the result")
+            .expect("exactly one model in DB");
+
+        assert_eq!(bakery.basics.id, 42);
+        assert_eq!(bakery.basics.title, "cool little bakery");
+        assert_eq!(bakery.shifted_id, 42 + offset);
+    }
+
+    ctx.delete().await;
+}
+
+#[derive(Debug, DerivePartialModel, PartialEq)]
+#[sea_orm(entity = "bakery::Entity", from_query_result)]
+struct BakeryIdName(i32, String);
+
+#[derive(Debug, FromQueryResult, DerivePartialModel, PartialEq)]
+#[sea_orm(entity = "bakery::Entity")]
+struct BakeryProfit(
+    #[sea_orm(from_col = "profit_margin")] f64,
+    #[sea_orm(from_expr = "Expr::col(bakery::Column::Name).count()")] i64,
+);
+
+#[derive(Debug, DerivePartialModel, PartialEq)]
+#[sea_orm(entity = "cake::Entity", from_query_result)]
+struct CakeBakeryTuple(
+    #[sea_orm(from_col = "name")] String,
+    #[sea_orm(nested)] Option<BakeryIdName>,
+);
+
+#[sea_orm_macros::test]
+async fn partial_model_tuple() {
+    let ctx = TestContext::new("partial_model_tuple").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    let bakery: BakeryIdName = bakery::Entity::find()
+        .into_partial_model()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+    assert_eq!(bakery, BakeryIdName(42, "cool little bakery".to_owned()));
+
+    let profit: BakeryProfit = bakery::Entity::find()
+        .group_by(bakery::Column::ProfitMargin)
+        .into_partial_model()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+    assert_eq!(profit, BakeryProfit(4.1, 1));
+
+    let cake: CakeBakeryTuple = cake::Entity::find()
+        .left_join(bakery::Entity)
+        .order_by_asc(cake::Column::Id)
+        .into_partial_model()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+    assert_eq!(
+        cake,
+        CakeBakeryTuple(
+            "Cheesecake".to_owned(),
+            Some(BakeryIdName(42, "cool little bakery".to_owned()))
+        )
+    );
+
+    ctx.delete().await;
+}
+
 #[sea_orm_macros::test]
 async fn partial_model_nested() {
     // SELECT "bakery"."id" AS "basics_id", "bakery"."name" AS "basics_title", "bakery"."profit_margin" AS "profit" FROM "bakery" LIMIT 1
@@ -430,14 +533,13 @@ async fn partial_model_join_three_flat() {
 
 #[sea_orm_macros::test]
 async fn partial_model_into_active_model() {
-    let mut cake = Cake {
+    let cake = Cake {
         id: 12,
         name: "Lemon Drizzle".to_owned(),
         bakery: None,
         ignore: Ignore {},
     }
     .into_active_model();
-    cake.serial = NotSet;
 
     assert_eq!(
         cake,
@@ -462,8 +564,251 @@ async fn partial_model_into_active_model() {
     );
 }
 
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "bakery::Entity", from_query_result, into_active_model)]
+struct BakeryRename {
+    id: i32,
+    #[sea_orm(from_col = "name")]
+    title: String,
+    #[sea_orm(from_expr = "Expr::col(bakery::Column::ProfitMargin).mul(2)")]
+    profit_margin: f64,
+}
+
+#[sea_orm_macros::test]
+async fn partial_model_into_active_model_update() {
+    let ctx = TestContext::new("partial_model_into_active_model_update").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    let mut bakery: BakeryRename = bakery::Entity::find()
+        .into_partial_model()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+    assert_eq!(bakery.profit_margin, 8.2);
+
+    bakery.title = "renamed bakery".to_owned();
+    let active_model = bakery.into_active_model();
+    assert_eq!(
+        active_model,
+        bakery::ActiveModel {
+            id: Set(42),
+            name: Set("renamed bakery".to_owned()),
+            profit_margin: NotSet,
+        }
+    );
+
+    let updated = active_model.update(&ctx.db).await.expect("update succeeds");
+    assert_eq!(updated.name, "renamed bakery");
+    assert_eq!(updated.profit_margin, 4.1);
+
+    ctx.delete().await;
+}
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "cake::Entity", from_query_result, separator = "__")]
+struct CakeSeparated {
+    name: String,
+    bakery_id: Option<i32>,
+    #[sea_orm(nested)]
+    bakery: Option<Bakery>,
+    #[sea_orm(nested_prefix = "b.")]
+    same_bakery: Option<Bakery>,
+}
+
+#[sea_orm_macros::test]
+async fn partial_model_nested_prefix() {
+    let ctx = TestContext::new("partial_model_nested_prefix").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    let select = || {
+        cake::Entity::find()
+            .left_join(bakery::Entity)
+            .order_by_asc(cake::Column::Id)
+            .into_partial_model::<CakeSeparated>()
+    };
+    assert_eq!(
+        select()
+            .into_statement(sea_orm::DbBackend::Sqlite)
+            .to_string(),
+        [
+            r#"SELECT "cake"."name" AS "name", "cake"."bakery_id" AS "bakery_id","#,
+            r#""bakery"."id" AS "bakery__id", "bakery"."name" AS "bakery__title","#,
+            r#""bakery"."id" AS "b.id", "bakery"."name" AS "b.title""#,
+            r#"FROM "cake" LEFT JOIN "bakery" ON "cake"."bakery_id" = "bakery"."id""#,
+            r#"ORDER BY "cake"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+
+    let cake = select()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+
+    assert_eq!(cake.name, "Cheesecake");
+    assert_eq!(cake.bakery_id, Some(42));
+    let bakery = cake.bakery.expect("the bakery is joined");
+    assert_eq!(bakery.id, 42);
+    assert_eq!(bakery.title, "cool little bakery");
+    let same_bakery = cake.same_bakery.expect("the bakery is joined");
+    assert_eq!(same_bakery.id, 42);
+
+    ctx.delete().await;
+}
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "E", from_query_result)]
+struct IdName<E: EntityTrait> {
+    id: i32,
+    name: String,
+    #[sea_orm(skip)]
+    entity: std::marker::PhantomData<E>,
+}
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "cake::Entity", from_query_result)]
+struct CakeIdName {
+    #[sea_orm(nested)]
+    cake: IdName<cake::Entity>,
+    #[sea_orm(nested)]
+    bakery: Option<IdName<bakery::Entity>>,
+}
+
+#[sea_orm_macros::test]
+async fn partial_model_generic() {
+    let ctx = TestContext::new("partial_model_generic").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    let bakery: IdName<bakery::Entity> = bakery::Entity::find()
+        .into_partial_model()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+    assert_eq!(bakery.id, 42);
+    assert_eq!(bakery.name, "cool little bakery");
+
+    let cake: CakeIdName = cake::Entity::find()
+        .left_join(bakery::Entity)
+        .order_by_asc(cake::Column::Id)
+        .into_partial_model()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+    assert_eq!(cake.cake.id, 13);
+    assert_eq!(cake.cake.name, "Cheesecake");
+    assert_eq!(cake.bakery.map(|bakery| bakery.id), Some(42));
+
+    ctx.delete().await;
+}
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "cake::Entity", from_query_result)]
+struct CakeJoinedBakery {
+    name: String,
+    #[sea_orm(from_col = "bakery::Column::Name")]
+    bakery_name: Option<String>,
+    #[sea_orm(from_col = "bakery::Column::ProfitMargin")]
+    profit_margin: Option<f64>,
+}
+
+#[sea_orm_macros::test]
+async fn partial_model_joined_col() {
+    let ctx = TestContext::new("partial_model_joined_col").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    let select = || {
+        cake::Entity::find()
+            .left_join(bakery::Entity)
+            .order_by_asc(cake::Column::Id)
+            .into_partial_model::<CakeJoinedBakery>()
+    };
+    assert_eq!(
+        select()
+            .into_statement(sea_orm::DbBackend::Sqlite)
+            .to_string(),
+        [
+            r#"SELECT "cake"."name" AS "name", "bakery"."name" AS "bakery_name","#,
+            r#""bakery"."profit_margin" AS "profit_margin""#,
+            r#"FROM "cake" LEFT JOIN "bakery" ON "cake"."bakery_id" = "bakery"."id""#,
+            r#"ORDER BY "cake"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+
+    let cake = select()
+        .one(&ctx.db)
+        .await
+        .expect("succeeds to get the result")
+        .expect("exactly one model in DB");
+
+    assert_eq!(cake.name, "Cheesecake");
+    assert_eq!(cake.bakery_name.as_deref(), Some("cool little bakery"));
+    assert_eq!(cake.profit_margin, Some(4.1));
+
+    ctx.delete().await;
+}
+
+#[derive(Debug, DerivePartialModel, PartialEq)]
+#[sea_orm(entity = "cake::Entity", from_query_result)]
+struct CakeRank {
+    name: String,
+    #[sea_orm(
+        from_expr = "WindowFunc::row_number().over(Window::new().order_by(cake::Column::Price, Order::Desc))"
+    )]
+    price_rank: i64,
+    #[sea_orm(
+        from_expr = "WindowFunc::sum(cake::Column::Id).over(Window::new().order_by(cake::Column::Id, Order::Asc).rows_between(Frame::UnboundedPreceding, Frame::CurrentRow))"
+    )]
+    running_id: i64,
+}
+
+#[sea_orm_macros::test]
+async fn partial_model_window() {
+    let ctx = TestContext::new("partial_model_window").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    let cakes: Vec<CakeRank> = cake::Entity::find()
+        .order_by_asc(cake::Column::Id)
+        .into_partial_model()
+        .all(&ctx.db)
+        .await
+        .expect("succeeds to get the result");
+
+    assert_eq!(
+        cakes,
+        [
+            CakeRank {
+                name: "Cheesecake".to_owned(),
+                price_rank: 2,
+                running_id: 13,
+            },
+            CakeRank {
+                name: "Chocolate".to_owned(),
+                price_rank: 1,
+                running_id: 28,
+            },
+        ]
+    );
+
+    ctx.delete().await;
+}
+
 #[derive(Debug, FromQueryResult, DerivePartialModel)]
-#[sea_orm(entity = "bakery::Entity")]
+#[sea_orm(entity = "bakery::Entity", skip_type_check)]
 struct WrongBakery {
     id: String,
     #[sea_orm(from_col = "Name")]
@@ -496,3 +841,102 @@ async fn partial_model_optional_field_but_type_error() {
 
     ctx.delete().await;
 }
+
+#[sea_orm_macros::test]
+async fn partial_model_nested_vec() {
+    let ctx = TestContext::new("partial_model_nested_vec").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    seed_data::init_1(&ctx, true).await;
+
+    #[derive(Debug, DerivePartialModel, PartialEq)]
+    #[sea_orm(entity = "order::Entity", from_query_result)]
+    struct Order {
+        id: i32,
+        #[sea_orm(nested)]
+        customer: Customer,
+        #[sea_orm(nested)]
+        lines: Vec<LineItem>,
+    }
+
+    #[derive(Debug, DerivePartialModel, PartialEq)]
+    #[sea_orm(entity = "customer::Entity", from_query_result)]
+    struct Customer {
+        name: String,
+    }
+
+    #[derive(Clone, Debug, DerivePartialModel, PartialEq)]
+    #[sea_orm(entity = "lineitem::Entity", from_query_result)]
+    struct LineItem {
+        price: Decimal,
+        quantity: i32,
+    }
+
+    #[derive(Debug, DerivePartialModel, PartialEq)]
+    #[sea_orm(entity = "customer::Entity", from_query_result)]
+    struct CustomerOrders {
+        name: String,
+        #[sea_orm(nested)]
+        orders: Vec<OrderLines>,
+    }
+
+    #[derive(Debug, DerivePartialModel, PartialEq)]
+    #[sea_orm(entity = "order::Entity", from_query_result)]
+    struct OrderLines {
+        id: i32,
+        #[sea_orm(nested)]
+        lines: Vec<LineItem>,
+    }
+
+    let orders: Vec<Order> = order::Entity::find()
+        .left_join(customer::Entity)
+        .left_join(lineitem::Entity)
+        .order_by_asc(order::Column::Id)
+        .order_by_asc(lineitem::Column::Id)
+        .into_partial_model()
+        .all(&ctx.db)
+        .await
+        .unwrap();
+
+    let lines = vec![
+        LineItem {
+            price: Decimal::from(2),
+            quantity: 2,
+        },
+        LineItem {
+            price: Decimal::from(3),
+            quantity: 2,
+        },
+    ];
+    assert_eq!(
+        orders,
+        [Order {
+            id: 101,
+            customer: Customer {
+                name: "Bob".to_owned()
+            },
+            lines: lines.clone(),
+        }]
+    );
+
+    let customers: Vec<CustomerOrders> = customer::Entity::find()
+        .left_join(order::Entity)
+        .join(JoinType::LeftJoin, order::Relation::Lineitem.def())
+        .order_by_asc(customer::Column::Id)
+        .order_by_asc(order::Column::Id)
+        .order_by_asc(lineitem::Column::Id)
+        .into_partial_model()
+        .all(&ctx.db)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        customers,
+        [CustomerOrders {
+            name: "Bob".to_owned(),
+            orders: vec![OrderLines { id: 101, lines }],
+        }]
+    );
+
+    ctx.delete().await;
+}
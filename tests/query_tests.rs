@@ -222,3 +222,84 @@ pub async fn select_only_exclude_option_fields() {
 
     ctx.delete().await;
 }
+
+#[sea_orm_macros::test]
+pub async fn find_from_derived_table() {
+    use sea_orm::{FromQueryResult, JoinType, QueryOrder, SelectDerived};
+
+    let ctx = TestContext::new("find_from_derived_table").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    for (name, cakes) in [("SeaSide Bakery", 2), ("LakeSide Bakery", 1)] {
+        let bakery = bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+
+        for i in 0..cakes {
+            cake::ActiveModel {
+                name: Set(format!("Cake {i}")),
+                price: Set(rust_dec(10)),
+                bakery_id: Set(Some(bakery.id)),
+                gluten_free: Set(false),
+                serial: Set(sea_orm::prelude::Uuid::new_v4()),
+                ..Default::default()
+            }
+            .insert(&ctx.db)
+            .await
+            .expect("could not insert cake");
+        }
+    }
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct BakeryCakes {
+        name: String,
+        cakes: i64,
+    }
+
+    let counts = SelectDerived::new(
+        Cake::find()
+            .select_only()
+            .column(cake::Column::BakeryId)
+            .column_as(cake::Column::Id.count(), "cakes")
+            .group_by(cake::Column::BakeryId),
+        "counts",
+    );
+    let bakery_id = counts.col("bakery_id");
+    let cakes = counts.col("cakes");
+
+    let bakeries = counts
+        .select_only()
+        .column(bakery::Column::Name)
+        .expr_as(cakes.clone(), "cakes")
+        .join_on(
+            JoinType::InnerJoin,
+            Bakery,
+            bakery_id.equals((bakery::Entity, bakery::Column::Id)),
+        )
+        .order_by_desc(cakes)
+        .into_model::<BakeryCakes>()
+        .all(&ctx.db)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        bakeries,
+        [
+            BakeryCakes {
+                name: "SeaSide Bakery".to_owned(),
+                cakes: 2,
+            },
+            BakeryCakes {
+                name: "LakeSide Bakery".to_owned(),
+                cakes: 1,
+            },
+        ]
+    );
+
+    ctx.delete().await;
+}
@@ -330,7 +330,7 @@ pub async fn group_by() {
     .await
     .expect("could not insert order");
 
-    #[cfg(any(feature = "sqlx-postgres"))]
+    #[cfg(feature = "sqlx-postgres")]
     type Type = i64;
     #[cfg(not(any(feature = "sqlx-postgres")))]
     type Type = i32;
@@ -1111,7 +1111,7 @@ pub async fn select_three() -> Result<(), DbErr> {
         total: Decimal::from(10),
         bakery_id: 42,
         customer_id: 11,
-        placed_at: DateTime::UNIX_EPOCH,
+        placed_at: DateTime::default(),
     };
 
     let customer = customer::Model {
@@ -155,6 +155,58 @@ async fn insert_many() {
     );
 }
 
+#[sea_orm_macros::test]
+async fn insert_many_auto_increment() {
+    pub use common::{features::*, TestContext};
+    use edit_log::*;
+
+    let ctx = TestContext::new("returning_tests_insert_many_auto_increment").await;
+    let db = &ctx.db;
+
+    create_tables(db).await.unwrap();
+
+    Entity::insert(ActiveModel {
+        id: NotSet,
+        action: Set("one".into()),
+        values: Set(json!({ "id": "unique-id-001" })),
+    })
+    .exec(db)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Entity::insert_many([
+            ActiveModel {
+                id: NotSet,
+                action: Set("two".into()),
+                values: Set(json!({ "id": "unique-id-002" })),
+            },
+            ActiveModel {
+                id: NotSet,
+                action: Set("three".into()),
+                values: Set(json!({ "id": "unique-id-003" })),
+            },
+        ])
+        .exec_with_returning_many(db)
+        .await
+        .unwrap(),
+        [
+            Model {
+                id: 2,
+                action: "two".into(),
+                values: json!({ "id": "unique-id-002" }),
+            },
+            Model {
+                id: 3,
+                action: "three".into(),
+                values: json!({ "id": "unique-id-003" }),
+            },
+        ]
+    );
+
+    ctx.delete().await;
+}
+
 #[sea_orm_macros::test]
 #[cfg_attr(
     any(
@@ -234,16 +286,6 @@ async fn insert_many_composite_key() {
 }
 
 #[sea_orm_macros::test]
-#[cfg_attr(
-    any(
-        feature = "sqlx-mysql",
-        all(
-            feature = "sqlx-sqlite",
-            not(feature = "sqlite-use-returning-for-3_35")
-        )
-    ),
-    should_panic(expected = "Database backend doesn't support RETURNING")
-)]
 async fn update_many() {
     pub use common::{features::*, TestContext};
     use edit_log::*;
@@ -353,16 +395,6 @@ async fn update_many() {
 }
 
 #[sea_orm_macros::test]
-#[cfg_attr(
-    any(
-        feature = "sqlx-mysql",
-        all(
-            feature = "sqlx-sqlite",
-            not(feature = "sqlite-use-returning-for-3_35")
-        )
-    ),
-    should_panic(expected = "Database backend doesn't support RETURNING")
-)]
 async fn delete_many() {
     pub use common::{features::*, TestContext};
     use edit_log::*;
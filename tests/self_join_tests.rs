@@ -4,7 +4,7 @@ pub mod common;
 
 pub use common::{features::*, setup::*, TestContext};
 use pretty_assertions::assert_eq;
-use sea_orm::{entity::prelude::*, query::*, DbBackend, IntoActiveModel, QueryOrder};
+use sea_orm::{entity::prelude::*, query::*, DbBackend, IntoActiveModel, QueryOrder, WithDepth};
 
 #[sea_orm_macros::test]
 async fn main() -> Result<(), DbErr> {
@@ -14,6 +14,7 @@ async fn main() -> Result<(), DbErr> {
     ctx.delete().await;
     find_linked_001();
     find_also_linked_001();
+    find_ancestors_001();
 
     Ok(())
 }
@@ -60,7 +61,7 @@ pub async fn create_metadata(db: &DatabaseConnection) -> Result<(), DbErr> {
             .find_linked(self_join::SelfReferencingLink)
             .all(db)
             .await?,
-        [model.clone()]
+        vec![model.clone()]
     );
 
     assert_eq!(
@@ -79,11 +80,70 @@ pub async fn create_metadata(db: &DatabaseConnection) -> Result<(), DbErr> {
             .await?,
         [
             (model.clone(), None),
-            (linked_model, Some(model)),
-            (not_linked_model, None),
+            (linked_model.clone(), Some(model.clone())),
+            (not_linked_model.clone(), None),
         ]
     );
 
+    let grandchild_model = self_join::Model {
+        uuid: Uuid::new_v4(),
+        uuid_ref: Some(linked_model.uuid),
+        time: Some(Time::from_hms_opt(4, 00, 00).unwrap()),
+    };
+
+    grandchild_model
+        .clone()
+        .into_active_model()
+        .insert(db)
+        .await?;
+
+    let depths = |rows: Vec<WithDepth<self_join::Model>>| {
+        rows.into_iter()
+            .map(|row| (row.model, row.depth))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        depths(
+            self_join::Entity::find_descendants(model.uuid, self_join::Column::UuidRef)
+                .all(db)
+                .await?
+        ),
+        [
+            (model.clone(), 0),
+            (linked_model.clone(), 1),
+            (grandchild_model.clone(), 2),
+        ]
+    );
+
+    assert_eq!(
+        depths(
+            self_join::Entity::find_descendants(model.uuid, self_join::Column::UuidRef)
+                .max_depth(1)
+                .all(db)
+                .await?
+        ),
+        [(model.clone(), 0), (linked_model.clone(), 1)]
+    );
+
+    assert_eq!(
+        depths(
+            self_join::Entity::find_ancestors(grandchild_model.uuid, self_join::Column::UuidRef)
+                .all(db)
+                .await?
+        ),
+        [(grandchild_model, 0), (linked_model, 1), (model, 2)]
+    );
+
+    assert_eq!(
+        depths(
+            self_join::Entity::find_ancestors(not_linked_model.uuid, self_join::Column::UuidRef)
+                .all(db)
+                .await?
+        ),
+        [(not_linked_model, 0)]
+    );
+
     Ok(())
 }
 
@@ -128,3 +188,22 @@ fn find_also_linked_001() {
         .join(" ")
     );
 }
+
+fn find_ancestors_001() {
+    use self_join::*;
+
+    assert_eq!(
+        Entity::find_ancestors(Uuid::default(), Column::UuidRef)
+            .build(DbBackend::MySql)
+            .to_string(),
+        [
+            r#"WITH RECURSIVE `tree` (`uuid`, `uuid_ref`, `time`, `depth`) AS"#,
+            r#"(SELECT `self_join`.`uuid`, `self_join`.`uuid_ref`, `self_join`.`time`, 0 AS `depth`"#,
+            r#"FROM `self_join` WHERE `self_join`.`uuid` = '00000000-0000-0000-0000-000000000000'"#,
+            r#"UNION ALL (SELECT `self_join`.`uuid`, `self_join`.`uuid_ref`, `self_join`.`time`, `tree`.`depth` + 1 AS `depth`"#,
+            r#"FROM `self_join` INNER JOIN `tree` ON `self_join`.`uuid` = `tree`.`uuid_ref`))"#,
+            r#"SELECT * FROM `tree` ORDER BY `depth` ASC"#,
+        ]
+        .join(" ")
+    );
+}
@@ -131,7 +131,7 @@ async fn seed_data(db: &DatabaseConnection) {
 }
 
 async fn find_baker_least_sales(db: &DatabaseConnection) -> Option<baker::Model> {
-    #[cfg(any(feature = "sqlx-postgres"))]
+    #[cfg(feature = "sqlx-postgres")]
     type Type = i64;
     #[cfg(not(any(feature = "sqlx-postgres")))]
     type Type = Decimal;
@@ -180,11 +180,11 @@ async fn find_baker_least_sales(db: &DatabaseConnection) -> Option<baker::Model>
         .into_iter()
         .map(|b| LeastSalesBakerResult {
             id: b.id,
-            cakes_sold: b.cakes_sold_opt.unwrap_or_default().into(),
+            cakes_sold: b.cakes_sold_opt.unwrap_or_default(),
         })
         .collect();
 
-    results.sort_by(|a, b| b.cakes_sold.cmp(&a.cakes_sold));
+    results.sort_by_key(|b| std::cmp::Reverse(b.cakes_sold));
 
     Baker::find_by_id(results.last().unwrap().id)
         .one(db)
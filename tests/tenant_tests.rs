@@ -0,0 +1,216 @@
+use sea_orm::{entity::*, query::*, DbBackend, TenantContext};
+
+mod post {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "post", tenant_key = "tenant_id")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub tenant_id: i32,
+        pub title: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(has_many = "super::comment::Entity")]
+        Comment,
+    }
+
+    impl Related<super::comment::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Comment.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod comment {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "comment", tenant_key = "tenant_id")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub tenant_id: i32,
+        pub post_id: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(
+            belongs_to = "super::post::Entity",
+            from = "Column::PostId",
+            to = "super::post::Column::Id"
+        )]
+        Post,
+    }
+
+    impl Related<super::post::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Post.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+fn sql<S: QueryTrait>(query: S) -> String {
+    query.build(DbBackend::Postgres).to_string()
+}
+
+#[test]
+fn tenant_key() {
+    assert_eq!(post::Entity.tenant_key(), Some("tenant_id"));
+}
+
+#[test]
+fn fail_closed_outside_tenant() {
+    assert_eq!(TenantContext::current(), None);
+    assert_eq!(
+        sql(post::Entity::find()),
+        r#"SELECT "post"."id", "post"."tenant_id", "post"."title" FROM "post" WHERE NULL /* no TenantContext */"#
+    );
+    assert_eq!(
+        sql(post::Entity::insert(post::ActiveModel {
+            title: Set("Hello".to_owned()),
+            ..Default::default()
+        })),
+        r#"INSERT INTO "post" ("tenant_id", "title") VALUES (NULL /* no TenantContext */, 'Hello')"#
+    );
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn refuse_outside_tenant() {
+    use sea_orm::{DbErr, MockDatabase};
+
+    let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+    assert!(matches!(
+        post::Entity::find().all(&db).await,
+        Err(DbErr::Custom(_))
+    ));
+    assert!(matches!(
+        post::Entity::delete_many().exec(&db).await,
+        Err(DbErr::Custom(_))
+    ));
+    assert!(db.into_transaction_log().is_empty());
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn refuse_as_built() {
+    use sea_orm::{sea_query::Expr, DbErr, MockDatabase};
+
+    let db = MockDatabase::new(DbBackend::Postgres)
+        .append_query_results([Vec::<post::Model>::new()])
+        .into_connection();
+    let ids = post::Entity::find()
+        .select_only()
+        .column(post::Column::Id)
+        .into_tuple::<i32>();
+    assert!(matches!(
+        TenantContext::new(7).scope(ids.all(&db)).await,
+        Err(DbErr::Custom(_))
+    ));
+    let mentions = TenantContext::sync_unscoped(|| {
+        post::Entity::find().filter(Expr::cust("'NULL /* no TenantContext */' IS NOT NULL"))
+    });
+    assert_eq!(mentions.all(&db).await, Ok(vec![]));
+    assert_eq!(db.into_transaction_log().len(), 1);
+}
+
+#[test]
+fn explicitly_unscoped() {
+    TenantContext::sync_unscoped(|| {
+        assert_eq!(
+            sql(post::Entity::find()),
+            r#"SELECT "post"."id", "post"."tenant_id", "post"."title" FROM "post""#
+        );
+    });
+}
+
+#[test]
+fn scope_joins() {
+    TenantContext::new(7).sync_scope(|| {
+        assert_eq!(
+            sql(post::Entity::find().left_join(comment::Entity)),
+            [
+                r#"SELECT "post"."id", "post"."tenant_id", "post"."title" FROM "post""#,
+                r#"LEFT JOIN "comment" ON "post"."id" = "comment"."post_id" AND "comment"."tenant_id" = 7"#,
+                r#"WHERE "post"."tenant_id" = 7"#,
+            ]
+            .join(" ")
+        );
+    });
+}
+
+#[test]
+fn filter_and_stamp() {
+    TenantContext::new(7).sync_scope(|| {
+        assert_eq!(
+            sql(post::Entity::find_by_id(1)),
+            r#"SELECT "post"."id", "post"."tenant_id", "post"."title" FROM "post" WHERE "post"."tenant_id" = 7 AND "post"."id" = 1"#
+        );
+        assert_eq!(
+            sql(post::Entity::insert(post::ActiveModel {
+                title: Set("Hello".to_owned()),
+                ..Default::default()
+            })),
+            r#"INSERT INTO "post" ("tenant_id", "title") VALUES (7, 'Hello')"#
+        );
+        assert_eq!(
+            sql(post::Entity::update(post::ActiveModel {
+                id: Unchanged(1),
+                tenant_id: Unchanged(7),
+                title: Set("Bye".to_owned()),
+            })),
+            r#"UPDATE "post" SET "title" = 'Bye' WHERE "post"."id" = 1 AND "post"."tenant_id" = 7"#
+        );
+        assert_eq!(
+            sql(post::Entity::update_many()
+                .col_expr(post::Column::Title, sea_orm::sea_query::Expr::value("Bye"))),
+            r#"UPDATE "post" SET "title" = 'Bye' WHERE "post"."tenant_id" = 7"#
+        );
+        assert_eq!(
+            sql(post::Entity::delete(post::ActiveModel {
+                id: Unchanged(1),
+                ..Default::default()
+            })),
+            r#"DELETE FROM "post" WHERE "post"."id" = 1 AND "post"."tenant_id" = 7"#
+        );
+        assert_eq!(
+            sql(post::Entity::delete_many()),
+            r#"DELETE FROM "post" WHERE "post"."tenant_id" = 7"#
+        );
+    });
+}
+
+#[test]
+fn nested_scopes() {
+    TenantContext::new(1).sync_scope(|| {
+        TenantContext::new(2).sync_scope(|| {
+            assert_eq!(TenantContext::current(), Some(TenantContext::new(2)));
+        });
+        assert_eq!(TenantContext::current(), Some(TenantContext::new(1)));
+    });
+    assert_eq!(TenantContext::current(), None);
+}
+
+#[tokio::test]
+async fn scope_across_await() {
+    let delete = TenantContext::new(3)
+        .scope(async {
+            tokio::task::yield_now().await;
+            let inner = TenantContext::unscoped(async { TenantContext::current() }).await;
+            assert_eq!(inner, None);
+            tokio::task::yield_now().await;
+            sql(post::Entity::delete_many())
+        })
+        .await;
+    assert_eq!(delete, r#"DELETE FROM "post" WHERE "post"."tenant_id" = 3"#);
+    assert_eq!(TenantContext::current(), None);
+}
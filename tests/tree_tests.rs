@@ -0,0 +1,122 @@
+use sea_orm::{query::*, DbBackend, TreeModel};
+
+mod category {
+    use sea_orm::{entity::prelude::*, DeriveTree};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveTree)]
+    #[sea_orm(table_name = "category")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(tree_path, indexed)]
+        pub path: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod region {
+    use sea_orm::{entity::prelude::*, DeriveTree};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveTree)]
+    #[sea_orm(table_name = "region")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub country: String,
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub code: i32,
+        #[sea_orm(tree_path, column_name = "lineage", enum_name = "Lineage")]
+        pub r#path: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+fn node(id: i32, path: &str) -> category::Model {
+    category::Model {
+        id,
+        path: path.to_owned(),
+    }
+}
+
+fn sql<S: QueryTrait>(query: S) -> String {
+    query.build(DbBackend::Postgres).to_string()
+}
+
+const SELECT: &str = r#"SELECT "category"."id", "category"."path" FROM "category""#;
+
+#[test]
+fn depth_and_ancestor_paths() {
+    let root = node(1, "1/");
+    assert_eq!(root.depth(), 0);
+    assert!(root.ancestor_paths().is_empty());
+
+    let leaf = node(9, "1/4/9/");
+    assert_eq!(leaf.depth(), 2);
+    assert_eq!(leaf.ancestor_paths(), ["1/", "1/4/"]);
+}
+
+#[test]
+fn roots() {
+    assert_eq!(
+        sql(category::Model::roots()),
+        format!(
+            r#"{SELECT} WHERE "category"."path" NOT LIKE '%/%/' ORDER BY "category"."path" ASC"#
+        )
+    );
+}
+
+#[test]
+fn parent() {
+    assert_eq!(
+        sql(node(9, "1/4/9/").parent()),
+        format!(r#"{SELECT} WHERE "category"."path" IN ('1/4/')"#)
+    );
+    assert_eq!(
+        sql(node(1, "1/").parent()),
+        format!(r#"{SELECT} WHERE 1 = 2"#)
+    );
+}
+
+#[test]
+fn children() {
+    assert_eq!(
+        sql(node(4, "1/4/").children()),
+        format!(
+            r#"{SELECT} WHERE "category"."path" LIKE '1/4/%/' AND "category"."path" NOT LIKE '1/4/%/%/' ORDER BY "category"."path" ASC"#
+        )
+    );
+}
+
+#[test]
+fn ancestors_of_root() {
+    assert_eq!(
+        sql(node(1, "1/").ancestors()),
+        format!(r#"{SELECT} WHERE 1 = 2 ORDER BY "category"."path" ASC"#)
+    );
+}
+
+#[test]
+fn composite_primary_key_segment() {
+    let region = region::Model {
+        country: "nz".to_owned(),
+        code: 12,
+        path: "nz-1/nz-12/".to_owned(),
+    };
+    assert_eq!(region.tree_segment(), "nz-12");
+    assert_eq!(
+        sql(region.descendants()),
+        [
+            r#"SELECT "region"."country", "region"."code", "region"."lineage" FROM "region""#,
+            r#"WHERE "region"."lineage" LIKE 'nz-1/nz-12/%' AND "region"."lineage" <> 'nz-1/nz-12/'"#,
+            r#"ORDER BY "region"."lineage" ASC"#,
+        ]
+        .join(" ")
+    );
+}
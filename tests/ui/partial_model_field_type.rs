@@ -0,0 +1,10 @@
+use sea_orm::{tests_cfg::cake, DerivePartialModel, FromQueryResult};
+
+#[derive(FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "cake::Entity")]
+struct Cake {
+    id: String,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use sea_orm::{tests_cfg::cake, DerivePartialModel, FromQueryResult};
+
+#[derive(FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "cake::Entity")]
+struct Cake {
+    #[sea_orm(from_col = "id")]
+    key: i32,
+    #[sea_orm(from_col = "name")]
+    title: f64,
+}
+
+fn main() {}
@@ -0,0 +1,146 @@
+#![cfg(feature = "utoipa")]
+
+use sea_orm::{entity::prelude::*, DerivePartialModel, FromQueryResult};
+use serde::Serialize;
+use serde_json::json;
+use utoipa::{PartialSchema, ToSchema};
+
+mod bakery {
+    use super::*;
+
+    /// A bakery
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+    #[sea_orm(table_name = "bakery", schema)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        pub profit_margin: Option<f64>,
+        pub size: Size,
+        pub opened_at: DateTimeWithTimeZone,
+        #[serde(skip)]
+        pub secret: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod baker {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "baker")]
+    #[sea_orm(serde(rename_all = "camelCase", skip = "contact_details"), schema)]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(serde(rename = "fullName"))]
+        pub name: String,
+        pub contact_details: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)", schema)]
+#[serde(rename_all = "lowercase")]
+pub enum Size {
+    #[sea_orm(string_value = "S")]
+    Small,
+    #[sea_orm(string_value = "L")]
+    Large,
+}
+
+#[derive(DerivePartialModel, FromQueryResult, Serialize)]
+#[sea_orm(entity = "bakery::Entity", schema)]
+struct BakeryName {
+    name: String,
+}
+
+mod cake {
+    use super::*;
+
+    /// Without `#[sea_orm(schema)]`, `ToSchema` is left to the user
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, ToSchema)]
+    #[sea_orm(table_name = "cake")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[test]
+fn model_schema() {
+    assert_eq!(bakery::Model::name(), "Bakery");
+    assert_eq!(
+        serde_json::to_value(bakery::Model::schema()).unwrap(),
+        json!({
+            "type": "object",
+            "description": "A bakery",
+            "properties": {
+                "id": { "type": "integer", "format": "int32" },
+                "name": { "type": "string" },
+                "profitMargin": { "type": ["number", "null"], "format": "double" },
+                "size": { "$ref": "#/components/schemas/Size" },
+                "openedAt": { "type": "string", "format": "date-time" }
+            },
+            "required": ["id", "name", "size", "openedAt"]
+        })
+    );
+}
+
+#[test]
+fn forwarded_serde_schema() {
+    assert_eq!(
+        serde_json::to_value(baker::Model::schema()).unwrap(),
+        json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer", "format": "int32" },
+                "fullName": { "type": "string" }
+            },
+            "required": ["id", "fullName"]
+        })
+    );
+}
+
+#[test]
+fn active_enum_schema() {
+    assert_eq!(<Size as ToSchema>::name(), "Size");
+    assert_eq!(
+        serde_json::to_value(Size::schema()).unwrap(),
+        json!({ "type": "string", "enum": ["small", "large"] })
+    );
+}
+
+#[test]
+fn partial_model_schema() {
+    assert_eq!(BakeryName::name(), "BakeryName");
+    assert_eq!(
+        serde_json::to_value(BakeryName::schema()).unwrap(),
+        json!({
+            "type": "object",
+            "properties": { "name": { "type": "string" } },
+            "required": ["name"]
+        })
+    );
+}
+
+#[test]
+fn derived_schema() {
+    assert_eq!(cake::Model::name(), "Model");
+}
@@ -0,0 +1,81 @@
+use sea_orm::{entity::*, query::*, sea_query::PostgresQueryBuilder, DbBackend, Schema};
+
+mod post {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(schema_name = "blog", table_name = "post", versioned)]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(unique)]
+        pub slug: String,
+        pub body: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod tag {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "tag")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[test]
+fn versioned_attribute() {
+    assert!(post::Entity.versioned());
+    assert!(!tag::Entity.versioned());
+}
+
+#[test]
+fn create_history_table() {
+    assert_eq!(
+        Schema::new(DbBackend::Postgres)
+            .create_history_table_from_entity(post::Entity)
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "blog"."post_history" ("#,
+            r#""id" integer NOT NULL,"#,
+            r#""slug" varchar NOT NULL,"#,
+            r#""body" varchar,"#,
+            r#""valid_from" timestamp NOT NULL,"#,
+            r#""valid_to" timestamp"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn as_of_keeps_filters_and_order() {
+    assert_eq!(
+        post::Entity::find()
+            .filter(post::Column::Slug.starts_with("rust"))
+            .order_by_asc(post::Column::Id)
+            .as_of("2024-06-30 12:00:00")
+            .build(DbBackend::Postgres)
+            .to_string(),
+        [
+            r#"SELECT "post"."id", "post"."slug", "post"."body" FROM "blog"."post_history" AS "post""#,
+            r#"WHERE "post"."slug" LIKE 'rust%'"#,
+            r#"AND "post"."valid_from" <= '2024-06-30 12:00:00'"#,
+            r#"AND ("post"."valid_to" IS NULL OR "post"."valid_to" > '2024-06-30 12:00:00')"#,
+            r#"ORDER BY "post"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+}